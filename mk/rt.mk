@@ -591,6 +591,11 @@ ifeq ($$(findstring emscripten,$(1)),emscripten)
 COMPRT_OBJS_$(1) :=
 endif
 
+ifeq ($$(findstring wasm32-unknown-unknown,$(1)),wasm32-unknown-unknown)
+# There's no libc to build compiler-rt against, the target doesn't use it
+COMPRT_OBJS_$(1) :=
+endif
+
 $$(COMPRT_LIB_$(1)): $$(COMPRT_OBJS_$(1))
 	@$$(call E, link: $$@)
 	$$(Q)$$(call CFG_CREATE_ARCHIVE_$(1),$$@) $$^
@@ -626,6 +631,10 @@ $$(BACKTRACE_LIB_$(1)):
 	touch $$@
 else ifeq ($$(findstring emscripten,$(1)),emscripten)
 # FIXME: libbacktrace doesn't understand the emscripten triple
+$$(BACKTRACE_LIB_$(1)):
+	touch $$@
+else ifeq ($$(findstring wasm32-unknown-unknown,$(1)),wasm32-unknown-unknown)
+# No backtraces in wasm modules
 $$(BACKTRACE_LIB_$(1)):
 	touch $$@
 else
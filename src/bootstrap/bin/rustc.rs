@@ -103,6 +103,23 @@ fn main() {
             cmd.arg("-C").arg("panic=abort");
         }
 
+        // Link the C runtime statically or dynamically if `crt-static` is set
+        // for this target in config.toml, instead of the target's default.
+        // FIXME(stage0): remove this `stage != "0"` condition
+        if let Ok(s) = env::var("RUSTC_CRT_STATIC") {
+            if stage != "0" {
+                let sign = if s == "true" {"+"} else {"-"};
+                cmd.arg("-C").arg(format!("target-feature={}crt-static", sign));
+            }
+        }
+
+        // The standard library may be instrumented with a sanitizer, in which
+        // case all of its crates are compiled with it. Build scripts don't
+        // get here as they're compiled without `--target`.
+        if let Ok(s) = env::var("RUSTC_SANITIZER") {
+            cmd.arg("-Z").arg(format!("sanitizer={}", s));
+        }
+
         // Set various options from config.toml to configure how we're building
         // code.
         if env::var("RUSTC_DEBUGINFO") == Ok("true".to_string()) {
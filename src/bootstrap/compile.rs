@@ -40,8 +40,7 @@ pub fn std<'a>(build: &'a Build, target: &str, compiler: &Compiler<'a>) {
     let libdir = build.sysroot_libdir(compiler, target);
     let _ = fs::remove_dir_all(&libdir);
     t!(fs::create_dir_all(&libdir));
-    copy(&build.compiler_rt_built.borrow()[target],
-         &libdir.join(staticlib("compiler-rt", target)));
+    copy_compiler_rt(build, target, &libdir);
 
     // Some platforms have startup objects that may be required to produce the
     // libstd dynamic library, for example.
@@ -64,6 +63,12 @@ pub fn std<'a>(build: &'a Build, target: &str, compiler: &Compiler<'a>) {
             cargo.env("MUSL_ROOT", p);
         }
     }
+    // FIXME(stage0): remove this `stage != 0` condition
+    if let Some(ref sanitizer) = build.config.sanitize_std {
+        if compiler.stage != 0 && target == "x86_64-unknown-linux-gnu" {
+            cargo.env("RUSTC_SANITIZER", sanitizer);
+        }
+    }
 
     build.run(&mut cargo);
     std_link(build, target, compiler, compiler.host);
@@ -87,8 +92,7 @@ pub fn std_link(build: &Build,
     if host != compiler.host {
         let _ = fs::remove_dir_all(&libdir);
         t!(fs::create_dir_all(&libdir));
-        copy(&build.compiler_rt_built.borrow()[target],
-             &libdir.join(staticlib("compiler-rt", target)));
+        copy_compiler_rt(build, target, &libdir);
     }
     add_to_sysroot(&out_dir, &libdir);
 
@@ -106,6 +110,23 @@ fn copy_third_party_objects(build: &Build, target: &str, into: &Path) {
     }
 }
 
+/// Copies compiler-rt, and the profiler and sanitizer runtimes if they were
+/// built, into the target libdir `into` where the compiler expects to find
+/// them.
+fn copy_compiler_rt(build: &Build, target: &str, into: &Path) {
+    copy(&build.compiler_rt_built.borrow()[target],
+         &into.join(staticlib("compiler-rt", target)));
+    if let Some(profiler_rt) = build.profiler_rt_built.borrow().get(target) {
+        copy(profiler_rt, &into.join(staticlib("profiler-rt", target)));
+    }
+    if let Some(runtimes) = build.sanitizers_built.borrow().get(target) {
+        for &(name, ref runtime) in runtimes.iter() {
+            let lib = format!("rustc_{}", name);
+            copy(runtime, &into.join(staticlib(&lib, target)));
+        }
+    }
+}
+
 /// Build and prepare startup objects like rsbegin.o and rsend.o
 ///
 /// These are primarily used on Windows right now for linking executables/dlls.
@@ -43,6 +43,8 @@ pub struct Config {
     pub submodules: bool,
     pub compiler_docs: bool,
     pub docs: bool,
+    pub profiler: bool,
+    pub sanitizers: bool,
     pub target_config: HashMap<String, Target>,
 
     // llvm codegen options
@@ -61,6 +63,7 @@ pub struct Config {
     pub rustc_default_ar: Option<String>,
     pub rust_optimize_tests: bool,
     pub rust_debuginfo_tests: bool,
+    pub sanitize_std: Option<String>,
 
     pub build: String,
     pub host: Vec<String>,
@@ -88,6 +91,7 @@ pub struct Target {
     pub cc: Option<PathBuf>,
     pub cxx: Option<PathBuf>,
     pub ndk: Option<PathBuf>,
+    pub crt_static: Option<bool>,
 }
 
 /// Structure of the `config.toml` file that configuration is read from.
@@ -113,6 +117,8 @@ struct Build {
     rustc: Option<String>,
     compiler_docs: Option<bool>,
     docs: Option<bool>,
+    profiler: Option<bool>,
+    sanitizers: Option<bool>,
 }
 
 /// TOML representation of how the LLVM build is configured.
@@ -143,6 +149,7 @@ struct Rust {
     rpath: Option<bool>,
     optimize_tests: Option<bool>,
     debuginfo_tests: Option<bool>,
+    sanitize_std: Option<String>,
 }
 
 /// TOML representation of how each build target is configured.
@@ -153,6 +160,7 @@ struct TomlTarget {
     cc: Option<String>,
     cxx: Option<String>,
     android_ndk: Option<String>,
+    crt_static: Option<bool>,
 }
 
 impl Config {
@@ -215,6 +223,8 @@ impl Config {
         config.cargo = build.cargo.map(PathBuf::from);
         set(&mut config.compiler_docs, build.compiler_docs);
         set(&mut config.docs, build.docs);
+        set(&mut config.profiler, build.profiler);
+        set(&mut config.sanitizers, build.sanitizers);
 
         if let Some(ref llvm) = toml.llvm {
             set(&mut config.ccache, llvm.ccache);
@@ -231,6 +241,7 @@ impl Config {
             set(&mut config.rust_optimize_tests, rust.optimize_tests);
             set(&mut config.rust_debuginfo_tests, rust.debuginfo_tests);
             set(&mut config.rust_rpath, rust.rpath);
+            config.sanitize_std = rust.sanitize_std.clone();
             set(&mut config.debug_jemalloc, rust.debug_jemalloc);
             set(&mut config.use_jemalloc, rust.use_jemalloc);
             set(&mut config.backtrace, rust.backtrace);
@@ -261,6 +272,7 @@ impl Config {
                 }
                 target.cxx = cfg.cxx.clone().map(PathBuf::from);
                 target.cc = cfg.cc.clone().map(PathBuf::from);
+                target.crt_static = cfg.crt_static;
 
                 config.target_config.insert(triple.clone(), target);
             }
@@ -320,6 +332,8 @@ impl Config {
                 ("RPATH", self.rust_rpath),
                 ("OPTIMIZE_TESTS", self.rust_optimize_tests),
                 ("DEBUGINFO_TESTS", self.rust_debuginfo_tests),
+                ("PROFILER", self.profiler),
+                ("SANITIZERS", self.sanitizers),
                 ("LOCAL_REBUILD", self.local_rebuild),
                 ("NINJA", self.ninja),
             }
@@ -132,6 +132,8 @@ pub struct Build {
     cc: HashMap<String, (gcc::Tool, Option<PathBuf>)>,
     cxx: HashMap<String, gcc::Tool>,
     compiler_rt_built: RefCell<HashMap<String, PathBuf>>,
+    profiler_rt_built: RefCell<HashMap<String, PathBuf>>,
+    sanitizers_built: RefCell<HashMap<String, Vec<(&'static str, PathBuf)>>>,
 }
 
 /// The various "modes" of invoking Cargo.
@@ -199,6 +201,8 @@ impl Build {
             cc: HashMap::new(),
             cxx: HashMap::new(),
             compiler_rt_built: RefCell::new(HashMap::new()),
+            profiler_rt_built: RefCell::new(HashMap::new()),
+            sanitizers_built: RefCell::new(HashMap::new()),
             gdb_version: None,
             lldb_version: None,
             lldb_python_dir: None,
@@ -254,6 +258,8 @@ impl Build {
                 }
                 CompilerRt { _dummy } => {
                     native::compiler_rt(self, target.target);
+                    native::profiler_rt(self, target.target);
+                    native::sanitizers(self, target.target);
                 }
                 TestHelpers { _dummy } => {
                     native::test_helpers(self, target.target);
@@ -574,6 +580,13 @@ impl Build {
              .env("RUSTDOC_REAL", self.rustdoc(compiler))
              .env("RUSTC_FLAGS", self.rustc_flags(target).join(" "));
 
+        // Whether the C runtime is linked statically, if configured. This is
+        // read both by our rustc shim and by build scripts, which don't get to
+        // see `cfg(target_feature = "crt-static")`.
+        if let Some(crt_static) = self.crt_static(target) {
+            cargo.env("RUSTC_CRT_STATIC", crt_static.to_string());
+        }
+
         self.add_bootstrap_key(compiler, &mut cargo);
 
         // Specify some various options for build scripts used throughout
@@ -651,7 +664,9 @@ impl Build {
         if self.config.debug_jemalloc {
             features.push_str(" debug-jemalloc");
         }
-        if self.config.use_jemalloc {
+        // The sanitizers intercept the system allocator to track allocations,
+        // which jemalloc would bypass.
+        if self.config.use_jemalloc && self.config.sanitize_std.is_none() {
             features.push_str(" jemalloc");
         }
         if self.config.backtrace {
@@ -739,6 +754,12 @@ impl Build {
         }
     }
 
+    /// Returns whether `crt-static` was configured for the specified target,
+    /// or `None` to use the target's default.
+    fn crt_static(&self, target: &str) -> Option<bool> {
+        self.config.target_config.get(target).and_then(|c| c.crt_static)
+    }
+
     /// Returns the path to `llvm-config` for the specified target.
     ///
     /// If a custom `llvm-config` was specified for target then that's returned
@@ -867,6 +888,12 @@ impl Build {
             base.push("-stdlib=libc++".into());
             base.push("-mmacosx-version-min=10.7".into());
         }
+        // C code has to be compiled for the same C runtime the Rust code is
+        // linked with, which on MSVC is the dynamic one unless configured
+        // otherwise.
+        if target.contains("msvc") && self.crt_static(target) == Some(true) {
+            base.push("/MT".into());
+        }
         // This is a hack, because newer binutils broke things on some vms/distros
         // (i.e., linking against unknown relocs disabled by the following flag)
         // See: https://github.com/rust-lang/rust/issues/34978
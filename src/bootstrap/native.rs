@@ -66,6 +66,7 @@ pub fn llvm(build: &Build, target: &str) {
        .profile(if build.config.llvm_optimize {"Release"} else {"Debug"})
        .define("LLVM_ENABLE_ASSERTIONS", assertions)
        .define("LLVM_TARGETS_TO_BUILD", "X86;ARM;AArch64;Mips;PowerPC")
+       .define("LLVM_EXPERIMENTAL_TARGETS_TO_BUILD", "WebAssembly")
        .define("LLVM_INCLUDE_EXAMPLES", "OFF")
        .define("LLVM_INCLUDE_TESTS", "OFF")
        .define("LLVM_INCLUDE_DOCS", "OFF")
@@ -526,6 +527,125 @@ pub fn compiler_rt(build: &Build, target: &str) {
     cfg.compile("libcompiler-rt.a");
 }
 
+/// Compiles the profiling runtime of compiler-rt, which programs compiled
+/// with `-C profile-generate` are linked against to write out their profile.
+///
+/// As with the builtins above we skip compiler-rt's own build system. The
+/// runtime is only built when the `profiler` option is set, as it's only of
+/// use for profile-guided optimization and, unlike the builtins, depends on
+/// the target's libc.
+pub fn profiler_rt(build: &Build, target: &str) {
+    if !build.config.profiler || target.contains("msvc") {
+        return
+    }
+
+    let build_dir = build.compiler_rt_out(target);
+    let output = build_dir.join(staticlib("profiler-rt", target));
+    build.profiler_rt_built.borrow_mut().insert(target.to_string(),
+                                                output.clone());
+    t!(fs::create_dir_all(&build_dir));
+
+    let mut cfg = gcc::Config::new();
+    cfg.cargo_metadata(false)
+       .out_dir(&build_dir)
+       .target(target)
+       .host(&build.config.build)
+       .opt_level(2)
+       .debug(false)
+       .flag("-fno-builtin")
+       .flag("-fvisibility=hidden")
+       .flag("-fomit-frame-pointer")
+       .define("VISIBILITY_HIDDEN", None)
+       .define("COMPILER_RT_HAS_UNAME", Some("1"));
+
+    let sources = [
+        "GCDAProfiling.c",
+        "InstrProfiling.c",
+        "InstrProfilingBuffer.c",
+        "InstrProfilingFile.c",
+        "InstrProfilingMerge.c",
+        "InstrProfilingMergeFile.c",
+        "InstrProfilingPlatformDarwin.c",
+        "InstrProfilingPlatformLinux.c",
+        "InstrProfilingPlatformOther.c",
+        "InstrProfilingRuntime.cc",
+        "InstrProfilingUtil.c",
+        "InstrProfilingValue.c",
+        "InstrProfilingWriter.c",
+    ];
+
+    let mut out_of_date = false;
+    for src in sources.iter() {
+        let src = build.src.join("src/compiler-rt/lib/profile").join(src);
+        out_of_date = out_of_date || !up_to_date(&src, &output);
+        cfg.file(src);
+    }
+    if !out_of_date {
+        return
+    }
+    cfg.compile("libprofiler-rt.a");
+}
+
+/// Compiles the sanitizer runtimes of compiler-rt, which programs compiled
+/// with `-Z sanitizer` are linked against.
+///
+/// Unlike the other runtimes these are far too involved to compile by hand,
+/// so this runs compiler-rt's own cmake build against our LLVM. The
+/// resulting archives are renamed to `librustc_{asan,lsan,msan,tsan}.a`,
+/// which is what the compiler looks for.
+pub fn sanitizers(build: &Build, target: &str) {
+    if !build.config.sanitizers || target != "x86_64-unknown-linux-gnu" {
+        return
+    }
+
+    let dst = build.compiler_rt_out(target).join("sanitizers");
+    let runtimes = ["asan", "lsan", "msan", "tsan"].iter().map(|name| {
+        let lib = format!("lib/linux/libclang_rt.{}-x86_64.a", name);
+        (*name, dst.join(lib))
+    }).collect::<Vec<_>>();
+    build.sanitizers_built.borrow_mut().insert(target.to_string(),
+                                               runtimes.clone());
+
+    let stamp = build.src.join("src/rustllvm/llvm-auto-clean-trigger");
+    let done_stamp = dst.join("sanitizers-finished-building");
+    build.clear_if_dirty(&dst, &stamp);
+    if fs::metadata(&done_stamp).is_ok() {
+        return
+    }
+
+    println!("Building sanitizers for {}", target);
+
+    let _ = fs::remove_dir_all(&dst.join("build"));
+    t!(fs::create_dir_all(&dst.join("build")));
+
+    let mut cfg = cmake::Config::new(build.src.join("src/compiler-rt"));
+    if build.config.ninja {
+        cfg.generator("Ninja");
+    }
+    cfg.target(target)
+       .host(&build.config.build)
+       .out_dir(&dst)
+       .profile("Release")
+       .define("LLVM_CONFIG_PATH", build.llvm_config(&build.config.build))
+       .define("COMPILER_RT_DEFAULT_TARGET_TRIPLE", target)
+       .define("COMPILER_RT_BUILD_SANITIZERS", "ON")
+       .define("COMPILER_RT_BUILD_BUILTINS", "OFF")
+       .define("COMPILER_RT_BUILD_XRAY", "OFF")
+       .define("COMPILER_RT_INCLUDE_TESTS", "OFF")
+       .define("CMAKE_C_COMPILER", build.cc(target))
+       .define("CMAKE_CXX_COMPILER", build.cxx(target))
+       .build_arg("-j").build_arg(build.jobs().to_string());
+    cfg.build();
+
+    for &(_, ref runtime) in runtimes.iter() {
+        if !runtime.exists() {
+            panic!("compiler-rt didn't produce {}", runtime.display());
+        }
+    }
+
+    t!(File::create(&done_stamp));
+}
+
 /// Compiles the `rust_test_helpers.c` library which we used in various
 /// `run-pass` test suites for ABI testing.
 pub fn test_helpers(build: &Build, target: &str) {
@@ -87,6 +87,21 @@ pub fn check(build: &mut Build) {
         need_cmd(build.cxx(host).as_ref());
     }
 
+    // The sanitizer runtimes are built with compiler-rt's own cmake build,
+    // and std can only be instrumented if they're there to link against.
+    if build.config.sanitizers {
+        need_cmd("cmake".as_ref());
+    }
+    if let Some(ref sanitizer) = build.config.sanitize_std {
+        if sanitizer != "address" && sanitizer != "thread" {
+            panic!("the rust.sanitize-std option must be \"address\" or \"thread\", \
+                    not {:?}", sanitizer);
+        }
+        if !build.config.sanitizers {
+            panic!("the rust.sanitize-std option requires build.sanitizers");
+        }
+    }
+
     // Externally configured LLVM requires FileCheck to exist
     let filecheck = build.llvm_filecheck(&build.config.build);
     if !filecheck.starts_with(&build.out) && !filecheck.exists() {
@@ -99,7 +114,8 @@ pub fn check(build: &mut Build) {
            target.contains("bitrig") ||
            target.contains("openbsd") ||
            target.contains("msvc") ||
-           target.contains("emscripten") {
+           target.contains("emscripten") ||
+           target.contains("wasm32") {
             build.config.use_jemalloc = false;
         }
 
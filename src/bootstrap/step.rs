@@ -349,7 +349,15 @@ impl<'a> Step<'a> {
                 vec![self.libstd(compiler),
                      self.target(host).rustc(compiler.stage)]
             }
-            Source::CompilerRt { _dummy } => Vec::new(),
+            Source::CompilerRt { _dummy } => {
+                // The sanitizer runtimes are built against the headers and
+                // `llvm-config` of our LLVM.
+                if build.config.sanitizers {
+                    vec![self.llvm(())]
+                } else {
+                    Vec::new()
+                }
+            }
             Source::Llvm { _dummy } => Vec::new(),
             Source::TestHelpers { _dummy } => Vec::new(),
             Source::DebuggerScripts { stage: _ } => Vec::new(),
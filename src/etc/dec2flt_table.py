@@ -22,6 +22,11 @@ normalized and rounded perfectly, i.e., within 0.5 ULP of the true value.
 The representation ([u64], [i16]) instead of the more natural [(u64, i16)]
 is used because (u64, i16) has a ton of padding which would make the table
 even larger, and it's already uncomfortably large (6 KiB).
+
+Additionally, outputs a table of 128 bit truncated approximations of 5^q used
+by the Eisel-Lemire fast path. Unlike the first table, these values are not
+rounded: for q >= 0 they are truncated to the leading 128 bits, and for q < 0
+they are 2^b / 5^-q rounded up, then truncated to 128 bits.
 """
 from __future__ import print_function
 import sys
@@ -103,7 +108,7 @@ HEADER = """
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//! Tables of approximations of powers of ten.
+//! Tables of approximations of powers of ten and five.
 //! DO NOT MODIFY: Generated by `src/etc/dec2flt_table.py`
 """
 
@@ -116,6 +121,8 @@ def main():
     print_short_powers(32, 24)
     print()
     print_short_powers(64, 53)
+    print()
+    print_powers_of_five_128()
 
 
 def print_proper_powers():
@@ -153,5 +160,36 @@ def print_short_powers(num_bits, significand_size):
     print("];")
 
 
+def print_powers_of_five_128():
+    SMALLEST_POWER_OF_FIVE = -342
+    LARGEST_POWER_OF_FIVE = 308
+    powers = []
+    for q in range(SMALLEST_POWER_OF_FIVE, 0):
+        power5 = 5 ** -q
+        z = power5.bit_length()
+        if q >= -27:
+            c = 2 ** (z + 127) // power5 + 1
+        else:
+            c = 2 ** (2 * z + 2 * 64) // power5 + 1
+            while c >= 2 ** 128:
+                c //= 2
+        powers.append(c)
+    for q in range(0, LARGEST_POWER_OF_FIVE + 1):
+        power5 = 5 ** q
+        while power5 < 2 ** 127:
+            power5 *= 2
+        while power5 >= 2 ** 128:
+            power5 //= 2
+        powers.append(power5)
+    print("pub const SMALLEST_POWER_OF_FIVE: i16 = {};".format(SMALLEST_POWER_OF_FIVE))
+    print("pub const LARGEST_POWER_OF_FIVE: i16 = {};".format(LARGEST_POWER_OF_FIVE))
+    print()
+    typ = "[(u64, u64); {}]".format(len(powers))
+    print("pub const POWERS_OF_FIVE_128: ", typ, " = [", sep='')
+    for q, c in zip(range(SMALLEST_POWER_OF_FIVE, LARGEST_POWER_OF_FIVE + 1), powers):
+        print("    (0x{:016x}, 0x{:016x}), // 5^{}".format(c >> 64, c & (2 ** 64 - 1), q))
+    print("];")
+
+
 if __name__ == '__main__':
     main()
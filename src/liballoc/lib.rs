@@ -88,7 +88,6 @@
 #![feature(staged_api)]
 #![feature(unboxed_closures)]
 #![feature(unique)]
-#![feature(unsafe_no_drop_flag, filling_drop)]
 #![feature(unsize)]
 
 #![cfg_attr(not(test), feature(raw, fn_traits, placement_new_protocol))]
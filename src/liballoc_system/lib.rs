@@ -20,6 +20,7 @@
 #![feature(allocator)]
 #![feature(staged_api)]
 #![cfg_attr(unix, feature(libc))]
+#![cfg_attr(target_arch = "wasm32", feature(link_llvm_intrinsics))]
 
 // The minimum alignment guaranteed by the architecture. This value is used to
 // add fast paths for low alignment values. In practice, the alignment is a
@@ -29,7 +30,8 @@
               target_arch = "mips",
               target_arch = "powerpc",
               target_arch = "powerpc64",
-              target_arch = "asmjs")))]
+              target_arch = "asmjs",
+              target_arch = "wasm32")))]
 const MIN_ALIGN: usize = 8;
 #[cfg(all(any(target_arch = "x86_64",
               target_arch = "aarch64")))]
@@ -243,3 +245,89 @@ mod imp {
         size
     }
 }
+
+// There's no libc in a wasm module, so memory is handed out by bumping a
+// pointer through pages requested with `grow_memory`. Only the most recent
+// allocation can be freed or resized in place, everything else is leaked.
+#[cfg(target_arch = "wasm32")]
+mod imp {
+    use core::cmp;
+    use core::ptr;
+    use MIN_ALIGN;
+
+    const PAGE_SIZE: usize = 64 * 1024;
+
+    extern {
+        #[link_name = "llvm.wasm.grow.memory.i32"]
+        fn grow_memory(pages: u32) -> i32;
+    }
+
+    // The module is single threaded, so nothing else touches these.
+    static mut NEXT: usize = 0;
+    static mut END: usize = 0;
+
+    fn align_to(n: usize, align: usize) -> usize {
+        (n + align - 1) & !(align - 1)
+    }
+
+    pub unsafe fn allocate(size: usize, align: usize) -> *mut u8 {
+        let align = cmp::max(align, MIN_ALIGN);
+        let mut start = align_to(NEXT, align);
+        if END == 0 || start.checked_add(size).map_or(true, |end| end > END) {
+            let pages = match size.checked_add(align) {
+                Some(n) => align_to(n, PAGE_SIZE) / PAGE_SIZE,
+                None => return ptr::null_mut(),
+            };
+            let prev = grow_memory(pages as u32);
+            if prev < 0 {
+                return ptr::null_mut()
+            }
+            // The new pages only extend the current chunk if nothing else
+            // grew the memory in the meantime.
+            let base = prev as usize * PAGE_SIZE;
+            if base != END {
+                NEXT = base;
+            }
+            END = base + pages * PAGE_SIZE;
+            start = align_to(NEXT, align);
+        }
+        NEXT = start + size;
+        start as *mut u8
+    }
+
+    pub unsafe fn reallocate(ptr: *mut u8, old_size: usize, size: usize, align: usize) -> *mut u8 {
+        if reallocate_inplace(ptr, old_size, size, align) >= size {
+            return ptr
+        }
+        let new_ptr = allocate(size, align);
+        if !new_ptr.is_null() {
+            ptr::copy(ptr, new_ptr, cmp::min(size, old_size));
+            deallocate(ptr, old_size, align);
+        }
+        new_ptr
+    }
+
+    pub unsafe fn reallocate_inplace(ptr: *mut u8,
+                                     old_size: usize,
+                                     size: usize,
+                                     _align: usize)
+                                     -> usize {
+        let start = ptr as usize;
+        if start + old_size == NEXT && start + size <= END {
+            NEXT = start + size;
+            size
+        } else {
+            old_size
+        }
+    }
+
+    pub unsafe fn deallocate(ptr: *mut u8, old_size: usize, _align: usize) {
+        if ptr as usize + old_size == NEXT {
+            NEXT = ptr as usize;
+        }
+    }
+
+    pub fn usable_size(size: usize, _align: usize) -> usize {
+        size
+    }
+}
@@ -87,6 +87,7 @@ struct LeafNode<K, V> {
 impl<K, V> LeafNode<K, V> {
     /// Creates a new `LeafNode`. Unsafe because all nodes should really be hidden behind
     /// `BoxedNode`, preventing accidental dropping of uninitialized keys and values.
+    #[allow(deprecated)]
     unsafe fn new() -> Self {
         LeafNode {
             // As a general policy, we leave fields uninitialized if they can be, as this should
@@ -122,6 +123,7 @@ impl<K, V> InternalNode<K, V> {
     /// edges are initialized and valid, meaning that even when the node is empty (having a
     /// `len` of 0), there must be one initialized and valid edge. This function does not set up
     /// such an edge.
+    #[allow(deprecated)]
     unsafe fn new() -> Self {
         InternalNode {
             data: LeafNode::new(),
@@ -36,6 +36,7 @@
 #![cfg_attr(not(test), feature(char_escape_debug))]
 #![feature(core_intrinsics)]
 #![feature(dropck_parametricity)]
+#![feature(fixed_size_array)]
 #![feature(fmt_internals)]
 #![feature(heap_api)]
 #![feature(inclusive_range)]
@@ -48,10 +49,10 @@
 #![feature(slice_patterns)]
 #![feature(specialization)]
 #![feature(staged_api)]
-#![feature(step_by)]
+#![feature(iterator_step_by)]
+#![feature(str_checked_slicing)]
 #![feature(unicode)]
 #![feature(unique)]
-#![feature(unsafe_no_drop_flag)]
 #![cfg_attr(test, feature(rand, test))]
 
 #![no_std]
@@ -160,6 +160,138 @@ impl<T> LinkedList<T> {
             node
         })
     }
+
+    /// Unlinks the specified node from the current list.
+    ///
+    /// Warning: this will not check that the provided node belongs to the current list.
+    #[inline]
+    unsafe fn unlink_node(&mut self, node: Shared<Node<T>>) {
+        let node = &mut **node;
+
+        match node.prev {
+            Some(prev) => (**prev).next = node.next,
+            // this node is the head node
+            None => self.head = node.next,
+        };
+
+        match node.next {
+            Some(next) => (**next).prev = node.prev,
+            // this node is the tail node
+            None => self.tail = node.prev,
+        };
+
+        self.len -= 1;
+    }
+
+    /// Splices a series of nodes between two existing nodes.
+    ///
+    /// Warning: this will not check that the provided node belongs to the two existing lists.
+    #[inline]
+    unsafe fn splice_nodes(&mut self,
+                           existing_prev: Option<Shared<Node<T>>>,
+                           existing_next: Option<Shared<Node<T>>>,
+                           splice_start: Shared<Node<T>>,
+                           splice_end: Shared<Node<T>>,
+                           splice_length: usize) {
+        match existing_prev {
+            Some(prev) => (**prev).next = Some(splice_start),
+            None => self.head = Some(splice_start),
+        }
+        match existing_next {
+            Some(next) => (**next).prev = Some(splice_end),
+            None => self.tail = Some(splice_end),
+        }
+        (**splice_start).prev = existing_prev;
+        (**splice_end).next = existing_next;
+
+        self.len += splice_length;
+    }
+
+    /// Detaches all nodes from a linked list as a series of nodes.
+    #[inline]
+    fn detach_all_nodes(mut self) -> Option<(Shared<Node<T>>, Shared<Node<T>>, usize)> {
+        let head = self.head.take();
+        let tail = self.tail.take();
+        let len = mem::replace(&mut self.len, 0);
+        match (head, tail) {
+            (Some(head), Some(tail)) => Some((head, tail, len)),
+            _ => None,
+        }
+    }
+
+    /// Splits the list after `split_node`, which must be the node at index
+    /// `at - 1`. A `split_node` of `None` moves the whole list out.
+    #[inline]
+    unsafe fn split_off_after_node(&mut self,
+                                   split_node: Option<Shared<Node<T>>>,
+                                   at: usize)
+                                   -> LinkedList<T> {
+        // The split node is the new tail node of the first part and owns
+        // the head of the second part.
+        match split_node {
+            Some(split_node) => {
+                let second_part_head = (**split_node).next.take();
+                let second_part_tail = match second_part_head {
+                    Some(head) => {
+                        (**head).prev = None;
+                        self.tail
+                    }
+                    None => None,
+                };
+
+                let second_part = LinkedList {
+                    head: second_part_head,
+                    tail: second_part_tail,
+                    len: self.len - at,
+                    marker: PhantomData,
+                };
+
+                // Fix the tail ptr of the first part
+                self.tail = Some(split_node);
+                self.len = at;
+
+                second_part
+            }
+            None => mem::replace(self, LinkedList::new()),
+        }
+    }
+
+    /// Splits the list before `split_node`, which must be the node at index
+    /// `at`. A `split_node` of `None` moves the whole list out.
+    #[inline]
+    unsafe fn split_off_before_node(&mut self,
+                                    split_node: Option<Shared<Node<T>>>,
+                                    at: usize)
+                                    -> LinkedList<T> {
+        // The split node is the new head node of the second part and owns
+        // the tail of the first part.
+        match split_node {
+            Some(split_node) => {
+                let first_part_tail = (**split_node).prev.take();
+                let first_part_head = match first_part_tail {
+                    Some(tail) => {
+                        (**tail).next = None;
+                        self.head
+                    }
+                    None => None,
+                };
+
+                let first_part = LinkedList {
+                    head: first_part_head,
+                    tail: first_part_tail,
+                    len: at,
+                    marker: PhantomData,
+                };
+
+                // Fix the head ptr of the second part
+                self.head = Some(split_node);
+                self.len = self.len - at;
+
+                first_part
+            }
+            None => mem::replace(self, LinkedList::new()),
+        }
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -703,6 +835,28 @@ impl<T> LinkedList<T> {
     pub fn back_place(&mut self) -> BackPlace<T> {
         BackPlace { list: self, node: IntermediateBox::make_place() }
     }
+
+    /// Provides a cursor with editing operations at the front element.
+    ///
+    /// The cursor is pointing to the "ghost" non-element if the list is empty.
+    #[inline]
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn cursor_front_mut(&mut self) -> CursorMut<T> {
+        CursorMut { index: 0, current: self.head, list: self }
+    }
+
+    /// Provides a cursor with editing operations at the back element.
+    ///
+    /// The cursor is pointing to the "ghost" non-element if the list is empty.
+    #[inline]
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn cursor_back_mut(&mut self) -> CursorMut<T> {
+        CursorMut {
+            index: self.len.checked_sub(1).unwrap_or(0),
+            current: self.tail,
+            list: self,
+        }
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -1133,6 +1287,249 @@ fn assert_covariance() {
     fn c<'a>(x: IntoIter<&'static str>) -> IntoIter<&'a str> { x }
 }
 
+/// A cursor over a `LinkedList` with editing operations.
+///
+/// A `Cursor` is like an iterator, except that it can freely seek back-and-forth, and can
+/// safely mutate the list during iteration. This is because the lifetime of its yielded
+/// references is tied to its own lifetime, instead of just the underlying list. This means
+/// cursors cannot yield multiple elements at once.
+///
+/// Cursors always rest between two elements in the list, and index in a logically circular way.
+/// To accommodate this, there is a "ghost" non-element that yields `None` between the head and
+/// tail of the list.
+#[unstable(feature = "linked_list_cursors", issue = "0")]
+pub struct CursorMut<'a, T: 'a> {
+    index: usize,
+    current: Option<Shared<Node<T>>>,
+    list: &'a mut LinkedList<T>,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// Returns the cursor position index within the `LinkedList`.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn index(&self) -> Option<usize> {
+        match self.current {
+            Some(_) => Some(self.index),
+            None => None,
+        }
+    }
+
+    /// Moves the cursor to the next element of the `LinkedList`.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will move it to
+    /// the first element of the `LinkedList`. If it is pointing to the last
+    /// element of the `LinkedList` then this will move it to the "ghost" non-element.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn move_next(&mut self) {
+        match self.current.take() {
+            // We had no current element; the cursor was sitting at the start position
+            // Next element should be the head of the list
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+            // We had a previous element, so let's go to its next
+            Some(current) => unsafe {
+                self.current = (**current).next;
+                self.index += 1;
+            }
+        }
+    }
+
+    /// Moves the cursor to the previous element of the `LinkedList`.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will move it to
+    /// the last element of the `LinkedList`. If it is pointing to the first
+    /// element of the `LinkedList` then this will move it to the "ghost" non-element.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn move_prev(&mut self) {
+        match self.current.take() {
+            // No current. We're at the start of the list. Yield None and jump to the end.
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len().checked_sub(1).unwrap_or(0);
+            }
+            // Have a prev. Yield it and go to the previous element.
+            Some(current) => unsafe {
+                let len = self.list.len();
+                self.current = (**current).prev;
+                self.index = self.index.checked_sub(1).unwrap_or(len);
+            }
+        }
+    }
+
+    /// Returns a reference to the element that the cursor is currently
+    /// pointing to.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current.map(|current| unsafe { &mut (**current).element })
+    }
+
+    /// Returns a reference to the next element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the first element of the `LinkedList`. If it is pointing to the last
+    /// element of the `LinkedList` then this returns `None`.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = match self.current {
+            None => self.list.head,
+            Some(current) => unsafe { (**current).next },
+        };
+        next.map(|next| unsafe { &mut (**next).element })
+    }
+
+    /// Returns a reference to the previous element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the last element of the `LinkedList`. If it is pointing to the first
+    /// element of the `LinkedList` then this returns `None`.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let prev = match self.current {
+            None => self.list.tail,
+            Some(current) => unsafe { (**current).prev },
+        };
+        prev.map(|prev| unsafe { &mut (**prev).element })
+    }
+
+    /// Inserts a new element into the `LinkedList` after the current one.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the new element is
+    /// inserted at the front of the `LinkedList`.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn insert_after(&mut self, item: T) {
+        unsafe {
+            let spliced_node = Shared::new(Box::into_raw(box Node::new(item)));
+            let node_next = match self.current {
+                None => self.list.head,
+                Some(node) => (**node).next,
+            };
+            self.list.splice_nodes(self.current, node_next, spliced_node, spliced_node, 1);
+            if self.current.is_none() {
+                // The "ghost" non-element's index has changed.
+                self.index = self.list.len;
+            }
+        }
+    }
+
+    /// Inserts a new element into the `LinkedList` before the current one.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the new element is
+    /// inserted at the end of the `LinkedList`.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn insert_before(&mut self, item: T) {
+        unsafe {
+            let spliced_node = Shared::new(Box::into_raw(box Node::new(item)));
+            let node_prev = match self.current {
+                None => self.list.tail,
+                Some(node) => (**node).prev,
+            };
+            self.list.splice_nodes(node_prev, self.current, spliced_node, spliced_node, 1);
+            self.index += 1;
+        }
+    }
+
+    /// Removes the current element from the `LinkedList`.
+    ///
+    /// The element that was removed is returned, and the cursor is
+    /// moved to point to the next element in the `LinkedList`.
+    ///
+    /// If the cursor is currently pointing to the "ghost" non-element then no element
+    /// is removed and `None` is returned.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn remove_current(&mut self) -> Option<T> {
+        let unlinked_node = match self.current {
+            Some(node) => node,
+            None => return None,
+        };
+        unsafe {
+            self.current = (**unlinked_node).next;
+            self.list.unlink_node(unlinked_node);
+            let unlinked_node = Box::from_raw(*unlinked_node);
+            Some(unlinked_node.into_element())
+        }
+    }
+
+    /// Inserts the elements from the given `LinkedList` after the current one.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the new elements are
+    /// inserted at the start of the `LinkedList`.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn splice_after(&mut self, list: LinkedList<T>) {
+        unsafe {
+            let (splice_head, splice_tail, splice_len) = match list.detach_all_nodes() {
+                Some(parts) => parts,
+                _ => return,
+            };
+            let node_next = match self.current {
+                None => self.list.head,
+                Some(node) => (**node).next,
+            };
+            self.list.splice_nodes(self.current, node_next, splice_head, splice_tail, splice_len);
+            if self.current.is_none() {
+                // The "ghost" non-element's index has changed.
+                self.index = self.list.len;
+            }
+        }
+    }
+
+    /// Inserts the elements from the given `LinkedList` before the current one.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the new elements are
+    /// inserted at the end of the `LinkedList`.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn splice_before(&mut self, list: LinkedList<T>) {
+        unsafe {
+            let (splice_head, splice_tail, splice_len) = match list.detach_all_nodes() {
+                Some(parts) => parts,
+                _ => return,
+            };
+            let node_prev = match self.current {
+                None => self.list.tail,
+                Some(node) => (**node).prev,
+            };
+            self.list.splice_nodes(node_prev, self.current, splice_head, splice_tail, splice_len);
+            self.index += splice_len;
+        }
+    }
+
+    /// Splits the list into two after the current element. This will return a
+    /// new list consisting of everything after the cursor, with the original
+    /// list retaining everything before.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the entire contents
+    /// of the `LinkedList` are moved.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn split_after(&mut self) -> LinkedList<T> {
+        let split_off_idx = if self.index == self.list.len { 0 } else { self.index + 1 };
+        if self.index == self.list.len {
+            // The "ghost" non-element's index has changed to 0.
+            self.index = 0;
+        }
+        unsafe { self.list.split_off_after_node(self.current, split_off_idx) }
+    }
+
+    /// Splits the list into two before the current element. This will return a
+    /// new list consisting of everything before the cursor, with the original
+    /// list retaining everything after.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the entire contents
+    /// of the `LinkedList` are moved.
+    #[unstable(feature = "linked_list_cursors", issue = "0")]
+    pub fn split_before(&mut self) -> LinkedList<T> {
+        let split_off_idx = self.index;
+        self.index = 0;
+        unsafe { self.list.split_off_before_node(self.current, split_off_idx) }
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 unsafe impl<T: Send> Send for LinkedList<T> {}
 
@@ -1151,6 +1548,12 @@ unsafe impl<'a, T: Send> Send for IterMut<'a, T> {}
 #[stable(feature = "rust1", since = "1.0.0")]
 unsafe impl<'a, T: Sync> Sync for IterMut<'a, T> {}
 
+#[unstable(feature = "linked_list_cursors", issue = "0")]
+unsafe impl<'a, T: Send> Send for CursorMut<'a, T> {}
+
+#[unstable(feature = "linked_list_cursors", issue = "0")]
+unsafe impl<'a, T: Sync> Sync for CursorMut<'a, T> {}
+
 #[cfg(test)]
 mod tests {
     use std::clone::Clone;
@@ -1289,6 +1692,138 @@ mod tests {
                    [-2, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1]);
     }
 
+    #[test]
+    fn test_cursor_move_peek() {
+        let mut m: LinkedList<u32> = LinkedList::new();
+        m.extend(&[1, 2, 3, 4, 5, 6]);
+        let mut cursor = m.cursor_front_mut();
+        assert_eq!(cursor.current(), Some(&mut 1));
+        assert_eq!(cursor.peek_next(), Some(&mut 2));
+        assert_eq!(cursor.peek_prev(), None);
+        assert_eq!(cursor.index(), Some(0));
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), Some(&mut 1));
+        assert_eq!(cursor.peek_prev(), Some(&mut 6));
+        assert_eq!(cursor.index(), None);
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert_eq!(cursor.peek_next(), Some(&mut 3));
+        assert_eq!(cursor.peek_prev(), Some(&mut 1));
+        assert_eq!(cursor.index(), Some(1));
+
+        let mut m: LinkedList<u32> = LinkedList::new();
+        m.extend(&[1, 2, 3, 4, 5, 6]);
+        let mut cursor = m.cursor_back_mut();
+        assert_eq!(cursor.current(), Some(&mut 6));
+        assert_eq!(cursor.peek_next(), None);
+        assert_eq!(cursor.peek_prev(), Some(&mut 5));
+        assert_eq!(cursor.index(), Some(5));
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), Some(&mut 1));
+        assert_eq!(cursor.peek_prev(), Some(&mut 6));
+        assert_eq!(cursor.index(), None);
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&mut 5));
+        assert_eq!(cursor.peek_next(), Some(&mut 6));
+        assert_eq!(cursor.peek_prev(), Some(&mut 4));
+        assert_eq!(cursor.index(), Some(4));
+
+        let mut m: LinkedList<u32> = LinkedList::new();
+        let mut cursor = m.cursor_front_mut();
+        assert_eq!(cursor.current(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+    }
+
+    #[test]
+    fn test_cursor_mut_insert() {
+        let mut m: LinkedList<u32> = LinkedList::new();
+        m.extend(&[1, 2, 3, 4, 5, 6]);
+        {
+            let mut cursor = m.cursor_front_mut();
+            cursor.insert_before(7);
+            cursor.insert_after(8);
+        }
+        check_links(&m);
+        assert_eq!(m.iter().cloned().collect::<Vec<_>>(), &[7, 1, 8, 2, 3, 4, 5, 6]);
+        {
+            let mut cursor = m.cursor_front_mut();
+            cursor.move_prev();
+            cursor.insert_before(9);
+            cursor.insert_after(10);
+        }
+        check_links(&m);
+        assert_eq!(m.iter().cloned().collect::<Vec<_>>(), &[10, 7, 1, 8, 2, 3, 4, 5, 6, 9]);
+        {
+            let mut cursor = m.cursor_front_mut();
+            cursor.move_prev();
+            assert_eq!(cursor.remove_current(), None);
+            cursor.move_next();
+            cursor.move_next();
+            assert_eq!(cursor.remove_current(), Some(7));
+            cursor.move_prev();
+            cursor.move_prev();
+            cursor.move_prev();
+            assert_eq!(cursor.remove_current(), Some(9));
+            cursor.move_next();
+            assert_eq!(cursor.remove_current(), Some(10));
+        }
+        check_links(&m);
+        assert_eq!(m.iter().cloned().collect::<Vec<_>>(), &[1, 8, 2, 3, 4, 5, 6]);
+        {
+            let mut cursor = m.cursor_front_mut();
+            let mut p: LinkedList<u32> = LinkedList::new();
+            p.extend(&[100, 101, 102, 103]);
+            let mut q: LinkedList<u32> = LinkedList::new();
+            q.extend(&[200, 201, 202, 203]);
+            cursor.splice_after(p);
+            cursor.splice_before(q);
+        }
+        check_links(&m);
+        assert_eq!(m.iter().cloned().collect::<Vec<_>>(),
+                   &[200, 201, 202, 203, 1, 100, 101, 102, 103, 8, 2, 3, 4, 5, 6]);
+        let tmp;
+        {
+            let mut cursor = m.cursor_front_mut();
+            cursor.move_prev();
+            tmp = cursor.split_before();
+        }
+        assert_eq!(m.iter().cloned().collect::<Vec<_>>(), &[]);
+        m = tmp;
+        let tmp;
+        {
+            let mut cursor = m.cursor_front_mut();
+            cursor.move_next();
+            cursor.move_next();
+            cursor.move_next();
+            cursor.move_next();
+            cursor.move_next();
+            cursor.move_next();
+            tmp = cursor.split_after();
+        }
+        check_links(&m);
+        check_links(&tmp);
+        assert_eq!(tmp.into_iter().collect::<Vec<_>>(), &[102, 103, 8, 2, 3, 4, 5, 6]);
+        assert_eq!(m.iter().cloned().collect::<Vec<_>>(), &[200, 201, 202, 203, 1, 100, 101]);
+        let tmp;
+        {
+            let mut cursor = m.cursor_front_mut();
+            cursor.move_next();
+            cursor.move_next();
+            tmp = cursor.split_before();
+        }
+        check_links(&m);
+        check_links(&tmp);
+        assert_eq!(tmp.into_iter().collect::<Vec<_>>(), &[200, 201]);
+        assert_eq!(m.into_iter().collect::<Vec<_>>(), &[202, 203, 1, 100, 101]);
+    }
+
     #[test]
     fn test_send() {
         let n = list_from(&[1, 2, 3]);
@@ -86,6 +86,7 @@
 #![cfg_attr(test, allow(unused_imports, dead_code))]
 
 use alloc::boxed::Box;
+use core::array::FixedSizeArray;
 use core::cmp::Ordering::{self, Greater, Less};
 use core::cmp;
 use core::mem::size_of;
@@ -106,6 +107,10 @@ pub use core::slice::{SplitMut, ChunksMut, Split};
 pub use core::slice::{SplitN, RSplitN, SplitNMut, RSplitNMut};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::slice::{from_raw_parts, from_raw_parts_mut};
+#[unstable(feature = "chunks_exact", issue = "0")]
+pub use core::slice::{ChunksExact, ChunksExactMut};
+#[unstable(feature = "array_chunks", issue = "0")]
+pub use core::slice::ArrayChunks;
 
 ////////////////////////////////////////////////////////////////////////////////
 // Basic slice extension methods
@@ -494,6 +499,68 @@ impl<T> [T] {
         core_slice::SliceExt::reverse(self)
     }
 
+    /// Rotates the slice in place such that the first `mid` elements of the
+    /// slice move to the end while the last `self.len() - mid` elements move
+    /// to the front. After calling `rotate_left`, the element previously at
+    /// index `mid` will become the first element in the slice.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `mid` is greater than the length of the
+    /// slice. Note that `mid == self.len()` does _not_ panic and is a no-op
+    /// rotation.
+    ///
+    /// # Complexity
+    ///
+    /// Takes linear (in `self.len()`) time and does not allocate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(slice_rotate)]
+    ///
+    /// let mut a = ['a', 'b', 'c', 'd', 'e', 'f'];
+    /// a.rotate_left(2);
+    /// assert_eq!(a, ['c', 'd', 'e', 'f', 'a', 'b']);
+    ///
+    /// let mut b = [1, 2, 3, 4, 5];
+    /// b[1..4].rotate_left(1);
+    /// assert_eq!(b, [1, 3, 4, 2, 5]);
+    /// ```
+    #[unstable(feature = "slice_rotate", issue = "0")]
+    pub fn rotate_left(&mut self, mid: usize) {
+        core_slice::SliceExt::rotate_left(self, mid)
+    }
+
+    /// Rotates the slice in place such that the first `self.len() - k`
+    /// elements of the slice move to the end while the last `k` elements move
+    /// to the front. After calling `rotate_right`, the element previously at
+    /// index `self.len() - k` will become the first element in the slice.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `k` is greater than the length of the
+    /// slice. Note that `k == self.len()` does _not_ panic and is a no-op
+    /// rotation.
+    ///
+    /// # Complexity
+    ///
+    /// Takes linear (in `self.len()`) time and does not allocate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(slice_rotate)]
+    ///
+    /// let mut a = ['a', 'b', 'c', 'd', 'e', 'f'];
+    /// a.rotate_right(2);
+    /// assert_eq!(a, ['e', 'f', 'a', 'b', 'c', 'd']);
+    /// ```
+    #[unstable(feature = "slice_rotate", issue = "0")]
+    pub fn rotate_right(&mut self, k: usize) {
+        core_slice::SliceExt::rotate_right(self, k)
+    }
+
     /// Returns an iterator over the slice.
     ///
     /// # Examples
@@ -591,6 +658,70 @@ impl<T> [T] {
         core_slice::SliceExt::chunks(self, size)
     }
 
+    /// Returns an iterator over `chunk_size` elements of the slice at a
+    /// time. The chunks are slices and do not overlap. If `chunk_size` does
+    /// not divide the length of the slice, then the last up to `chunk_size-1`
+    /// elements will be omitted and can be retrieved from the `remainder`
+    /// function of the iterator.
+    ///
+    /// Due to each chunk having exactly `chunk_size` elements, the compiler
+    /// can often optimize the resulting code better than in the case of
+    /// `chunks`, removing bounds checks on indexing into the chunks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #![feature(chunks_exact)]
+    ///
+    /// let slice = ['l', 'o', 'r', 'e', 'm'];
+    /// let mut iter = slice.chunks_exact(2);
+    /// assert_eq!(iter.next().unwrap(), &['l', 'o']);
+    /// assert_eq!(iter.next().unwrap(), &['r', 'e']);
+    /// assert!(iter.next().is_none());
+    /// assert_eq!(iter.remainder(), &['m']);
+    /// ```
+    #[unstable(feature = "chunks_exact", issue = "0")]
+    #[inline]
+    pub fn chunks_exact(&self, chunk_size: usize) -> ChunksExact<T> {
+        core_slice::SliceExt::chunks_exact(self, chunk_size)
+    }
+
+    /// Returns an iterator over the slice in chunks of `A`, a fixed-size
+    /// array of `T`s. The chunks do not overlap. If the length of `A` does
+    /// not divide the length of the slice, then the last elements will be
+    /// omitted and can be retrieved from the `remainder` function of the
+    /// iterator.
+    ///
+    /// This works like `chunks_exact`, except that the chunk size is part of
+    /// the type, so the compiler knows the length of every chunk even when
+    /// the iterator is passed around.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `A` has length 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #![feature(array_chunks)]
+    ///
+    /// let slice = ['l', 'o', 'r', 'e', 'm'];
+    /// let mut iter = slice.array_chunks::<[char; 2]>();
+    /// assert_eq!(iter.next().unwrap(), &['l', 'o']);
+    /// assert_eq!(iter.next().unwrap(), &['r', 'e']);
+    /// assert!(iter.next().is_none());
+    /// assert_eq!(iter.remainder(), &['m']);
+    /// ```
+    #[unstable(feature = "array_chunks", issue = "0")]
+    #[inline]
+    pub fn array_chunks<A>(&self) -> ArrayChunks<T, A> where A: FixedSizeArray<T> {
+        core_slice::SliceExt::array_chunks(self)
+    }
+
     /// Returns an iterator over `chunk_size` elements of the slice at a time.
     /// The chunks are mutable slices, and do not overlap. If `chunk_size` does
     /// not divide the length of the slice, then the last chunk will not
@@ -620,6 +751,42 @@ impl<T> [T] {
         core_slice::SliceExt::chunks_mut(self, chunk_size)
     }
 
+    /// Returns an iterator over `chunk_size` elements of the slice at a time.
+    /// The chunks are mutable slices, and do not overlap. If `chunk_size` does
+    /// not divide the length of the slice, then the last up to `chunk_size-1`
+    /// elements will be omitted and can be retrieved from the
+    /// `into_remainder` function of the iterator.
+    ///
+    /// Due to each chunk having exactly `chunk_size` elements, the compiler
+    /// can often optimize the resulting code better than in the case of
+    /// `chunks_mut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(chunks_exact)]
+    ///
+    /// let v = &mut [0, 0, 0, 0, 0];
+    /// let mut count = 1;
+    ///
+    /// for chunk in v.chunks_exact_mut(2) {
+    ///     for elem in chunk.iter_mut() {
+    ///         *elem += count;
+    ///     }
+    ///     count += 1;
+    /// }
+    /// assert_eq!(v, &[1, 1, 2, 2, 0]);
+    /// ```
+    #[unstable(feature = "chunks_exact", issue = "0")]
+    #[inline]
+    pub fn chunks_exact_mut(&mut self, chunk_size: usize) -> ChunksExactMut<T> {
+        core_slice::SliceExt::chunks_exact_mut(self, chunk_size)
+    }
+
     /// Divides one slice into two at an index.
     ///
     /// The first will contain all indices from `[0, mid)` (excluding
@@ -1084,6 +1251,101 @@ impl<T> [T] {
         merge_sort(self, compare)
     }
 
+    /// Sorts the slice, but may not preserve the order of equal elements.
+    ///
+    /// This sort is unstable (i.e. may reorder equal elements), in-place (i.e. does not allocate),
+    /// and `O(n log n)` worst-case.
+    ///
+    /// # Current implementation
+    ///
+    /// The current algorithm is based on Orson Peters' [pattern-defeating quicksort][pdqsort],
+    /// which is a quicksort variant designed to be very fast on certain kinds of patterns,
+    /// sometimes achieving linear time. It is randomized but deterministic, and falls back to
+    /// heapsort on degenerate inputs.
+    ///
+    /// It is generally faster than stable sorting, except in a few special cases, e.g. when the
+    /// slice consists of several concatenated sorted sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(sort_unstable)]
+    ///
+    /// let mut v = [-5, 4, 1, -3, 2];
+    ///
+    /// v.sort_unstable();
+    /// assert!(v == [-5, -3, 1, 2, 4]);
+    /// ```
+    ///
+    /// [pdqsort]: https://github.com/orlp/pdqsort
+    #[unstable(feature = "sort_unstable", issue = "0")]
+    #[inline]
+    pub fn sort_unstable(&mut self)
+        where T: Ord
+    {
+        core_slice::SliceExt::sort_unstable(self);
+    }
+
+    /// Sorts the slice using `compare` to compare elements, but may not preserve the order of
+    /// equal elements.
+    ///
+    /// This sort is unstable (i.e. may reorder equal elements), in-place (i.e. does not allocate),
+    /// and `O(n log n)` worst-case.
+    ///
+    /// # Current implementation
+    ///
+    /// See [`sort_unstable`](#method.sort_unstable).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(sort_unstable)]
+    ///
+    /// let mut v = [5, 4, 1, 3, 2];
+    /// v.sort_unstable_by(|a, b| a.cmp(b));
+    /// assert!(v == [1, 2, 3, 4, 5]);
+    ///
+    /// // reverse sorting
+    /// v.sort_unstable_by(|a, b| b.cmp(a));
+    /// assert!(v == [5, 4, 3, 2, 1]);
+    /// ```
+    #[unstable(feature = "sort_unstable", issue = "0")]
+    #[inline]
+    pub fn sort_unstable_by<F>(&mut self, compare: F)
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        core_slice::SliceExt::sort_unstable_by(self, compare);
+    }
+
+    /// Sorts the slice using `f` to extract a key to compare elements by, but may not preserve the
+    /// order of equal elements.
+    ///
+    /// This sort is unstable (i.e. may reorder equal elements), in-place (i.e. does not allocate),
+    /// and `O(n log n)` worst-case.
+    ///
+    /// # Current implementation
+    ///
+    /// See [`sort_unstable`](#method.sort_unstable).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(sort_unstable)]
+    ///
+    /// let mut v = [-5i32, 4, 1, -3, 2];
+    ///
+    /// v.sort_unstable_by_key(|k| k.abs());
+    /// assert!(v == [1, 2, -3, 4, -5]);
+    /// ```
+    #[unstable(feature = "sort_unstable", issue = "0")]
+    #[inline]
+    pub fn sort_unstable_by_key<B, F>(&mut self, f: F)
+        where F: FnMut(&T) -> B,
+              B: Ord
+    {
+        core_slice::SliceExt::sort_unstable_by_key(self, f);
+    }
+
     /// Copies the elements from `src` into `self`.
     ///
     /// The length of `src` must be the same as `self`.
@@ -1128,6 +1390,76 @@ impl<T> [T] {
         core_slice::SliceExt::copy_from_slice(self, src)
     }
 
+    /// Transmutes the slice to a slice of another type, ensuring alignment of
+    /// the types is maintained.
+    ///
+    /// This method splits the slice into three distinct slices: prefix,
+    /// correctly aligned middle slice of a new type, and the suffix slice.
+    /// The middle slice is as long as possible; the prefix and suffix hold
+    /// the elements before and after it that couldn't be reinterpreted. If
+    /// either type is zero-sized, the whole slice is returned as the prefix.
+    ///
+    /// # Safety
+    ///
+    /// This method is essentially a `transmute` of the middle elements, so
+    /// all of its usual caveats apply: every bit pattern of the middle part
+    /// must be a valid value of `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_align_to)]
+    ///
+    /// let bytes: [u8; 7] = [1, 2, 3, 4, 5, 6, 7];
+    /// unsafe {
+    ///     let (prefix, shorts, suffix) = bytes.align_to::<u16>();
+    ///     // The exact split depends on where `bytes` ended up in memory,
+    ///     // but nothing is lost.
+    ///     assert_eq!(prefix.len() + shorts.len() * 2 + suffix.len(), 7);
+    /// }
+    /// ```
+    #[unstable(feature = "slice_align_to", issue = "0")]
+    #[inline]
+    pub unsafe fn align_to<U>(&self) -> (&[T], &[U], &[T]) {
+        core_slice::SliceExt::align_to(self)
+    }
+
+    /// Transmutes the mutable slice to a mutable slice of another type,
+    /// ensuring alignment of the types is maintained.
+    ///
+    /// This is the mutable version of [`align_to`]; see it for details.
+    ///
+    /// [`align_to`]: #method.align_to
+    ///
+    /// # Safety
+    ///
+    /// This method is essentially a `transmute` of the middle elements, so
+    /// all of its usual caveats apply. In addition, any value written through
+    /// the middle slice must also be valid for `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_align_to)]
+    ///
+    /// let mut bytes = [0u8; 16];
+    /// unsafe {
+    ///     let (prefix, words, suffix) = bytes.align_to_mut::<u32>();
+    ///     for b in prefix.iter_mut().chain(suffix) {
+    ///         *b = 1;
+    ///     }
+    ///     for w in words {
+    ///         *w = 0x01010101;
+    ///     }
+    /// }
+    /// assert!(bytes.iter().all(|&b| b == 1));
+    /// ```
+    #[unstable(feature = "slice_align_to", issue = "0")]
+    #[inline]
+    pub unsafe fn align_to_mut<U>(&mut self) -> (&mut [T], &mut [U], &mut [T]) {
+        core_slice::SliceExt::align_to_mut(self)
+    }
+
 
     /// Copies `self` into a new `Vec`.
     ///
@@ -23,6 +23,7 @@ use core::str as core_str;
 use core::str::pattern::Pattern;
 use core::str::pattern::{Searcher, ReverseSearcher, DoubleEndedSearcher};
 use core::mem;
+use core::ptr;
 use rustc_unicode::str::{UnicodeStr, Utf16Encoder};
 
 use vec_deque::VecDeque;
@@ -56,6 +57,8 @@ pub use core::str::{from_utf8_unchecked, ParseBoolError};
 pub use rustc_unicode::str::SplitWhitespace;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::pattern;
+#[unstable(feature = "str_checked_slicing", issue = "0")]
+pub use core::str::SliceIndex;
 
 #[unstable(feature = "slice_concat_ext",
            reason = "trait should not have to exist",
@@ -241,6 +244,63 @@ impl str {
         core_str::StrExt::is_char_boundary(self, index)
     }
 
+    /// Returns a subslice of `str`, or `None` if the range is out of bounds
+    /// or doesn't start and end on character boundaries.
+    ///
+    /// This is the non-panicking alternative to indexing the `str`, and takes
+    /// the same range types, e.g. `s.get(1..5)` instead of `&s[1..5]`.
+    ///
+    /// To get a mutable string slice instead, see the [`get_mut()`] method.
+    ///
+    /// [`get_mut()`]: #method.get_mut
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_checked_slicing)]
+    ///
+    /// let s = "Löwe 老虎 Léopard";
+    /// assert_eq!(s.get(0..1), Some("L"));
+    /// assert_eq!(s.get(1..9), Some("öwe 老"));
+    /// assert_eq!(s.get(..4), Some("Löw"));
+    ///
+    /// // byte 2 lies within `ö`
+    /// assert_eq!(s.get(2..3), None);
+    ///
+    /// // byte 8 lies within `老`
+    /// assert_eq!(s.get(1..8), None);
+    ///
+    /// // byte 100 is outside the string
+    /// assert_eq!(s.get(3..100), None);
+    /// ```
+    #[unstable(feature = "str_checked_slicing", issue = "0")]
+    #[inline]
+    pub fn get<I: SliceIndex>(&self, index: I) -> Option<&str> {
+        core_str::StrExt::get(self, index)
+    }
+
+    /// Returns a mutable subslice of `str`, or `None` if the range is out of
+    /// bounds or doesn't start and end on character boundaries.
+    ///
+    /// To get an immutable string slice instead, see the [`get()`] method.
+    ///
+    /// [`get()`]: #method.get
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_checked_slicing)]
+    ///
+    /// let mut s = String::from("hello");
+    /// assert_eq!(s.get_mut(1..).map(|s| &*s), Some("ello"));
+    /// assert!(s.get_mut(6..).is_none());
+    /// ```
+    #[unstable(feature = "str_checked_slicing", issue = "0")]
+    #[inline]
+    pub fn get_mut<I: SliceIndex>(&mut self, index: I) -> Option<&mut str> {
+        core_str::StrExt::get_mut(self, index)
+    }
+
     /// Converts a string slice to a byte slice.
     ///
     /// # Examples
@@ -1742,4 +1802,52 @@ impl str {
             String::from_utf8_unchecked(slice.into_vec())
         }
     }
+
+    /// Creates a new [`String`] by repeating a string `n` times.
+    ///
+    /// The result is allocated once, up front, and filled by repeatedly
+    /// doubling the part already written.
+    ///
+    /// [`String`]: string/struct.String.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the result would overflow a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(repeat_str)]
+    ///
+    /// assert_eq!("abc".repeat(4), String::from("abcabcabcabc"));
+    /// ```
+    #[unstable(feature = "repeat_str", issue = "0")]
+    pub fn repeat(&self, n: usize) -> String {
+        let len = self.len().checked_mul(n).expect("capacity overflow");
+        if len == 0 {
+            return String::new();
+        }
+
+        let mut buf = Vec::with_capacity(len);
+        buf.extend_from_slice(self.as_bytes());
+        unsafe {
+            // Double the copies written so far for as long as that fits, then
+            // fill the rest, which is a whole number of copies, from the front.
+            while buf.len() <= len / 2 {
+                let done = buf.len();
+                ptr::copy_nonoverlapping(buf.as_ptr(),
+                                         buf.as_mut_ptr().offset(done as isize),
+                                         done);
+                buf.set_len(done * 2);
+            }
+            let done = buf.len();
+            ptr::copy_nonoverlapping(buf.as_ptr(),
+                                     buf.as_mut_ptr().offset(done as isize),
+                                     len - done);
+            buf.set_len(len);
+            String::from_utf8_unchecked(buf)
+        }
+    }
 }
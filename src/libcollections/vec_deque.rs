@@ -34,6 +34,7 @@ use alloc::raw_vec::RawVec;
 
 use super::range::RangeArgument;
 use super::vec::Vec;
+use Bound::{Excluded, Included, Unbounded};
 
 const INITIAL_CAPACITY: usize = 7; // 2^3 - 1
 const MINIMUM_CAPACITY: usize = 1; // 2 - 1
@@ -323,6 +324,26 @@ impl<T> VecDeque<T> {
         }
     }
 
+    /// Copies all values from `src` to the buffer, starting at `dst` and
+    /// wrapping around the end of the buffer if needed. Assumes there is
+    /// room for `src.len()` elements starting at `dst`.
+    #[inline]
+    unsafe fn copy_slice(&mut self, dst: usize, src: &[T]) {
+        debug_assert!(src.len() < self.cap(),
+                      "cps dst={} len={} cap={}",
+                      dst,
+                      src.len(),
+                      self.cap());
+        let head_room = self.cap() - dst;
+        if src.len() <= head_room {
+            ptr::copy_nonoverlapping(src.as_ptr(), self.ptr().offset(dst as isize), src.len());
+        } else {
+            let (left, right) = src.split_at(head_room);
+            ptr::copy_nonoverlapping(left.as_ptr(), self.ptr().offset(dst as isize), left.len());
+            ptr::copy_nonoverlapping(right.as_ptr(), self.ptr(), right.len());
+        }
+    }
+
     /// Frobs the head and tail sections around to handle the fact that we
     /// just reallocated. Unsafe because it trusts old_cap.
     #[inline]
@@ -874,8 +895,16 @@ impl<T> VecDeque<T> {
         // and the head/tail values will be restored correctly.
         //
         let len = self.len();
-        let start = *range.start().unwrap_or(&0);
-        let end = *range.end().unwrap_or(&len);
+        let start = match range.start() {
+            Included(&n) => n,
+            Excluded(&n) => n.checked_add(1).expect("drain lower bound overflowed usize"),
+            Unbounded => 0,
+        };
+        let end = match range.end() {
+            Included(&n) => n.checked_add(1).expect("drain upper bound overflowed usize"),
+            Excluded(&n) => n,
+            Unbounded => len,
+        };
         assert!(start <= end, "drain lower bound was too large");
         assert!(end <= len, "drain upper bound was too large");
 
@@ -1747,8 +1776,53 @@ impl<T> VecDeque<T> {
     #[inline]
     #[stable(feature = "append", since = "1.4.0")]
     pub fn append(&mut self, other: &mut Self) {
-        // naive impl
-        self.extend(other.drain(..));
+        self.reserve(other.len());
+        unsafe {
+            // Copy both halves of `other` in bulk behind our head.
+            let (left, right) = other.as_slices();
+            let head = self.head;
+            self.copy_slice(head, left);
+            let head = self.wrap_add(head, left.len());
+            self.copy_slice(head, right);
+            self.head = self.wrap_add(head, right.len());
+        }
+        // The elements now belong to `self`, so silently forget them in `other`.
+        other.tail = other.head;
+    }
+
+    /// Inserts all the elements of `iter` at the front of the `VecDeque`,
+    /// keeping them in the order they are yielded.
+    ///
+    /// This is equivalent to building a `VecDeque` from `iter` and appending
+    /// `self` to it, but reuses `self`'s buffer: the new elements are copied
+    /// in bulk in front of the existing ones, across the end of the ring
+    /// buffer if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new number of elements in self overflows a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(deque_extend_front)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf: VecDeque<_> = vec![4, 5, 6].into_iter().collect();
+    /// buf.extend_front(vec![1, 2, 3]);
+    /// assert_eq!(buf.into_iter().collect::<Vec<_>>(), [1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[unstable(feature = "deque_extend_front", issue = "0")]
+    pub fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut items: Vec<T> = iter.into_iter().collect();
+        self.reserve(items.len());
+        unsafe {
+            let tail = self.wrap_sub(self.tail, items.len());
+            self.copy_slice(tail, &items);
+            self.tail = tail;
+            // The elements have been moved into `self`.
+            items.set_len(0);
+        }
     }
 
     /// Retains only the elements specified by the predicate.
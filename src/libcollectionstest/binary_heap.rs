@@ -237,6 +237,31 @@ fn test_drain() {
     assert!(q.is_empty());
 }
 
+#[test]
+fn test_drain_sorted() {
+    let mut q: BinaryHeap<_> = [9, 8, 7, 6, 5, 4, 3, 2, 1].iter().cloned().collect();
+
+    assert_eq!(q.drain_sorted().take(5).collect::<Vec<_>>(), vec![9, 8, 7, 6, 5]);
+
+    assert!(q.is_empty());
+}
+
+#[test]
+fn test_into_iter_sorted() {
+    let data = vec![5, 9, 3];
+    let iterout = vec![9, 5, 3];
+    let pq = BinaryHeap::from(data);
+
+    let v: Vec<_> = pq.into_iter_sorted().collect();
+    assert_eq!(v, iterout);
+
+    let pq = BinaryHeap::from(vec![2, 1, 4, 3]);
+    let mut it = pq.into_iter_sorted();
+    assert_eq!(it.len(), 4);
+    assert_eq!(it.next(), Some(4));
+    assert_eq!(it.len(), 3);
+}
+
 #[test]
 fn test_extend_ref() {
     let mut a = BinaryHeap::new();
@@ -11,6 +11,7 @@
 use std::collections::BTreeMap;
 use std::collections::Bound::{self, Excluded, Included, Unbounded};
 use std::collections::btree_map::Entry::{Occupied, Vacant};
+use std::collections::btree_map::Range;
 use std::rc::Rc;
 
 use std::iter::FromIterator;
@@ -170,7 +171,7 @@ fn test_range_small() {
     let map: BTreeMap<_, _> = (0..size).map(|i| (i, i)).collect();
 
     let mut j = 0;
-    for ((&k, &v), i) in map.range(Included(&2), Unbounded).zip(2..size) {
+    for ((&k, &v), i) in map.range(2..).zip(2..size) {
         assert_eq!(k, i);
         assert_eq!(v, i);
         j += 1;
@@ -184,7 +185,7 @@ fn test_range_1000() {
     let map: BTreeMap<_, _> = (0..size).map(|i| (i, i)).collect();
 
     fn test(map: &BTreeMap<u32, u32>, size: u32, min: Bound<&u32>, max: Bound<&u32>) {
-        let mut kvs = map.range(min, max).map(|(&k, &v)| (k, v));
+        let mut kvs = map.range((min, max)).map(|(&k, &v)| (k, v));
         let mut pairs = (0..size).map(|i| (i, i));
 
         for (kv, pair) in kvs.by_ref().zip(pairs.by_ref()) {
@@ -201,6 +202,59 @@ fn test_range_1000() {
     test(&map, size, Unbounded, Unbounded);
 }
 
+#[test]
+fn test_range_bounds() {
+    let map: BTreeMap<_, _> = (0..10).map(|i| (i, i)).collect();
+
+    let keys = |r: Range<i32, i32>| r.map(|(&k, _)| k).collect::<Vec<_>>();
+    assert_eq!(keys(map.range(3..6)), [3, 4, 5]);
+    assert_eq!(keys(map.range(..3)), [0, 1, 2]);
+    assert_eq!(keys(map.range(7..)), [7, 8, 9]);
+    assert_eq!(keys(map.range(..)).len(), 10);
+    assert_eq!(keys(map.range((Excluded(3), Included(6)))), [4, 5, 6]);
+    assert!(keys(map.range((Excluded(&3), Excluded(&4)))).is_empty());
+    assert!(keys(map.range(4..4)).is_empty());
+}
+
+#[test]
+fn test_range_mut_bounds() {
+    let mut map: BTreeMap<_, _> = (0..10).map(|i| (i, i)).collect();
+
+    for (_, v) in map.range_mut(3..6) {
+        *v *= 10;
+    }
+    for (&k, &v) in &map {
+        assert_eq!(v, if 3 <= k && k < 6 { k * 10 } else { k });
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_range_equal_excluded() {
+    let map: BTreeMap<_, _> = (0..10).map(|i| (i, i)).collect();
+    map.range((Excluded(2), Excluded(2)));
+}
+
+#[test]
+#[should_panic]
+fn test_range_backwards() {
+    let map: BTreeMap<_, _> = (0..10).map(|i| (i, i)).collect();
+    map.range((Included(3), Included(2)));
+}
+
+#[test]
+fn test_range_borrowed_key() {
+    let mut map = BTreeMap::new();
+    map.insert("aardvark".to_string(), 1);
+    map.insert("baboon".to_string(), 2);
+    map.insert("coyote".to_string(), 3);
+    map.insert("dingo".to_string(), 4);
+    let mut iter = map.range::<str, _>((Included("b"), Excluded("d")));
+    assert_eq!(iter.next(), Some((&"baboon".to_string(), &2)));
+    assert_eq!(iter.next(), Some((&"coyote".to_string(), &3)));
+    assert_eq!(iter.next(), None);
+}
+
 #[test]
 fn test_range() {
     let size = 200;
@@ -208,7 +262,7 @@ fn test_range() {
 
     for i in 0..size {
         for j in i..size {
-            let mut kvs = map.range(Included(&i), Included(&j)).map(|(&k, &v)| (k, v));
+            let mut kvs = map.range((Included(&i), Included(&j))).map(|(&k, &v)| (k, v));
             let mut pairs = (i..j + 1).map(|i| (i, i));
 
             for (kv, pair) in kvs.by_ref().zip(pairs.by_ref()) {
@@ -531,6 +585,48 @@ create_append_test!(test_append_181, 181);
 create_append_test!(test_append_239, 239);
 create_append_test!(test_append_1700, 1700);
 
+macro_rules! create_append_disjoint_test {
+    ($name:ident, $len:expr) => {
+        #[test]
+        fn $name() {
+            let mut a = BTreeMap::new();
+            for i in 0..$len {
+                a.insert(i, i);
+            }
+
+            let mut b = BTreeMap::new();
+            for i in $len..2*$len {
+                b.insert(i, 2*i);
+            }
+
+            // Smaller keys on either side.
+            let mut c = a.clone();
+            let mut d = b.clone();
+            a.append(&mut b);
+            d.append(&mut c);
+
+            for map in &[a, d] {
+                assert_eq!(map.len(), 2*$len);
+                for i in 0..2*$len {
+                    if i < $len {
+                        assert_eq!(map[&i], i);
+                    } else {
+                        assert_eq!(map[&i], 2*i);
+                    }
+                }
+            }
+            assert!(b.is_empty());
+            assert!(c.is_empty());
+        }
+    };
+}
+
+// Appending the larger keys of a disjoint map only builds up the right edge.
+create_append_disjoint_test!(test_append_disjoint_6, 6);
+create_append_disjoint_test!(test_append_disjoint_12, 12);
+create_append_disjoint_test!(test_append_disjoint_100, 100);
+create_append_disjoint_test!(test_append_disjoint_1000, 1000);
+
 fn rand_data(len: usize) -> Vec<(u32, u32)> {
     let mut rng = DeterministicRng::new();
     Vec::from_iter(
@@ -10,20 +10,34 @@
 
 #![deny(warnings)]
 
+#![feature(binary_heap_drain_sorted)]
 #![feature(binary_heap_extras)]
+#![feature(binary_heap_into_iter_sorted)]
 #![feature(binary_heap_peek_mut)]
 #![feature(box_syntax)]
 #![feature(btree_range)]
 #![feature(collections)]
 #![feature(collections_bound)]
 #![feature(const_fn)]
+#![feature(dedup_by)]
+#![feature(deque_extend_front)]
+#![feature(drain_filter)]
 #![feature(fn_traits)]
 #![feature(enumset)]
+#![feature(inclusive_range)]
+#![feature(inclusive_range_syntax)]
 #![feature(linked_list_contains)]
 #![feature(pattern)]
 #![feature(rand)]
-#![feature(step_by)]
+#![feature(rc_counts)]
+#![feature(repeat_str)]
+#![feature(slice_rotate)]
+#![feature(sort_unstable)]
+#![feature(splice)]
+#![feature(iterator_step_by)]
+#![feature(str_checked_slicing)]
 #![feature(str_escape)]
+#![feature(string_retain)]
 #![feature(test)]
 #![feature(unboxed_closures)]
 #![feature(unicode)]
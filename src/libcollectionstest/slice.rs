@@ -332,6 +332,40 @@ fn test_dedup() {
     case(vec![1, 1, 2, 2, 2, 3, 3], vec![1, 2, 3]);
 }
 
+#[test]
+fn test_dedup_by_key() {
+    fn case(a: Vec<i32>, b: Vec<i32>) {
+        let mut v = a;
+        v.dedup_by_key(|i| *i / 10);
+        assert_eq!(v, b);
+    }
+    case(vec![], vec![]);
+    case(vec![10], vec![10]);
+    case(vec![10, 11], vec![10]);
+    case(vec![10, 20, 30], vec![10, 20, 30]);
+    case(vec![10, 11, 20, 30], vec![10, 20, 30]);
+    case(vec![10, 20, 21, 30], vec![10, 20, 30]);
+    case(vec![10, 20, 30, 31], vec![10, 20, 30]);
+    case(vec![10, 11, 20, 21, 22, 30, 31], vec![10, 20, 30]);
+}
+
+#[test]
+fn test_dedup_by() {
+    use std::ascii::AsciiExt;
+
+    let mut vec = vec!["foo", "bar", "Bar", "baz", "bar"];
+    vec.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+
+    assert_eq!(vec, ["foo", "bar", "baz", "bar"]);
+
+    // The first argument is the element being considered for removal, the
+    // second is the previously retained one, which may be updated in place.
+    let mut vec = vec![("foo", 1), ("foo", 2), ("bar", 3), ("bar", 4), ("bar", 5)];
+    vec.dedup_by(|a, b| a.0 == b.0 && { b.1 += a.1; true });
+
+    assert_eq!(vec, [("foo", 3), ("bar", 12)]);
+}
+
 #[test]
 fn test_dedup_unique() {
     let mut v0: Vec<Box<_>> = vec![box 1, box 1, box 2, box 3];
@@ -449,6 +483,91 @@ fn test_sort() {
     assert!(v == [0xDEADBEEF]);
 }
 
+#[test]
+fn test_sort_unstable() {
+    let mut v = [0; 600];
+    let mut tmp = [0; 600];
+    let mut rng = thread_rng();
+
+    for len in (2..25).chain(500..510) {
+        let v = &mut v[0..len];
+        let tmp = &mut tmp[0..len];
+
+        for &modulus in &[5, 10, 100, 1000] {
+            for _ in 0..100 {
+                for i in 0..len {
+                    v[i] = rng.gen::<i32>() % modulus;
+                }
+
+                // Sort in default order.
+                tmp.copy_from_slice(v);
+                tmp.sort_unstable();
+                assert!(tmp.windows(2).all(|w| w[0] <= w[1]));
+
+                // Sort in ascending order.
+                tmp.copy_from_slice(v);
+                tmp.sort_unstable_by(|a, b| a.cmp(b));
+                assert!(tmp.windows(2).all(|w| w[0] <= w[1]));
+
+                // Sort in descending order.
+                tmp.copy_from_slice(v);
+                tmp.sort_unstable_by(|a, b| b.cmp(a));
+                assert!(tmp.windows(2).all(|w| w[0] >= w[1]));
+
+                // Sort by a key.
+                tmp.copy_from_slice(v);
+                tmp.sort_unstable_by_key(|x| x.wrapping_abs());
+                assert!(tmp.windows(2).all(|w| w[0].wrapping_abs() <= w[1].wrapping_abs()));
+            }
+        }
+    }
+
+    // Sort already sorted, reversed and mostly sorted inputs, which exercise the pattern
+    // detection in pivot selection.
+    let mut v: Vec<_> = (0..1000).collect();
+    v.sort_unstable();
+    assert!(v.windows(2).all(|w| w[0] <= w[1]));
+    v.reverse();
+    v.sort_unstable();
+    assert!(v.windows(2).all(|w| w[0] <= w[1]));
+    v.swap(10, 900);
+    v.sort_unstable();
+    assert!(v.windows(2).all(|w| w[0] <= w[1]));
+
+    // Should not panic.
+    [0i32; 0].sort_unstable();
+    [(); 10].sort_unstable();
+    [(); 100].sort_unstable();
+
+    let mut v = [0xDEADBEEFu64];
+    v.sort_unstable();
+    assert!(v == [0xDEADBEEF]);
+}
+
+#[test]
+fn test_sort_unstable_panic_safe() {
+    use std::panic;
+
+    // Every element is live exactly once after a comparison panics midway, so no element was
+    // duplicated or dropped twice.
+    let counter = Rc::new(());
+    let mut v: Vec<_> = (0..200).rev().map(|x| (x, counter.clone())).collect();
+    let mut comparisons = 0;
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        v.sort_unstable_by(|a, b| {
+            comparisons += 1;
+            if comparisons == 300 {
+                panic!("comparison failed");
+            }
+            a.0.cmp(&b.0)
+        });
+    }));
+    assert!(result.is_err());
+    assert_eq!(Rc::strong_count(&counter), 201);
+    drop(v);
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
+
 #[test]
 fn test_sort_stability() {
     for len in 4..25 {
@@ -861,6 +980,78 @@ fn test_reverse_part() {
     assert!(values == [1, 4, 3, 2, 5]);
 }
 
+#[test]
+fn test_rotate_left() {
+    let expected: Vec<_> = (0..13).collect();
+    let mut v = Vec::new();
+
+    // no-ops
+    v.clone_from(&expected);
+    v.rotate_left(0);
+    assert_eq!(v, expected);
+    v.rotate_left(expected.len());
+    assert_eq!(v, expected);
+    let mut zst_array = [(), (), ()];
+    zst_array.rotate_left(2);
+
+    // happy path
+    v = (5..13).chain(0..5).collect();
+    v.rotate_left(8);
+    assert_eq!(v, expected);
+
+    let expected: Vec<_> = (0..1000).collect();
+
+    // small rotations in large slice
+    v = (2..1000).chain(0..2).collect();
+    v.rotate_left(998);
+    assert_eq!(v, expected);
+    v = (998..1000).chain(0..998).collect();
+    v.rotate_left(2);
+    assert_eq!(v, expected);
+
+    // non-small prime rotation
+    v = (389..1000).chain(0..389).collect();
+    v.rotate_left(1000 - 389);
+    assert_eq!(v, expected);
+}
+
+#[test]
+fn test_rotate_right() {
+    let expected: Vec<_> = (0..13).collect();
+    let mut v = Vec::new();
+
+    // no-ops
+    v.clone_from(&expected);
+    v.rotate_right(0);
+    assert_eq!(v, expected);
+    v.rotate_right(expected.len());
+    assert_eq!(v, expected);
+    let mut zst_array = [(), (), ()];
+    zst_array.rotate_right(2);
+
+    // happy path
+    v = (5..13).chain(0..5).collect();
+    v.rotate_right(5);
+    assert_eq!(v, expected);
+
+    let expected: Vec<_> = (0..1000).collect();
+
+    // small rotations in large slice
+    v = (2..1000).chain(0..2).collect();
+    v.rotate_right(2);
+    assert_eq!(v, expected);
+    v = (998..1000).chain(0..998).collect();
+    v.rotate_right(998);
+    assert_eq!(v, expected);
+}
+
+#[test]
+#[should_panic]
+fn test_rotate_left_out_of_bounds() {
+    let mut v = [1, 2, 3];
+    v.rotate_left(4);
+}
+
 #[test]
 fn test_show() {
     macro_rules! test_show_vec {
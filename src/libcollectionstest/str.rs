@@ -229,6 +229,24 @@ fn test_replace() {
     assert_eq!(" test test ".replace(test, ""), "   ");
 }
 
+#[test]
+fn test_repeat() {
+    assert_eq!("".repeat(3), "");
+    assert_eq!("abc".repeat(0), "");
+    assert_eq!("abc".repeat(1), "abc");
+    assert_eq!("abc".repeat(5), "abcabcabcabcabc");
+    assert_eq!("中华".repeat(3), "中华中华中华");
+    let s = "x".repeat(1000);
+    assert_eq!(s.len(), 1000);
+    assert!(s.chars().all(|c| c == 'x'));
+}
+
+#[test]
+#[should_panic]
+fn test_repeat_overflow() {
+    "ab".repeat(usize::max_value());
+}
+
 #[test]
 fn test_replace_2a() {
     let data = "ประเทศไทย中华";
@@ -360,6 +378,40 @@ fn test_is_char_boundary() {
         }
     }
 }
+
+#[test]
+fn test_str_get() {
+    let s = "中华Việt Nam";
+    assert_eq!(s.get(0..3), Some("中"));
+    assert_eq!(s.get(3..6), Some("华"));
+    assert_eq!(s.get(..6), Some("中华"));
+    assert_eq!(s.get(6..), Some("Việt Nam"));
+    assert_eq!(s.get(..), Some(s));
+    assert_eq!(s.get(0...2), Some("中"));
+    assert_eq!(s.get(...5), Some("中华"));
+    assert_eq!(s.get(s.len()..), Some(""));
+
+    // not on a char boundary
+    assert_eq!(s.get(0..2), None);
+    assert_eq!(s.get(1..), None);
+    assert_eq!(s.get(..7), Some("中华V"));
+    assert_eq!(s.get(..9), None);
+    // out of bounds or reversed
+    assert_eq!(s.get(..s.len() + 1), None);
+    assert_eq!(s.get(s.len() + 1..), None);
+    assert_eq!(s.get(6..3), None);
+    assert_eq!(s.get(0...usize::max_value()), None);
+}
+
+#[test]
+fn test_str_get_mut() {
+    let mut s = String::from("中华Việt Nam");
+    assert!(s.get_mut(0..2).is_none());
+    assert!(s.get_mut(...usize::max_value()).is_none());
+    assert_eq!(s.get_mut(6..).map(|s| &*s), Some("Việt Nam"));
+    let (head, _) = s.get_mut(..6).unwrap().split_at_mut(3);
+    assert_eq!(head, "中");
+}
 const LOREM_PARAGRAPH: &'static str = "\
 Lorem ipsum dolor sit amet, consectetur adipiscing elit. Suspendisse quis lorem sit amet dolor \
 ultricies condimentum. Praesent iaculis purus elit, ac malesuada quam malesuada in. Duis sed orci \
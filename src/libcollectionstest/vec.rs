@@ -46,9 +46,8 @@ fn test_double_drop() {
         tv.x.push(DropCounter { count: &mut count_x });
         tv.y.push(DropCounter { count: &mut count_y });
 
-        // If Vec had a drop flag, here is where it would be zeroed.
-        // Instead, it should rely on its internal state to prevent
-        // doing anything significant when dropped multiple times.
+        // Moving out of `tv.x` must keep its destructor from running
+        // again when `tv` goes out of scope.
         drop(tv.x);
 
         // Here tv goes out of scope, tv.y should be dropped, but not tv.x.
@@ -431,6 +430,16 @@ fn test_drain_out_of_bounds() {
     v.drain(5..6);
 }
 
+#[test]
+#[should_panic(expected = "drain upper bound overflowed usize")]
+fn test_drain_inclusive_max_end() {
+    use std::collections::Bound::{Included, Unbounded};
+    use std::usize;
+
+    let mut v = vec![1, 2, 3, 4, 5];
+    v.drain((Unbounded, Included(usize::MAX)));
+}
+
 #[test]
 fn test_drain_range() {
     let mut v = vec![1, 2, 3, 4, 5];
@@ -454,6 +463,159 @@ fn test_drain_range() {
     assert_eq!(v, &[(), ()]);
 }
 
+#[test]
+fn test_drain_filter_complete() {
+    let mut vec = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    let removed = vec.drain_filter(|x| *x % 2 == 0).collect::<Vec<_>>();
+    assert_eq!(removed, [2, 4, 6, 8, 10, 12, 14]);
+    assert_eq!(vec, [1, 3, 5, 7, 9, 11, 13, 15]);
+
+    let mut vec: Vec<_> = (1..6).map(|x| x.to_string()).collect();
+    let removed = vec.drain_filter(|x| x.len() == 1).count();
+    assert_eq!(removed, 5);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn test_drain_filter_dropped_early() {
+    let mut vec = vec![1, 2, 3, 4, 5, 6, 7, 8];
+    {
+        let mut drain = vec.drain_filter(|x| *x % 2 == 0);
+        assert_eq!(drain.next(), Some(2));
+    }
+    // The rest of the vector is still filtered.
+    assert_eq!(vec, [1, 3, 5, 7]);
+}
+
+#[test]
+fn test_drain_filter_leaked() {
+    let mut vec = vec![1, 2, 3, 4];
+    {
+        let mut drain = vec.drain_filter(|x| *x > 1);
+        assert_eq!(drain.next(), Some(2));
+        ::std::mem::forget(drain);
+    }
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn test_drain_filter_mutate() {
+    let mut vec = vec![1, 2, 3, 4];
+    let removed = vec.drain_filter(|x| { *x *= 10; *x > 20 }).collect::<Vec<_>>();
+    assert_eq!(removed, [30, 40]);
+    assert_eq!(vec, [10, 20]);
+}
+
+#[test]
+fn test_drain_filter_pred_panics() {
+    use std::panic::{self, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    let data = (0..8).map(Rc::new).collect::<Vec<_>>();
+    let mut vec = data.clone();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        vec.drain_filter(|x| {
+            if **x == 5 {
+                panic!("predicate panicked");
+            }
+            **x % 2 == 0
+        }).count();
+    }));
+    assert!(result.is_err());
+
+    // The element the predicate panicked on is kept, and the rest of the
+    // vector is neither filtered nor lost.
+    assert_eq!(vec.iter().map(|x| **x).collect::<Vec<_>>(), [1, 3, 5, 6, 7]);
+    for x in &data {
+        let expected = if vec.contains(x) { 2 } else { 1 };
+        assert_eq!(Rc::strong_count(x), expected);
+    }
+}
+
+#[test]
+fn test_drain_filter_pred_panics_on_drop() {
+    use std::panic::{self, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    let data = (0..8).map(Rc::new).collect::<Vec<_>>();
+    let mut vec = data.clone();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut drain = vec.drain_filter(|x| {
+            if **x == 5 {
+                panic!("predicate panicked");
+            }
+            **x % 2 == 0
+        });
+        assert_eq!(drain.next().map(|x| *x), Some(0));
+        // The rest is filtered when `drain` is dropped.
+    }));
+    assert!(result.is_err());
+
+    assert_eq!(vec.iter().map(|x| **x).collect::<Vec<_>>(), [1, 3, 5, 6, 7]);
+    for x in &data {
+        let expected = if vec.contains(x) { 2 } else { 1 };
+        assert_eq!(Rc::strong_count(x), expected);
+    }
+}
+
+#[test]
+fn test_splice() {
+    let mut v = vec![1, 2, 3, 4, 5];
+    let a = [10, 11, 12];
+    v.splice(2..4, a.iter().cloned());
+    assert_eq!(v, &[1, 2, 10, 11, 12, 5]);
+    v.splice(1..3, Some(20));
+    assert_eq!(v, &[1, 20, 11, 12, 5]);
+}
+
+#[test]
+fn test_splice_unbounded() {
+    let mut vec = vec![1, 2, 3, 4, 5];
+    let t: Vec<_> = vec.splice(.., None).collect();
+    assert_eq!(vec, &[]);
+    assert_eq!(t, &[1, 2, 3, 4, 5]);
+
+    let mut vec = vec![1, 2, 3, 4, 5];
+    let t: Vec<_> = vec.splice(3.., vec![6, 7, 8]).collect();
+    assert_eq!(vec, &[1, 2, 3, 6, 7, 8]);
+    assert_eq!(t, &[4, 5]);
+}
+
+#[test]
+fn test_splice_shorter() {
+    let mut v: Vec<_> = (1..8).map(|x| x.to_string()).collect();
+    let t: Vec<_> = v.splice(1..5, vec!["a".to_string()]).collect();
+    assert_eq!(t, &["2", "3", "4", "5"]);
+    assert_eq!(v, &["1", "a", "6", "7"]);
+}
+
+#[test]
+fn test_splice_unknown_length() {
+    // `filter` has a lower size_hint of zero, so the remaining items are
+    // collected before the tail is moved.
+    let mut v = vec![1, 2, 3, 4];
+    let t: Vec<_> = v.splice(1..2, (10..20).filter(|x| x % 3 == 0)).collect();
+    assert_eq!(t, &[2]);
+    assert_eq!(v, &[1, 12, 15, 18, 3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn test_splice_out_of_bounds() {
+    let mut v = vec![1, 2, 3, 4, 5];
+    let a = [10, 11, 12];
+    v.splice(5..6, a.iter().cloned());
+}
+
+#[test]
+fn test_splice_items_zero_sized() {
+    let mut vec = vec![(), (), ()];
+    let vec2 = vec![];
+    let t: Vec<_> = vec.splice(1..2, vec2.iter().cloned()).collect();
+    assert_eq!(vec, &[(), ()]);
+    assert_eq!(t, &[()]);
+}
+
 #[test]
 fn test_into_boxed_slice() {
     let xs = vec![1, 2, 3];
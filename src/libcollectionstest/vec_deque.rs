@@ -953,6 +953,63 @@ fn test_append() {
     assert_eq!(a.iter().cloned().collect::<Vec<_>>(), []);
 }
 
+#[test]
+fn test_append_wrapping() {
+    // Exercise every offset of both rings, so that the copied slices are
+    // split across the wrap point of the source and of the destination.
+    for a_offset in 0..8 {
+        for b_offset in 0..8 {
+            let mut a = VecDeque::with_capacity(7);
+            let mut b = VecDeque::with_capacity(7);
+            for _ in 0..a_offset {
+                a.push_back(0);
+                a.pop_front();
+            }
+            for _ in 0..b_offset {
+                b.push_back(0);
+                b.pop_front();
+            }
+            a.extend(vec![1, 2, 3].into_iter().map(|x| x.to_string()));
+            b.extend(vec![4, 5, 6, 7].into_iter().map(|x| x.to_string()));
+
+            a.append(&mut b);
+            assert_eq!(a.iter().map(|s| &s[..]).collect::<Vec<_>>(),
+                       ["1", "2", "3", "4", "5", "6", "7"]);
+            assert!(b.is_empty());
+        }
+    }
+}
+
+#[test]
+fn test_append_zst() {
+    let mut a: VecDeque<()> = vec![(); 3].into_iter().collect();
+    let mut b: VecDeque<()> = vec![(); 4].into_iter().collect();
+    a.append(&mut b);
+    assert_eq!(a.len(), 7);
+    assert_eq!(b.len(), 0);
+}
+
+#[test]
+fn test_extend_front() {
+    let mut a: VecDeque<_> = vec![4, 5, 6].into_iter().collect();
+    a.extend_front(vec![1, 2, 3]);
+    assert_eq!(a.iter().cloned().collect::<Vec<_>>(), [1, 2, 3, 4, 5, 6]);
+
+    a.extend_front(Vec::new());
+    assert_eq!(a.iter().cloned().collect::<Vec<_>>(), [1, 2, 3, 4, 5, 6]);
+
+    // The front half of the buffer wraps around.
+    let mut b = VecDeque::with_capacity(7);
+    b.push_back(10);
+    b.extend_front(0..10);
+    assert_eq!(b.iter().cloned().collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+
+    let mut c: VecDeque<String> = VecDeque::new();
+    c.push_back("c".to_string());
+    c.extend_front(vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(c.iter().map(|s| &s[..]).collect::<Vec<_>>(), ["a", "b", "c"]);
+}
+
 #[test]
 fn test_retain() {
     let mut buf = VecDeque::new();
@@ -0,0 +1,80 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Architecture-specific vendor intrinsics.
+//!
+//! This module exposes the SIMD intrinsics of each platform under the names
+//! used by the vendor's own documentation, e.g. `_mm_add_epi32` on x86. Each
+//! platform only gets its own submodule: `x86` on 32-bit x86 and `x86_64` on
+//! 64-bit x86.
+//!
+//! # Safety
+//!
+//! Every intrinsic is an `unsafe fn` compiled with `#[target_feature]`, since
+//! executing an instruction the CPU doesn't support is undefined behavior.
+//! It is up to the caller to make sure the CPU supports the features: either
+//! statically, with `cfg(target_feature = "...")` and `-C target-feature`, or
+//! by inspecting the CPU at runtime before calling into code that was itself
+//! compiled with `#[target_feature(enable = "...")]`.
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(cfg_target_feature, stdsimd)]
+//!
+//! #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+//! fn sum(x: [i32; 4], y: [i32; 4]) -> [i32; 4] {
+//!     use std::arch::x86_64::*;
+//!     use std::mem;
+//!
+//!     unsafe {
+//!         let x = _mm_loadu_si128(x.as_ptr() as *const __m128i);
+//!         let y = _mm_loadu_si128(y.as_ptr() as *const __m128i);
+//!         mem::transmute(_mm_add_epi32(x, y))
+//!     }
+//! }
+//!
+//! # #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+//! # fn main() {
+//! assert_eq!(sum([1, 2, 3, 4], [10, 20, 30, 40]), [11, 22, 33, 44]);
+//! # }
+//! # #[cfg(not(all(target_arch = "x86_64", target_feature = "sse2")))]
+//! # fn main() {}
+//! ```
+
+#![unstable(feature = "stdsimd", issue = "0")]
+
+#[macro_use]
+mod macros;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod simd;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[path = "x86/mod.rs"]
+mod x86_shared;
+#[cfg(target_arch = "x86_64")]
+#[path = "x86_64/mod.rs"]
+mod x86_64_only;
+
+/// Platform-specific intrinsics for the `x86` platform.
+#[cfg(target_arch = "x86")]
+pub mod x86 {
+    pub use super::x86_shared::*;
+}
+
+/// Platform-specific intrinsics for the `x86_64` platform.
+///
+/// This contains everything from the 32-bit `x86` platform plus the
+/// intrinsics which only exist in 64-bit mode.
+#[cfg(target_arch = "x86_64")]
+pub mod x86_64 {
+    pub use super::x86_shared::*;
+    pub use super::x86_64_only::*;
+}
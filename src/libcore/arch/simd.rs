@@ -0,0 +1,113 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Internal SIMD vector types and the generic SIMD intrinsics.
+//!
+//! The vendor types like `__m128i` don't say how many lanes of which type
+//! they hold. The intrinsics are implemented by reinterpreting them as one
+//! of the types below and using either the generic `simd_*` operations or
+//! the platform intrinsics, which need the exact lane types.
+
+#![allow(dead_code)]
+
+// 128-bit vectors
+simd_ty!(i8x16[i8]:
+        i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8
+        | x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15);
+simd_ty!(u8x16[u8]:
+        u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8
+        | x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15);
+simd_ty!(i16x8[i16]:
+        i16, i16, i16, i16, i16, i16, i16, i16
+        | x0, x1, x2, x3, x4, x5, x6, x7);
+simd_ty!(u16x8[u16]:
+        u16, u16, u16, u16, u16, u16, u16, u16
+        | x0, x1, x2, x3, x4, x5, x6, x7);
+simd_ty!(i32x4[i32]:
+        i32, i32, i32, i32
+        | x0, x1, x2, x3);
+simd_ty!(u32x4[u32]:
+        u32, u32, u32, u32
+        | x0, x1, x2, x3);
+simd_ty!(i64x2[i64]:
+        i64, i64
+        | x0, x1);
+simd_ty!(u64x2[u64]:
+        u64, u64
+        | x0, x1);
+simd_ty!(f32x4[f32]:
+        f32, f32, f32, f32
+        | x0, x1, x2, x3);
+simd_ty!(f64x2[f64]:
+        f64, f64
+        | x0, x1);
+
+// 256-bit vectors
+simd_ty!(i8x32[i8]:
+        i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8,
+        i8, i8, i8, i8, i8, i8, i8, i8, i8
+        | x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15, x16, x17, x18, x19,
+          x20, x21, x22, x23, x24, x25, x26, x27, x28, x29, x30, x31);
+simd_ty!(u8x32[u8]:
+        u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8,
+        u8, u8, u8, u8, u8, u8, u8, u8, u8
+        | x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15, x16, x17, x18, x19,
+          x20, x21, x22, x23, x24, x25, x26, x27, x28, x29, x30, x31);
+simd_ty!(i16x16[i16]:
+        i16, i16, i16, i16, i16, i16, i16, i16, i16, i16, i16, i16, i16, i16, i16, i16
+        | x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15);
+simd_ty!(i32x8[i32]:
+        i32, i32, i32, i32, i32, i32, i32, i32
+        | x0, x1, x2, x3, x4, x5, x6, x7);
+simd_ty!(u32x8[u32]:
+        u32, u32, u32, u32, u32, u32, u32, u32
+        | x0, x1, x2, x3, x4, x5, x6, x7);
+simd_ty!(i64x4[i64]:
+        i64, i64, i64, i64
+        | x0, x1, x2, x3);
+simd_ty!(u64x4[u64]:
+        u64, u64, u64, u64
+        | x0, x1, x2, x3);
+simd_ty!(f32x8[f32]:
+        f32, f32, f32, f32, f32, f32, f32, f32
+        | x0, x1, x2, x3, x4, x5, x6, x7);
+simd_ty!(f64x4[f64]:
+        f64, f64, f64, f64
+        | x0, x1, x2, x3);
+
+extern "platform-intrinsic" {
+    pub fn simd_eq<T, U>(x: T, y: T) -> U;
+    pub fn simd_ne<T, U>(x: T, y: T) -> U;
+    pub fn simd_lt<T, U>(x: T, y: T) -> U;
+    pub fn simd_le<T, U>(x: T, y: T) -> U;
+    pub fn simd_gt<T, U>(x: T, y: T) -> U;
+    pub fn simd_ge<T, U>(x: T, y: T) -> U;
+
+    pub fn simd_shuffle2<T, U>(x: T, y: T, idx: [u32; 2]) -> U;
+    pub fn simd_shuffle4<T, U>(x: T, y: T, idx: [u32; 4]) -> U;
+    pub fn simd_shuffle8<T, U>(x: T, y: T, idx: [u32; 8]) -> U;
+    pub fn simd_shuffle16<T, U>(x: T, y: T, idx: [u32; 16]) -> U;
+    pub fn simd_shuffle32<T, U>(x: T, y: T, idx: [u32; 32]) -> U;
+
+    pub fn simd_insert<T, U>(x: T, idx: u32, val: U) -> T;
+    pub fn simd_extract<T, U>(x: T, idx: u32) -> U;
+
+    pub fn simd_cast<T, U>(x: T) -> U;
+
+    pub fn simd_add<T>(x: T, y: T) -> T;
+    pub fn simd_sub<T>(x: T, y: T) -> T;
+    pub fn simd_mul<T>(x: T, y: T) -> T;
+    pub fn simd_div<T>(x: T, y: T) -> T;
+    pub fn simd_shl<T>(x: T, y: T) -> T;
+    pub fn simd_shr<T>(x: T, y: T) -> T;
+    pub fn simd_and<T>(x: T, y: T) -> T;
+    pub fn simd_or<T>(x: T, y: T) -> T;
+    pub fn simd_xor<T>(x: T, y: T) -> T;
+}
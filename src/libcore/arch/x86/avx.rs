@@ -0,0 +1,380 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Advanced Vector Extensions (AVX)
+
+use mem::{self, transmute};
+use ptr;
+
+use super::*;
+use super::super::simd::*;
+
+extern "platform-intrinsic" {
+    fn x86_mm256_max_ps(a: f32x8, b: f32x8) -> f32x8;
+    fn x86_mm256_min_ps(a: f32x8, b: f32x8) -> f32x8;
+    fn x86_mm256_sqrt_ps(a: f32x8) -> f32x8;
+    fn x86_mm256_hadd_ps(a: f32x8, b: f32x8) -> f32x8;
+    fn x86_mm256_movemask_ps(a: f32x8) -> i32;
+    fn x86_mm256_max_pd(a: f64x4, b: f64x4) -> f64x4;
+    fn x86_mm256_min_pd(a: f64x4, b: f64x4) -> f64x4;
+    fn x86_mm256_sqrt_pd(a: f64x4) -> f64x4;
+    fn x86_mm256_movemask_pd(a: f64x4) -> i32;
+    fn x86_mm256_testz_si256(a: u64x4, b: u64x4) -> i32;
+    fn x86_mm256_zeroupper();
+}
+
+/// Returns a vector of type `__m256` with all elements set to zero.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_setzero_ps() -> __m256 {
+    _mm256_set1_ps(0.0)
+}
+
+/// Broadcasts `a` to all elements.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_set1_ps(a: f32) -> __m256 {
+    transmute(f32x8::splat(a))
+}
+
+/// Sets packed single-precision elements with the supplied values, from
+/// highest to lowest.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_set_ps(e7: f32, e6: f32, e5: f32, e4: f32,
+                            e3: f32, e2: f32, e1: f32, e0: f32) -> __m256 {
+    transmute(f32x8::new(e0, e1, e2, e3, e4, e5, e6, e7))
+}
+
+/// Returns a vector of type `__m256d` with all elements set to zero.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_setzero_pd() -> __m256d {
+    _mm256_set1_pd(0.0)
+}
+
+/// Broadcasts `a` to all elements.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_set1_pd(a: f64) -> __m256d {
+    transmute(f64x4::splat(a))
+}
+
+/// Sets packed double-precision elements with the supplied values, from
+/// highest to lowest.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_set_pd(e3: f64, e2: f64, e1: f64, e0: f64) -> __m256d {
+    transmute(f64x4::new(e0, e1, e2, e3))
+}
+
+/// Returns a vector of type `__m256i` with all elements set to zero.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_setzero_si256() -> __m256i {
+    _mm256_set1_epi64x(0)
+}
+
+/// Broadcasts 8-bit integer `a` to all elements.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_set1_epi8(a: i8) -> __m256i {
+    transmute(i8x32::splat(a))
+}
+
+/// Broadcasts 32-bit integer `a` to all elements.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_set1_epi32(a: i32) -> __m256i {
+    transmute(i32x8::splat(a))
+}
+
+/// Broadcasts 64-bit integer `a` to all elements.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_set1_epi64x(a: i64) -> __m256i {
+    transmute(i64x4::splat(a))
+}
+
+/// Sets packed 32-bit integers with the supplied values, from highest to
+/// lowest.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_set_epi32(e7: i32, e6: i32, e5: i32, e4: i32,
+                               e3: i32, e2: i32, e1: i32, e0: i32) -> __m256i {
+    transmute(i32x8::new(e0, e1, e2, e3, e4, e5, e6, e7))
+}
+
+/// Loads eight `f32` values from memory into a new vector.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_loadu_ps(mem_addr: *const f32) -> __m256 {
+    let mut dst = _mm256_setzero_ps();
+    ptr::copy_nonoverlapping(mem_addr as *const u8,
+                             &mut dst as *mut __m256 as *mut u8,
+                             mem::size_of::<__m256>());
+    dst
+}
+
+/// Stores the eight `f32` values of `a` into memory.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_storeu_ps(mem_addr: *mut f32, a: __m256) {
+    ptr::copy_nonoverlapping(&a as *const __m256 as *const u8,
+                             mem_addr as *mut u8,
+                             mem::size_of::<__m256>());
+}
+
+/// Loads four `f64` values from memory into a new vector.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_loadu_pd(mem_addr: *const f64) -> __m256d {
+    let mut dst = _mm256_setzero_pd();
+    ptr::copy_nonoverlapping(mem_addr as *const u8,
+                             &mut dst as *mut __m256d as *mut u8,
+                             mem::size_of::<__m256d>());
+    dst
+}
+
+/// Stores the four `f64` values of `a` into memory.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_storeu_pd(mem_addr: *mut f64, a: __m256d) {
+    ptr::copy_nonoverlapping(&a as *const __m256d as *const u8,
+                             mem_addr as *mut u8,
+                             mem::size_of::<__m256d>());
+}
+
+/// Loads 256 bits of integer data from memory into a new vector.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_loadu_si256(mem_addr: *const __m256i) -> __m256i {
+    let mut dst = _mm256_setzero_si256();
+    ptr::copy_nonoverlapping(mem_addr as *const u8,
+                             &mut dst as *mut __m256i as *mut u8,
+                             mem::size_of::<__m256i>());
+    dst
+}
+
+/// Stores 256 bits of integer data from `a` into memory.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_storeu_si256(mem_addr: *mut __m256i, a: __m256i) {
+    ptr::copy_nonoverlapping(&a as *const __m256i as *const u8,
+                             mem_addr as *mut u8,
+                             mem::size_of::<__m256i>());
+}
+
+/// Adds packed single-precision elements in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_add_ps(a: __m256, b: __m256) -> __m256 {
+    transmute(simd_add(a.as_f32x8(), b.as_f32x8()))
+}
+
+/// Subtracts packed single-precision elements in `b` from `a`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_sub_ps(a: __m256, b: __m256) -> __m256 {
+    transmute(simd_sub(a.as_f32x8(), b.as_f32x8()))
+}
+
+/// Multiplies packed single-precision elements in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_mul_ps(a: __m256, b: __m256) -> __m256 {
+    transmute(simd_mul(a.as_f32x8(), b.as_f32x8()))
+}
+
+/// Divides packed single-precision elements in `a` by those in `b`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_div_ps(a: __m256, b: __m256) -> __m256 {
+    transmute(simd_div(a.as_f32x8(), b.as_f32x8()))
+}
+
+/// Adds packed double-precision elements in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_add_pd(a: __m256d, b: __m256d) -> __m256d {
+    transmute(simd_add(a.as_f64x4(), b.as_f64x4()))
+}
+
+/// Subtracts packed double-precision elements in `b` from `a`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_sub_pd(a: __m256d, b: __m256d) -> __m256d {
+    transmute(simd_sub(a.as_f64x4(), b.as_f64x4()))
+}
+
+/// Multiplies packed double-precision elements in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_mul_pd(a: __m256d, b: __m256d) -> __m256d {
+    transmute(simd_mul(a.as_f64x4(), b.as_f64x4()))
+}
+
+/// Divides packed double-precision elements in `a` by those in `b`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_div_pd(a: __m256d, b: __m256d) -> __m256d {
+    transmute(simd_div(a.as_f64x4(), b.as_f64x4()))
+}
+
+/// Computes the bitwise AND of packed single-precision elements in `a` and
+/// `b`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_and_ps(a: __m256, b: __m256) -> __m256 {
+    transmute(simd_and(a.as_u32x8(), b.as_u32x8()))
+}
+
+/// Computes the bitwise OR of packed single-precision elements in `a` and
+/// `b`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_or_ps(a: __m256, b: __m256) -> __m256 {
+    transmute(simd_or(a.as_u32x8(), b.as_u32x8()))
+}
+
+/// Computes the bitwise XOR of packed single-precision elements in `a` and
+/// `b`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_xor_ps(a: __m256, b: __m256) -> __m256 {
+    transmute(simd_xor(a.as_u32x8(), b.as_u32x8()))
+}
+
+/// Returns the packed maximum of single-precision elements in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_max_ps(a: __m256, b: __m256) -> __m256 {
+    transmute(x86_mm256_max_ps(a.as_f32x8(), b.as_f32x8()))
+}
+
+/// Returns the packed minimum of single-precision elements in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_min_ps(a: __m256, b: __m256) -> __m256 {
+    transmute(x86_mm256_min_ps(a.as_f32x8(), b.as_f32x8()))
+}
+
+/// Returns the square roots of the single-precision elements in `a`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_sqrt_ps(a: __m256) -> __m256 {
+    transmute(x86_mm256_sqrt_ps(a.as_f32x8()))
+}
+
+/// Horizontally adds adjacent pairs of single-precision elements in `a` and
+/// `b`, within each 128-bit half.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_hadd_ps(a: __m256, b: __m256) -> __m256 {
+    transmute(x86_mm256_hadd_ps(a.as_f32x8(), b.as_f32x8()))
+}
+
+/// Returns a mask of the sign bit of each single-precision element in `a`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_movemask_ps(a: __m256) -> i32 {
+    x86_mm256_movemask_ps(a.as_f32x8())
+}
+
+/// Returns the packed maximum of double-precision elements in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_max_pd(a: __m256d, b: __m256d) -> __m256d {
+    transmute(x86_mm256_max_pd(a.as_f64x4(), b.as_f64x4()))
+}
+
+/// Returns the packed minimum of double-precision elements in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_min_pd(a: __m256d, b: __m256d) -> __m256d {
+    transmute(x86_mm256_min_pd(a.as_f64x4(), b.as_f64x4()))
+}
+
+/// Returns the square roots of the double-precision elements in `a`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_sqrt_pd(a: __m256d) -> __m256d {
+    transmute(x86_mm256_sqrt_pd(a.as_f64x4()))
+}
+
+/// Returns a mask of the sign bit of each double-precision element in `a`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_movemask_pd(a: __m256d) -> i32 {
+    x86_mm256_movemask_pd(a.as_f64x4())
+}
+
+/// Returns `1` if the bitwise AND of `a` and `b` is zero, and `0` otherwise.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_testz_si256(a: __m256i, b: __m256i) -> i32 {
+    x86_mm256_testz_si256(a.as_u64x4(), b.as_u64x4())
+}
+
+/// Zeroes the upper 128 bits of all YMM registers.
+///
+/// This avoids the penalty of transitioning from AVX to legacy SSE code.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_zeroupper() {
+    x86_mm256_zeroupper()
+}
+
+/// Reinterprets `a` as an integer vector.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_castps_si256(a: __m256) -> __m256i {
+    transmute(a)
+}
+
+/// Reinterprets `a` as a vector of eight `f32` values.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_castsi256_ps(a: __m256i) -> __m256 {
+    transmute(a)
+}
+
+/// Reinterprets `a` as an integer vector.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_castpd_si256(a: __m256d) -> __m256i {
+    transmute(a)
+}
+
+/// Reinterprets `a` as a vector of four `f64` values.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_castsi256_pd(a: __m256i) -> __m256d {
+    transmute(a)
+}
+
+/// Returns the lower 128 bits of `a`.
+#[inline]
+#[target_feature(enable = "avx")]
+pub unsafe fn _mm256_castsi256_si128(a: __m256i) -> __m128i {
+    transmute::<i64x2, _>(simd_shuffle2(a.as_i64x4(), a.as_i64x4(), [0, 1]))
+}
@@ -0,0 +1,271 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Advanced Vector Extensions 2 (AVX2)
+//!
+//! Most of these are the 256-bit integer counterparts of the SSE2
+//! intrinsics. Note that the byte shuffles and horizontal operations work
+//! within each 128-bit half of their operands.
+
+use mem::transmute;
+
+use super::*;
+use super::super::simd::*;
+
+extern "platform-intrinsic" {
+    fn x86_mm256_abs_epi8(a: i8x32) -> i8x32;
+    fn x86_mm256_abs_epi16(a: i16x16) -> i16x16;
+    fn x86_mm256_abs_epi32(a: i32x8) -> i32x8;
+    fn x86_mm256_adds_epu8(a: u8x32, b: u8x32) -> u8x32;
+    fn x86_mm256_subs_epu8(a: u8x32, b: u8x32) -> u8x32;
+    fn x86_mm256_max_epu8(a: u8x32, b: u8x32) -> u8x32;
+    fn x86_mm256_min_epu8(a: u8x32, b: u8x32) -> u8x32;
+    fn x86_mm256_max_epi32(a: i32x8, b: i32x8) -> i32x8;
+    fn x86_mm256_min_epi32(a: i32x8, b: i32x8) -> i32x8;
+    fn x86_mm256_movemask_epi8(a: i8x32) -> i32;
+    fn x86_mm256_shuffle_epi8(a: i8x32, b: i8x32) -> i8x32;
+    fn x86_mm256_permutevar8x32_epi32(a: i32x8, b: i32x8) -> i32x8;
+}
+
+/// Adds packed 8-bit integers in `a` and `b`, wrapping around on overflow.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_add_epi8(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_add(a.as_i8x32(), b.as_i8x32()))
+}
+
+/// Adds packed 16-bit integers in `a` and `b`, wrapping around on overflow.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_add_epi16(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_add(a.as_i16x16(), b.as_i16x16()))
+}
+
+/// Adds packed 32-bit integers in `a` and `b`, wrapping around on overflow.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_add_epi32(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_add(a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Adds packed 64-bit integers in `a` and `b`, wrapping around on overflow.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_add_epi64(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_add(a.as_i64x4(), b.as_i64x4()))
+}
+
+/// Adds packed unsigned 8-bit integers in `a` and `b` using saturation.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_adds_epu8(a: __m256i, b: __m256i) -> __m256i {
+    transmute(x86_mm256_adds_epu8(a.as_u8x32(), b.as_u8x32()))
+}
+
+/// Subtracts packed 8-bit integers in `b` from `a`, wrapping around on
+/// overflow.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_sub_epi8(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_sub(a.as_i8x32(), b.as_i8x32()))
+}
+
+/// Subtracts packed 16-bit integers in `b` from `a`, wrapping around on
+/// overflow.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_sub_epi16(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_sub(a.as_i16x16(), b.as_i16x16()))
+}
+
+/// Subtracts packed 32-bit integers in `b` from `a`, wrapping around on
+/// overflow.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_sub_epi32(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_sub(a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Subtracts packed 64-bit integers in `b` from `a`, wrapping around on
+/// overflow.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_sub_epi64(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_sub(a.as_i64x4(), b.as_i64x4()))
+}
+
+/// Subtracts packed unsigned 8-bit integers in `b` from `a` using
+/// saturation.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_subs_epu8(a: __m256i, b: __m256i) -> __m256i {
+    transmute(x86_mm256_subs_epu8(a.as_u8x32(), b.as_u8x32()))
+}
+
+/// Multiplies packed 32-bit integers in `a` and `b`, keeping the low 32 bits
+/// of each product.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_mullo_epi32(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_mul(a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Computes the bitwise AND of 256 bits in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_and_si256(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_and(a.as_u64x4(), b.as_u64x4()))
+}
+
+/// Computes the bitwise NOT of 256 bits in `a` and then AND with `b`.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_andnot_si256(a: __m256i, b: __m256i) -> __m256i {
+    let not_a = simd_xor(a.as_u64x4(), u64x4::splat(!0));
+    transmute(simd_and(not_a, b.as_u64x4()))
+}
+
+/// Computes the bitwise OR of 256 bits in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_or_si256(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_or(a.as_u64x4(), b.as_u64x4()))
+}
+
+/// Computes the bitwise XOR of 256 bits in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_xor_si256(a: __m256i, b: __m256i) -> __m256i {
+    transmute(simd_xor(a.as_u64x4(), b.as_u64x4()))
+}
+
+/// Compares packed 8-bit integers in `a` and `b` for equality.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_cmpeq_epi8(a: __m256i, b: __m256i) -> __m256i {
+    transmute::<i8x32, _>(simd_eq(a.as_i8x32(), b.as_i8x32()))
+}
+
+/// Compares packed 32-bit integers in `a` and `b` for equality.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_cmpeq_epi32(a: __m256i, b: __m256i) -> __m256i {
+    transmute::<i32x8, _>(simd_eq(a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Compares packed 8-bit integers in `a` and `b` for greater-than.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_cmpgt_epi8(a: __m256i, b: __m256i) -> __m256i {
+    transmute::<i8x32, _>(simd_gt(a.as_i8x32(), b.as_i8x32()))
+}
+
+/// Compares packed 32-bit integers in `a` and `b` for greater-than.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_cmpgt_epi32(a: __m256i, b: __m256i) -> __m256i {
+    transmute::<i32x8, _>(simd_gt(a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Returns a mask of the most significant bit of each 8-bit element in `a`.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_movemask_epi8(a: __m256i) -> i32 {
+    x86_mm256_movemask_epi8(a.as_i8x32())
+}
+
+/// Computes the absolute values of packed 8-bit integers in `a`.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_abs_epi8(a: __m256i) -> __m256i {
+    transmute(x86_mm256_abs_epi8(a.as_i8x32()))
+}
+
+/// Computes the absolute values of packed 16-bit integers in `a`.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_abs_epi16(a: __m256i) -> __m256i {
+    transmute(x86_mm256_abs_epi16(a.as_i16x16()))
+}
+
+/// Computes the absolute values of packed 32-bit integers in `a`.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_abs_epi32(a: __m256i) -> __m256i {
+    transmute(x86_mm256_abs_epi32(a.as_i32x8()))
+}
+
+/// Returns the packed maximum of unsigned 8-bit integers in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_max_epu8(a: __m256i, b: __m256i) -> __m256i {
+    transmute(x86_mm256_max_epu8(a.as_u8x32(), b.as_u8x32()))
+}
+
+/// Returns the packed minimum of unsigned 8-bit integers in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_min_epu8(a: __m256i, b: __m256i) -> __m256i {
+    transmute(x86_mm256_min_epu8(a.as_u8x32(), b.as_u8x32()))
+}
+
+/// Returns the packed maximum of 32-bit integers in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_max_epi32(a: __m256i, b: __m256i) -> __m256i {
+    transmute(x86_mm256_max_epi32(a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Returns the packed minimum of 32-bit integers in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_min_epi32(a: __m256i, b: __m256i) -> __m256i {
+    transmute(x86_mm256_min_epi32(a.as_i32x8(), b.as_i32x8()))
+}
+
+/// Shuffles the bytes of `a` according to the indices in `b`, within each
+/// 128-bit half.
+///
+/// A result byte is zero if the most significant bit of its index is set.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_shuffle_epi8(a: __m256i, b: __m256i) -> __m256i {
+    transmute(x86_mm256_shuffle_epi8(a.as_i8x32(), b.as_i8x32()))
+}
+
+/// Permutes the 32-bit integers of `a` across both halves according to the
+/// indices in `idx`.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_permutevar8x32_epi32(a: __m256i, idx: __m256i) -> __m256i {
+    transmute(x86_mm256_permutevar8x32_epi32(a.as_i32x8(), idx.as_i32x8()))
+}
+
+/// Shifts packed 32-bit integers in `a` left by `imm8` while shifting in
+/// zeros.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_slli_epi32(a: __m256i, imm8: i32) -> __m256i {
+    if imm8 as u32 > 31 {
+        return _mm256_setzero_si256();
+    }
+    transmute(simd_shl(a.as_u32x8(), u32x8::splat(imm8 as u32)))
+}
+
+/// Shifts packed 32-bit integers in `a` right by `imm8` while shifting in
+/// zeros.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub unsafe fn _mm256_srli_epi32(a: __m256i, imm8: i32) -> __m256i {
+    if imm8 as u32 > 31 {
+        return _mm256_setzero_si256();
+    }
+    transmute(simd_shr(a.as_u32x8(), u32x8::splat(imm8 as u32)))
+}
@@ -0,0 +1,137 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `cpuid` and `xgetbv`, the building blocks of runtime feature detection.
+
+/// The registers returned by the `cpuid` instruction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CpuidResult {
+    /// EAX register.
+    pub eax: u32,
+    /// EBX register.
+    pub ebx: u32,
+    /// ECX register.
+    pub ecx: u32,
+    /// EDX register.
+    pub edx: u32,
+}
+
+/// Returns the result of the `cpuid` instruction for the given `leaf`
+/// (EAX) and `sub_leaf` (ECX).
+///
+/// The highest supported leaf is returned in EAX by `__cpuid(0)`, and the
+/// highest supported extended leaf by `__cpuid(0x8000_0000)`. Querying a
+/// leaf above these returns unspecified data.
+///
+/// On 32-bit x86 the `cpuid` instruction itself may be missing, which
+/// `has_cpuid` checks for.
+#[inline]
+pub unsafe fn __cpuid_count(leaf: u32, sub_leaf: u32) -> CpuidResult {
+    // LLVM may reserve `rbx` as the base pointer on x86_64, and `ebx` is the
+    // PIC register on x86, so neither can be an operand. Save it in another
+    // register around `cpuid` and swap the result out afterwards.
+    #[cfg(target_arch = "x86_64")]
+    unsafe fn imp(leaf: u32, sub_leaf: u32) -> CpuidResult {
+        let eax: u32;
+        let ebx: u32;
+        let ecx: u32;
+        let edx: u32;
+        asm!("mov {0:r}, rbx",
+             "cpuid",
+             "xchg {0:r}, rbx",
+             out(reg) ebx,
+             inout("eax") leaf => eax,
+             inout("ecx") sub_leaf => ecx,
+             out("edx") edx,
+             options(nomem, nostack));
+        CpuidResult { eax: eax, ebx: ebx, ecx: ecx, edx: edx }
+    }
+
+    #[cfg(target_arch = "x86")]
+    unsafe fn imp(leaf: u32, sub_leaf: u32) -> CpuidResult {
+        let eax: u32;
+        let ebx: u32;
+        let ecx: u32;
+        let edx: u32;
+        asm!("mov {0:e}, ebx",
+             "cpuid",
+             "xchg {0:e}, ebx",
+             out(reg) ebx,
+             inout("eax") leaf => eax,
+             inout("ecx") sub_leaf => ecx,
+             out("edx") edx,
+             options(nomem, nostack));
+        CpuidResult { eax: eax, ebx: ebx, ecx: ecx, edx: edx }
+    }
+
+    imp(leaf, sub_leaf)
+}
+
+/// Returns the result of the `cpuid` instruction for the given `leaf`,
+/// with a sub-leaf of zero.
+#[inline]
+pub unsafe fn __cpuid(leaf: u32) -> CpuidResult {
+    __cpuid_count(leaf, 0)
+}
+
+/// Returns whether the `cpuid` instruction is available.
+///
+/// It always is on `x86_64`. On `x86` this checks whether the ID bit of
+/// EFLAGS can be toggled, which is how pre-Pentium CPUs are told apart.
+#[inline]
+pub fn has_cpuid() -> bool {
+    #[cfg(target_arch = "x86_64")]
+    fn imp() -> bool {
+        true
+    }
+
+    #[cfg(target_arch = "x86")]
+    fn imp() -> bool {
+        let changed: u32;
+        unsafe {
+            asm!("pushfd",
+                 "pop eax",
+                 "mov ecx, eax",
+                 "xor eax, 0x200000",
+                 "push eax",
+                 "popfd",
+                 "pushfd",
+                 "pop eax",
+                 "xor eax, ecx",
+                 "push ecx",
+                 "popfd",
+                 out("eax") changed,
+                 out("ecx") _);
+        }
+        changed != 0
+    }
+
+    imp()
+}
+
+/// The extended control register holding the state components (x87, SSE,
+/// AVX, ...) which the operating system saves and restores.
+pub const _XCR_XFEATURE_ENABLED_MASK: u32 = 0;
+
+/// Reads the contents of the extended control register `xcr`.
+///
+/// The caller has to make sure the OS enabled `xgetbv`, which is the case
+/// when `cpuid` leaf 1 reports OSXSAVE (bit 27 of ECX).
+#[inline]
+pub unsafe fn _xgetbv(xcr: u32) -> u64 {
+    let eax: u32;
+    let edx: u32;
+    asm!("xgetbv",
+         out("eax") eax,
+         out("edx") edx,
+         in("ecx") xcr,
+         options(nomem, nostack));
+    ((edx as u64) << 32) | eax as u64
+}
@@ -0,0 +1,123 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Intrinsics and vector types shared by `x86` and `x86_64`.
+
+#![allow(non_camel_case_types)]
+
+use clone::Clone;
+use fmt;
+use marker::Copy;
+use mem::transmute;
+
+use super::simd::*;
+
+pub use self::cpuid::*;
+pub use self::sse2::*;
+pub use self::sse42::*;
+pub use self::avx::*;
+pub use self::avx2::*;
+
+mod cpuid;
+mod sse2;
+mod sse42;
+mod avx;
+mod avx2;
+
+/// 128-bit wide set of four `f32` values, x86-specific.
+///
+/// This is the `__m128` type of Intel's documentation. It is opaque: rather
+/// than reading its lanes directly, use the intrinsics or `mem::transmute` it
+/// to an `[f32; 4]`.
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct __m128(f32, f32, f32, f32);
+
+/// 128-bit wide set of two `f64` values, x86-specific.
+///
+/// This is the `__m128d` type of Intel's documentation.
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct __m128d(f64, f64);
+
+/// 128-bit wide integer vector, x86-specific.
+///
+/// This is the `__m128i` type of Intel's documentation. The intrinsics decide
+/// how the bits are split into lanes, e.g. `_mm_add_epi8` treats it as
+/// sixteen 8-bit integers while `_mm_add_epi32` treats it as four 32-bit ones.
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct __m128i(i64, i64);
+
+/// 256-bit wide set of eight `f32` values, x86-specific.
+///
+/// This is the `__m256` type of Intel's documentation.
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct __m256(f32, f32, f32, f32, f32, f32, f32, f32);
+
+/// 256-bit wide set of four `f64` values, x86-specific.
+///
+/// This is the `__m256d` type of Intel's documentation.
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct __m256d(f64, f64, f64, f64);
+
+/// 256-bit wide integer vector, x86-specific.
+///
+/// This is the `__m256i` type of Intel's documentation. As with `__m128i`,
+/// the intrinsics decide how its bits are split into lanes.
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct __m256i(i64, i64, i64, i64);
+
+macro_rules! vector_debug {
+    ($($ty:ident: [$elem:ty; $n:expr]),*) => {$(
+        impl fmt::Debug for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let lanes: [$elem; $n] = unsafe { transmute(*self) };
+                f.debug_tuple(stringify!($ty)).field(&lanes).finish()
+            }
+        }
+    )*}
+}
+
+vector_debug! {
+    __m128: [f32; 4], __m128d: [f64; 2], __m128i: [i64; 2],
+    __m256: [f32; 8], __m256d: [f64; 4], __m256i: [i64; 4]
+}
+
+/// Reinterprets the vendor types as the internal vector types.
+macro_rules! as_simd {
+    ($ty:ident { $($name:ident -> $simd:ident),* }) => {
+        #[allow(dead_code)]
+        impl $ty {
+            $(
+                #[inline(always)]
+                fn $name(self) -> $simd {
+                    unsafe { transmute(self) }
+                }
+            )*
+        }
+    }
+}
+
+as_simd!(__m128 { as_f32x4 -> f32x4, as_u32x4 -> u32x4 });
+as_simd!(__m128d { as_f64x2 -> f64x2, as_u64x2 -> u64x2 });
+as_simd!(__m128i {
+    as_i8x16 -> i8x16, as_u8x16 -> u8x16, as_i16x8 -> i16x8, as_u16x8 -> u16x8,
+    as_i32x4 -> i32x4, as_u32x4 -> u32x4, as_i64x2 -> i64x2, as_u64x2 -> u64x2
+});
+as_simd!(__m256 { as_f32x8 -> f32x8, as_u32x8 -> u32x8 });
+as_simd!(__m256d { as_f64x4 -> f64x4, as_u64x4 -> u64x4 });
+as_simd!(__m256i {
+    as_i8x32 -> i8x32, as_u8x32 -> u8x32, as_i16x16 -> i16x16, as_i32x8 -> i32x8,
+    as_u32x8 -> u32x8, as_i64x4 -> i64x4, as_u64x4 -> u64x4
+});
@@ -0,0 +1,509 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Streaming SIMD Extensions 2 (SSE2)
+
+use mem::{self, transmute};
+use ptr;
+
+use super::*;
+use super::super::simd::*;
+
+extern "platform-intrinsic" {
+    fn x86_mm_adds_epu8(a: u8x16, b: u8x16) -> u8x16;
+    fn x86_mm_subs_epu8(a: u8x16, b: u8x16) -> u8x16;
+    fn x86_mm_max_epu8(a: u8x16, b: u8x16) -> u8x16;
+    fn x86_mm_min_epu8(a: u8x16, b: u8x16) -> u8x16;
+    fn x86_mm_max_epi16(a: i16x8, b: i16x8) -> i16x8;
+    fn x86_mm_min_epi16(a: i16x8, b: i16x8) -> i16x8;
+    fn x86_mm_movemask_epi8(a: i8x16) -> i32;
+    fn x86_mm_max_pd(a: f64x2, b: f64x2) -> f64x2;
+    fn x86_mm_min_pd(a: f64x2, b: f64x2) -> f64x2;
+    fn x86_mm_sqrt_pd(a: f64x2) -> f64x2;
+}
+
+/// Returns a vector of type `__m128i` with all elements set to zero.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_setzero_si128() -> __m128i {
+    _mm_set1_epi64x(0)
+}
+
+/// Broadcasts 8-bit integer `a` to all elements.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_set1_epi8(a: i8) -> __m128i {
+    transmute(i8x16::splat(a))
+}
+
+/// Broadcasts 16-bit integer `a` to all elements.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_set1_epi16(a: i16) -> __m128i {
+    transmute(i16x8::splat(a))
+}
+
+/// Broadcasts 32-bit integer `a` to all elements.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_set1_epi32(a: i32) -> __m128i {
+    transmute(i32x4::splat(a))
+}
+
+/// Broadcasts 64-bit integer `a` to all elements.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_set1_epi64x(a: i64) -> __m128i {
+    transmute(i64x2::splat(a))
+}
+
+/// Sets packed 32-bit integers with the supplied values, from highest to
+/// lowest.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_set_epi32(e3: i32, e2: i32, e1: i32, e0: i32) -> __m128i {
+    transmute(i32x4::new(e0, e1, e2, e3))
+}
+
+/// Sets packed 32-bit integers with the supplied values in reverse order,
+/// i.e. from lowest to highest.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_setr_epi32(e3: i32, e2: i32, e1: i32, e0: i32) -> __m128i {
+    _mm_set_epi32(e0, e1, e2, e3)
+}
+
+/// Sets packed 64-bit integers with the supplied values, from highest to
+/// lowest.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_set_epi64x(e1: i64, e0: i64) -> __m128i {
+    transmute(i64x2::new(e0, e1))
+}
+
+/// Loads 128 bits of integer data from memory into a new vector.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_loadu_si128(mem_addr: *const __m128i) -> __m128i {
+    let mut dst = _mm_setzero_si128();
+    ptr::copy_nonoverlapping(mem_addr as *const u8,
+                             &mut dst as *mut __m128i as *mut u8,
+                             mem::size_of::<__m128i>());
+    dst
+}
+
+/// Loads 128 bits of integer data from memory into a new vector.
+///
+/// `mem_addr` must be aligned on a 16-byte boundary.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_load_si128(mem_addr: *const __m128i) -> __m128i {
+    *mem_addr
+}
+
+/// Stores 128 bits of integer data from `a` into memory.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_storeu_si128(mem_addr: *mut __m128i, a: __m128i) {
+    ptr::copy_nonoverlapping(&a as *const __m128i as *const u8,
+                             mem_addr as *mut u8,
+                             mem::size_of::<__m128i>());
+}
+
+/// Stores 128 bits of integer data from `a` into memory.
+///
+/// `mem_addr` must be aligned on a 16-byte boundary.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_store_si128(mem_addr: *mut __m128i, a: __m128i) {
+    *mem_addr = a;
+}
+
+/// Adds packed 8-bit integers in `a` and `b`, wrapping around on overflow.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_add_epi8(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_add(a.as_i8x16(), b.as_i8x16()))
+}
+
+/// Adds packed 16-bit integers in `a` and `b`, wrapping around on overflow.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_add_epi16(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_add(a.as_i16x8(), b.as_i16x8()))
+}
+
+/// Adds packed 32-bit integers in `a` and `b`, wrapping around on overflow.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_add_epi32(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_add(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Adds packed 64-bit integers in `a` and `b`, wrapping around on overflow.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_add_epi64(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_add(a.as_i64x2(), b.as_i64x2()))
+}
+
+/// Adds packed unsigned 8-bit integers in `a` and `b` using saturation.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_adds_epu8(a: __m128i, b: __m128i) -> __m128i {
+    transmute(x86_mm_adds_epu8(a.as_u8x16(), b.as_u8x16()))
+}
+
+/// Subtracts packed 8-bit integers in `b` from `a`, wrapping around on
+/// overflow.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_sub_epi8(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_sub(a.as_i8x16(), b.as_i8x16()))
+}
+
+/// Subtracts packed 16-bit integers in `b` from `a`, wrapping around on
+/// overflow.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_sub_epi16(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_sub(a.as_i16x8(), b.as_i16x8()))
+}
+
+/// Subtracts packed 32-bit integers in `b` from `a`, wrapping around on
+/// overflow.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_sub_epi32(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_sub(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Subtracts packed 64-bit integers in `b` from `a`, wrapping around on
+/// overflow.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_sub_epi64(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_sub(a.as_i64x2(), b.as_i64x2()))
+}
+
+/// Subtracts packed unsigned 8-bit integers in `b` from `a` using
+/// saturation.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_subs_epu8(a: __m128i, b: __m128i) -> __m128i {
+    transmute(x86_mm_subs_epu8(a.as_u8x16(), b.as_u8x16()))
+}
+
+/// Multiplies packed 16-bit integers in `a` and `b`, keeping the low 16 bits
+/// of each product.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_mullo_epi16(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_mul(a.as_i16x8(), b.as_i16x8()))
+}
+
+/// Returns the packed maximum of unsigned 8-bit integers in `a` and `b`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_max_epu8(a: __m128i, b: __m128i) -> __m128i {
+    transmute(x86_mm_max_epu8(a.as_u8x16(), b.as_u8x16()))
+}
+
+/// Returns the packed minimum of unsigned 8-bit integers in `a` and `b`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_min_epu8(a: __m128i, b: __m128i) -> __m128i {
+    transmute(x86_mm_min_epu8(a.as_u8x16(), b.as_u8x16()))
+}
+
+/// Returns the packed maximum of 16-bit integers in `a` and `b`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_max_epi16(a: __m128i, b: __m128i) -> __m128i {
+    transmute(x86_mm_max_epi16(a.as_i16x8(), b.as_i16x8()))
+}
+
+/// Returns the packed minimum of 16-bit integers in `a` and `b`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_min_epi16(a: __m128i, b: __m128i) -> __m128i {
+    transmute(x86_mm_min_epi16(a.as_i16x8(), b.as_i16x8()))
+}
+
+/// Computes the bitwise AND of 128 bits in `a` and `b`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_and_si128(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_and(a.as_u64x2(), b.as_u64x2()))
+}
+
+/// Computes the bitwise NOT of 128 bits in `a` and then AND with `b`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_andnot_si128(a: __m128i, b: __m128i) -> __m128i {
+    let not_a = simd_xor(a.as_u64x2(), u64x2::splat(!0));
+    transmute(simd_and(not_a, b.as_u64x2()))
+}
+
+/// Computes the bitwise OR of 128 bits in `a` and `b`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_or_si128(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_or(a.as_u64x2(), b.as_u64x2()))
+}
+
+/// Computes the bitwise XOR of 128 bits in `a` and `b`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_xor_si128(a: __m128i, b: __m128i) -> __m128i {
+    transmute(simd_xor(a.as_u64x2(), b.as_u64x2()))
+}
+
+/// Shifts packed 32-bit integers in `a` left by `imm8` while shifting in
+/// zeros.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_slli_epi32(a: __m128i, imm8: i32) -> __m128i {
+    // Unlike LLVM's `shl`, the instruction is defined for any count.
+    if imm8 as u32 > 31 {
+        return _mm_setzero_si128();
+    }
+    transmute(simd_shl(a.as_u32x4(), u32x4::splat(imm8 as u32)))
+}
+
+/// Shifts packed 32-bit integers in `a` right by `imm8` while shifting in
+/// zeros.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_srli_epi32(a: __m128i, imm8: i32) -> __m128i {
+    if imm8 as u32 > 31 {
+        return _mm_setzero_si128();
+    }
+    transmute(simd_shr(a.as_u32x4(), u32x4::splat(imm8 as u32)))
+}
+
+/// Compares packed 8-bit integers in `a` and `b` for equality.
+///
+/// Each lane of the result is all ones if the lanes are equal, and zero
+/// otherwise.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_cmpeq_epi8(a: __m128i, b: __m128i) -> __m128i {
+    transmute::<i8x16, _>(simd_eq(a.as_i8x16(), b.as_i8x16()))
+}
+
+/// Compares packed 16-bit integers in `a` and `b` for equality.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_cmpeq_epi16(a: __m128i, b: __m128i) -> __m128i {
+    transmute::<i16x8, _>(simd_eq(a.as_i16x8(), b.as_i16x8()))
+}
+
+/// Compares packed 32-bit integers in `a` and `b` for equality.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_cmpeq_epi32(a: __m128i, b: __m128i) -> __m128i {
+    transmute::<i32x4, _>(simd_eq(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Compares packed 8-bit integers in `a` and `b` for greater-than.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_cmpgt_epi8(a: __m128i, b: __m128i) -> __m128i {
+    transmute::<i8x16, _>(simd_gt(a.as_i8x16(), b.as_i8x16()))
+}
+
+/// Compares packed 16-bit integers in `a` and `b` for greater-than.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_cmpgt_epi16(a: __m128i, b: __m128i) -> __m128i {
+    transmute::<i16x8, _>(simd_gt(a.as_i16x8(), b.as_i16x8()))
+}
+
+/// Compares packed 32-bit integers in `a` and `b` for greater-than.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_cmpgt_epi32(a: __m128i, b: __m128i) -> __m128i {
+    transmute::<i32x4, _>(simd_gt(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Compares packed 8-bit integers in `a` and `b` for less-than.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_cmplt_epi8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_cmpgt_epi8(b, a)
+}
+
+/// Returns a mask of the most significant bit of each 8-bit element in `a`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_movemask_epi8(a: __m128i) -> i32 {
+    x86_mm_movemask_epi8(a.as_i8x16())
+}
+
+/// Interleaves the 8-bit integers from the low halves of `a` and `b`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_unpacklo_epi8(a: __m128i, b: __m128i) -> __m128i {
+    transmute::<i8x16, _>(simd_shuffle16(a.as_i8x16(), b.as_i8x16(),
+                                         [0, 16, 1, 17, 2, 18, 3, 19,
+                                          4, 20, 5, 21, 6, 22, 7, 23]))
+}
+
+/// Interleaves the 8-bit integers from the high halves of `a` and `b`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_unpackhi_epi8(a: __m128i, b: __m128i) -> __m128i {
+    transmute::<i8x16, _>(simd_shuffle16(a.as_i8x16(), b.as_i8x16(),
+                                         [8, 24, 9, 25, 10, 26, 11, 27,
+                                          12, 28, 13, 29, 14, 30, 15, 31]))
+}
+
+/// Returns a vector whose lowest element is `a` and all higher elements are
+/// zero.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_cvtsi32_si128(a: i32) -> __m128i {
+    transmute(i32x4::new(a, 0, 0, 0))
+}
+
+/// Returns the lowest element of `a`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_cvtsi128_si32(a: __m128i) -> i32 {
+    simd_extract(a.as_i32x4(), 0)
+}
+
+/// Returns a vector of type `__m128d` with all elements set to zero.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_setzero_pd() -> __m128d {
+    _mm_set1_pd(0.0)
+}
+
+/// Broadcasts `a` to all elements.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_set1_pd(a: f64) -> __m128d {
+    transmute(f64x2::splat(a))
+}
+
+/// Sets packed double-precision elements with the supplied values, from
+/// highest to lowest.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_set_pd(e1: f64, e0: f64) -> __m128d {
+    transmute(f64x2::new(e0, e1))
+}
+
+/// Loads two `f64` values from memory into a new vector.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_loadu_pd(mem_addr: *const f64) -> __m128d {
+    let mut dst = _mm_setzero_pd();
+    ptr::copy_nonoverlapping(mem_addr as *const u8,
+                             &mut dst as *mut __m128d as *mut u8,
+                             mem::size_of::<__m128d>());
+    dst
+}
+
+/// Stores the two `f64` values of `a` into memory.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_storeu_pd(mem_addr: *mut f64, a: __m128d) {
+    ptr::copy_nonoverlapping(&a as *const __m128d as *const u8,
+                             mem_addr as *mut u8,
+                             mem::size_of::<__m128d>());
+}
+
+/// Adds packed double-precision elements in `a` and `b`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_add_pd(a: __m128d, b: __m128d) -> __m128d {
+    transmute(simd_add(a.as_f64x2(), b.as_f64x2()))
+}
+
+/// Subtracts packed double-precision elements in `b` from `a`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_sub_pd(a: __m128d, b: __m128d) -> __m128d {
+    transmute(simd_sub(a.as_f64x2(), b.as_f64x2()))
+}
+
+/// Multiplies packed double-precision elements in `a` and `b`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_mul_pd(a: __m128d, b: __m128d) -> __m128d {
+    transmute(simd_mul(a.as_f64x2(), b.as_f64x2()))
+}
+
+/// Divides packed double-precision elements in `a` by those in `b`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_div_pd(a: __m128d, b: __m128d) -> __m128d {
+    transmute(simd_div(a.as_f64x2(), b.as_f64x2()))
+}
+
+/// Returns the packed maximum of double-precision elements in `a` and `b`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_max_pd(a: __m128d, b: __m128d) -> __m128d {
+    transmute(x86_mm_max_pd(a.as_f64x2(), b.as_f64x2()))
+}
+
+/// Returns the packed minimum of double-precision elements in `a` and `b`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_min_pd(a: __m128d, b: __m128d) -> __m128d {
+    transmute(x86_mm_min_pd(a.as_f64x2(), b.as_f64x2()))
+}
+
+/// Returns the square roots of the double-precision elements in `a`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_sqrt_pd(a: __m128d) -> __m128d {
+    transmute(x86_mm_sqrt_pd(a.as_f64x2()))
+}
+
+/// Reinterprets `a` as a vector of four `f32` values.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_castsi128_ps(a: __m128i) -> __m128 {
+    transmute(a)
+}
+
+/// Reinterprets `a` as an integer vector.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_castps_si128(a: __m128) -> __m128i {
+    transmute(a)
+}
+
+/// Reinterprets `a` as a vector of two `f64` values.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_castsi128_pd(a: __m128i) -> __m128d {
+    transmute(a)
+}
+
+/// Reinterprets `a` as an integer vector.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_castpd_si128(a: __m128d) -> __m128i {
+    transmute(a)
+}
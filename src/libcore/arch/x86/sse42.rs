@@ -0,0 +1,192 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Streaming SIMD Extensions 4.2 (SSE4.2)
+
+use mem::transmute;
+
+use super::*;
+use super::super::simd::*;
+
+/// String contains unsigned 8-bit characters *(default)*
+pub const _SIDD_UBYTE_OPS: i32 = 0b0000_0000;
+/// String contains unsigned 16-bit characters
+pub const _SIDD_UWORD_OPS: i32 = 0b0000_0001;
+/// String contains signed 8-bit characters
+pub const _SIDD_SBYTE_OPS: i32 = 0b0000_0010;
+/// String contains signed 16-bit characters
+pub const _SIDD_SWORD_OPS: i32 = 0b0000_0011;
+
+/// For each character in `a`, find if it is in `b` *(default)*
+pub const _SIDD_CMP_EQUAL_ANY: i32 = 0b0000_0000;
+/// For each character in `a`, determine if `b[0] <= c <= b[1] or
+/// b[1] <= c <= b[2]...`
+pub const _SIDD_CMP_RANGES: i32 = 0b0000_0100;
+/// The strings defined by `a` and `b` are equal
+pub const _SIDD_CMP_EQUAL_EACH: i32 = 0b0000_1000;
+/// Search for the defined substring in the target
+pub const _SIDD_CMP_EQUAL_ORDERED: i32 = 0b0000_1100;
+
+/// Do not negate results *(default)*
+pub const _SIDD_POSITIVE_POLARITY: i32 = 0b0000_0000;
+/// Negate results
+pub const _SIDD_NEGATIVE_POLARITY: i32 = 0b0001_0000;
+/// Do not negate results before the end of the string
+pub const _SIDD_MASKED_POSITIVE_POLARITY: i32 = 0b0010_0000;
+/// Negate results only before the end of the string
+pub const _SIDD_MASKED_NEGATIVE_POLARITY: i32 = 0b0011_0000;
+
+/// **Index only**: return the least significant bit *(default)*
+pub const _SIDD_LEAST_SIGNIFICANT: i32 = 0b0000_0000;
+/// **Index only**: return the most significant bit
+pub const _SIDD_MOST_SIGNIFICANT: i32 = 0b0100_0000;
+
+/// **Mask only**: return the bit mask *(default)*
+pub const _SIDD_BIT_MASK: i32 = 0b0000_0000;
+/// **Mask only**: return the byte mask
+pub const _SIDD_UNIT_MASK: i32 = 0b0100_0000;
+
+extern "platform-intrinsic" {
+    fn x86_mm_cmpistrm(a: i8x16, b: i8x16, imm8: i32) -> i8x16;
+    fn x86_mm_cmpistri(a: i8x16, b: i8x16, imm8: i32) -> i32;
+    fn x86_mm_cmpistrz(a: i8x16, b: i8x16, imm8: i32) -> i32;
+    fn x86_mm_cmpistrc(a: i8x16, b: i8x16, imm8: i32) -> i32;
+    fn x86_mm_cmpestri(a: i8x16, la: i32, b: i8x16, lb: i32, imm8: i32) -> i32;
+}
+
+extern {
+    #[link_name = "llvm.x86.sse42.crc32.32.8"]
+    fn crc32_32_8(crc: u32, v: u8) -> u32;
+    #[link_name = "llvm.x86.sse42.crc32.32.16"]
+    fn crc32_32_16(crc: u32, v: u16) -> u32;
+    #[link_name = "llvm.x86.sse42.crc32.32.32"]
+    fn crc32_32_32(crc: u32, v: u32) -> u32;
+}
+
+/// Compares packed strings with implicit lengths in `a` and `b` using the
+/// control in `imm8`, and returns the generated mask.
+#[inline]
+#[target_feature(enable = "sse4.2")]
+pub unsafe fn _mm_cmpistrm(a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    let a = a.as_i8x16();
+    let b = b.as_i8x16();
+    macro_rules! call {
+        ($imm8:expr) => { x86_mm_cmpistrm(a, b, $imm8) }
+    }
+    transmute(constify_imm8!(imm8, call))
+}
+
+/// Compares packed strings with implicit lengths in `a` and `b` using the
+/// control in `imm8`, and returns the generated index.
+///
+/// A string ends at its first zero character. If no match is found, the
+/// result is 16 for byte strings and 8 for word strings.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(cfg_target_feature, stdsimd)]
+///
+/// # #[cfg(all(target_arch = "x86_64", target_feature = "sse4.2"))]
+/// # fn main() {
+/// use std::arch::x86_64::*;
+///
+/// unsafe {
+///     let haystack = b"Split \r\n\t line  ";
+///     let needles = b"\r\n\t\0\0\0\0\0\0\0\0\0\0\0\0\0";
+///     let a = _mm_loadu_si128(needles.as_ptr() as *const _);
+///     let b = _mm_loadu_si128(haystack.as_ptr() as *const _);
+///     let idx = _mm_cmpistri(a, b, _SIDD_CMP_EQUAL_ANY);
+///     assert_eq!(idx, 6);
+/// }
+/// # }
+/// # #[cfg(not(all(target_arch = "x86_64", target_feature = "sse4.2")))]
+/// # fn main() {}
+/// ```
+#[inline]
+#[target_feature(enable = "sse4.2")]
+pub unsafe fn _mm_cmpistri(a: __m128i, b: __m128i, imm8: i32) -> i32 {
+    let a = a.as_i8x16();
+    let b = b.as_i8x16();
+    macro_rules! call {
+        ($imm8:expr) => { x86_mm_cmpistri(a, b, $imm8) }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Compares packed strings with implicit lengths in `a` and `b` using the
+/// control in `imm8`, and returns `1` if any character in `b` was null.
+#[inline]
+#[target_feature(enable = "sse4.2")]
+pub unsafe fn _mm_cmpistrz(a: __m128i, b: __m128i, imm8: i32) -> i32 {
+    let a = a.as_i8x16();
+    let b = b.as_i8x16();
+    macro_rules! call {
+        ($imm8:expr) => { x86_mm_cmpistrz(a, b, $imm8) }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Compares packed strings with implicit lengths in `a` and `b` using the
+/// control in `imm8`, and returns `1` if the resulting mask was non-zero.
+#[inline]
+#[target_feature(enable = "sse4.2")]
+pub unsafe fn _mm_cmpistrc(a: __m128i, b: __m128i, imm8: i32) -> i32 {
+    let a = a.as_i8x16();
+    let b = b.as_i8x16();
+    macro_rules! call {
+        ($imm8:expr) => { x86_mm_cmpistrc(a, b, $imm8) }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Compares packed strings in `a` and `b` with the explicit lengths `la` and
+/// `lb` using the control in `imm8`, and returns the generated index.
+#[inline]
+#[target_feature(enable = "sse4.2")]
+pub unsafe fn _mm_cmpestri(a: __m128i, la: i32, b: __m128i, lb: i32, imm8: i32) -> i32 {
+    let a = a.as_i8x16();
+    let b = b.as_i8x16();
+    macro_rules! call {
+        ($imm8:expr) => { x86_mm_cmpestri(a, la, b, lb, $imm8) }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Compares packed 64-bit integers in `a` and `b` for greater-than.
+#[inline]
+#[target_feature(enable = "sse4.2")]
+pub unsafe fn _mm_cmpgt_epi64(a: __m128i, b: __m128i) -> __m128i {
+    transmute::<i64x2, _>(simd_gt(a.as_i64x2(), b.as_i64x2()))
+}
+
+/// Starting with the initial value in `crc`, returns the accumulated CRC32C
+/// value for the unsigned 8-bit integer `v`.
+#[inline]
+#[target_feature(enable = "sse4.2")]
+pub unsafe fn _mm_crc32_u8(crc: u32, v: u8) -> u32 {
+    crc32_32_8(crc, v)
+}
+
+/// Starting with the initial value in `crc`, returns the accumulated CRC32C
+/// value for the unsigned 16-bit integer `v`.
+#[inline]
+#[target_feature(enable = "sse4.2")]
+pub unsafe fn _mm_crc32_u16(crc: u32, v: u16) -> u32 {
+    crc32_32_16(crc, v)
+}
+
+/// Starting with the initial value in `crc`, returns the accumulated CRC32C
+/// value for the unsigned 32-bit integer `v`.
+#[inline]
+#[target_feature(enable = "sse4.2")]
+pub unsafe fn _mm_crc32_u32(crc: u32, v: u32) -> u32 {
+    crc32_32_32(crc, v)
+}
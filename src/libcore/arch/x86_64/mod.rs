@@ -0,0 +1,45 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Intrinsics which operate on 64-bit general purpose registers, and thus
+//! only exist on `x86_64`.
+
+use mem::transmute;
+
+use super::simd::*;
+use super::x86_shared::*;
+
+extern {
+    #[link_name = "llvm.x86.sse42.crc32.64.64"]
+    fn crc32_64_64(crc: u64, v: u64) -> u64;
+}
+
+/// Returns a vector whose lowest element is `a` and all higher elements are
+/// zero.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_cvtsi64_si128(a: i64) -> __m128i {
+    transmute(i64x2::new(a, 0))
+}
+
+/// Returns the lowest element of `a`.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_cvtsi128_si64(a: __m128i) -> i64 {
+    simd_extract(transmute::<_, i64x2>(a), 0)
+}
+
+/// Starting with the initial value in `crc`, returns the accumulated CRC32C
+/// value for the unsigned 64-bit integer `v`.
+#[inline]
+#[target_feature(enable = "sse4.2")]
+pub unsafe fn _mm_crc32_u64(crc: u64, v: u64) -> u64 {
+    crc32_64_64(crc, v)
+}
@@ -28,7 +28,9 @@ use fmt;
 use hash::{Hash, self};
 use iter::IntoIterator;
 use marker::{Copy, Sized, Unsize};
+use mem;
 use option::Option;
+use ptr;
 use slice::{Iter, IterMut, SliceExt};
 
 /// Utility trait implemented only on arrays of fixed size
@@ -49,6 +51,8 @@ pub unsafe trait FixedSizeArray<T> {
     fn as_slice(&self) -> &[T];
     /// Converts the array to mutable slice
     fn as_mut_slice(&mut self) -> &mut [T];
+    /// Returns the number of elements in arrays of this type
+    fn len() -> usize where Self: Sized;
 }
 
 unsafe impl<T, A: Unsize<[T]>> FixedSizeArray<T> for A {
@@ -60,6 +64,20 @@ unsafe impl<T, A: Unsize<[T]>> FixedSizeArray<T> for A {
     fn as_mut_slice(&mut self) -> &mut [T] {
         self
     }
+    #[inline]
+    fn len() -> usize {
+        // Unsizing a pointer to the array attaches the length to it, no
+        // actual array is needed for that.
+        let array: *const A = ptr::null();
+        let slice: *const [T] = array;
+        unsafe { mem::transmute::<*const [T], Repr<T>>(slice).len }
+    }
+}
+
+#[repr(C)]
+struct Repr<T> {
+    pub data: *const T,
+    pub len: usize,
 }
 
 macro_rules! __impl_slice_eq1 {
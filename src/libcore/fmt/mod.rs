@@ -1519,7 +1519,7 @@ fn float_to_decimal_common<T>(fmt: &mut Formatter, num: &T, negative_zero: bool)
         flt2dec::to_exact_fixed_str(flt2dec::strategy::grisu::format_exact, *num, sign,
                                     precision, false, &mut buf, &mut parts)
     } else {
-        flt2dec::to_shortest_str(flt2dec::strategy::grisu::format_shortest, *num, sign,
+        flt2dec::to_shortest_str(flt2dec::strategy::ryu::format_shortest, *num, sign,
                                  0, false, &mut buf, &mut parts)
     };
     fmt.pad_formatted_parts(&formatted)
@@ -1542,7 +1542,7 @@ fn float_to_exponential_common<T>(fmt: &mut Formatter, num: &T, upper: bool) ->
         flt2dec::to_exact_exp_str(flt2dec::strategy::grisu::format_exact, *num, sign,
                                   precision + 1, upper, &mut buf, &mut parts)
     } else {
-        flt2dec::to_shortest_exp_str(flt2dec::strategy::grisu::format_shortest, *num, sign,
+        flt2dec::to_shortest_exp_str(flt2dec::strategy::ryu::format_shortest, *num, sign,
                                      (0, 0), upper, &mut buf, &mut parts)
     };
     fmt.pad_formatted_parts(&formatted)
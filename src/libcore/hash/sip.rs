@@ -37,8 +37,8 @@ pub struct SipHasher24 {
 ///
 /// See: https://131002.net/siphash/
 ///
-/// This is currently the default hashing function used by standard library
-/// (eg. `collections::HashMap` uses it by default).
+/// The standard library's `collections::HashMap` uses the faster SipHash 1-3
+/// variant by default, through `collections::hash_map::DefaultHasher`.
 ///
 /// SipHash is a general-purpose hashing function: it runs at a good
 /// speed (competitive with Spooky and City) and permits strong _keyed_
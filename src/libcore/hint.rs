@@ -0,0 +1,59 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Hints to the compiler that affect how code should be emitted or
+//! optimized.
+
+#![unstable(feature = "bench_black_box", issue = "0")]
+
+#[cfg(not(stage0))]
+use intrinsics;
+
+/// An identity function that is opaque to the optimizer.
+///
+/// The optimizer has to assume that `black_box` may read and modify `dummy`
+/// in any way, so it can neither drop the computation of the argument nor
+/// make assumptions about the value that is returned. This is mostly useful
+/// in benchmarks, to keep the measured code from being optimized away or
+/// const-folded.
+///
+/// This is only a best-effort hint, and it doesn't provide any guarantees
+/// to `unsafe` code: the value is returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(bench_black_box)]
+///
+/// use std::hint::black_box;
+///
+/// fn contains(haystack: &[&str], needle: &str) -> bool {
+///     haystack.iter().any(|x| x == &needle)
+/// }
+///
+/// let haystack = vec!["abc", "def", "ghi", "jkl", "mno"];
+/// let needle = "ghi";
+/// // Without `black_box`, the call could be evaluated at compile time.
+/// assert!(black_box(contains(black_box(&haystack), black_box(needle))));
+/// ```
+#[cfg(not(stage0))]
+#[inline]
+#[unstable(feature = "bench_black_box", issue = "0")]
+pub fn black_box<T>(dummy: T) -> T {
+    unsafe { intrinsics::black_box(dummy) }
+}
+
+/// An identity function that is opaque to the optimizer.
+#[cfg(stage0)]
+#[inline(never)]
+#[unstable(feature = "bench_black_box", issue = "0")]
+pub fn black_box<T>(dummy: T) -> T {
+    dummy
+}
@@ -244,19 +244,6 @@ extern "rust-intrinsic" {
     /// crate it is invoked in.
     pub fn type_id<T: ?Sized + 'static>() -> u64;
 
-    /// Creates a value initialized to so that its drop flag,
-    /// if any, says that it has been dropped.
-    ///
-    /// `init_dropped` is unsafe because it returns a datum with all
-    /// of its bytes set to the drop flag, which generally does not
-    /// correspond to a valid value.
-    ///
-    /// This intrinsic is likely to be deprecated in the future when
-    /// Rust moves to non-zeroing dynamic drop (and thus removes the
-    /// embedded drop flags that are being established by this
-    /// intrinsic).
-    pub fn init_dropped<T>() -> T;
-
     /// Creates a value initialized to zero.
     ///
     /// `init` is unsafe because it returns a zeroed-out datum,
@@ -268,9 +255,7 @@ extern "rust-intrinsic" {
     /// Creates an uninitialized value.
     ///
     /// `uninit` is unsafe because there is no guarantee of what its
-    /// contents are. In particular its drop-flag may be set to any
-    /// state, which means it may claim either dropped or
-    /// undropped. In the general case one must use `ptr::write` to
+    /// contents are. In the general case one must use `ptr::write` to
     /// initialize memory previous set to the result of `uninit`.
     pub fn uninit<T>() -> T;
 
@@ -483,6 +468,13 @@ extern "rust-intrinsic" {
     /// `Copy`, then may return `true` or `false`.
     pub fn needs_drop<T>() -> bool;
 
+    /// Returns `dummy` unchanged, while making the optimizer assume that it
+    /// may have been read and modified in arbitrary ways.
+    ///
+    /// The user-facing version of this intrinsic is `hint::black_box`.
+    #[cfg(not(stage0))]
+    pub fn black_box<T>(dummy: T) -> T;
+
     /// Calculates the offset from a pointer.
     ///
     /// This is implemented as an intrinsic to avoid converting to and from an
@@ -742,6 +734,10 @@ extern "rust-intrinsic" {
     /// Reverses the bytes in an integer type `T`.
     pub fn bswap<T>(x: T) -> T;
 
+    /// Reverses the bits in an integer type `T`.
+    #[cfg(not(stage0))]
+    pub fn bitreverse<T>(x: T) -> T;
+
     /// Performs checked integer addition.
     pub fn add_with_overflow<T>(x: T, y: T) -> (T, bool);
 
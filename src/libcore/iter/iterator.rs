@@ -11,14 +11,14 @@
 use clone::Clone;
 use cmp::{Ord, PartialOrd, PartialEq, Ordering};
 use default::Default;
-use ops::FnMut;
+use ops::{FnMut, Try};
 use option::Option::{self, Some, None};
 use marker::Sized;
 
-use super::{Chain, Cycle, Cloned, Enumerate, Filter, FilterMap, FlatMap, Fuse};
-use super::{Inspect, Map, Peekable, Scan, Skip, SkipWhile, Take, TakeWhile, Rev};
+use super::{Chain, Cycle, Cloned, Enumerate, Filter, FilterMap, FlatMap, Flatten, Fuse};
+use super::{Inspect, Map, Peekable, Scan, Skip, SkipWhile, StepBy, Take, TakeWhile, Rev};
 use super::{Zip, Sum, Product};
-use super::ChainState;
+use super::{ChainState, LoopState};
 use super::{DoubleEndedIterator, ExactSizeIterator, Extend, FromIterator};
 use super::{IntoIterator, ZipImpl};
 
@@ -821,6 +821,45 @@ pub trait Iterator {
         Skip{iter: self, n: n}
     }
 
+    /// Creates an iterator starting at the same point, but stepping by the
+    /// given amount at each iteration.
+    ///
+    /// The first element of the iterator is always yielded, followed by every
+    /// `step`th element after it. The skipped elements are still consumed
+    /// from the underlying iterator, using [`nth()`] so that iterators which
+    /// can skip cheaply do.
+    ///
+    /// The adaptor is fused whenever the underlying iterator is: once that
+    /// returns `None`, so does this one.
+    ///
+    /// [`nth()`]: #method.nth
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(iterator_step_by)]
+    ///
+    /// let a = [0, 1, 2, 3, 4, 5];
+    /// let mut iter = a.iter().step_by(2);
+    ///
+    /// assert_eq!(iter.next(), Some(&0));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&4));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "iterator_step_by", issue = "0")]
+    fn step_by(self, step: usize) -> StepBy<Self> where Self: Sized {
+        assert!(step != 0);
+        StepBy{iter: self, step: step - 1, first_take: true}
+    }
+
     /// Creates an iterator that yields its first `n` elements.
     ///
     /// # Examples
@@ -932,6 +971,51 @@ pub trait Iterator {
         FlatMap{iter: self, f: f, frontiter: None, backiter: None }
     }
 
+    /// Creates an iterator that flattens nested structure.
+    ///
+    /// This is useful when you have an iterator of iterators or an iterator of
+    /// things that can be turned into iterators and you want to remove one
+    /// level of indirection.
+    ///
+    /// `flatten()` is equivalent to [`flat_map()`] with the identity function,
+    /// and, like it, can be iterated from both ends if the outer and the
+    /// inner iterators are double-ended.
+    ///
+    /// [`flat_map()`]: #method.flat_map
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(iterator_flatten)]
+    ///
+    /// let data = vec![vec![1, 2, 3, 4], vec![5, 6]];
+    /// let flattened = data.into_iter().flatten().collect::<Vec<u8>>();
+    /// assert_eq!(flattened, &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    ///
+    /// Flattening only removes one level of nesting at a time:
+    ///
+    /// ```
+    /// #![feature(iterator_flatten)]
+    ///
+    /// let d3 = [[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+    ///
+    /// let d2 = d3.iter().flatten().collect::<Vec<_>>();
+    /// assert_eq!(d2, [&[1, 2], &[3, 4], &[5, 6], &[7, 8]]);
+    ///
+    /// let d1 = d3.iter().flatten().flatten().collect::<Vec<_>>();
+    /// assert_eq!(d1, [&1, &2, &3, &4, &5, &6, &7, &8]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "iterator_flatten", issue = "0")]
+    fn flatten(self) -> Flatten<Self>
+        where Self: Sized, Self::Item: IntoIterator,
+    {
+        Flatten { iter: self, frontiter: None, backiter: None }
+    }
+
     /// Creates an iterator which ends after the first `None`.
     ///
     /// After an iterator returns `None`, future calls may or may not yield
@@ -1320,6 +1404,104 @@ pub trait Iterator {
         accum
     }
 
+    /// An iterator method that applies a function as long as it returns
+    /// successfully, producing a single, final value.
+    ///
+    /// `try_fold()` is like [`fold()`], except that the closure returns a
+    /// "try" type such as `Result` or `Option`. If the closure succeeds, the
+    /// value it returns becomes the accumulator for the next element. If it
+    /// fails, `try_fold()` stops right away and returns that failure; the
+    /// remaining elements are left in the iterator.
+    ///
+    /// Unlike `fold()` this borrows the iterator instead of consuming it, so
+    /// iteration can continue after a short-circuit. Adaptors like `Chain`
+    /// and `FlatMap` implement it by folding over their inner iterators,
+    /// which is usually much faster than calling `next()` repeatedly. The
+    /// short-circuiting methods like [`all()`], [`any()`], [`find()`] and
+    /// [`position()`] are built on top of it.
+    ///
+    /// [`fold()`]: #method.fold
+    /// [`all()`]: #method.all
+    /// [`any()`]: #method.any
+    /// [`find()`]: #method.find
+    /// [`position()`]: #method.position
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(iterator_try_fold)]
+    ///
+    /// let a = [1, 2, 3];
+    ///
+    /// // the checked sum of all of the elements of the array
+    /// let sum = a.iter().try_fold(0i8, |acc, &x| acc.checked_add(x));
+    ///
+    /// assert_eq!(sum, Some(6));
+    /// ```
+    ///
+    /// Short-circuiting:
+    ///
+    /// ```
+    /// #![feature(iterator_try_fold)]
+    ///
+    /// let a = [10, 20, 30, 100, 40, 50];
+    /// let mut it = a.iter();
+    ///
+    /// // This sum overflows when adding the 100 element
+    /// let sum = it.try_fold(0i8, |acc, &x| acc.checked_add(x));
+    /// assert_eq!(sum, None);
+    ///
+    /// // Because it short-circuited, the remaining elements are still
+    /// // available through the iterator.
+    /// assert_eq!(it.len(), 2);
+    /// assert_eq!(it.next(), Some(&40));
+    /// ```
+    #[inline]
+    #[unstable(feature = "iterator_try_fold", issue = "0")]
+    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R where
+        Self: Sized, F: FnMut(B, Self::Item) -> R, R: Try<Ok=B>
+    {
+        let mut accum = init;
+        while let Some(x) = self.next() {
+            accum = try_op!(f(accum, x));
+        }
+        Try::from_ok(accum)
+    }
+
+    /// An iterator method that applies a fallible function to each item in
+    /// the iterator, stopping at the first error and returning that error.
+    ///
+    /// This can also be thought of as the fallible form of a `for` loop over
+    /// the iterator, or as [`try_fold()`] with a unit accumulator.
+    ///
+    /// [`try_fold()`]: #method.try_fold
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iterator_try_fold)]
+    ///
+    /// let data = ["no_tea.txt", "stale_bread.json", "torrential_rain.png"];
+    ///
+    /// let res = data.iter().try_for_each(|name| {
+    ///     if name.ends_with(".png") {
+    ///         Err(name)
+    ///     } else {
+    ///         Ok(())
+    ///     }
+    /// });
+    /// assert_eq!(res, Err(&"torrential_rain.png"));
+    /// ```
+    #[inline]
+    #[unstable(feature = "iterator_try_fold", issue = "0")]
+    fn try_for_each<F, R>(&mut self, mut f: F) -> R where
+        Self: Sized, F: FnMut(Self::Item) -> R, R: Try<Ok=()>
+    {
+        self.try_fold((), move |(), x| f(x))
+    }
+
     /// Tests if every element of the iterator matches a predicate.
     ///
     /// `all()` takes a closure that returns `true` or `false`. It applies
@@ -1362,12 +1544,13 @@ pub trait Iterator {
     fn all<F>(&mut self, mut f: F) -> bool where
         Self: Sized, F: FnMut(Self::Item) -> bool
     {
-        for x in self {
-            if !f(x) {
-                return false;
+        self.try_fold((), move |(), x| {
+            if f(x) {
+                LoopState::Continue(())
+            } else {
+                LoopState::Break(())
             }
-        }
-        true
+        }) == LoopState::Continue(())
     }
 
     /// Tests if any element of the iterator matches a predicate.
@@ -1413,12 +1596,13 @@ pub trait Iterator {
         Self: Sized,
         F: FnMut(Self::Item) -> bool
     {
-        for x in self {
+        self.try_fold((), move |(), x| {
             if f(x) {
-                return true;
+                LoopState::Break(())
+            } else {
+                LoopState::Continue(())
             }
-        }
-        false
+        }) == LoopState::Break(())
     }
 
     /// Searches for an element of an iterator that satisfies a predicate.
@@ -1466,10 +1650,13 @@ pub trait Iterator {
         Self: Sized,
         P: FnMut(&Self::Item) -> bool,
     {
-        for x in self {
-            if predicate(&x) { return Some(x) }
-        }
-        None
+        self.try_fold((), move |(), x| {
+            if predicate(&x) {
+                LoopState::Break(x)
+            } else {
+                LoopState::Continue(())
+            }
+        }).break_value()
     }
 
     /// Searches for an element in an iterator, returning its index.
@@ -1519,18 +1706,20 @@ pub trait Iterator {
     /// assert_eq!(iter.next(), Some(&3));
     /// ```
     #[inline]
+    #[rustc_inherit_overflow_checks]
     #[stable(feature = "rust1", since = "1.0.0")]
     fn position<P>(&mut self, mut predicate: P) -> Option<usize> where
         Self: Sized,
         P: FnMut(Self::Item) -> bool,
     {
-        // `enumerate` might overflow.
-        for (i, x) in self.enumerate() {
+        // The addition might overflow.
+        self.try_fold(0, move |i, x| {
             if predicate(x) {
-                return Some(i);
+                LoopState::Break(i)
+            } else {
+                LoopState::Continue(i + 1)
             }
-        }
-        None
+        }).break_value()
     }
 
     /// Searches for an element in an iterator from the right, returning its
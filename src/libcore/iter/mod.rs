@@ -300,14 +300,23 @@
 #![stable(feature = "rust1", since = "1.0.0")]
 
 use clone::Clone;
-use cmp;
+use cmp::{self, PartialEq};
 use default::Default;
 use fmt;
 use iter_private::TrustedRandomAccess;
-use ops::FnMut;
+use ops::{FnMut, Try};
 use option::Option::{self, Some, None};
+use result::Result::{self, Ok, Err};
 use usize;
 
+// Like the `?` operator, but for any `Try` type instead of just `Result`.
+macro_rules! try_op {
+    ($e:expr) => (match $crate::ops::Try::into_result($e) {
+        $crate::result::Result::Ok(v) => v,
+        $crate::result::Result::Err(e) => return $crate::ops::Try::from_error(e),
+    })
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::iterator::Iterator;
 
@@ -315,9 +324,6 @@ pub use self::iterator::Iterator;
            reason = "likely to be replaced by finer-grained traits",
            issue = "27741")]
 pub use self::range::Step;
-#[unstable(feature = "step_by", reason = "recent addition",
-           issue = "27741")]
-pub use self::range::StepBy;
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::sources::{Repeat, repeat};
@@ -336,6 +342,43 @@ mod range;
 mod sources;
 mod traits;
 
+/// Used to make `try_fold` closures more like normal loops: `Continue` keeps
+/// going with a new accumulator, `Break` stops with a result.
+#[derive(PartialEq, Debug)]
+enum LoopState<C, B> {
+    Continue(C),
+    Break(B),
+}
+
+impl<C, B> Try for LoopState<C, B> {
+    type Ok = C;
+    type Error = B;
+
+    #[inline]
+    fn into_result(self) -> Result<C, B> {
+        match self {
+            LoopState::Continue(y) => Ok(y),
+            LoopState::Break(x) => Err(x),
+        }
+    }
+
+    #[inline]
+    fn from_error(v: B) -> Self { LoopState::Break(v) }
+
+    #[inline]
+    fn from_ok(v: C) -> Self { LoopState::Continue(v) }
+}
+
+impl<C, B> LoopState<C, B> {
+    #[inline]
+    fn break_value(self) -> Option<B> {
+        match self {
+            LoopState::Continue(..) => None,
+            LoopState::Break(x) => Some(x),
+        }
+    }
+}
+
 /// An double-ended iterator with the direction inverted.
 ///
 /// This `struct` is created by the [`rev()`] method on [`Iterator`]. See its
@@ -512,6 +555,26 @@ impl<A, B> Iterator for Chain<A, B> where
         }
     }
 
+    #[inline]
+    fn try_fold<Acc, F, R>(&mut self, init: Acc, mut f: F) -> R where
+        F: FnMut(Acc, Self::Item) -> R, R: Try<Ok=Acc>
+    {
+        let mut accum = init;
+        match self.state {
+            ChainState::Both | ChainState::Front => {
+                accum = try_op!(self.a.try_fold(accum, &mut f));
+                if let ChainState::Both = self.state {
+                    self.state = ChainState::Back;
+                }
+            }
+            ChainState::Back => {}
+        }
+        if let ChainState::Back = self.state {
+            accum = try_op!(self.b.try_fold(accum, &mut f));
+        }
+        Try::from_ok(accum)
+    }
+
     #[inline]
     #[rustc_inherit_overflow_checks]
     fn count(self) -> usize {
@@ -903,6 +966,14 @@ impl<B, I: Iterator, F> Iterator for Map<I, F> where F: FnMut(I::Item) -> B {
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.iter.size_hint()
     }
+
+    #[inline]
+    fn try_fold<Acc, G, R>(&mut self, init: Acc, mut g: G) -> R where
+        G: FnMut(Acc, Self::Item) -> R, R: Try<Ok=Acc>
+    {
+        let f = &mut self.f;
+        self.iter.try_fold(init, move |acc, elt| g(acc, f(elt)))
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -962,6 +1033,18 @@ impl<I: Iterator, P> Iterator for Filter<I, P> where P: FnMut(&I::Item) -> bool
         let (_, upper) = self.iter.size_hint();
         (0, upper) // can't know a lower bound, due to the predicate
     }
+
+    #[inline]
+    fn try_fold<Acc, G, R>(&mut self, init: Acc, mut fold: G) -> R where
+        G: FnMut(Acc, Self::Item) -> R, R: Try<Ok=Acc>
+    {
+        let predicate = &mut self.predicate;
+        self.iter.try_fold(init, move |acc, item| if predicate(&item) {
+            fold(acc, item)
+        } else {
+            Try::from_ok(acc)
+        })
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -1085,6 +1168,20 @@ impl<I> Iterator for Enumerate<I> where I: Iterator {
         self.iter.size_hint()
     }
 
+    #[inline]
+    #[rustc_inherit_overflow_checks]
+    fn try_fold<Acc, Fold, R>(&mut self, init: Acc, mut fold: Fold) -> R where
+        Fold: FnMut(Acc, Self::Item) -> R, R: Try<Ok=Acc>
+    {
+        let count = &mut self.count;
+        self.iter.try_fold(init, move |acc, item| {
+            let acc = fold(acc, (*count, item));
+            // Possible undefined overflow.
+            *count += 1;
+            acc
+        })
+    }
+
     #[inline]
     #[rustc_inherit_overflow_checks]
     fn nth(&mut self, n: usize) -> Option<(usize, I::Item)> {
@@ -1244,6 +1341,121 @@ impl<I: Iterator> Peekable<I> {
             None => None,
         }
     }
+
+    /// Returns a mutable reference to the next() value without advancing the
+    /// iterator.
+    ///
+    /// Like [`peek()`], but the peeked value can be modified in place before
+    /// it is returned by [`next()`].
+    ///
+    /// [`peek()`]: #method.peek
+    /// [`next()`]: trait.Iterator.html#tymethod.next
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(peekable_peek_mut)]
+    ///
+    /// let mut iter = [1, 2, 3].iter().cloned().peekable();
+    ///
+    /// if let Some(p) = iter.peek_mut() {
+    ///     if *p == 1 {
+    ///         *p = 5;
+    ///     }
+    /// }
+    ///
+    /// // The changed value is what `next()` returns
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![5, 2, 3]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "peekable_peek_mut", issue = "0")]
+    pub fn peek_mut(&mut self) -> Option<&mut I::Item> {
+        if self.peeked.is_none() {
+            self.peeked = self.iter.next();
+        }
+        match self.peeked {
+            Some(ref mut value) => Some(value),
+            None => None,
+        }
+    }
+
+    /// Consumes and returns the next value of this iterator if a condition is
+    /// true.
+    ///
+    /// If `func` returns `true` for the next value of this iterator, that
+    /// value is consumed and returned. Otherwise, `None` is returned and the
+    /// value stays in the iterator, so that a later call sees it again.
+    ///
+    /// # Examples
+    ///
+    /// Consume a number if it's equal to 0:
+    ///
+    /// ```
+    /// #![feature(peekable_next_if)]
+    ///
+    /// let mut iter = (0..5).peekable();
+    /// // The first item of the iterator is 0; consume it.
+    /// assert_eq!(iter.next_if(|&x| x == 0), Some(0));
+    /// // The next item returned is now 1, so `next_if` returns `None`.
+    /// assert_eq!(iter.next_if(|&x| x == 0), None);
+    /// // `next_if` keeps the value of the next item if it was not equal.
+    /// assert_eq!(iter.next(), Some(1));
+    /// ```
+    ///
+    /// Consume any number less than 10:
+    ///
+    /// ```
+    /// #![feature(peekable_next_if)]
+    ///
+    /// let mut iter = (1..20).peekable();
+    /// // Consume all numbers less than 10
+    /// while iter.next_if(|&x| x < 10).is_some() {}
+    /// // The next value returned will be 10
+    /// assert_eq!(iter.next(), Some(10));
+    /// ```
+    #[inline]
+    #[unstable(feature = "peekable_next_if", issue = "0")]
+    pub fn next_if<F>(&mut self, func: F) -> Option<I::Item> where
+        F: FnOnce(&I::Item) -> bool,
+    {
+        match self.next() {
+            Some(matched) => {
+                if func(&matched) {
+                    Some(matched)
+                } else {
+                    // Put the value back so that it's peeked again.
+                    self.peeked = Some(matched);
+                    None
+                }
+            }
+            None => None,
+        }
+    }
+
+    /// Consumes and returns the next item if it is equal to `expected`.
+    ///
+    /// # Examples
+    ///
+    /// Consume a number if it's equal to 0:
+    ///
+    /// ```
+    /// #![feature(peekable_next_if)]
+    ///
+    /// let mut iter = (0..5).peekable();
+    /// // The first item of the iterator is 0; consume it.
+    /// assert_eq!(iter.next_if_eq(&0), Some(0));
+    /// // The next item returned is now 1, so `next_if_eq` returns `None`.
+    /// assert_eq!(iter.next_if_eq(&0), None);
+    /// // `next_if_eq` keeps the value of the next item if it was not equal.
+    /// assert_eq!(iter.next(), Some(1));
+    /// ```
+    #[inline]
+    #[unstable(feature = "peekable_next_if", issue = "0")]
+    pub fn next_if_eq<T: ?Sized>(&mut self, expected: &T) -> Option<I::Item> where
+        I::Item: PartialEq<T>,
+    {
+        self.next_if(|next| *next == *expected)
+    }
 }
 
 /// An iterator that rejects elements while `predicate` is true.
@@ -1442,6 +1654,58 @@ impl<I> DoubleEndedIterator for Skip<I> where I: DoubleEndedIterator + ExactSize
     }
 }
 
+/// An iterator that yields every `step`th element of another iterator.
+///
+/// This `struct` is created by the [`step_by()`] method on [`Iterator`]. See
+/// its documentation for more.
+///
+/// [`step_by()`]: trait.Iterator.html#method.step_by
+/// [`Iterator`]: trait.Iterator.html
+#[derive(Clone, Debug)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[unstable(feature = "iterator_step_by", issue = "0")]
+pub struct StepBy<I> {
+    iter: I,
+    // One less than the step, which is the number of elements skipped
+    // between two yielded ones.
+    step: usize,
+    first_take: bool,
+}
+
+#[unstable(feature = "iterator_step_by", issue = "0")]
+impl<I> Iterator for StepBy<I> where I: Iterator {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        if self.first_take {
+            self.first_take = false;
+            self.iter.next()
+        } else {
+            self.iter.nth(self.step)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let step = self.step;
+
+        // The first element is yielded right away, after that only every
+        // `step + 1`th one.
+        let remaining = |n: usize| if self.first_take {
+            if n == 0 { 0 } else { 1 + (n - 1) / (step + 1) }
+        } else {
+            n / (step + 1)
+        };
+
+        (remaining(lower), upper.map(remaining))
+    }
+}
+
+#[unstable(feature = "iterator_step_by", issue = "0")]
+impl<I> ExactSizeIterator for StepBy<I> where I: ExactSizeIterator {}
+
 /// An iterator that only iterates over the first `n` iterations of `iter`.
 ///
 /// This `struct` is created by the [`take()`] method on [`Iterator`]. See its
@@ -1611,6 +1875,37 @@ impl<I: Iterator, U: IntoIterator, F> Iterator for FlatMap<I, U, F>
             _ => (lo, None)
         }
     }
+
+    #[inline]
+    fn try_fold<Acc, Fold, R>(&mut self, mut init: Acc, mut fold: Fold) -> R where
+        Fold: FnMut(Acc, Self::Item) -> R, R: Try<Ok=Acc>
+    {
+        if let Some(ref mut front) = self.frontiter {
+            init = try_op!(front.try_fold(init, &mut fold));
+        }
+        self.frontiter = None;
+
+        {
+            let f = &mut self.f;
+            let frontiter = &mut self.frontiter;
+            init = try_op!(self.iter.try_fold(init, |acc, x| {
+                let mut mid = f(x).into_iter();
+                let r = mid.try_fold(acc, &mut fold);
+                // Keep the unfinished inner iterator around in case we
+                // short-circuited out of it.
+                *frontiter = Some(mid);
+                r
+            }));
+        }
+        self.frontiter = None;
+
+        if let Some(ref mut back) = self.backiter {
+            init = try_op!(back.try_fold(init, &mut fold));
+        }
+        self.backiter = None;
+
+        Try::from_ok(init)
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -1635,6 +1930,135 @@ impl<I: DoubleEndedIterator, U, F> DoubleEndedIterator for FlatMap<I, U, F> wher
     }
 }
 
+/// An iterator that flattens one level of nesting in an iterator of things
+/// that can be turned into iterators.
+///
+/// This `struct` is created by the [`flatten()`] method on [`Iterator`]. See its
+/// documentation for more.
+///
+/// [`flatten()`]: trait.Iterator.html#method.flatten
+/// [`Iterator`]: trait.Iterator.html
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[unstable(feature = "iterator_flatten", issue = "0")]
+pub struct Flatten<I: Iterator> where I::Item: IntoIterator {
+    iter: I,
+    frontiter: Option<<I::Item as IntoIterator>::IntoIter>,
+    backiter: Option<<I::Item as IntoIterator>::IntoIter>,
+}
+
+#[unstable(feature = "iterator_flatten", issue = "0")]
+impl<I, U> Clone for Flatten<I>
+    where I: Iterator + Clone, I::Item: IntoIterator<IntoIter = U, Item = U::Item>,
+          U: Iterator + Clone,
+{
+    fn clone(&self) -> Flatten<I> {
+        Flatten {
+            iter: self.iter.clone(),
+            frontiter: self.frontiter.clone(),
+            backiter: self.backiter.clone(),
+        }
+    }
+}
+
+#[unstable(feature = "iterator_flatten", issue = "0")]
+impl<I, U> fmt::Debug for Flatten<I>
+    where I: Iterator + fmt::Debug, I::Item: IntoIterator<IntoIter = U, Item = U::Item>,
+          U: Iterator + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Flatten")
+            .field("iter", &self.iter)
+            .field("frontiter", &self.frontiter)
+            .field("backiter", &self.backiter)
+            .finish()
+    }
+}
+
+#[unstable(feature = "iterator_flatten", issue = "0")]
+impl<I, U> Iterator for Flatten<I>
+    where I: Iterator, I::Item: IntoIterator<IntoIter = U, Item = U::Item>,
+          U: Iterator,
+{
+    type Item = U::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<U::Item> {
+        loop {
+            if let Some(ref mut inner) = self.frontiter {
+                if let Some(x) = inner.by_ref().next() {
+                    return Some(x)
+                }
+            }
+            match self.iter.next() {
+                None => return self.backiter.as_mut().and_then(|it| it.next()),
+                Some(inner) => self.frontiter = Some(inner.into_iter()),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (flo, fhi) = self.frontiter.as_ref().map_or((0, Some(0)), |it| it.size_hint());
+        let (blo, bhi) = self.backiter.as_ref().map_or((0, Some(0)), |it| it.size_hint());
+        let lo = flo.saturating_add(blo);
+        match (self.iter.size_hint(), fhi, bhi) {
+            ((0, Some(0)), Some(a), Some(b)) => (lo, a.checked_add(b)),
+            _ => (lo, None)
+        }
+    }
+
+    #[inline]
+    fn try_fold<Acc, Fold, R>(&mut self, mut init: Acc, mut fold: Fold) -> R where
+        Fold: FnMut(Acc, Self::Item) -> R, R: Try<Ok=Acc>
+    {
+        if let Some(ref mut front) = self.frontiter {
+            init = try_op!(front.try_fold(init, &mut fold));
+        }
+        self.frontiter = None;
+
+        {
+            let frontiter = &mut self.frontiter;
+            init = try_op!(self.iter.try_fold(init, |acc, x| {
+                let mut mid = x.into_iter();
+                let r = mid.try_fold(acc, &mut fold);
+                // Keep the unfinished inner iterator around in case we
+                // short-circuited out of it.
+                *frontiter = Some(mid);
+                r
+            }));
+        }
+        self.frontiter = None;
+
+        if let Some(ref mut back) = self.backiter {
+            init = try_op!(back.try_fold(init, &mut fold));
+        }
+        self.backiter = None;
+
+        Try::from_ok(init)
+    }
+}
+
+#[unstable(feature = "iterator_flatten", issue = "0")]
+impl<I, U> DoubleEndedIterator for Flatten<I>
+    where I: DoubleEndedIterator, I::Item: IntoIterator<IntoIter = U, Item = U::Item>,
+          U: DoubleEndedIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<U::Item> {
+        loop {
+            if let Some(ref mut inner) = self.backiter {
+                if let Some(y) = inner.next_back() {
+                    return Some(y)
+                }
+            }
+            match self.iter.next_back() {
+                None => return self.frontiter.as_mut().and_then(|it| it.next_back()),
+                Some(inner) => self.backiter = Some(inner.into_iter()),
+            }
+        }
+    }
+}
+
 /// An iterator that yields `None` forever after the underlying iterator
 /// yields `None` once.
 ///
@@ -76,6 +76,8 @@
 #![feature(inclusive_range_syntax)]
 #![feature(intrinsics)]
 #![feature(lang_items)]
+#![feature(link_llvm_intrinsics)]
+#![feature(naked_functions)]
 #![feature(no_core)]
 #![feature(on_unimplemented)]
 #![feature(optin_builtin_traits)]
@@ -90,6 +92,7 @@
 
 // NOTE: remove the cfg_attr next snapshot
 #![cfg_attr(not(stage0), feature(never_type))]
+#![cfg_attr(not(stage0), feature(target_feature))]
 
 #[macro_use]
 mod macros;
@@ -131,6 +134,7 @@ pub mod prelude;
 /* Core modules for ownership management */
 
 pub mod intrinsics;
+pub mod hint;
 pub mod mem;
 pub mod nonzero;
 pub mod ptr;
@@ -162,6 +166,13 @@ pub mod slice;
 pub mod str;
 pub mod hash;
 pub mod fmt;
+pub mod simd;
+
+// `#[target_feature]` is unknown to stage0
+#[cfg(not(stage0))]
+pub mod arch;
+
+pub mod probestack;
 
 // note: does not need to be public
 mod char_private;
@@ -424,3 +424,9 @@ macro_rules! unreachable {
 macro_rules! unimplemented {
     () => (panic!("not yet implemented"))
 }
+
+// The bootstrap compiler still calls `llvm_asm!` by its old name.
+#[cfg(stage0)]
+macro_rules! llvm_asm {
+    ($($t:tt)*) => (asm!($($t)*))
+}
@@ -15,9 +15,13 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
-use marker::Sized;
+use clone::Clone;
+use fmt;
+use marker::{Copy, Sized};
 use intrinsics;
+use ops::{Deref, DerefMut};
 use ptr;
+use slice::SliceExt;
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use intrinsics::transmute;
@@ -241,27 +245,6 @@ pub unsafe fn zeroed<T>() -> T {
     intrinsics::init()
 }
 
-/// Creates a value initialized to an unspecified series of bytes.
-///
-/// The byte sequence usually indicates that the value at the memory
-/// in question has been dropped. Thus, *if* T carries a drop flag,
-/// any associated destructor will not be run when the value falls out
-/// of scope.
-///
-/// Some code at one time used the `zeroed` function above to
-/// accomplish this goal.
-///
-/// This function is expected to be deprecated with the transition
-/// to non-zeroing drop.
-#[inline]
-#[unstable(feature = "filling_drop", issue = "5016")]
-pub unsafe fn dropped<T>() -> T {
-    #[inline(always)]
-    unsafe fn dropped_impl<T>() -> T { intrinsics::init_dropped() }
-
-    dropped_impl()
-}
-
 /// Bypasses Rust's normal memory-initialization checks by pretending to
 /// produce a value of type T, while doing nothing at all.
 ///
@@ -294,6 +277,7 @@ pub unsafe fn dropped<T>() -> T {
 /// Here's how to safely initialize an array of `Vec`s.
 ///
 /// ```
+/// # #![allow(deprecated)]
 /// use std::mem;
 /// use std::ptr;
 ///
@@ -343,10 +327,337 @@ pub unsafe fn dropped<T>() -> T {
 /// ```
 #[inline]
 #[stable(feature = "rust1", since = "1.0.0")]
+#[rustc_deprecated(reason = "use `mem::MaybeUninit` instead", since = "1.12.0")]
 pub unsafe fn uninitialized<T>() -> T {
     intrinsics::uninit()
 }
 
+/// A wrapper to construct uninitialized instances of `T`.
+///
+/// The compiler, in general, assumes that a variable is properly initialized
+/// at all times, and `mem::uninitialized` breaks that assumption for any `T`
+/// with invalid bit patterns or a destructor. `MaybeUninit<T>` instead tells
+/// the compiler that its contents might not be initialized yet: it never
+/// drops the `T` inside, and the layout of enums containing it doesn't
+/// assume anything about the bits of the `T` either.
+///
+/// Once the value has been written, `assume_init` turns the wrapper back
+/// into a `T`. It is up to the caller to guarantee that this really is the
+/// case.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(maybe_uninit)]
+///
+/// use std::mem::MaybeUninit;
+///
+/// let mut x = MaybeUninit::<Vec<u32>>::uninit();
+/// unsafe {
+///     x.write(vec![1, 2, 3]);
+///     let x = x.assume_init();
+///     assert_eq!(x, [1, 2, 3]);
+/// }
+/// ```
+///
+/// Arrays of `MaybeUninit` can be initialized element by element. Nothing is
+/// dropped if a panic happens halfway, which leaks the elements written so
+/// far but never drops uninitialized memory:
+///
+/// ```
+/// #![feature(maybe_uninit)]
+///
+/// use std::mem::{self, MaybeUninit};
+///
+/// let data: [Vec<u32>; 4] = unsafe {
+///     let mut data: [MaybeUninit<Vec<u32>>; 4] = MaybeUninit::uninit().assume_init();
+///     for elem in &mut data[..] {
+///         elem.write(Vec::new());
+///     }
+///     mem::transmute(data)
+/// };
+/// assert!(data.iter().all(|v| v.is_empty()));
+/// ```
+#[cfg_attr(not(stage0), lang = "maybe_uninit")]
+#[unstable(feature = "maybe_uninit", issue = "0")]
+pub struct MaybeUninit<T> {
+    // NB: the stage0 compiler doesn't know about the lang item and will drop
+    // `value` like any other field, so only use `MaybeUninit` with types
+    // that don't need dropping while it is around.
+    value: T,
+}
+
+#[unstable(feature = "maybe_uninit", issue = "0")]
+impl<T: Copy> Clone for MaybeUninit<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[unstable(feature = "maybe_uninit", issue = "0")]
+impl<T: Copy> Copy for MaybeUninit<T> {}
+
+#[unstable(feature = "maybe_uninit", issue = "0")]
+impl<T> fmt::Debug for MaybeUninit<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad("MaybeUninit { .. }")
+    }
+}
+
+impl<T> MaybeUninit<T> {
+    /// Creates a new `MaybeUninit<T>` initialized with the given value.
+    ///
+    /// Note that dropping a `MaybeUninit<T>` will never drop the `T` inside.
+    #[inline(always)]
+    #[unstable(feature = "maybe_uninit", issue = "0")]
+    pub fn new(val: T) -> MaybeUninit<T> {
+        MaybeUninit { value: val }
+    }
+
+    /// Creates a new `MaybeUninit<T>` in an uninitialized state.
+    ///
+    /// Note that dropping a `MaybeUninit<T>` will never drop the `T` inside.
+    #[inline(always)]
+    #[unstable(feature = "maybe_uninit", issue = "0")]
+    pub fn uninit() -> MaybeUninit<T> {
+        unsafe { MaybeUninit { value: intrinsics::uninit() } }
+    }
+
+    /// Creates a new `MaybeUninit<T>` with the memory filled with `0` bytes.
+    ///
+    /// Whether that is a valid `T` depends on `T`: it is for integers and
+    /// raw pointers, but not for references or `NonZero` types.
+    #[inline]
+    #[unstable(feature = "maybe_uninit", issue = "0")]
+    pub fn zeroed() -> MaybeUninit<T> {
+        let mut u = MaybeUninit::<T>::uninit();
+        unsafe {
+            ptr::write_bytes(u.as_mut_ptr(), 0u8, 1);
+        }
+        u
+    }
+
+    /// Sets the value of the `MaybeUninit<T>`, and returns a mutable
+    /// reference to it.
+    ///
+    /// This overwrites any previous value without dropping it, so be careful
+    /// not to call this twice unless you want to skip running the destructor.
+    #[inline(always)]
+    #[unstable(feature = "maybe_uninit", issue = "0")]
+    pub fn write(&mut self, val: T) -> &mut T {
+        unsafe {
+            ptr::write(&mut self.value, val);
+            &mut self.value
+        }
+    }
+
+    /// Gets a pointer to the contained value. Reading from this pointer or
+    /// turning it into a reference is undefined behavior unless the
+    /// `MaybeUninit<T>` is initialized.
+    #[inline(always)]
+    #[unstable(feature = "maybe_uninit", issue = "0")]
+    pub fn as_ptr(&self) -> *const T {
+        &self.value as *const T
+    }
+
+    /// Gets a mutable pointer to the contained value. Reading from this
+    /// pointer or turning it into a reference is undefined behavior unless
+    /// the `MaybeUninit<T>` is initialized.
+    #[inline(always)]
+    #[unstable(feature = "maybe_uninit", issue = "0")]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        &mut self.value as *mut T
+    }
+
+    /// Extracts the value from the `MaybeUninit<T>` container.
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to guarantee that the `MaybeUninit<T>` really
+    /// is in an initialized state. Calling this when the content is not yet
+    /// fully initialized causes undefined behavior.
+    #[inline(always)]
+    #[unstable(feature = "maybe_uninit", issue = "0")]
+    pub unsafe fn assume_init(self) -> T {
+        self.value
+    }
+
+    /// Gets a shared reference to the contained value.
+    ///
+    /// # Safety
+    ///
+    /// As with `assume_init`, the `MaybeUninit<T>` must be initialized.
+    #[inline(always)]
+    #[unstable(feature = "maybe_uninit", issue = "0")]
+    pub unsafe fn assume_init_ref(&self) -> &T {
+        &self.value
+    }
+
+    /// Gets a mutable reference to the contained value.
+    ///
+    /// # Safety
+    ///
+    /// As with `assume_init`, the `MaybeUninit<T>` must be initialized.
+    #[inline(always)]
+    #[unstable(feature = "maybe_uninit", issue = "0")]
+    pub unsafe fn assume_init_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
+    /// Gets a shared slice of the contained values.
+    ///
+    /// # Safety
+    ///
+    /// Every element of `slice` must be initialized.
+    #[inline(always)]
+    #[unstable(feature = "maybe_uninit", issue = "0")]
+    pub unsafe fn slice_assume_init_ref(slice: &[MaybeUninit<T>]) -> &[T] {
+        &*(slice as *const [MaybeUninit<T>] as *const [T])
+    }
+
+    /// Gets a mutable slice of the contained values.
+    ///
+    /// # Safety
+    ///
+    /// Every element of `slice` must be initialized.
+    #[inline(always)]
+    #[unstable(feature = "maybe_uninit", issue = "0")]
+    pub unsafe fn slice_assume_init_mut(slice: &mut [MaybeUninit<T>]) -> &mut [T] {
+        &mut *(slice as *mut [MaybeUninit<T>] as *mut [T])
+    }
+
+    /// Gets a pointer to the first element of `slice`.
+    #[inline(always)]
+    #[unstable(feature = "maybe_uninit", issue = "0")]
+    pub fn slice_as_ptr(slice: &[MaybeUninit<T>]) -> *const T {
+        slice.as_ptr() as *const T
+    }
+
+    /// Gets a mutable pointer to the first element of `slice`.
+    #[inline(always)]
+    #[unstable(feature = "maybe_uninit", issue = "0")]
+    pub fn slice_as_mut_ptr(slice: &mut [MaybeUninit<T>]) -> *mut T {
+        slice.as_mut_ptr() as *mut T
+    }
+}
+
+/// A wrapper to inhibit the compiler from automatically calling `T`'s
+/// destructor.
+///
+/// `ManuallyDrop<T>` has the same layout as `T`, and dereferences to it, but
+/// dropping it doesn't drop the `T` inside. This makes it possible to control
+/// the order in which the fields of a struct are dropped, or to keep a value
+/// in an out-pointer, without resorting to `mem::forget` or wrapping the
+/// value in an `Option` just to be able to take it out again.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(manually_drop)]
+///
+/// use std::mem::ManuallyDrop;
+///
+/// struct Peach;
+/// struct Banana;
+/// struct Melon;
+///
+/// struct FruitBox {
+///     // Fields are normally dropped in declaration order. Wrapping them
+///     // lets the `Drop` impl decide instead.
+///     peach: ManuallyDrop<Peach>,
+///     melon: Melon, // Dropped after the `Drop` impl has run.
+///     banana: ManuallyDrop<Banana>,
+/// }
+///
+/// impl Drop for FruitBox {
+///     fn drop(&mut self) {
+///         unsafe {
+///             // Drop the banana before the peach.
+///             ManuallyDrop::drop(&mut self.banana);
+///             ManuallyDrop::drop(&mut self.peach);
+///         }
+///     }
+/// }
+/// # let _ = FruitBox {
+/// #     peach: ManuallyDrop::new(Peach),
+/// #     melon: Melon,
+/// #     banana: ManuallyDrop::new(Banana),
+/// # };
+/// ```
+#[cfg_attr(not(stage0), lang = "manually_drop")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[unstable(feature = "manually_drop", issue = "0")]
+pub struct ManuallyDrop<T> {
+    // NB: as with `MaybeUninit`, the stage0 compiler still drops `value`.
+    value: T,
+}
+
+impl<T> ManuallyDrop<T> {
+    /// Wraps a value to be manually dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(manually_drop)]
+    ///
+    /// use std::mem::ManuallyDrop;
+    ///
+    /// let x = ManuallyDrop::new(Box::new(()));
+    /// let _: Box<()> = ManuallyDrop::into_inner(x);
+    /// ```
+    #[inline]
+    #[unstable(feature = "manually_drop", issue = "0")]
+    pub fn new(value: T) -> ManuallyDrop<T> {
+        ManuallyDrop { value: value }
+    }
+
+    /// Extracts the value from the `ManuallyDrop` container, so that it is
+    /// dropped normally again.
+    ///
+    /// This is an associated function rather than a method, so that it
+    /// doesn't conflict with methods on `T` reached through `Deref`; call it
+    /// as `ManuallyDrop::into_inner(x)`.
+    #[inline]
+    #[unstable(feature = "manually_drop", issue = "0")]
+    pub fn into_inner(slot: ManuallyDrop<T>) -> T {
+        slot.value
+    }
+
+    /// Manually drops the contained value.
+    ///
+    /// # Safety
+    ///
+    /// This runs the destructor of the contained value and thus the wrapped
+    /// value now represents uninitialized data. It is up to the user of this
+    /// method to ensure the uninitialized data is not actually used, and in
+    /// particular that `drop` isn't called a second time.
+    #[inline]
+    #[unstable(feature = "manually_drop", issue = "0")]
+    pub unsafe fn drop(slot: &mut ManuallyDrop<T>) {
+        ptr::drop_in_place(&mut slot.value)
+    }
+}
+
+#[unstable(feature = "manually_drop", issue = "0")]
+impl<T> Deref for ManuallyDrop<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+#[unstable(feature = "manually_drop", issue = "0")]
+impl<T> DerefMut for ManuallyDrop<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+
 /// Swap the values at two mutable locations of the same type, without deinitializing or copying
 /// either one.
 ///
@@ -368,7 +679,7 @@ pub unsafe fn uninitialized<T>() -> T {
 pub fn swap<T>(x: &mut T, y: &mut T) {
     unsafe {
         // Give ourselves some scratch space to work with
-        let mut t: T = uninitialized();
+        let mut t: T = intrinsics::uninit();
 
         // Perform the swap, `&mut` pointers never alias
         ptr::copy_nonoverlapping(&*x, &mut t, 1);
@@ -518,56 +829,6 @@ pub fn replace<T>(dest: &mut T, mut src: T) -> T {
 #[stable(feature = "rust1", since = "1.0.0")]
 pub fn drop<T>(_x: T) { }
 
-macro_rules! repeat_u8_as_u16 {
-    ($name:expr) => { (($name as u16) <<  8 |
-                       ($name as u16)) }
-}
-macro_rules! repeat_u8_as_u32 {
-    ($name:expr) => { (($name as u32) << 24 |
-                       ($name as u32) << 16 |
-                       ($name as u32) <<  8 |
-                       ($name as u32)) }
-}
-macro_rules! repeat_u8_as_u64 {
-    ($name:expr) => { ((repeat_u8_as_u32!($name) as u64) << 32 |
-                       (repeat_u8_as_u32!($name) as u64)) }
-}
-
-// NOTE: Keep synchronized with values used in librustc_trans::trans::adt.
-//
-// In particular, the POST_DROP_U8 marker must never equal the
-// DTOR_NEEDED_U8 marker.
-//
-// For a while pnkfelix was using 0xc1 here.
-// But having the sign bit set is a pain, so 0x1d is probably better.
-//
-// And of course, 0x00 brings back the old world of zero'ing on drop.
-#[unstable(feature = "filling_drop", issue = "5016")]
-#[allow(missing_docs)]
-pub const POST_DROP_U8: u8 = 0x1d;
-#[unstable(feature = "filling_drop", issue = "5016")]
-#[allow(missing_docs)]
-pub const POST_DROP_U16: u16 = repeat_u8_as_u16!(POST_DROP_U8);
-#[unstable(feature = "filling_drop", issue = "5016")]
-#[allow(missing_docs)]
-pub const POST_DROP_U32: u32 = repeat_u8_as_u32!(POST_DROP_U8);
-#[unstable(feature = "filling_drop", issue = "5016")]
-#[allow(missing_docs)]
-pub const POST_DROP_U64: u64 = repeat_u8_as_u64!(POST_DROP_U8);
-
-#[cfg(target_pointer_width = "16")]
-#[unstable(feature = "filling_drop", issue = "5016")]
-#[allow(missing_docs)]
-pub const POST_DROP_USIZE: usize = POST_DROP_U16 as usize;
-#[cfg(target_pointer_width = "32")]
-#[unstable(feature = "filling_drop", issue = "5016")]
-#[allow(missing_docs)]
-pub const POST_DROP_USIZE: usize = POST_DROP_U32 as usize;
-#[cfg(target_pointer_width = "64")]
-#[unstable(feature = "filling_drop", issue = "5016")]
-#[allow(missing_docs)]
-pub const POST_DROP_USIZE: usize = POST_DROP_U64 as usize;
-
 /// Interprets `src` as `&U`, and then reads `src` without moving the contained
 /// value.
 ///
@@ -70,7 +70,7 @@ mod fpu_precision {
     pub struct FPUControlWord(u16);
 
     fn set_cw(cw: u16) {
-        unsafe { asm!("fldcw $0" :: "m" (cw) :: "volatile") }
+        unsafe { llvm_asm!("fldcw $0" :: "m" (cw) :: "volatile") }
     }
 
     /// Set the precision field of the FPU to `T` and return a `FPUControlWord`
@@ -86,7 +86,7 @@ mod fpu_precision {
 
         // Get the original value of the control word to restore it later, when the
         // `FPUControlWord` structure is dropped
-        unsafe { asm!("fnstcw $0" : "=*m" (&cw) ::: "volatile") }
+        unsafe { llvm_asm!("fnstcw $0" : "=*m" (&cw) ::: "volatile") }
 
         // Set the control word to the desired precision. This is achieved by masking away the old
         // precision (bits 8 and 9, 0x300) and replacing it with the precision flag computed above.
@@ -139,6 +139,137 @@ pub fn fast_path<T: RawFloat>(integral: &[u8], fractional: &[u8], e: i64) -> Opt
     }
 }
 
+/// The Eisel-Lemire algorithm, a fast path for inputs the simple fast path can't handle.
+///
+/// This multiplies the (normalized) leading 64 bits of the significand with a 128 bit truncated
+/// approximation of the power of five and checks whether the product is precise enough to
+/// determine the correctly rounded result. The check fails only very rarely, and in that case
+/// `None` is returned and the caller is expected to use one of the slower algorithms.
+///
+/// Inputs with more than 19 significant digits are truncated to 19 digits, and the algorithm is
+/// run for both the truncated significand `w` and `w + 1`. If both round to the same float, that
+/// float is also the correctly rounded result for the full input.
+///
+/// The algorithm is described in "Number Parsing at a Gigabyte per Second" by Daniel Lemire,
+/// available online: https://arxiv.org/abs/2101.11408
+pub fn eisel_lemire<T: RawFloat>(integral: &[u8], fractional: &[u8], e: i64) -> Option<T> {
+    let num_digits = integral.len() + fractional.len();
+    // 10^19 < 2^64, so 19 decimal digits always fit into a u64.
+    let taken = min(num_digits, 19);
+    let w = num::from_str_unchecked(integral.iter().chain(fractional.iter()).take(taken));
+    let q = e + (num_digits - taken) as i64;
+    let bits = match compute_float::<T>(q, w) {
+        Some(bits) => bits,
+        None => return None,
+    };
+    if taken < num_digits && compute_float::<T>(q, w + 1) != Some(bits) {
+        return None;
+    }
+    Some(T::from_bits(bits))
+}
+
+/// Compute the bit pattern of the float closest to `w * 10^q`, or `None` if the 128 bit
+/// approximation of `5^q` isn't precise enough to tell.
+fn compute_float<T: RawFloat>(q: i64, mut w: u64) -> Option<u64> {
+    let explicit_sig_bits = T::explicit_sig_bits() as i32;
+    let infinity = (T::max_encoded_exp() as u64) << explicit_sig_bits;
+    if w == 0 || q < T::smallest_power_of_ten() as i64 {
+        return Some(0);
+    } else if q > T::largest_power_of_ten() as i64 {
+        return Some(infinity);
+    }
+    let q = q as i32;
+    let lz = w.leading_zeros() as i32;
+    w <<= lz;
+    // Three more bits than the significand has: one for the (possibly zero) leading bit of the
+    // product, one for the hidden bit and one for rounding.
+    let (lo, hi) = product_approx(q, w, explicit_sig_bits as u32 + 3);
+    if lo == !0 {
+        // The lower bits of the product might be off by one, so a carry into the upper bits
+        // can't be ruled out, except when `5^q` is exact or nearly so.
+        if q < -27 || q > 55 {
+            return None;
+        }
+    }
+    let upper_bit = (hi >> 63) as i32;
+    let shift = upper_bit + 64 - explicit_sig_bits - 3;
+    let mut sig = hi >> shift;
+    let mut power2 = power(q) + upper_bit - lz + T::max_exp() as i32;
+    if power2 <= 0 {
+        // Subnormal (or zero), shift the significand into place and round half up. There's no
+        // need to care about ties: they can't occur for subnormals.
+        if -power2 + 1 >= 64 {
+            return Some(0);
+        }
+        sig >>= -power2 + 1;
+        sig += sig & 1;
+        sig >>= 1;
+        // Rounding up may have produced the smallest normal number.
+        let exp = (sig >= (1 << explicit_sig_bits)) as u64;
+        return Some(sig | (exp << explicit_sig_bits));
+    }
+    // If the value is exactly halfway between two floats, round to even instead of up.
+    if lo <= 1 &&
+       q >= T::min_exponent_round_to_even() as i32 &&
+       q <= T::max_exponent_round_to_even() as i32 &&
+       sig & 3 == 1 &&
+       (sig << shift) == hi {
+        sig &= !1;
+    }
+    sig += sig & 1;
+    sig >>= 1;
+    if sig >= (2 << explicit_sig_bits) {
+        // Rounding overflowed the significand.
+        sig = 1 << explicit_sig_bits;
+        power2 += 1;
+    }
+    sig &= !(1 << explicit_sig_bits);
+    if power2 >= T::max_encoded_exp() as i32 {
+        return Some(infinity);
+    }
+    Some(sig | ((power2 as u64) << explicit_sig_bits))
+}
+
+/// An approximation of `floor(log2(10^q)) + 63`, exact for `-1000 < q < 1000`.
+fn power(q: i32) -> i32 {
+    ((q * (152170 + 65536)) >> 16) + 63
+}
+
+/// Multiply the normalized `w` with the 128 bit approximation of `5^q` and return the upper 128
+/// bits of the product as `(low, high)`. Only computes the full product when the bits below
+/// `precision` bits of `high` are all ones, because only then could they change the result.
+fn product_approx(q: i32, w: u64, precision: u32) -> (u64, u64) {
+    debug_assert!(precision < 64);
+    let mask = !0 >> precision;
+    let index = q - table::SMALLEST_POWER_OF_FIVE as i32;
+    let (hi5, lo5) = table::POWERS_OF_FIVE_128[index as usize];
+    let (mut first_lo, mut first_hi) = full_mul(w, hi5);
+    if first_hi & mask == mask {
+        let (_, second_hi) = full_mul(w, lo5);
+        first_lo = first_lo.wrapping_add(second_hi);
+        if second_hi > first_lo {
+            first_hi += 1;
+        }
+    }
+    (first_lo, first_hi)
+}
+
+/// Compute the full 128 bit product of `a` and `b` as `(low, high)`.
+fn full_mul(a: u64, b: u64) -> (u64, u64) {
+    const MASK: u64 = 0xffff_ffff;
+    let (a_lo, a_hi) = (a & MASK, a >> 32);
+    let (b_lo, b_hi) = (b & MASK, b >> 32);
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+    // The sum of three 32 bit numbers can't overflow.
+    let mid = (lo_lo >> 32) + (hi_lo & MASK) + (lo_hi & MASK);
+    let lo = (mid << 32) | (lo_lo & MASK);
+    let hi = hi_hi + (hi_lo >> 32) + (lo_hi >> 32) + (mid >> 32);
+    (lo, hi)
+}
+
 /// Algorithm Bellerophon is trivial code justified by non-trivial numeric analysis.
 ///
 /// It rounds ``f`` to a float with 64 bit significand and multiplies it by the best approximation
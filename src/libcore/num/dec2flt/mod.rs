@@ -37,7 +37,8 @@
 //!
 //! We then try a long chain of progressively more general and expensive special cases using
 //! machine-sized integers and small, fixed-sized floating point numbers (first `f32`/`f64`, then
-//! a type with 64 bit significand, `Fp`). When all these fail, we bite the bullet and resort to a
+//! the 128 bit products of the Eisel-Lemire algorithm, then a type with 64 bit significand,
+//! `Fp`). When all these fail, we bite the bullet and resort to a
 //! simple but very slow algorithm that involved computing `f * 10^e` fully and doing an iterative
 //! search for the best approximation.
 //!
@@ -242,6 +243,9 @@ fn convert<T: RawFloat>(mut decimal: Decimal) -> Result<T, ParseFloatError> {
     if let Some(x) = algorithm::fast_path(decimal.integral, decimal.fractional, e) {
         return Ok(x);
     }
+    if let Some(x) = algorithm::eisel_lemire(decimal.integral, decimal.fractional, e) {
+        return Ok(x);
+    }
     // Big32x40 is limited to 1280 bits, which translates to about 385 decimal digits.
     // If we exceed this, we'll crash, so we error out before getting too close (within 10^10).
     let upper_bound = bound_intermediate_digits(&decimal, e);
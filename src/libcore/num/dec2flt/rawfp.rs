@@ -119,6 +119,22 @@ pub trait RawFloat : Float + Copy + Debug + LowerExp
     /// is certainly rounded to zero.
     fn zero_cutoff() -> i64;
 
+    /// The smallest decimal exponent `q` such that a number `w * 10^q` with a 64 bit `w` might
+    /// not be rounded to zero.
+    fn smallest_power_of_ten() -> i16;
+
+    /// The largest decimal exponent `q` such that a number `w * 10^q` with a non-zero 64 bit `w`
+    /// might not be rounded to infinity.
+    fn largest_power_of_ten() -> i16;
+
+    /// The range of decimal exponents `q` for which `w * 10^q` with a 64 bit `w` can be exactly
+    /// halfway between two adjacent floats. Outside of this range, ties to even need no special
+    /// treatment in the Eisel-Lemire algorithm.
+    fn min_exponent_round_to_even() -> i16;
+
+    /// See `min_exponent_round_to_even`.
+    fn max_exponent_round_to_even() -> i16;
+
     /// The number of bits in the exponent.
     fn exp_bits() -> u8;
 
@@ -219,6 +235,22 @@ impl RawFloat for f32 {
     fn zero_cutoff() -> i64 {
         -48
     }
+
+    fn smallest_power_of_ten() -> i16 {
+        -65
+    }
+
+    fn largest_power_of_ten() -> i16 {
+        38
+    }
+
+    fn min_exponent_round_to_even() -> i16 {
+        -17
+    }
+
+    fn max_exponent_round_to_even() -> i16 {
+        10
+    }
 }
 
 
@@ -275,6 +307,22 @@ impl RawFloat for f64 {
         -326
     }
 
+    fn smallest_power_of_ten() -> i16 {
+        -342
+    }
+
+    fn largest_power_of_ten() -> i16 {
+        308
+    }
+
+    fn min_exponent_round_to_even() -> i16 {
+        -4
+    }
+
+    fn max_exponent_round_to_even() -> i16 {
+        23
+    }
+
 }
 
 /// Convert an Fp to the closest f64. Only handles number that fit into a normalized f64.
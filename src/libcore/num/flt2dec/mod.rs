@@ -76,6 +76,12 @@ There are two classes of algorithms widely known to be correct.
   representation which is at least guaranteed to be shortest. The variant,
   Grisu3, actively detects if the resulting representation is incorrect.
 
+For the shortest mode only, there is also the more recent Ryū algorithm by
+Ulf Adams. It is similar to Grisu, but uses enough precision that no fallback is
+needed, and is noticeably faster than Grisu3. It is available in `strategy::ryu`
+and used by `Display` and `Debug`; it only implements `format_shortest` and
+falls back to Grisu for inputs it wasn't designed for.
+
 We implement both algorithms with necessary tweaks to suit our requirements.
 In particular, published literatures are short of the actual implementation
 difficulties like how to avoid arithmetic overflows. Each implementation,
@@ -141,6 +147,7 @@ pub mod decoder;
 pub mod strategy {
     pub mod dragon;
     pub mod grisu;
+    pub mod ryu;
 }
 
 /// The minimum size of buffer necessary for the shortest mode.
@@ -403,7 +410,7 @@ fn determine_sign(sign: Sign, decoded: &FullDecoded, negative: bool) -> &'static
 /// (which can be an empty string if no sign is rendered).
 ///
 /// `format_shortest` should be the underlying digit-generation function.
-/// You probably would want `strategy::grisu::format_shortest` for this.
+/// You probably would want `strategy::ryu::format_shortest` for this.
 ///
 /// `frac_digits` can be less than the number of actual fractional digits in `v`;
 /// it will be ignored and full digits will be printed. It is only used to print
@@ -458,7 +465,7 @@ pub fn to_shortest_str<'a, T, F>(mut format_shortest: F, v: T,
 /// an empty string if no sign is rendered).
 ///
 /// `format_shortest` should be the underlying digit-generation function.
-/// You probably would want `strategy::grisu::format_shortest` for this.
+/// You probably would want `strategy::ryu::format_shortest` for this.
 ///
 /// The `dec_bounds` is a tuple `(lo, hi)` such that the number is formatted
 /// as decimal only when `10^lo <= V < 10^hi`. Note that this is the *apparent* `V`
@@ -19,6 +19,7 @@ use fmt;
 use intrinsics;
 use marker::{Copy, Sized};
 use mem::size_of;
+use nonzero::NonZero;
 use option::Option::{self, Some, None};
 use result::Result::{self, Ok, Err};
 use str::{FromStr, StrExt};
@@ -95,6 +96,98 @@ impl<T: fmt::UpperHex> fmt::UpperHex for Wrapping<T> {
 
 mod wrapping;
 
+macro_rules! nonzero_integers {
+    ( $( $Ty: ident($Int: ty); )+ ) => {
+        $(
+            /// An integer that is known not to equal zero.
+            ///
+            /// This enables some memory layout optimization: an `Option` of a
+            /// non-zero integer is the same size as the integer itself, with
+            /// `None` represented as zero.
+            ///
+            /// ```rust
+            /// #![feature(nonzero_int)]
+            /// use std::mem::size_of;
+            /// use std::num::NonZeroU32;
+            ///
+            /// assert_eq!(size_of::<Option<NonZeroU32>>(), size_of::<u32>());
+            /// ```
+            #[unstable(feature = "nonzero_int", issue = "0")]
+            #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+            pub struct $Ty(NonZero<$Int>);
+
+            impl $Ty {
+                /// Creates a non-zero without checking the value.
+                ///
+                /// # Safety
+                ///
+                /// The value must not be zero.
+                #[unstable(feature = "nonzero_int", issue = "0")]
+                #[inline]
+                pub const unsafe fn new_unchecked(n: $Int) -> $Ty {
+                    $Ty(NonZero::new(n))
+                }
+
+                /// Creates a non-zero if the given value is not zero.
+                #[unstable(feature = "nonzero_int", issue = "0")]
+                #[inline]
+                pub fn new(n: $Int) -> Option<$Ty> {
+                    if n != 0 {
+                        Some(unsafe { $Ty::new_unchecked(n) })
+                    } else {
+                        None
+                    }
+                }
+
+                /// Returns the value as a primitive type.
+                #[unstable(feature = "nonzero_int", issue = "0")]
+                #[inline]
+                pub fn get(self) -> $Int {
+                    *self.0
+                }
+            }
+
+            #[unstable(feature = "nonzero_int", issue = "0")]
+            impl From<$Ty> for $Int {
+                fn from(nonzero: $Ty) -> $Int {
+                    nonzero.get()
+                }
+            }
+
+            impl_nonzero_fmt! {
+                (Debug, Display, Binary, Octal, LowerHex, UpperHex) for $Ty
+            }
+        )+
+    }
+}
+
+macro_rules! impl_nonzero_fmt {
+    ( ( $( $Trait: ident ),+ ) for $Ty: ident ) => {
+        $(
+            #[unstable(feature = "nonzero_int", issue = "0")]
+            impl fmt::$Trait for $Ty {
+                #[inline]
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    fmt::$Trait::fmt(&self.get(), f)
+                }
+            }
+        )+
+    }
+}
+
+nonzero_integers! {
+    NonZeroU8(u8);
+    NonZeroU16(u16);
+    NonZeroU32(u32);
+    NonZeroU64(u64);
+    NonZeroUsize(usize);
+    NonZeroI8(i8);
+    NonZeroI16(i16);
+    NonZeroI32(i32);
+    NonZeroI64(i64);
+    NonZeroIsize(isize);
+}
+
 // All these modules are technically private and only exposed for libcoretest:
 pub mod flt2dec;
 pub mod dec2flt;
@@ -296,6 +389,46 @@ macro_rules! int_impl {
             (self as $UnsignedT).trailing_zeros()
         }
 
+        /// Returns the number of leading ones in the binary representation
+        /// of `self`.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(leading_trailing_ones)]
+        ///
+        /// let n = -1i16;
+        ///
+        /// assert_eq!(n.leading_ones(), 16);
+        /// ```
+        #[unstable(feature = "leading_trailing_ones", issue = "0")]
+        #[inline]
+        pub fn leading_ones(self) -> u32 {
+            (self as $UnsignedT).leading_ones()
+        }
+
+        /// Returns the number of trailing ones in the binary representation
+        /// of `self`.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(leading_trailing_ones)]
+        ///
+        /// let n = 3i8;
+        ///
+        /// assert_eq!(n.trailing_ones(), 2);
+        /// ```
+        #[unstable(feature = "leading_trailing_ones", issue = "0")]
+        #[inline]
+        pub fn trailing_ones(self) -> u32 {
+            (self as $UnsignedT).trailing_ones()
+        }
+
         /// Shifts the bits to the left by a specified amount, `n`,
         /// wrapping the truncated bits to the end of the resulting integer.
         ///
@@ -357,6 +490,29 @@ macro_rules! int_impl {
             (self as $UnsignedT).swap_bytes() as Self
         }
 
+        /// Reverses the bit pattern of the integer.
+        ///
+        /// The least significant bit becomes the most significant bit, the
+        /// second least significant bit becomes the second most significant
+        /// bit, and so on.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(reverse_bits)]
+        ///
+        /// let n = 0b0000_0001i8;
+        ///
+        /// assert_eq!(n.reverse_bits(), i8::min_value());
+        /// ```
+        #[unstable(feature = "reverse_bits", issue = "0")]
+        #[inline]
+        pub fn reverse_bits(self) -> Self {
+            (self as $UnsignedT).reverse_bits() as Self
+        }
+
         /// Converts an integer from big endian to the target's endianness.
         ///
         /// On big endian this is a no-op. On little endian the bytes are
@@ -1469,6 +1625,46 @@ macro_rules! uint_impl {
             }
         }
 
+        /// Returns the number of leading ones in the binary representation
+        /// of `self`.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(leading_trailing_ones)]
+        ///
+        /// let n = !(u16::max_value() >> 2);
+        ///
+        /// assert_eq!(n.leading_ones(), 2);
+        /// ```
+        #[unstable(feature = "leading_trailing_ones", issue = "0")]
+        #[inline]
+        pub fn leading_ones(self) -> u32 {
+            (!self).leading_zeros()
+        }
+
+        /// Returns the number of trailing ones in the binary representation
+        /// of `self`.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(leading_trailing_ones)]
+        ///
+        /// let n = 0b1010111u8;
+        ///
+        /// assert_eq!(n.trailing_ones(), 3);
+        /// ```
+        #[unstable(feature = "leading_trailing_ones", issue = "0")]
+        #[inline]
+        pub fn trailing_ones(self) -> u32 {
+            (!self).trailing_zeros()
+        }
+
         /// Shifts the bits to the left by a specified amount, `n`,
         /// wrapping the truncated bits to the end of the resulting integer.
         ///
@@ -1534,6 +1730,48 @@ macro_rules! uint_impl {
             unsafe { $bswap(self as $ActualT) as Self }
         }
 
+        /// Reverses the bit pattern of the integer.
+        ///
+        /// The least significant bit becomes the most significant bit, the
+        /// second least significant bit becomes the second most significant
+        /// bit, and so on.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(reverse_bits)]
+        ///
+        /// let n = 0x12345678u32;
+        /// let m = 0x1E6A2C48u32;
+        ///
+        /// assert_eq!(n.reverse_bits(), m);
+        /// ```
+        #[unstable(feature = "reverse_bits", issue = "0")]
+        #[inline]
+        #[cfg(not(stage0))]
+        pub fn reverse_bits(self) -> Self {
+            unsafe { intrinsics::bitreverse(self as $ActualT) as Self }
+        }
+
+        /// Reverses the bit pattern of the integer.
+        #[unstable(feature = "reverse_bits", issue = "0")]
+        #[inline]
+        #[cfg(stage0)]
+        pub fn reverse_bits(self) -> Self {
+            // Swap the bytes first, then the nibbles, bit pairs and bits
+            // within every byte.
+            let m1: Self = !0 / 3;  // 0b0101...
+            let m2: Self = !0 / 5;  // 0b0011...
+            let m4: Self = !0 / 17; // 0b00001111...
+            let mut x = self.swap_bytes();
+            x = ((x >> 4) & m4) | ((x & m4) << 4);
+            x = ((x >> 2) & m2) | ((x & m2) << 2);
+            x = ((x >> 1) & m1) | ((x & m1) << 1);
+            x
+        }
+
         /// Converts an integer from big endian to the target's endianness.
         ///
         /// On big endian this is a no-op. On little endian the bytes are
@@ -2088,6 +2326,70 @@ macro_rules! uint_impl {
             }
         }
 
+        /// Calculates the complete product `self * rhs` without the
+        /// possibility to overflow.
+        ///
+        /// Returns a tuple of the low and the high half of the double-width
+        /// product.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage
+        ///
+        /// ```
+        /// #![feature(widening_mul)]
+        ///
+        /// assert_eq!(5u32.widening_mul(2), (10, 0));
+        /// assert_eq!(1_000_000_000u32.widening_mul(10), (1410065408, 2));
+        /// ```
+        #[inline]
+        #[unstable(feature = "widening_mul", issue = "0")]
+        pub fn widening_mul(self, rhs: Self) -> (Self, Self) {
+            // Schoolbook multiplication on half-width digits, none of
+            // which can overflow.
+            let half = $BITS / 2;
+            let mask: Self = !0 >> half;
+            let (a1, a0) = (self >> half, self & mask);
+            let (b1, b0) = (rhs >> half, rhs & mask);
+
+            let low = a0 * b0;
+            let cross1 = a0 * b1;
+            let cross2 = a1 * b0;
+            let mid = (low >> half) + (cross1 & mask) + (cross2 & mask);
+
+            let lo = (low & mask) | (mid << half);
+            let hi = a1 * b1 + (cross1 >> half) + (cross2 >> half) + (mid >> half);
+            (lo, hi)
+        }
+
+        /// Calculates `self * rhs + carry` without the possibility to
+        /// overflow.
+        ///
+        /// Returns a tuple of the low and the high half of the double-width
+        /// result. This is the building block of multi-word multiplication:
+        /// the high half is the carry into the next word.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage
+        ///
+        /// ```
+        /// #![feature(widening_mul)]
+        ///
+        /// assert_eq!(5u32.carrying_mul(2, 0), (10, 0));
+        /// assert_eq!(5u32.carrying_mul(2, 10), (20, 0));
+        /// assert_eq!(u32::max_value().carrying_mul(u32::max_value(), u32::max_value()),
+        ///            (0, u32::max_value()));
+        /// ```
+        #[inline]
+        #[unstable(feature = "widening_mul", issue = "0")]
+        pub fn carrying_mul(self, rhs: Self, carry: Self) -> (Self, Self) {
+            let (lo, hi) = self.widening_mul(rhs);
+            let (lo, overflow) = lo.overflowing_add(carry);
+            // `(MAX * MAX + MAX) >> BITS` is `MAX`, so this can't overflow.
+            (lo, hi + overflow as Self)
+        }
+
         /// Calculates the divisor when `self` is divided by `rhs`.
         ///
         /// Returns a tuple of the divisor along with a boolean indicating
@@ -70,6 +70,7 @@
 use cmp::PartialOrd;
 use fmt;
 use marker::{Sized, Unsize};
+use result::Result;
 
 /// The `Drop` trait is used to run some code when a value goes out of scope.
 /// This is sometimes called a 'destructor'.
@@ -2145,3 +2146,27 @@ pub trait BoxPlace<Data: ?Sized> : Place<Data> {
     /// Creates a globally fresh place.
     fn make_place() -> Self;
 }
+
+/// A trait for types which can short-circuit, such as `Result` and `Option`.
+///
+/// Every value of such a type either holds a value of type `Ok`, in which
+/// case processing should continue, or a value of type `Error`, in which case
+/// processing should stop and the error be handed back to the caller. This
+/// is used by the likes of `Iterator::try_fold` to be generic over the type
+/// which is threaded through.
+#[unstable(feature = "try_trait", issue = "0")]
+pub trait Try {
+    /// The type of the value when processing should continue.
+    type Ok;
+    /// The type of the value when processing should stop.
+    type Error;
+
+    /// Splits this value into a `Result` of the two cases.
+    fn into_result(self) -> Result<Self::Ok, Self::Error>;
+
+    /// Wraps an error value, which was previously produced by `into_result`.
+    fn from_error(v: Self::Error) -> Self;
+
+    /// Wraps a value to continue with.
+    fn from_ok(v: Self::Ok) -> Self;
+}
@@ -147,7 +147,7 @@ use default::Default;
 use iter::ExactSizeIterator;
 use iter::{Iterator, DoubleEndedIterator, FromIterator, IntoIterator};
 use mem;
-use ops::FnOnce;
+use ops::{FnOnce, Try};
 use result::Result::{Ok, Err};
 use result::Result;
 
@@ -762,6 +762,32 @@ impl<T> From<T> for Option<T> {
     }
 }
 
+/// The error type resulting from applying the `Try` trait to a `None` value.
+#[unstable(feature = "try_trait", issue = "0")]
+#[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
+pub struct NoneError;
+
+#[unstable(feature = "try_trait", issue = "0")]
+impl<T> Try for Option<T> {
+    type Ok = T;
+    type Error = NoneError;
+
+    #[inline]
+    fn into_result(self) -> Result<T, NoneError> {
+        self.ok_or(NoneError)
+    }
+
+    #[inline]
+    fn from_ok(v: T) -> Option<T> {
+        Some(v)
+    }
+
+    #[inline]
+    fn from_error(_: NoneError) -> Option<T> {
+        None
+    }
+}
+
 /////////////////////////////////////////////////////////////////////////////
 // The Option Iterators
 /////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,73 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The stack probing routine used by targets with `stack_probes` enabled.
+//!
+//! The compiler makes every function whose frame is larger than a page call
+//! `__rust_probestack` before it allocates the frame. The routine touches
+//! each page of the frame in order, so a stack overflow is guaranteed to hit
+//! the guard page below the stack rather than silently writing to whatever
+//! memory lies beyond it.
+//!
+//! The size of the frame is passed in `%eax`/`%rax`, and the routine must not
+//! clobber any other register, which is why it's written in assembly.
+
+#![unstable(feature = "probestack", issue = "0")]
+#![doc(hidden)]
+
+#[cfg(target_arch = "x86_64")]
+#[naked]
+#[no_mangle]
+pub unsafe extern fn __rust_probestack() {
+    // %rax = size of the frame, %r11 = bytes left to probe
+    llvm_asm!("
+        mov    %rax,%r11
+        cmp    $$0x1000,%r11
+        jna    3f
+    2:
+        sub    $$0x1000,%rsp
+        test   %rsp,8(%rsp)
+        sub    $$0x1000,%r11
+        cmp    $$0x1000,%r11
+        ja     2b
+    3:
+        sub    %r11,%rsp
+        test   %rsp,8(%rsp)
+
+        add    %rax,%rsp
+        ret
+    " ::: "memory" : "volatile");
+}
+
+#[cfg(target_arch = "x86")]
+#[naked]
+#[no_mangle]
+pub unsafe extern fn __rust_probestack() {
+    // %eax = size of the frame, %ecx = bytes left to probe
+    llvm_asm!("
+        push   %ecx
+        mov    %eax,%ecx
+        cmp    $$0x1000,%ecx
+        jna    3f
+    2:
+        sub    $$0x1000,%esp
+        test   %esp,8(%esp)
+        sub    $$0x1000,%ecx
+        cmp    $$0x1000,%ecx
+        ja     2b
+    3:
+        sub    %ecx,%esp
+        test   %esp,8(%esp)
+
+        add    %eax,%esp
+        pop    %ecx
+        ret
+    " ::: "memory" : "volatile");
+}
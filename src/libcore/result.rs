@@ -241,7 +241,7 @@ use self::Result::{Ok, Err};
 use clone::Clone;
 use fmt;
 use iter::{Iterator, DoubleEndedIterator, FromIterator, ExactSizeIterator, IntoIterator};
-use ops::FnOnce;
+use ops::{FnOnce, Try};
 use option::Option::{self, None, Some};
 
 /// `Result` is a type that represents either success (`Ok`) or failure (`Err`).
@@ -838,6 +838,27 @@ impl<'a, T, E> IntoIterator for &'a mut Result<T, E> {
     }
 }
 
+#[unstable(feature = "try_trait", issue = "0")]
+impl<T, E> Try for Result<T, E> {
+    type Ok = T;
+    type Error = E;
+
+    #[inline]
+    fn into_result(self) -> Result<T, E> {
+        self
+    }
+
+    #[inline]
+    fn from_ok(v: T) -> Result<T, E> {
+        Ok(v)
+    }
+
+    #[inline]
+    fn from_error(v: E) -> Result<T, E> {
+        Err(v)
+    }
+}
+
 /////////////////////////////////////////////////////////////////////////////
 // The Result Iterators
 /////////////////////////////////////////////////////////////////////////////
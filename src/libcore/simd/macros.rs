@@ -0,0 +1,530 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/// Defines the API shared by all vector types, integer and floating point.
+macro_rules! simd_vector {
+    ($id:ident: [$elem:ident; $lanes:expr], $mask:ident, $shuffle:ident,
+     reverse: $reverse:expr, interleave: $lo:expr, $hi:expr,
+     $($elem_ty:ty),* | $($field:ident),*) => {
+        #[repr(simd)]
+        #[derive(Copy, Clone, Debug)]
+        pub struct $id($($elem_ty),*);
+
+        impl $id {
+            /// Creates a new vector from the given lanes, lowest lane first.
+            #[inline]
+            pub fn new($($field: $elem),*) -> $id {
+                $id($($field),*)
+            }
+
+            /// Creates a new vector with all lanes set to `value`.
+            #[inline]
+            pub fn splat(value: $elem) -> $id {
+                $id($({ let $field = value; $field }),*)
+            }
+
+            /// Returns the number of lanes in this vector.
+            #[inline]
+            pub fn lanes() -> usize {
+                $lanes
+            }
+
+            /// Returns the value of the lane at `index`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index >= Self::lanes()`.
+            #[inline]
+            pub fn extract(self, index: usize) -> $elem {
+                assert!(index < $lanes, "lane index out of bounds");
+                unsafe { simd_extract(self, index as u32) }
+            }
+
+            /// Returns a copy of this vector with the lane at `index` set to
+            /// `value`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index >= Self::lanes()`.
+            #[inline]
+            pub fn replace(self, index: usize, value: $elem) -> $id {
+                assert!(index < $lanes, "lane index out of bounds");
+                unsafe { simd_insert(self, index as u32, value) }
+            }
+
+            /// Loads a vector from the first `Self::lanes()` elements of
+            /// `slice`, which doesn't need to be aligned.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is too short.
+            #[inline]
+            pub fn from_slice_unaligned(slice: &[$elem]) -> $id {
+                assert!(slice.len() >= $lanes, "slice is shorter than the vector");
+                let mut v = $id::splat(0 as $elem);
+                unsafe {
+                    ptr::copy_nonoverlapping(slice.as_ptr(),
+                                             &mut v as *mut $id as *mut $elem,
+                                             $lanes);
+                }
+                v
+            }
+
+            /// Stores this vector into the first `Self::lanes()` elements of
+            /// `slice`, which doesn't need to be aligned.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is too short.
+            #[inline]
+            pub fn write_to_slice_unaligned(self, slice: &mut [$elem]) {
+                assert!(slice.len() >= $lanes, "slice is shorter than the vector");
+                unsafe {
+                    ptr::copy_nonoverlapping(&self as *const $id as *const $elem,
+                                             slice.as_mut_ptr(),
+                                             $lanes);
+                }
+            }
+
+            /// Lane-wise `==`.
+            #[inline]
+            pub fn eq(self, other: $id) -> $mask {
+                unsafe { simd_eq(self, other) }
+            }
+
+            /// Lane-wise `!=`.
+            #[inline]
+            pub fn ne(self, other: $id) -> $mask {
+                unsafe { simd_ne(self, other) }
+            }
+
+            /// Lane-wise `<`.
+            #[inline]
+            pub fn lt(self, other: $id) -> $mask {
+                unsafe { simd_lt(self, other) }
+            }
+
+            /// Lane-wise `<=`.
+            #[inline]
+            pub fn le(self, other: $id) -> $mask {
+                unsafe { simd_le(self, other) }
+            }
+
+            /// Lane-wise `>`.
+            #[inline]
+            pub fn gt(self, other: $id) -> $mask {
+                unsafe { simd_gt(self, other) }
+            }
+
+            /// Lane-wise `>=`.
+            #[inline]
+            pub fn ge(self, other: $id) -> $mask {
+                unsafe { simd_ge(self, other) }
+            }
+
+            /// Picks each lane from `if_true` where the corresponding lane of
+            /// `mask` is set, and from `if_false` otherwise.
+            #[inline]
+            pub fn select(mask: $mask, if_true: $id, if_false: $id) -> $id {
+                unsafe {
+                    let t: $mask = mem::transmute(if_true);
+                    let f: $mask = mem::transmute(if_false);
+                    let bits = simd_or(simd_and(mask, t), simd_and(!mask, f));
+                    mem::transmute(bits)
+                }
+            }
+
+            /// Lane-wise minimum.
+            ///
+            /// For floating point vectors, a lane is taken from `other` if
+            /// either of the two lanes is NaN.
+            #[inline]
+            pub fn min(self, other: $id) -> $id {
+                $id::select(self.lt(other), self, other)
+            }
+
+            /// Lane-wise maximum.
+            ///
+            /// For floating point vectors, a lane is taken from `other` if
+            /// either of the two lanes is NaN.
+            #[inline]
+            pub fn max(self, other: $id) -> $id {
+                $id::select(self.gt(other), self, other)
+            }
+
+            /// Returns the smallest lane.
+            #[inline]
+            pub fn min_element(self) -> $elem {
+                let mut min = self.extract(0);
+                for i in 1..$lanes {
+                    let x = self.extract(i);
+                    if x < min {
+                        min = x;
+                    }
+                }
+                min
+            }
+
+            /// Returns the largest lane.
+            #[inline]
+            pub fn max_element(self) -> $elem {
+                let mut max = self.extract(0);
+                for i in 1..$lanes {
+                    let x = self.extract(i);
+                    if x > max {
+                        max = x;
+                    }
+                }
+                max
+            }
+
+            /// Returns a vector with the lanes in reverse order.
+            #[inline]
+            pub fn reverse(self) -> $id {
+                unsafe { $shuffle(self, self, $reverse) }
+            }
+
+            /// Interleaves the lanes of the low halves of `self` and `other`,
+            /// starting with the lowest lane of `self`.
+            #[inline]
+            pub fn interleave_low(self, other: $id) -> $id {
+                unsafe { $shuffle(self, other, $lo) }
+            }
+
+            /// Interleaves the lanes of the high halves of `self` and `other`,
+            /// starting with the middle lane of `self`.
+            #[inline]
+            pub fn interleave_high(self, other: $id) -> $id {
+                unsafe { $shuffle(self, other, $hi) }
+            }
+        }
+
+        impl Default for $id {
+            #[inline]
+            fn default() -> $id {
+                $id::splat(0 as $elem)
+            }
+        }
+
+        impl PartialEq for $id {
+            #[inline]
+            fn eq(&self, other: &$id) -> bool {
+                $id::eq(*self, *other).all()
+            }
+
+            #[inline]
+            fn ne(&self, other: &$id) -> bool {
+                $id::ne(*self, *other).any()
+            }
+        }
+
+        simd_binop! { $id: Add add, AddAssign add_assign, simd_add }
+        simd_binop! { $id: Sub sub, SubAssign sub_assign, simd_sub }
+        simd_binop! { $id: Mul mul, MulAssign mul_assign, simd_mul }
+    }
+}
+
+/// Implements an operator trait and its assigning counterpart with a generic
+/// SIMD intrinsic.
+macro_rules! simd_binop {
+    ($id:ident: $tr:ident $method:ident, $tr_assign:ident $method_assign:ident, $func:ident) => {
+        impl $tr for $id {
+            type Output = $id;
+
+            #[inline]
+            fn $method(self, other: $id) -> $id {
+                unsafe { $func(self, other) }
+            }
+        }
+
+        impl $tr_assign for $id {
+            #[inline]
+            fn $method_assign(&mut self, other: $id) {
+                *self = $tr::$method(*self, other);
+            }
+        }
+    }
+}
+
+/// Implements the bitwise operators.
+macro_rules! simd_bitops {
+    ($id:ident, $all_ones:expr) => {
+        simd_binop! { $id: BitAnd bitand, BitAndAssign bitand_assign, simd_and }
+        simd_binop! { $id: BitOr bitor, BitOrAssign bitor_assign, simd_or }
+        simd_binop! { $id: BitXor bitxor, BitXorAssign bitxor_assign, simd_xor }
+
+        impl Not for $id {
+            type Output = $id;
+
+            #[inline]
+            fn not(self) -> $id {
+                unsafe { simd_xor(self, $id::splat($all_ones)) }
+            }
+        }
+    }
+}
+
+/// Defines an integer vector type.
+///
+/// Like the wrapping methods of the primitive integers, arithmetic on integer
+/// vectors wraps around on overflow and shifts mask the shift amount.
+macro_rules! simd_int_vector {
+    ($id:ident: [$elem:ident; $lanes:expr], $mask:ident, $($rest:tt)*) => {
+        simd_vector! { $id: [$elem; $lanes], $mask, $($rest)* }
+        simd_bitops! { $id, !0 }
+
+        impl Eq for $id {}
+
+        impl Shl<u32> for $id {
+            type Output = $id;
+
+            #[inline]
+            fn shl(self, amount: u32) -> $id {
+                let bits = mem::size_of::<$elem>() as u32 * 8;
+                unsafe { simd_shl(self, $id::splat((amount & (bits - 1)) as $elem)) }
+            }
+        }
+
+        impl Shr<u32> for $id {
+            type Output = $id;
+
+            #[inline]
+            fn shr(self, amount: u32) -> $id {
+                let bits = mem::size_of::<$elem>() as u32 * 8;
+                unsafe { simd_shr(self, $id::splat((amount & (bits - 1)) as $elem)) }
+            }
+        }
+
+        impl $id {
+            /// Returns the sum of all lanes, wrapping around on overflow.
+            #[inline]
+            pub fn wrapping_sum(self) -> $elem {
+                let mut sum = self.extract(0);
+                for i in 1..$lanes {
+                    sum = sum.wrapping_add(self.extract(i));
+                }
+                sum
+            }
+
+            /// Returns the product of all lanes, wrapping around on overflow.
+            #[inline]
+            pub fn wrapping_product(self) -> $elem {
+                let mut product = self.extract(0);
+                for i in 1..$lanes {
+                    product = product.wrapping_mul(self.extract(i));
+                }
+                product
+            }
+
+            /// Returns the bitwise AND of all lanes.
+            #[inline]
+            pub fn and(self) -> $elem {
+                let mut x = self.extract(0);
+                for i in 1..$lanes {
+                    x &= self.extract(i);
+                }
+                x
+            }
+
+            /// Returns the bitwise OR of all lanes.
+            #[inline]
+            pub fn or(self) -> $elem {
+                let mut x = self.extract(0);
+                for i in 1..$lanes {
+                    x |= self.extract(i);
+                }
+                x
+            }
+
+            /// Returns the bitwise XOR of all lanes.
+            #[inline]
+            pub fn xor(self) -> $elem {
+                let mut x = self.extract(0);
+                for i in 1..$lanes {
+                    x ^= self.extract(i);
+                }
+                x
+            }
+        }
+    }
+}
+
+/// Implements `Neg` for a signed integer or floating point vector.
+macro_rules! simd_neg {
+    ($id:ident, $zero:expr) => {
+        impl Neg for $id {
+            type Output = $id;
+
+            #[inline]
+            fn neg(self) -> $id {
+                unsafe { simd_sub($id::splat($zero), self) }
+            }
+        }
+    }
+}
+
+/// Defines a floating point vector type.
+macro_rules! simd_float_vector {
+    ($id:ident: [$elem:ident; $lanes:expr], $mask:ident, bits: $bits:ident,
+     $($rest:tt)*) => {
+        simd_vector! { $id: [$elem; $lanes], $mask, $($rest)* }
+        simd_binop! { $id: Div div, DivAssign div_assign, simd_div }
+        // `-0.0 - x` rather than `0.0 - x`, so that `-0.0` becomes `0.0`.
+        simd_neg! { $id, -0.0 }
+
+        impl $id {
+            /// Returns the absolute value of each lane.
+            ///
+            /// This clears the sign bit, so `-0.0` becomes `0.0` and NaNs
+            /// become positive, like the `abs` method of the primitive type.
+            #[inline]
+            pub fn abs(self) -> $id {
+                unsafe {
+                    let bits: $bits = mem::transmute(self);
+                    mem::transmute(bits & $bits::splat(!0 >> 1))
+                }
+            }
+
+            /// Returns the sum of all lanes.
+            ///
+            /// The lanes are added in lane order, so the result is the same
+            /// as that of the equivalent scalar loop.
+            #[inline]
+            pub fn sum(self) -> $elem {
+                let mut sum = self.extract(0);
+                for i in 1..$lanes {
+                    sum += self.extract(i);
+                }
+                sum
+            }
+
+            /// Returns the product of all lanes, multiplied in lane order.
+            #[inline]
+            pub fn product(self) -> $elem {
+                let mut product = self.extract(0);
+                for i in 1..$lanes {
+                    product *= self.extract(i);
+                }
+                product
+            }
+        }
+    }
+}
+
+/// Defines a mask type: a vector of integers where each lane is either all
+/// ones (set) or all zeros (clear).
+macro_rules! simd_mask {
+    ($id:ident: [$elem:ident; $lanes:expr], $($elem_ty:ty),* | $($field:ident),*) => {
+        #[repr(simd)]
+        #[derive(Copy, Clone)]
+        pub struct $id($($elem_ty),*);
+
+        impl $id {
+            /// Creates a new mask from the given lanes, lowest lane first.
+            #[inline]
+            pub fn new($($field: bool),*) -> $id {
+                $id($(-($field as $elem)),*)
+            }
+
+            /// Creates a new mask with all lanes set to `value`.
+            #[inline]
+            pub fn splat(value: bool) -> $id {
+                let lane = -(value as $elem);
+                $id($({ let $field = lane; $field }),*)
+            }
+
+            /// Returns the number of lanes in this mask.
+            #[inline]
+            pub fn lanes() -> usize {
+                $lanes
+            }
+
+            /// Returns whether the lane at `index` is set.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index >= Self::lanes()`.
+            #[inline]
+            pub fn extract(self, index: usize) -> bool {
+                assert!(index < $lanes, "lane index out of bounds");
+                let lane: $elem = unsafe { simd_extract(self, index as u32) };
+                lane != 0
+            }
+
+            /// Returns a copy of this mask with the lane at `index` set to
+            /// `value`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index >= Self::lanes()`.
+            #[inline]
+            pub fn replace(self, index: usize, value: bool) -> $id {
+                assert!(index < $lanes, "lane index out of bounds");
+                unsafe { simd_insert(self, index as u32, -(value as $elem)) }
+            }
+
+            /// Returns `true` if any lane is set.
+            #[inline]
+            pub fn any(self) -> bool {
+                let mut any = 0;
+                for i in 0..$lanes {
+                    let lane: $elem = unsafe { simd_extract(self, i as u32) };
+                    any |= lane;
+                }
+                any != 0
+            }
+
+            /// Returns `true` if all lanes are set.
+            #[inline]
+            pub fn all(self) -> bool {
+                let mut all = !0;
+                for i in 0..$lanes {
+                    let lane: $elem = unsafe { simd_extract(self, i as u32) };
+                    all &= lane;
+                }
+                all != 0
+            }
+
+            /// Returns `true` if no lane is set.
+            #[inline]
+            pub fn none(self) -> bool {
+                !self.any()
+            }
+        }
+
+        impl Default for $id {
+            #[inline]
+            fn default() -> $id {
+                $id::splat(false)
+            }
+        }
+
+        impl PartialEq for $id {
+            #[inline]
+            fn eq(&self, other: &$id) -> bool {
+                (*self ^ *other).none()
+            }
+        }
+
+        impl Eq for $id {}
+
+        impl fmt::Debug for $id {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let mut builder = f.debug_tuple(stringify!($id));
+                for i in 0..$lanes {
+                    builder.field(&self.extract(i));
+                }
+                builder.finish()
+            }
+        }
+
+        simd_bitops! { $id, true }
+    }
+}
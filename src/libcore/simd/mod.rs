@@ -0,0 +1,360 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Portable SIMD vector types.
+//!
+//! The types in this module, named after their lane type and count like
+//! `f32x4` or `u8x16`, hold several values which are operated on at once.
+//! Unlike the vendor intrinsics in `arch`, they are available on every
+//! target: the operations lower to LLVM's generic vector instructions, which
+//! pick the best instructions the target supports and fall back to scalar
+//! code where there are none. Code written against them therefore needs no
+//! per-ISA `cfg` blocks.
+//!
+//! Arithmetic operators work lane-wise. Integer arithmetic wraps around on
+//! overflow, as if done with the `wrapping_*` methods of the primitive types.
+//!
+//! Comparisons such as `lt` return a mask, e.g. `m32x4` for `f32x4`. Masks
+//! can be combined with the bitwise operators, reduced with `any` and `all`,
+//! or used to pick lanes from two vectors with `select`. The `==` operator
+//! compares whole vectors.
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(portable_simd)]
+//!
+//! use std::simd::f32x4;
+//!
+//! fn dot(a: &[f32], b: &[f32]) -> f32 {
+//!     assert_eq!(a.len(), b.len());
+//!     let mut sums = f32x4::splat(0.0);
+//!     let chunks = a.len() / 4;
+//!     for i in 0..chunks {
+//!         let x = f32x4::from_slice_unaligned(&a[i * 4..]);
+//!         let y = f32x4::from_slice_unaligned(&b[i * 4..]);
+//!         sums += x * y;
+//!     }
+//!     let mut sum = sums.sum();
+//!     for i in chunks * 4..a.len() {
+//!         sum += a[i] * b[i];
+//!     }
+//!     sum
+//! }
+//!
+//! let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+//! let b = [2.0, 2.0, 2.0, 2.0, 2.0];
+//! assert_eq!(dot(&a, &b), 30.0);
+//!
+//! let v = f32x4::new(-1.0, 4.0, -9.0, 16.0);
+//! let negative = v.lt(f32x4::splat(0.0));
+//! assert!(negative.any() && !negative.all());
+//! assert_eq!(f32x4::select(negative, -v, v), v.abs());
+//! ```
+
+#![unstable(feature = "portable_simd", issue = "0")]
+#![allow(non_camel_case_types)]
+
+use cmp::{Eq, PartialEq};
+use default::Default;
+use fmt;
+use mem;
+use ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg, Not};
+use ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Shl, Shr};
+use ptr;
+use slice::SliceExt;
+
+#[macro_use]
+mod macros;
+
+extern "platform-intrinsic" {
+    fn simd_eq<T, U>(x: T, y: T) -> U;
+    fn simd_ne<T, U>(x: T, y: T) -> U;
+    fn simd_lt<T, U>(x: T, y: T) -> U;
+    fn simd_le<T, U>(x: T, y: T) -> U;
+    fn simd_gt<T, U>(x: T, y: T) -> U;
+    fn simd_ge<T, U>(x: T, y: T) -> U;
+
+    fn simd_shuffle2<T, U>(x: T, y: T, idx: [u32; 2]) -> U;
+    fn simd_shuffle4<T, U>(x: T, y: T, idx: [u32; 4]) -> U;
+    fn simd_shuffle8<T, U>(x: T, y: T, idx: [u32; 8]) -> U;
+    fn simd_shuffle16<T, U>(x: T, y: T, idx: [u32; 16]) -> U;
+    fn simd_shuffle32<T, U>(x: T, y: T, idx: [u32; 32]) -> U;
+
+    fn simd_insert<T, U>(x: T, idx: u32, val: U) -> T;
+    fn simd_extract<T, U>(x: T, idx: u32) -> U;
+
+    fn simd_add<T>(x: T, y: T) -> T;
+    fn simd_sub<T>(x: T, y: T) -> T;
+    fn simd_mul<T>(x: T, y: T) -> T;
+    fn simd_div<T>(x: T, y: T) -> T;
+    fn simd_shl<T>(x: T, y: T) -> T;
+    fn simd_shr<T>(x: T, y: T) -> T;
+    fn simd_and<T>(x: T, y: T) -> T;
+    fn simd_or<T>(x: T, y: T) -> T;
+    fn simd_xor<T>(x: T, y: T) -> T;
+}
+
+// 128-bit masks
+simd_mask! {
+    m8x16: [i8; 16],
+    i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8
+    |
+    x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15
+}
+simd_mask! {
+    m16x8: [i16; 8],
+    i16, i16, i16, i16, i16, i16, i16, i16
+    |
+    x0, x1, x2, x3, x4, x5, x6, x7
+}
+simd_mask! {
+    m32x4: [i32; 4],
+    i32, i32, i32, i32
+    |
+    x0, x1, x2, x3
+}
+simd_mask! {
+    m64x2: [i64; 2],
+    i64, i64
+    |
+    x0, x1
+}
+
+// 256-bit masks
+simd_mask! {
+    m8x32: [i8; 32],
+    i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8,
+    i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8
+    |
+    x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15, x16, x17, x18,
+    x19, x20, x21, x22, x23, x24, x25, x26, x27, x28, x29, x30, x31
+}
+simd_mask! {
+    m16x16: [i16; 16],
+    i16, i16, i16, i16, i16, i16, i16, i16, i16, i16, i16, i16, i16, i16, i16, i16
+    |
+    x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15
+}
+simd_mask! {
+    m32x8: [i32; 8],
+    i32, i32, i32, i32, i32, i32, i32, i32
+    |
+    x0, x1, x2, x3, x4, x5, x6, x7
+}
+simd_mask! {
+    m64x4: [i64; 4],
+    i64, i64, i64, i64
+    |
+    x0, x1, x2, x3
+}
+
+// 128-bit vectors
+simd_int_vector! {
+    i8x16: [i8; 16], m8x16, simd_shuffle16,
+    reverse: [15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+    interleave: [0, 16, 1, 17, 2, 18, 3, 19, 4, 20, 5, 21, 6, 22, 7, 23],
+                [8, 24, 9, 25, 10, 26, 11, 27, 12, 28, 13, 29, 14, 30, 15, 31],
+    i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8
+    |
+    x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15
+}
+simd_int_vector! {
+    u8x16: [u8; 16], m8x16, simd_shuffle16,
+    reverse: [15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+    interleave: [0, 16, 1, 17, 2, 18, 3, 19, 4, 20, 5, 21, 6, 22, 7, 23],
+                [8, 24, 9, 25, 10, 26, 11, 27, 12, 28, 13, 29, 14, 30, 15, 31],
+    u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8
+    |
+    x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15
+}
+simd_int_vector! {
+    i16x8: [i16; 8], m16x8, simd_shuffle8,
+    reverse: [7, 6, 5, 4, 3, 2, 1, 0],
+    interleave: [0, 8, 1, 9, 2, 10, 3, 11],
+                [4, 12, 5, 13, 6, 14, 7, 15],
+    i16, i16, i16, i16, i16, i16, i16, i16
+    |
+    x0, x1, x2, x3, x4, x5, x6, x7
+}
+simd_int_vector! {
+    u16x8: [u16; 8], m16x8, simd_shuffle8,
+    reverse: [7, 6, 5, 4, 3, 2, 1, 0],
+    interleave: [0, 8, 1, 9, 2, 10, 3, 11],
+                [4, 12, 5, 13, 6, 14, 7, 15],
+    u16, u16, u16, u16, u16, u16, u16, u16
+    |
+    x0, x1, x2, x3, x4, x5, x6, x7
+}
+simd_int_vector! {
+    i32x4: [i32; 4], m32x4, simd_shuffle4,
+    reverse: [3, 2, 1, 0],
+    interleave: [0, 4, 1, 5],
+                [2, 6, 3, 7],
+    i32, i32, i32, i32
+    |
+    x0, x1, x2, x3
+}
+simd_int_vector! {
+    u32x4: [u32; 4], m32x4, simd_shuffle4,
+    reverse: [3, 2, 1, 0],
+    interleave: [0, 4, 1, 5],
+                [2, 6, 3, 7],
+    u32, u32, u32, u32
+    |
+    x0, x1, x2, x3
+}
+simd_int_vector! {
+    i64x2: [i64; 2], m64x2, simd_shuffle2,
+    reverse: [1, 0],
+    interleave: [0, 2],
+                [1, 3],
+    i64, i64
+    |
+    x0, x1
+}
+simd_int_vector! {
+    u64x2: [u64; 2], m64x2, simd_shuffle2,
+    reverse: [1, 0],
+    interleave: [0, 2],
+                [1, 3],
+    u64, u64
+    |
+    x0, x1
+}
+simd_float_vector! {
+    f32x4: [f32; 4], m32x4, bits: u32x4, simd_shuffle4,
+    reverse: [3, 2, 1, 0],
+    interleave: [0, 4, 1, 5],
+                [2, 6, 3, 7],
+    f32, f32, f32, f32
+    |
+    x0, x1, x2, x3
+}
+simd_float_vector! {
+    f64x2: [f64; 2], m64x2, bits: u64x2, simd_shuffle2,
+    reverse: [1, 0],
+    interleave: [0, 2],
+                [1, 3],
+    f64, f64
+    |
+    x0, x1
+}
+
+// 256-bit vectors
+simd_int_vector! {
+    i8x32: [i8; 32], m8x32, simd_shuffle32,
+    reverse: [31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 16, 15, 14, 13, 12, 11,
+              10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+    interleave: [0, 32, 1, 33, 2, 34, 3, 35, 4, 36, 5, 37, 6, 38, 7, 39, 8, 40, 9, 41, 10, 42, 11,
+                 43, 12, 44, 13, 45, 14, 46, 15, 47],
+                [16, 48, 17, 49, 18, 50, 19, 51, 20, 52, 21, 53, 22, 54, 23, 55, 24, 56, 25, 57,
+                 26, 58, 27, 59, 28, 60, 29, 61, 30, 62, 31, 63],
+    i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8,
+    i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8
+    |
+    x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15, x16, x17, x18,
+    x19, x20, x21, x22, x23, x24, x25, x26, x27, x28, x29, x30, x31
+}
+simd_int_vector! {
+    u8x32: [u8; 32], m8x32, simd_shuffle32,
+    reverse: [31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 16, 15, 14, 13, 12, 11,
+              10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+    interleave: [0, 32, 1, 33, 2, 34, 3, 35, 4, 36, 5, 37, 6, 38, 7, 39, 8, 40, 9, 41, 10, 42, 11,
+                 43, 12, 44, 13, 45, 14, 46, 15, 47],
+                [16, 48, 17, 49, 18, 50, 19, 51, 20, 52, 21, 53, 22, 54, 23, 55, 24, 56, 25, 57,
+                 26, 58, 27, 59, 28, 60, 29, 61, 30, 62, 31, 63],
+    u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8,
+    u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8
+    |
+    x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15, x16, x17, x18,
+    x19, x20, x21, x22, x23, x24, x25, x26, x27, x28, x29, x30, x31
+}
+simd_int_vector! {
+    i16x16: [i16; 16], m16x16, simd_shuffle16,
+    reverse: [15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+    interleave: [0, 16, 1, 17, 2, 18, 3, 19, 4, 20, 5, 21, 6, 22, 7, 23],
+                [8, 24, 9, 25, 10, 26, 11, 27, 12, 28, 13, 29, 14, 30, 15, 31],
+    i16, i16, i16, i16, i16, i16, i16, i16, i16, i16, i16, i16, i16, i16, i16, i16
+    |
+    x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15
+}
+simd_int_vector! {
+    u16x16: [u16; 16], m16x16, simd_shuffle16,
+    reverse: [15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+    interleave: [0, 16, 1, 17, 2, 18, 3, 19, 4, 20, 5, 21, 6, 22, 7, 23],
+                [8, 24, 9, 25, 10, 26, 11, 27, 12, 28, 13, 29, 14, 30, 15, 31],
+    u16, u16, u16, u16, u16, u16, u16, u16, u16, u16, u16, u16, u16, u16, u16, u16
+    |
+    x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15
+}
+simd_int_vector! {
+    i32x8: [i32; 8], m32x8, simd_shuffle8,
+    reverse: [7, 6, 5, 4, 3, 2, 1, 0],
+    interleave: [0, 8, 1, 9, 2, 10, 3, 11],
+                [4, 12, 5, 13, 6, 14, 7, 15],
+    i32, i32, i32, i32, i32, i32, i32, i32
+    |
+    x0, x1, x2, x3, x4, x5, x6, x7
+}
+simd_int_vector! {
+    u32x8: [u32; 8], m32x8, simd_shuffle8,
+    reverse: [7, 6, 5, 4, 3, 2, 1, 0],
+    interleave: [0, 8, 1, 9, 2, 10, 3, 11],
+                [4, 12, 5, 13, 6, 14, 7, 15],
+    u32, u32, u32, u32, u32, u32, u32, u32
+    |
+    x0, x1, x2, x3, x4, x5, x6, x7
+}
+simd_int_vector! {
+    i64x4: [i64; 4], m64x4, simd_shuffle4,
+    reverse: [3, 2, 1, 0],
+    interleave: [0, 4, 1, 5],
+                [2, 6, 3, 7],
+    i64, i64, i64, i64
+    |
+    x0, x1, x2, x3
+}
+simd_int_vector! {
+    u64x4: [u64; 4], m64x4, simd_shuffle4,
+    reverse: [3, 2, 1, 0],
+    interleave: [0, 4, 1, 5],
+                [2, 6, 3, 7],
+    u64, u64, u64, u64
+    |
+    x0, x1, x2, x3
+}
+simd_float_vector! {
+    f32x8: [f32; 8], m32x8, bits: u32x8, simd_shuffle8,
+    reverse: [7, 6, 5, 4, 3, 2, 1, 0],
+    interleave: [0, 8, 1, 9, 2, 10, 3, 11],
+                [4, 12, 5, 13, 6, 14, 7, 15],
+    f32, f32, f32, f32, f32, f32, f32, f32
+    |
+    x0, x1, x2, x3, x4, x5, x6, x7
+}
+simd_float_vector! {
+    f64x4: [f64; 4], m64x4, bits: u64x4, simd_shuffle4,
+    reverse: [3, 2, 1, 0],
+    interleave: [0, 4, 1, 5],
+                [2, 6, 3, 7],
+    f64, f64, f64, f64
+    |
+    x0, x1, x2, x3
+}
+
+simd_neg! { i8x16, 0 }
+simd_neg! { i16x8, 0 }
+simd_neg! { i32x4, 0 }
+simd_neg! { i64x2, 0 }
+simd_neg! { i8x32, 0 }
+simd_neg! { i16x16, 0 }
+simd_neg! { i32x8, 0 }
+simd_neg! { i64x4, 0 }
@@ -33,6 +33,7 @@
 // * The `raw` and `bytes` submodules.
 // * Boilerplate trait implementations.
 
+use array::FixedSizeArray;
 use clone::Clone;
 use cmp::{Ordering, PartialEq, PartialOrd, Eq, Ord};
 use cmp::Ordering::{Less, Equal, Greater};
@@ -53,6 +54,8 @@ use mem;
 use marker::{Copy, Send, Sync, self};
 use iter_private::TrustedRandomAccess;
 
+mod sort;
+
 #[repr(C)]
 struct Repr<T> {
     pub data: *const T,
@@ -88,6 +91,11 @@ pub trait SliceExt {
     fn windows(&self, size: usize) -> Windows<Self::Item>;
     #[stable(feature = "core", since = "1.6.0")]
     fn chunks(&self, size: usize) -> Chunks<Self::Item>;
+    #[unstable(feature = "chunks_exact", issue = "0")]
+    fn chunks_exact(&self, chunk_size: usize) -> ChunksExact<Self::Item>;
+    #[unstable(feature = "array_chunks", issue = "0")]
+    fn array_chunks<A>(&self) -> ArrayChunks<Self::Item, A>
+        where A: FixedSizeArray<Self::Item>;
     #[stable(feature = "core", since = "1.6.0")]
     fn get(&self, index: usize) -> Option<&Self::Item>;
     #[stable(feature = "core", since = "1.6.0")]
@@ -139,12 +147,18 @@ pub trait SliceExt {
                       where P: FnMut(&Self::Item) -> bool;
     #[stable(feature = "core", since = "1.6.0")]
     fn chunks_mut(&mut self, chunk_size: usize) -> ChunksMut<Self::Item>;
+    #[unstable(feature = "chunks_exact", issue = "0")]
+    fn chunks_exact_mut(&mut self, chunk_size: usize) -> ChunksExactMut<Self::Item>;
     #[stable(feature = "core", since = "1.6.0")]
     fn swap(&mut self, a: usize, b: usize);
     #[stable(feature = "core", since = "1.6.0")]
     fn split_at_mut(&mut self, mid: usize) -> (&mut [Self::Item], &mut [Self::Item]);
     #[stable(feature = "core", since = "1.6.0")]
     fn reverse(&mut self);
+    #[unstable(feature = "slice_rotate", issue = "0")]
+    fn rotate_left(&mut self, mid: usize);
+    #[unstable(feature = "slice_rotate", issue = "0")]
+    fn rotate_right(&mut self, k: usize);
     #[stable(feature = "core", since = "1.6.0")]
     unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut Self::Item;
     #[stable(feature = "core", since = "1.6.0")]
@@ -163,6 +177,24 @@ pub trait SliceExt {
     fn clone_from_slice(&mut self, src: &[Self::Item]) where Self::Item: Clone;
     #[stable(feature = "copy_from_slice", since = "1.9.0")]
     fn copy_from_slice(&mut self, src: &[Self::Item]) where Self::Item: Copy;
+
+    #[unstable(feature = "sort_unstable", issue = "0")]
+    fn sort_unstable(&mut self)
+        where Self::Item: Ord;
+
+    #[unstable(feature = "sort_unstable", issue = "0")]
+    fn sort_unstable_by<F>(&mut self, compare: F)
+        where F: FnMut(&Self::Item, &Self::Item) -> Ordering;
+
+    #[unstable(feature = "sort_unstable", issue = "0")]
+    fn sort_unstable_by_key<B, F>(&mut self, f: F)
+        where F: FnMut(&Self::Item) -> B,
+              B: Ord;
+
+    #[unstable(feature = "slice_align_to", issue = "0")]
+    unsafe fn align_to<U>(&self) -> (&[Self::Item], &[U], &[Self::Item]);
+    #[unstable(feature = "slice_align_to", issue = "0")]
+    unsafe fn align_to_mut<U>(&mut self) -> (&mut [Self::Item], &mut [U], &mut [Self::Item]);
 }
 
 // Use macros to be generic over const/mut
@@ -266,6 +298,22 @@ impl<T> SliceExt for [T] {
         Chunks { v: self, size: size }
     }
 
+    #[inline]
+    fn chunks_exact(&self, chunk_size: usize) -> ChunksExact<T> {
+        assert!(chunk_size != 0);
+        let rem = self.len() % chunk_size;
+        let len = self.len() - rem;
+        let (fst, snd) = self.split_at(len);
+        ChunksExact { v: fst, rem: snd, chunk_size: chunk_size }
+    }
+
+    #[inline]
+    fn array_chunks<A>(&self) -> ArrayChunks<T, A> where A: FixedSizeArray<T> {
+        let n = <A as FixedSizeArray<T>>::len();
+        assert!(n != 0);
+        ArrayChunks { iter: self.chunks_exact(n), _marker: marker::PhantomData }
+    }
+
     #[inline]
     fn get(&self, index: usize) -> Option<&T> {
         if index < self.len() { Some(&self[index]) } else { None }
@@ -434,6 +482,15 @@ impl<T> SliceExt for [T] {
         ChunksMut { v: self, chunk_size: chunk_size }
     }
 
+    #[inline]
+    fn chunks_exact_mut(&mut self, chunk_size: usize) -> ChunksExactMut<T> {
+        assert!(chunk_size > 0);
+        let rem = self.len() % chunk_size;
+        let len = self.len() - rem;
+        let (fst, snd) = self.split_at_mut(len);
+        ChunksExactMut { v: fst, rem: snd, chunk_size: chunk_size }
+    }
+
     #[inline]
     fn swap(&mut self, a: usize, b: usize) {
         unsafe {
@@ -459,6 +516,24 @@ impl<T> SliceExt for [T] {
         }
     }
 
+    fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len());
+        // Rotating by reversal: reversing both halves and then the whole
+        // slice moves every element exactly twice, without any scratch space.
+        {
+            let (a, b) = self.split_at_mut(mid);
+            a.reverse();
+            b.reverse();
+        }
+        self.reverse();
+    }
+
+    fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.len());
+        let mid = self.len() - k;
+        self.rotate_left(mid);
+    }
+
     #[inline]
     unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
         &mut *self.as_mut_ptr().offset(index as isize)
@@ -521,6 +596,95 @@ impl<T> SliceExt for [T] {
     {
         self.binary_search_by(|k| f(k).cmp(b))
     }
+
+    #[inline]
+    fn sort_unstable(&mut self)
+        where Self::Item: Ord
+    {
+        sort::quicksort(self, |a, b| a.lt(b));
+    }
+
+    #[inline]
+    fn sort_unstable_by<F>(&mut self, mut compare: F)
+        where F: FnMut(&Self::Item, &Self::Item) -> Ordering
+    {
+        sort::quicksort(self, |a, b| compare(a, b) == Ordering::Less);
+    }
+
+    #[inline]
+    fn sort_unstable_by_key<B, F>(&mut self, mut f: F)
+        where F: FnMut(&Self::Item) -> B,
+              B: Ord
+    {
+        sort::quicksort(self, |a, b| f(a).lt(&f(b)));
+    }
+
+    unsafe fn align_to<U>(&self) -> (&[T], &[U], &[T]) {
+        let (prefix, us) = align_to_offsets::<T, U>(self.as_ptr(), self.len());
+        if us == 0 {
+            // Nothing fits, but the empty middle still has to be aligned.
+            let len = self.len();
+            let (all, none) = self.split_at(len);
+            return (all, from_raw_parts(mem::align_of::<U>() as *const U, 0), none);
+        }
+        let ts = us * mem::size_of::<U>() / mem::size_of::<T>();
+        let (left, rest) = self.split_at(prefix);
+        let (mid, right) = rest.split_at(ts);
+        (left, from_raw_parts(mid.as_ptr() as *const U, us), right)
+    }
+
+    unsafe fn align_to_mut<U>(&mut self) -> (&mut [T], &mut [U], &mut [T]) {
+        let (prefix, us) = align_to_offsets::<T, U>(self.as_ptr(), self.len());
+        if us == 0 {
+            // Nothing fits, but the empty middle still has to be aligned.
+            let len = self.len();
+            let (all, none) = self.split_at_mut(len);
+            return (all, from_raw_parts_mut(mem::align_of::<U>() as *mut U, 0), none);
+        }
+        let ts = us * mem::size_of::<U>() / mem::size_of::<T>();
+        let (left, rest) = self.split_at_mut(prefix);
+        let (mid, right) = rest.split_at_mut(ts);
+        (left, from_raw_parts_mut(mid.as_mut_ptr() as *mut U, us), right)
+    }
+}
+
+/// Splits the `len` elements of type `T` starting at `ptr` for `align_to`.
+///
+/// Returns the number of `T`s which have to be skipped until the address is
+/// suitably aligned for a `U`, and the number of `U`s which fit after that
+/// while still ending on a `T` boundary. Zero-sized types never fit.
+fn align_to_offsets<T, U>(ptr: *const T, len: usize) -> (usize, usize) {
+    fn gcd(mut a: usize, mut b: usize) -> usize {
+        while b != 0 {
+            let t = a % b;
+            a = b;
+            b = t;
+        }
+        a
+    }
+
+    let t_size = mem::size_of::<T>();
+    let u_size = mem::size_of::<U>();
+    let u_align = mem::align_of::<U>();
+    let addr = ptr as usize;
+    if t_size == 0 || u_size == 0 {
+        return (len, 0);
+    }
+
+    // Stepping by `t_size` the address modulo `u_align` repeats after at most
+    // `u_align` steps, so if none of those lines up it never will.
+    let mut prefix = 0;
+    while addr.wrapping_add(prefix.wrapping_mul(t_size)) % u_align != 0 {
+        prefix += 1;
+        if prefix >= len || prefix >= u_align {
+            return (len, 0);
+        }
+    }
+
+    // `us * u_size` has to be a multiple of `t_size`.
+    let step = t_size / gcd(t_size, u_size);
+    let us = (len - prefix) * t_size / u_size;
+    (prefix, us - us % step)
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -1704,6 +1868,280 @@ impl<'a, T> DoubleEndedIterator for ChunksMut<'a, T> {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a, T> ExactSizeIterator for ChunksMut<'a, T> {}
 
+/// An iterator over a slice in (non-overlapping) chunks (`chunk_size`
+/// elements at a time).
+///
+/// When the slice len is not evenly divided by the chunk size, the last up to
+/// `chunk_size-1` elements will be omitted but can be retrieved from the
+/// `remainder` function from the iterator.
+///
+/// Because every chunk has exactly `chunk_size` elements, the compiler can
+/// often optimize the resulting code better than for `Chunks`.
+#[derive(Debug)]
+#[unstable(feature = "chunks_exact", issue = "0")]
+pub struct ChunksExact<'a, T:'a> {
+    v: &'a [T],
+    rem: &'a [T],
+    chunk_size: usize
+}
+
+impl<'a, T> ChunksExact<'a, T> {
+    /// Returns the remainder of the original slice that is not going to be
+    /// returned by the iterator. The returned slice has at most
+    /// `chunk_size-1` elements.
+    #[unstable(feature = "chunks_exact", issue = "0")]
+    pub fn remainder(&self) -> &'a [T] {
+        self.rem
+    }
+}
+
+// FIXME(#19839) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> Clone for ChunksExact<'a, T> {
+    fn clone(&self) -> ChunksExact<'a, T> {
+        ChunksExact {
+            v: self.v,
+            rem: self.rem,
+            chunk_size: self.chunk_size,
+        }
+    }
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> Iterator for ChunksExact<'a, T> {
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.v.len() < self.chunk_size {
+            None
+        } else {
+            let (fst, snd) = self.v.split_at(self.chunk_size);
+            self.v = snd;
+            Some(fst)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.v.len() / self.chunk_size;
+        (n, Some(n))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (start, overflow) = n.overflowing_mul(self.chunk_size);
+        if start >= self.v.len() || overflow {
+            self.v = &[];
+            None
+        } else {
+            let (_, snd) = self.v.split_at(start);
+            self.v = snd;
+            self.next()
+        }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> DoubleEndedIterator for ChunksExact<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.v.len() < self.chunk_size {
+            None
+        } else {
+            let (fst, snd) = self.v.split_at(self.v.len() - self.chunk_size);
+            self.v = fst;
+            Some(snd)
+        }
+    }
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> ExactSizeIterator for ChunksExact<'a, T> {}
+
+/// An iterator over a slice in (non-overlapping) mutable chunks
+/// (`chunk_size` elements at a time).
+///
+/// When the slice len is not evenly divided by the chunk size, the last up to
+/// `chunk_size-1` elements will be omitted but can be retrieved from the
+/// `into_remainder` function from the iterator.
+#[derive(Debug)]
+#[unstable(feature = "chunks_exact", issue = "0")]
+pub struct ChunksExactMut<'a, T:'a> {
+    v: &'a mut [T],
+    rem: &'a mut [T],
+    chunk_size: usize
+}
+
+impl<'a, T> ChunksExactMut<'a, T> {
+    /// Returns the remainder of the original slice that is not going to be
+    /// returned by the iterator. The returned slice has at most
+    /// `chunk_size-1` elements.
+    #[unstable(feature = "chunks_exact", issue = "0")]
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.rem
+    }
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> Iterator for ChunksExactMut<'a, T> {
+    type Item = &'a mut [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        if self.v.len() < self.chunk_size {
+            None
+        } else {
+            let tmp = mem::replace(&mut self.v, &mut []);
+            let (head, tail) = tmp.split_at_mut(self.chunk_size);
+            self.v = tail;
+            Some(head)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.v.len() / self.chunk_size;
+        (n, Some(n))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<&'a mut [T]> {
+        let (start, overflow) = n.overflowing_mul(self.chunk_size);
+        if start >= self.v.len() || overflow {
+            self.v = &mut [];
+            None
+        } else {
+            let tmp = mem::replace(&mut self.v, &mut []);
+            let (_, snd) = tmp.split_at_mut(start);
+            self.v = snd;
+            self.next()
+        }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> DoubleEndedIterator for ChunksExactMut<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut [T]> {
+        if self.v.len() < self.chunk_size {
+            None
+        } else {
+            let tmp = mem::replace(&mut self.v, &mut []);
+            let tmp_len = tmp.len();
+            let (head, tail) = tmp.split_at_mut(tmp_len - self.chunk_size);
+            self.v = head;
+            Some(tail)
+        }
+    }
+}
+
+#[unstable(feature = "chunks_exact", issue = "0")]
+impl<'a, T> ExactSizeIterator for ChunksExactMut<'a, T> {}
+
+/// An iterator over a slice in (non-overlapping) chunks, each of which is
+/// viewed as a reference to the array type `A`.
+///
+/// This is the typed counterpart of `ChunksExact`: when the slice len is not
+/// evenly divided by the array length, the last elements are omitted but can
+/// be retrieved from the `remainder` function from the iterator.
+#[derive(Debug)]
+#[unstable(feature = "array_chunks", issue = "0")]
+pub struct ArrayChunks<'a, T:'a, A:'a> {
+    iter: ChunksExact<'a, T>,
+    _marker: marker::PhantomData<&'a A>,
+}
+
+impl<'a, T, A> ArrayChunks<'a, T, A> {
+    /// Returns the remainder of the original slice that is not going to be
+    /// returned by the iterator. The returned slice has fewer elements than
+    /// the array type `A`.
+    #[unstable(feature = "array_chunks", issue = "0")]
+    pub fn remainder(&self) -> &'a [T] {
+        self.iter.remainder()
+    }
+}
+
+// FIXME(#19839) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "array_chunks", issue = "0")]
+impl<'a, T, A> Clone for ArrayChunks<'a, T, A> {
+    fn clone(&self) -> ArrayChunks<'a, T, A> {
+        ArrayChunks {
+            iter: self.iter.clone(),
+            _marker: marker::PhantomData,
+        }
+    }
+}
+
+#[inline]
+fn cast_chunk<T, A: FixedSizeArray<T>>(chunk: &[T]) -> &A {
+    debug_assert_eq!(chunk.len(), <A as FixedSizeArray<T>>::len());
+    // `FixedSizeArray` guarantees the layout of an array of `T`s, and the
+    // chunk has exactly that many elements.
+    unsafe { &*(chunk.as_ptr() as *const A) }
+}
+
+#[unstable(feature = "array_chunks", issue = "0")]
+impl<'a, T, A: FixedSizeArray<T>> Iterator for ArrayChunks<'a, T, A> {
+    type Item = &'a A;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a A> {
+        self.iter.next().map(cast_chunk)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<&'a A> {
+        self.iter.nth(n).map(cast_chunk)
+    }
+
+    #[inline]
+    fn last(self) -> Option<&'a A> {
+        self.iter.last().map(cast_chunk)
+    }
+}
+
+#[unstable(feature = "array_chunks", issue = "0")]
+impl<'a, T, A: FixedSizeArray<T>> DoubleEndedIterator for ArrayChunks<'a, T, A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a A> {
+        self.iter.next_back().map(cast_chunk)
+    }
+}
+
+#[unstable(feature = "array_chunks", issue = "0")]
+impl<'a, T, A: FixedSizeArray<T>> ExactSizeIterator for ArrayChunks<'a, T, A> {}
+
 //
 // Free functions
 //
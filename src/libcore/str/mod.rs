@@ -1316,9 +1316,9 @@ Section: Trait implementations
 mod traits {
     use cmp::{Ord, Ordering, PartialEq, PartialOrd, Eq};
     use option::Option;
-    use option::Option::Some;
+    use option::Option::{Some, None};
     use ops;
-    use str::{StrExt, eq_slice};
+    use str::{StrExt, SliceIndex, eq_slice};
 
     #[stable(feature = "rust1", since = "1.0.0")]
     impl Ord for str {
@@ -1590,6 +1590,122 @@ mod traits {
             self.index_mut(0...index.end)
         }
     }
+
+    #[unstable(feature = "str_checked_slicing", issue = "0")]
+    impl SliceIndex for ops::Range<usize> {
+        #[inline]
+        fn get(self, slice: &str) -> Option<&str> {
+            // is_char_boundary checks that the index is in [0, .len()]
+            if self.start <= self.end &&
+               slice.is_char_boundary(self.start) &&
+               slice.is_char_boundary(self.end) {
+                Some(unsafe { slice.slice_unchecked(self.start, self.end) })
+            } else {
+                None
+            }
+        }
+
+        #[inline]
+        fn get_mut(self, slice: &mut str) -> Option<&mut str> {
+            if self.start <= self.end &&
+               slice.is_char_boundary(self.start) &&
+               slice.is_char_boundary(self.end) {
+                Some(unsafe { slice.slice_mut_unchecked(self.start, self.end) })
+            } else {
+                None
+            }
+        }
+    }
+
+    #[unstable(feature = "str_checked_slicing", issue = "0")]
+    impl SliceIndex for ops::RangeTo<usize> {
+        #[inline]
+        fn get(self, slice: &str) -> Option<&str> {
+            (0..self.end).get(slice)
+        }
+
+        #[inline]
+        fn get_mut(self, slice: &mut str) -> Option<&mut str> {
+            (0..self.end).get_mut(slice)
+        }
+    }
+
+    #[unstable(feature = "str_checked_slicing", issue = "0")]
+    impl SliceIndex for ops::RangeFrom<usize> {
+        #[inline]
+        fn get(self, slice: &str) -> Option<&str> {
+            (self.start..slice.len()).get(slice)
+        }
+
+        #[inline]
+        fn get_mut(self, slice: &mut str) -> Option<&mut str> {
+            let len = slice.len();
+            (self.start..len).get_mut(slice)
+        }
+    }
+
+    #[unstable(feature = "str_checked_slicing", issue = "0")]
+    impl SliceIndex for ops::RangeFull {
+        #[inline]
+        fn get(self, slice: &str) -> Option<&str> {
+            Some(slice)
+        }
+
+        #[inline]
+        fn get_mut(self, slice: &mut str) -> Option<&mut str> {
+            Some(slice)
+        }
+    }
+
+    #[unstable(feature = "str_checked_slicing", issue = "0")]
+    impl SliceIndex for ops::RangeInclusive<usize> {
+        #[inline]
+        fn get(self, slice: &str) -> Option<&str> {
+            match self {
+                ops::RangeInclusive::Empty { .. } => Some(""),
+                ops::RangeInclusive::NonEmpty { end, .. } if end == usize::max_value() => None,
+                ops::RangeInclusive::NonEmpty { start, end } => (start..end + 1).get(slice),
+            }
+        }
+
+        #[inline]
+        fn get_mut(self, slice: &mut str) -> Option<&mut str> {
+            match self {
+                ops::RangeInclusive::Empty { .. } => (0..0).get_mut(slice),
+                ops::RangeInclusive::NonEmpty { end, .. } if end == usize::max_value() => None,
+                ops::RangeInclusive::NonEmpty { start, end } => (start..end + 1).get_mut(slice),
+            }
+        }
+    }
+
+    #[unstable(feature = "str_checked_slicing", issue = "0")]
+    impl SliceIndex for ops::RangeToInclusive<usize> {
+        #[inline]
+        fn get(self, slice: &str) -> Option<&str> {
+            (0...self.end).get(slice)
+        }
+
+        #[inline]
+        fn get_mut(self, slice: &mut str) -> Option<&mut str> {
+            (0...self.end).get_mut(slice)
+        }
+    }
+}
+
+/// A range which can be used to take a substring of a `str` without panicking.
+///
+/// This is implemented for all range types `str` can be indexed with, and is
+/// used by `str::get` and `str::get_mut`.
+#[unstable(feature = "str_checked_slicing", issue = "0")]
+pub trait SliceIndex {
+    /// Returns the substring of `slice` in this range, or `None` if the range
+    /// is out of bounds or doesn't start and end on character boundaries.
+    fn get(self, slice: &str) -> Option<&str>;
+
+    /// Returns the mutable substring of `slice` in this range, or `None` if
+    /// the range is out of bounds or doesn't start and end on character
+    /// boundaries.
+    fn get_mut(self, slice: &mut str) -> Option<&mut str>;
 }
 
 /// Methods for string slices
@@ -1660,6 +1776,10 @@ pub trait StrExt {
         where P::Searcher: ReverseSearcher<'a>;
     #[stable(feature = "is_char_boundary", since = "1.9.0")]
     fn is_char_boundary(&self, index: usize) -> bool;
+    #[unstable(feature = "str_checked_slicing", issue = "0")]
+    fn get<I: SliceIndex>(&self, index: I) -> Option<&str>;
+    #[unstable(feature = "str_checked_slicing", issue = "0")]
+    fn get_mut<I: SliceIndex>(&mut self, index: I) -> Option<&mut str>;
     #[stable(feature = "core", since = "1.6.0")]
     fn as_bytes(&self) -> &[u8];
     #[stable(feature = "core", since = "1.6.0")]
@@ -1900,6 +2020,16 @@ impl StrExt for str {
         }
     }
 
+    #[inline]
+    fn get<I: SliceIndex>(&self, index: I) -> Option<&str> {
+        index.get(self)
+    }
+
+    #[inline]
+    fn get_mut<I: SliceIndex>(&mut self, index: I) -> Option<&mut str> {
+        index.get_mut(self)
+    }
+
     #[inline]
     fn as_bytes(&self) -> &[u8] {
         unsafe { mem::transmute(self) }
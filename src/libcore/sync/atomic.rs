@@ -1048,6 +1048,50 @@ macro_rules! atomic_int {
             pub fn fetch_xor(&self, val: $int_type, order: Ordering) -> $int_type {
                 unsafe { atomic_xor(self.v.get(), val, order) }
             }
+
+            /// Fetches the value, and applies a function to it that returns an optional
+            /// new value. Returns a `Result` of `Ok(previous_value)` if the function returned
+            /// `Some(_)`, else `Err(previous_value)`.
+            ///
+            /// The function may be called multiple times if the value has been changed by other
+            /// threads in the meantime, as long as it keeps returning `Some(_)`, but the new value
+            /// is only stored once.
+            ///
+            /// `fetch_update` takes two `Ordering` arguments to describe the memory ordering of
+            /// this operation. The first describes the required ordering for when the operation
+            /// finally succeeds while the second describes the required ordering for loads. These
+            /// correspond to the success and failure orderings of `compare_exchange`
+            /// respectively.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(atomic_fetch_update)]
+            /// use std::sync::atomic::{AtomicIsize, Ordering};
+            ///
+            /// let x = AtomicIsize::new(7);
+            /// assert_eq!(x.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |_| None), Err(7));
+            /// assert_eq!(x.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| Some(x + 1)), Ok(7));
+            /// assert_eq!(x.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| Some(x + 1)), Ok(8));
+            /// assert_eq!(x.load(Ordering::SeqCst), 9);
+            /// ```
+            #[inline]
+            #[unstable(feature = "atomic_fetch_update", issue = "0")]
+            pub fn fetch_update<F>(&self,
+                                   set_order: Ordering,
+                                   fetch_order: Ordering,
+                                   mut f: F) -> Result<$int_type, $int_type>
+                where F: FnMut($int_type) -> Option<$int_type>
+            {
+                let mut prev = self.load(fetch_order);
+                while let Some(next) = f(prev) {
+                    match self.compare_exchange_weak(prev, next, set_order, fetch_order) {
+                        Ok(x) => return Ok(x),
+                        Err(next_prev) => prev = next_prev,
+                    }
+                }
+                Err(prev)
+            }
         }
     }
 }
@@ -0,0 +1,124 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::arch::x86_64::*;
+use core::mem::transmute;
+
+// SSE2 is part of the x86_64 baseline, so these always run.
+
+#[test]
+fn test_sse2_add_sub() {
+    unsafe {
+        let a = _mm_set_epi32(4, 3, 2, 1);
+        let b = _mm_set_epi32(40, 30, 20, 10);
+        let r: [i32; 4] = transmute(_mm_add_epi32(a, b));
+        assert_eq!(r, [11, 22, 33, 44]);
+        let r: [i32; 4] = transmute(_mm_sub_epi32(a, b));
+        assert_eq!(r, [-9, -18, -27, -36]);
+
+        let r: [u8; 16] = transmute(_mm_adds_epu8(_mm_set1_epi8(-56), _mm_set1_epi8(100)));
+        assert_eq!(r, [255; 16]);
+        let r: [u8; 16] = transmute(_mm_subs_epu8(_mm_set1_epi8(10), _mm_set1_epi8(20)));
+        assert_eq!(r, [0; 16]);
+    }
+}
+
+#[test]
+fn test_sse2_load_store() {
+    unsafe {
+        let src: [u8; 17] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        // Deliberately misaligned.
+        let v = _mm_loadu_si128(src[1..].as_ptr() as *const __m128i);
+        let mut dst = [0u8; 16];
+        _mm_storeu_si128(dst.as_mut_ptr() as *mut __m128i, v);
+        assert_eq!(&dst[..], &src[1..]);
+        assert_eq!(_mm_cvtsi128_si32(v), 0x04030201);
+        assert_eq!(_mm_cvtsi128_si64(v), 0x0807060504030201);
+    }
+}
+
+#[test]
+fn test_sse2_cmp_movemask() {
+    unsafe {
+        let a = _mm_setr_epi32(1, 2, 3, 4);
+        let b = _mm_setr_epi32(1, 0, 3, 0);
+        assert_eq!(_mm_movemask_epi8(_mm_cmpeq_epi32(a, b)), 0x0f0f);
+        assert_eq!(_mm_movemask_epi8(_mm_cmpgt_epi32(a, b)), 0xf0f0);
+        assert_eq!(_mm_movemask_epi8(_mm_setzero_si128()), 0);
+    }
+}
+
+#[test]
+fn test_sse2_shifts() {
+    unsafe {
+        let a = _mm_set1_epi32(0x10);
+        let r: [i32; 4] = transmute(_mm_slli_epi32(a, 4));
+        assert_eq!(r, [0x100; 4]);
+        let r: [i32; 4] = transmute(_mm_srli_epi32(a, 4));
+        assert_eq!(r, [1; 4]);
+        // Out of range counts clear the vector instead of being undefined.
+        let r: [i32; 4] = transmute(_mm_slli_epi32(a, 32));
+        assert_eq!(r, [0; 4]);
+    }
+}
+
+#[test]
+fn test_sse2_pd() {
+    unsafe {
+        let a = _mm_set_pd(9.0, 4.0);
+        let r: [f64; 2] = transmute(_mm_sqrt_pd(a));
+        assert_eq!(r, [2.0, 3.0]);
+        let r: [f64; 2] = transmute(_mm_max_pd(a, _mm_set1_pd(5.0)));
+        assert_eq!(r, [5.0, 9.0]);
+    }
+}
+
+#[test]
+#[cfg(target_feature = "sse4.2")]
+fn test_sse42_cmpistri() {
+    unsafe {
+        let a = _mm_loadu_si128(b"Hello\0\0\0\0\0\0\0\0\0\0\0".as_ptr() as *const _);
+        let b = _mm_loadu_si128(b"  Hello, world!\0".as_ptr() as *const _);
+        assert_eq!(_mm_cmpistri(a, b, _SIDD_CMP_EQUAL_ORDERED), 2);
+        assert_eq!(_mm_crc32_u8(!0, 0), 0xad82acae);
+    }
+}
+
+#[test]
+#[cfg(target_feature = "avx2")]
+fn test_avx2() {
+    unsafe {
+        let a = _mm256_set_epi32(8, 7, 6, 5, 4, 3, 2, 1);
+        let r: [i32; 8] = transmute(_mm256_add_epi32(a, a));
+        assert_eq!(r, [2, 4, 6, 8, 10, 12, 14, 16]);
+        let r: [i32; 8] = transmute(_mm256_abs_epi32(_mm256_sub_epi32(_mm256_setzero_si256(), a)));
+        assert_eq!(r, [1, 2, 3, 4, 5, 6, 7, 8]);
+        let idx = _mm256_set_epi32(0, 1, 2, 3, 4, 5, 6, 7);
+        let r: [i32; 8] = transmute(_mm256_permutevar8x32_epi32(a, idx));
+        assert_eq!(r, [8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(_mm256_movemask_epi8(_mm256_cmpeq_epi8(a, a)), -1);
+    }
+}
+
+#[test]
+fn test_cpuid() {
+    assert!(has_cpuid());
+    unsafe {
+        // Leaf 0 holds the highest basic leaf and the vendor string, and
+        // every x86_64 CPU reports SSE2 in leaf 1.
+        let CpuidResult { eax, ebx, ecx, edx } = __cpuid(0);
+        assert!(eax >= 1);
+        let vendor: [u32; 3] = [ebx, edx, ecx];
+        let vendor: [u8; 12] = transmute(vendor);
+        assert!(vendor.iter().all(|&b| b >= b' ' && b <= b'~'));
+        assert!(__cpuid(1).edx & (1 << 26) != 0);
+        assert_eq!(__cpuid(1), __cpuid_count(1, 0));
+    }
+}
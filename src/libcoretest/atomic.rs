@@ -70,6 +70,30 @@ fn int_xor() {
     assert_eq!(x.load(SeqCst), 0xf731 ^ 0x137f);
 }
 
+#[test]
+fn uint_fetch_update() {
+    let x = AtomicUsize::new(7);
+    assert_eq!(x.fetch_update(SeqCst, SeqCst, |x| if x < 8 { Some(x + 1) } else { None }),
+               Ok(7));
+    assert_eq!(x.fetch_update(SeqCst, SeqCst, |x| if x < 8 { Some(x + 1) } else { None }),
+               Err(8));
+    assert_eq!(x.load(SeqCst), 8);
+}
+
+#[test]
+fn sized_int_wrapping() {
+    let x = AtomicU8::new(0xff);
+    assert_eq!(x.fetch_add(1, SeqCst), 0xff);
+    assert_eq!(x.load(SeqCst), 0);
+
+    let y = AtomicI16::new(i16::min_value());
+    assert_eq!(y.fetch_sub(1, SeqCst), i16::min_value());
+    assert_eq!(y.load(SeqCst), i16::max_value());
+
+    let z = AtomicU32::new(0);
+    assert_eq!(z.fetch_update(SeqCst, SeqCst, |z| z.checked_sub(1)), Err(0));
+}
+
 static S_FALSE: AtomicBool = AtomicBool::new(false);
 static S_TRUE: AtomicBool = AtomicBool::new(true);
 static S_INT: AtomicIsize  = AtomicIsize::new(0);
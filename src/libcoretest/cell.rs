@@ -248,4 +248,46 @@ fn refcell_ref_coercion() {
     }
 }
 
+#[test]
+fn once_cell() {
+    let c = OnceCell::new();
+    assert!(c.get().is_none());
+    assert_eq!(*c.get_or_init(|| 92), 92);
+    assert_eq!(*c.get_or_init(|| panic!()), 92);
+    assert_eq!(c.set(62), Err(62));
+    assert_eq!(c.get(), Some(&92));
+
+    let mut c = c;
+    *c.get_mut().unwrap() += 1;
+    assert_eq!(c.into_inner(), Some(93));
+    assert_eq!(OnceCell::<i32>::new().into_inner(), None);
+}
+
+#[test]
+#[should_panic]
+fn once_cell_reentrant_init() {
+    let c = OnceCell::new();
+    c.get_or_init(|| *c.get_or_init(|| 1) + 1);
+}
+
+#[test]
+fn once_cell_debug() {
+    let c = OnceCell::new();
+    assert_eq!(format!("{:?}", c), "OnceCell(<uninit>)");
+    c.set(1).unwrap();
+    assert_eq!(format!("{:?}", c), "OnceCell(1)");
+}
 
+#[test]
+fn lazy_new() {
+    let called = Cell::new(0);
+    let x = Lazy::new(|| {
+        called.set(called.get() + 1);
+        92
+    });
+    assert_eq!(called.get(), 0);
+    assert_eq!(*x, 92);
+    assert_eq!(called.get(), 1);
+    assert_eq!(*Lazy::force(&x), 92);
+    assert_eq!(called.get(), 1);
+}
@@ -384,3 +384,18 @@ fn test_decode_utf8() {
                                    .map(|r_b| r_b.unwrap_or('\u{FFFD}'))));
     }
 }
+
+#[test]
+fn test_try_from_u32() {
+    use std::convert::{TryFrom, TryInto};
+
+    assert_eq!(<char as TryFrom<u32>>::try_from(0x41), Ok('A'));
+    assert_eq!(<char as TryFrom<u32>>::try_from(0x10FFFF), Ok('\u{10FFFF}'));
+    assert!(<char as TryFrom<u32>>::try_from(0xD800).is_err());
+    assert!(<char as TryFrom<u32>>::try_from(0xDFFF).is_err());
+    assert!(<char as TryFrom<u32>>::try_from(0x110000).is_err());
+
+    let c: Result<char, _> = 0x2764u32.try_into();
+    assert_eq!(c, Ok('❤'));
+    assert_eq!(char::from_u32(0xD800), None);
+}
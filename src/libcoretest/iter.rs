@@ -248,6 +248,44 @@ fn test_iterator_peekable_count() {
 
 }
 
+#[test]
+fn test_iterator_peekable_peek_mut() {
+    let mut it = vec![1, 2, 3].into_iter().peekable();
+    if let Some(p) = it.peek_mut() {
+        if *p == 1 {
+            *p = 5;
+        }
+    }
+    assert_eq!(it.collect::<Vec<_>>(), vec![5, 2, 3]);
+
+    let mut it = (0..0).peekable();
+    assert_eq!(it.peek_mut(), None);
+}
+
+#[test]
+fn test_iterator_peekable_next_if() {
+    let mut it = (0..10).peekable();
+    assert_eq!(it.next_if(|&x| x == 5), None);
+    assert_eq!(it.next_if(|&x| x == 0), Some(0));
+    assert_eq!(it.peek(), Some(&1));
+    while it.next_if(|&x| x < 8).is_some() {}
+    assert_eq!(it.len(), 2);
+    assert_eq!(it.next(), Some(8));
+
+    let mut it = (0..0).peekable();
+    assert_eq!(it.next_if(|_| true), None);
+}
+
+#[test]
+fn test_iterator_peekable_next_if_eq() {
+    let mut it = vec!["Heart", "of", "Gold"].into_iter().peekable();
+    assert_eq!(it.next_if_eq(&"trillian"), None);
+    assert_eq!(it.next_if_eq(&"Heart"), Some("Heart"));
+    assert_eq!(it.next_if_eq(&"of"), Some("of"));
+    assert_eq!(it.next(), Some("Gold"));
+    assert_eq!(it.next_if_eq(&"Gold"), None);
+}
+
 #[test]
 fn test_iterator_peekable_nth() {
     let xs = [0, 1, 2, 3, 4, 5];
@@ -302,6 +340,134 @@ fn test_iterator_skip_while() {
     assert_eq!(i, ys.len());
 }
 
+#[test]
+fn test_iterator_step_by() {
+    let xs = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+    let ys: Vec<_> = xs.iter().cloned().step_by(3).collect();
+    assert_eq!(ys, [0, 3, 6, 9]);
+
+    let mut it = xs.iter().step_by(4);
+    assert_eq!(it.next(), Some(&0));
+    assert_eq!(it.next(), Some(&4));
+    assert_eq!(it.next(), Some(&8));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+
+    let mut it = xs.iter().step_by(1);
+    assert_eq!(it.next(), Some(&0));
+    assert_eq!(it.len(), 9);
+
+    let empty: [i32; 0] = [];
+    assert_eq!(empty.iter().step_by(2).next(), None);
+}
+
+#[test]
+fn test_iterator_step_by_size_hint() {
+    let xs = [0; 10];
+    let mut it = xs.iter().step_by(3);
+    assert_eq!(it.size_hint(), (4, Some(4)));
+    it.next();
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    it.next();
+    it.next();
+    assert_eq!(it.size_hint(), (1, Some(1)));
+    it.next();
+    assert_eq!(it.size_hint(), (0, Some(0)));
+
+    assert_eq!(xs.iter().step_by(10).size_hint(), (1, Some(1)));
+    assert_eq!(xs.iter().step_by(11).size_hint(), (1, Some(1)));
+    assert_eq!(xs.iter().filter(|_| true).step_by(2).size_hint(), (0, Some(5)));
+}
+
+#[test]
+#[should_panic]
+fn test_iterator_step_by_zero() {
+    let _ = [0, 1].iter().step_by(0);
+}
+
+#[test]
+fn test_try_fold() {
+    let xs = [1, 2, 3, 4, 5];
+    let sum = |acc: i32, &x: &i32| acc.checked_add(x);
+    assert_eq!(xs.iter().try_fold(0, &sum), Some(15));
+
+    let mut it = xs.iter();
+    assert_eq!(it.try_fold(0, |acc, &x| if x == 3 { None } else { Some(acc + x) }),
+               None);
+    assert_eq!(it.next(), Some(&4));
+
+    let r: Result<i32, i32> = xs.iter().try_fold(0, |acc, &x| {
+        if acc > 5 { Err(acc) } else { Ok(acc + x) }
+    });
+    assert_eq!(r, Err(6));
+}
+
+#[test]
+fn test_try_fold_adaptors() {
+    let xs = [1, 2, 3, 4, 5];
+    let ys = [10, 20, 30];
+    let ok = |acc: i32, x: i32| -> Result<i32, ()> { Ok(acc + x) };
+    let stop_at = |stop| move |acc: i32, x: i32| if x == stop { Err(acc) } else { Ok(acc + x) };
+
+    let mut chain = xs.iter().chain(ys.iter()).cloned();
+    assert_eq!(chain.try_fold(0, &ok), Ok(75));
+    let mut chain = xs.iter().chain(ys.iter()).cloned();
+    assert_eq!(chain.try_fold(0, stop_at(20)), Err(25));
+    assert_eq!(chain.next(), Some(30));
+    assert_eq!(chain.next(), None);
+
+    let mut flat = (3..5).flat_map(|n| 0..n);
+    assert_eq!(flat.try_fold(0, stop_at(1)), Err(0));
+    assert_eq!(flat.collect::<Vec<_>>(), [2, 0, 1, 2, 3]);
+    let mut flat = (0..4).flat_map(|n| 0..n);
+    assert_eq!(flat.next_back(), Some(2));
+    assert_eq!(flat.try_fold(0, &ok), Ok(2));
+    assert_eq!(flat.next(), None);
+
+    let mut filter = xs.iter().cloned().filter(|&x| x % 2 == 1);
+    assert_eq!(filter.try_fold(0, stop_at(5)), Err(4));
+    assert_eq!(filter.next(), None);
+
+    let mut map = xs.iter().map(|&x| x * 10);
+    assert_eq!(map.try_fold(0, stop_at(30)), Err(30));
+    assert_eq!(map.next(), Some(40));
+
+    let mut enumerate = xs.iter().enumerate();
+    assert_eq!(enumerate.try_fold(0, |acc, (i, &x)| {
+        if i == 2 { Err(acc) } else { Ok(acc + x) }
+    }), Err(3));
+    assert_eq!(enumerate.next(), Some((3, &4)));
+}
+
+#[test]
+fn test_try_for_each() {
+    let xs = [1, 2, 3, 4];
+    let mut seen = Vec::new();
+    let r = xs.iter().try_for_each(|&x| {
+        seen.push(x);
+        if x < 3 { Ok(()) } else { Err(x) }
+    });
+    assert_eq!(r, Err(3));
+    assert_eq!(seen, [1, 2, 3]);
+    assert_eq!(xs.iter().try_for_each(|_| Some(())), Some(()));
+}
+
+#[test]
+fn test_short_circuit_through_adaptors() {
+    let xs = [1, 2, 3];
+    let ys = [4, 5, 6];
+    let mut chain = xs.iter().chain(ys.iter());
+    assert_eq!(chain.position(|&x| x == 4), Some(3));
+    assert!(chain.any(|&x| x == 5));
+    assert!(!chain.all(|&x| x == 5));
+    assert_eq!(chain.next(), None);
+
+    let slices = [&xs[..], &ys[..]];
+    let mut flat = slices.iter().flat_map(|s| s.iter());
+    assert_eq!(flat.find(|&&x| x > 2), Some(&3));
+    assert_eq!(flat.next(), Some(&4));
+}
+
 #[test]
 fn test_iterator_skip() {
     let xs = [0, 1, 2, 3, 5, 13, 15, 16, 17, 19, 20, 30];
@@ -486,6 +652,29 @@ fn test_iterator_flat_map() {
     assert_eq!(i, ys.len());
 }
 
+#[test]
+fn test_iterator_flatten() {
+    let xs = [0, 3, 6];
+    let ys = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+    let it = xs.iter().map(|&x| (x..).step_by(1).take(3)).flatten();
+    let mut i = 0;
+    for x in it {
+        assert_eq!(x, ys[i]);
+        i += 1;
+    }
+    assert_eq!(i, ys.len());
+
+    let nested = vec![vec![], vec![1], vec![], vec![2, 3], vec![]];
+    assert_eq!(nested.into_iter().flatten().collect::<Vec<i32>>(), [1, 2, 3]);
+
+    let opts = [Some(1), None, Some(3)];
+    let mut it = opts.iter().flatten();
+    assert_eq!(it.size_hint(), (0, None));
+    assert_eq!(it.next(), Some(&1));
+    assert_eq!(it.try_fold(0, |acc, &x| Some(acc + x)), Some(3));
+    assert_eq!(it.next(), None);
+}
+
 #[test]
 fn test_inspect() {
     let xs = [1, 2, 3, 4];
@@ -829,6 +1018,23 @@ fn test_rposition_panic() {
 }
 
 
+#[test]
+fn test_double_ended_flatten() {
+    let u = [0,1];
+    let v = [5,6,7,8];
+    let mut it = u.iter().map(|x| &v[*x..v.len()]).flatten();
+    assert_eq!(it.next_back().unwrap(), &8);
+    assert_eq!(it.next().unwrap(),      &5);
+    assert_eq!(it.next_back().unwrap(), &7);
+    assert_eq!(it.next_back().unwrap(), &6);
+    assert_eq!(it.next_back().unwrap(), &8);
+    assert_eq!(it.next().unwrap(),      &6);
+    assert_eq!(it.next_back().unwrap(), &7);
+    assert_eq!(it.next_back(), None);
+    assert_eq!(it.next(),      None);
+    assert_eq!(it.next_back(), None);
+}
+
 #[test]
 fn test_double_ended_flat_map() {
     let u = [0,1];
@@ -884,8 +1090,8 @@ fn test_range() {
 #[test]
 fn test_range_step() {
     assert_eq!((0..20).step_by(5).collect::<Vec<isize>>(), [0, 5, 10, 15]);
-    assert_eq!((20..0).step_by(-5).collect::<Vec<isize>>(), [20, 15, 10, 5]);
-    assert_eq!((20..0).step_by(-6).collect::<Vec<isize>>(), [20, 14, 8, 2]);
+    assert_eq!((1..21).rev().step_by(5).collect::<Vec<isize>>(), [20, 15, 10, 5]);
+    assert_eq!((0..21).rev().step_by(6).collect::<Vec<isize>>(), [20, 14, 8, 2]);
     assert_eq!((200..255).step_by(50).collect::<Vec<u8>>(), [200, 250]);
     assert_eq!((200..-5).step_by(1).collect::<Vec<isize>>(), []);
     assert_eq!((200..200).step_by(1).collect::<Vec<isize>>(), []);
@@ -893,13 +1099,12 @@ fn test_range_step() {
     assert_eq!((0..20).step_by(1).size_hint(), (20, Some(20)));
     assert_eq!((0..20).step_by(21).size_hint(), (1, Some(1)));
     assert_eq!((0..20).step_by(5).size_hint(), (4, Some(4)));
-    assert_eq!((20..0).step_by(-5).size_hint(), (4, Some(4)));
-    assert_eq!((20..0).step_by(-6).size_hint(), (4, Some(4)));
+    assert_eq!((1..21).rev().step_by(5).size_hint(), (4, Some(4)));
+    assert_eq!((0..21).rev().step_by(6).size_hint(), (4, Some(4)));
     assert_eq!((20..-5).step_by(1).size_hint(), (0, Some(0)));
     assert_eq!((20..20).step_by(1).size_hint(), (0, Some(0)));
-    assert_eq!((0..1).step_by(0).size_hint(), (0, None));
-    assert_eq!((i8::MAX..i8::MIN).step_by(i8::MIN).size_hint(), (2, Some(2)));
-    assert_eq!((i16::MIN..i16::MAX).step_by(i16::MAX).size_hint(), (3, Some(3)));
+    assert_eq!((i8::MIN..i8::MAX).step_by(128).size_hint(), (2, Some(2)));
+    assert_eq!((i16::MIN..i16::MAX).step_by(i16::MAX as usize).size_hint(), (3, Some(3)));
     assert_eq!((isize::MIN..isize::MAX).step_by(1).size_hint(), (usize::MAX, Some(usize::MAX)));
 }
 
@@ -10,11 +10,15 @@
 
 #![deny(warnings)]
 
+#![feature(array_chunks)]
 #![feature(as_unsafe_cell)]
+#![feature(atomic_fetch_update)]
 #![feature(borrow_state)]
 #![feature(box_syntax)]
 #![feature(cell_extras)]
+#![feature(cfg_target_feature)]
 #![feature(char_escape_debug)]
+#![feature(chunks_exact)]
 #![feature(const_fn)]
 #![feature(core_private_bignum)]
 #![feature(core_private_diy_float)]
@@ -22,17 +26,32 @@
 #![feature(decode_utf8)]
 #![feature(fixed_size_array)]
 #![feature(flt2dec)]
+#![feature(integer_atomics)]
+#![feature(iterator_flatten)]
+#![feature(iterator_step_by)]
+#![feature(iterator_try_fold)]
+#![feature(leading_trailing_ones)]
 #![feature(libc)]
+#![feature(manually_drop)]
+#![feature(maybe_uninit)]
 #![feature(nonzero)]
+#![feature(nonzero_int)]
+#![feature(once_cell)]
+#![feature(peekable_next_if)]
+#![feature(peekable_peek_mut)]
+#![feature(portable_simd)]
 #![feature(rand)]
 #![feature(raw)]
+#![feature(reverse_bits)]
 #![feature(sip_hash_13)]
+#![feature(slice_align_to)]
 #![feature(slice_patterns)]
-#![feature(step_by)]
+#![feature(stdsimd)]
 #![feature(test)]
 #![feature(try_from)]
 #![feature(unicode)]
 #![feature(unique)]
+#![feature(widening_mul)]
 
 extern crate core;
 extern crate test;
@@ -41,6 +60,8 @@ extern crate rustc_unicode;
 extern crate rand;
 
 mod any;
+#[cfg(target_arch = "x86_64")]
+mod arch;
 mod array;
 mod atomic;
 mod cell;
@@ -58,6 +79,7 @@ mod ops;
 mod option;
 mod ptr;
 mod result;
+mod simd;
 mod slice;
 mod str;
 mod tuple;
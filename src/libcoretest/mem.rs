@@ -121,6 +121,95 @@ fn test_transmute() {
     }
 }
 
+#[test]
+fn test_maybe_uninit_write() {
+    let mut x = MaybeUninit::<String>::uninit();
+    *x.write("hello".to_string()) += " world";
+    unsafe {
+        assert_eq!(*x.assume_init_ref(), "hello world");
+        assert_eq!(x.assume_init(), "hello world");
+    }
+}
+
+#[test]
+fn test_maybe_uninit_no_drop() {
+    use core::cell::Cell;
+
+    struct IncOnDrop<'a>(&'a Cell<usize>);
+    impl<'a> Drop for IncOnDrop<'a> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    drop(MaybeUninit::new(IncOnDrop(&drops)));
+    assert_eq!(drops.get(), 0);
+
+    let mut x = MaybeUninit::uninit();
+    x.write(IncOnDrop(&drops));
+    x.write(IncOnDrop(&drops));
+    assert_eq!(drops.get(), 0);
+    drop(unsafe { x.assume_init() });
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn test_maybe_uninit_layout() {
+    assert_eq!(size_of::<MaybeUninit<u64>>(), size_of::<u64>());
+    assert_eq!(align_of::<MaybeUninit<u64>>(), align_of::<u64>());
+    // The contents may be uninitialized, so there is no niche to use.
+    assert!(size_of::<Option<MaybeUninit<&u8>>>() > size_of::<&u8>());
+}
+
+#[test]
+fn test_maybe_uninit_slice() {
+    let mut buf = [MaybeUninit::<u32>::uninit(); 4];
+    unsafe {
+        let p = MaybeUninit::slice_as_mut_ptr(&mut buf);
+        for i in 0..4 {
+            *p.offset(i as isize) = i * 2;
+        }
+        assert_eq!(MaybeUninit::slice_assume_init_ref(&buf), [0, 2, 4, 6]);
+        MaybeUninit::slice_assume_init_mut(&mut buf)[1] = 3;
+        assert_eq!(*buf[1].assume_init_ref(), 3);
+    }
+    assert_eq!(MaybeUninit::slice_as_ptr(&buf), buf[0].as_ptr());
+
+    let zeroed = MaybeUninit::<[u16; 3]>::zeroed();
+    assert_eq!(unsafe { zeroed.assume_init() }, [0; 3]);
+}
+
+#[test]
+fn test_manually_drop() {
+    use core::cell::RefCell;
+
+    struct Logged<'a>(&'static str, &'a RefCell<Vec<&'static str>>);
+    impl<'a> Drop for Logged<'a> {
+        fn drop(&mut self) {
+            self.1.borrow_mut().push(self.0);
+        }
+    }
+
+    let log = RefCell::new(vec![]);
+    {
+        let mut first = ManuallyDrop::new(Logged("first", &log));
+        let _second = Logged("second", &log);
+        let third = ManuallyDrop::new(Logged("third", &log));
+        assert_eq!(third.0, "third");
+        drop(third);
+        unsafe { ManuallyDrop::drop(&mut first); }
+    }
+    assert_eq!(*log.borrow(), ["first", "second"]);
+
+    let x = ManuallyDrop::new(Logged("fourth", &log));
+    drop(ManuallyDrop::into_inner(x));
+    assert_eq!(*log.borrow(), ["first", "second", "fourth"]);
+
+    // Unlike `MaybeUninit`, the contents are still known to be initialized.
+    assert_eq!(size_of::<Option<ManuallyDrop<&u8>>>(), size_of::<&u8>());
+}
+
 // FIXME #13642 (these benchmarks should be in another place)
 /// Completely miscellaneous language-construct benchmarks.
 // Static/dynamic method dispatch
@@ -98,3 +98,40 @@ fn test_match_option_string() {
         None => panic!("unexpected None while matching on Some(String { ... })")
     }
 }
+
+#[test]
+fn test_nonzero_int_size_in_option() {
+    use core::num::{NonZeroU8, NonZeroU32, NonZeroUsize, NonZeroI64};
+
+    assert_eq!(size_of::<Option<NonZeroU8>>(), size_of::<u8>());
+    assert_eq!(size_of::<Option<NonZeroU32>>(), size_of::<u32>());
+    assert_eq!(size_of::<Option<NonZeroUsize>>(), size_of::<usize>());
+    assert_eq!(size_of::<Option<NonZeroI64>>(), size_of::<i64>());
+    assert_eq!(size_of::<Option<(u8, NonZeroU32)>>(), size_of::<(u8, u32)>());
+}
+
+#[test]
+fn test_nonzero_int_new() {
+    use core::num::{NonZeroU32, NonZeroI8};
+
+    assert!(NonZeroU32::new(0).is_none());
+    assert_eq!(NonZeroU32::new(7).map(NonZeroU32::get), Some(7));
+    assert_eq!(NonZeroI8::new(-1).map(NonZeroI8::get), Some(-1));
+    assert_eq!(u32::from(unsafe { NonZeroU32::new_unchecked(42) }), 42);
+
+    match NonZeroU32::new(3) {
+        Some(n) => assert_eq!(n.get(), 3),
+        None => panic!("unexpected None from NonZeroU32::new(3)")
+    }
+}
+
+#[test]
+fn test_nonzero_int_fmt() {
+    use core::num::NonZeroU16;
+
+    let n = NonZeroU16::new(255).unwrap();
+    assert_eq!(format!("{}", n), "255");
+    assert_eq!(format!("{:?}", n), "255");
+    assert_eq!(format!("{:x}", n), "ff");
+    assert_eq!(format!("{:#b}", n), "0b11111111");
+}
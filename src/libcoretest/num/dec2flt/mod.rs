@@ -87,6 +87,26 @@ fn zero() {
     test_literal!(1e-500);
 }
 
+#[test]
+fn eisel_lemire_halfway() {
+    // 2^53 + 1 and 2^53 + 3 are exactly halfway between two adjacent doubles.
+    assert_eq!("9007199254740993".parse(), Ok(9007199254740992.0f64));
+    assert_eq!("9007199254740995".parse(), Ok(9007199254740996.0f64));
+    // Same for 2^24 + 1 and 2^24 + 3 with f32.
+    assert_eq!("16777217".parse(), Ok(16777216.0f32));
+    assert_eq!("16777219".parse(), Ok(16777220.0f32));
+    assert_eq!("1.6777217e7".parse(), Ok(16777216.0f32));
+}
+
+#[test]
+fn eisel_lemire_many_digits() {
+    // More than 19 significant digits, which are truncated but still decide the rounding.
+    assert_eq!("9007199254740993.0000000000000000001".parse(), Ok(9007199254740994.0f64));
+    assert_eq!("9007199254740992.9999999999999999999".parse(), Ok(9007199254740992.0f64));
+    test_literal!(1.0000000000000002220446049250313080847263336181640625);
+    test_literal!(0.333333333333333333333333333333333333333333333333333333);
+}
+
 #[test]
 fn fast_path_correct() {
     // This number triggers the fast path and is handled incorrectly when compiling on
@@ -140,8 +160,9 @@ fn borderline_overflow() {
     for _ in 0..375 {
         s.push('3');
     }
-    // At the time of this writing, this returns Err(..), but this is a bug that should be fixed.
-    // It makes no sense to enshrine that in a test, the important part is that it doesn't panic.
+    // This used to return Err(..), which is a bug. Inputs like this are now usually handled
+    // before reaching the bignum code, but it makes no sense to enshrine either result in a
+    // test, the important part is that it doesn't panic.
     let _ = s.parse::<f64>();
 }
 
@@ -0,0 +1,82 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::f64;
+use super::super::*;
+use core::num::flt2dec::*;
+use core::num::flt2dec::strategy::ryu::*;
+
+#[test]
+fn test_tables() {
+    // every entry is normalized to exactly 125 bits.
+    for entry in POW5_INV_SPLIT.iter().chain(POW5_SPLIT.iter()).skip(1) {
+        assert_eq!(entry[1] >> 60, 1);
+    }
+    // `5^0` is the only power of two, with one more bit in its inverse.
+    assert_eq!(POW5_SPLIT[0], [0, 1 << 60]);
+    assert_eq!(POW5_INV_SPLIT[0], [1, 1 << 61]);
+}
+
+#[test]
+fn shortest_sanity_test() {
+    f64_shortest_sanity_test(format_shortest);
+    f32_shortest_sanity_test(format_shortest);
+    more_shortest_sanity_test(format_shortest);
+}
+
+#[test]
+fn shortest_opt_covers_all_floats() {
+    // the extremes of both types never need the fallback.
+    for &v in &[f64::MIN_POSITIVE, f64::MAX, 5e-324, 1.0, 2.0, 1e23] {
+        let mut buf = [0; MAX_SIG_DIGITS];
+        assert!(format_shortest_opt(&decode_finite(v), &mut buf).is_some());
+    }
+    for &v in &[::std::f32::MIN_POSITIVE, ::std::f32::MAX, 1e-45, 1.0, 2.0] {
+        let mut buf = [0; MAX_SIG_DIGITS];
+        assert!(format_shortest_opt(&decode_finite(v), &mut buf).is_some());
+    }
+}
+
+#[test]
+fn shortest_random_equivalence_test() {
+    use core::num::flt2dec::strategy::dragon::format_shortest as fallback;
+    f64_random_equivalence_test(format_shortest_opt, fallback, MAX_SIG_DIGITS, 10_000);
+    f32_random_equivalence_test(format_shortest_opt, fallback, MAX_SIG_DIGITS, 10_000);
+}
+
+#[test] #[ignore] // it is too expensive
+fn shortest_f32_exhaustive_equivalence_test() {
+    // unlike with Grisu, this should not ignore anything:
+    // `done, ignored=0 passed=2139095039 failed=0`.
+
+    use core::num::flt2dec::strategy::dragon::format_shortest as fallback;
+    f32_exhaustive_equivalence_test(format_shortest_opt, fallback, MAX_SIG_DIGITS);
+}
+
+#[test] #[ignore] // it is too expensive
+fn shortest_f64_hard_random_equivalence_test() {
+    use core::num::flt2dec::strategy::dragon::format_shortest as fallback;
+    f64_random_equivalence_test(format_shortest_opt, fallback,
+                                         MAX_SIG_DIGITS, 100_000_000);
+}
+
+#[bench]
+fn bench_small_shortest(b: &mut Bencher) {
+    let decoded = decode_finite(3.141592f64);
+    let mut buf = [0; MAX_SIG_DIGITS];
+    b.iter(|| format_shortest(&decoded, &mut buf));
+}
+
+#[bench]
+fn bench_big_shortest(b: &mut Bencher) {
+    let decoded = decode_finite(f64::MAX);
+    let mut buf = [0; MAX_SIG_DIGITS];
+    b.iter(|| format_shortest(&decoded, &mut buf));
+}
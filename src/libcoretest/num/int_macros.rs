@@ -129,6 +129,32 @@ mod tests {
         assert_eq!(_1.swap_bytes(), _1);
     }
 
+    #[test]
+    fn test_reverse_bits() {
+        assert_eq!(A.reverse_bits().reverse_bits(), A);
+        assert_eq!(B.reverse_bits().reverse_bits(), B);
+        assert_eq!(C.reverse_bits().reverse_bits(), C);
+
+        // Reversing these should make no difference
+        assert_eq!(_0.reverse_bits(), _0);
+        assert_eq!(_1.reverse_bits(), _1);
+
+        assert_eq!((1 as $T).reverse_bits(), MIN);
+    }
+
+    #[test]
+    fn test_leading_trailing_ones() {
+        let bits = (mem::size_of::<$T>() * 8) as u32;
+
+        assert_eq!(_0.leading_ones(), 0);
+        assert_eq!(_1.leading_ones(), bits);
+        assert_eq!(_1.trailing_ones(), bits);
+        assert_eq!(MAX.leading_ones(), 0);
+        assert_eq!(MAX.trailing_ones(), bits - 1);
+        assert_eq!(MIN.leading_ones(), 1);
+        assert_eq!((-4 as $T).leading_ones(), bits - 2);
+    }
+
     #[test]
     fn test_le() {
         assert_eq!($T::from_le(A.to_le()), A);
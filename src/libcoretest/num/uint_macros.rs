@@ -97,6 +97,54 @@ mod tests {
         assert_eq!(_1.swap_bytes(), _1);
     }
 
+    #[test]
+    fn test_reverse_bits() {
+        assert_eq!(A.reverse_bits().reverse_bits(), A);
+        assert_eq!(B.reverse_bits().reverse_bits(), B);
+        assert_eq!(C.reverse_bits().reverse_bits(), C);
+
+        // Reversing these should make no difference
+        assert_eq!(_0.reverse_bits(), _0);
+        assert_eq!(_1.reverse_bits(), _1);
+
+        let bits = mem::size_of::<$T>() * 8;
+        assert_eq!((1 as $T).reverse_bits(), (1 as $T) << (bits - 1));
+        assert_eq!((0b0110 as $T).reverse_bits(), (0b0110 as $T) << (bits - 4));
+    }
+
+    #[test]
+    fn test_leading_trailing_ones() {
+        let bits = (mem::size_of::<$T>() * 8) as u32;
+
+        assert_eq!(_0.leading_ones(), 0);
+        assert_eq!(_0.trailing_ones(), 0);
+        assert_eq!(_1.leading_ones(), bits);
+        assert_eq!(_1.trailing_ones(), bits);
+
+        assert_eq!(A.trailing_ones(), 0);
+        assert_eq!(C.trailing_ones(), 1);
+        assert_eq!((_1 >> 3).leading_ones(), 0);
+        assert_eq!((!(_1 >> 3)).leading_ones(), 3);
+        assert_eq!((_1 >> 3).trailing_ones(), bits - 3);
+    }
+
+    #[test]
+    fn test_widening_mul() {
+        assert_eq!((3 as $T).widening_mul(5), (15, 0));
+        assert_eq!(_0.widening_mul(_1), (0, 0));
+        assert_eq!(_1.widening_mul(2), (_1 - 1, 1));
+        assert_eq!(_1.widening_mul(_1), (1, _1 - 1));
+        assert_eq!(C.widening_mul(B).0, C.wrapping_mul(B));
+    }
+
+    #[test]
+    fn test_carrying_mul() {
+        assert_eq!((3 as $T).carrying_mul(5, 2), (17, 0));
+        assert_eq!(_0.carrying_mul(_1, 7), (7, 0));
+        assert_eq!(_1.carrying_mul(2, 2), (0, 2));
+        assert_eq!(_1.carrying_mul(_1, _1), (0, _1));
+    }
+
     #[test]
     fn test_le() {
         assert_eq!($T::from_le(A.to_le()), A);
@@ -0,0 +1,154 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::simd::*;
+
+#[test]
+fn test_lanes() {
+    let v = i32x4::new(1, 2, 3, 4);
+    assert_eq!(i32x4::lanes(), 4);
+    assert_eq!(u8x32::lanes(), 32);
+    assert_eq!(i16x16::lanes(), 16);
+    assert_eq!(u16x16::splat(3).extract(15), 3);
+    assert_eq!(v.extract(0), 1);
+    assert_eq!(v.extract(3), 4);
+    assert_eq!(v.replace(1, 20), i32x4::new(1, 20, 3, 4));
+    assert_eq!(i32x4::splat(7), i32x4::new(7, 7, 7, 7));
+    assert_eq!(u8x16::default(), u8x16::splat(0));
+}
+
+#[test]
+#[should_panic]
+fn test_extract_out_of_bounds() {
+    f32x4::splat(1.0).extract(4);
+}
+
+#[test]
+fn test_slices() {
+    let data = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+    let v = u16x8::from_slice_unaligned(&data[1..]);
+    assert_eq!(v, u16x8::new(1, 2, 3, 4, 5, 6, 7, 8));
+    let mut out = [0; 9];
+    v.write_to_slice_unaligned(&mut out[1..]);
+    assert_eq!(out, [0, 1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn test_int_arithmetic() {
+    let a = u8x16::splat(200);
+    let b = u8x16::splat(100);
+    assert_eq!(a + b, u8x16::splat(44));
+    assert_eq!(b - a, u8x16::splat(156));
+    assert_eq!(i32x4::new(1, -2, 3, -4) * i32x4::splat(3), i32x4::new(3, -6, 9, -12));
+    assert_eq!(-i32x4::new(1, -2, 0, i32::min_value()),
+               i32x4::new(-1, 2, 0, i32::min_value()));
+
+    let mut c = i64x2::new(1, 2);
+    c += i64x2::splat(10);
+    c -= i64x2::splat(1);
+    assert_eq!(c, i64x2::new(10, 11));
+}
+
+#[test]
+fn test_int_bitops() {
+    let a = u32x4::new(0b1100, 0b1010, !0, 0);
+    let b = u32x4::splat(0b0110);
+    assert_eq!(a & b, u32x4::new(0b0100, 0b0010, 0b0110, 0));
+    assert_eq!(a | b, u32x4::new(0b1110, 0b1110, !0, 0b0110));
+    assert_eq!(a ^ b, u32x4::new(0b1010, 0b1100, !0b0110, 0b0110));
+    assert_eq!(!u32x4::splat(0), u32x4::splat(!0));
+    assert_eq!(u32x4::splat(1) << 4, u32x4::splat(16));
+    // The shift amount wraps around, like `wrapping_shl`.
+    assert_eq!(u32x4::splat(1) << 33, u32x4::splat(2));
+    assert_eq!(i32x4::splat(-16) >> 2, i32x4::splat(-4));
+    assert_eq!(u32x4::splat(!0) >> 28, u32x4::splat(0xf));
+}
+
+#[test]
+fn test_float_arithmetic() {
+    let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+    let b = f32x4::splat(2.0);
+    assert_eq!(a + b, f32x4::new(3.0, 4.0, 5.0, 6.0));
+    assert_eq!(a / b, f32x4::new(0.5, 1.0, 1.5, 2.0));
+    assert_eq!((-a).abs(), a);
+
+    // `abs` clears the sign bit, even of `-0.0` and of negative NaNs.
+    let neg_nan: f64 = unsafe { ::core::mem::transmute(0xfff8_0000_0000_0000u64) };
+    let abs = f64x2::new(-0.0, neg_nan).abs();
+    assert!(abs.extract(0) == 0.0 && abs.extract(0).is_sign_positive());
+    assert!(abs.extract(1).is_nan() && abs.extract(1).is_sign_positive());
+    assert!(f32x8::splat(-0.0).abs().extract(7).is_sign_positive());
+
+    let z = -f64x2::new(0.0, -0.0);
+    assert!(z.extract(0).is_sign_negative());
+    assert!(z.extract(1).is_sign_positive());
+}
+
+#[test]
+fn test_comparisons_and_masks() {
+    let a = i32x4::new(1, 5, 3, 7);
+    let b = i32x4::new(4, 4, 4, 4);
+    let lt = a.lt(b);
+    assert_eq!(lt, m32x4::new(true, false, true, false));
+    assert_eq!(a.ge(b), !lt);
+    assert_eq!(a.eq(b), m32x4::splat(false));
+    assert!(lt.any() && !lt.all() && !lt.none());
+    assert!(m32x4::splat(true).all());
+    assert!(m32x4::default().none());
+    assert!(lt.extract(0) && !lt.extract(1));
+    assert_eq!(lt.replace(1, true) & lt.replace(0, false), m32x4::new(false, false, true, false));
+
+    assert_eq!(i32x4::select(lt, a, b), i32x4::new(1, 4, 3, 4));
+    assert_eq!(a.min(b), i32x4::new(1, 4, 3, 4));
+    assert_eq!(a.max(b), i32x4::new(4, 5, 4, 7));
+
+    let x = f32x4::new(1.0, -1.0, 0.5, 8.0);
+    assert_eq!(f32x4::select(x.gt(f32x4::splat(0.0)), x, f32x4::splat(0.0)),
+               f32x4::new(1.0, 0.0, 0.5, 8.0));
+
+    assert!(i32x4::splat(1) != i32x4::new(1, 1, 1, 2));
+    assert!(f32x4::splat(::core::f32::NAN) != f32x4::splat(::core::f32::NAN));
+}
+
+#[test]
+fn test_swizzles() {
+    let a = u32x4::new(0, 1, 2, 3);
+    let b = u32x4::new(4, 5, 6, 7);
+    assert_eq!(a.reverse(), u32x4::new(3, 2, 1, 0));
+    assert_eq!(a.interleave_low(b), u32x4::new(0, 4, 1, 5));
+    assert_eq!(a.interleave_high(b), u32x4::new(2, 6, 3, 7));
+
+    let c = f64x4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(c.reverse(), f64x4::new(4.0, 3.0, 2.0, 1.0));
+
+    let d = i16x16::new(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    let e = -d;
+    assert_eq!(d.interleave_low(e),
+               i16x16::new(0, 0, 1, -1, 2, -2, 3, -3, 4, -4, 5, -5, 6, -6, 7, -7));
+    assert_eq!(d.reverse().extract(0), 15);
+}
+
+#[test]
+fn test_reductions() {
+    let a = i8x16::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+    // 1 + 2 + ... + 16 = 136, which wraps around to -120.
+    assert_eq!(a.wrapping_sum(), -120);
+    assert_eq!(a.max_element(), 16);
+    assert_eq!(a.min_element(), 1);
+    assert_eq!(u32x4::new(1, 2, 3, 4).wrapping_product(), 24);
+    assert_eq!(u32x4::new(0b011, 0b110, 0b111, 0b010).and(), 0b010);
+    assert_eq!(u32x4::new(0b001, 0b100, 0, 0).or(), 0b101);
+    assert_eq!(u32x4::new(0b011, 0b001, 0, 0).xor(), 0b010);
+
+    let f = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+    assert_eq!(f.sum(), 36.0);
+    assert_eq!(f32x4::new(1.0, 2.0, 3.0, 4.0).product(), 24.0);
+    assert_eq!(f.max_element(), 8.0);
+}
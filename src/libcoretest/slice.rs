@@ -139,6 +139,82 @@ fn test_chunks_mut_last() {
     assert_eq!(c2.last().unwrap()[0], 4);
 }
 
+#[test]
+fn test_chunks_exact() {
+    let v: &[i32] = &[0, 1, 2, 3, 4, 5, 6];
+    let mut c = v.chunks_exact(3);
+    assert_eq!(c.len(), 2);
+    assert_eq!(c.remainder(), &[6]);
+    assert_eq!(c.next().unwrap(), &[0, 1, 2]);
+    assert_eq!(c.next_back().unwrap(), &[3, 4, 5]);
+    assert_eq!(c.next(), None);
+    assert_eq!(c.next_back(), None);
+
+    let v2: &[i32] = &[0, 1, 2, 3];
+    assert_eq!(v2.chunks_exact(2).count(), 2);
+    assert!(v2.chunks_exact(2).remainder().is_empty());
+    assert_eq!(v2.chunks_exact(5).count(), 0);
+    assert_eq!(v2.chunks_exact(5).remainder(), v2);
+}
+
+#[test]
+fn test_chunks_exact_nth_last() {
+    let v: &[i32] = &[0, 1, 2, 3, 4, 5, 6];
+    let mut c = v.chunks_exact(2);
+    assert_eq!(c.nth(1).unwrap(), &[2, 3]);
+    assert_eq!(c.next().unwrap(), &[4, 5]);
+    assert_eq!(c.next(), None);
+
+    let mut c = v.chunks_exact(2);
+    assert_eq!(c.nth(3), None);
+    assert_eq!(v.chunks_exact(2).last().unwrap(), &[4, 5]);
+}
+
+#[test]
+fn test_chunks_exact_mut() {
+    let v: &mut [i32] = &mut [0, 1, 2, 3, 4, 5, 6];
+    {
+        let mut c = v.chunks_exact_mut(3);
+        assert_eq!(c.len(), 2);
+        for x in c.next().unwrap() {
+            *x += 10;
+        }
+        assert_eq!(c.nth(0).unwrap(), &[3, 4, 5]);
+        assert_eq!(c.next(), None);
+        c.into_remainder()[0] = 60;
+    }
+    assert_eq!(v, &[10, 11, 12, 3, 4, 5, 60]);
+
+    let v2: &mut [i32] = &mut [0, 1, 2, 3, 4];
+    assert_eq!(v2.chunks_exact_mut(2).next_back().unwrap(), &[2, 3]);
+    assert_eq!(v2.chunks_exact_mut(2).last().unwrap(), &[2, 3]);
+}
+
+#[test]
+fn test_array_chunks() {
+    let v: &[i32] = &[0, 1, 2, 3, 4, 5, 6];
+    let mut c = v.array_chunks::<[i32; 3]>();
+    assert_eq!(c.len(), 2);
+    assert_eq!(c.remainder(), &[6]);
+    assert_eq!(c.next(), Some(&[0, 1, 2]));
+    assert_eq!(c.next_back(), Some(&[3, 4, 5]));
+    assert_eq!(c.next(), None);
+
+    let sums: Vec<i32> = v.array_chunks::<[i32; 2]>().map(|&[a, b]| a + b).collect();
+    assert_eq!(sums, [1, 5, 9]);
+
+    let units = [(); 5];
+    assert_eq!(units.array_chunks::<[(); 2]>().count(), 2);
+    assert_eq!(units.array_chunks::<[(); 2]>().remainder().len(), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_array_chunks_zero() {
+    let v: &[i32] = &[0, 1];
+    v.array_chunks::<[i32; 0]>();
+}
+
 
 
 
@@ -180,3 +256,66 @@ fn test_windows_last() {
     let c2 = v2.windows(2);
     assert_eq!(c2.last().unwrap()[0], 3);
 }
+
+#[test]
+fn test_align_to_simple() {
+    let bytes = [1u8, 2, 3, 4, 5, 6, 7];
+    let (prefix, aligned, suffix) = unsafe { bytes.align_to::<u16>() };
+    assert_eq!(aligned.len(), 3);
+    assert!(prefix == [1] || suffix == [7]);
+    let expect1 = [1 << 8 | 2, 3 << 8 | 4, 5 << 8 | 6];
+    let expect2 = [1 | 2 << 8, 3 | 4 << 8, 5 | 6 << 8];
+    let expect3 = [2 << 8 | 3, 4 << 8 | 5, 6 << 8 | 7];
+    let expect4 = [2 | 3 << 8, 4 | 5 << 8, 6 | 7 << 8];
+    assert!(aligned == expect1 || aligned == expect2 || aligned == expect3 || aligned == expect4,
+            "aligned={:?} expected={:?} || {:?} || {:?} || {:?}",
+            aligned, expect1, expect2, expect3, expect4);
+}
+
+#[test]
+fn test_align_to_zst() {
+    let bytes = [1, 2, 3, 4, 5, 6, 7];
+    let (prefix, aligned, suffix) = unsafe { bytes.align_to::<()>() };
+    assert_eq!(aligned.len(), 0);
+    assert_eq!(prefix, [1, 2, 3, 4, 5, 6, 7]);
+    assert!(suffix.is_empty());
+}
+
+#[test]
+fn test_align_to_odd_sizes() {
+    // Three `[u8; 3]`s make up exactly one `[u8; 9]`, so the middle has to
+    // be a multiple of nine bytes even though `[u8; 9]` has no alignment.
+    let data = [[0u8; 3]; 10];
+    let (prefix, aligned, suffix) = unsafe { data.align_to::<[u8; 9]>() };
+    assert_eq!(prefix.len(), 0);
+    assert_eq!(aligned.len(), 3);
+    assert_eq!(suffix.len(), 1);
+}
+
+#[test]
+fn test_align_to_empty_mid() {
+    use core::mem;
+
+    // A single byte never holds a `u32`, but even then the empty middle
+    // slice must be aligned.
+    let bytes = [1u8, 2, 3, 4, 5, 6, 7];
+    for offset in 0..4 {
+        let (prefix, mid, suffix) = unsafe { bytes[offset..offset + 1].align_to::<u32>() };
+        assert_eq!(prefix, [offset as u8 + 1]);
+        assert!(mid.is_empty());
+        assert!(suffix.is_empty());
+        assert_eq!(mid.as_ptr() as usize % mem::align_of::<u32>(), 0);
+    }
+}
+
+#[test]
+fn test_align_to_mut() {
+    let mut val = [1u8, 2, 3, 4, 5];
+    {
+        let (begin, mid, end) = unsafe { val.align_to_mut::<[u8; 2]>() };
+        assert_eq!(begin.len(), 0);
+        assert_eq!(end.len(), 1);
+        mid[0] = mid[1];
+    }
+    assert_eq!(val, [3, 4, 3, 4, 5]);
+}
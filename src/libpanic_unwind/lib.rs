@@ -72,7 +72,14 @@ mod imp;
 #[path = "gcc.rs"]
 mod imp;
 
+// wasm32-unknown-unknown
+#[cfg(target_arch = "wasm32")]
+#[path = "wasm32.rs"]
+mod imp;
+
+#[cfg(not(target_arch = "wasm32"))]
 mod dwarf;
+#[cfg(not(target_arch = "wasm32"))]
 mod windows;
 
 // Entry point for catching an exception, implemented using the `try` intrinsic
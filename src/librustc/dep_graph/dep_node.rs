@@ -57,6 +57,7 @@ pub enum DepNode<D: Clone + Debug> {
     ResolveLifetimes,
     RegionResolveCrate,
     CheckLoops,
+    CheckNakedFunctions,
     PluginRegistrar,
     StabilityIndex,
     CollectItem(D),
@@ -178,6 +179,7 @@ impl<D: Clone + Debug> DepNode<D> {
             ResolveLifetimes => Some(ResolveLifetimes),
             RegionResolveCrate => Some(RegionResolveCrate),
             CheckLoops => Some(CheckLoops),
+            CheckNakedFunctions => Some(CheckNakedFunctions),
             PluginRegistrar => Some(PluginRegistrar),
             StabilityIndex => Some(StabilityIndex),
             Coherence => Some(Coherence),
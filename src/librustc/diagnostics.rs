@@ -365,40 +365,6 @@ type X = u32; // ok!
 ```
 "##,
 
-E0133: r##"
-Unsafe code was used outside of an unsafe function or block.
-
-Erroneous code example:
-
-```compile_fail,E0133
-unsafe fn f() { return; } // This is the unsafe code
-
-fn main() {
-    f(); // error: call to unsafe function requires unsafe function or block
-}
-```
-
-Using unsafe functionality is potentially dangerous and disallowed by safety
-checks. Examples:
-
-* Dereferencing raw pointers
-* Calling functions via FFI
-* Calling functions marked unsafe
-
-These safety checks can be relaxed for a section of the code by wrapping the
-unsafe instructions with an `unsafe` block. For instance:
-
-```
-unsafe fn f() { return; }
-
-fn main() {
-    unsafe { f(); } // ok!
-}
-```
-
-See also https://doc.rust-lang.org/book/unsafe.html
-"##,
-
 // This shouldn't really ever trigger since the repeated value error comes first
 E0136: r##"
 A binary can only have one entry point, and by default that entry point is the
@@ -1690,6 +1656,33 @@ fn cookie() -> ! { // error: definition of an unknown language item: `cookie`
 ```
 "##,
 
+E0565: r##"
+The `#[target_feature]` attribute was applied to something other than an
+`unsafe` function.
+
+Erroneous code example:
+
+```compile_fail,E0565
+#![feature(target_feature)]
+
+#[target_feature(enable = "avx2")]
+fn foo() {} // error: `#[target_feature]` can only be applied to `unsafe`
+            //        functions
+```
+
+A function compiled with extra target features may only be called on CPUs
+which support them, which the compiler can't verify. Calling it is therefore
+`unsafe`, and the caller is responsible for checking that the CPU supports
+the features, for example with `cfg!(target_feature = "...")`:
+
+```
+#![feature(target_feature)]
+
+#[target_feature(enable = "avx2")]
+unsafe fn foo() {}
+```
+"##,
+
 }
 
 
@@ -20,6 +20,7 @@ enum Target {
     Fn,
     Struct,
     Enum,
+    Static,
     Other,
 }
 
@@ -29,6 +30,7 @@ impl Target {
             ast::ItemKind::Fn(..) => Target::Fn,
             ast::ItemKind::Struct(..) => Target::Struct,
             ast::ItemKind::Enum(..) => Target::Enum,
+            ast::ItemKind::Static(..) => Target::Static,
             _ => Target::Other,
         }
     }
@@ -85,11 +87,36 @@ impl<'a> CheckAttrVisitor<'a> {
         }
     }
 
+    fn check_used(&self, attr: &ast::Attribute, target: Target) {
+        if target != Target::Static {
+            self.sess.span_err(attr.span, "attribute must be applied to a `static` variable");
+        }
+    }
+
+    fn check_target_feature(&self, attr: &ast::Attribute, unsafety: Option<ast::Unsafety>) {
+        if unsafety != Some(ast::Unsafety::Unsafe) {
+            span_err!(self.sess, attr.span, E0565,
+                      "`#[target_feature]` can only be applied to `unsafe` functions");
+        }
+        let well_formed = match attr.meta_item_list() {
+            Some(items) => items.iter().all(|item| {
+                item.check_name("enable") && item.value_str().is_some()
+            }),
+            None => false,
+        };
+        if !well_formed {
+            self.sess.span_err(attr.span,
+                               "malformed `target_feature` attribute, expected \
+                                `#[target_feature(enable = \"...\")]`");
+        }
+    }
+
     fn check_attribute(&self, attr: &ast::Attribute, target: Target) {
         let name: &str = &attr.name();
         match name {
             "inline" => self.check_inline(attr, target),
             "repr" => self.check_repr(attr, target),
+            "used" => self.check_used(attr, target),
             _ => (),
         }
     }
@@ -98,11 +125,44 @@ impl<'a> CheckAttrVisitor<'a> {
 impl<'a> Visitor for CheckAttrVisitor<'a> {
     fn visit_item(&mut self, item: &ast::Item) {
         let target = Target::from_item(item);
+        let unsafety = match item.node {
+            ast::ItemKind::Fn(_, unsafety, ..) => Some(unsafety),
+            _ => None,
+        };
         for attr in &item.attrs {
+            if attr.check_name("target_feature") {
+                self.check_target_feature(attr, unsafety);
+            }
             self.check_attribute(attr, target);
         }
         visit::walk_item(self, item);
     }
+
+    fn visit_impl_item(&mut self, impl_item: &ast::ImplItem) {
+        let unsafety = match impl_item.node {
+            ast::ImplItemKind::Method(ref sig, _) => Some(sig.unsafety),
+            _ => None,
+        };
+        for attr in &impl_item.attrs {
+            if attr.check_name("target_feature") {
+                self.check_target_feature(attr, unsafety);
+            }
+        }
+        visit::walk_impl_item(self, impl_item);
+    }
+
+    fn visit_trait_item(&mut self, trait_item: &ast::TraitItem) {
+        let unsafety = match trait_item.node {
+            ast::TraitItemKind::Method(ref sig, _) => Some(sig.unsafety),
+            _ => None,
+        };
+        for attr in &trait_item.attrs {
+            if attr.check_name("target_feature") {
+                self.check_target_feature(attr, unsafety);
+            }
+        }
+        visit::walk_trait_item(self, trait_item);
+    }
 }
 
 pub fn check_crate(sess: &Session, krate: &ast::Crate) {
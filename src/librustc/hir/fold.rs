@@ -532,13 +532,14 @@ pub fn noop_fold_ty_param_bound<T>(tpb: TyParamBound, fld: &mut T) -> TyParamBou
 }
 
 pub fn noop_fold_ty_param<T: Folder>(tp: TyParam, fld: &mut T) -> TyParam {
-    let TyParam {id, name, bounds, default, span} = tp;
+    let TyParam {id, name, bounds, default, span, synthetic} = tp;
     TyParam {
         id: fld.new_id(id),
         name: name,
         bounds: fld.fold_bounds(bounds),
         default: default.map(|x| fld.fold_ty(x)),
         span: span,
+        synthetic: synthetic,
     }
 }
 
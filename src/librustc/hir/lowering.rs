@@ -49,11 +49,13 @@ use session::Session;
 
 use std::collections::BTreeMap;
 use std::iter;
+use std::mem;
 use syntax::ast::*;
 use syntax::errors;
 use syntax::ptr::P;
 use syntax::codemap::{respan, Spanned};
 use syntax::parse::token;
+use syntax::print::pprust;
 use syntax::std_inject;
 use syntax::visit::{self, Visitor};
 use syntax_pos::Span;
@@ -67,6 +69,9 @@ pub struct LoweringContext<'a> {
     // a definition, then we can properly create the def id.
     parent_def: Option<DefIndex>,
     resolver: &'a mut Resolver,
+    // The type parameters introduced by `impl Trait` in the arguments of the
+    // function being lowered, or `None` outside of argument position.
+    impl_trait_params: Option<Vec<hir::TyParam>>,
 }
 
 pub trait Resolver {
@@ -118,6 +123,7 @@ pub fn lower_crate(sess: &Session,
         sess: Some(sess),
         parent_def: None,
         resolver: resolver,
+        impl_trait_params: None,
     }.lower_crate(krate)
 }
 
@@ -128,6 +134,7 @@ impl<'a> LoweringContext<'a> {
             sess: None,
             parent_def: None,
             resolver: resolver,
+            impl_trait_params: None,
         }
     }
 
@@ -297,6 +304,9 @@ impl<'a> LoweringContext<'a> {
                     hir::TyPolyTraitRef(self.lower_bounds(bounds))
                 }
                 ImplTrait(ref bounds) => {
+                    if self.impl_trait_params.is_some() {
+                        return self.lower_universal_impl_trait(t, bounds);
+                    }
                     hir::TyImplTrait(self.lower_bounds(bounds))
                 }
                 Mac(_) => panic!("TyMac should have been expanded by now."),
@@ -305,6 +315,35 @@ impl<'a> LoweringContext<'a> {
         })
     }
 
+    // In argument position, `impl Bounds` is sugar for an anonymous type
+    // parameter `T: Bounds` of the function. The parameter takes over the
+    // node id, and thus the definition, of the `impl Trait` type, which is
+    // replaced by a path to the parameter.
+    fn lower_universal_impl_trait(&mut self, t: &Ty, bounds: &TyParamBounds) -> P<hir::Ty> {
+        let name = token::intern(&pprust::ty_to_string(t));
+        let param = hir::TyParam {
+            id: t.id,
+            name: name,
+            bounds: self.lower_bounds(bounds),
+            default: None,
+            span: t.span,
+            synthetic: true,
+        };
+        self.impl_trait_params.as_mut().unwrap().push(param);
+
+        let id = self.next_id();
+        let def = self.resolver.definitions().map(|defs| {
+            Def::TyParam(defs.local_def_id(t.id))
+        }).unwrap_or(Def::Err);
+        self.resolver.record_resolution(id, def);
+
+        P(hir::Ty {
+            id: id,
+            node: hir::TyPath(None, hir::Path::from_name(t.span, name)),
+            span: t.span,
+        })
+    }
+
     fn lower_foreign_mod(&mut self, fm: &ForeignMod) -> hir::ForeignMod {
         hir::ForeignMod {
             abi: fm.abi,
@@ -364,11 +403,15 @@ impl<'a> LoweringContext<'a> {
                                           data: &ParenthesizedParameterData)
                                           -> hir::ParenthesizedParameterData {
         let &ParenthesizedParameterData { ref inputs, ref output, span } = data;
-        hir::ParenthesizedParameterData {
+        // `Fn(impl Trait)` isn't an argument of the enclosing function.
+        let impl_trait_params = self.impl_trait_params.take();
+        let data = hir::ParenthesizedParameterData {
             inputs: inputs.iter().map(|ty| self.lower_ty(ty)).collect(),
             output: output.as_ref().map(|ty| self.lower_ty(ty)),
             span: span,
-        }
+        };
+        self.impl_trait_params = impl_trait_params;
+        data
     }
 
     fn lower_local(&mut self, l: &Local) -> P<hir::Local> {
@@ -398,14 +441,47 @@ impl<'a> LoweringContext<'a> {
     }
 
     fn lower_fn_decl(&mut self, decl: &FnDecl) -> P<hir::FnDecl> {
-        P(hir::FnDecl {
+        // Closures, foreign functions and function pointers can't have
+        // `impl Trait` arguments.
+        let impl_trait_params = self.impl_trait_params.take();
+        let decl = P(hir::FnDecl {
             inputs: decl.inputs.iter().map(|x| self.lower_arg(x)).collect(),
-            output: match decl.output {
-                FunctionRetTy::Ty(ref ty) => hir::Return(self.lower_ty(ty)),
-                FunctionRetTy::Default(span) => hir::DefaultReturn(span),
-            },
+            output: self.lower_fn_ret_ty(&decl.output),
             variadic: decl.variadic,
-        })
+        });
+        self.impl_trait_params = impl_trait_params;
+        decl
+    }
+
+    fn lower_fn_ret_ty(&mut self, output: &FunctionRetTy) -> hir::FunctionRetTy {
+        match *output {
+            FunctionRetTy::Ty(ref ty) => hir::Return(self.lower_ty(ty)),
+            FunctionRetTy::Default(span) => hir::DefaultReturn(span),
+        }
+    }
+
+    // Lowers the signature of a function item or method, whose arguments may
+    // use `impl Trait`. The type parameters that introduces are appended to
+    // the explicit ones.
+    fn lower_fn_decl_and_generics(&mut self, decl: &FnDecl, generics: &Generics)
+                                  -> (P<hir::FnDecl>, hir::Generics) {
+        let mut generics = self.lower_generics(generics);
+
+        let outer = mem::replace(&mut self.impl_trait_params, Some(Vec::new()));
+        let inputs = decl.inputs.iter().map(|x| self.lower_arg(x)).collect();
+        let impl_trait_params = mem::replace(&mut self.impl_trait_params, outer).unwrap();
+
+        let decl = P(hir::FnDecl {
+            inputs: inputs,
+            output: self.lower_fn_ret_ty(&decl.output),
+            variadic: decl.variadic,
+        });
+        if !impl_trait_params.is_empty() {
+            let mut ty_params = generics.ty_params.into_vec();
+            ty_params.extend(impl_trait_params);
+            generics.ty_params = ty_params.into();
+        }
+        (decl, generics)
     }
 
     fn lower_ty_param_bound(&mut self, tpb: &TyParamBound) -> hir::TyParamBound {
@@ -427,6 +503,7 @@ impl<'a> LoweringContext<'a> {
             bounds: self.lower_bounds(&tp.bounds),
             default: tp.default.as_ref().map(|x| self.lower_ty(x)),
             span: tp.span,
+            synthetic: false,
         }
     }
 
@@ -618,11 +695,12 @@ impl<'a> LoweringContext<'a> {
                 hir::ItemConst(self.lower_ty(t), self.lower_expr(e))
             }
             ItemKind::Fn(ref decl, unsafety, constness, abi, ref generics, ref body) => {
-                hir::ItemFn(self.lower_fn_decl(decl),
+                let (decl, generics) = self.lower_fn_decl_and_generics(decl, generics);
+                hir::ItemFn(decl,
                             self.lower_unsafety(unsafety),
                             self.lower_constness(constness),
                             abi,
-                            self.lower_generics(generics),
+                            generics,
                             self.lower_block(body))
             }
             ItemKind::Mod(ref m) => hir::ItemMod(self.lower_mod(m)),
@@ -667,7 +745,7 @@ impl<'a> LoweringContext<'a> {
                                bounds,
                                items)
             }
-            ItemKind::Mac(_) => panic!("Shouldn't still be around"),
+            ItemKind::TraitAlias(..) | ItemKind::Mac(_) => panic!("Shouldn't still be around"),
         }
     }
 
@@ -782,12 +860,13 @@ impl<'a> LoweringContext<'a> {
     }
 
     fn lower_method_sig(&mut self, sig: &MethodSig) -> hir::MethodSig {
+        let (decl, generics) = self.lower_fn_decl_and_generics(&sig.decl, &sig.generics);
         let hir_sig = hir::MethodSig {
-            generics: self.lower_generics(&sig.generics),
+            generics: generics,
             abi: sig.abi,
             unsafety: self.lower_unsafety(sig.unsafety),
             constness: self.lower_constness(sig.constness),
-            decl: self.lower_fn_decl(&sig.decl),
+            decl: decl,
         };
         // Check for `self: _` and `self: &_`
         if let Some(SelfKind::Explicit(..)) = sig.decl.get_self().map(|eself| eself.node) {
@@ -1242,6 +1321,7 @@ impl<'a> LoweringContext<'a> {
                         alignstack,
                         dialect,
                         expn_id,
+                        ref operand_classes,
                     }) => hir::ExprInlineAsm(hir::InlineAsm {
                     inputs: inputs.iter().map(|&(ref c, _)| c.clone()).collect(),
                     outputs: outputs.iter()
@@ -1260,6 +1340,7 @@ impl<'a> LoweringContext<'a> {
                     alignstack: alignstack,
                     dialect: dialect,
                     expn_id: expn_id,
+                    operand_classes: operand_classes.clone().into(),
                 }, outputs.iter().map(|out| self.lower_expr(&out.expr)).collect(),
                    inputs.iter().map(|&(_, ref input)| self.lower_expr(input)).collect()),
                 ExprKind::Struct(ref path, ref fields, ref maybe_expr) => {
@@ -292,6 +292,9 @@ pub struct TyParam {
     pub bounds: TyParamBounds,
     pub default: Option<P<Ty>>,
     pub span: Span,
+    /// Whether the parameter was introduced by `impl Trait` in argument
+    /// position, rather than written by the user.
+    pub synthetic: bool,
 }
 
 /// Represents lifetimes and type parameters attached to a declaration
@@ -1162,6 +1165,7 @@ pub struct InlineAsm {
     pub alignstack: bool,
     pub dialect: AsmDialect,
     pub expn_id: ExpnId,
+    pub operand_classes: HirVec<InternedString>,
 }
 
 /// represents an argument in a function header
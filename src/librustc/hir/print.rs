@@ -1490,7 +1490,7 @@ impl<'a> State<'a> {
                 }
             }
             hir::ExprInlineAsm(ref a, ref outputs, ref inputs) => {
-                word(&mut self.s, "asm!")?;
+                word(&mut self.s, "llvm_asm!")?;
                 self.popen()?;
                 self.print_string(&a.asm, a.asm_str_style)?;
                 self.word_space(":")?;
@@ -2019,7 +2019,10 @@ impl<'a> State<'a> {
     }
 
     pub fn print_generics(&mut self, generics: &hir::Generics) -> io::Result<()> {
-        let total = generics.lifetimes.len() + generics.ty_params.len();
+        // Parameters introduced by `impl Trait` are printed in argument
+        // position, as they were written. They always come last.
+        let ty_params = generics.ty_params.iter().take_while(|p| !p.synthetic).count();
+        let total = generics.lifetimes.len() + ty_params;
         if total == 0 {
             return Ok(());
         }
@@ -1222,6 +1222,7 @@ impl<'a, 'gcx, 'tcx> Rebuilder<'a, 'gcx, 'tcx> {
                 bounds: bounds,
                 default: ty_param.default.clone(),
                 span: ty_param.span,
+                synthetic: ty_param.synthetic,
             }
         }).collect()
     }
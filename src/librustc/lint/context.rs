@@ -262,15 +262,13 @@ impl LintStore {
                 Ok(lint_id) => self.set_level(lint_id, (level, CommandLine)),
                 Err(FindLintError::Removed) => { }
                 Err(_) => {
-                    match self.lint_groups.iter().map(|(&x, pair)| (x, pair.0.clone()))
-                                                 .collect::<FnvHashMap<&'static str,
-                                                                       Vec<LintId>>>()
-                                                 .get(&lint_name[..]) {
-                        Some(v) => {
-                            v.iter()
-                             .map(|lint_id: &LintId|
-                                     self.set_level(*lint_id, (level, CommandLine)))
-                             .collect::<Vec<()>>();
+                    let group = self.lint_groups.get(&lint_name[..])
+                                                .map(|&(ref ids, _)| ids.clone());
+                    match group {
+                        Some(ids) => {
+                            for lint_id in ids {
+                                self.set_level(lint_id, (level, CommandLine));
+                            }
                         }
                         None => {
                             // The lint or lint group doesn't exist.
@@ -1114,6 +1112,10 @@ fn check_lint_name(lint_cx: &LintStore,
         },
         None => {
             match lint_cx.lint_groups.get(lint_name) {
+                None if is_known_tool_lint(lint_name) => {
+                    // The tool isn't running, so it didn't register its lints.
+                    CheckLintNameResult::Ok
+                }
                 None => {
                     CheckLintNameResult::NoLint
                 }
@@ -1130,6 +1132,17 @@ fn check_lint_name(lint_cx: &LintStore,
     }
 }
 
+/// Tools whose lints, written as `tool::lint_name`, are accepted in lint
+/// attributes and on the command line even when the tool isn't loaded.
+const KNOWN_TOOLS: &'static [&'static str] = &["clippy"];
+
+fn is_known_tool_lint(lint_name: &str) -> bool {
+    match lint_name.find("::") {
+        Some(i) => KNOWN_TOOLS.contains(&&lint_name[..i]),
+        None => false
+    }
+}
+
 // Checks the validity of lint names derived from attributes
 fn check_lint_name_attribute(cx: &LateContext, attr: &ast::Attribute) {
     for result in gather_attr(attr) {
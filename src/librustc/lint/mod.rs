@@ -55,7 +55,8 @@ pub struct Lint {
     /// statics without repeating the name.
     ///
     /// The name is written with underscores, e.g. "unused_imports".
-    /// On the command line, underscores become dashes.
+    /// On the command line, underscores become dashes. Lints of external
+    /// tools are prefixed with the tool's name, e.g. "clippy::needless_return".
     pub name: &'static str,
 
     /// Default level for the lint.
@@ -99,6 +100,27 @@ macro_rules! declare_lint {
     );
 }
 
+/// Declare a static item of type `&'static Lint` for a lint that belongs
+/// to an external tool. The lint is named `tool::name`, which keeps it out
+/// of rustc's own lint namespace.
+#[macro_export]
+macro_rules! declare_tool_lint {
+    (pub $tool:ident::$name:ident, $level:ident, $desc:expr) => (
+        pub static $name: &'static ::rustc::lint::Lint = &::rustc::lint::Lint {
+            name: concat!(stringify!($tool), "::", stringify!($name)),
+            default_level: ::rustc::lint::$level,
+            desc: $desc,
+        };
+    );
+    ($tool:ident::$name:ident, $level:ident, $desc:expr) => (
+        static $name: &'static ::rustc::lint::Lint = &::rustc::lint::Lint {
+            name: concat!(stringify!($tool), "::", stringify!($name)),
+            default_level: ::rustc::lint::$level,
+            desc: $desc,
+        };
+    );
+}
+
 /// Declare a static `LintArray` and return it as an expression.
 #[macro_export]
 macro_rules! lint_array { ($( $lint:expr ),*) => (
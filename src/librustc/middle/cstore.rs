@@ -32,7 +32,7 @@ use ty::{self, Ty, TyCtxt, VariantKind};
 use mir::repr::Mir;
 use mir::mir_map::MirMap;
 use session::Session;
-use session::config::PanicStrategy;
+use session::config::{PanicStrategy, Sanitizer};
 use session::search_paths::PathKind;
 use util::nodemap::{FnvHashMap, NodeSet, DefIdMap};
 use std::rc::Rc;
@@ -203,6 +203,7 @@ pub trait CrateStore<'tcx> {
     fn is_static_method(&self, did: DefId) -> bool;
     fn is_statically_included_foreign_item(&self, id: ast::NodeId) -> bool;
     fn is_typedef(&self, did: DefId) -> bool;
+    fn is_static_mut(&self, did: DefId) -> bool;
 
     // crate metadata
     fn dylib_dependency_formats(&self, cnum: ast::CrateNum)
@@ -214,6 +215,7 @@ pub trait CrateStore<'tcx> {
     fn is_allocator(&self, cnum: ast::CrateNum) -> bool;
     fn is_panic_runtime(&self, cnum: ast::CrateNum) -> bool;
     fn panic_strategy(&self, cnum: ast::CrateNum) -> PanicStrategy;
+    fn sanitizer(&self, cnum: ast::CrateNum) -> Option<Sanitizer>;
     fn extern_crate(&self, cnum: ast::CrateNum) -> Option<ExternCrate>;
     fn crate_attrs(&self, cnum: ast::CrateNum) -> Vec<ast::Attribute>;
     /// The name of the crate as it is referred to in source code of the current
@@ -397,6 +399,7 @@ impl<'tcx> CrateStore<'tcx> for DummyCrateStore {
     fn is_static_method(&self, did: DefId) -> bool { bug!("is_static_method") }
     fn is_statically_included_foreign_item(&self, id: ast::NodeId) -> bool { false }
     fn is_typedef(&self, did: DefId) -> bool { bug!("is_typedef") }
+    fn is_static_mut(&self, did: DefId) -> bool { bug!("is_static_mut") }
 
     // crate metadata
     fn dylib_dependency_formats(&self, cnum: ast::CrateNum)
@@ -413,6 +416,7 @@ impl<'tcx> CrateStore<'tcx> for DummyCrateStore {
     fn panic_strategy(&self, cnum: ast::CrateNum) -> PanicStrategy {
         bug!("panic_strategy")
     }
+    fn sanitizer(&self, cnum: ast::CrateNum) -> Option<Sanitizer> { bug!("sanitizer") }
     fn extern_crate(&self, cnum: ast::CrateNum) -> Option<ExternCrate> { bug!("extern_crate") }
     fn crate_attrs(&self, cnum: ast::CrateNum) -> Vec<ast::Attribute>
         { bug!("crate_attrs") }
@@ -65,6 +65,7 @@ use syntax::ast;
 
 use session;
 use session::config::{self, PanicStrategy};
+use middle::cstore::LOCAL_CRATE;
 use middle::cstore::LinkagePreference::{self, RequireStatic, RequireDynamic};
 use util::nodemap::FnvHashMap;
 
@@ -313,7 +314,8 @@ fn activate_injected_dep(injected: Option<ast::CrateNum>,
 }
 
 // After the linkage for a crate has been determined we need to verify that
-// there's only going to be one allocator in the output.
+// there's only going to be one allocator, panic runtime and sanitizer runtime
+// in the output.
 fn verify_ok(sess: &session::Session, list: &[Linkage]) {
     if list.len() == 0 {
         return
@@ -352,7 +354,7 @@ fn verify_ok(sess: &session::Session, list: &[Linkage]) {
     // only one, but we perform validation here that all the panic strategy
     // compilation modes for the whole DAG are valid.
     if let Some((cnum, found_strategy)) = panic_runtime {
-        let desired_strategy = sess.opts.cg.panic.clone();
+        let desired_strategy = sess.panic_strategy();
 
         // First up, validate that our selected panic runtime is indeed exactly
         // our same strategy.
@@ -390,4 +392,37 @@ fn verify_ok(sess: &session::Session, list: &[Linkage]) {
                               desired_strategy.desc()));
         }
     }
+
+    // Finally, the output can only contain one sanitizer runtime, so all
+    // instrumented crates must agree on the sanitizer. Uninstrumented
+    // crates can always be linked in.
+    let mut sanitizer = sess.opts.debugging_opts.sanitizer.map(|s| (LOCAL_CRATE, s));
+    for (i, linkage) in list.iter().enumerate() {
+        if let Linkage::NotLinked = *linkage {
+            continue
+        }
+        let cnum = (i + 1) as ast::CrateNum;
+        let found = match sess.cstore.sanitizer(cnum) {
+            Some(found) => found,
+            None => continue,
+        };
+        match sanitizer {
+            Some((_, desired)) if desired == found => {}
+            Some((prev, desired)) => {
+                let prev_name = if prev == LOCAL_CRATE {
+                    "this crate".to_string()
+                } else {
+                    format!("the crate `{}`", sess.cstore.crate_name(prev))
+                };
+                sess.err(&format!("the crate `{}` is compiled with the \
+                                   `{}` sanitizer which is incompatible \
+                                   with {}'s `{}` sanitizer",
+                                  sess.cstore.crate_name(cnum),
+                                  found.desc(),
+                                  prev_name,
+                                  desired.desc()));
+            }
+            None => sanitizer = Some((cnum, found)),
+        }
+    }
 }
@@ -14,7 +14,7 @@ use hir::def_id::DefId;
 use infer::InferCtxt;
 use traits::Reveal;
 use ty::{self, Ty, TyCtxt};
-use ty::layout::{LayoutError, Pointer, SizeSkeleton};
+use ty::layout::{self, Layout, LayoutError, Pointer, SizeSkeleton};
 
 use syntax::abi::Abi::RustIntrinsic;
 use syntax::ast;
@@ -45,6 +45,68 @@ impl<'a, 'tcx> ItemVisitor<'a, 'tcx> {
     }
 }
 
+/// The types a value in an inline asm register can have, which is all that
+/// matters about the Rust type of an `asm!` operand.
+#[derive(Copy, Clone, PartialEq)]
+enum AsmType {
+    I8,
+    I16,
+    I32,
+    I64,
+    F32,
+    F64,
+    /// A SIMD vector of the given size in bits.
+    Vector(u64),
+}
+
+impl AsmType {
+    fn to_string(self) -> String {
+        match self {
+            AsmType::I8 => "i8".to_string(),
+            AsmType::I16 => "i16".to_string(),
+            AsmType::I32 => "i32".to_string(),
+            AsmType::I64 => "i64".to_string(),
+            AsmType::F32 => "f32".to_string(),
+            AsmType::F64 => "f64".to_string(),
+            AsmType::Vector(bits) => format!("{}-bit SIMD vector", bits),
+        }
+    }
+}
+
+/// The types an operand in the register class `class` can have on the
+/// architecture `arch`.
+fn supported_asm_types(arch: &str, class: &str) -> Vec<AsmType> {
+    use self::AsmType::*;
+    match (arch, class) {
+        ("x86", "reg") | ("x86", "reg_abcd") => vec![I16, I32, F32],
+        ("x86_64", "reg") | ("x86_64", "reg_abcd") => vec![I16, I32, I64, F32, F64],
+        ("x86", "reg_byte") | ("x86_64", "reg_byte") => vec![I8],
+        ("x86", "xmm_reg") | ("x86_64", "xmm_reg") => vec![I32, I64, F32, F64, Vector(128)],
+        ("x86", "ymm_reg") | ("x86_64", "ymm_reg") => {
+            vec![I32, I64, F32, F64, Vector(128), Vector(256)]
+        }
+        ("x86", "zmm_reg") | ("x86_64", "zmm_reg") => {
+            vec![I32, I64, F32, F64, Vector(128), Vector(256), Vector(512)]
+        }
+        ("x86", "kreg") | ("x86_64", "kreg") => vec![I8, I16, I32, I64],
+        ("arm", "reg") => vec![I8, I16, I32, F32],
+        ("arm", "sreg") => vec![I32, F32],
+        ("arm", "dreg") => vec![I64, F64, Vector(64)],
+        ("arm", "qreg") => vec![Vector(128)],
+        ("aarch64", "reg") => vec![I8, I16, I32, I64, F32, F64],
+        ("aarch64", "vreg") | ("aarch64", "vreg_low16") => {
+            vec![I8, I16, I32, I64, F32, F64, Vector(64), Vector(128)]
+        }
+        ("mips", "reg") => vec![I8, I16, I32, F32],
+        ("mips64", "reg") => vec![I8, I16, I32, I64, F32, F64],
+        ("mips", "freg") | ("mips64", "freg") => vec![F32, F64],
+        ("powerpc", "reg") | ("powerpc", "reg_nonzero") => vec![I8, I16, I32],
+        ("powerpc64", "reg") | ("powerpc64", "reg_nonzero") => vec![I8, I16, I32, I64],
+        ("powerpc", "freg") | ("powerpc64", "freg") => vec![F32, F64],
+        _ => vec![],
+    }
+}
+
 struct ExprVisitor<'a, 'gcx: 'a+'tcx, 'tcx: 'a> {
     infcx: &'a InferCtxt<'a, 'gcx, 'tcx>
 }
@@ -109,6 +171,60 @@ impl<'a, 'gcx, 'tcx> ExprVisitor<'a, 'gcx, 'tcx> {
                   from, skeleton_string(from, sk_from),
                   to, skeleton_string(to, sk_to));
     }
+
+    /// Returns how a value of type `ty` is passed to inline asm, if it can
+    /// be at all: integers, floats, thin pointers and SIMD vectors.
+    fn asm_type(&self, ty: Ty<'gcx>) -> Option<AsmType> {
+        match ty.sty {
+            ty::TyInt(_) | ty::TyUint(_) | ty::TyFloat(_) |
+            ty::TyRawPtr(_) | ty::TyRef(..) | ty::TyFnPtr(_) => {}
+            ty::TyStruct(def, _) if def.is_simd() => {}
+            _ => return None,
+        }
+        let dl = &self.infcx.tcx.data_layout;
+        let primitive = |value| match value {
+            layout::Int(layout::I8) => Some(AsmType::I8),
+            layout::Int(layout::I16) => Some(AsmType::I16),
+            layout::Int(layout::I32) => Some(AsmType::I32),
+            layout::Int(layout::I64) => Some(AsmType::I64),
+            layout::F32 => Some(AsmType::F32),
+            layout::F64 => Some(AsmType::F64),
+            layout::Pointer => match Pointer.size(dl).bits() {
+                32 => Some(AsmType::I32),
+                64 => Some(AsmType::I64),
+                _ => None,
+            },
+            layout::Int(layout::I1) => None,
+        };
+        match ty.layout(self.infcx) {
+            Ok(&Layout::Scalar { value, .. }) => primitive(value),
+            Ok(&Layout::Vector { element, count }) => {
+                Some(AsmType::Vector(element.size(dl).bits() * count))
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks that `expr`, an operand of an `asm!` in the register class
+    /// `class`, has a type which fits in such a register.
+    fn check_asm_operand(&self, expr: &hir::Expr, class: &str) {
+        let tcx = self.infcx.tcx;
+        let arch = &tcx.sess.target.target.arch;
+        let ty = tcx.node_id_to_type(expr.id);
+        let supported = supported_asm_types(arch, class);
+        match self.asm_type(ty) {
+            Some(asm_ty) if supported.contains(&asm_ty) => {}
+            _ => {
+                let types = supported.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+                tcx.sess.struct_span_err(expr.span,
+                                         &format!("type `{}` cannot be used with register \
+                                                   class `{}`", ty, class))
+                    .note(&format!("register class `{}` supports these types: {}",
+                                   class, types.join(", ")))
+                    .emit();
+            }
+        }
+    }
 }
 
 impl<'a, 'tcx, 'v> Visitor<'v> for ItemVisitor<'a, 'tcx> {
@@ -174,6 +290,13 @@ impl<'a, 'gcx, 'tcx, 'v> Visitor<'v> for ExprVisitor<'a, 'gcx, 'tcx> {
             }
         }
 
+        if let hir::ExprInlineAsm(ref ia, ref outputs, ref inputs) = expr.node {
+            // `llvm_asm!` has no register classes and is left to LLVM.
+            for (operand, class) in outputs.iter().chain(inputs).zip(&ia.operand_classes) {
+                self.check_asm_operand(operand, class);
+            }
+        }
+
         intravisit::walk_expr(self, expr);
     }
 }
@@ -325,6 +325,8 @@ language_item_table! {
     IndexMutTraitLangItem,           "index_mut",               index_mut_trait;
 
     UnsafeCellTypeLangItem,          "unsafe_cell",             unsafe_cell_type;
+    MaybeUninitTypeLangItem,         "maybe_uninit",            maybe_uninit_type;
+    ManuallyDropTypeLangItem,        "manually_drop",           manually_drop_type;
 
     DerefTraitLangItem,              "deref",                   deref_trait;
     DerefMutTraitLangItem,           "deref_mut",               deref_mut_trait;
@@ -875,6 +875,20 @@ pub const ARGUMENT_VISIBILITY_SCOPE : VisibilityScope = VisibilityScope(0);
 pub struct VisibilityScopeData {
     pub span: Span,
     pub parent_scope: Option<VisibilityScope>,
+    pub safety: Safety,
+}
+
+/// Whether the code in a visibility scope may perform unsafe operations.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, RustcEncodable, RustcDecodable)]
+pub enum Safety {
+    Safe,
+    /// Unsafe because of a compiler-generated unsafe block, or a
+    /// `push_unsafe!` block around desugared code.
+    BuiltinUnsafe,
+    /// Unsafe because the whole body is that of an `unsafe fn`.
+    FnUnsafe,
+    /// Unsafe because of the user's `unsafe` block with the given id.
+    ExplicitUnsafe(ast::NodeId),
 }
 
 ///////////////////////////////////////////////////////////////////////////
@@ -294,6 +294,7 @@ macro_rules! make_mir_visitor {
                 let VisibilityScopeData {
                     ref $($mutability)* span,
                     ref $($mutability)* parent_scope,
+                    safety: _,
                 } = *scope_data;
 
                 self.visit_span(span);
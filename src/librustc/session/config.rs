@@ -15,6 +15,7 @@ pub use self::EntryFnType::*;
 pub use self::CrateType::*;
 pub use self::Passes::*;
 pub use self::DebugInfoLevel::*;
+pub use rustc_back::{PanicStrategy, SplitDebuginfo};
 
 use session::{early_error, early_warn, Session};
 use session::search_paths::SearchPaths;
@@ -42,7 +43,7 @@ use std::env;
 use std::fmt;
 use std::hash::{Hasher, SipHasher};
 use std::iter::FromIterator;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub struct Config {
     pub target: Target,
@@ -82,6 +83,7 @@ pub enum OutputType {
 pub enum ErrorOutputType {
     HumanReadable(ColorConfig),
     Json,
+    Short(ColorConfig),
 }
 
 impl Default for ErrorOutputType {
@@ -493,17 +495,34 @@ impl Passes {
     }
 }
 
-#[derive(Clone, PartialEq, Hash)]
-pub enum PanicStrategy {
-    Unwind,
-    Abort,
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum Lto {
+    /// Don't do any link-time optimization.
+    No,
+    /// Merge this crate and all of its upstream crates into a single module.
+    Fat,
+    /// Run ThinLTO over the codegen units of this crate and all of its
+    /// upstream crates.
+    Thin,
+    /// Run ThinLTO over the codegen units of this crate only.
+    ThinLocal,
 }
 
-impl PanicStrategy {
-    pub fn desc(&self) -> &str {
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum Sanitizer {
+    Address,
+    Leak,
+    Memory,
+    Thread,
+}
+
+impl Sanitizer {
+    pub fn desc(&self) -> &'static str {
         match *self {
-            PanicStrategy::Unwind => "unwind",
-            PanicStrategy::Abort => "abort",
+            Sanitizer::Address => "address",
+            Sanitizer::Leak => "leak",
+            Sanitizer::Memory => "memory",
+            Sanitizer::Thread => "thread",
         }
     }
 }
@@ -605,8 +624,6 @@ macro_rules! options {
         pub const parse_bool: Option<&'static str> = None;
         pub const parse_opt_bool: Option<&'static str> =
             Some("one of: `y`, `yes`, `on`, `n`, `no`, or `off`");
-        pub const parse_all_bool: Option<&'static str> =
-            Some("one of: `y`, `yes`, `on`, `n`, `no`, or `off`");
         pub const parse_string: Option<&'static str> = Some("a string");
         pub const parse_opt_string: Option<&'static str> = Some("a string");
         pub const parse_list: Option<&'static str> = Some("a space-separated list of strings");
@@ -618,11 +635,18 @@ macro_rules! options {
             Some("a number");
         pub const parse_panic_strategy: Option<&'static str> =
             Some("either `panic` or `abort`");
+        pub const parse_split_debuginfo: Option<&'static str> =
+            Some("one of `off`, `packed` or `unpacked`");
+        pub const parse_lto: Option<&'static str> =
+            Some("one of `thin`, `thin-local`, or no value for full LTO");
+        pub const parse_sanitizer: Option<&'static str> =
+            Some("one of: `address`, `leak`, `memory` or `thread`");
     }
 
     #[allow(dead_code)]
     mod $mod_set {
-        use super::{$struct_name, Passes, SomePasses, AllPasses, PanicStrategy};
+        use super::{$struct_name, Passes, SomePasses, AllPasses, PanicStrategy, Lto,
+                    Sanitizer, SplitDebuginfo};
 
         $(
             pub fn $opt(cg: &mut $struct_name, v: Option<&str>) -> bool {
@@ -656,25 +680,6 @@ macro_rules! options {
             }
         }
 
-        fn parse_all_bool(slot: &mut bool, v: Option<&str>) -> bool {
-            match v {
-                Some(s) => {
-                    match s {
-                        "n" | "no" | "off" => {
-                            *slot = false;
-                        }
-                        "y" | "yes" | "on" => {
-                            *slot = true;
-                        }
-                        _ => { return false; }
-                    }
-
-                    true
-                },
-                None => { *slot = true; true }
-            }
-        }
-
         fn parse_opt_string(slot: &mut Option<String>, v: Option<&str>) -> bool {
             match v {
                 Some(s) => { *slot = Some(s.to_string()); true },
@@ -746,10 +751,39 @@ macro_rules! options {
             }
         }
 
-        fn parse_panic_strategy(slot: &mut PanicStrategy, v: Option<&str>) -> bool {
+        fn parse_panic_strategy(slot: &mut Option<PanicStrategy>, v: Option<&str>) -> bool {
+            match v {
+                Some("unwind") => *slot = Some(PanicStrategy::Unwind),
+                Some("abort") => *slot = Some(PanicStrategy::Abort),
+                _ => return false
+            }
+            true
+        }
+
+        fn parse_split_debuginfo(slot: &mut Option<SplitDebuginfo>, v: Option<&str>) -> bool {
+            match v.and_then(SplitDebuginfo::from_str) {
+                Some(split) => *slot = Some(split),
+                None => return false,
+            }
+            true
+        }
+
+        fn parse_sanitizer(slot: &mut Option<Sanitizer>, v: Option<&str>) -> bool {
+            match v {
+                Some("address") => *slot = Some(Sanitizer::Address),
+                Some("leak") => *slot = Some(Sanitizer::Leak),
+                Some("memory") => *slot = Some(Sanitizer::Memory),
+                Some("thread") => *slot = Some(Sanitizer::Thread),
+                _ => return false
+            }
+            true
+        }
+
+        fn parse_lto(slot: &mut Lto, v: Option<&str>) -> bool {
             match v {
-                Some("unwind") => *slot = PanicStrategy::Unwind,
-                Some("abort") => *slot = PanicStrategy::Abort,
+                None => *slot = Lto::Fat,
+                Some("thin") => *slot = Lto::Thin,
+                Some("thin-local") => *slot = Lto::ThinLocal,
                 _ => return false
             }
             true
@@ -768,7 +802,7 @@ options! {CodegenOptions, CodegenSetter, basic_codegen_options,
         "extra arguments to pass to the linker (space separated)"),
     link_dead_code: bool = (false, parse_bool, [UNTRACKED],
         "don't let linker strip dead code (turning it on can be used for code coverage)"),
-    lto: bool = (false, parse_bool, [TRACKED],
+    lto: Lto = (Lto::No, parse_lto, [TRACKED],
         "perform LLVM link-time optimizations"),
     target_cpu: Option<String> = (None, parse_opt_string, [TRACKED],
         "select target processor (rustc --print target-cpus for details)"),
@@ -821,8 +855,15 @@ options! {CodegenOptions, CodegenSetter, basic_codegen_options,
         "explicitly enable the cfg(debug_assertions) directive"),
     inline_threshold: Option<usize> = (None, parse_opt_uint, [TRACKED],
         "set the inlining threshold for"),
-    panic: PanicStrategy = (PanicStrategy::Unwind, parse_panic_strategy,
+    panic: Option<PanicStrategy> = (None, parse_panic_strategy,
         [TRACKED], "panic strategy to compile crate with"),
+    profile_generate: Option<String> = (None, parse_opt_string, [TRACKED],
+        "instrument the generated code to write an execution profile into \
+         the given directory"),
+    profile_use: Option<String> = (None, parse_opt_string, [TRACKED],
+        "use the given `.profdata` file for profile-guided optimization"),
+    split_debuginfo: Option<SplitDebuginfo> = (None, parse_split_debuginfo, [TRACKED],
+        "how to split debuginfo off from the output (off, packed, or unpacked)"),
 }
 
 options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
@@ -910,12 +951,8 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
           "adds unstable command line options to rustc interface"),
     force_overflow_checks: Option<bool> = (None, parse_opt_bool, [TRACKED],
           "force overflow checks on or off"),
-    force_dropflag_checks: Option<bool> = (None, parse_opt_bool, [TRACKED],
-          "force drop flag checks on or off"),
     trace_macros: bool = (false, parse_bool, [UNTRACKED],
           "for every macro invocation, print its name and arguments"),
-    enable_nonzeroing_move_hints: bool = (false, parse_bool, [TRACKED],
-          "force nonzeroing move optimization on"),
     keep_hygiene_data: bool = (false, parse_bool, [UNTRACKED],
           "don't clear the hygiene data after analysis"),
     keep_ast: bool = (false, parse_bool, [UNTRACKED],
@@ -930,8 +967,18 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
           "dump MIR state at various points in translation"),
     dump_mir_dir: Option<String> = (None, parse_opt_string, [UNTRACKED],
           "the directory the MIR is dumped into"),
-    orbit: bool = (true, parse_all_bool, [UNTRACKED],
-          "get MIR where it belongs - everywhere; most importantly, in orbit"),
+    dump_mir_graphviz: bool = (false, parse_bool, [UNTRACKED],
+          "in addition to `.mir` files, create graphviz `.dot` files"),
+    dump_mir_html: bool = (false, parse_bool, [UNTRACKED],
+          "in addition to `.mir` files, create `.html` files comparing the CFG \
+           before and after each pass"),
+    validate_mir: bool = (false, parse_bool, [UNTRACKED],
+          "check the MIR for consistency after every MIR pass"),
+    variant_size_ratio: Option<usize> = (None, parse_opt_uint, [TRACKED],
+          "how many times larger than the next largest an enum variant must be \
+           for the `variant_size_differences` lint to fire (default: 3)"),
+    sanitizer: Option<Sanitizer> = (None, parse_sanitizer, [TRACKED],
+          "instrument the generated code with a sanitizer"),
 }
 
 pub fn default_lib_output() -> CrateType {
@@ -1212,7 +1259,7 @@ pub fn rustc_optgroups() -> Vec<RustcOptGroup> {
         opt::multi_ubnr("Z", "", "Set internal debugging options", "FLAG"),
         opt::opt_s("", "error-format",
                       "How errors and other messages are produced",
-                      "human|json"),
+                      "human|json|short"),
         opt::opt_s("", "color", "Configure coloring of output:
                                  auto   = colorize, if output goes to a tty (default);
                                  always = always colorize output;
@@ -1287,13 +1334,14 @@ pub fn build_session_options_and_crate_config(matches: &getopts::Matches)
         match matches.opt_str("error-format").as_ref().map(|s| &s[..]) {
             Some("human")   => ErrorOutputType::HumanReadable(color),
             Some("json") => ErrorOutputType::Json,
+            Some("short") => ErrorOutputType::Short(color),
 
             None => ErrorOutputType::HumanReadable(color),
 
             Some(arg) => {
                 early_error(ErrorOutputType::HumanReadable(color),
-                            &format!("argument for --error-format must be human or json (instead \
-                                      was `{}`)",
+                            &format!("argument for --error-format must be human, json or short \
+                                      (instead was `{}`)",
                                      arg))
             }
         }
@@ -1324,15 +1372,7 @@ pub fn build_session_options_and_crate_config(matches: &getopts::Matches)
         })
     });
 
-    let mut debugging_opts = build_debugging_options(matches, error_format);
-
-    // Incremental compilation only works reliably when translation is done via
-    // MIR, so let's enable -Z orbit if necessary (see #34973).
-    if debugging_opts.incremental.is_some() && !debugging_opts.orbit {
-        early_warn(error_format, "Automatically enabling `-Z orbit` because \
-                                  `-Z incremental` was specified");
-        debugging_opts.orbit = true;
-    }
+    let debugging_opts = build_debugging_options(matches, error_format);
 
     let mir_opt_level = debugging_opts.mir_opt_level.unwrap_or(1);
 
@@ -1387,6 +1427,17 @@ pub fn build_session_options_and_crate_config(matches: &getopts::Matches)
         early_error(error_format, "Value for codegen units must be a positive nonzero integer");
     }
 
+    if cg.profile_generate.is_some() && cg.profile_use.is_some() {
+        early_error(error_format, "options `-C profile-generate` and `-C profile-use` \
+                                   are exclusive");
+    }
+    if let Some(ref path) = cg.profile_use {
+        if !Path::new(path).is_file() {
+            early_error(error_format, &format!("file `{}` passed to `-C profile-use` \
+                                                does not exist", path));
+        }
+    }
+
     let mut prints = Vec::<PrintRequest>::new();
     if cg.target_cpu.as_ref().map_or(false, |s| s == "help") {
         prints.push(PrintRequest::TargetCPUs);
@@ -1438,6 +1489,12 @@ pub fn build_session_options_and_crate_config(matches: &getopts::Matches)
             }
         }
     };
+    if cg.profile_generate.is_some() && opt_level == OptLevel::No {
+        // LLVM only adds the instrumentation as part of its optimization
+        // pipeline, so the profile would always come out empty.
+        early_error(error_format, "`-C profile-generate` requires optimizations, \
+                                   pass `-C opt-level=1` or higher");
+    }
     let debug_assertions = cg.debug_assertions.unwrap_or(opt_level == OptLevel::No);
     let debuginfo = if matches.opt_present("g") {
         if cg.debuginfo.is_some() {
@@ -1696,8 +1753,8 @@ mod dep_tracking {
     use std::collections::BTreeMap;
     use std::hash::{Hash, SipHasher};
     use std::path::PathBuf;
-    use super::{Passes, PanicStrategy, CrateType, OptLevel, DebugInfoLevel,
-                OutputTypes, Externs, ErrorOutputType};
+    use super::{Passes, PanicStrategy, Lto, Sanitizer, SplitDebuginfo, CrateType,
+                OptLevel, DebugInfoLevel, OutputTypes, Externs, ErrorOutputType};
     use syntax::feature_gate::UnstableFeatures;
 
     pub trait DepTrackingHash {
@@ -1739,8 +1796,12 @@ mod dep_tracking {
     impl_dep_tracking_hash_via_hash!(Option<String>);
     impl_dep_tracking_hash_via_hash!(Option<lint::Level>);
     impl_dep_tracking_hash_via_hash!(Option<PathBuf>);
+    impl_dep_tracking_hash_via_hash!(Option<PanicStrategy>);
+    impl_dep_tracking_hash_via_hash!(Option<Sanitizer>);
+    impl_dep_tracking_hash_via_hash!(Option<SplitDebuginfo>);
     impl_dep_tracking_hash_via_hash!(CrateType);
     impl_dep_tracking_hash_via_hash!(PanicStrategy);
+    impl_dep_tracking_hash_via_hash!(Lto);
     impl_dep_tracking_hash_via_hash!(Passes);
     impl_dep_tracking_hash_via_hash!(OptLevel);
     impl_dep_tracking_hash_via_hash!(DebugInfoLevel);
@@ -1803,7 +1864,8 @@ mod tests {
     use std::iter::FromIterator;
     use std::path::PathBuf;
     use std::rc::Rc;
-    use super::{OutputType, OutputTypes, Externs, PanicStrategy};
+    use super::{OutputType, OutputTypes, Externs, PanicStrategy, Lto, Sanitizer,
+                SplitDebuginfo};
     use syntax::attr;
     use syntax::attr::AttrMetaMethods;
 
@@ -2270,7 +2332,11 @@ mod tests {
 
         // Make sure changing a [TRACKED] option changes the hash
         opts = reference.clone();
-        opts.cg.lto = true;
+        opts.cg.lto = Lto::Fat;
+        assert!(reference.dep_tracking_hash() != opts.dep_tracking_hash());
+
+        opts = reference.clone();
+        opts.cg.lto = Lto::Thin;
         assert!(reference.dep_tracking_hash() != opts.dep_tracking_hash());
 
         opts = reference.clone();
@@ -2346,7 +2412,19 @@ mod tests {
         assert!(reference.dep_tracking_hash() != opts.dep_tracking_hash());
 
         opts = reference.clone();
-        opts.cg.panic = PanicStrategy::Abort;
+        opts.cg.panic = Some(PanicStrategy::Abort);
+        assert!(reference.dep_tracking_hash() != opts.dep_tracking_hash());
+
+        opts = reference.clone();
+        opts.cg.profile_generate = Some(String::from("/tmp/pgo"));
+        assert!(reference.dep_tracking_hash() != opts.dep_tracking_hash());
+
+        opts = reference.clone();
+        opts.cg.profile_use = Some(String::from("/tmp/pgo/merged.profdata"));
+        assert!(reference.dep_tracking_hash() != opts.dep_tracking_hash());
+
+        opts = reference.clone();
+        opts.cg.split_debuginfo = Some(SplitDebuginfo::Unpacked);
         assert!(reference.dep_tracking_hash() != opts.dep_tracking_hash());
     }
 
@@ -2424,7 +2502,11 @@ mod tests {
         assert_eq!(reference.dep_tracking_hash(), opts.dep_tracking_hash());
         opts.debugging_opts.dump_mir_dir = Some(String::from("abc"));
         assert_eq!(reference.dep_tracking_hash(), opts.dep_tracking_hash());
-        opts.debugging_opts.orbit = false;
+        opts.debugging_opts.dump_mir_graphviz = true;
+        assert_eq!(reference.dep_tracking_hash(), opts.dep_tracking_hash());
+        opts.debugging_opts.dump_mir_html = true;
+        assert_eq!(reference.dep_tracking_hash(), opts.dep_tracking_hash());
+        opts.debugging_opts.validate_mir = true;
         assert_eq!(reference.dep_tracking_hash(), opts.dep_tracking_hash());
 
         // Make sure changing a [TRACKED] option changes the hash
@@ -2461,19 +2543,19 @@ mod tests {
         assert!(reference.dep_tracking_hash() != opts.dep_tracking_hash());
 
         opts = reference.clone();
-        opts.debugging_opts.force_dropflag_checks = Some(true);
+        opts.debugging_opts.show_span = Some(String::from("abc"));
         assert!(reference.dep_tracking_hash() != opts.dep_tracking_hash());
 
         opts = reference.clone();
-        opts.debugging_opts.enable_nonzeroing_move_hints = true;
+        opts.debugging_opts.mir_opt_level = Some(1);
         assert!(reference.dep_tracking_hash() != opts.dep_tracking_hash());
 
         opts = reference.clone();
-        opts.debugging_opts.show_span = Some(String::from("abc"));
+        opts.debugging_opts.variant_size_ratio = Some(2);
         assert!(reference.dep_tracking_hash() != opts.dep_tracking_hash());
 
         opts = reference.clone();
-        opts.debugging_opts.mir_opt_level = Some(1);
+        opts.debugging_opts.sanitizer = Some(Sanitizer::Thread);
         assert!(reference.dep_tracking_hash() != opts.dep_tracking_hash());
     }
 }
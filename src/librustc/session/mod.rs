@@ -15,14 +15,14 @@ use lint;
 use middle::cstore::CrateStore;
 use middle::dependency_format;
 use session::search_paths::PathKind;
-use session::config::{DebugInfoLevel, PanicStrategy};
+use session::config::{DebugInfoLevel, PanicStrategy, Lto, SplitDebuginfo};
 use ty::tls;
 use util::nodemap::{NodeMap, FnvHashMap};
 use mir::transform as mir_pass;
 
 use syntax::ast::{NodeId, Name};
 use errors::{self, DiagnosticBuilder};
-use errors::emitter::{Emitter, EmitterWriter};
+use errors::emitter::{Emitter, EmitterWriter, ShortEmitter};
 use syntax::json::JsonEmitter;
 use syntax::feature_gate;
 use syntax::parse;
@@ -285,19 +285,63 @@ impl Session {
     pub fn print_llvm_passes(&self) -> bool {
         self.opts.debugging_opts.print_llvm_passes
     }
+    /// Whether the upstream crates are optimized together with this one,
+    /// either by fat LTO or by cross-crate ThinLTO.
     pub fn lto(&self) -> bool {
-        self.opts.cg.lto
+        match self.opts.cg.lto {
+            Lto::Fat | Lto::Thin => true,
+            Lto::No | Lto::ThinLocal => false,
+        }
+    }
+    pub fn thin_lto(&self) -> bool {
+        match self.opts.cg.lto {
+            Lto::Thin | Lto::ThinLocal => true,
+            Lto::No | Lto::Fat => false,
+        }
+    }
+    /// The panic strategy to compile with, either given with `-C panic` or
+    /// the target's default.
+    pub fn panic_strategy(&self) -> PanicStrategy {
+        self.opts.cg.panic.unwrap_or(self.target.target.options.panic_strategy)
+    }
+    pub fn split_debuginfo(&self) -> SplitDebuginfo {
+        self.opts.cg.split_debuginfo.unwrap_or(self.target.target.options.split_debuginfo)
+    }
+    /// Whether the DWARF of each object is split off into a `.dwo` file.
+    /// This is how debuginfo is split on ELF targets.
+    pub fn split_dwarf(&self) -> bool {
+        self.opts.debuginfo != DebugInfoLevel::NoDebugInfo &&
+            self.split_debuginfo() != SplitDebuginfo::Off &&
+            self.target_is_elf()
+    }
+    /// Whether the target's objects and binaries are ELF files.
+    pub fn target_is_elf(&self) -> bool {
+        let target = &self.target.target;
+        !target.options.is_like_osx && !target.options.is_like_windows &&
+            target.arch != "asmjs" && target.arch != "wasm32" && target.arch != "le32"
+    }
+    /// Whether the C runtime is linked statically, as requested by
+    /// `-C target-feature=[+-]crt-static` (the last mention wins).
+    pub fn crt_static(&self) -> bool {
+        let options = &self.target.target.options;
+        if !options.crt_static_respected {
+            return options.crt_static_default;
+        }
+        self.opts.cg.target_feature.split(',').fold(options.crt_static_default, |on, f| {
+            match f.trim() {
+                "+crt-static" => true,
+                "-crt-static" => false,
+                _ => on,
+            }
+        })
     }
     pub fn no_landing_pads(&self) -> bool {
         self.opts.debugging_opts.no_landing_pads ||
-            self.opts.cg.panic == PanicStrategy::Abort
+            self.panic_strategy() == PanicStrategy::Abort
     }
     pub fn unstable_options(&self) -> bool {
         self.opts.debugging_opts.unstable_options
     }
-    pub fn nonzeroing_move_hints(&self) -> bool {
-        self.opts.debugging_opts.enable_nonzeroing_move_hints
-    }
 
     pub fn must_not_eliminate_frame_pointers(&self) -> bool {
         self.opts.debuginfo != DebugInfoLevel::NoDebugInfo ||
@@ -373,6 +417,10 @@ pub fn build_session_with_codemap(sopts: config::Options,
         config::ErrorOutputType::Json => {
             Box::new(JsonEmitter::stderr(Some(registry), codemap.clone()))
         }
+        config::ErrorOutputType::Short(color_config) => {
+            Box::new(ShortEmitter::stderr(color_config,
+                                          Some(codemap.clone())))
+        }
     };
 
     let diagnostic_handler =
@@ -488,6 +536,9 @@ unsafe fn configure_llvm(sess: &Session) {
         add("rustc"); // fake program name
         if sess.time_llvm_passes() { add("-time-passes"); }
         if sess.print_llvm_passes() { add("-debug-pass=Structure"); }
+        // The skeleton compile units point at the `.dwo` files, whose
+        // sections trans extracts from the objects.
+        if sess.split_dwarf() { add("-split-dwarf=Enable"); }
 
         for arg in &sess.opts.cg.llvm_args {
             add(&(*arg));
@@ -509,6 +560,9 @@ pub fn early_error(output: config::ErrorOutputType, msg: &str) -> ! {
                                            None))
         }
         config::ErrorOutputType::Json => Box::new(JsonEmitter::basic()),
+        config::ErrorOutputType::Short(color_config) => {
+            Box::new(ShortEmitter::stderr(color_config, None))
+        }
     };
     let handler = errors::Handler::with_emitter(true, false, emitter);
     handler.emit(&MultiSpan::new(), msg, errors::Level::Fatal);
@@ -522,6 +576,9 @@ pub fn early_warn(output: config::ErrorOutputType, msg: &str) {
                                            None))
         }
         config::ErrorOutputType::Json => Box::new(JsonEmitter::basic()),
+        config::ErrorOutputType::Short(color_config) => {
+            Box::new(ShortEmitter::stderr(color_config, None))
+        }
     };
     let handler = errors::Handler::with_emitter(true, false, emitter);
     handler.emit(&MultiSpan::new(), msg, errors::Level::Warning);
@@ -260,6 +260,11 @@ impl<'a, 'tcx> ty::TyS<'tcx> {
                                       -> TypeContents {
             if Some(did) == tcx.lang_items.unsafe_cell_type() {
                 tc | TC::InteriorUnsafe
+            } else if Some(did) == tcx.lang_items.maybe_uninit_type() ||
+                      Some(did) == tcx.lang_items.manually_drop_type() {
+                // The contents may not be initialized, or are dropped by
+                // hand, so the wrapper never owns (and never drops) them.
+                tc - TC::OwnsAll
             } else {
                 tc
             }
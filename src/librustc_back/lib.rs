@@ -36,7 +36,7 @@
 #![feature(rand)]
 #![feature(rustc_private)]
 #![feature(staged_api)]
-#![feature(step_by)]
+#![feature(iterator_step_by)]
 #![feature(question_mark)]
 #![cfg_attr(test, feature(test, rand))]
 
@@ -51,3 +51,108 @@ pub mod sha2;
 pub mod target;
 pub mod slice;
 pub mod dynamic_lib;
+
+use serialize::json::{Json, ToJson};
+
+#[derive(Clone, Copy, Debug, PartialEq, Hash)]
+pub enum PanicStrategy {
+    Unwind,
+    Abort,
+}
+
+impl PanicStrategy {
+    pub fn desc(&self) -> &str {
+        match *self {
+            PanicStrategy::Unwind => "unwind",
+            PanicStrategy::Abort => "abort",
+        }
+    }
+}
+
+impl ToJson for PanicStrategy {
+    fn to_json(&self) -> Json {
+        self.desc().to_json()
+    }
+}
+
+/// The kind of linker a target is linked with, which decides how the
+/// arguments passed to it are spelled.
+#[derive(Clone, Copy, Debug, PartialEq, Hash)]
+pub enum LinkerFlavor {
+    /// A C compiler driver (`cc`, `gcc`, `clang`) in front of a GNU-like
+    /// linker; linker options are passed through with `-Wl,`.
+    Gcc,
+    /// A GNU-like linker (`ld`, `ld.lld`) invoked directly.
+    Ld,
+    /// The MSVC linker, `link.exe`, or something emulating it.
+    Msvc,
+    /// LLD's WebAssembly flavor.
+    Wasm,
+}
+
+impl LinkerFlavor {
+    pub fn from_str(s: &str) -> Option<LinkerFlavor> {
+        match s {
+            "gcc" => Some(LinkerFlavor::Gcc),
+            "ld" => Some(LinkerFlavor::Ld),
+            "msvc" => Some(LinkerFlavor::Msvc),
+            "wasm" => Some(LinkerFlavor::Wasm),
+            _ => None,
+        }
+    }
+
+    pub fn desc(&self) -> &str {
+        match *self {
+            LinkerFlavor::Gcc => "gcc",
+            LinkerFlavor::Ld => "ld",
+            LinkerFlavor::Msvc => "msvc",
+            LinkerFlavor::Wasm => "wasm",
+        }
+    }
+}
+
+impl ToJson for LinkerFlavor {
+    fn to_json(&self) -> Json {
+        self.desc().to_json()
+    }
+}
+
+/// Where the debuginfo of a crate ends up once the crate is linked.
+#[derive(Clone, Copy, Debug, PartialEq, Hash)]
+pub enum SplitDebuginfo {
+    /// Debuginfo is linked into the output itself. The OSX linker can't do
+    /// that, so there this still collects it into a `.dSYM` like `Packed`.
+    Off,
+    /// Debuginfo is collected into a single file next to the output: a
+    /// `.dSYM` on OSX, a `.dwp` for split DWARF and a `.pdb` for MSVC.
+    Packed,
+    /// Debuginfo is left in one file per object, which the output refers to:
+    /// the objects themselves on OSX and a `.dwo` per object for split DWARF
+    /// on ELF targets.
+    Unpacked,
+}
+
+impl SplitDebuginfo {
+    pub fn from_str(s: &str) -> Option<SplitDebuginfo> {
+        match s {
+            "off" => Some(SplitDebuginfo::Off),
+            "packed" => Some(SplitDebuginfo::Packed),
+            "unpacked" => Some(SplitDebuginfo::Unpacked),
+            _ => None,
+        }
+    }
+
+    pub fn desc(&self) -> &str {
+        match *self {
+            SplitDebuginfo::Off => "off",
+            SplitDebuginfo::Packed => "packed",
+            SplitDebuginfo::Unpacked => "unpacked",
+        }
+    }
+}
+
+impl ToJson for SplitDebuginfo {
+    fn to_json(&self) -> Json {
+        self.desc().to_json()
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// The wasm32-unknown-unknown target compiles straight to WebAssembly with
+// LLVM's wasm backend, without involving Emscripten. There is no libc or
+// operating system underneath, so the standard library is mostly stubbed out
+// and modules are linked with LLD's wasm flavor. Any functions which are left
+// undefined at link time become imports of the resulting module.
+//
+// LLD isn't shipped with the toolchain, so an `lld` new enough to know the
+// wasm flavor has to be installed and on `PATH` (or passed with `-C linker`).
+
+use super::{Target, TargetOptions};
+use {LinkerFlavor, PanicStrategy};
+
+pub fn target() -> Result<Target, String> {
+    let opts = TargetOptions {
+        linker: "lld".to_string(),
+        linker_flavor: LinkerFlavor::Wasm,
+        // `-flavor` has to be the very first argument to `lld`. Executables
+        // export `main` as their entry point, which `--no-entry` overrides
+        // again for cdylibs.
+        pre_link_args: vec!["-flavor".to_string(),
+                            "wasm".to_string(),
+                            "--allow-undefined".to_string(),
+                            "--entry=main".to_string()],
+
+        // There are no shared libraries in WebAssembly, but a `cdylib` is a
+        // module exporting the crate's public functions.
+        dynamic_linking: true,
+        only_cdylib: true,
+        executables: true,
+        dll_prefix: "".to_string(),
+        dll_suffix: ".wasm".to_string(),
+        exe_suffix: ".wasm".to_string(),
+        relocation_model: "static".to_string(),
+        no_compiler_rt: true,
+        allow_asm: false,
+        max_atomic_width: 32,
+        // Exceptions aren't supported in WebAssembly yet.
+        panic_strategy: PanicStrategy::Abort,
+        .. Default::default()
+    };
+    Ok(Target {
+        llvm_target: "wasm32-unknown-unknown".to_string(),
+        target_endian: "little".to_string(),
+        target_pointer_width: "32".to_string(),
+        target_os: "unknown".to_string(),
+        target_env: "".to_string(),
+        target_vendor: "unknown".to_string(),
+        data_layout: "e-m:e-p:32:32-i64:64-n32:64-S128".to_string(),
+        arch: "wasm32".to_string(),
+        options: opts,
+    })
+}
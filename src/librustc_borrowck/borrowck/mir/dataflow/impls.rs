@@ -10,6 +10,7 @@
 
 use rustc::ty::TyCtxt;
 use rustc::mir::repr::{self, Mir};
+use rustc::mir::visit::{LvalueContext, Visitor};
 use rustc_data_structures::indexed_vec::Idx;
 
 use super::super::gather_moves::{Location};
@@ -20,11 +21,11 @@ use super::super::drop_flag_effects_for_function_entry;
 use super::super::drop_flag_effects_for_location;
 use super::super::on_all_children_bits;
 
-use super::{BitDenotation, BlockSets, DataflowOperator};
+use super::{BitDenotation, BlockSets, DataflowOperator, Direction};
 
-use bitslice::BitSlice; // adds set_bit/get_bit to &[usize] bitvector rep.
-use bitslice::{BitwiseOperator};
-use indexed_set::{IdxSet};
+use rustc_data_structures::bitslice::BitSlice; // adds set_bit/get_bit to &[usize] bitvector rep.
+use rustc_data_structures::bitslice::{BitwiseOperator};
+use rustc_data_structures::indexed_set::{IdxSet};
 
 // Dataflow analyses are built upon some interpretation of the
 // bitvectors attached to each basic block, represented via a
@@ -174,6 +175,41 @@ impl<'a, 'tcx: 'a> DefinitelyInitializedLvals<'a, 'tcx> {
     }
 }
 
+/// `MaybeLiveLvals` tracks the local variables, arguments and
+/// temporaries whose current value might still be read at some later
+/// point of the control flow for a function. It is a backward analysis.
+///
+/// For example, in code like the following, we have corresponding
+/// dataflow information shown in the right-hand comments.
+///
+/// ```rust
+/// fn foo(pred: bool, x: u32, y: u32) -> u32 { // maybe-live:
+///                                            // {pred, x, y}
+///     let a = 1; let b;                      // {pred, x, y, a}
+///
+///     if pred {
+///         b = x;                             // {a, b}
+///     } else {
+///         b = y;                             // {a, b}
+///     }                                      // {a, b}
+///
+///     a + b                                  // {}
+/// }
+/// ```
+///
+/// Only whole locals are tracked: a use of any projection of a local
+/// counts as a use of all of it, and only an assignment to the whole
+/// local kills it.
+pub struct MaybeLiveLvals<'a, 'tcx: 'a> {
+    mir: &'a Mir<'tcx>,
+}
+
+impl<'a, 'tcx: 'a> MaybeLiveLvals<'a, 'tcx> {
+    pub fn new(mir: &'a Mir<'tcx>) -> Self {
+        MaybeLiveLvals { mir: mir }
+    }
+}
+
 /// `MovingOutStatements` tracks the statements that perform moves out
 /// of particular l-values. More precisely, it tracks whether the
 /// *effect* of such moves (namely, the uninitialization of the
@@ -505,6 +541,98 @@ impl<'a, 'tcx> BitDenotation for MovingOutStatements<'a, 'tcx> {
     }
 }
 
+/// Returns the move path of `lvalue` if it is a whole local.
+fn local_path<'tcx>(ctxt: &MoveDataParamEnv<'tcx>,
+                    lvalue: &repr::Lvalue<'tcx>) -> Option<MovePathIndex> {
+    match *lvalue {
+        repr::Lvalue::Var(_) |
+        repr::Lvalue::Temp(_) |
+        repr::Lvalue::Arg(_) => Some(ctxt.move_data.rev_lookup.find(lvalue)),
+        repr::Lvalue::Static(_) |
+        repr::Lvalue::ReturnPointer |
+        repr::Lvalue::Projection(_) => None,
+    }
+}
+
+/// Gens every local that is read by the visited MIR node.
+struct LiveUses<'b, 'c: 'b, 'tcx: 'c> {
+    ctxt: &'b MoveDataParamEnv<'tcx>,
+    sets: &'b mut BlockSets<'c, MovePathIndex>,
+}
+
+impl<'b, 'c, 'tcx> Visitor<'tcx> for LiveUses<'b, 'c, 'tcx> {
+    fn visit_lvalue(&mut self, lvalue: &repr::Lvalue<'tcx>, context: LvalueContext) {
+        match context {
+            // Whole-local definitions are handled by the callers; the base
+            // of a projection that is written to is still read.
+            LvalueContext::Store |
+            LvalueContext::Call |
+            LvalueContext::StorageLive |
+            LvalueContext::StorageDead => {}
+            _ => if let Some(path) = local_path(self.ctxt, lvalue) {
+                self.sets.gen(&path);
+            }
+        }
+        self.super_lvalue(lvalue, context);
+    }
+}
+
+impl<'a, 'tcx> BitDenotation for MaybeLiveLvals<'a, 'tcx> {
+    type Idx = MovePathIndex;
+    type Ctxt = MoveDataParamEnv<'tcx>;
+    fn name() -> &'static str { "maybe_live" }
+    fn direction() -> Direction { Direction::Backward }
+    fn bits_per_block(&self, ctxt: &Self::Ctxt) -> usize {
+        ctxt.move_data.move_paths.len()
+    }
+
+    fn start_block_effect(&self, _ctxt: &Self::Ctxt, _sets: &mut BlockSets<MovePathIndex>) {
+        // nothing is read after the function returns, so this method
+        // has no effect on `_sets`.
+    }
+
+    fn statement_effect(&self,
+                        ctxt: &Self::Ctxt,
+                        sets: &mut BlockSets<MovePathIndex>,
+                        bb: repr::BasicBlock,
+                        idx: usize)
+    {
+        let stmt = &self.mir[bb].statements[idx];
+        // The statement is applied backwards: its definition is killed
+        // before the values it reads are gen'ed.
+        if let repr::StatementKind::Assign(ref lvalue, _) = stmt.kind {
+            if let Some(path) = local_path(ctxt, lvalue) {
+                sets.kill(&path);
+            }
+        }
+        LiveUses { ctxt: ctxt, sets: sets }.visit_statement(bb, stmt);
+    }
+
+    fn terminator_effect(&self,
+                         ctxt: &Self::Ctxt,
+                         sets: &mut BlockSets<MovePathIndex>,
+                         bb: repr::BasicBlock,
+                         _statements_len: usize)
+    {
+        let term = self.mir[bb].terminator();
+        if let repr::TerminatorKind::Call { destination: Some((ref dest, _)), .. } = term.kind {
+            if let Some(path) = local_path(ctxt, dest) {
+                sets.kill(&path);
+            }
+        }
+        LiveUses { ctxt: ctxt, sets: sets }.visit_terminator(bb, term);
+    }
+
+    fn propagate_call_return(&self,
+                             _ctxt: &Self::Ctxt,
+                             _in_out: &mut IdxSet<MovePathIndex>,
+                             _call_bb: repr::BasicBlock,
+                             _dest_bb: repr::BasicBlock,
+                             _dest_lval: &repr::Lvalue) {
+        // backward analyses kill the destination in `terminator_effect`.
+    }
+}
+
 fn zero_to_one(bitvec: &mut [usize], move_index: MoveOutIndex) {
     let retval = bitvec.set_bit(move_index.index());
     assert!(retval);
@@ -538,6 +666,13 @@ impl<'a, 'tcx> BitwiseOperator for DefinitelyInitializedLvals<'a, 'tcx> {
     }
 }
 
+impl<'a, 'tcx> BitwiseOperator for MaybeLiveLvals<'a, 'tcx> {
+    #[inline]
+    fn join(&self, succ1: usize, succ2: usize) -> usize {
+        succ1 | succ2 // "maybe" means we union effects of both succs
+    }
+}
+
 // The way that dataflow fixed point iteration works, you want to
 // start at bottom and work your way to a fixed point. Control-flow
 // merges will apply the `join` operator to each block entry's current
@@ -575,3 +710,10 @@ impl<'a, 'tcx> DataflowOperator for DefinitelyInitializedLvals<'a, 'tcx> {
         true // bottom = initialized (start_block_effect counters this at outset)
     }
 }
+
+impl<'a, 'tcx> DataflowOperator for MaybeLiveLvals<'a, 'tcx> {
+    #[inline]
+    fn bottom_value() -> bool {
+        false // bottom = dead
+    }
+}
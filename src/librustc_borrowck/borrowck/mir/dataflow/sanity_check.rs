@@ -18,7 +18,7 @@ use rustc_data_structures::indexed_vec::Idx;
 
 use super::super::gather_moves::{MovePathIndex};
 use super::super::MoveDataParamEnv;
-use super::BitDenotation;
+use super::{BitDenotation, BlockSets, Direction};
 use super::DataflowResults;
 
 /// This function scans `mir` for all calls to the intrinsic
@@ -47,7 +47,7 @@ pub fn sanity_check_via_rustc_peek<'a, 'tcx, O>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
 {
     debug!("sanity_check_via_rustc_peek id: {:?}", id);
     // FIXME: this is not DRY. Figure out way to abstract this and
-    // `dataflow::build_sets`. (But note it is doing non-standard
+    // `rustc_mir::dataflow::build_sets`. (But note it is doing non-standard
     // stuff, so such generalization may not be realistic.)
 
     for bb in mir.basic_blocks().indices() {
@@ -84,9 +84,9 @@ fn each_block<'a, 'tcx, O>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
         }
     };
 
-    let mut entry = results.0.sets.on_entry_set_for(bb.index()).to_owned();
-    let mut gen = results.0.sets.gen_set_for(bb.index()).to_owned();
-    let mut kill = results.0.sets.kill_set_for(bb.index()).to_owned();
+    let mut entry = results.sets().on_entry_set_for(bb.index()).to_owned();
+    let mut gen = results.sets().gen_set_for(bb.index()).to_owned();
+    let mut kill = results.sets().kill_set_for(bb.index()).to_owned();
 
     // Emulate effect of all statements in the block up to (but not
     // including) the borrow within `peek_arg_lval`. Do *not* include
@@ -94,9 +94,49 @@ fn each_block<'a, 'tcx, O>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
     // of the argument at time immediate preceding Call to
     // `rustc_peek`).
 
-    let mut sets = super::BlockSets { on_entry: &mut entry,
-                                      gen_set: &mut gen,
-                                      kill_set: &mut kill };
+    let mut sets = BlockSets { on_entry: &mut entry,
+                               gen_set: &mut gen,
+                               kill_set: &mut kill };
+
+    if O::direction() == Direction::Backward {
+        // For a backward analysis the entry set holds the state at the
+        // *end* of the block, so walk back from there: emulate the call
+        // to `rustc_peek` itself and then every statement after the
+        // borrow within `peek_arg_lval`.
+        for e in sets.gen_set.words_mut() { *e = 0; }
+        for e in sets.kill_set.words_mut() { *e = 0; }
+        results.operator().terminator_effect(ctxt, &mut sets, bb, statements.len());
+        sets.on_entry.union(sets.gen_set);
+        sets.on_entry.subtract(sets.kill_set);
+
+        for (j, stmt) in statements.iter().enumerate().rev() {
+            if let repr::StatementKind::Assign(ref lvalue, ref rvalue) = stmt.kind {
+                if lvalue == peek_arg_lval {
+                    if let repr::Rvalue::Ref(_,
+                                             repr::BorrowKind::Shared,
+                                             ref peeking_at_lval) = *rvalue {
+                        let peek_mpi = move_data.rev_lookup.find(peeking_at_lval);
+                        let bit_state = sets.on_entry.contains(&peek_mpi);
+                        debug!("rustc_peek({:?} = &{:?}) bit_state: {}",
+                               lvalue, peeking_at_lval, bit_state);
+                        if !bit_state {
+                            tcx.sess.span_err(span, &format!("rustc_peek: bit not set"));
+                        }
+                        return;
+                    }
+                }
+            }
+
+            for e in sets.gen_set.words_mut() { *e = 0; }
+            for e in sets.kill_set.words_mut() { *e = 0; }
+            results.operator().statement_effect(ctxt, &mut sets, bb, j);
+            sets.on_entry.union(sets.gen_set);
+            sets.on_entry.subtract(sets.kill_set);
+        }
+
+        report_unanticipated_pattern(tcx, span);
+        return;
+    }
 
     for (j, stmt) in statements.iter().enumerate() {
         debug!("rustc_peek: ({:?},{}) {:?}", bb, j, stmt);
@@ -141,11 +181,15 @@ fn each_block<'a, 'tcx, O>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
         // reset GEN and KILL sets before emulating their effect.
         for e in sets.gen_set.words_mut() { *e = 0; }
         for e in sets.kill_set.words_mut() { *e = 0; }
-        results.0.operator.statement_effect(ctxt, &mut sets, bb, j);
+        results.operator().statement_effect(ctxt, &mut sets, bb, j);
         sets.on_entry.union(sets.gen_set);
         sets.on_entry.subtract(sets.kill_set);
     }
 
+    report_unanticipated_pattern(tcx, span);
+}
+
+fn report_unanticipated_pattern<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, span: Span) {
     tcx.sess.span_err(span, &format!("rustc_peek: MIR did not match \
                                       anticipated pattern; note that \
                                       rustc_peek expects input of \
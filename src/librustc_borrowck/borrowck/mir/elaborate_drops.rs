@@ -8,7 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use indexed_set::IdxSetBuf;
+use rustc_data_structures::indexed_set::IdxSetBuf;
 use super::gather_moves::{MoveData, MovePathIndex, MovePathContent, Location};
 use super::dataflow::{MaybeInitializedLvals, MaybeUninitializedLvals};
 use super::dataflow::{DataflowResults};
@@ -34,7 +34,7 @@ use self::dataflow::{BitDenotation};
 use self::dataflow::{DataflowOperator};
 use self::dataflow::{Dataflow, DataflowAnalysis, DataflowResults};
 use self::dataflow::{MaybeInitializedLvals, MaybeUninitializedLvals};
-use self::dataflow::{DefinitelyInitializedLvals};
+use self::dataflow::{DefinitelyInitializedLvals, MaybeLiveLvals};
 use self::gather_moves::{MoveData, MovePathIndex, Location};
 use self::gather_moves::{MovePathContent, MovePathData};
 
@@ -97,6 +97,11 @@ pub fn borrowck_mir<'a, 'tcx: 'a>(
     if has_rustc_mir_with(attributes, "rustc_peek_definite_init").is_some() {
         dataflow::sanity_check_via_rustc_peek(bcx.tcx, mir, id, attributes, &mdpe, &flow_def_inits);
     }
+    if has_rustc_mir_with(attributes, "rustc_peek_maybe_live").is_some() {
+        let flow_lives =
+            do_dataflow(tcx, mir, id, attributes, &mdpe, MaybeLiveLvals::new(mir));
+        dataflow::sanity_check_via_rustc_peek(bcx.tcx, mir, id, attributes, &mdpe, &flow_lives);
+    }
 
     if has_rustc_mir_with(attributes, "stop_after_dataflow").is_some() {
         bcx.tcx.sess.fatal("stop_after_dataflow ended compilation");
@@ -44,7 +44,7 @@ use std::rc::Rc;
 use syntax::ast;
 use syntax::attr::AttrMetaMethods;
 use syntax_pos::{MultiSpan, Span};
-use errors::DiagnosticBuilder;
+use errors::{Applicability, DiagnosticBuilder};
 
 use rustc::hir;
 use rustc::hir::{FnDecl, Block};
@@ -187,9 +187,6 @@ fn borrowck_fn(this: &mut BorrowckCtxt,
                                                     this.tcx,
                                                     sp,
                                                     id);
-    move_data::fragments::build_unfragmented_map(this,
-                                                 &flowed_moves.move_data,
-                                                 id);
 
     check_loans::check_loans(this,
                              &loan_dfcx,
@@ -954,12 +951,14 @@ impl<'a, 'tcx> BorrowckCtxt<'a, 'tcx> {
             .span_label(err.span,
                        &format!("may outlive borrowed value {}",
                                 cmt_path_or_string))
-            .span_suggestion(err.span,
-                             &format!("to force the closure to take ownership of {} \
-                                       (and any other referenced variables), \
-                                       use the `move` keyword, as shown:",
-                                       cmt_path_or_string),
-                             suggestion)
+            .span_suggestion_with_applicability(
+                err.span,
+                &format!("to force the closure to take ownership of {} \
+                          (and any other referenced variables), \
+                          use the `move` keyword, as shown:",
+                         cmt_path_or_string),
+                suggestion,
+                Applicability::MachineApplicable)
             .emit();
     }
 
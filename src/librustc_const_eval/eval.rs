@@ -1098,6 +1098,36 @@ fn resolve_trait_associated_const<'a, 'tcx: 'a>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
     })
 }
 
+/// Evaluates `e`, a path to an associated constant, using `substs` to
+/// resolve it instead of the ones recorded by type checking.
+fn eval_assoc_const_path<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                   e: &Expr,
+                                   substs: &'tcx Substs<'tcx>,
+                                   ty_hint: EvalHint<'tcx>)
+                                   -> EvalResult {
+    let resolution = tcx.def_map.borrow().get(&e.id).cloned();
+    let def_id = match resolution {
+        Some(PathResolution { base_def: Def::AssociatedConst(def_id), depth: 0 }) => def_id,
+        _ => return eval_const_expr_partial(tcx, e, ty_hint, None),
+    };
+    match lookup_const_by_id(tcx, def_id, Some(substs)) {
+        Some((expr, ty)) => {
+            let item_hint = match ty {
+                Some(ty) => ty_hint.checked_or(ty),
+                None => ty_hint,
+            };
+            match eval_const_expr_partial(tcx, expr, item_hint, None) {
+                Ok(val) => Ok(val),
+                Err(err) => {
+                    debug!("bad reference: {:?}, {:?}", err.description(), err.span);
+                    signal!(e, ErroneousReferencedConstant(box err))
+                },
+            }
+        }
+        None => signal!(e, NonConstPath),
+    }
+}
+
 fn cast_const_int<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, val: ConstInt, ty: ty::Ty) -> CastResult {
     let v = val.to_u64_unchecked();
     match ty.sty {
@@ -1324,9 +1354,28 @@ pub fn eval_length<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                              count_expr: &hir::Expr,
                              reason: &str)
                              -> Result<usize, ErrorReported>
+{
+    eval_length_with_substs(tcx, count_expr, None, reason)
+}
+
+/// Like `eval_length`, but if `count_expr` is a path to a trait-associated
+/// constant, `substs` are used to select the impl providing its value.
+///
+/// Lengths are evaluated before type checking has recorded the
+/// substitutions of the path, so callers which know them (e.g. from the
+/// self type in `<T as Trait>::N`) have to pass them in explicitly.
+pub fn eval_length_with_substs<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                         count_expr: &hir::Expr,
+                                         substs: Option<&'tcx Substs<'tcx>>,
+                                         reason: &str)
+                                         -> Result<usize, ErrorReported>
 {
     let hint = UncheckedExprHint(tcx.types.usize);
-    match eval_const_expr_partial(tcx, count_expr, hint, None) {
+    let result = match substs {
+        Some(substs) => eval_assoc_const_path(tcx, count_expr, substs, hint),
+        None => eval_const_expr_partial(tcx, count_expr, hint, None),
+    };
+    match result {
         Ok(Integral(Usize(count))) => {
             let val = count.as_u64(tcx.sess.target.uint_type);
             assert_eq!(val as usize as u64, val);
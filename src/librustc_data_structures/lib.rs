@@ -38,9 +38,11 @@ extern crate core;
 extern crate log;
 extern crate serialize as rustc_serialize; // used by deriving
 
+pub mod bitslice;
 pub mod bitvec;
 pub mod graph;
 pub mod ivar;
+pub mod indexed_set;
 pub mod indexed_vec;
 pub mod obligation_forest;
 pub mod snapshot_map;
@@ -37,7 +37,8 @@ use rustc_typeck as typeck;
 use rustc_privacy;
 use rustc_plugin::registry::Registry;
 use rustc_plugin as plugin;
-use rustc_passes::{ast_validation, no_asm, loops, consts, rvalues, static_recursion};
+use rustc_passes::{ast_validation, no_asm, loops, naked_functions, consts, rvalues,
+                   static_recursion};
 use rustc_const_eval::check_match;
 use super::Compilation;
 
@@ -202,7 +203,8 @@ pub fn compile_input(sess: &Session,
             }
             let trans = phase_4_translate_to_llvm(tcx,
                                                   mir_map.unwrap(),
-                                                  analysis);
+                                                  analysis,
+                                                  &outputs);
 
             if log_enabled!(::log::INFO) {
                 println!("Post-trans");
@@ -841,6 +843,10 @@ pub fn phase_3_run_analysis_passes<'tcx, F, R>(sess: &'tcx Session,
          "loop checking",
          || loops::check_crate(sess, &hir_map));
 
+    time(time_passes,
+         "naked function checking",
+         || naked_functions::check_crate(sess, &resolutions.def_map, &hir_map));
+
     time(time_passes,
               "static item recursion checking",
               || static_recursion::check_crate(sess, &resolutions.def_map, &hir_map))?;
@@ -885,10 +891,6 @@ pub fn phase_3_run_analysis_passes<'tcx, F, R>(sess: &'tcx Session,
              "intrinsic checking",
              || middle::intrinsicck::check_crate(tcx));
 
-        time(time_passes,
-             "effect checking",
-             || middle::effect::check_crate(tcx));
-
         time(time_passes,
              "match checking",
              || check_match::check_crate(tcx));
@@ -913,13 +915,16 @@ pub fn phase_3_run_analysis_passes<'tcx, F, R>(sess: &'tcx Session,
         time(time_passes, "MIR passes", || {
             let mut passes = sess.mir_passes.borrow_mut();
             // Push all the built-in passes.
-            passes.push_hook(box mir::transform::dump_mir::DumpMir);
+            passes.push_hook(box mir::transform::dump_mir::DumpMir::new());
+            passes.push_hook(box mir::transform::validate::ValidateMir);
+            passes.push_pass(box mir::transform::check_unsafety::CheckUnsafety);
             passes.push_pass(box mir::transform::simplify_cfg::SimplifyCfg::new("initial"));
             passes.push_pass(box mir::transform::qualify_consts::QualifyAndPromoteConstants);
             passes.push_pass(box mir::transform::type_check::TypeckMir);
             passes.push_pass(
                 box mir::transform::simplify_branches::SimplifyBranches::new("initial"));
             passes.push_pass(box mir::transform::simplify_cfg::SimplifyCfg::new("qualify-consts"));
+            passes.push_pass(box mir::transform::eval_consts::EvalConsts);
             // And run everything.
             passes.run_passes(tcx, &mut mir_map);
         });
@@ -973,7 +978,8 @@ pub fn phase_3_run_analysis_passes<'tcx, F, R>(sess: &'tcx Session,
 /// Run the translation phase to LLVM, after which the AST and analysis can
 pub fn phase_4_translate_to_llvm<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                                            mut mir_map: MirMap<'tcx>,
-                                           analysis: ty::CrateAnalysis)
+                                           analysis: ty::CrateAnalysis,
+                                           outputs: &OutputFilenames)
                                            -> trans::CrateTranslation {
     let time_passes = tcx.sess.time_passes();
 
@@ -985,7 +991,8 @@ pub fn phase_4_translate_to_llvm<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
     // to LLVM code.
     time(time_passes, "Prepare MIR codegen passes", || {
         let mut passes = ::rustc::mir::transform::Passes::new();
-        passes.push_hook(box mir::transform::dump_mir::DumpMir);
+        passes.push_hook(box mir::transform::dump_mir::DumpMir::new());
+        passes.push_hook(box mir::transform::validate::ValidateMir);
         passes.push_pass(box mir::transform::no_landing_pads::NoLandingPads);
         passes.push_pass(box mir::transform::simplify_cfg::SimplifyCfg::new("no-landing-pads"));
 
@@ -996,6 +1003,15 @@ pub fn phase_4_translate_to_llvm<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
         passes.push_pass(box mir::transform::no_landing_pads::NoLandingPads);
         passes.push_pass(box mir::transform::simplify_cfg::SimplifyCfg::new("elaborate-drops"));
 
+        passes.push_pass(box mir::transform::inline::Inline);
+        passes.push_pass(box mir::transform::simplify_cfg::SimplifyCfg::new("inline"));
+
+        passes.push_pass(box mir::transform::const_prop::ConstPropagation);
+        passes.push_pass(
+            box mir::transform::simplify_branches::SimplifyBranches::new("after-const-prop"));
+        passes.push_pass(box mir::transform::simplify_cfg::SimplifyCfg::new("after-const-prop"));
+        passes.push_pass(box mir::transform::copy_prop::CopyPropagation);
+
         passes.push_pass(box mir::transform::deaggregator::Deaggregator);
 
         passes.push_pass(box mir::transform::add_call_guards::AddCallGuards);
@@ -1007,7 +1023,7 @@ pub fn phase_4_translate_to_llvm<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
     let translation =
         time(time_passes,
              "translation",
-             move || trans::trans_crate(tcx, &mir_map, analysis));
+             move || trans::trans_crate(tcx, &mir_map, analysis, outputs));
 
     time(time_passes,
          "assert dep graph",
@@ -387,6 +387,9 @@ fn check_cfg(cfg: &ast::CrateConfig,
             Box::new(errors::emitter::EmitterWriter::stderr(color_config, None))
         }
         config::ErrorOutputType::Json => Box::new(json::JsonEmitter::basic()),
+        config::ErrorOutputType::Short(color_config) => {
+            Box::new(errors::emitter::ShortEmitter::stderr(color_config, None))
+        }
     };
     let handler = errors::Handler::with_emitter(true, false, emitter);
 
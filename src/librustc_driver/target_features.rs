@@ -46,6 +46,9 @@ const X86_WHITELIST: &'static [&'static str] = &[
 ///
 /// This is performed by checking whether a whitelisted set of
 /// features is available on the target machine, by querying LLVM.
+///
+/// `target_feature = "crt-static"` is added as well when the C runtime is
+/// linked statically, so that libraries can pick the matching native libs.
 pub fn add_configuration(cfg: &mut ast::CrateConfig, sess: &Session) {
     let target_machine = create_target_machine(sess);
 
@@ -62,4 +65,8 @@ pub fn add_configuration(cfg: &mut ast::CrateConfig, sess: &Session) {
             cfg.push(attr::mk_name_value_item_str(tf.clone(), intern(&feat[..feat.len()-1])))
         }
     }
+
+    if sess.crt_static() {
+        cfg.push(attr::mk_name_value_item_str(tf, intern("crt-static")));
+    }
 }
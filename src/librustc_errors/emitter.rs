@@ -34,6 +34,12 @@ impl Emitter for EmitterWriter {
     }
 }
 
+impl Emitter for ShortEmitter {
+    fn emit(&mut self, db: &DiagnosticBuilder) {
+        self.emit_message_short(db);
+    }
+}
+
 /// maximum number of lines we will print for each error; arbitrary.
 pub const MAX_HIGHLIGHT_LINES: usize = 6;
 
@@ -59,6 +65,14 @@ pub struct EmitterWriter {
     cm: Option<Rc<CodeMapper>>,
 }
 
+/// Prints each diagnostic as a single `file:line:col: level[code]: message`
+/// line, leaving out the source snippet and any sub-diagnostics. This is
+/// meant for output that is filtered by other tools.
+pub struct ShortEmitter {
+    dst: Destination,
+    cm: Option<Rc<CodeMapper>>,
+}
+
 struct FileWithAnnotatedLines {
     file: Rc<FileMap>,
     lines: Vec<Line>,
@@ -389,7 +403,7 @@ impl EmitterWriter {
 
         for sub in &db.children {
             let sub_result = self.get_multispan_max_line_num(&sub.span);
-            max = if sub_result > max { primary } else { max };
+            max = if sub_result > max { sub_result } else { max };
         }
         max
     }
@@ -665,6 +679,56 @@ impl EmitterWriter {
     }
 }
 
+impl ShortEmitter {
+    pub fn stderr(color_config: ColorConfig,
+                  code_map: Option<Rc<CodeMapper>>)
+                  -> ShortEmitter {
+        if color_config.use_color() {
+            let dst = Destination::from_stderr();
+            ShortEmitter { dst: dst,
+                           cm: code_map}
+        } else {
+            ShortEmitter { dst: Raw(Box::new(io::stderr())),
+                           cm: code_map}
+        }
+    }
+
+    pub fn new(dst: Box<Write + Send>,
+               code_map: Option<Rc<CodeMapper>>)
+               -> ShortEmitter {
+        ShortEmitter { dst: Raw(dst),
+                       cm: code_map}
+    }
+
+    fn emit_message_short(&mut self, db: &DiagnosticBuilder) {
+        let mut buffer = StyledBuffer::new();
+
+        if let (Some(ref cm), Some(primary_span)) = (self.cm.as_ref(), db.span.primary_span()) {
+            if primary_span != DUMMY_SP && primary_span != COMMAND_LINE_SP {
+                let loc = cm.lookup_char_pos(primary_span.lo);
+                buffer.append(0,
+                              &format!("{}:{}:{}", loc.file.name, loc.line, loc.col.0 + 1),
+                              Style::LineAndColumn);
+                buffer.append(0, ": ", Style::NoStyle);
+            }
+        }
+
+        buffer.append(0, &db.level.to_string(), Style::Level(db.level.clone()));
+        if let Some(ref code) = db.code {
+            buffer.append(0, "[", Style::Level(db.level.clone()));
+            buffer.append(0, &code, Style::Level(db.level.clone()));
+            buffer.append(0, "]", Style::Level(db.level.clone()));
+        }
+        buffer.append(0, ": ", Style::HeaderMsg);
+        buffer.append(0, &db.message, Style::HeaderMsg);
+
+        match emit_to_destination(&buffer.render(), &db.level, &mut self.dst) {
+            Ok(()) => (),
+            Err(e) => panic!("failed to emit error: {}", e)
+        }
+    }
+}
+
 fn draw_col_separator(buffer: &mut StyledBuffer, line: usize, col: usize) {
     buffer.puts(line, col, "| ", Style::LineNumber);
 }
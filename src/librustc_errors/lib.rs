@@ -72,6 +72,23 @@ pub enum RenderSpan {
 pub struct CodeSuggestion {
     pub msp: MultiSpan,
     pub substitutes: Vec<String>,
+    pub applicability: Applicability,
+}
+
+/// Indicates how confident we are that a suggestion is what the user
+/// wants, so that tools can decide whether to apply it automatically.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, RustcEncodable)]
+pub enum Applicability {
+    /// The suggestion is definitely what the user intended, and it
+    /// produces valid code. It can be applied without asking.
+    MachineApplicable,
+
+    /// The suggestion may be what the user intended, but that is not
+    /// certain, or it may not result in valid code.
+    MaybeIncorrect,
+
+    /// The applicability of the suggestion has not been determined.
+    Unspecified,
 }
 
 pub trait CodeMapper {
@@ -320,9 +337,21 @@ impl<'a> DiagnosticBuilder<'a> {
                                                msg: &str,
                                                suggestion: String)
                                                -> &mut DiagnosticBuilder<'a> {
+        self.span_suggestion_with_applicability(sp, msg, suggestion, Applicability::Unspecified)
+    }
+
+    /// Like `span_suggestion`, but also states how safe it is for tools
+    /// to apply the suggested edit without asking the user.
+    pub fn span_suggestion_with_applicability<S: Into<MultiSpan>>(&mut self,
+                                                                  sp: S,
+                                                                  msg: &str,
+                                                                  suggestion: String,
+                                                                  applicability: Applicability)
+                                                                  -> &mut DiagnosticBuilder<'a> {
         self.sub(Level::Help, msg, MultiSpan::new(), Some(Suggestion(CodeSuggestion {
             msp: sp.into(),
             substitutes: vec![suggestion],
+            applicability: applicability,
         })));
         self
     }
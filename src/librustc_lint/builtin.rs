@@ -45,7 +45,8 @@ use std::collections::HashSet;
 
 use syntax::{ast};
 use syntax::attr::{self, AttrMetaMethods, AttributeMethods};
-use syntax_pos::{self, Span};
+use syntax::errors::Applicability;
+use syntax_pos::Span;
 
 use rustc::hir::{self, PatKind};
 use rustc::hir::intravisit::FnKind;
@@ -580,13 +581,13 @@ impl Deprecated {
         }
     }
 
-    fn lint(&self, cx: &LateContext, _id: DefId, span: Span,
+    fn lint(&self, cx: &LateContext, id: DefId, span: Span,
             stability: &Option<&attr::Stability>,
             deprecation: &Option<stability::DeprecationEntry>) {
         // Deprecated attributes apply in-crate and cross-crate.
         if let Some(&attr::Stability{rustc_depr: Some(attr::RustcDeprecation{ref reason, ..}), ..})
                 = *stability {
-            output(cx, DEPRECATED, span, Some(&reason))
+            output(cx, DEPRECATED, span, Some(&reason), None)
         } else if let Some(ref depr_entry) = *deprecation {
             if let Some(parent_depr) = cx.tcx.lookup_deprecation_entry(self.parent_def(cx)) {
                 if parent_depr.same_origin(depr_entry) {
@@ -594,17 +595,48 @@ impl Deprecated {
                 }
             }
 
-            output(cx, DEPRECATED, span, depr_entry.attr.note.as_ref().map(|x| &**x))
+            let suggestion = depr_entry.attr.suggestion.as_ref().and_then(|suggestion| {
+                rename_suggestion(cx, id, span, suggestion)
+            });
+            output(cx, DEPRECATED, span, depr_entry.attr.note.as_ref().map(|x| &**x),
+                   suggestion)
         }
 
-        fn output(cx: &LateContext, lint: &'static Lint, span: Span, note: Option<&str>) {
+        fn output(cx: &LateContext, lint: &'static Lint, span: Span, note: Option<&str>,
+                  suggestion: Option<String>) {
             let msg = if let Some(note) = note {
                 format!("use of deprecated item: {}", note)
             } else {
                 format!("use of deprecated item")
             };
 
-            cx.span_lint(lint, span, &msg);
+            let mut err = cx.struct_span_lint(lint, span, &msg);
+            if let Some(suggestion) = suggestion {
+                err.span_suggestion_with_applicability(span,
+                                                       "replace the use of the deprecated item",
+                                                       suggestion,
+                                                       Applicability::MachineApplicable);
+            }
+            err.emit();
+        }
+
+        // The use is either a method name or a path ending in the item's
+        // name; anything else (e.g. a path with type parameters) is left
+        // alone.
+        fn rename_suggestion(cx: &LateContext, id: DefId, span: Span, new_name: &str)
+                             -> Option<String> {
+            let old_name = cx.tcx.item_name(id).as_str();
+            let snippet = match cx.sess().codemap().span_to_snippet(span) {
+                Ok(snippet) => snippet,
+                Err(_) => return None
+            };
+            if snippet.ends_with(&*old_name) {
+                let prefix = &snippet[..snippet.len() - old_name.len()];
+                if prefix.is_empty() || prefix.ends_with("::") {
+                    return Some(format!("{}{}", prefix, new_name));
+                }
+            }
+            None
         }
     }
 
@@ -1155,55 +1187,49 @@ impl LateLintPass for UnstableFeatures {
     }
 }
 
-/// Lints for attempts to impl Drop on types that have `#[repr(C)]`
-/// attribute (see issue #24585).
-#[derive(Copy, Clone)]
-pub struct DropWithReprExtern;
-
 declare_lint! {
-    DROP_WITH_REPR_EXTERN,
-    Warn,
-    "use of #[repr(C)] on a type that implements Drop"
+    pub UNREACHABLE_PUB,
+    Allow,
+    "`pub` items not reachable from crate root"
 }
 
-impl LintPass for DropWithReprExtern {
+/// Lints for `pub` items that cannot be named from outside the crate
+/// because one of their parent modules is private.
+#[derive(Copy, Clone)]
+pub struct UnreachablePub;
+
+impl LintPass for UnreachablePub {
     fn get_lints(&self) -> LintArray {
-        lint_array!(DROP_WITH_REPR_EXTERN)
+        lint_array!(UNREACHABLE_PUB)
     }
 }
 
-impl LateLintPass for DropWithReprExtern {
-    fn check_crate(&mut self, ctx: &LateContext, _: &hir::Crate) {
-        let drop_trait = match ctx.tcx.lang_items.drop_trait() {
-            Some(id) => ctx.tcx.lookup_trait_def(id), None => { return }
-        };
-        drop_trait.for_each_impl(ctx.tcx, |drop_impl_did| {
-            if !drop_impl_did.is_local() {
-                return;
-            }
-            let dtor_self_type = ctx.tcx.lookup_item_type(drop_impl_did).ty;
-
-            match dtor_self_type.sty {
-                ty::TyEnum(self_type_def, _) |
-                ty::TyStruct(self_type_def, _) => {
-                    let self_type_did = self_type_def.did;
-                    let hints = ctx.tcx.lookup_repr_hints(self_type_did);
-                    if hints.iter().any(|attr| *attr == attr::ReprExtern) &&
-                        self_type_def.dtor_kind().has_drop_flag() {
-                        let drop_impl_span = ctx.tcx.map.def_id_span(drop_impl_did,
-                                                                     syntax_pos::DUMMY_SP);
-                        let self_defn_span = ctx.tcx.map.def_id_span(self_type_did,
-                                                                     syntax_pos::DUMMY_SP);
-                        ctx.span_lint_note(DROP_WITH_REPR_EXTERN,
-                                           drop_impl_span,
-                                           "implementing Drop adds hidden state to types, \
-                                            possibly conflicting with `#[repr(C)]`",
-                                            self_defn_span,
-                                            "the `#[repr(C)]` attribute is attached here");
-                    }
-                }
-                _ => {}
-            }
-        })
+impl UnreachablePub {
+    fn perform_lint(&self, cx: &LateContext, what: &str, id: ast::NodeId,
+                    vis: &hir::Visibility, span: Span) {
+        if *vis == hir::Public && !cx.access_levels.is_reachable(id) {
+            let mut err = cx.struct_span_lint(UNREACHABLE_PUB, span,
+                                              &format!("unreachable `pub` {}", what));
+            err.help("consider restricting its visibility with `pub(crate)`");
+            err.emit();
+        }
+    }
+}
+
+impl LateLintPass for UnreachablePub {
+    fn check_item(&mut self, cx: &LateContext, item: &hir::Item) {
+        self.perform_lint(cx, "item", item.id, &item.vis, item.span);
+    }
+
+    fn check_foreign_item(&mut self, cx: &LateContext, foreign_item: &hir::ForeignItem) {
+        self.perform_lint(cx, "item", foreign_item.id, &foreign_item.vis, foreign_item.span);
+    }
+
+    fn check_struct_field(&mut self, cx: &LateContext, field: &hir::StructField) {
+        self.perform_lint(cx, "field", field.id, &field.vis, field.span);
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext, impl_item: &hir::ImplItem) {
+        self.perform_lint(cx, "item", impl_item.id, &impl_item.vis, impl_item.span);
     }
 }
@@ -727,13 +727,24 @@ impl LateLintPass for VariantSizeDifferences {
                                 }
                         );
 
-                    // we only warn if the largest variant is at least thrice as large as
-                    // the second-largest.
-                    if largest > slargest * 3 && slargest > 0 {
-                        cx.span_lint(VARIANT_SIZE_DIFFERENCES,
-                                     enum_definition.variants[largest_index].span,
-                                     &format!("enum variant is more than three times larger \
-                                               ({} bytes) than the next largest", largest));
+                    // we only warn if the largest variant is at least `ratio` times
+                    // (thrice, by default) as large as the second-largest.
+                    let ratio = cx.sess().opts.debugging_opts.variant_size_ratio.unwrap_or(3);
+                    if largest > slargest * ratio as u64 && slargest > 0 {
+                        let variant = &enum_definition.variants[largest_index];
+                        let bytes = |n: u64| {
+                            format!("{} {}", n, if n == 1 { "byte" } else { "bytes" })
+                        };
+                        let mut err = cx.struct_span_lint(
+                            VARIANT_SIZE_DIFFERENCES,
+                            variant.span,
+                            &format!("enum variant is more than {} times larger ({}) \
+                                      than the next largest ({})",
+                                     ratio, bytes(largest), bytes(slargest)));
+                        err.help(&format!("consider boxing the large fields of `{}` to reduce \
+                                           the total size of `{}` ({})",
+                                          variant.node.name, t, bytes(size.bytes())));
+                        err.emit();
                     }
                 }
             }
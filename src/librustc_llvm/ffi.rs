@@ -428,6 +428,12 @@ pub type RustArchiveMemberRef = *mut RustArchiveMember_opaque;
 #[allow(missing_copy_implementations)]
 pub enum OperandBundleDef_opaque {}
 pub type OperandBundleDefRef = *mut OperandBundleDef_opaque;
+#[allow(missing_copy_implementations)]
+pub enum ThinLTOBuffer_opaque {}
+pub type ThinLTOBufferRef = *mut ThinLTOBuffer_opaque;
+#[allow(missing_copy_implementations)]
+pub enum ThinLTOCodeGenerator_opaque {}
+pub type ThinLTOCodeGeneratorRef = *mut ThinLTOCodeGenerator_opaque;
 
 pub type DiagnosticHandler = unsafe extern "C" fn(DiagnosticInfoRef, *mut c_void);
 pub type InlineAsmDiagHandler = unsafe extern "C" fn(SMDiagnosticRef, *const c_void, c_uint);
@@ -1964,7 +1970,9 @@ extern {
                                                OptLevel: CodeGenOptLevel,
                                                MergeFunctions: bool,
                                                SLPVectorize: bool,
-                                               LoopVectorize: bool);
+                                               LoopVectorize: bool,
+                                               PGOGenPath: *const c_char,
+                                               PGOUsePath: *const c_char);
     pub fn LLVMRustAddLibraryInfo(PM: PassManagerRef, M: ModuleRef,
                                   DisableSimplifyLibCalls: bool);
     pub fn LLVMRustRunFunctionPassManager(PM: PassManagerRef, M: ModuleRef);
@@ -1990,6 +1998,31 @@ extern {
                                       len: size_t);
     pub fn LLVMRustMarkAllFunctionsNounwind(M: ModuleRef);
 
+    pub fn LLVMRustThinLTOAvailable() -> bool;
+    pub fn LLVMRustThinLTOBufferCreate(M: ModuleRef) -> ThinLTOBufferRef;
+    pub fn LLVMRustThinLTOBufferFree(B: ThinLTOBufferRef);
+    pub fn LLVMRustThinLTOBufferPtr(B: ThinLTOBufferRef) -> *const c_char;
+    pub fn LLVMRustThinLTOBufferLen(B: ThinLTOBufferRef) -> size_t;
+    pub fn LLVMRustParseBitcodeForThinLTO(C: ContextRef,
+                                          data: *const c_char,
+                                          len: size_t,
+                                          identifier: *const c_char)
+                                          -> ModuleRef;
+    pub fn LLVMRustThinLTOCodeGeneratorCreate(TM: TargetMachineRef)
+                                              -> ThinLTOCodeGeneratorRef;
+    pub fn LLVMRustThinLTOCodeGeneratorFree(CG: ThinLTOCodeGeneratorRef);
+    pub fn LLVMRustThinLTOAddModule(CG: ThinLTOCodeGeneratorRef,
+                                    identifier: *const c_char,
+                                    data: *const c_char,
+                                    len: size_t);
+    pub fn LLVMRustThinLTOPreserveSymbol(CG: ThinLTOCodeGeneratorRef,
+                                         name: *const c_char);
+    pub fn LLVMRustThinLTORun(CG: ThinLTOCodeGeneratorRef);
+    pub fn LLVMRustThinLTOGetObject(CG: ThinLTOCodeGeneratorRef,
+                                    idx: size_t,
+                                    len: *mut size_t)
+                                    -> *const c_char;
+
     pub fn LLVMRustOpenArchive(path: *const c_char) -> ArchiveRef;
     pub fn LLVMRustArchiveIteratorNew(AR: ArchiveRef) -> ArchiveIteratorRef;
     pub fn LLVMRustArchiveIteratorNext(AIR: ArchiveIteratorRef) -> ArchiveChildRef;
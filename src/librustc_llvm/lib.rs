@@ -120,15 +120,15 @@ impl Attributes {
 pub fn AddFunctionAttrStringValue(
     llfn: ValueRef,
     idx: AttributePlace,
-    attr: &'static str,
-    value: &'static str
+    attr: &CStr,
+    value: &CStr
 ) {
     unsafe {
         LLVMRustAddFunctionAttrStringValue(
             llfn,
             idx.as_uint(),
-            attr.as_ptr() as *const _,
-            value.as_ptr() as *const _)
+            attr.as_ptr(),
+            value.as_ptr())
     }
 }
 
@@ -428,6 +428,11 @@ pub fn initialize_available_targets() {
                  LLVMInitializePNaClTargetInfo,
                  LLVMInitializePNaClTarget,
                  LLVMInitializePNaClTargetMC);
+    init_target!(llvm_component = "webassembly",
+                 LLVMInitializeWebAssemblyTargetInfo,
+                 LLVMInitializeWebAssemblyTarget,
+                 LLVMInitializeWebAssemblyTargetMC,
+                 LLVMInitializeWebAssemblyAsmPrinter);
 }
 
 pub fn last_error() -> Option<String> {
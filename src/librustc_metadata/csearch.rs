@@ -27,7 +27,7 @@ use rustc::hir::map::DefKey;
 use rustc::mir::repr::Mir;
 use rustc::mir::mir_map::MirMap;
 use rustc::util::nodemap::{FnvHashMap, NodeSet, DefIdMap};
-use rustc::session::config::PanicStrategy;
+use rustc::session::config::{PanicStrategy, Sanitizer};
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -309,6 +309,12 @@ impl<'tcx> CrateStore<'tcx> for cstore::CStore {
         decoder::is_typedef(&cdata, did.index)
     }
 
+    fn is_static_mut(&self, did: DefId) -> bool {
+        self.dep_graph.read(DepNode::MetaData(did));
+        let cdata = self.get_crate_data(did.krate);
+        decoder::is_static_mut(&cdata, did.index)
+    }
+
     fn dylib_dependency_formats(&self, cnum: ast::CrateNum)
                                 -> Vec<(ast::CrateNum, LinkagePreference)>
     {
@@ -357,6 +363,10 @@ impl<'tcx> CrateStore<'tcx> for cstore::CStore {
         self.get_crate_data(cnum).panic_strategy()
     }
 
+    fn sanitizer(&self, cnum: ast::CrateNum) -> Option<Sanitizer> {
+        self.get_crate_data(cnum).sanitizer()
+    }
+
     fn crate_attrs(&self, cnum: ast::CrateNum) -> Vec<ast::Attribute>
     {
         decoder::get_crate_attributes(self.get_crate_data(cnum).data())
@@ -26,7 +26,7 @@ use rustc::hir::def_id::{DefIndex, DefId};
 use rustc::hir::map::DefKey;
 use rustc::hir::svh::Svh;
 use rustc::middle::cstore::ExternCrate;
-use rustc::session::config::PanicStrategy;
+use rustc::session::config::{PanicStrategy, Sanitizer};
 use rustc_data_structures::indexed_vec::IndexVec;
 use rustc::util::nodemap::{FnvHashMap, NodeMap, NodeSet, DefIdMap};
 
@@ -333,6 +333,10 @@ impl CrateMetadata {
     pub fn panic_strategy(&self) -> PanicStrategy {
         decoder::get_panic_strategy(self.data())
     }
+
+    pub fn sanitizer(&self) -> Option<Sanitizer> {
+        decoder::get_sanitizer(self.data())
+    }
 }
 
 impl MetadataBlob {
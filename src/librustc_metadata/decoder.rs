@@ -28,7 +28,7 @@ use rustc::hir::map as hir_map;
 use rustc::hir::map::DefKey;
 use rustc::util::nodemap::FnvHashMap;
 use rustc::hir;
-use rustc::session::config::PanicStrategy;
+use rustc::session::config::{PanicStrategy, Sanitizer};
 
 use middle::cstore::{InlinedItem, LinkagePreference};
 use middle::cstore::{DefLike, DlDef, DlField, DlImpl, tls};
@@ -1510,6 +1510,14 @@ pub fn is_typedef(cdata: Cmd, id: DefIndex) -> bool {
     }
 }
 
+pub fn is_static_mut(cdata: Cmd, id: DefIndex) -> bool {
+    let item_doc = cdata.lookup_item(id);
+    match item_family(item_doc) {
+        MutStatic => true,
+        _ => false,
+    }
+}
+
 pub fn is_const_fn(cdata: Cmd, id: DefIndex) -> bool {
     let item_doc = cdata.lookup_item(id);
     match fn_constness(item_doc) {
@@ -1675,3 +1683,16 @@ pub fn get_panic_strategy(data: &[u8]) -> PanicStrategy {
         b => panic!("unknown panic strategy in metadata: {}", b),
     }
 }
+
+pub fn get_sanitizer(data: &[u8]) -> Option<Sanitizer> {
+    let crate_doc = rbml::Doc::new(data);
+    let sanitizer_doc = reader::get_doc(crate_doc, tag_sanitizer);
+    match reader::doc_as_u8(sanitizer_doc) {
+        b'N' => None,
+        b'A' => Some(Sanitizer::Address),
+        b'L' => Some(Sanitizer::Leak),
+        b'M' => Some(Sanitizer::Memory),
+        b'T' => Some(Sanitizer::Thread),
+        b => panic!("unknown sanitizer in metadata: {}", b),
+    }
+}
@@ -32,7 +32,7 @@ use rustc::ty::util::IntTypeExt;
 
 use rustc::hir::svh::Svh;
 use rustc::mir::mir_map::MirMap;
-use rustc::session::config::{self, PanicStrategy};
+use rustc::session::config::{self, PanicStrategy, Sanitizer};
 use rustc::util::nodemap::{FnvHashMap, NodeSet};
 
 use rustc_serialize::Encodable;
@@ -1770,7 +1770,7 @@ fn encode_dylib_dependency_formats(rbml_w: &mut Encoder, ecx: &EncodeContext) {
 }
 
 fn encode_panic_strategy(rbml_w: &mut Encoder, ecx: &EncodeContext) {
-    match ecx.tcx.sess.opts.cg.panic {
+    match ecx.tcx.sess.panic_strategy() {
         PanicStrategy::Unwind => {
             rbml_w.wr_tagged_u8(tag_panic_strategy, b'U');
         }
@@ -1780,6 +1780,17 @@ fn encode_panic_strategy(rbml_w: &mut Encoder, ecx: &EncodeContext) {
     }
 }
 
+fn encode_sanitizer(rbml_w: &mut Encoder, ecx: &EncodeContext) {
+    let b = match ecx.tcx.sess.opts.debugging_opts.sanitizer {
+        None => b'N',
+        Some(Sanitizer::Address) => b'A',
+        Some(Sanitizer::Leak) => b'L',
+        Some(Sanitizer::Memory) => b'M',
+        Some(Sanitizer::Thread) => b'T',
+    };
+    rbml_w.wr_tagged_u8(tag_sanitizer, b);
+}
+
 pub fn encode_metadata(ecx: EncodeContext, krate: &hir::Crate) -> Vec<u8> {
     let mut wr = Cursor::new(Vec::new());
 
@@ -1877,6 +1888,7 @@ fn encode_metadata_inner(rbml_w: &mut Encoder,
     encode_crate_disambiguator(rbml_w, &ecx.tcx.sess.local_crate_disambiguator());
     encode_dylib_dependency_formats(rbml_w, &ecx);
     encode_panic_strategy(rbml_w, &ecx);
+    encode_sanitizer(rbml_w, &ecx);
 
     let mut i = rbml_w.writer.seek(SeekFrom::Current(0)).unwrap();
     encode_attributes(rbml_w, &krate.attrs);
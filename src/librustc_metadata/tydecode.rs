@@ -115,6 +115,14 @@ impl<'a,'tcx> TyDecoder<'a,'tcx> {
         token::intern(str::from_utf8(bytes).unwrap())
     }
 
+    fn parse_ty_param_name(&mut self) -> ast::Name {
+        let len = self.parse_uint();
+        assert_eq!(self.next(), '#');
+        let bytes = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        token::intern(str::from_utf8(bytes).unwrap())
+    }
+
     fn parse_size(&mut self) -> Option<usize> {
         assert_eq!(self.next(), '/');
 
@@ -397,7 +405,8 @@ impl<'a,'tcx> TyDecoder<'a,'tcx> {
                 assert_eq!(self.next(), '[');
                 let index = self.parse_u32();
                 assert_eq!(self.next(), '|');
-                let name = token::intern(&self.parse_str(']'));
+                let name = self.parse_ty_param_name();
+                assert_eq!(self.next(), ']');
                 return tcx.mk_param(index, name);
             }
             '~' => return tcx.mk_box(self.parse_ty()),
@@ -639,11 +648,18 @@ impl<'a,'tcx> TyDecoder<'a,'tcx> {
     }
 
     fn parse_type_param_def(&mut self) -> ty::TypeParameterDef<'tcx> {
-        let name = self.parse_name(':');
+        let name = self.parse_ty_param_name();
+        assert_eq!(self.next(), ':');
         let def_id = self.parse_def();
         let index = self.parse_u32();
         assert_eq!(self.next(), '|');
         let default_def_id = self.parse_def();
+        let synthetic = match self.next() {
+            'S' => true,
+            'N' => false,
+            c => bug!("parse_type_param_def: bad synthetic marker: {}", c)
+        };
+        assert_eq!(self.next(), '|');
         let default = self.parse_opt(|this| this.parse_ty());
         let object_lifetime_default = self.parse_object_lifetime_default();
 
@@ -654,6 +670,7 @@ impl<'a,'tcx> TyDecoder<'a,'tcx> {
             default_def_id: default_def_id,
             default: default,
             object_lifetime_default: object_lifetime_default,
+            synthetic: synthetic,
         }
     }
 
@@ -163,7 +163,9 @@ pub fn enc_ty<'a, 'tcx>(w: &mut Cursor<Vec<u8>>, cx: &ctxt<'a, 'tcx>, t: Ty<'tcx
             bug!("cannot encode inference variable types");
         }
         ty::TyParam(p) => {
-            write!(w, "p[{}|{}]", p.idx, p.name);
+            write!(w, "p[{}|", p.idx);
+            enc_ty_param_name(w, p.name);
+            write!(w, "]");
         }
         ty::TyStruct(def, substs) => {
             write!(w, "a[{}|", (cx.ds)(cx.tcx, def.did));
@@ -432,11 +434,21 @@ fn enc_builtin_bounds(w: &mut Cursor<Vec<u8>>, _cx: &ctxt, bs: &ty::BuiltinBound
     write!(w, ".");
 }
 
+// The names of type parameters introduced by `impl Trait` are the printed
+// bounds, so they may contain any character and are prefixed with their
+// length instead of being terminated.
+fn enc_ty_param_name(w: &mut Cursor<Vec<u8>>, name: ast::Name) {
+    let name = name.as_str();
+    write!(w, "{}#{}", name.len(), name);
+}
+
 fn enc_type_param_def<'a, 'tcx>(w: &mut Cursor<Vec<u8>>, cx: &ctxt<'a, 'tcx>,
                                 v: &ty::TypeParameterDef<'tcx>) {
-    write!(w, "{}:{}|{}|{}|",
-           v.name, (cx.ds)(cx.tcx, v.def_id),
-           v.index, (cx.ds)(cx.tcx, v.default_def_id));
+    enc_ty_param_name(w, v.name);
+    write!(w, ":{}|{}|{}|{}|",
+           (cx.ds)(cx.tcx, v.def_id),
+           v.index, (cx.ds)(cx.tcx, v.default_def_id),
+           if v.synthetic { 'S' } else { 'N' });
     enc_opt(w, v.default, |w, t| enc_ty(w, cx, t));
     enc_object_lifetime_default(w, cx, v.object_lifetime_default);
 }
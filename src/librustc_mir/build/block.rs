@@ -12,6 +12,7 @@ use build::{BlockAnd, BlockAndExtension, Builder};
 use hair::*;
 use rustc::mir::repr::*;
 use rustc::hir;
+use syntax_pos::Span;
 
 impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
     pub fn ast_block(&mut self,
@@ -21,7 +22,7 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
                      mut block: BasicBlock,
                      ast_block: &'tcx hir::Block)
                      -> BlockAnd<()> {
-        let Block { extent, span, stmts, expr } = self.hir.mirror(ast_block);
+        let Block { extent, span, stmts, expr, safety_mode } = self.hir.mirror(ast_block);
         self.in_scope(extent, block, move |this| {
             // This convoluted structure is to avoid using recursion as we walk down a list
             // of statements. Basically, the structure we get back is something like:
@@ -41,6 +42,10 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
             // First we build all the statements in the block.
             let mut let_extent_stack = Vec::with_capacity(8);
             let outer_visibility_scope = this.visibility_scope;
+            let outer_push_unsafe_count = this.push_unsafe_count;
+            let outer_unpushed_unsafe = this.unpushed_unsafe;
+            this.update_visibility_scope_for_safety_mode(span, safety_mode);
+
             for stmt in stmts {
                 let Stmt { span: _, kind } = this.hir.mirror(stmt);
                 match kind {
@@ -95,7 +100,60 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
             }
             // Restore the original visibility scope.
             this.visibility_scope = outer_visibility_scope;
+            this.push_unsafe_count = outer_push_unsafe_count;
+            this.unpushed_unsafe = outer_unpushed_unsafe;
             block.unit()
         })
     }
+
+    /// If we are changing the safety mode, create a new visibility scope.
+    fn update_visibility_scope_for_safety_mode(&mut self,
+                                               span: Span,
+                                               safety_mode: BlockSafety) {
+        debug!("update_visibility_scope_for({:?}, {:?})", span, safety_mode);
+        let new_unsafety = match safety_mode {
+            BlockSafety::Safe => None,
+            BlockSafety::ExplicitUnsafe(node_id) => {
+                // Only the outermost `unsafe` block is used; any
+                // nested ones are reported as unnecessary.
+                if self.unpushed_unsafe != Safety::Safe {
+                    return;
+                }
+                self.unpushed_unsafe = Safety::ExplicitUnsafe(node_id);
+                self.unpushed_unsafe_scope()
+            }
+            BlockSafety::BuiltinUnsafe => {
+                // Unsafe code generated by the compiler should not
+                // count as a use of an enclosing user `unsafe` block.
+                self.unpushed_unsafe = Safety::BuiltinUnsafe;
+                self.unpushed_unsafe_scope()
+            }
+            BlockSafety::PushUnsafe => {
+                self.push_unsafe_count += 1;
+                Some(Safety::BuiltinUnsafe)
+            }
+            BlockSafety::PopUnsafe => {
+                self.push_unsafe_count =
+                    self.push_unsafe_count.checked_sub(1).unwrap_or_else(|| {
+                        span_bug!(span, "unsafe count underflow")
+                    });
+                self.unpushed_unsafe_scope()
+            }
+        };
+
+        if let Some(unsafety) = new_unsafety {
+            let scope = self.new_visibility_scope(span);
+            self.visibility_scopes[scope].safety = unsafety;
+            self.visibility_scope = scope;
+        }
+    }
+
+    /// The safety outside of `push_unsafe!` blocks, if it is in effect here.
+    fn unpushed_unsafe_scope(&self) -> Option<Safety> {
+        if self.push_unsafe_count == 0 {
+            Some(self.unpushed_unsafe)
+        } else {
+            None
+        }
+    }
 }
@@ -49,6 +49,11 @@ pub struct Builder<'a, 'gcx: 'a+'tcx, 'tcx: 'a> {
     visibility_scopes: IndexVec<VisibilityScope, VisibilityScopeData>,
     visibility_scope: VisibilityScope,
 
+    /// the number of enclosing `push_unsafe!` blocks, and the safety
+    /// of the code outside of them; see `update_visibility_scope_for_safety_mode`
+    push_unsafe_count: usize,
+    unpushed_unsafe: Safety,
+
     var_decls: IndexVec<Var, VarDecl<'tcx>>,
     var_indices: NodeMap<Var>,
     temp_decls: IndexVec<Temp, TempDecl<'tcx>>,
@@ -169,7 +174,13 @@ pub fn construct_fn<'a, 'gcx, 'tcx, A>(hir: Cx<'a, 'gcx, 'tcx>,
 {
     let tcx = hir.tcx();
     let span = tcx.map.span(fn_id);
-    let mut builder = Builder::new(hir, span);
+    // Closures are checked as part of the function that creates them,
+    // so they start out safe even inside an `unsafe` block or fn.
+    let safety = match tcx.node_id_to_type(fn_id).sty {
+        ty::TyFnDef(_, _, f) if f.unsafety == hir::Unsafety::Unsafe => Safety::FnUnsafe,
+        _ => Safety::Safe
+    };
+    let mut builder = Builder::new(hir, span, safety);
 
     let body_id = ast_block.id;
     let call_site_extent =
@@ -236,7 +247,7 @@ pub fn construct_const<'a, 'gcx, 'tcx>(hir: Cx<'a, 'gcx, 'tcx>,
                                        -> (Mir<'tcx>, ScopeAuxiliaryVec) {
     let tcx = hir.tcx();
     let span = tcx.map.span(item_id);
-    let mut builder = Builder::new(hir, span);
+    let mut builder = Builder::new(hir, span, Safety::Safe);
 
     let extent = tcx.region_maps.temporary_scope(ast_expr.id)
                     .unwrap_or(ROOT_CODE_EXTENT);
@@ -260,7 +271,7 @@ pub fn construct_const<'a, 'gcx, 'tcx>(hir: Cx<'a, 'gcx, 'tcx>,
 }
 
 impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
-    fn new(hir: Cx<'a, 'gcx, 'tcx>, span: Span) -> Builder<'a, 'gcx, 'tcx> {
+    fn new(hir: Cx<'a, 'gcx, 'tcx>, span: Span, safety: Safety) -> Builder<'a, 'gcx, 'tcx> {
         let mut builder = Builder {
             hir: hir,
             cfg: CFG { basic_blocks: IndexVec::new() },
@@ -268,6 +279,8 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
             scopes: vec![],
             visibility_scopes: IndexVec::new(),
             visibility_scope: ARGUMENT_VISIBILITY_SCOPE,
+            push_unsafe_count: 0,
+            unpushed_unsafe: safety,
             scope_auxiliary: IndexVec::new(),
             loop_scopes: vec![],
             temp_decls: IndexVec::new(),
@@ -279,8 +292,11 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
         };
 
         assert_eq!(builder.cfg.start_new_block(), START_BLOCK);
-        assert_eq!(builder.new_visibility_scope(span), ARGUMENT_VISIBILITY_SCOPE);
-        builder.visibility_scopes[ARGUMENT_VISIBILITY_SCOPE].parent_scope = None;
+        assert_eq!(builder.visibility_scopes.push(VisibilityScopeData {
+            span: span,
+            parent_scope: None,
+            safety: safety
+        }), ARGUMENT_VISIBILITY_SCOPE);
 
         builder
     }
@@ -378,9 +378,11 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
     pub fn new_visibility_scope(&mut self, span: Span) -> VisibilityScope {
         let parent = self.visibility_scope;
         let scope = VisibilityScope::new(self.visibility_scopes.len());
+        let safety = self.visibility_scopes[parent].safety;
         self.visibility_scopes.push(VisibilityScopeData {
             span: span,
             parent_scope: Some(parent),
+            safety: safety,
         });
         scope
     }
@@ -0,0 +1,640 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! An evaluator for constants that interprets their MIR.
+//!
+//! `rustc_const_eval` works on the HIR and has to reimplement the language's
+//! semantics, which it does not always get right. Interpreting the MIR
+//! instead means constants see exactly the operations runtime code would
+//! execute, including the overflow and bounds checks.
+//!
+//! Only scalars, and aggregates built out of them, are supported; anything
+//! that needs memory (references, statics, boxes) is reported as
+//! unimplemented, as are calls to functions whose MIR is not in the map.
+
+use rustc::middle::const_val::ConstVal;
+use rustc::hir::def_id::DefId;
+use rustc::mir::mir_map::MirMap;
+use rustc::mir::repr::*;
+use rustc::ty::{self, Ty, TyCtxt};
+use rustc_const_eval::{ConstEvalErr, ErrKind};
+use rustc_const_math::{ConstInt, ConstIsize, ConstMathErr, ConstUsize};
+use rustc_data_structures::indexed_vec::Idx;
+use syntax::ast;
+use syntax_pos::Span;
+
+use std::cmp::Ordering;
+
+/// The number of statements and terminators to execute before giving up.
+const STEP_LIMIT: usize = 1_000_000;
+
+/// The deepest chain of nested calls and constant references allowed.
+const DEPTH_LIMIT: usize = 64;
+
+/// The longest array a constant may build.
+const ARRAY_LIMIT: u64 = 1 << 16;
+
+pub type EvalResult<T> = Result<T, ConstEvalErr>;
+
+/// Evaluates the constant `def_id`, whose MIR has to be in `map`.
+pub fn eval_const<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                            map: &'a MirMap<'tcx>,
+                            def_id: DefId)
+                            -> EvalResult<ConstVal> {
+    let mir = match map.map.get(&def_id) {
+        Some(mir) => mir,
+        None => bug!("eval_const: no MIR for {:?}", def_id)
+    };
+    let mut evaluator = Evaluator {
+        tcx: tcx,
+        map: map,
+        steps: 0,
+        depth: 0,
+    };
+    match evaluator.run(mir, vec![], mir.span)? {
+        Value::Scalar(value) => Ok(value),
+        Value::Undef |
+        Value::Aggregate { .. } => {
+            Err(unimplemented(mir.span, "constants of non-scalar type"))
+        }
+    }
+}
+
+/// Whether evaluation failed because the constant would fail at runtime,
+/// as opposed to it using something the evaluator does not support.
+pub fn is_runtime_error(err: &ConstEvalErr) -> bool {
+    match err.kind {
+        ErrKind::Math(_) |
+        ErrKind::IndexOutOfBounds { .. } => true,
+        _ => false
+    }
+}
+
+/// The value of a local or of a part of it.
+#[derive(Clone, Debug)]
+enum Value {
+    Undef,
+    Scalar(ConstVal),
+    /// Tuples, structs, enum variants and arrays. Fields that have not been
+    /// written yet may be missing.
+    Aggregate { variant: usize, fields: Vec<Value> },
+}
+
+/// One step of a projection out of a local, with indices evaluated.
+#[derive(Copy, Clone, Debug)]
+enum Step {
+    Field(usize),
+    Downcast(usize),
+    Index(usize),
+    IndexFromEnd(usize),
+}
+
+struct Frame<'a, 'tcx: 'a> {
+    mir: &'a Mir<'tcx>,
+    locals: Vec<Value>,
+}
+
+struct Evaluator<'a, 'tcx: 'a> {
+    tcx: TyCtxt<'a, 'tcx, 'tcx>,
+    map: &'a MirMap<'tcx>,
+    steps: usize,
+    depth: usize,
+}
+
+fn unimplemented(span: Span, what: &'static str) -> ConstEvalErr {
+    ConstEvalErr { span: span, kind: ErrKind::UnimplementedConstVal(what) }
+}
+
+fn math_err(span: Span, err: ConstMathErr) -> ConstEvalErr {
+    ConstEvalErr { span: span, kind: ErrKind::Math(err) }
+}
+
+impl<'a, 'tcx> Evaluator<'a, 'tcx> {
+    /// Runs `mir` with the given arguments and returns its return value.
+    fn run(&mut self, mir: &'a Mir<'tcx>, args: Vec<Value>, span: Span) -> EvalResult<Value> {
+        if self.depth == DEPTH_LIMIT {
+            return Err(unimplemented(span, "deeply nested constant evaluation"));
+        }
+        self.depth += 1;
+        let result = self.run_frame(mir, args);
+        self.depth -= 1;
+        result
+    }
+
+    fn run_frame(&mut self, mir: &'a Mir<'tcx>, args: Vec<Value>) -> EvalResult<Value> {
+        let mut frame = Frame {
+            mir: mir,
+            locals: vec![Value::Undef; mir.count_locals()],
+        };
+        for (index, arg) in args.into_iter().enumerate() {
+            let local = mir.local_index(&Lvalue::Arg(Arg::new(index))).unwrap();
+            frame.locals[local.index()] = arg;
+        }
+
+        let mut bb = START_BLOCK;
+        loop {
+            let data = &mir[bb];
+            for statement in &data.statements {
+                self.step(statement.source_info.span)?;
+                self.statement(&mut frame, statement)?;
+            }
+            let terminator = data.terminator();
+            self.step(terminator.source_info.span)?;
+            bb = match self.terminator(&mut frame, terminator)? {
+                Some(target) => target,
+                None => {
+                    let local = mir.local_index(&Lvalue::ReturnPointer).unwrap();
+                    return Ok(frame.locals[local.index()].clone());
+                }
+            };
+        }
+    }
+
+    fn step(&mut self, span: Span) -> EvalResult<()> {
+        self.steps += 1;
+        if self.steps > STEP_LIMIT {
+            return Err(unimplemented(span, "constants that take this long to evaluate"));
+        }
+        Ok(())
+    }
+
+    fn statement(&mut self, frame: &mut Frame<'a, 'tcx>, statement: &Statement<'tcx>)
+                 -> EvalResult<()> {
+        let span = statement.source_info.span;
+        match statement.kind {
+            StatementKind::Assign(ref lvalue, ref rvalue) => {
+                let value = self.rvalue(frame, rvalue, span)?;
+                self.store(frame, lvalue, value, span)
+            }
+            StatementKind::SetDiscriminant { ref lvalue, variant_index } => {
+                let place = self.place(frame, lvalue, span)?;
+                if let Value::Aggregate { ref mut variant, .. } = *place {
+                    *variant = variant_index;
+                    return Ok(());
+                }
+                *place = Value::Aggregate { variant: variant_index, fields: vec![] };
+                Ok(())
+            }
+            StatementKind::StorageLive(ref lvalue) |
+            StatementKind::StorageDead(ref lvalue) => {
+                self.store(frame, lvalue, Value::Undef, span)
+            }
+        }
+    }
+
+    /// Executes a terminator, returning the next block to run or `None` if
+    /// the function returned.
+    fn terminator(&mut self, frame: &mut Frame<'a, 'tcx>, terminator: &Terminator<'tcx>)
+                  -> EvalResult<Option<BasicBlock>> {
+        let span = terminator.source_info.span;
+        match terminator.kind {
+            TerminatorKind::Goto { target } |
+            TerminatorKind::Drop { target, .. } => Ok(Some(target)),
+            TerminatorKind::If { ref cond, targets: (on_true, on_false) } => {
+                match self.scalar(frame, cond, span)? {
+                    ConstVal::Bool(true) => Ok(Some(on_true)),
+                    ConstVal::Bool(false) => Ok(Some(on_false)),
+                    _ => bug!("const_eval: branch on non-bool at {:?}", span)
+                }
+            }
+            TerminatorKind::Switch { ref discr, ref targets, .. } => {
+                match self.load(frame, discr, span)? {
+                    Value::Aggregate { variant, .. } if variant < targets.len() => {
+                        Ok(Some(targets[variant]))
+                    }
+                    _ => Err(unimplemented(span, "switching on an unknown variant"))
+                }
+            }
+            TerminatorKind::SwitchInt { ref discr, ref values, ref targets, .. } => {
+                let value = match self.load(frame, discr, span)? {
+                    Value::Scalar(value) => value,
+                    _ => return Err(unimplemented(span, "switching on an unknown value"))
+                };
+                let index = values.iter().position(|v| same_value(v, &value));
+                Ok(Some(targets[index.unwrap_or(values.len())]))
+            }
+            TerminatorKind::Return => Ok(None),
+            TerminatorKind::Resume |
+            TerminatorKind::Unreachable => {
+                Err(unimplemented(span, "unwinding or unreachable code"))
+            }
+            TerminatorKind::DropAndReplace { ref location, ref value, target, .. } => {
+                let value = self.operand(frame, value, span)?;
+                self.store(frame, location, value, span)?;
+                Ok(Some(target))
+            }
+            TerminatorKind::Call { ref func, ref args, ref destination, .. } => {
+                let callee = match *func {
+                    Operand::Constant(Constant {
+                        literal: Literal::Item { def_id, substs }, ty, ..
+                    }) => {
+                        match ty.sty {
+                            ty::TyFnDef(..) if substs.types.is_empty() => {
+                                let map = self.map;
+                                map.map.get(&def_id)
+                            }
+                            _ => None
+                        }
+                    }
+                    _ => None
+                };
+                let callee = match callee {
+                    Some(callee) => callee,
+                    None => return Err(unimplemented(span, "calls to this function"))
+                };
+                let mut arg_values = Vec::with_capacity(args.len());
+                for arg in args {
+                    arg_values.push(self.operand(frame, arg, span)?);
+                }
+                let result = self.run(callee, arg_values, span)?;
+                match *destination {
+                    Some((ref lvalue, target)) => {
+                        self.store(frame, lvalue, result, span)?;
+                        Ok(Some(target))
+                    }
+                    None => Err(unimplemented(span, "diverging calls"))
+                }
+            }
+            TerminatorKind::Assert { ref cond, expected, ref msg, target, .. } => {
+                match self.scalar(frame, cond, span)? {
+                    ConstVal::Bool(b) if b == expected => return Ok(Some(target)),
+                    ConstVal::Bool(_) => {}
+                    _ => bug!("const_eval: assert on non-bool at {:?}", span)
+                }
+                let kind = match *msg {
+                    AssertMessage::BoundsCheck { ref len, ref index } => {
+                        let len = self.scalar(frame, len, span)?;
+                        let index = self.scalar(frame, index, span)?;
+                        match (len, index) {
+                            (ConstVal::Integral(len), ConstVal::Integral(index)) => {
+                                ErrKind::IndexOutOfBounds {
+                                    len: len.to_u64_unchecked(),
+                                    index: index.to_u64_unchecked(),
+                                }
+                            }
+                            _ => bug!("const_eval: non-integral bounds check at {:?}", span)
+                        }
+                    }
+                    AssertMessage::Math(ref err) => ErrKind::Math(err.clone())
+                };
+                Err(ConstEvalErr { span: span, kind: kind })
+            }
+        }
+    }
+
+    fn rvalue(&mut self, frame: &Frame<'a, 'tcx>, rvalue: &Rvalue<'tcx>, span: Span)
+              -> EvalResult<Value> {
+        match *rvalue {
+            Rvalue::Use(ref operand) => self.operand(frame, operand, span),
+            Rvalue::Repeat(ref operand, ref count) => {
+                let count = count.value.as_u64(self.tcx.sess.target.uint_type);
+                if count > ARRAY_LIMIT {
+                    return Err(unimplemented(span, "large arrays"));
+                }
+                let value = self.operand(frame, operand, span)?;
+                Ok(Value::Aggregate { variant: 0, fields: vec![value; count as usize] })
+            }
+            Rvalue::Len(ref lvalue) => {
+                match self.load(frame, lvalue, span)? {
+                    Value::Aggregate { ref fields, .. } => {
+                        Ok(Value::Scalar(self.usize(fields.len())))
+                    }
+                    _ => Err(unimplemented(span, "the length of this array"))
+                }
+            }
+            Rvalue::Cast(CastKind::Misc, ref operand, ty) => {
+                let value = self.scalar(frame, operand, span)?;
+                match cast(self.tcx, value, ty) {
+                    Some(value) => Ok(Value::Scalar(value)),
+                    None => Err(unimplemented(span, "this cast"))
+                }
+            }
+            Rvalue::BinaryOp(op, ref lhs, ref rhs) => {
+                let lhs = self.scalar(frame, lhs, span)?;
+                let rhs = self.scalar(frame, rhs, span)?;
+                match binop(op, lhs, rhs) {
+                    Some(Ok(value)) => Ok(Value::Scalar(value)),
+                    Some(Err(err)) => Err(math_err(span, err)),
+                    None => Err(unimplemented(span, "this binary operation"))
+                }
+            }
+            Rvalue::CheckedBinaryOp(op, ref lhs, ref rhs) => {
+                let lhs = self.scalar(frame, lhs, span)?;
+                let rhs = self.scalar(frame, rhs, span)?;
+                let (value, overflowed) = match binop(op, lhs, rhs) {
+                    Some(Ok(value)) => (Value::Scalar(value), false),
+                    // The assert following the operation reports the overflow.
+                    Some(Err(ConstMathErr::Overflow(_))) |
+                    Some(Err(ConstMathErr::ShiftNegative)) => (Value::Undef, true),
+                    Some(Err(err)) => return Err(math_err(span, err)),
+                    None => return Err(unimplemented(span, "this binary operation"))
+                };
+                Ok(Value::Aggregate {
+                    variant: 0,
+                    fields: vec![value, Value::Scalar(ConstVal::Bool(overflowed))],
+                })
+            }
+            Rvalue::UnaryOp(op, ref operand) => {
+                let value = match (op, self.scalar(frame, operand, span)?) {
+                    (UnOp::Not, ConstVal::Bool(b)) => ConstVal::Bool(!b),
+                    (UnOp::Not, ConstVal::Integral(i)) => {
+                        ConstVal::Integral((!i).map_err(|err| math_err(span, err))?)
+                    }
+                    (UnOp::Neg, ConstVal::Integral(i)) => {
+                        ConstVal::Integral((-i).map_err(|err| math_err(span, err))?)
+                    }
+                    _ => return Err(unimplemented(span, "this unary operation"))
+                };
+                Ok(Value::Scalar(value))
+            }
+            Rvalue::Aggregate(ref kind, ref operands) => {
+                let variant = match *kind {
+                    AggregateKind::Vec | AggregateKind::Tuple => 0,
+                    AggregateKind::Adt(_, variant, _) => variant,
+                    AggregateKind::Closure(..) => return Err(unimplemented(span, "closures"))
+                };
+                let mut fields = Vec::with_capacity(operands.len());
+                for operand in operands {
+                    fields.push(self.operand(frame, operand, span)?);
+                }
+                Ok(Value::Aggregate { variant: variant, fields: fields })
+            }
+            Rvalue::Cast(..) => Err(unimplemented(span, "pointer casts")),
+            Rvalue::Ref(..) => Err(unimplemented(span, "references")),
+            Rvalue::Box(..) => Err(unimplemented(span, "boxes")),
+            Rvalue::InlineAsm { .. } => Err(unimplemented(span, "inline assembly")),
+        }
+    }
+
+    fn operand(&mut self, frame: &Frame<'a, 'tcx>, operand: &Operand<'tcx>, span: Span)
+               -> EvalResult<Value> {
+        let constant = match *operand {
+            Operand::Consume(ref lvalue) => return self.load(frame, lvalue, span),
+            Operand::Constant(ref constant) => constant,
+        };
+        match constant.literal {
+            Literal::Value { ref value } => Ok(Value::Scalar(value.clone())),
+            Literal::Item { def_id, substs } => {
+                if let ty::TyFnDef(..) = constant.ty.sty {
+                    return Err(unimplemented(constant.span, "function items as values"));
+                }
+                if !substs.types.is_empty() {
+                    return Err(unimplemented(constant.span, "generic constants"));
+                }
+                let map = self.map;
+                match map.map.get(&def_id) {
+                    Some(mir) => self.run(mir, vec![], constant.span),
+                    None => Err(unimplemented(constant.span, "constants without MIR"))
+                }
+            }
+            Literal::Promoted { index } => {
+                let mir = frame.mir;
+                self.run(&mir.promoted[index], vec![], constant.span)
+            }
+        }
+    }
+
+    /// Evaluates an operand that has to be a scalar.
+    fn scalar(&mut self, frame: &Frame<'a, 'tcx>, operand: &Operand<'tcx>, span: Span)
+              -> EvalResult<ConstVal> {
+        match self.operand(frame, operand, span)? {
+            Value::Scalar(value) => Ok(value),
+            Value::Undef => Err(unimplemented(span, "uninitialized values")),
+            Value::Aggregate { .. } => Err(unimplemented(span, "this aggregate"))
+        }
+    }
+
+    /// Splits an lvalue into the local it projects from and the steps
+    /// taken out of it.
+    fn resolve(&mut self, frame: &Frame<'a, 'tcx>, lvalue: &Lvalue<'tcx>, span: Span)
+               -> EvalResult<(usize, Vec<Step>)> {
+        let proj = match *lvalue {
+            Lvalue::Static(_) => return Err(unimplemented(span, "statics")),
+            Lvalue::Projection(ref proj) => proj,
+            _ => return Ok((frame.mir.local_index(lvalue).unwrap().index(), vec![])),
+        };
+        let (local, mut steps) = self.resolve(frame, &proj.base, span)?;
+        steps.push(match proj.elem {
+            ProjectionElem::Field(field, _) => Step::Field(field.index()),
+            ProjectionElem::Downcast(_, variant) => Step::Downcast(variant),
+            ProjectionElem::Index(ref index) => {
+                match self.scalar(frame, index, span)? {
+                    ConstVal::Integral(index) => Step::Index(index.to_u64_unchecked() as usize),
+                    _ => bug!("const_eval: non-integral index at {:?}", span)
+                }
+            }
+            ProjectionElem::ConstantIndex { offset, from_end: false, .. } => {
+                Step::Index(offset as usize)
+            }
+            ProjectionElem::ConstantIndex { offset, from_end: true, .. } => {
+                Step::IndexFromEnd(offset as usize)
+            }
+            ProjectionElem::Subslice { .. } => return Err(unimplemented(span, "subslices")),
+            ProjectionElem::Deref => return Err(unimplemented(span, "dereferences")),
+        });
+        Ok((local, steps))
+    }
+
+    fn load(&mut self, frame: &Frame<'a, 'tcx>, lvalue: &Lvalue<'tcx>, span: Span)
+            -> EvalResult<Value> {
+        let (local, steps) = self.resolve(frame, lvalue, span)?;
+        let mut value = &frame.locals[local];
+        for step in steps {
+            let fields = match *value {
+                Value::Aggregate { ref fields, variant } => {
+                    if let Step::Downcast(downcast) = step {
+                        // Reading the fields of another variant is undefined.
+                        if downcast != variant {
+                            return Ok(Value::Undef);
+                        }
+                        continue;
+                    }
+                    fields
+                }
+                Value::Undef => return Ok(Value::Undef),
+                Value::Scalar(_) => bug!("const_eval: projection out of a scalar at {:?}", span)
+            };
+            let index = match step {
+                Step::Field(index) => index,
+                Step::Index(index) => index,
+                Step::IndexFromEnd(offset) => fields.len().wrapping_sub(offset),
+                Step::Downcast(_) => unreachable!(),
+            };
+            value = match fields.get(index) {
+                Some(field) => field,
+                None => return Ok(Value::Undef)
+            };
+        }
+        Ok(value.clone())
+    }
+
+    /// Finds the value an lvalue refers to, creating the aggregates on the
+    /// way to it if they are not initialized yet.
+    fn place<'f>(&mut self, frame: &'f mut Frame<'a, 'tcx>, lvalue: &Lvalue<'tcx>, span: Span)
+                 -> EvalResult<&'f mut Value> {
+        let (local, steps) = self.resolve(frame, lvalue, span)?;
+        let mut value = &mut frame.locals[local];
+        for step in steps {
+            // The variant is set by `SetDiscriminant` or the whole aggregate.
+            if let Step::Downcast(_) = step {
+                continue;
+            }
+            let current = value;
+            if let Value::Undef = *current {
+                *current = Value::Aggregate { variant: 0, fields: vec![] };
+            }
+            let fields = match *current {
+                Value::Aggregate { ref mut fields, .. } => fields,
+                _ => bug!("const_eval: projection out of a scalar at {:?}", span)
+            };
+            let index = match step {
+                Step::Field(index) => {
+                    if fields.len() <= index {
+                        fields.resize(index + 1, Value::Undef);
+                    }
+                    index
+                }
+                Step::Index(index) => index,
+                Step::IndexFromEnd(offset) => fields.len().wrapping_sub(offset),
+                Step::Downcast(_) => unreachable!(),
+            };
+            if index >= fields.len() {
+                return Err(ConstEvalErr {
+                    span: span,
+                    kind: ErrKind::IndexOutOfBounds {
+                        len: fields.len() as u64,
+                        index: index as u64,
+                    },
+                });
+            }
+            value = &mut fields[index];
+        }
+        Ok(value)
+    }
+
+    fn store(&mut self,
+             frame: &mut Frame<'a, 'tcx>,
+             lvalue: &Lvalue<'tcx>,
+             value: Value,
+             span: Span)
+             -> EvalResult<()> {
+        *self.place(frame, lvalue, span)? = value;
+        Ok(())
+    }
+
+    fn usize(&self, n: usize) -> ConstVal {
+        let uint_type = self.tcx.sess.target.uint_type;
+        ConstVal::Integral(ConstInt::Usize(ConstUsize::new_truncating(n as u64, uint_type)))
+    }
+}
+
+/// Applies a binary operator, returning `None` for unsupported operands.
+fn binop(op: BinOp, lhs: ConstVal, rhs: ConstVal) -> Option<Result<ConstVal, ConstMathErr>> {
+    use rustc::middle::const_val::ConstVal::*;
+    match (lhs, rhs) {
+        (Integral(a), Integral(b)) => {
+            let result = match op {
+                BinOp::Add => a + b,
+                BinOp::Sub => a - b,
+                BinOp::Mul => a * b,
+                BinOp::Div => a / b,
+                BinOp::Rem => a % b,
+                BinOp::BitXor => a ^ b,
+                BinOp::BitAnd => a & b,
+                BinOp::BitOr => a | b,
+                BinOp::Shl => a << b,
+                BinOp::Shr => a >> b,
+                BinOp::Eq | BinOp::Lt | BinOp::Le |
+                BinOp::Ne | BinOp::Ge | BinOp::Gt => {
+                    return Some(a.try_cmp(b).map(|ord| Bool(compare(op, ord))));
+                }
+            };
+            Some(result.map(Integral))
+        }
+        (Bool(a), Bool(b)) => {
+            Some(Ok(Bool(match op {
+                BinOp::BitXor => a ^ b,
+                BinOp::BitAnd => a & b,
+                BinOp::BitOr => a | b,
+                BinOp::Eq | BinOp::Lt | BinOp::Le |
+                BinOp::Ne | BinOp::Ge | BinOp::Gt => compare(op, a.cmp(&b)),
+                _ => return None
+            })))
+        }
+        (Char(a), Char(b)) => {
+            match op {
+                BinOp::Eq | BinOp::Lt | BinOp::Le |
+                BinOp::Ne | BinOp::Ge | BinOp::Gt => Some(Ok(Bool(compare(op, a.cmp(&b))))),
+                _ => None
+            }
+        }
+        _ => None
+    }
+}
+
+fn compare(op: BinOp, ord: Ordering) -> bool {
+    match op {
+        BinOp::Eq => ord == Ordering::Equal,
+        BinOp::Ne => ord != Ordering::Equal,
+        BinOp::Lt => ord == Ordering::Less,
+        BinOp::Le => ord != Ordering::Greater,
+        BinOp::Gt => ord == Ordering::Greater,
+        BinOp::Ge => ord != Ordering::Less,
+        _ => bug!("compare: {:?} is not a comparison", op)
+    }
+}
+
+/// Whether two scalars are the same value, ignoring integer types that have
+/// not been inferred (as the HIR evaluator produces them).
+pub fn same_value(a: &ConstVal, b: &ConstVal) -> bool {
+    match (a, b) {
+        (&ConstVal::Integral(a), &ConstVal::Integral(b)) => {
+            a.try_cmp(b) == Ok(Ordering::Equal)
+        }
+        _ => a == b
+    }
+}
+
+/// Performs an `as` cast between integers, `bool` and `char` with the
+/// same truncating and sign-extending semantics runtime code has.
+fn cast<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, value: ConstVal, ty: Ty<'tcx>)
+                  -> Option<ConstVal> {
+    let bits = match value {
+        ConstVal::Integral(i) => i.to_u64_unchecked(),
+        ConstVal::Bool(b) => b as u64,
+        ConstVal::Char(c) => c as u64,
+        _ => return None
+    };
+    let int = match ty.sty {
+        ty::TyInt(ast::IntTy::I8) => ConstInt::I8(bits as i8),
+        ty::TyInt(ast::IntTy::I16) => ConstInt::I16(bits as i16),
+        ty::TyInt(ast::IntTy::I32) => ConstInt::I32(bits as i32),
+        ty::TyInt(ast::IntTy::I64) => ConstInt::I64(bits as i64),
+        ty::TyInt(ast::IntTy::Is) => {
+            ConstInt::Isize(ConstIsize::new_truncating(bits as i64, tcx.sess.target.int_type))
+        }
+        ty::TyUint(ast::UintTy::U8) => ConstInt::U8(bits as u8),
+        ty::TyUint(ast::UintTy::U16) => ConstInt::U16(bits as u16),
+        ty::TyUint(ast::UintTy::U32) => ConstInt::U32(bits as u32),
+        ty::TyUint(ast::UintTy::U64) => ConstInt::U64(bits),
+        ty::TyUint(ast::UintTy::Us) => {
+            ConstInt::Usize(ConstUsize::new_truncating(bits, tcx.sess.target.uint_type))
+        }
+        // Only `u8` can be cast to `char`.
+        ty::TyChar => {
+            return match value {
+                ConstVal::Integral(ConstInt::U8(b)) => Some(ConstVal::Char(b as char)),
+                _ => None
+            };
+        }
+        _ => return None
+    };
+    Some(ConstVal::Integral(int))
+}
@@ -21,15 +21,13 @@ use std::fmt::Debug;
 use std::fs::File;
 use std::io;
 use std::io::prelude::*;
-use std::marker::PhantomData;
 use std::mem;
 use std::path::Path;
 
-use super::super::MoveDataParamEnv;
-use super::super::MirBorrowckCtxtPreDataflow;
-use bitslice::bits_to_string;
-use indexed_set::{IdxSet};
-use super::{BitDenotation, DataflowState};
+use rustc_data_structures::bitslice::bits_to_string;
+use rustc_data_structures::indexed_set::IdxSet;
+
+use super::{BitDenotation, DataflowAnalysis, DataflowState};
 
 impl<O: BitDenotation> DataflowState<O> {
     fn each_bit<F>(&self, ctxt: &O::Ctxt, words: &IdxSet<O::Idx>, mut f: F)
@@ -79,45 +77,30 @@ impl<O: BitDenotation> DataflowState<O> {
     }
 }
 
-pub trait MirWithFlowState<'tcx> {
-    type BD: BitDenotation<Ctxt=MoveDataParamEnv<'tcx>>;
-    fn node_id(&self) -> NodeId;
-    fn mir(&self) -> &Mir<'tcx>;
-    fn analysis_ctxt(&self) -> &<Self::BD as BitDenotation>::Ctxt;
-    fn flow_state(&self) -> &DataflowState<Self::BD>;
-}
-
-impl<'a, 'tcx: 'a, BD> MirWithFlowState<'tcx> for MirBorrowckCtxtPreDataflow<'a, 'tcx, BD>
-    where 'a, 'tcx: 'a, BD: BitDenotation<Ctxt=MoveDataParamEnv<'tcx>>
-{
-    type BD = BD;
-    fn node_id(&self) -> NodeId { self.node_id }
-    fn mir(&self) -> &Mir<'tcx> { self.flow_state.mir() }
-    fn analysis_ctxt(&self) -> &BD::Ctxt { &self.flow_state.ctxt }
-    fn flow_state(&self) -> &DataflowState<Self::BD> { &self.flow_state.flow_state }
-}
-
-struct Graph<'a, 'tcx, MWF:'a, P> where
-    MWF: MirWithFlowState<'tcx>
+struct Graph<'b, 'a: 'b, 'tcx: 'a, BD, P>
+    where BD: 'b + BitDenotation, BD::Ctxt: 'a
 {
-    mbcx: &'a MWF,
-    phantom: PhantomData<&'tcx ()>,
+    analysis: &'b DataflowAnalysis<'a, 'tcx, BD>,
+    node_id: NodeId,
     render_idx: P,
 }
 
-pub fn print_borrowck_graph_to<'a, 'tcx, BD, P>(
-    mbcx: &MirBorrowckCtxtPreDataflow<'a, 'tcx, BD>,
-    path: &Path,
-    render_idx: P)
-    -> io::Result<()>
-    where BD: BitDenotation<Ctxt=MoveDataParamEnv<'tcx>>,
+/// Writes the control-flow graph of the MIR of `node_id` to `path`, with
+/// each block annotated by the entry-, gen- and kill-sets of `analysis`.
+/// `render_idx` is used to describe the meaning of each set bit.
+pub fn print_dataflow_graph_to<'a, 'tcx, BD, P>(analysis: &DataflowAnalysis<'a, 'tcx, BD>,
+                                                node_id: NodeId,
+                                                path: &Path,
+                                                render_idx: P)
+                                                -> io::Result<()>
+    where BD: BitDenotation,
           P: for <'b> Fn(&'b BD::Ctxt, BD::Idx) -> &'b Debug
 {
-    let g = Graph { mbcx: mbcx, phantom: PhantomData, render_idx: render_idx };
+    let g = Graph { analysis: analysis, node_id: node_id, render_idx: render_idx };
     let mut v = Vec::new();
     dot::render(&g, &mut v)?;
-    debug!("print_borrowck_graph_to path: {} node_id: {}",
-           path.display(), mbcx.node_id);
+    debug!("print_dataflow_graph_to path: {} node_id: {}",
+           path.display(), node_id);
     File::create(path).and_then(|mut f| f.write_all(&v))
 }
 
@@ -131,17 +114,15 @@ fn outgoing(mir: &Mir, bb: BasicBlock) -> Vec<Edge> {
     (0..succ_len).map(|index| Edge { source: bb, index: index}).collect()
 }
 
-impl<'a, 'tcx, MWF, P> dot::Labeller<'a> for Graph<'a, 'tcx, MWF, P>
-    where MWF: MirWithFlowState<'tcx>,
-          P: for <'b> Fn(&'b <MWF::BD as BitDenotation>::Ctxt,
-                         <MWF::BD as BitDenotation>::Idx)
-                         -> &'b Debug,
+impl<'g, 'b, 'a, 'tcx, BD, P> dot::Labeller<'g> for Graph<'b, 'a, 'tcx, BD, P>
+    where BD: BitDenotation,
+          P: for <'c> Fn(&'c BD::Ctxt, BD::Idx) -> &'c Debug
 {
     type Node = Node;
     type Edge = Edge;
     fn graph_id(&self) -> dot::Id {
         dot::Id::new(format!("graph_for_node_{}",
-                             self.mbcx.node_id()))
+                             self.node_id))
             .unwrap()
     }
 
@@ -224,11 +205,11 @@ impl<'a, 'tcx, MWF, P> dot::Labeller<'a> for Graph<'a, 'tcx, MWF, P>
             }
             Ok(())
         }
-        ::rustc_mir::graphviz::write_node_label(
-            *n, self.mbcx.mir(), &mut v, 4,
+        ::graphviz::write_node_label(
+            *n, self.analysis.mir(), &mut v, 4,
             |w| {
-                let ctxt = self.mbcx.analysis_ctxt();
-                let flow = self.mbcx.flow_state();
+                let ctxt = self.analysis.ctxt();
+                let flow = self.analysis.flow_state();
                 let entry_interp = flow.interpret_set(ctxt,
                                                       flow.sets.on_entry_set_for(i),
                                                       &self.render_idx);
@@ -244,8 +225,8 @@ impl<'a, 'tcx, MWF, P> dot::Labeller<'a> for Graph<'a, 'tcx, MWF, P>
                        entrybits=bits_to_string(entry.words(), bits_per_block))
             },
             |w| {
-                let ctxt = self.mbcx.analysis_ctxt();
-                let flow = self.mbcx.flow_state();
+                let ctxt = self.analysis.ctxt();
+                let flow = self.analysis.flow_state();
                 let gen_interp =
                     flow.interpret_set(ctxt, flow.sets.gen_set_for(i), &self.render_idx);
                 let kill_interp =
@@ -307,13 +288,13 @@ impl<'a, 'tcx, MWF, P> dot::Labeller<'a> for Graph<'a, 'tcx, MWF, P>
     }
 }
 
-impl<'a, 'tcx, MWF, P> dot::GraphWalk<'a> for Graph<'a, 'tcx, MWF, P>
-    where MWF: MirWithFlowState<'tcx>
+impl<'g, 'b, 'a, 'tcx, BD, P> dot::GraphWalk<'g> for Graph<'b, 'a, 'tcx, BD, P>
+    where BD: BitDenotation
 {
     type Node = Node;
     type Edge = Edge;
     fn nodes(&self) -> dot::Nodes<Node> {
-        self.mbcx.mir()
+        self.analysis.mir()
             .basic_blocks()
             .indices()
             .collect::<Vec<_>>()
@@ -321,7 +302,7 @@ impl<'a, 'tcx, MWF, P> dot::GraphWalk<'a> for Graph<'a, 'tcx, MWF, P>
     }
 
     fn edges(&self) -> dot::Edges<Edge> {
-        let mir = self.mbcx.mir();
+        let mir = self.analysis.mir();
         // base initial capacity on assumption every block has at
         // least one outgoing edge (Which should be true for all
         // blocks but one, the exit-block).
@@ -338,7 +319,7 @@ impl<'a, 'tcx, MWF, P> dot::GraphWalk<'a> for Graph<'a, 'tcx, MWF, P>
     }
 
     fn target(&self, edge: &Edge) -> Node {
-        let mir = self.mbcx.mir();
+        let mir = self.analysis.mir();
         mir[edge.source].terminator().successors()[edge.index]
     }
 }
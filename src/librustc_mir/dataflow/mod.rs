@@ -0,0 +1,554 @@
+// Copyright 2012-2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A generic gen/kill dataflow framework for MIR.
+//!
+//! An analysis is described by implementing `BitDenotation` (what the
+//! bits mean and how each statement and terminator affects them) and
+//! `DataflowOperator` (how the states of several predecessors are
+//! joined). `DataflowAnalysis` then computes a fixed point over the
+//! control-flow graph, either forwards or backwards, and hands out the
+//! per-block sets via `DataflowResults`.
+
+use rustc_data_structures::bitslice::{bitwise, BitwiseOperator};
+use rustc_data_structures::indexed_set::{IdxSet, IdxSetBuf};
+use rustc_data_structures::indexed_vec::Idx;
+
+use rustc::ty::TyCtxt;
+use rustc::mir::repr::{self, Mir};
+
+use std::mem;
+
+pub use self::graphviz::print_dataflow_graph_to;
+
+mod graphviz;
+
+/// The direction in which an analysis propagates its bits through the
+/// control-flow graph.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// From each block into its successors, starting at `START_BLOCK`.
+    Forward,
+    /// From each block into its predecessors, starting at the blocks
+    /// that return from the function.
+    Backward,
+}
+
+struct PropagationContext<'b, 'a: 'b, 'tcx: 'a, O>
+    where O: 'b + BitDenotation, O::Ctxt: 'a
+{
+    builder: &'b mut DataflowAnalysis<'a, 'tcx, O>,
+    changed: bool,
+}
+
+impl<'a, 'tcx: 'a, BD> DataflowAnalysis<'a, 'tcx, BD>
+    where BD: BitDenotation + DataflowOperator
+{
+    /// Builds the sets and propagates them to a fixed point, returning
+    /// the results.
+    pub fn run(mut self) -> DataflowResults<BD> {
+        self.build_sets();
+        self.propagate();
+        self.results()
+    }
+
+    /// Iterates the entry sets to a fixed point. `build_sets` must have
+    /// been called first.
+    pub fn propagate(&mut self) {
+        let mut temp = IdxSetBuf::new_empty(self.flow_state.sets.bits_per_block);
+        let mut propcx = PropagationContext {
+            builder: self,
+            changed: true,
+        };
+        while propcx.changed {
+            propcx.changed = false;
+            propcx.reset(&mut temp);
+            propcx.walk_cfg(&mut temp);
+        }
+    }
+
+    /// Computes the gen- and kill-sets of every block, as well as the
+    /// entry sets of the blocks the analysis starts from.
+    pub fn build_sets(&mut self) {
+        let mir = self.mir;
+
+        match BD::direction() {
+            Direction::Forward => {
+                let sets = &mut self.flow_state.sets.for_block(repr::START_BLOCK.index());
+                self.flow_state.operator.start_block_effect(&self.ctxt, sets);
+            }
+            Direction::Backward => {
+                for (bb, data) in mir.basic_blocks().iter_enumerated() {
+                    if is_return(data) {
+                        let sets = &mut self.flow_state.sets.for_block(bb.index());
+                        self.flow_state.operator.start_block_effect(&self.ctxt, sets);
+                    }
+                }
+            }
+        }
+
+        for (bb, data) in mir.basic_blocks().iter_enumerated() {
+            let &repr::BasicBlockData { ref statements,
+                                        ref terminator,
+                                        is_cleanup: _ } = data;
+
+            let sets = &mut self.flow_state.sets.for_block(bb.index());
+            let stmts_len = statements.len();
+            match BD::direction() {
+                Direction::Forward => {
+                    for j_stmt in 0..stmts_len {
+                        self.flow_state.operator.statement_effect(&self.ctxt, sets, bb, j_stmt);
+                    }
+                    if terminator.is_some() {
+                        self.flow_state.operator.terminator_effect(&self.ctxt, sets, bb,
+                                                                   stmts_len);
+                    }
+                }
+                Direction::Backward => {
+                    if terminator.is_some() {
+                        self.flow_state.operator.terminator_effect(&self.ctxt, sets, bb,
+                                                                   stmts_len);
+                    }
+                    for j_stmt in (0..stmts_len).rev() {
+                        self.flow_state.operator.statement_effect(&self.ctxt, sets, bb, j_stmt);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn is_return(data: &repr::BasicBlockData) -> bool {
+    match data.terminator {
+        Some(repr::Terminator { kind: repr::TerminatorKind::Return, .. }) => true,
+        _ => false
+    }
+}
+
+impl<'b, 'a: 'b, 'tcx: 'a, BD> PropagationContext<'b, 'a, 'tcx, BD>
+    where BD: BitDenotation + DataflowOperator
+{
+    fn reset(&mut self, bits: &mut IdxSet<BD::Idx>) {
+        let e = if BD::bottom_value() {!0} else {0};
+        for b in bits.words_mut() {
+            *b = e;
+        }
+    }
+
+    fn walk_cfg(&mut self, in_out: &mut IdxSet<BD::Idx>) {
+        let mir = self.builder.mir;
+        for (bb_idx, bb_data) in mir.basic_blocks().iter().enumerate() {
+            let builder = &mut self.builder;
+            {
+                let sets = builder.flow_state.sets.for_block(bb_idx);
+                debug_assert!(in_out.words().len() == sets.on_entry.words().len());
+                in_out.clone_from(sets.on_entry);
+                in_out.union(sets.gen_set);
+                in_out.subtract(sets.kill_set);
+            }
+            let bb = repr::BasicBlock::new(bb_idx);
+            match BD::direction() {
+                Direction::Forward => {
+                    builder.propagate_bits_into_graph_successors_of(
+                        in_out, &mut self.changed, (bb, bb_data));
+                }
+                Direction::Backward => {
+                    builder.propagate_bits_into_graph_predecessors_of(
+                        in_out, &mut self.changed, bb);
+                }
+            }
+        }
+    }
+}
+
+/// Maps each block to a set of bits
+#[derive(Debug)]
+struct Bits<E:Idx> {
+    bits: IdxSetBuf<E>,
+}
+
+impl<E:Idx> Clone for Bits<E> {
+    fn clone(&self) -> Self { Bits { bits: self.bits.clone() } }
+}
+
+impl<E:Idx> Bits<E> {
+    fn new(bits: IdxSetBuf<E>) -> Self {
+        Bits { bits: bits }
+    }
+}
+
+pub struct DataflowAnalysis<'a, 'tcx: 'a, O>
+    where O: BitDenotation, O::Ctxt: 'a
+{
+    flow_state: DataflowState<O>,
+    mir: &'a Mir<'tcx>,
+    ctxt: &'a O::Ctxt,
+}
+
+impl<'a, 'tcx: 'a, O> DataflowAnalysis<'a, 'tcx, O>
+    where O: BitDenotation
+{
+    pub fn results(self) -> DataflowResults<O> {
+        DataflowResults(self.flow_state)
+    }
+
+    pub fn mir(&self) -> &'a Mir<'tcx> { self.mir }
+
+    pub fn ctxt(&self) -> &'a O::Ctxt { self.ctxt }
+
+    pub fn flow_state(&self) -> &DataflowState<O> { &self.flow_state }
+}
+
+pub struct DataflowResults<O>(DataflowState<O>) where O: BitDenotation;
+
+impl<O: BitDenotation> DataflowResults<O> {
+    pub fn sets(&self) -> &AllSets<O::Idx> {
+        &self.0.sets
+    }
+
+    pub fn operator(&self) -> &O {
+        &self.0.operator
+    }
+}
+
+pub struct DataflowState<O: BitDenotation>
+{
+    /// All the sets for the analysis. (Factored into its
+    /// own structure so that we can borrow it mutably
+    /// on its own separate from other fields.)
+    pub sets: AllSets<O::Idx>,
+
+    /// operator used to initialize, combine, and interpret bits.
+    operator: O,
+}
+
+impl<O: BitDenotation> DataflowState<O> {
+    pub fn operator(&self) -> &O {
+        &self.operator
+    }
+}
+
+#[derive(Debug)]
+pub struct AllSets<E: Idx> {
+    /// Analysis bitwidth for each block.
+    bits_per_block: usize,
+
+    /// Number of words associated with each block entry
+    /// equal to bits_per_block / usize::BITS, rounded up.
+    words_per_block: usize,
+
+    /// For each block, bits generated by executing the statements in
+    /// the block. (For comparison, the Terminator for each block is
+    /// handled in a flow-specific manner during propagation.)
+    gen_sets: Bits<E>,
+
+    /// For each block, bits killed by executing the statements in the
+    /// block. (For comparison, the Terminator for each block is
+    /// handled in a flow-specific manner during propagation.)
+    kill_sets: Bits<E>,
+
+    /// For each block, bits valid on entry to the block. For a backward
+    /// analysis, this is the state at the *end* of the block.
+    on_entry_sets: Bits<E>,
+}
+
+/// The flow sets of a single block.
+pub struct BlockSets<'a, E: Idx> {
+    pub on_entry: &'a mut IdxSet<E>,
+    pub gen_set: &'a mut IdxSet<E>,
+    pub kill_set: &'a mut IdxSet<E>,
+}
+
+impl<'a, E:Idx> BlockSets<'a, E> {
+    /// Records that `e` is generated, undoing any earlier kill of it.
+    pub fn gen(&mut self, e: &E) {
+        self.gen_set.add(e);
+        self.kill_set.remove(e);
+    }
+
+    /// Records that `e` is killed, undoing any earlier gen of it.
+    pub fn kill(&mut self, e: &E) {
+        self.gen_set.remove(e);
+        self.kill_set.add(e);
+    }
+}
+
+impl<E:Idx> AllSets<E> {
+    pub fn bits_per_block(&self) -> usize { self.bits_per_block }
+    pub fn for_block(&mut self, block_idx: usize) -> BlockSets<E> {
+        let offset = self.words_per_block * block_idx;
+        let range = E::new(offset)..E::new(offset + self.words_per_block);
+        BlockSets {
+            on_entry: self.on_entry_sets.bits.range_mut(&range),
+            gen_set: self.gen_sets.bits.range_mut(&range),
+            kill_set: self.kill_sets.bits.range_mut(&range),
+        }
+    }
+
+    fn lookup_set_for<'a>(&self, sets: &'a Bits<E>, block_idx: usize) -> &'a IdxSet<E> {
+        let offset = self.words_per_block * block_idx;
+        let range = E::new(offset)..E::new(offset + self.words_per_block);
+        sets.bits.range(&range)
+    }
+    pub fn gen_set_for(&self, block_idx: usize) -> &IdxSet<E> {
+        self.lookup_set_for(&self.gen_sets, block_idx)
+    }
+    pub fn kill_set_for(&self, block_idx: usize) -> &IdxSet<E> {
+        self.lookup_set_for(&self.kill_sets, block_idx)
+    }
+    pub fn on_entry_set_for(&self, block_idx: usize) -> &IdxSet<E> {
+        self.lookup_set_for(&self.on_entry_sets, block_idx)
+    }
+}
+
+/// Parameterization for the precise form of data flow that is used.
+pub trait DataflowOperator: BitwiseOperator {
+    /// Specifies the initial value for each bit in the `on_entry` set
+    fn bottom_value() -> bool;
+}
+
+pub trait BitDenotation {
+    /// Specifies what index type is used to access the bitvector.
+    type Idx: Idx;
+
+    /// Specifies what, if any, separate context needs to be supplied for methods below.
+    type Ctxt;
+
+    /// A name describing the dataflow analysis that this
+    /// BitDenotation is supporting.  The name should be something
+    /// suitable for plugging in as part of a filename e.g. avoid
+    /// space-characters or other things that tend to look bad on a
+    /// file system, like slashes or periods. It is also better for
+    /// the name to be reasonably short, again because it will be
+    /// plugged into a filename.
+    fn name() -> &'static str;
+
+    /// The direction in which the analysis runs.
+    ///
+    /// A backward analysis visits the terminator of each block before
+    /// its statements (in reverse order) when building the gen- and
+    /// kill-sets, and propagates bits from each block into its
+    /// predecessors.
+    fn direction() -> Direction { Direction::Forward }
+
+    /// Size of each bitvector allocated for each block in the analysis.
+    fn bits_per_block(&self, &Self::Ctxt) -> usize;
+
+    /// Mutates the block-sets (the flow sets for the given
+    /// basic block) according to the effects that have been
+    /// established *prior* to entering the start block.
+    ///
+    /// (For example, establishing the call arguments.)
+    ///
+    /// (Typically this should only modify `sets.on_entry`, since the
+    /// gen and kill sets should reflect the effects of *executing*
+    /// the start block itself.)
+    ///
+    /// For a backward analysis this is called for every block that
+    /// ends in a `Return` terminator instead.
+    fn start_block_effect(&self, ctxt: &Self::Ctxt, sets: &mut BlockSets<Self::Idx>);
+
+    /// Mutates the block-sets (the flow sets for the given
+    /// basic block) according to the effects of evaluating statement.
+    ///
+    /// This is used, in particular, for building up the
+    /// "transfer-function" represnting the overall-effect of the
+    /// block, represented via GEN and KILL sets.
+    ///
+    /// The statement is identified as `bb_data[idx_stmt]`, where
+    /// `bb_data` is the sequence of statements identifed by `bb` in
+    /// the MIR.
+    fn statement_effect(&self,
+                        ctxt: &Self::Ctxt,
+                        sets: &mut BlockSets<Self::Idx>,
+                        bb: repr::BasicBlock,
+                        idx_stmt: usize);
+
+    /// Mutates the block-sets (the flow sets for the given
+    /// basic block) according to the effects of evaluating
+    /// the terminator.
+    ///
+    /// This is used, in particular, for building up the
+    /// "transfer-function" represnting the overall-effect of the
+    /// block, represented via GEN and KILL sets.
+    ///
+    /// The effects applied here cannot depend on which branch the
+    /// terminator took.
+    fn terminator_effect(&self,
+                         ctxt: &Self::Ctxt,
+                         sets: &mut BlockSets<Self::Idx>,
+                         bb: repr::BasicBlock,
+                         idx_term: usize);
+
+    /// Mutates the block-sets according to the (flow-dependent)
+    /// effect of a successful return from a Call terminator.
+    ///
+    /// If basic-block BB_x ends with a call-instruction that, upon
+    /// successful return, flows to BB_y, then this method will be
+    /// called on the exit flow-state of BB_x in order to set up the
+    /// entry flow-state of BB_y.
+    ///
+    /// This is used, in particular, as a special case during the
+    /// "propagate" loop where all of the basic blocks are repeatedly
+    /// visited. Since the effects of a Call terminator are
+    /// flow-dependent, the current MIR cannot encode them via just
+    /// GEN and KILL sets attached to the block, and so instead we add
+    /// this extra machinery to represent the flow-dependent effect.
+    ///
+    /// Backward analyses never call this; they should account for the
+    /// call's destination in `terminator_effect`.
+    ///
+    /// FIXME: Right now this is a bit of a wart in the API. It might
+    /// be better to represent this as an additional gen- and
+    /// kill-sets associated with each edge coming out of the basic
+    /// block.
+    fn propagate_call_return(&self,
+                             ctxt: &Self::Ctxt,
+                             in_out: &mut IdxSet<Self::Idx>,
+                             call_bb: repr::BasicBlock,
+                             dest_bb: repr::BasicBlock,
+                             dest_lval: &repr::Lvalue);
+}
+
+impl<'a, 'tcx: 'a, D> DataflowAnalysis<'a, 'tcx, D>
+    where D: BitDenotation + DataflowOperator
+{
+    pub fn new(_tcx: TyCtxt<'a, 'tcx, 'tcx>,
+               mir: &'a Mir<'tcx>,
+               ctxt: &'a D::Ctxt,
+               denotation: D) -> Self {
+        let bits_per_block = denotation.bits_per_block(&ctxt);
+        let usize_bits = mem::size_of::<usize>() * 8;
+        let words_per_block = (bits_per_block + usize_bits - 1) / usize_bits;
+
+        // (now rounded up to multiple of word size)
+        let bits_per_block = words_per_block * usize_bits;
+
+        let num_blocks = mir.basic_blocks().len();
+        let num_overall = num_blocks * bits_per_block;
+
+        let zeroes = Bits::new(IdxSetBuf::new_empty(num_overall));
+        let on_entry = Bits::new(if D::bottom_value() {
+            IdxSetBuf::new_filled(num_overall)
+        } else {
+            IdxSetBuf::new_empty(num_overall)
+        });
+
+        DataflowAnalysis {
+            ctxt: ctxt,
+            mir: mir,
+            flow_state: DataflowState {
+                sets: AllSets {
+                    bits_per_block: bits_per_block,
+                    words_per_block: words_per_block,
+                    gen_sets: zeroes.clone(),
+                    kill_sets: zeroes,
+                    on_entry_sets: on_entry,
+                },
+                operator: denotation,
+            },
+        }
+
+    }
+}
+
+impl<'a, 'tcx: 'a, D> DataflowAnalysis<'a, 'tcx, D>
+    where D: BitDenotation + DataflowOperator
+{
+    /// Propagates the bits of `in_out` into all the successors of `bb`,
+    /// using bitwise operator denoted by `self.operator`.
+    ///
+    /// For most blocks, this is entirely uniform. However, for blocks
+    /// that end with a call terminator, the effect of the call on the
+    /// dataflow state may depend on whether the call returned
+    /// successfully or unwound.
+    ///
+    /// To reflect this, the `propagate_call_return` method of the
+    /// `BitDenotation` mutates `in_out` when propagating `in_out` via
+    /// a call terminator; such mutation is performed *last*, to
+    /// ensure its side-effects do not leak elsewhere (e.g. into
+    /// unwind target).
+    fn propagate_bits_into_graph_successors_of(
+        &mut self,
+        in_out: &mut IdxSet<D::Idx>,
+        changed: &mut bool,
+        (bb, bb_data): (repr::BasicBlock, &repr::BasicBlockData))
+    {
+        match bb_data.terminator().kind {
+            repr::TerminatorKind::Return |
+            repr::TerminatorKind::Resume |
+            repr::TerminatorKind::Unreachable => {}
+            repr::TerminatorKind::Goto { ref target } |
+            repr::TerminatorKind::Assert { ref target, cleanup: None, .. } |
+            repr::TerminatorKind::Drop { ref target, location: _, unwind: None } |
+            repr::TerminatorKind::DropAndReplace {
+                ref target, value: _, location: _, unwind: None
+            } => {
+                self.propagate_bits_into_entry_set_for(in_out, changed, target);
+            }
+            repr::TerminatorKind::Assert { ref target, cleanup: Some(ref unwind), .. } |
+            repr::TerminatorKind::Drop { ref target, location: _, unwind: Some(ref unwind) } |
+            repr::TerminatorKind::DropAndReplace {
+                ref target, value: _, location: _, unwind: Some(ref unwind)
+            } => {
+                self.propagate_bits_into_entry_set_for(in_out, changed, target);
+                self.propagate_bits_into_entry_set_for(in_out, changed, unwind);
+            }
+            repr::TerminatorKind::If { ref targets, .. } => {
+                self.propagate_bits_into_entry_set_for(in_out, changed, &targets.0);
+                self.propagate_bits_into_entry_set_for(in_out, changed, &targets.1);
+            }
+            repr::TerminatorKind::Switch { ref targets, .. } |
+            repr::TerminatorKind::SwitchInt { ref targets, .. } => {
+                for target in targets {
+                    self.propagate_bits_into_entry_set_for(in_out, changed, target);
+                }
+            }
+            repr::TerminatorKind::Call { ref cleanup, ref destination, func: _, args: _ } => {
+                if let Some(ref unwind) = *cleanup {
+                    self.propagate_bits_into_entry_set_for(in_out, changed, unwind);
+                }
+                if let Some((ref dest_lval, ref dest_bb)) = *destination {
+                    // N.B.: This must be done *last*, after all other
+                    // propagation, as documented in comment above.
+                    self.flow_state.operator.propagate_call_return(
+                        &self.ctxt, in_out, bb, *dest_bb, dest_lval);
+                    self.propagate_bits_into_entry_set_for(in_out, changed, dest_bb);
+                }
+            }
+        }
+    }
+
+    /// Propagates the bits of `in_out` into all the predecessors of
+    /// `bb`, for a backward analysis.
+    fn propagate_bits_into_graph_predecessors_of(&mut self,
+                                                 in_out: &IdxSet<D::Idx>,
+                                                 changed: &mut bool,
+                                                 bb: repr::BasicBlock) {
+        let mir = self.mir;
+        let predecessors = mir.predecessors_for(bb);
+        for pred in predecessors.iter() {
+            self.propagate_bits_into_entry_set_for(in_out, changed, pred);
+        }
+    }
+
+    fn propagate_bits_into_entry_set_for(&mut self,
+                                         in_out: &IdxSet<D::Idx>,
+                                         changed: &mut bool,
+                                         bb: &repr::BasicBlock) {
+        let entry_set = self.flow_state.sets.for_block(bb.index()).on_entry;
+        let set_changed = bitwise(entry_set.words_mut(),
+                                  in_out.words(),
+                                  &self.flow_state.operator);
+        if set_changed {
+            *changed = true;
+        }
+    }
+}
@@ -30,27 +30,68 @@ pub fn write_mir_graphviz<'a, 'b, 'tcx, W, I>(tcx: TyCtxt<'b, 'tcx, 'tcx>,
     for def_id in iter {
         let nodeid = tcx.map.as_local_node_id(def_id).unwrap();
         let mir = &mir_map.map[&def_id];
+        write_mir_fn_graphviz(tcx, nodeid, mir, w)?;
+    }
+    Ok(())
+}
 
-        writeln!(w, "digraph Mir_{} {{", nodeid)?;
-
-        // Global graph properties
-        writeln!(w, r#"    graph [fontname="monospace"];"#)?;
-        writeln!(w, r#"    node [fontname="monospace"];"#)?;
-        writeln!(w, r#"    edge [fontname="monospace"];"#)?;
-
-        // Graph label
-        write_graph_label(tcx, nodeid, mir, w)?;
+/// Write a graphviz DOT graph of the MIR of a single function.
+pub fn write_mir_fn_graphviz<'a, 'tcx, W: Write>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                                 nodeid: NodeId,
+                                                 mir: &Mir,
+                                                 w: &mut W)
+                                                 -> io::Result<()> {
+    writeln!(w, "digraph Mir_{} {{", nodeid)?;
+
+    // Global graph properties
+    writeln!(w, r#"    graph [fontname="monospace"];"#)?;
+    writeln!(w, r#"    node [fontname="monospace"];"#)?;
+    writeln!(w, r#"    edge [fontname="monospace"];"#)?;
+
+    // Graph label
+    write_graph_label(tcx, nodeid, mir, w)?;
+
+    // Nodes
+    for (block, _) in mir.basic_blocks().iter_enumerated() {
+        write_node(block, mir, w)?;
+    }
 
-        // Nodes
-        for (block, _) in mir.basic_blocks().iter_enumerated() {
-            write_node(block, mir, w)?;
-        }
+    // Edges
+    for (source, _) in mir.basic_blocks().iter_enumerated() {
+        write_edges(source, mir, w)?;
+    }
+    writeln!(w, "}}")
+}
 
-        // Edges
-        for (source, _) in mir.basic_blocks().iter_enumerated() {
-            write_edges(source, mir, w)?;
-        }
-        writeln!(w, "}}")?
+/// Write the CFG of a MIR as a sequence of HTML tables, one per basic
+/// block, in the same format as the graphviz node labels. As an HTML page
+/// has no edges to draw, the successors of each block are listed in a row at
+/// the bottom of its table.
+///
+/// The ids of the blocks, which the successors link to, start with
+/// `id_prefix`, so that several CFGs can be put on the same page.
+pub fn write_mir_cfg_html<W: Write>(mir: &Mir, id_prefix: &str, w: &mut W) -> io::Result<()> {
+    for (block, _) in mir.basic_blocks().iter_enumerated() {
+        write!(w, r#"<div id="{}bb{}" class="block">"#, id_prefix, block.index())?;
+        write_node_label(block, mir, w, 1, |_| Ok(()), |w| {
+            let terminator = mir[block].terminator();
+            let labels = terminator.kind.fmt_successor_labels();
+            if labels.is_empty() {
+                return Ok(());
+            }
+            write!(w, r#"<tr><td align="left">"#)?;
+            for (&target, label) in terminator.successors().iter().zip(labels) {
+                if label.is_empty() {
+                    write!(w, r##"-&gt; <a href="#{1}bb{0}">bb{0}</a><br/>"##,
+                           target.index(), id_prefix)?;
+                } else {
+                    write!(w, r##"{2}: <a href="#{1}bb{0}">bb{0}</a><br/>"##,
+                           target.index(), id_prefix, dot::escape_html(&label))?;
+                }
+            }
+            write!(w, "</td></tr>")
+        })?;
+        writeln!(w, "</div>")?;
     }
     Ok(())
 }
@@ -26,7 +26,16 @@ impl<'tcx> Mirror<'tcx> for &'tcx hir::Block {
             extent: cx.tcx.region_maps.node_extent(self.id),
             span: self.span,
             stmts: stmts,
-            expr: self.expr.to_ref()
+            expr: self.expr.to_ref(),
+            safety_mode: match self.rules {
+                hir::DefaultBlock |
+                hir::PushUnstableBlock |
+                hir::PopUnstableBlock => BlockSafety::Safe,
+                hir::UnsafeBlock(hir::UserProvided) => BlockSafety::ExplicitUnsafe(self.id),
+                hir::UnsafeBlock(hir::CompilerGenerated) => BlockSafety::BuiltinUnsafe,
+                hir::PushUnsafeBlock(..) => BlockSafety::PushUnsafe,
+                hir::PopUnsafeBlock(..) => BlockSafety::PopUnsafe,
+            }
         }
     }
 }
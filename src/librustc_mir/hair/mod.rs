@@ -34,6 +34,18 @@ pub struct Block<'tcx> {
     pub span: Span,
     pub stmts: Vec<StmtRef<'tcx>>,
     pub expr: Option<ExprRef<'tcx>>,
+    pub safety_mode: BlockSafety,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum BlockSafety {
+    Safe,
+    /// A user-written `unsafe` block.
+    ExplicitUnsafe(ast::NodeId),
+    /// An `unsafe` block generated by the compiler.
+    BuiltinUnsafe,
+    PushUnsafe,
+    PopUnsafe,
 }
 
 #[derive(Clone, Debug)]
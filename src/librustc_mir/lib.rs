@@ -45,6 +45,8 @@ extern crate rustc_const_eval;
 pub mod diagnostics;
 
 pub mod build;
+pub mod const_eval;
+pub mod dataflow;
 pub mod graphviz;
 mod hair;
 pub mod mir_map;
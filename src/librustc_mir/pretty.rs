@@ -9,6 +9,7 @@
 // except according to those terms.
 
 use build::{Location, ScopeAuxiliaryVec, ScopeId};
+use graphviz::write_mir_fn_graphviz;
 use rustc::hir;
 use rustc::hir::def_id::DefId;
 use rustc::mir::repr::*;
@@ -33,6 +34,9 @@ const ALIGN: usize = 40;
 /// rustc.node<node_id>.<pass_name>.<disambiguator>
 /// ```
 ///
+/// With `-Z dump-mir-graphviz`, a graphviz rendering of the CFG is written
+/// next to it, with a `.dot` extension.
+///
 /// Output from this function is controlled by passing `-Z dump-mir=<filter>`,
 /// where `<filter>` takes the following forms:
 ///
@@ -46,23 +50,62 @@ pub fn dump_mir<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                           src: MirSource,
                           mir: &Mir<'tcx>,
                           auxiliary: Option<&ScopeAuxiliaryVec>) {
+    if !dump_enabled(tcx, pass_name, src) {
+        return;
+    }
+
+    let node_id = src.item_id();
+    let node_path = tcx.item_path_str(tcx.map.local_def_id(node_id));
+    let file_path = dump_path(tcx, src, pass_name, disambiguator, "mir");
+    let _ = fs::File::create(&file_path).and_then(|mut file| {
+        try!(writeln!(file, "// MIR for `{}`", node_path));
+        try!(writeln!(file, "// node_id = {}", node_id));
+        try!(writeln!(file, "// pass_name = {}", pass_name));
+        try!(writeln!(file, "// disambiguator = {}", disambiguator));
+        try!(writeln!(file, ""));
+        try!(write_mir_fn(tcx, src, mir, &mut file, auxiliary));
+        Ok(())
+    });
+
+    if tcx.sess.opts.debugging_opts.dump_mir_graphviz {
+        let file_path = dump_path(tcx, src, pass_name, disambiguator, "dot");
+        let _ = fs::File::create(&file_path).and_then(|mut file| {
+            write_mir_fn_graphviz(tcx, node_id, mir, &mut file)
+        });
+    }
+}
+
+/// Returns whether `-Z dump-mir` asks for the MIR of `src` to be dumped
+/// around the pass `pass_name`.
+pub fn dump_enabled<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                              pass_name: &str,
+                              src: MirSource)
+                              -> bool {
     let filters = match tcx.sess.opts.debugging_opts.dump_mir {
-        None => return,
+        None => return false,
         Some(ref filters) => filters,
     };
     let node_id = src.item_id();
     let node_path = tcx.item_path_str(tcx.map.local_def_id(node_id));
-    let is_matched =
-        filters.split("&")
-               .any(|filter| {
-                   filter == "all" ||
-                       pass_name.contains(filter) ||
-                       node_path.contains(filter)
-               });
-    if !is_matched {
-        return;
-    }
+    filters.split("&")
+           .any(|filter| {
+               filter == "all" ||
+                   pass_name.contains(filter) ||
+                   node_path.contains(filter)
+           })
+}
 
+/// Returns the path of a dump file, inside of `-Z dump-mir-dir` if given:
+///
+/// ```text
+/// rustc.node<node_id>.<pass_name>.<disambiguator>.<extension>
+/// ```
+pub fn dump_path<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                           src: MirSource,
+                           pass_name: &str,
+                           disambiguator: &Display,
+                           extension: &str)
+                           -> PathBuf {
     let promotion_id = match src {
         MirSource::Promoted(_, id) => format!("-{:?}", id),
         _ => String::new()
@@ -73,18 +116,10 @@ pub fn dump_mir<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
         let p = Path::new(file_dir);
         file_path.push(p);
     };
-    let file_name = format!("rustc.node{}{}.{}.{}.mir",
-                            node_id, promotion_id, pass_name, disambiguator);
+    let file_name = format!("rustc.node{}{}.{}.{}.{}",
+                            src.item_id(), promotion_id, pass_name, disambiguator, extension);
     file_path.push(&file_name);
-    let _ = fs::File::create(&file_path).and_then(|mut file| {
-        try!(writeln!(file, "// MIR for `{}`", node_path));
-        try!(writeln!(file, "// node_id = {}", node_id));
-        try!(writeln!(file, "// pass_name = {}", pass_name));
-        try!(writeln!(file, "// disambiguator = {}", disambiguator));
-        try!(writeln!(file, ""));
-        try!(write_mir_fn(tcx, src, mir, &mut file, auxiliary));
-        Ok(())
-    });
+    file_path
 }
 
 /// Write out a human-readable textual representation for the given MIR.
@@ -0,0 +1,224 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Enforces the Rust effect system. Currently there is just one effect,
+//! `unsafe`.
+//!
+//! Dereferencing raw pointers, using mutable statics, inline assembly
+//! and calling unsafe functions are only allowed in visibility scopes
+//! that the MIR builder marked as unsafe. Every user `unsafe` block that
+//! permits one of these operations is recorded in `tcx.used_unsafe`,
+//! which the `unused_unsafe` lint consults afterwards.
+//!
+//! Closures are checked together with the MIR that creates them, in the
+//! context in which they are constructed. As with nested blocks, only the
+//! outermost `unsafe` block counts as used.
+
+use rustc::dep_graph::DepNode;
+use rustc::hir;
+use rustc::hir::def_id::DefId;
+use rustc::hir::map as hir_map;
+use rustc::mir::repr::*;
+use rustc::mir::mir_map::MirMap;
+use rustc::mir::transform::{MirMapPass, MirPassHook, Pass};
+use rustc::mir::visit::{LvalueContext, Visitor};
+use rustc::ty::{self, Ty, TyCtxt};
+use syntax_pos::Span;
+
+pub struct CheckUnsafety;
+
+impl Pass for CheckUnsafety {}
+
+impl<'tcx> MirMapPass<'tcx> for CheckUnsafety {
+    fn run_pass<'a>(&mut self,
+                    tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                    map: &mut MirMap<'tcx>,
+                    _hooks: &mut [Box<for<'s> MirPassHook<'s>>]) {
+        let _task = tcx.dep_graph.in_task(DepNode::EffectCheck);
+
+        for (&def_id, mir) in &map.map {
+            let id = tcx.map.as_local_node_id(def_id).unwrap();
+            if let hir_map::NodeExpr(&hir::Expr { node: hir::ExprClosure(..), .. }) =
+                    tcx.map.get(id) {
+                // Checked as part of the MIR creating the closure.
+                continue;
+            }
+
+            for violation in check_mir(tcx, map, mir, Safety::Safe) {
+                struct_span_err!(tcx.sess, violation.span, E0133,
+                                 "{} requires unsafe function or block",
+                                 violation.description)
+                    .span_label(violation.span,
+                                &format!("unsafe call requires unsafe function or block"))
+                    .emit();
+            }
+        }
+    }
+}
+
+/// An operation that requires an unsafe context.
+#[derive(Copy, Clone, PartialEq, Eq)]
+struct UnsafetyViolation {
+    span: Span,
+    description: &'static str,
+}
+
+/// Checks `mir`, whose body is placed in a context of `outer_safety`, and
+/// returns the operations in it which are not covered by an unsafe scope.
+fn check_mir<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                       map: &MirMap<'tcx>,
+                       mir: &Mir<'tcx>,
+                       outer_safety: Safety)
+                       -> Vec<UnsafetyViolation> {
+    let mut checker = UnsafetyChecker {
+        tcx: tcx,
+        map: map,
+        mir: mir,
+        outer_safety: outer_safety,
+        source_info: SourceInfo {
+            span: mir.span,
+            scope: ARGUMENT_VISIBILITY_SCOPE
+        },
+        violations: vec![],
+    };
+    checker.visit_mir(mir);
+    checker.violations
+}
+
+struct UnsafetyChecker<'a, 'b, 'tcx: 'a> {
+    tcx: TyCtxt<'a, 'tcx, 'tcx>,
+    map: &'b MirMap<'tcx>,
+    mir: &'b Mir<'tcx>,
+    outer_safety: Safety,
+    source_info: SourceInfo,
+    violations: Vec<UnsafetyViolation>,
+}
+
+impl<'a, 'b, 'tcx> UnsafetyChecker<'a, 'b, 'tcx> {
+    fn require_unsafe(&mut self, description: &'static str) {
+        let violation = UnsafetyViolation {
+            span: self.source_info.span,
+            description: description
+        };
+        self.register_violations(&[violation]);
+    }
+
+    /// The safety of the current scope.
+    fn safety(&self) -> Safety {
+        match (self.outer_safety, self.mir.visibility_scopes[self.source_info.scope].safety) {
+            (Safety::Safe, safety) |
+            (_, safety @ Safety::BuiltinUnsafe) => safety,
+            (outer_safety, _) => outer_safety
+        }
+    }
+
+    fn register_violations(&mut self, violations: &[UnsafetyViolation]) {
+        if violations.is_empty() {
+            return;
+        }
+        match self.safety() {
+            Safety::Safe => {
+                for &violation in violations {
+                    // A single expression, e.g. `STATIC += 1`, can be
+                    // lowered to several uses of the same lvalue.
+                    if !self.violations.contains(&violation) {
+                        self.violations.push(violation);
+                    }
+                }
+            }
+            Safety::BuiltinUnsafe | Safety::FnUnsafe => {}
+            Safety::ExplicitUnsafe(block_id) => {
+                debug!("check_unsafety: recording unsafe block as used: {}", block_id);
+                self.tcx.used_unsafe.borrow_mut().insert(block_id);
+            }
+        }
+    }
+
+    fn is_static_mut(&self, def_id: DefId) -> bool {
+        let id = match self.tcx.map.as_local_node_id(def_id) {
+            Some(id) => id,
+            None => return self.tcx.sess.cstore.is_static_mut(def_id)
+        };
+        match self.tcx.map.get(id) {
+            hir_map::NodeItem(&hir::Item {
+                node: hir::ItemStatic(_, hir::MutMutable, _), ..
+            }) => true,
+            hir_map::NodeForeignItem(&hir::ForeignItem {
+                node: hir::ForeignItemStatic(_, mutbl), ..
+            }) => mutbl,
+            _ => false
+        }
+    }
+}
+
+fn type_is_unsafe_function(ty: Ty) -> bool {
+    match ty.sty {
+        ty::TyFnDef(_, _, ref f) |
+        ty::TyFnPtr(ref f) => f.unsafety == hir::Unsafety::Unsafe,
+        _ => false,
+    }
+}
+
+impl<'a, 'b, 'tcx> Visitor<'tcx> for UnsafetyChecker<'a, 'b, 'tcx> {
+    fn visit_statement(&mut self, block: BasicBlock, statement: &Statement<'tcx>) {
+        self.source_info = statement.source_info;
+        self.super_statement(block, statement);
+    }
+
+    fn visit_terminator(&mut self, block: BasicBlock, terminator: &Terminator<'tcx>) {
+        self.source_info = terminator.source_info;
+        if let TerminatorKind::Call { ref func, .. } = terminator.kind {
+            let func_ty = func.ty(self.mir, self.tcx);
+            debug!("check_unsafety: call case, func type is {:?}", func_ty);
+            if type_is_unsafe_function(func_ty) {
+                self.require_unsafe("call to unsafe function");
+            }
+        }
+        self.super_terminator(block, terminator);
+    }
+
+    fn visit_rvalue(&mut self, rvalue: &Rvalue<'tcx>) {
+        match *rvalue {
+            Rvalue::InlineAsm { .. } => {
+                self.require_unsafe("use of inline assembly");
+            }
+            Rvalue::Aggregate(AggregateKind::Closure(def_id, _), _) => {
+                if let Some(closure_mir) = self.map.map.get(&def_id) {
+                    let safety = self.safety();
+                    let violations = check_mir(self.tcx, self.map, closure_mir, safety);
+                    self.register_violations(&violations);
+                }
+            }
+            _ => {}
+        }
+        self.super_rvalue(rvalue);
+    }
+
+    fn visit_lvalue(&mut self, lvalue: &Lvalue<'tcx>, context: LvalueContext) {
+        match *lvalue {
+            Lvalue::Projection(ref proj) => {
+                if let ProjectionElem::Deref = proj.elem {
+                    let base_ty = proj.base.ty(self.mir, self.tcx).to_ty(self.tcx);
+                    debug!("check_unsafety: deref case, base type is {:?}", base_ty);
+                    if let ty::TyRawPtr(_) = base_ty.sty {
+                        self.require_unsafe("dereference of raw pointer");
+                    }
+                }
+            }
+            Lvalue::Static(def_id) => {
+                if self.is_static_mut(def_id) {
+                    self.require_unsafe("use of mutable static");
+                }
+            }
+            _ => {}
+        }
+        self.super_lvalue(lvalue, context);
+    }
+}
@@ -0,0 +1,434 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A pass that propagates and folds constants.
+//!
+//! Only variables and temporaries that are assigned exactly once, and
+//! whose address is never taken, are considered. For those, the
+//! assigned rvalue is evaluated whenever all of its operands are known
+//! constants, and every use of the local is then replaced by the
+//! resulting constant. Arithmetic that would overflow or divide by zero
+//! is left untouched, so the runtime checks still fire.
+//!
+//! Switches on a local whose value (or, for enums, whose variant) is
+//! known are turned into gotos; `If` and `Assert` terminators whose
+//! condition became constant are left for `SimplifyBranches`, which is
+//! expected to run right after this pass.
+
+use rustc::middle::const_val::ConstVal;
+use rustc::ty::{Ty, TyCtxt};
+use rustc::mir::repr::*;
+use rustc::mir::transform::{MirPass, MirSource, Pass};
+use rustc::mir::visit::{LvalueContext, MutVisitor, Visitor};
+use rustc_data_structures::indexed_vec::{Idx, IndexVec};
+use syntax_pos::Span;
+
+use std::cmp::Ordering;
+
+pub struct ConstPropagation;
+
+impl Pass for ConstPropagation {}
+
+impl<'tcx> MirPass<'tcx> for ConstPropagation {
+    fn run_pass<'a>(&mut self, tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                    _src: MirSource, mir: &mut Mir<'tcx>) {
+        // we only run when mir_opt_level > 1
+        if tcx.sess.opts.mir_opt_level <= 1 {
+            return;
+        }
+
+        let facts = {
+            let candidates = find_candidates(mir);
+            evaluate(mir, &candidates)
+        };
+        if facts.iter().all(|fact| fact.is_none()) {
+            return;
+        }
+
+        let mut propagator = Propagator {
+            local_indices: LocalIndices::new(mir),
+            facts: &facts,
+        };
+        for (bb, data) in mir.basic_blocks_mut().iter_enumerated_mut() {
+            propagator.visit_basic_block_data(bb, data);
+        }
+
+        let local_indices = LocalIndices::new(mir);
+        for block in mir.basic_blocks_mut() {
+            let terminator = block.terminator_mut();
+            terminator.kind = match terminator.kind {
+                TerminatorKind::Switch { ref discr, ref targets, .. } => {
+                    match local_indices.fact(&facts, discr) {
+                        Some(&Fact { value: Known::Variant(variant), .. }) => {
+                            TerminatorKind::Goto { target: targets[variant] }
+                        }
+                        _ => continue
+                    }
+                }
+                TerminatorKind::SwitchInt { ref discr, ref values, ref targets, .. } => {
+                    match local_indices.fact(&facts, discr) {
+                        Some(&Fact { value: Known::Value(ref value), .. }) => {
+                            // The last target is the `otherwise` branch.
+                            let index = values.iter()
+                                              .position(|v| v == value)
+                                              .unwrap_or(values.len());
+                            TerminatorKind::Goto { target: targets[index] }
+                        }
+                        _ => continue
+                    }
+                }
+                _ => continue
+            };
+        }
+    }
+}
+
+/// What is statically known about the single value a local ever holds.
+#[derive(Clone, Debug)]
+enum Known {
+    /// The local holds this scalar constant.
+    Value(ConstVal),
+
+    /// The local holds the `(value, false)` pair produced by a non-overflowing
+    /// `CheckedBinaryOp`.
+    Checked(ConstVal),
+
+    /// The local holds an enum value of this variant.
+    Variant(usize),
+}
+
+#[derive(Clone, Debug)]
+struct Fact<'tcx> {
+    value: Known,
+    ty: Ty<'tcx>,
+    span: Span,
+}
+
+/// Maps the `Var`s and `Temp`s of a Mir onto `Local` indices. Arguments and
+/// the return pointer are deliberately left out, since their values are
+/// produced or observed outside of the function.
+struct LocalIndices {
+    num_args: usize,
+    num_vars: usize,
+}
+
+impl LocalIndices {
+    fn new(mir: &Mir) -> LocalIndices {
+        LocalIndices {
+            num_args: mir.arg_decls.len(),
+            num_vars: mir.var_decls.len(),
+        }
+    }
+
+    fn index(&self, lvalue: &Lvalue) -> Option<Local> {
+        match *lvalue {
+            Lvalue::Var(var) => Some(Local::new(self.num_args + var.index())),
+            Lvalue::Temp(temp) => {
+                Some(Local::new(self.num_args + self.num_vars + temp.index()))
+            }
+            _ => None
+        }
+    }
+
+    fn fact<'a, 'tcx>(&self, facts: &'a IndexVec<Local, Option<Fact<'tcx>>>,
+                      lvalue: &Lvalue<'tcx>) -> Option<&'a Fact<'tcx>> {
+        self.index(lvalue).and_then(|local| facts[local].as_ref())
+    }
+}
+
+/// Returns the local at the root of a (possibly projected) lvalue.
+fn base_local(indices: &LocalIndices, lvalue: &Lvalue) -> Option<Local> {
+    match *lvalue {
+        Lvalue::Projection(ref proj) => base_local(indices, &proj.base),
+        _ => indices.index(lvalue),
+    }
+}
+
+struct CandidateFinder {
+    local_indices: LocalIndices,
+    defs: IndexVec<Local, usize>,
+    escaping: IndexVec<Local, bool>,
+}
+
+impl<'tcx> Visitor<'tcx> for CandidateFinder {
+    fn visit_lvalue(&mut self, lvalue: &Lvalue<'tcx>, context: LvalueContext) {
+        match context {
+            LvalueContext::Store |
+            LvalueContext::Call => {
+                if let Some(local) = self.local_indices.index(lvalue) {
+                    self.defs[local] += 1;
+                } else if let Some(local) = base_local(&self.local_indices, lvalue) {
+                    // Partially overwritten.
+                    self.escaping[local] = true;
+                }
+            }
+            LvalueContext::Borrow { .. } |
+            LvalueContext::Slice { .. } |
+            LvalueContext::Drop => {
+                // Borrows may be used to mutate the local behind our back, and
+                // `DropAndReplace` is an assignment in disguise.
+                if let Some(local) = base_local(&self.local_indices, lvalue) {
+                    self.escaping[local] = true;
+                }
+            }
+            LvalueContext::Inspect |
+            LvalueContext::Projection |
+            LvalueContext::Consume |
+            LvalueContext::StorageLive |
+            LvalueContext::StorageDead => {}
+        }
+        self.super_lvalue(lvalue, context);
+    }
+}
+
+/// Finds the locals that are assigned exactly once and never borrowed.
+fn find_candidates(mir: &Mir) -> IndexVec<Local, bool> {
+    let num_locals = mir.count_locals();
+    let mut finder = CandidateFinder {
+        local_indices: LocalIndices::new(mir),
+        defs: IndexVec::from_elem_n(0, num_locals),
+        escaping: IndexVec::from_elem_n(false, num_locals),
+    };
+    finder.visit_mir(mir);
+    finder.defs.iter().zip(finder.escaping.iter())
+          .map(|(&defs, &escaping)| defs == 1 && !escaping)
+          .collect()
+}
+
+/// Evaluates the assignments of all candidate locals until no more values
+/// can be determined.
+fn evaluate<'tcx>(mir: &Mir<'tcx>, candidates: &IndexVec<Local, bool>)
+                  -> IndexVec<Local, Option<Fact<'tcx>>> {
+    let local_indices = LocalIndices::new(mir);
+    let mut facts = IndexVec::from_elem_n(None, mir.count_locals());
+    loop {
+        let mut changed = false;
+        for data in mir.basic_blocks() {
+            for statement in &data.statements {
+                let (lvalue, rvalue) = match statement.kind {
+                    StatementKind::Assign(ref lvalue, ref rvalue) => (lvalue, rvalue),
+                    _ => continue
+                };
+                let local = match local_indices.index(lvalue) {
+                    Some(local) if candidates[local] && facts[local].is_none() => local,
+                    _ => continue
+                };
+                let ty = match *lvalue {
+                    Lvalue::Var(var) => mir.var_decls[var].ty,
+                    Lvalue::Temp(temp) => mir.temp_decls[temp].ty,
+                    _ => bug!("unexpected candidate {:?}", lvalue)
+                };
+                if let Some(value) = eval_rvalue(&local_indices, &facts, rvalue) {
+                    debug!("const_prop: {:?} = {:?}", lvalue, value);
+                    facts[local] = Some(Fact {
+                        value: value,
+                        ty: ty,
+                        span: statement.source_info.span,
+                    });
+                    changed = true;
+                }
+            }
+        }
+        if !changed {
+            return facts;
+        }
+    }
+}
+
+fn eval_operand<'tcx>(local_indices: &LocalIndices,
+                      facts: &IndexVec<Local, Option<Fact<'tcx>>>,
+                      operand: &Operand<'tcx>)
+                      -> Option<ConstVal> {
+    match *operand {
+        Operand::Constant(Constant { literal: Literal::Value { ref value }, .. }) => {
+            match *value {
+                ConstVal::Integral(_) |
+                ConstVal::Bool(_) |
+                ConstVal::Char(_) => Some(value.clone()),
+                _ => None
+            }
+        }
+        Operand::Constant(_) => None,
+        Operand::Consume(Lvalue::Projection(ref proj)) => {
+            match (local_indices.fact(facts, &proj.base), &proj.elem) {
+                (Some(&Fact { value: Known::Checked(ref value), .. }),
+                 &ProjectionElem::Field(field, _)) => {
+                    match field.index() {
+                        0 => Some(value.clone()),
+                        1 => Some(ConstVal::Bool(false)),
+                        _ => None
+                    }
+                }
+                _ => None
+            }
+        }
+        Operand::Consume(ref lvalue) => {
+            match local_indices.fact(facts, lvalue) {
+                Some(&Fact { value: Known::Value(ref value), .. }) => Some(value.clone()),
+                _ => None
+            }
+        }
+    }
+}
+
+fn eval_rvalue<'tcx>(local_indices: &LocalIndices,
+                     facts: &IndexVec<Local, Option<Fact<'tcx>>>,
+                     rvalue: &Rvalue<'tcx>)
+                     -> Option<Known> {
+    let operand = |op: &Operand<'tcx>| eval_operand(local_indices, facts, op);
+    match *rvalue {
+        Rvalue::Use(ref op) => operand(op).map(Known::Value),
+        Rvalue::BinaryOp(op, ref lhs, ref rhs) => {
+            match (operand(lhs), operand(rhs)) {
+                (Some(lhs), Some(rhs)) => eval_binop(op, lhs, rhs).map(Known::Value),
+                _ => None
+            }
+        }
+        Rvalue::CheckedBinaryOp(op, ref lhs, ref rhs) => {
+            // Overflow makes `eval_binop` bail out, so a result always
+            // means the overflow flag is false.
+            match (operand(lhs), operand(rhs)) {
+                (Some(lhs), Some(rhs)) => eval_binop(op, lhs, rhs).map(Known::Checked),
+                _ => None
+            }
+        }
+        Rvalue::UnaryOp(op, ref arg) => {
+            let value = match (op, operand(arg)) {
+                (UnOp::Not, Some(ConstVal::Bool(b))) => Some(ConstVal::Bool(!b)),
+                (UnOp::Not, Some(ConstVal::Integral(i))) => (!i).ok().map(ConstVal::Integral),
+                (UnOp::Neg, Some(ConstVal::Integral(i))) => (-i).ok().map(ConstVal::Integral),
+                _ => None
+            };
+            value.map(Known::Value)
+        }
+        Rvalue::Aggregate(AggregateKind::Adt(_, variant, _), _) => {
+            Some(Known::Variant(variant))
+        }
+        Rvalue::Repeat(..) |
+        Rvalue::Ref(..) |
+        Rvalue::Len(..) |
+        Rvalue::Cast(..) |
+        Rvalue::Box(..) |
+        Rvalue::Aggregate(..) |
+        Rvalue::InlineAsm { .. } => None
+    }
+}
+
+fn eval_binop(op: BinOp, lhs: ConstVal, rhs: ConstVal) -> Option<ConstVal> {
+    use rustc::middle::const_val::ConstVal::*;
+    match (lhs, rhs) {
+        (Integral(a), Integral(b)) => {
+            let result = match op {
+                BinOp::Add => a + b,
+                BinOp::Sub => a - b,
+                BinOp::Mul => a * b,
+                BinOp::Div => a / b,
+                BinOp::Rem => a % b,
+                BinOp::BitXor => a ^ b,
+                BinOp::BitAnd => a & b,
+                BinOp::BitOr => a | b,
+                BinOp::Shl => a << b,
+                BinOp::Shr => a >> b,
+                BinOp::Eq | BinOp::Lt | BinOp::Le |
+                BinOp::Ne | BinOp::Ge | BinOp::Gt => {
+                    return a.try_cmp(b).ok().and_then(|ord| compare(op, ord));
+                }
+            };
+            result.ok().map(Integral)
+        }
+        (Bool(a), Bool(b)) => {
+            match op {
+                BinOp::BitXor => Some(Bool(a ^ b)),
+                BinOp::BitAnd => Some(Bool(a & b)),
+                BinOp::BitOr => Some(Bool(a | b)),
+                _ => compare(op, a.cmp(&b))
+            }
+        }
+        (Char(a), Char(b)) => compare(op, a.cmp(&b)),
+        _ => None
+    }
+}
+
+fn compare(op: BinOp, ord: Ordering) -> Option<ConstVal> {
+    Some(ConstVal::Bool(match op {
+        BinOp::Eq => ord == Ordering::Equal,
+        BinOp::Ne => ord != Ordering::Equal,
+        BinOp::Lt => ord == Ordering::Less,
+        BinOp::Le => ord != Ordering::Greater,
+        BinOp::Gt => ord == Ordering::Greater,
+        BinOp::Ge => ord != Ordering::Less,
+        _ => return None
+    }))
+}
+
+/// Replaces uses of locals with known values by constants.
+struct Propagator<'a, 'tcx: 'a> {
+    local_indices: LocalIndices,
+    facts: &'a IndexVec<Local, Option<Fact<'tcx>>>,
+}
+
+impl<'a, 'tcx> Propagator<'a, 'tcx> {
+    fn constant_for(&self, lvalue: &Lvalue<'tcx>) -> Option<Constant<'tcx>> {
+        let (value, ty, span) = match *lvalue {
+            Lvalue::Projection(ref proj) => {
+                let fact = match self.local_indices.fact(self.facts, &proj.base) {
+                    Some(fact) => fact,
+                    None => return None
+                };
+                match (&fact.value, &proj.elem) {
+                    (&Known::Checked(ref value), &ProjectionElem::Field(field, ty)) => {
+                        match field.index() {
+                            0 => (value.clone(), ty, fact.span),
+                            1 => (ConstVal::Bool(false), ty, fact.span),
+                            _ => return None
+                        }
+                    }
+                    _ => return None
+                }
+            }
+            _ => {
+                match self.local_indices.fact(self.facts, lvalue) {
+                    Some(&Fact { value: Known::Value(ref value), ty, span }) => {
+                        (value.clone(), ty, span)
+                    }
+                    _ => return None
+                }
+            }
+        };
+        Some(Constant {
+            span: span,
+            ty: ty,
+            literal: Literal::Value { value: value },
+        })
+    }
+}
+
+impl<'a, 'tcx> MutVisitor<'tcx> for Propagator<'a, 'tcx> {
+    fn visit_assign(&mut self,
+                    block: BasicBlock,
+                    lvalue: &mut Lvalue<'tcx>,
+                    rvalue: &mut Rvalue<'tcx>) {
+        if let Some(constant) = self.constant_for(lvalue) {
+            *rvalue = Rvalue::Use(Operand::Constant(constant));
+        }
+        self.super_assign(block, lvalue, rvalue);
+    }
+
+    fn visit_operand(&mut self, operand: &mut Operand<'tcx>) {
+        let constant = match *operand {
+            Operand::Consume(ref lvalue) => self.constant_for(lvalue),
+            Operand::Constant(_) => None,
+        };
+        match constant {
+            Some(constant) => *operand = Operand::Constant(constant),
+            None => self.super_operand(operand),
+        }
+    }
+}
@@ -0,0 +1,318 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A pass that coalesces temporaries which are only used to move a value
+//! into another local.
+//!
+//! MIR construction frequently produces code like:
+//!
+//! ```text
+//! tmp0 = foo(arg0) -> bb1;
+//!
+//! bb1: {
+//!     StorageLive(var0);
+//!     var0 = tmp0;
+//!     ...
+//! ```
+//!
+//! or the same with an assignment statement instead of a call. When `tmp0`
+//! is assigned and used exactly once, and nothing but storage markers sit
+//! between the two, the value can be written into `var0` directly. Storage
+//! markers for the destination are moved along with the write.
+
+use rustc::ty::TyCtxt;
+use rustc::mir::repr::*;
+use rustc::mir::transform::{MirPass, MirSource, Pass};
+use rustc::mir::visit::{LvalueContext, Visitor};
+use rustc_data_structures::indexed_vec::IndexVec;
+
+pub struct CopyPropagation;
+
+impl Pass for CopyPropagation {}
+
+impl<'tcx> MirPass<'tcx> for CopyPropagation {
+    fn run_pass<'a>(&mut self, tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                    _src: MirSource, mir: &mut Mir<'tcx>) {
+        // we only run when mir_opt_level > 1
+        if tcx.sess.opts.mir_opt_level <= 1 {
+            return;
+        }
+
+        let mut counter = TempUseCounter {
+            defs: IndexVec::from_elem(0, &mir.temp_decls),
+            uses: IndexVec::from_elem(0, &mir.temp_decls),
+        };
+        counter.visit_mir(mir);
+
+        for temp in mir.temp_decls.indices() {
+            if counter.defs[temp] != 1 || counter.uses[temp] != 1 {
+                continue;
+            }
+            let (bb, use_index, dest) = match find_move_out(mir, temp) {
+                Some(move_out) => move_out,
+                None => continue
+            };
+            // A borrow of the destination may be live across the write, e.g.
+            // in `a = f(&a)` the callee would be writing its return value
+            // straight into memory it can still see through its argument.
+            if is_borrowed(mir, &dest) {
+                continue;
+            }
+            if !propagate_from_statement(mir, temp, bb, use_index, &dest) {
+                propagate_from_call(mir, temp, bb, use_index, &dest);
+            }
+        }
+    }
+}
+
+/// Counts definitions and uses of temporaries; storage markers are ignored.
+struct TempUseCounter {
+    defs: IndexVec<Temp, usize>,
+    uses: IndexVec<Temp, usize>,
+}
+
+impl<'tcx> Visitor<'tcx> for TempUseCounter {
+    fn visit_lvalue(&mut self, lvalue: &Lvalue<'tcx>, context: LvalueContext) {
+        if let Lvalue::Temp(temp) = *lvalue {
+            match context {
+                LvalueContext::Store |
+                LvalueContext::Call => self.defs[temp] += 1,
+                LvalueContext::StorageLive |
+                LvalueContext::StorageDead => {}
+                LvalueContext::Drop |
+                LvalueContext::Inspect |
+                LvalueContext::Borrow { .. } |
+                LvalueContext::Slice { .. } |
+                LvalueContext::Projection |
+                LvalueContext::Consume => self.uses[temp] += 1,
+            }
+        }
+        self.super_lvalue(lvalue, context);
+    }
+}
+
+/// Checks whether an lvalue is mentioned anywhere within a MIR fragment.
+struct LvalueFinder<'a, 'tcx: 'a> {
+    target: &'a Lvalue<'tcx>,
+    found: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for LvalueFinder<'a, 'tcx> {
+    fn visit_lvalue(&mut self, lvalue: &Lvalue<'tcx>, context: LvalueContext) {
+        if lvalue == self.target {
+            self.found = true;
+        }
+        self.super_lvalue(lvalue, context);
+    }
+}
+
+/// Checks whether an lvalue, or any part of it, is borrowed anywhere.
+struct BorrowFinder<'a, 'tcx: 'a> {
+    target: &'a Lvalue<'tcx>,
+    found: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for BorrowFinder<'a, 'tcx> {
+    fn visit_lvalue(&mut self, lvalue: &Lvalue<'tcx>, context: LvalueContext) {
+        match context {
+            LvalueContext::Borrow { .. } |
+            LvalueContext::Slice { .. } => {
+                let mut base = lvalue;
+                while let Lvalue::Projection(ref proj) = *base {
+                    base = &proj.base;
+                }
+                if base == self.target {
+                    self.found = true;
+                }
+            }
+            _ => {}
+        }
+        self.super_lvalue(lvalue, context);
+    }
+}
+
+fn is_borrowed<'tcx>(mir: &Mir<'tcx>, lvalue: &Lvalue<'tcx>) -> bool {
+    let mut finder = BorrowFinder { target: lvalue, found: false };
+    finder.visit_mir(mir);
+    finder.found
+}
+
+fn rvalue_mentions<'tcx>(rvalue: &Rvalue<'tcx>, lvalue: &Lvalue<'tcx>) -> bool {
+    let mut finder = LvalueFinder { target: lvalue, found: false };
+    finder.visit_rvalue(rvalue);
+    finder.found
+}
+
+fn operands_mention<'tcx>(operands: &[&Operand<'tcx>], lvalue: &Lvalue<'tcx>) -> bool {
+    let mut finder = LvalueFinder { target: lvalue, found: false };
+    for operand in operands {
+        finder.visit_operand(operand);
+    }
+    finder.found
+}
+
+/// Finds the `dest = temp` statement moving `temp` into another local.
+fn find_move_out<'tcx>(mir: &Mir<'tcx>, temp: Temp)
+                       -> Option<(BasicBlock, usize, Lvalue<'tcx>)> {
+    for (bb, data) in mir.basic_blocks().iter_enumerated() {
+        for (index, statement) in data.statements.iter().enumerate() {
+            match statement.kind {
+                StatementKind::Assign(ref dest,
+                                      Rvalue::Use(Operand::Consume(Lvalue::Temp(src))))
+                    if src == temp => {
+                    return match *dest {
+                        Lvalue::Var(_) |
+                        Lvalue::Temp(_) |
+                        Lvalue::ReturnPointer => Some((bb, index, dest.clone())),
+                        Lvalue::Arg(_) |
+                        Lvalue::Static(_) |
+                        Lvalue::Projection(_) => None
+                    };
+                }
+                _ => {}
+            }
+        }
+    }
+    None
+}
+
+/// Whether the statements between a definition and its move-out are all
+/// storage markers that the move can be performed across. Markers for the
+/// destination are only allowed if `allow_dest_live` is set.
+fn only_storage_markers<'tcx, F>(statements: &[Statement<'tcx>],
+                                 dest: &Lvalue<'tcx>,
+                                 allow_dest_live: bool,
+                                 mut is_operand: F)
+                                 -> bool
+    where F: FnMut(&Lvalue<'tcx>) -> bool
+{
+    statements.iter().all(|statement| {
+        match statement.kind {
+            StatementKind::StorageLive(ref lvalue) if lvalue == dest => allow_dest_live,
+            StatementKind::StorageLive(ref lvalue) |
+            StatementKind::StorageDead(ref lvalue) => lvalue != dest && !is_operand(lvalue),
+            StatementKind::Assign(..) |
+            StatementKind::SetDiscriminant { .. } => false
+        }
+    })
+}
+
+/// Handles `temp = rvalue; ...; dest = temp;` within a single block by
+/// replacing the move with `dest = rvalue`.
+fn propagate_from_statement<'tcx>(mir: &mut Mir<'tcx>,
+                                  temp: Temp,
+                                  bb: BasicBlock,
+                                  use_index: usize,
+                                  dest: &Lvalue<'tcx>)
+                                  -> bool {
+    let def_index = {
+        let statements = &mir[bb].statements;
+        let def_index = statements[..use_index].iter().position(|statement| {
+            match statement.kind {
+                StatementKind::Assign(Lvalue::Temp(t), _) => t == temp,
+                _ => false
+            }
+        });
+        let def_index = match def_index {
+            Some(def_index) => def_index,
+            None => return false
+        };
+        let rvalue = match statements[def_index].kind {
+            StatementKind::Assign(_, ref rvalue) => rvalue,
+            _ => bug!()
+        };
+        if rvalue_mentions(rvalue, dest) {
+            return false;
+        }
+        // The rvalue is now evaluated at the move, so none of its
+        // operands may change storage in between.
+        let between = &statements[def_index + 1..use_index];
+        if !only_storage_markers(between, dest, true, |lv| rvalue_mentions(rvalue, lv)) {
+            return false;
+        }
+        def_index
+    };
+
+    debug!("copy_prop: propagating {:?} into {:?} in {:?}", temp, dest, bb);
+    let statements = &mut mir[bb].statements;
+    let def = statements.remove(def_index);
+    let rvalue = match def.kind {
+        StatementKind::Assign(_, rvalue) => rvalue,
+        _ => bug!()
+    };
+    // The move-out moved up by one.
+    statements[use_index - 1].kind = StatementKind::Assign(dest.clone(), rvalue);
+    true
+}
+
+/// Handles `temp = f(...) -> bb; bb: ...; dest = temp;` by making `dest` the
+/// destination of the call. The callee writes its destination while its
+/// arguments are still live, so to stay conservative only other temporaries
+/// are made call destinations.
+fn propagate_from_call<'tcx>(mir: &mut Mir<'tcx>,
+                             temp: Temp,
+                             bb: BasicBlock,
+                             use_index: usize,
+                             dest: &Lvalue<'tcx>)
+                             -> bool {
+    match *dest {
+        Lvalue::Temp(_) => {}
+        _ => return false
+    }
+    let pred = {
+        let preds = mir.predecessors_for(bb);
+        if preds.len() != 1 {
+            return false;
+        }
+        preds[0]
+    };
+    match mir[pred].terminator().kind {
+        TerminatorKind::Call {
+            ref func, ref args, destination: Some((Lvalue::Temp(t), target)), ..
+        } if t == temp && target == bb => {
+            let mut operands = vec![func];
+            operands.extend(args);
+            if operands_mention(&operands, dest) {
+                return false;
+            }
+        }
+        _ => return false
+    }
+    // The call's operands were evaluated before any of these markers.
+    if !only_storage_markers(&mir[bb].statements[..use_index], dest, true, |_| false) {
+        return false;
+    }
+
+    debug!("copy_prop: making {:?} the destination of the call in {:?}", dest, pred);
+    let mut hoisted = vec![];
+    {
+        let statements = &mut mir[bb].statements;
+        statements.remove(use_index);
+        // Storage for the destination has to be live before the call writes it.
+        for index in (0..use_index).rev() {
+            let is_dest_live = match statements[index].kind {
+                StatementKind::StorageLive(ref lvalue) => lvalue == dest,
+                _ => false
+            };
+            if is_dest_live {
+                hoisted.push(statements.remove(index));
+            }
+        }
+    }
+    let pred_data = &mut mir[pred];
+    pred_data.statements.extend(hoisted);
+    match pred_data.terminator_mut().kind {
+        TerminatorKind::Call { destination: Some((ref mut lvalue, _)), .. } => {
+            *lvalue = dest.clone();
+        }
+        _ => bug!()
+    }
+    true
+}
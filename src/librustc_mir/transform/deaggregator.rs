@@ -8,11 +8,10 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use rustc::ty::TyCtxt;
+use rustc::ty::{self, Ty, TyCtxt};
 use rustc::mir::repr::*;
 use rustc::mir::transform::{MirPass, MirSource, Pass};
 use rustc_data_structures::indexed_vec::Idx;
-use rustc::ty::VariantKind;
 
 pub struct Deaggregator;
 
@@ -25,84 +24,107 @@ impl<'tcx> MirPass<'tcx> for Deaggregator {
         let node_path = tcx.item_path_str(tcx.map.local_def_id(node_id));
         debug!("running on: {:?}", node_path);
         // we only run when mir_opt_level > 1
-        match tcx.sess.opts.debugging_opts.mir_opt_level {
-            Some(0) |
-            Some(1) |
-            None => { return; },
-            _ => {}
-        };
+        if tcx.sess.opts.mir_opt_level <= 1 {
+            return;
+        }
 
         // Do not trigger on constants.  Could be revised in future
         if let MirSource::Fn(_) = source {} else { return; }
         // In fact, we might not want to trigger in other cases.
         // Ex: when we could use SROA.  See issue #35259
 
-        let mut curr: usize = 0;
-        for bb in mir.basic_blocks_mut() {
-            let idx = match get_aggregate_statement_index(curr, &bb.statements) {
-                Some(idx) => idx,
-                None => continue,
-            };
-            // do the replacement
-            debug!("removing statement {:?}", idx);
-            let src_info = bb.statements[idx].source_info;
-            let suffix_stmts = bb.statements.split_off(idx+1);
-            let orig_stmt = bb.statements.pop().unwrap();
-            let (lhs, rhs) = match orig_stmt.kind {
-                StatementKind::Assign(ref lhs, ref rhs) => (lhs, rhs),
-                _ => span_bug!(src_info.span, "expected assign, not {:?}", orig_stmt),
-            };
-            let (agg_kind, operands) = match rhs {
-                &Rvalue::Aggregate(ref agg_kind, ref operands) => (agg_kind, operands),
-                _ => span_bug!(src_info.span, "expected aggregate, not {:?}", rhs),
-            };
-            let (adt_def, variant, substs) = match agg_kind {
-                &AggregateKind::Adt(adt_def, variant, substs) => (adt_def, variant, substs),
-                _ => span_bug!(src_info.span, "expected struct, not {:?}", rhs),
-            };
-            let n = bb.statements.len();
-            bb.statements.reserve(n + operands.len() + suffix_stmts.len());
-            for (i, op) in operands.iter().enumerate() {
-                let ref variant_def = adt_def.variants[variant];
-                let ty = variant_def.fields[i].ty(tcx, substs);
-                let rhs = Rvalue::Use(op.clone());
-
-                let lhs_cast = if adt_def.variants.len() > 1 {
-                    Lvalue::Projection(Box::new(LvalueProjection {
-                        base: lhs.clone(),
-                        elem: ProjectionElem::Downcast(adt_def, variant),
-                    }))
-                } else {
-                    lhs.clone()
+        for bb in mir.basic_blocks().indices() {
+            let mut curr: usize = 0;
+            while let Some(idx) = get_aggregate_statement_index(curr, &mir[bb].statements) {
+                // do the replacement
+                debug!("removing statement {:?}", idx);
+                let field_tys = aggregate_field_tys(tcx, mir, &mir[bb].statements[idx]);
+                let bb = &mut mir[bb];
+                let src_info = bb.statements[idx].source_info;
+                let suffix_stmts = bb.statements.split_off(idx+1);
+                let orig_stmt = bb.statements.pop().unwrap();
+                let (lhs, rhs) = match orig_stmt.kind {
+                    StatementKind::Assign(ref lhs, ref rhs) => (lhs, rhs),
+                    _ => span_bug!(src_info.span, "expected assign, not {:?}", orig_stmt),
                 };
-
-                let lhs_proj = Lvalue::Projection(Box::new(LvalueProjection {
-                    base: lhs_cast,
-                    elem: ProjectionElem::Field(Field::new(i), ty),
-                }));
-                let new_statement = Statement {
-                    source_info: src_info,
-                    kind: StatementKind::Assign(lhs_proj, rhs),
+                let (agg_kind, operands) = match rhs {
+                    &Rvalue::Aggregate(ref agg_kind, ref operands) => (agg_kind, operands),
+                    _ => span_bug!(src_info.span, "expected aggregate, not {:?}", rhs),
                 };
-                debug!("inserting: {:?} @ {:?}", new_statement, idx + i);
-                bb.statements.push(new_statement);
-            }
+                // For enums, the fields are those of the constructed variant.
+                let downcast = match agg_kind {
+                    &AggregateKind::Adt(adt_def, variant, _) if adt_def.variants.len() > 1 => {
+                        Some((adt_def, variant))
+                    }
+                    &AggregateKind::Adt(..) | &AggregateKind::Tuple => None,
+                    _ => span_bug!(src_info.span, "expected struct or tuple, not {:?}", rhs),
+                };
+                let n = bb.statements.len();
+                bb.statements.reserve(n + operands.len() + suffix_stmts.len());
+                for (i, (op, ty)) in operands.iter().zip(field_tys).enumerate() {
+                    let rhs = Rvalue::Use(op.clone());
+
+                    let lhs_cast = if let Some((adt_def, variant)) = downcast {
+                        Lvalue::Projection(Box::new(LvalueProjection {
+                            base: lhs.clone(),
+                            elem: ProjectionElem::Downcast(adt_def, variant),
+                        }))
+                    } else {
+                        lhs.clone()
+                    };
+
+                    let lhs_proj = Lvalue::Projection(Box::new(LvalueProjection {
+                        base: lhs_cast,
+                        elem: ProjectionElem::Field(Field::new(i), ty),
+                    }));
+                    let new_statement = Statement {
+                        source_info: src_info,
+                        kind: StatementKind::Assign(lhs_proj, rhs),
+                    };
+                    debug!("inserting: {:?} @ {:?}", new_statement, idx + i);
+                    bb.statements.push(new_statement);
+                }
 
-            // if the aggregate was an enum, we need to set the discriminant
-            if adt_def.variants.len() > 1 {
-                let set_discriminant = Statement {
-                    kind: StatementKind::SetDiscriminant {
-                        lvalue: lhs.clone(),
-                        variant_index: variant,
-                    },
-                    source_info: src_info,
+                // if the aggregate was an enum, we need to set the discriminant
+                if let Some((_, variant)) = downcast {
+                    let set_discriminant = Statement {
+                        kind: StatementKind::SetDiscriminant {
+                            lvalue: lhs.clone(),
+                            variant_index: variant,
+                        },
+                        source_info: src_info,
+                    };
+                    bb.statements.push(set_discriminant);
                 };
-                bb.statements.push(set_discriminant);
-            };
 
-            curr = bb.statements.len();
-            bb.statements.extend(suffix_stmts);
+                curr = bb.statements.len();
+                bb.statements.extend(suffix_stmts);
+            }
+        }
+    }
+}
+
+/// Returns the types of the fields initialized by the aggregate
+/// assignment `statement`.
+fn aggregate_field_tys<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                 mir: &Mir<'tcx>,
+                                 statement: &Statement<'tcx>)
+                                 -> Vec<Ty<'tcx>> {
+    let (lhs, kind) = match statement.kind {
+        StatementKind::Assign(ref lhs, Rvalue::Aggregate(ref kind, _)) => (lhs, kind),
+        _ => span_bug!(statement.source_info.span, "expected aggregate, not {:?}", statement),
+    };
+    match *kind {
+        AggregateKind::Adt(adt_def, variant, substs) => {
+            adt_def.variants[variant].fields.iter().map(|f| f.ty(tcx, substs)).collect()
+        }
+        AggregateKind::Tuple => {
+            match lhs.ty(mir, tcx).to_ty(tcx).sty {
+                ty::TyTuple(tys) => tys.to_vec(),
+                ref sty => span_bug!(statement.source_info.span, "expected tuple, not {:?}", sty),
+            }
         }
+        _ => span_bug!(statement.source_info.span, "expected struct or tuple, not {:?}", kind),
     }
 }
 
@@ -111,28 +133,44 @@ fn get_aggregate_statement_index<'a, 'tcx, 'b>(start: usize,
                                          -> Option<usize> {
     for i in start..statements.len() {
         let ref statement = statements[i];
-        let rhs = match statement.kind {
-            StatementKind::Assign(_, ref rhs) => rhs,
+        let (lhs, rhs) = match statement.kind {
+            StatementKind::Assign(ref lhs, ref rhs) => (lhs, rhs),
             _ => continue,
         };
         let (kind, operands) = match rhs {
             &Rvalue::Aggregate(ref kind, ref operands) => (kind, operands),
             _ => continue,
         };
-        let (adt_def, variant) = match kind {
-            &AggregateKind::Adt(adt_def, variant, _) => (adt_def, variant),
+        let is_enum = match kind {
+            &AggregateKind::Adt(adt_def, variant, _) => {
+                debug!("getting variant {:?}", variant);
+                debug!("for adt_def {:?}", adt_def);
+                adt_def.variants.len() > 1
+            }
+            &AggregateKind::Tuple => false,
             _ => continue,
         };
-        if operands.len() == 0 {
+        if operands.len() == 0 && !is_enum {
             // don't deaggregate ()
             continue;
         }
-        debug!("getting variant {:?}", variant);
-        debug!("for adt_def {:?}", adt_def);
-        let variant_def = &adt_def.variants[variant];
-        if variant_def.kind == VariantKind::Struct {
+        // Writing the fields one at a time would clobber the operands
+        // if they are read from the destination, e.g. in `x = (x.1, x.0)`.
+        let base = lvalue_base(lhs);
+        let reads_lhs = operands.iter().any(|op| match *op {
+            Operand::Consume(ref lvalue) => lvalue_base(lvalue) == base,
+            Operand::Constant(_) => false,
+        });
+        if !reads_lhs {
             return Some(i);
         }
     };
     None
 }
+
+fn lvalue_base<'a, 'tcx>(mut lvalue: &'a Lvalue<'tcx>) -> &'a Lvalue<'tcx> {
+    while let Lvalue::Projection(ref proj) = *lvalue {
+        lvalue = &proj.base;
+    }
+    lvalue
+}
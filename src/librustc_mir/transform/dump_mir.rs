@@ -11,10 +11,14 @@
 //! This pass just dumps MIR at a specified point.
 
 use std::fmt;
+use std::fs;
+use std::io::{self, Write};
 
+use dot;
 use rustc::ty::TyCtxt;
 use rustc::mir::repr::*;
 use rustc::mir::transform::{Pass, MirPass, MirPassHook, MirSource};
+use graphviz::write_mir_cfg_html;
 use pretty;
 
 pub struct Marker<'a>(pub &'a str);
@@ -45,7 +49,20 @@ impl<'a> fmt::Display for Disambiguator<'a> {
     }
 }
 
-pub struct DumpMir;
+/// Dumps the MIR around every pass, see `pretty::dump_mir`.
+///
+/// With `-Z dump-mir-html`, this additionally writes one `.html` file per
+/// pass that shows the CFG before and after the pass side by side.
+pub struct DumpMir {
+    /// The CFG rendered by `on_mir_pass` right before the current pass ran.
+    before: Option<Vec<u8>>,
+}
+
+impl DumpMir {
+    pub fn new() -> DumpMir {
+        DumpMir { before: None }
+    }
+}
 
 impl<'tcx> MirPassHook<'tcx> for DumpMir {
     fn on_mir_pass<'a>(
@@ -67,7 +84,65 @@ impl<'tcx> MirPassHook<'tcx> for DumpMir {
             mir,
             None
         );
+
+        if !tcx.sess.opts.debugging_opts.dump_mir_html ||
+           !pretty::dump_enabled(tcx, pass.name(), src) {
+            return;
+        }
+
+        let mut cfg = vec![];
+        let id_prefix = if is_after { "after-" } else { "before-" };
+        if write_mir_cfg_html(mir, id_prefix, &mut cfg).is_err() {
+            return;
+        }
+        if !is_after {
+            self.before = Some(cfg);
+            return;
+        }
+        let before = match self.before.take() {
+            Some(before) => before,
+            None => return,
+        };
+
+        let label = match pass.disambiguator() {
+            Some(fmt) => format!("{}-cfg", fmt),
+            None => String::from("cfg"),
+        };
+        let file_path = pretty::dump_path(tcx, src, pass.name(), &label, "html");
+        let _ = fs::File::create(&file_path).and_then(|mut file| {
+            write_html_comparison(tcx, src, pass, &before, &cfg, &mut file)
+        });
     }
 }
 
-impl<'b> Pass for DumpMir {}
+impl Pass for DumpMir {}
+
+fn write_html_comparison<'a, 'tcx, W: Write>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                             src: MirSource,
+                                             pass: &Pass,
+                                             before: &[u8],
+                                             after: &[u8],
+                                             w: &mut W)
+                                             -> io::Result<()> {
+    let node_path = tcx.item_path_str(tcx.map.local_def_id(src.item_id()));
+    let title = format!("MIR for `{}` around {}", node_path, pass.name());
+
+    writeln!(w, "<!DOCTYPE html>")?;
+    writeln!(w, r#"<html><head><meta charset="utf-8">"#)?;
+    writeln!(w, "<title>{}</title>", dot::escape_html(&title))?;
+    writeln!(w, "<style>")?;
+    writeln!(w, "body {{ font-family: monospace; }}")?;
+    writeln!(w, ".cfg {{ vertical-align: top; padding: 0 2em; }}")?;
+    writeln!(w, ".block {{ margin-bottom: 1em; }}")?;
+    writeln!(w, ".block td {{ padding: 0.2em 0.5em; }}")?;
+    writeln!(w, "</style></head><body>")?;
+    writeln!(w, "<h1>{}</h1>", dot::escape_html(&title))?;
+    writeln!(w, "<table><tr><th>before</th><th>after</th></tr><tr>")?;
+    write!(w, r#"<td class="cfg">"#)?;
+    w.write_all(before)?;
+    writeln!(w, "</td>")?;
+    write!(w, r#"<td class="cfg">"#)?;
+    w.write_all(after)?;
+    writeln!(w, "</td>")?;
+    writeln!(w, "</tr></table></body></html>")
+}
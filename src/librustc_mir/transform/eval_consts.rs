@@ -0,0 +1,115 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Evaluates scalar constants by interpreting their MIR.
+//!
+//! This covers `const` items as well as enum discriminants, array lengths
+//! and repeat counts. The MIR is what defines their value: it is what trans
+//! translates, and overflow and out-of-bounds indexing that the HIR
+//! evaluator let through are reported as errors here.
+//!
+//! Type-checking needs array lengths and discriminants before MIR exists,
+//! so `rustc_const_eval` still evaluates them first. Its result is only
+//! cross-checked against the MIR one with a debug assertion: a mismatch is
+//! a bug in the HIR evaluator, not in the crate being compiled.
+
+use rustc::dep_graph::DepNode;
+use rustc::hir;
+use rustc::hir::map as hir_map;
+use rustc::middle::const_val::ConstVal;
+use rustc::mir::mir_map::MirMap;
+use rustc::mir::transform::{MirMapPass, MirPassHook, MirSource, Pass};
+use rustc::ty::{Ty, TyCtxt};
+use rustc_const_eval::{eval_const_expr_partial, report_const_eval_err};
+use rustc_const_eval::EvalHint::UncheckedExprHint;
+use syntax::ast::NodeId;
+
+use const_eval;
+
+pub struct EvalConsts;
+
+impl Pass for EvalConsts {}
+
+impl<'tcx> MirMapPass<'tcx> for EvalConsts {
+    fn run_pass<'a>(&mut self,
+                    tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                    map: &mut MirMap<'tcx>,
+                    _hooks: &mut [Box<for<'s> MirPassHook<'s>>]) {
+        if tcx.sess.err_count() > 0 {
+            // The HIR evaluator has already reported its errors, and
+            // broken constants would only be reported twice.
+            return;
+        }
+
+        for def_id in map.map.keys() {
+            let _task = tcx.dep_graph.in_task(DepNode::Mir(def_id));
+            let id = tcx.map.as_local_node_id(def_id).unwrap();
+            if let MirSource::Const(_) = MirSource::from_node(tcx, id) {} else { continue; }
+            let ty = map.map[&def_id].return_ty;
+            if !is_scalar(ty) {
+                continue;
+            }
+            let expr = match const_expr(tcx, id) {
+                Some(expr) => expr,
+                None => continue
+            };
+
+            let hir_result = eval_const_expr_partial(tcx, expr, UncheckedExprHint(ty), None);
+            let mir_result = const_eval::eval_const(tcx, map, def_id);
+            debug!("eval_consts: {:?} is {:?} (HIR: {:?})",
+                   def_id, mir_result.as_ref().ok(), hir_result.as_ref().ok());
+            match (hir_result, mir_result) {
+                (Ok(_), Err(err)) => {
+                    if const_eval::is_runtime_error(&err) {
+                        report_const_eval_err(tcx, &err, expr.span, "constant").emit();
+                    }
+                }
+                (Ok(hir_value), Ok(mir_value)) => {
+                    debug_assert!(const_eval::same_value(&hir_value, &mir_value),
+                                  "{:?}: the HIR evaluator computed {}, but the MIR \
+                                   evaluator computed {}",
+                                  def_id, display(&hir_value), display(&mir_value));
+                }
+                // Failures of the HIR evaluator are reported where it is used.
+                (Err(_), _) => {}
+            }
+        }
+    }
+}
+
+fn is_scalar(ty: Ty) -> bool {
+    ty.is_integral() || ty.is_bool() || ty.is_char()
+}
+
+/// The expression of the constant `id`, which is either an associated or
+/// `const` item or an anonymous constant such as an array length.
+fn const_expr<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, id: NodeId)
+                        -> Option<&'tcx hir::Expr> {
+    match tcx.map.get(id) {
+        hir_map::NodeItem(&hir::Item { node: hir::ItemConst(_, ref expr), .. }) |
+        hir_map::NodeTraitItem(&hir::TraitItem {
+            node: hir::ConstTraitItem(_, Some(ref expr)), ..
+        }) |
+        hir_map::NodeImplItem(&hir::ImplItem {
+            node: hir::ImplItemKind::Const(_, ref expr), ..
+        }) => Some(expr),
+        hir_map::NodeExpr(expr) => Some(expr),
+        _ => None
+    }
+}
+
+fn display(value: &ConstVal) -> String {
+    match *value {
+        ConstVal::Integral(i) => format!("`{}`", i),
+        ConstVal::Bool(b) => format!("`{}`", b),
+        ConstVal::Char(c) => format!("`{:?}`", c),
+        _ => value.description().to_string()
+    }
+}
@@ -0,0 +1,301 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A pass that inlines small calls at the MIR level.
+//!
+//! Only calls to local, monomorphic functions whose MIR is available in the
+//! `MirMap` are considered. The callee's arguments, variables and temporaries
+//! become fresh locals of the caller, its visibility scopes are nested inside
+//! the scope of the call, `return` jumps to the call's target and unwinding
+//! out of the callee continues at the call's cleanup block.
+//!
+//! Each pass over a function only inlines the calls that were present before
+//! the pass started, so (mutually) recursive functions cannot make it loop.
+
+use rustc::dep_graph::DepNode;
+use rustc::hir::def_id::DefId;
+use rustc::mir::mir_map::MirMap;
+use rustc::mir::repr::*;
+use rustc::mir::transform::{MirMapPass, MirPassHook, MirSource, Pass};
+use rustc::mir::visit::{LvalueContext, MutVisitor};
+use rustc::ty::{self, TyCtxt};
+use rustc_data_structures::indexed_vec::{Idx, IndexVec};
+use syntax::attr::{self, InlineAttr};
+
+use std::mem;
+
+/// Callees with a cost of at most this are inlined.
+const DEFAULT_THRESHOLD: usize = 20;
+
+/// Threshold for callees marked `#[inline]`.
+const HINT_THRESHOLD: usize = 50;
+
+pub struct Inline;
+
+impl Pass for Inline {}
+
+impl<'tcx> MirMapPass<'tcx> for Inline {
+    fn run_pass<'a>(&mut self,
+                    tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                    map: &mut MirMap<'tcx>,
+                    hooks: &mut [Box<for<'s> MirPassHook<'s>>]) {
+        // we only run when mir_opt_level > 1
+        if tcx.sess.opts.mir_opt_level <= 1 {
+            return;
+        }
+
+        for def_id in map.map.keys() {
+            let _task = tcx.dep_graph.in_task(DepNode::Mir(def_id));
+            let id = tcx.map.as_local_node_id(def_id).unwrap();
+            let src = MirSource::from_node(tcx, id);
+            if let MirSource::Fn(_) = src {} else { continue; }
+
+            let callsites = find_callsites(tcx, map, def_id);
+            if callsites.is_empty() {
+                continue;
+            }
+
+            let mir = map.map.get_mut(&def_id).unwrap();
+            for hook in &mut *hooks {
+                hook.on_mir_pass(tcx, src, mir, self, false);
+            }
+
+            for (bb, callee_def_id, callee_mir) in callsites {
+                debug!("inlining {:?} into {:?} at {:?}", callee_def_id, def_id, bb);
+                inline_call(mir, bb, callee_mir);
+            }
+
+            for hook in &mut *hooks {
+                hook.on_mir_pass(tcx, src, mir, self, true);
+            }
+        }
+    }
+}
+
+/// Collects the calls in `caller` that should be inlined, together with a
+/// copy of the callee's MIR.
+fn find_callsites<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                            map: &MirMap<'tcx>,
+                            caller: DefId)
+                            -> Vec<(BasicBlock, DefId, Mir<'tcx>)> {
+    let mir = &map.map[&caller];
+    let mut callsites = vec![];
+    for (bb, data) in mir.basic_blocks().iter_enumerated() {
+        let callee = match data.terminator().kind {
+            TerminatorKind::Call {
+                func: Operand::Constant(Constant {
+                    literal: Literal::Item { def_id, substs }, ty, ..
+                }),
+                destination: Some(_), ..
+            } => {
+                match ty.sty {
+                    ty::TyFnDef(..) if substs.types.is_empty() => def_id,
+                    _ => continue
+                }
+            }
+            _ => continue
+        };
+        if callee == caller {
+            continue;
+        }
+        let callee_mir = match map.map.get(&callee) {
+            Some(callee_mir) => callee_mir,
+            None => continue
+        };
+        if should_inline(tcx, callee, callee_mir) {
+            // Once inlined, the caller's MIR depends on the callee's, so it
+            // has to be rebuilt whenever the callee changes. `map.map.get`
+            // records this read as well, but we don't want to rely on how
+            // the callee's MIR happened to be looked up.
+            tcx.dep_graph.read(DepNode::Mir(callee));
+            callsites.push((bb, callee, callee_mir.clone()));
+        }
+    }
+    callsites
+}
+
+fn should_inline<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                           callee: DefId,
+                           callee_mir: &Mir<'tcx>)
+                           -> bool {
+    let threshold = match attr::find_inline_attr(None, &tcx.get_attrs(callee)) {
+        InlineAttr::Never => return false,
+        InlineAttr::Always => None,
+        InlineAttr::Hint => Some(HINT_THRESHOLD),
+        InlineAttr::None => Some(DEFAULT_THRESHOLD),
+    };
+
+    // Promoted constants are referred to by index, and "rust-call"
+    // arguments have to be untupled; neither is supported yet.
+    if !callee_mir.promoted.is_empty() ||
+       callee_mir.arg_decls.iter().any(|arg| arg.spread) {
+        return false;
+    }
+
+    let mut cost = 0;
+    for data in callee_mir.basic_blocks() {
+        cost += data.statements.len() + 1;
+        match data.terminator().kind {
+            // Inlining self-recursive functions only unrolls them by one level.
+            TerminatorKind::Call {
+                func: Operand::Constant(Constant {
+                    literal: Literal::Item { def_id, .. }, ..
+                }), ..
+            } if def_id == callee => return false,
+            _ => {}
+        }
+    }
+    debug!("should_inline: {:?} has cost {} (threshold {:?})", callee, cost, threshold);
+    threshold.map_or(true, |threshold| cost <= threshold)
+}
+
+/// Replaces the call terminating `bb` with the body of `callee`.
+fn inline_call<'tcx>(caller: &mut Mir<'tcx>, bb: BasicBlock, mut callee: Mir<'tcx>) {
+    let terminator = caller[bb].terminator.take().unwrap();
+    let caller_is_cleanup = caller[bb].is_cleanup;
+    let source_info = terminator.source_info;
+    let (args, destination, target, cleanup) = match terminator.kind {
+        TerminatorKind::Call { args, destination: Some((destination, target)), cleanup, .. } => {
+            (args, destination, target, cleanup)
+        }
+        kind => span_bug!(source_info.span, "expected a call, not {:?}", kind)
+    };
+
+    let var_offset = caller.var_decls.len();
+    let temp_offset = caller.temp_decls.len();
+    let scope_offset = caller.visibility_scopes.len();
+    let block_offset = caller.basic_blocks().len();
+
+    for mut scope in mem::replace(&mut callee.visibility_scopes, IndexVec::new()) {
+        scope.parent_scope = Some(match scope.parent_scope {
+            Some(parent) => VisibilityScope::new(parent.index() + scope_offset),
+            None => source_info.scope,
+        });
+        caller.visibility_scopes.push(scope);
+    }
+
+    for mut var in mem::replace(&mut callee.var_decls, IndexVec::new()) {
+        var.source_info.scope = VisibilityScope::new(var.source_info.scope.index() +
+                                                     scope_offset);
+        caller.var_decls.push(var);
+    }
+
+    caller.temp_decls.extend(mem::replace(&mut callee.temp_decls, IndexVec::new()));
+
+    // Move the arguments into temporaries standing in for the callee's args.
+    let mut arg_temps = IndexVec::new();
+    for (arg_decl, arg) in callee.arg_decls.iter().zip(args) {
+        let temp = caller.temp_decls.push(TempDecl { ty: arg_decl.ty });
+        caller[bb].statements.push(Statement {
+            source_info: source_info,
+            kind: StatementKind::Assign(Lvalue::Temp(temp), Rvalue::Use(arg)),
+        });
+        arg_temps.push(temp);
+    }
+    caller[bb].terminator = Some(Terminator {
+        source_info: source_info,
+        kind: TerminatorKind::Goto { target: BasicBlock::new(block_offset) },
+    });
+
+    let mut integrator = Integrator {
+        block_offset: block_offset,
+        var_offset: var_offset,
+        temp_offset: temp_offset,
+        scope_offset: scope_offset,
+        args: arg_temps,
+        destination: destination,
+        return_block: target,
+        cleanup_block: cleanup,
+        caller_is_cleanup: caller_is_cleanup,
+        in_cleanup_block: false,
+    };
+    let blocks = mem::replace(callee.basic_blocks_mut(), IndexVec::new());
+    for (callee_bb, mut data) in blocks.into_iter_enumerated() {
+        integrator.visit_basic_block_data(callee_bb, &mut data);
+        caller.basic_blocks_mut().push(data);
+    }
+}
+
+/// Renames the locals, scopes and blocks of an inlined body so they fit into
+/// the caller.
+struct Integrator<'tcx> {
+    block_offset: usize,
+    var_offset: usize,
+    temp_offset: usize,
+    scope_offset: usize,
+    args: IndexVec<Arg, Temp>,
+    destination: Lvalue<'tcx>,
+    return_block: BasicBlock,
+    cleanup_block: Option<BasicBlock>,
+    /// Whether the call being inlined sits in a cleanup block of the caller,
+    /// in which case every block of the callee runs during unwinding too.
+    caller_is_cleanup: bool,
+    in_cleanup_block: bool,
+}
+
+impl<'tcx> MutVisitor<'tcx> for Integrator<'tcx> {
+    fn visit_basic_block_data(&mut self, block: BasicBlock, data: &mut BasicBlockData<'tcx>) {
+        data.is_cleanup = data.is_cleanup || self.caller_is_cleanup;
+        self.in_cleanup_block = data.is_cleanup;
+        self.super_basic_block_data(block, data);
+    }
+
+    fn visit_lvalue(&mut self, lvalue: &mut Lvalue<'tcx>, context: LvalueContext) {
+        *lvalue = match *lvalue {
+            Lvalue::Var(var) => Lvalue::Var(Var::new(var.index() + self.var_offset)),
+            Lvalue::Temp(temp) => Lvalue::Temp(Temp::new(temp.index() + self.temp_offset)),
+            Lvalue::Arg(arg) => Lvalue::Temp(self.args[arg]),
+            Lvalue::ReturnPointer => self.destination.clone(),
+            Lvalue::Static(_) |
+            Lvalue::Projection(_) => return self.super_lvalue(lvalue, context),
+        };
+    }
+
+    fn visit_visibility_scope(&mut self, scope: &mut VisibilityScope) {
+        *scope = VisibilityScope::new(scope.index() + self.scope_offset);
+    }
+
+    fn visit_terminator_kind(&mut self, block: BasicBlock, kind: &mut TerminatorKind<'tcx>) {
+        self.super_terminator_kind(block, kind);
+
+        for target in kind.successors_mut() {
+            *target = BasicBlock::new(target.index() + self.block_offset);
+        }
+
+        match *kind {
+            TerminatorKind::Return => {
+                *kind = TerminatorKind::Goto { target: self.return_block };
+            }
+            TerminatorKind::Resume => {
+                if let Some(cleanup) = self.cleanup_block {
+                    *kind = TerminatorKind::Goto { target: cleanup };
+                }
+            }
+            // Unwinding out of the callee has to run the caller's cleanup.
+            TerminatorKind::Call { ref mut cleanup, .. } |
+            TerminatorKind::Assert { ref mut cleanup, .. } => {
+                if cleanup.is_none() && !self.in_cleanup_block {
+                    *cleanup = self.cleanup_block;
+                }
+            }
+            TerminatorKind::Drop { ref mut unwind, .. } |
+            TerminatorKind::DropAndReplace { ref mut unwind, .. } => {
+                if unwind.is_none() && !self.in_cleanup_block {
+                    *unwind = self.cleanup_block;
+                }
+            }
+            TerminatorKind::Goto { .. } |
+            TerminatorKind::If { .. } |
+            TerminatorKind::Switch { .. } |
+            TerminatorKind::SwitchInt { .. } |
+            TerminatorKind::Unreachable => {}
+        }
+    }
+}
@@ -18,3 +18,9 @@ pub mod promote_consts;
 pub mod qualify_consts;
 pub mod dump_mir;
 pub mod deaggregator;
+pub mod const_prop;
+pub mod inline;
+pub mod copy_prop;
+pub mod validate;
+pub mod eval_consts;
+pub mod check_unsafety;
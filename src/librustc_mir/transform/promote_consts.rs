@@ -388,7 +388,8 @@ pub fn promote_candidates<'a, 'tcx>(mir: &mut Mir<'tcx>,
                 IndexVec::new(),
                 Some(VisibilityScopeData {
                     span: span,
-                    parent_scope: None
+                    parent_scope: None,
+                    safety: Safety::Safe
                 }).into_iter().collect(),
                 IndexVec::new(),
                 ty,
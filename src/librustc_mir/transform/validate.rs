@@ -0,0 +1,452 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A consistency checker that runs after every MIR pass when
+//! `-Z validate-mir` is given.
+//!
+//! Unlike `TypeckMir`, which runs once on freshly built MIR, this is meant
+//! to catch transformations that break the MIR, so that the offending pass
+//! is named instead of trans ICEing on it later. It checks that:
+//!
+//! - every block has a terminator, and all branch targets and locals exist;
+//! - unwind edges lead to cleanup blocks and normal edges do not leave them;
+//! - the operands of assignments, branches and calls have sensible types;
+//! - no local with storage markers is used while its storage may be dead.
+
+use rustc::ty::{self, Ty, TyCtxt};
+use rustc::ty::fold::TypeFoldable;
+use rustc::mir::repr::*;
+use rustc::mir::transform::{MirPassHook, MirSource, Pass};
+use rustc::mir::visit::{LvalueContext, Visitor};
+use rustc_data_structures::bitvec::BitVector;
+use rustc_data_structures::indexed_vec::{Idx, IndexVec};
+use syntax_pos::Span;
+
+pub struct ValidateMir;
+
+impl Pass for ValidateMir {}
+
+impl<'tcx> MirPassHook<'tcx> for ValidateMir {
+    fn on_mir_pass<'a>(&mut self,
+                       tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                       src: MirSource,
+                       mir: &Mir<'tcx>,
+                       pass: &Pass,
+                       is_after: bool) {
+        if !is_after || !tcx.sess.opts.debugging_opts.validate_mir {
+            return;
+        }
+        if tcx.sess.err_count() > 0 {
+            // compiling a broken program can obviously result in a
+            // broken MIR, so try not to report duplicate errors.
+            return;
+        }
+
+        let mut validator = Validator {
+            tcx: tcx,
+            mir: mir,
+            errors: vec![],
+        };
+        validator.validate();
+        if validator.errors.is_empty() {
+            return;
+        }
+
+        let pass_name = match pass.disambiguator() {
+            Some(disambiguator) => format!("{}-{}", pass.name(), disambiguator),
+            None => pass.name().to_string(),
+        };
+        let item = tcx.node_path_str(src.item_id());
+        for (span, message) in validator.errors {
+            tcx.sess.span_err(span, &format!("broken MIR in {} after pass `{}`: {}",
+                                             item, pass_name, message));
+        }
+        span_bug!(mir.span, "MIR validation failed after pass `{}`", pass_name);
+    }
+}
+
+struct Validator<'a, 'tcx: 'a> {
+    tcx: TyCtxt<'a, 'tcx, 'tcx>,
+    mir: &'a Mir<'tcx>,
+    errors: Vec<(Span, String)>,
+}
+
+impl<'a, 'tcx> Validator<'a, 'tcx> {
+    fn error(&mut self, span: Span, message: String) {
+        self.errors.push((span, message));
+    }
+
+    fn validate(&mut self) {
+        self.check_structure();
+        // The remaining checks index locals and blocks freely.
+        if !self.errors.is_empty() {
+            return;
+        }
+        self.check_types();
+        self.check_storage();
+    }
+
+    fn check_structure(&mut self) {
+        let mir = self.mir;
+        let block_count = mir.basic_blocks().len();
+        for (bb, data) in mir.basic_blocks().iter_enumerated() {
+            let terminator = match data.terminator {
+                Some(ref terminator) => terminator,
+                None => {
+                    self.error(mir.span, format!("{:?} has no terminator", bb));
+                    continue;
+                }
+            };
+            let span = terminator.source_info.span;
+            let successors = terminator.kind.successors();
+            if let Some(&target) = successors.iter().find(|target| target.index() >= block_count) {
+                self.error(span, format!("{:?} branches to nonexistent {:?}", bb, target));
+                continue;
+            }
+
+            let (normal, unwind) = match unwind_target(&terminator.kind) {
+                Some(unwind) => (&successors[..successors.len() - 1], Some(unwind)),
+                None => (&successors[..], None),
+            };
+            for &target in normal {
+                if mir[target].is_cleanup != data.is_cleanup {
+                    self.error(span, format!("edge {:?} -> {:?} enters or leaves cleanup",
+                                             bb, target));
+                }
+            }
+            if let Some(unwind) = unwind {
+                if data.is_cleanup {
+                    self.error(span, format!("cleanup {:?} unwinds to {:?}", bb, unwind));
+                } else if !mir[unwind].is_cleanup {
+                    self.error(span, format!("{:?} unwinds to non-cleanup {:?}", bb, unwind));
+                }
+            }
+            match terminator.kind {
+                TerminatorKind::Resume if !data.is_cleanup => {
+                    self.error(span, format!("resume in non-cleanup {:?}", bb));
+                }
+                TerminatorKind::Return if data.is_cleanup => {
+                    self.error(span, format!("return in cleanup {:?}", bb));
+                }
+                _ => {}
+            }
+        }
+
+        let mut checker = LocalRangeChecker {
+            mir: mir,
+            span: mir.span,
+            errors: vec![],
+        };
+        for (bb, data) in mir.basic_blocks().iter_enumerated() {
+            checker.visit_basic_block_data(bb, data);
+        }
+        self.errors.extend(checker.errors);
+    }
+
+    fn check_types(&mut self) {
+        let (tcx, mir) = (self.tcx, self.mir);
+        for data in mir.basic_blocks() {
+            for statement in &data.statements {
+                if let StatementKind::Assign(ref lvalue, ref rvalue) = statement.kind {
+                    let span = statement.source_info.span;
+                    if !self.check_binop_operands(span, rvalue) {
+                        continue;
+                    }
+                    if let Some(rvalue_ty) = rvalue.ty(mir, tcx) {
+                        let lvalue_ty = lvalue.ty(mir, tcx).to_ty(tcx);
+                        self.check_same_type(span, "assignment", lvalue_ty, rvalue_ty);
+                    }
+                }
+            }
+            self.check_terminator(data.terminator());
+        }
+    }
+
+    /// Checks the operands of arithmetic operators, whose type is otherwise
+    /// computed by asserting that they agree.
+    fn check_binop_operands(&mut self, span: Span, rvalue: &Rvalue<'tcx>) -> bool {
+        let (tcx, mir) = (self.tcx, self.mir);
+        let (op, lhs, rhs) = match *rvalue {
+            Rvalue::BinaryOp(op, ref lhs, ref rhs) |
+            Rvalue::CheckedBinaryOp(op, ref lhs, ref rhs) => (op, lhs, rhs),
+            _ => return true
+        };
+        match op {
+            BinOp::Add | BinOp::Sub | BinOp::Mul | BinOp::Div | BinOp::Rem |
+            BinOp::BitXor | BinOp::BitAnd | BinOp::BitOr => {
+                let lhs_ty = tcx.erase_regions(&lhs.ty(mir, tcx));
+                let rhs_ty = tcx.erase_regions(&rhs.ty(mir, tcx));
+                if lhs_ty != rhs_ty {
+                    self.error(span, format!("{:?} on mismatched operands {:?} and {:?}",
+                                             op, lhs_ty, rhs_ty));
+                    return false;
+                }
+                true
+            }
+            BinOp::Shl | BinOp::Shr |
+            BinOp::Eq | BinOp::Lt | BinOp::Le |
+            BinOp::Ne | BinOp::Ge | BinOp::Gt => true
+        }
+    }
+
+    fn check_terminator(&mut self, terminator: &Terminator<'tcx>) {
+        let (tcx, mir) = (self.tcx, self.mir);
+        let span = terminator.source_info.span;
+        match terminator.kind {
+            TerminatorKind::Goto { .. } |
+            TerminatorKind::Resume |
+            TerminatorKind::Return |
+            TerminatorKind::Unreachable |
+            TerminatorKind::Drop { .. } => {}
+            TerminatorKind::DropAndReplace { ref location, ref value, .. } => {
+                let location_ty = location.ty(mir, tcx).to_ty(tcx);
+                self.check_same_type(span, "DropAndReplace", location_ty, value.ty(mir, tcx));
+            }
+            TerminatorKind::If { ref cond, .. } |
+            TerminatorKind::Assert { ref cond, .. } => {
+                let cond_ty = cond.ty(mir, tcx);
+                if cond_ty != tcx.types.bool {
+                    self.error(span, format!("branch on non-bool {:?}", cond_ty));
+                }
+            }
+            TerminatorKind::Switch { ref discr, adt_def, ref targets } => {
+                let discr_ty = discr.ty(mir, tcx).to_ty(tcx);
+                match discr_ty.sty {
+                    ty::TyEnum(def, _) if def == adt_def => {
+                        if targets.len() != adt_def.variants.len() {
+                            self.error(span, format!("Switch on {:?} has {} targets",
+                                                     discr_ty, targets.len()));
+                        }
+                    }
+                    _ => {
+                        self.error(span, format!("Switch for {:?} on {:?}",
+                                                 adt_def, discr_ty));
+                    }
+                }
+            }
+            TerminatorKind::SwitchInt { ref discr, switch_ty, ref values, ref targets } => {
+                let discr_ty = discr.ty(mir, tcx).to_ty(tcx);
+                self.check_same_type(span, "SwitchInt", switch_ty, discr_ty);
+                if targets.len() != values.len() + 1 {
+                    self.error(span, format!("SwitchInt has {} values but {} targets",
+                                             values.len(), targets.len()));
+                }
+            }
+            TerminatorKind::Call { ref func, ref args, ref destination, .. } => {
+                let func_ty = func.ty(mir, tcx);
+                let sig = match func_ty.sty {
+                    ty::TyFnDef(_, _, fn_ty) | ty::TyFnPtr(fn_ty) => {
+                        tcx.erase_late_bound_regions(&fn_ty.sig)
+                    }
+                    _ => {
+                        self.error(span, format!("call to non-function {:?}", func_ty));
+                        return;
+                    }
+                };
+                if args.len() < sig.inputs.len() ||
+                   (args.len() > sig.inputs.len() && !sig.variadic) {
+                    self.error(span, format!("call to {:?} with {} arguments",
+                                             func_ty, args.len()));
+                }
+                if let Some((ref dest, _)) = *destination {
+                    let dest_ty = dest.ty(mir, tcx).to_ty(tcx);
+                    self.check_same_type(span, "call destination", dest_ty, sig.output);
+                }
+            }
+        }
+    }
+
+    /// Reports `actual` if it is not `expected`, ignoring regions.
+    ///
+    /// Types that may legitimately differ by subtyping or normalization
+    /// are not compared.
+    fn check_same_type(&mut self, span: Span, what: &str, expected: Ty<'tcx>, actual: Ty<'tcx>) {
+        let expected = self.tcx.erase_regions(&expected);
+        let actual = self.tcx.erase_regions(&actual);
+        if expected == actual || !is_comparable(expected) || !is_comparable(actual) {
+            return;
+        }
+        self.error(span, format!("{} of type {:?} where {:?} was expected",
+                                 what, actual, expected));
+    }
+
+    fn check_storage(&mut self) {
+        let mir = self.mir;
+        let local_count = mir.count_locals();
+
+        // Locals without storage markers are live throughout the function.
+        let mut tracked = vec![false; local_count];
+        for data in mir.basic_blocks() {
+            for statement in &data.statements {
+                match statement.kind {
+                    StatementKind::StorageLive(ref lvalue) |
+                    StatementKind::StorageDead(ref lvalue) => {
+                        if let Some(local) = mir.local_index(lvalue) {
+                            tracked[local.index()] = true;
+                        }
+                    }
+                    StatementKind::Assign(..) |
+                    StatementKind::SetDiscriminant { .. } => {}
+                }
+            }
+        }
+
+        // Compute the locals whose storage may be dead on entry to each block.
+        let mut maybe_dead = IndexVec::from_elem(vec![false; local_count], mir.basic_blocks());
+        let mut reached = BitVector::new(mir.basic_blocks().len());
+        maybe_dead[START_BLOCK] = tracked;
+        reached.insert(START_BLOCK.index());
+        let mut worklist = vec![START_BLOCK];
+        while let Some(bb) = worklist.pop() {
+            let mut dead = maybe_dead[bb].clone();
+            for statement in &mir[bb].statements {
+                apply_storage_marker(mir, statement, &mut dead);
+            }
+            for &target in mir[bb].terminator().successors().iter() {
+                let mut changed = reached.insert(target.index());
+                for (entry, &exit) in maybe_dead[target].iter_mut().zip(&dead) {
+                    if exit && !*entry {
+                        *entry = true;
+                        changed = true;
+                    }
+                }
+                if changed {
+                    worklist.push(target);
+                }
+            }
+        }
+
+        for (bb, data) in mir.basic_blocks().iter_enumerated() {
+            if !reached.contains(bb.index()) {
+                continue;
+            }
+            let mut dead = maybe_dead[bb].clone();
+            for statement in &data.statements {
+                let mut uses = LocalUses { mir: mir, uses: vec![] };
+                uses.visit_statement(bb, statement);
+                self.check_uses(statement.source_info.span, &uses.uses, &dead);
+                apply_storage_marker(mir, statement, &mut dead);
+            }
+            let terminator = data.terminator();
+            let mut uses = LocalUses { mir: mir, uses: vec![] };
+            uses.visit_terminator(bb, terminator);
+            self.check_uses(terminator.source_info.span, &uses.uses, &dead);
+        }
+    }
+
+    fn check_uses(&mut self, span: Span, uses: &[Local], dead: &[bool]) {
+        for &local in uses {
+            if dead[local.index()] {
+                self.error(span, format!("{:?} used while its storage may be dead", local));
+            }
+        }
+    }
+}
+
+/// The unwind edge of a terminator, which is always its last successor.
+fn unwind_target(kind: &TerminatorKind) -> Option<BasicBlock> {
+    match *kind {
+        TerminatorKind::Drop { unwind, .. } |
+        TerminatorKind::DropAndReplace { unwind, .. } => unwind,
+        TerminatorKind::Call { cleanup, .. } |
+        TerminatorKind::Assert { cleanup, .. } => cleanup,
+        TerminatorKind::Goto { .. } |
+        TerminatorKind::If { .. } |
+        TerminatorKind::Switch { .. } |
+        TerminatorKind::SwitchInt { .. } |
+        TerminatorKind::Resume |
+        TerminatorKind::Return |
+        TerminatorKind::Unreachable => None
+    }
+}
+
+fn is_comparable(ty: Ty) -> bool {
+    // Higher-ranked regions survive `erase_regions`, so fn pointers and
+    // trait objects may differ by subtyping.
+    !ty.has_projection_types() && !ty.walk().any(|ty| {
+        match ty.sty {
+            ty::TyFnPtr(_) | ty::TyTrait(_) => true,
+            _ => false
+        }
+    })
+}
+
+fn apply_storage_marker<'tcx>(mir: &Mir<'tcx>, statement: &Statement<'tcx>, dead: &mut [bool]) {
+    match statement.kind {
+        StatementKind::StorageLive(ref lvalue) => {
+            if let Some(local) = mir.local_index(lvalue) {
+                dead[local.index()] = false;
+            }
+        }
+        StatementKind::StorageDead(ref lvalue) => {
+            if let Some(local) = mir.local_index(lvalue) {
+                dead[local.index()] = true;
+            }
+        }
+        StatementKind::Assign(..) |
+        StatementKind::SetDiscriminant { .. } => {}
+    }
+}
+
+/// Reports args, vars and temps that have no declaration.
+struct LocalRangeChecker<'a, 'tcx: 'a> {
+    mir: &'a Mir<'tcx>,
+    span: Span,
+    errors: Vec<(Span, String)>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for LocalRangeChecker<'a, 'tcx> {
+    fn visit_statement(&mut self, block: BasicBlock, statement: &Statement<'tcx>) {
+        self.span = statement.source_info.span;
+        self.super_statement(block, statement);
+    }
+
+    fn visit_terminator(&mut self, block: BasicBlock, terminator: &Terminator<'tcx>) {
+        self.span = terminator.source_info.span;
+        self.super_terminator(block, terminator);
+    }
+
+    fn visit_lvalue(&mut self, lvalue: &Lvalue<'tcx>, context: LvalueContext) {
+        let in_range = match *lvalue {
+            Lvalue::Var(var) => var.index() < self.mir.var_decls.len(),
+            Lvalue::Temp(temp) => temp.index() < self.mir.temp_decls.len(),
+            Lvalue::Arg(arg) => arg.index() < self.mir.arg_decls.len(),
+            Lvalue::Static(_) |
+            Lvalue::ReturnPointer |
+            Lvalue::Projection(_) => true
+        };
+        if !in_range {
+            self.errors.push((self.span, format!("use of undeclared {:?}", lvalue)));
+        }
+        self.super_lvalue(lvalue, context);
+    }
+}
+
+/// Collects the locals accessed by a statement or terminator, other than
+/// by storage markers.
+struct LocalUses<'a, 'tcx: 'a> {
+    mir: &'a Mir<'tcx>,
+    uses: Vec<Local>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for LocalUses<'a, 'tcx> {
+    fn visit_lvalue(&mut self, lvalue: &Lvalue<'tcx>, context: LvalueContext) {
+        match context {
+            LvalueContext::StorageLive |
+            LvalueContext::StorageDead => {}
+            _ => {
+                if let Some(local) = self.mir.local_index(lvalue) {
+                    self.uses.push(local);
+                }
+            }
+        }
+        self.super_lvalue(lvalue, context);
+    }
+}
@@ -221,4 +221,7 @@ pub impl Foo for Bar {
 register_diagnostics! {
     E0472, // asm! is unsupported on this target
     E0561, // patterns aren't allowed in function pointer types
+    E0566, // patterns aren't allowed in naked function parameters
+    E0567, // naked functions must contain a single asm block
+    E0568, // referencing function parameters is not allowed in naked functions
 }
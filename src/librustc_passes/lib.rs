@@ -45,6 +45,7 @@ pub mod diagnostics;
 pub mod ast_validation;
 pub mod consts;
 pub mod loops;
+pub mod naked_functions;
 pub mod no_asm;
 pub mod rvalues;
 pub mod static_recursion;
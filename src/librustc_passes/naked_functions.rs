@@ -0,0 +1,132 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// This pass checks the restrictions on `#[naked]` functions. A naked
+// function has no prologue or epilogue, so there is no stack frame the
+// compiler can use for arguments or temporaries. Its body therefore has to
+// be a single `asm!` block which doesn't refer to the function's
+// parameters, and which must not fall off the end of the function.
+
+use rustc::dep_graph::DepNode;
+use rustc::hir::def::{Def, DefMap};
+use rustc::hir::intravisit::{self, FnKind, Visitor};
+use rustc::hir::map::Map;
+use rustc::hir::{self, PatKind};
+use rustc::session::Session;
+
+use syntax::ast;
+use syntax::attr;
+use syntax_pos::Span;
+
+struct CheckNakedFunctions<'a> {
+    sess: &'a Session,
+    def_map: &'a DefMap,
+}
+
+pub fn check_crate(sess: &Session, def_map: &DefMap, map: &Map) {
+    let _task = map.dep_graph.in_task(DepNode::CheckNakedFunctions);
+    let krate = map.krate();
+    krate.visit_all_items(&mut CheckNakedFunctions {
+        sess: sess,
+        def_map: def_map,
+    });
+}
+
+impl<'a, 'v> Visitor<'v> for CheckNakedFunctions<'a> {
+    fn visit_fn(&mut self,
+                fk: FnKind<'v>,
+                fd: &'v hir::FnDecl,
+                b: &'v hir::Block,
+                s: Span,
+                fn_id: ast::NodeId) {
+        let is_fn = match fk {
+            FnKind::ItemFn(..) | FnKind::Method(..) => true,
+            FnKind::Closure(..) => false,
+        };
+        if is_fn && attr::contains_name(fk.attrs(), "naked") {
+            self.check_params(fd);
+            self.check_body(b);
+        }
+        intravisit::walk_fn(self, fk, fd, b, s, fn_id)
+    }
+}
+
+impl<'a> CheckNakedFunctions<'a> {
+    fn check_params(&self, fd: &hir::FnDecl) {
+        for arg in &fd.inputs {
+            match arg.pat.node {
+                PatKind::Wild |
+                PatKind::Binding(hir::BindByValue(_), _, None) => {}
+                _ => {
+                    span_err!(self.sess, arg.pat.span, E0566,
+                              "patterns aren't allowed in naked function parameters");
+                }
+            }
+        }
+    }
+
+    fn check_body(&self, b: &hir::Block) {
+        match single_asm(b) {
+            Some(&hir::ExprInlineAsm(_, ref outputs, ref inputs)) => {
+                let mut visitor = CheckOperands {
+                    sess: self.sess,
+                    def_map: self.def_map,
+                };
+                for expr in outputs.iter().chain(inputs) {
+                    visitor.visit_expr(expr);
+                }
+            }
+            _ => {
+                span_err!(self.sess, b.span, E0567,
+                          "naked functions must contain a single asm block");
+            }
+        }
+    }
+}
+
+/// Returns the `asm!` expression making up the whole of `b`. The asm may
+/// be wrapped in blocks, e.g. `unsafe { asm!(...) }`, and may be followed
+/// by a semicolon.
+fn single_asm(b: &hir::Block) -> Option<&hir::Expr_> {
+    let expr = match (b.stmts.len(), &b.expr) {
+        (0, &Some(ref expr)) => expr,
+        (1, &None) => match b.stmts[0].node {
+            hir::StmtExpr(ref expr, _) | hir::StmtSemi(ref expr, _) => expr,
+            hir::StmtDecl(..) => return None,
+        },
+        _ => return None,
+    };
+    match expr.node {
+        hir::ExprInlineAsm(..) => Some(&expr.node),
+        hir::ExprBlock(ref b) => single_asm(b),
+        _ => None,
+    }
+}
+
+/// Reports uses of local variables in the operands of an `asm!` block. In
+/// a naked function all of these are the function's parameters, which
+/// don't live anywhere the asm could refer to.
+struct CheckOperands<'a> {
+    sess: &'a Session,
+    def_map: &'a DefMap,
+}
+
+impl<'a, 'v> Visitor<'v> for CheckOperands<'a> {
+    fn visit_expr(&mut self, e: &hir::Expr) {
+        if let hir::ExprPath(..) = e.node {
+            if let Some(Def::Local(..)) = self.def_map.get(&e.id).map(|d| d.base_def) {
+                span_err!(self.sess, e.span, E0568,
+                          "referencing function parameters is not allowed in naked \
+                           functions");
+            }
+        }
+        intravisit::walk_expr(self, e)
+    }
+}
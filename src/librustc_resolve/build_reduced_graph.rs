@@ -307,6 +307,7 @@ impl<'b> Resolver<'b> {
                     self.trait_item_map.insert((item.ident.name, def_id), is_static_method);
                 }
             }
+            ItemKind::TraitAlias(..) => panic!("unexpanded trait alias in resolve!"),
             ItemKind::Mac(_) => panic!("unexpanded macro in resolve!"),
         }
     }
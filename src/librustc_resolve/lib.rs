@@ -139,6 +139,7 @@ enum ResolutionError<'a> {
         is_static_method: bool,
         is_field: bool,
         def: Def,
+        candidates: Option<SuggestedCandidates>,
     },
     /// error E0426: use of undeclared label
     UndeclaredLabel(&'a str),
@@ -338,7 +339,7 @@ fn resolve_struct_error<'b, 'a: 'b, 'c>(resolver: &'b Resolver<'a>,
                              argument is missing?")
         }
         ResolutionError::UnresolvedName { path, message: msg, context, is_static_method,
-                                          is_field, def } => {
+                                          is_field, def, candidates } => {
             let mut err = struct_span_err!(resolver.session,
                                            span,
                                            E0425,
@@ -351,6 +352,13 @@ fn resolve_struct_error<'b, 'a: 'b, 'c>(resolver: &'b Resolver<'a>,
                         err.help("this is an associated function, you don't have access to \
                                   this type's fields or methods");
                     }
+                    // Most unresolved values are misspelled locals, so only
+                    // mention imports when there is something to import.
+                    if let Some(ref candidates) = candidates {
+                        if !candidates.candidates.is_empty() {
+                            show_candidates(&mut err, candidates);
+                        }
+                    }
                 }
                 UnresolvedNameContext::PathIsMod(parent) => {
                     err.help(&match parent.map(|parent| &parent.node) {
@@ -1086,6 +1094,7 @@ impl<'a> hir::lowering::Resolver for Resolver<'a> {
                         is_static_method: false,
                         is_field: false,
                         def: Def::Err,
+                        candidates: None,
                     };
                 resolve_error(self, path.span, error);
                 Def::Err
@@ -1709,6 +1718,7 @@ impl<'a> Resolver<'a> {
                 // do nothing, these are just around to be encoded
             }
 
+            ItemKind::TraitAlias(..) => panic!("unexpanded trait alias in resolve!"),
             ItemKind::Mac(_) => panic!("unexpanded macro in resolve!"),
         }
     }
@@ -2914,6 +2924,7 @@ impl<'a> Resolver<'a> {
 
                                 let mut context =  UnresolvedNameContext::Other;
                                 let mut def = Def::Err;
+                                let mut candidates = None;
                                 if !msg.is_empty() {
                                     msg = format!(". Did you mean {}?", msg);
                                 } else {
@@ -2933,6 +2944,22 @@ impl<'a> Resolver<'a> {
                                         },
                                         _ => {},
                                     };
+
+                                    if context == UnresolvedNameContext::Other &&
+                                       !path.global && path.segments.len() == 1 {
+                                        candidates = Some(self.lookup_candidates(
+                                            last_name,
+                                            ValueNS,
+                                            |def| match def {
+                                                Def::Fn(_) |
+                                                Def::Const(_) |
+                                                Def::Static(..) |
+                                                Def::Struct(_) |
+                                                Def::Variant(..) => true,
+                                                _                => false,
+                                            },
+                                        ));
+                                    }
                                 }
 
                                 resolve_error(self,
@@ -2944,6 +2971,7 @@ impl<'a> Resolver<'a> {
                                                   is_static_method: method_scope && is_static,
                                                   is_field: is_field,
                                                   def: def,
+                                                  candidates: candidates,
                                               });
                             }
                         }
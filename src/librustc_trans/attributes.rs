@@ -9,9 +9,12 @@
 // except according to those terms.
 //! Set and unset common attributes on LLVM values.
 
+use std::ffi::{CStr, CString};
+
 use llvm::{self, Attribute, ValueRef};
 use llvm::AttributePlace::Function;
 pub use syntax::attr::InlineAttr;
+use rustc::session::config::Sanitizer;
 use syntax::ast;
 use context::CrateContext;
 
@@ -64,9 +67,67 @@ pub fn set_frame_pointer_elimination(ccx: &CrateContext, llfn: ValueRef) {
         llvm::AddFunctionAttrStringValue(
             llfn,
             llvm::AttributePlace::Function,
-            "no-frame-pointer-elim\0",
-            "true\0")
+            cstr("no-frame-pointer-elim\0"),
+            cstr("true\0"))
+    }
+}
+
+/// Whether large stack frames have to be probed, so that overflowing the
+/// stack always faults on the guard page instead of skipping over it.
+pub fn stack_probes(ccx: &CrateContext) -> bool {
+    // AddressSanitizer already catches stack overflows, and its instrumented
+    // frames don't get along with the probes.
+    ccx.sess().target.target.options.stack_probes &&
+        ccx.sess().opts.debugging_opts.sanitizer != Some(Sanitizer::Address)
+}
+
+/// Whether LLVM inserts the stack probes itself. Only the X86 backend of
+/// LLVM 5 and later implements the `probe-stack` attribute; with older
+/// versions trans probes the frame instead, see
+/// `FunctionContext::probe_stack_frame`.
+pub fn llvm_probes_stack() -> bool {
+    unsafe { llvm::LLVMRustVersionMajor() >= 5 }
+}
+
+/// Make LLVM probe each page of large stack frames by calling
+/// `__rust_probestack` from the prologue.
+pub fn set_probestack(ccx: &CrateContext, llfn: ValueRef) {
+    if !stack_probes(ccx) || !llvm_probes_stack() {
+        return
     }
+
+    llvm::AddFunctionAttrStringValue(
+        llfn,
+        llvm::AttributePlace::Function,
+        cstr("probe-stack\0"),
+        cstr("__rust_probestack\0"));
+}
+
+/// Enable the target features requested with `#[target_feature(enable = "...")]`
+/// for this function only, on top of the ones enabled for the whole crate.
+pub fn target_features(llfn: ValueRef, attrs: &[ast::Attribute]) {
+    use syntax::attr::AttrMetaMethods;
+
+    let features = attrs.iter()
+        .filter(|attr| attr.check_name("target_feature"))
+        .flat_map(|attr| attr.meta_item_list().unwrap_or(&[]))
+        .filter(|item| item.check_name("enable"))
+        .filter_map(|item| item.value_str())
+        .flat_map(|value| {
+            value.split(',').map(|feature| format!("+{}", feature.trim())).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    if !features.is_empty() {
+        let value = CString::new(features.join(",")).unwrap();
+        llvm::AddFunctionAttrStringValue(llfn,
+                                         llvm::AttributePlace::Function,
+                                         cstr("target-features\0"),
+                                         &value);
+    }
+}
+
+fn cstr(s: &'static str) -> &'static CStr {
+    CStr::from_bytes_with_nul(s.as_bytes()).unwrap()
 }
 
 /// Composite function which sets LLVM attributes for function depending on its AST (#[attribute])
@@ -76,6 +137,7 @@ pub fn from_fn_attrs(ccx: &CrateContext, attrs: &[ast::Attribute], llfn: ValueRe
     inline(llfn, find_inline_attr(Some(ccx.sess().diagnostic()), attrs));
 
     set_frame_pointer_elimination(ccx, llfn);
+    target_features(llfn, attrs);
 
     for attr in attrs {
         if attr.check_name("cold") {
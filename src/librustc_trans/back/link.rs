@@ -13,9 +13,10 @@ use super::linker::Linker;
 use super::rpath::RPathConfig;
 use super::rpath;
 use super::msvc;
+use super::lto;
 use session::config;
 use session::config::NoDebugInfo;
-use session::config::{OutputFilenames, Input, OutputType};
+use session::config::{OutputFilenames, Input, OutputType, Sanitizer, SplitDebuginfo};
 use session::filesearch;
 use session::search_paths::PathKind;
 use session::Session;
@@ -206,10 +207,29 @@ pub fn link_binary(sess: &Session,
 
     // Remove the temporary object file and metadata if we aren't saving temps
     if !sess.opts.cg.save_temps {
-        for obj in object_filenames(trans, outputs) {
-            remove(sess, &obj);
+        // Unpacked debuginfo on OSX is read out of the objects themselves.
+        let keep_objects = sess.target.target.options.is_like_osx &&
+                           sess.opts.debuginfo != NoDebugInfo &&
+                           sess.split_debuginfo() == SplitDebuginfo::Unpacked;
+        if !keep_objects {
+            for obj in object_filenames(sess, trans, outputs) {
+                remove(sess, &obj);
+            }
         }
         remove(sess, &outputs.with_extension("metadata.o"));
+
+        // Once packed into a .dwp the .dwo files are no longer needed, unless
+        // an rlib or staticlib still refers to them.
+        let all_packed = sess.crate_types.borrow().iter().all(|&crate_type| {
+            crate_type != config::CrateTypeRlib &&
+                crate_type != config::CrateTypeStaticlib
+        });
+        if sess.split_dwarf() && sess.split_debuginfo() == SplitDebuginfo::Packed &&
+           all_packed {
+            for module in trans.modules.iter() {
+                remove(sess, &outputs.temp_path_ext("dwo", Some(&module.name[..])));
+            }
+        }
     }
 
     out_filenames
@@ -240,6 +260,7 @@ pub fn invalid_output_for_target(sess: &Session,
            sess.target.target.options.executables, crate_type) {
         (false, _, config::CrateTypeCdylib) |
         (false, _, config::CrateTypeDylib) => true,
+        (true, _, config::CrateTypeDylib) => sess.target.target.options.only_cdylib,
         (_, false, config::CrateTypeExecutable) => true,
         _ => false
     }
@@ -317,7 +338,7 @@ fn link_binary_output(sess: &Session,
                       crate_type: config::CrateType,
                       outputs: &OutputFilenames,
                       crate_name: &str) -> PathBuf {
-    let objects = object_filenames(trans, outputs);
+    let objects = object_filenames(sess, trans, outputs);
     let default_filename = filename_for_input(sess, crate_type, crate_name,
                                               outputs);
     let out_filename = outputs.outputs.get(&OutputType::Exe)
@@ -357,12 +378,19 @@ fn link_binary_output(sess: &Session,
     out_filename
 }
 
-fn object_filenames(trans: &CrateTranslation,
+fn object_filenames(sess: &Session,
+                    trans: &CrateTranslation,
                     outputs: &OutputFilenames)
                     -> Vec<PathBuf> {
-    trans.modules.iter().map(|module| {
+    let mut objects: Vec<_> = trans.modules.iter().map(|module| {
         outputs.temp_path(OutputType::Object, Some(&module.name[..]))
-    }).collect()
+    }).collect();
+    // With ThinLTO across crates the upstream crates are compiled anew, and
+    // the objects in their rlibs are skipped.
+    if sess.lto() && sess.thin_lto() {
+        objects.extend(lto::thin_lto_upstream_objects(sess, outputs));
+    }
+    objects
 }
 
 fn archive_search_paths(sess: &Session) -> Vec<PathBuf> {
@@ -574,6 +602,10 @@ fn link_staticlib(sess: &Session, objects: &[PathBuf], out_filename: &Path,
     if !sess.target.target.options.no_compiler_rt {
         ab.add_native_library("compiler-rt");
     }
+    if sess.opts.cg.profile_generate.is_some() {
+        check_profiler_runtime(sess);
+        ab.add_native_library("profiler-rt");
+    }
 
     let mut all_native_libs = vec![];
 
@@ -609,6 +641,42 @@ fn link_staticlib(sess: &Session, objects: &[PathBuf], out_filename: &Path,
 //
 // This will invoke the system linker/cc to create the resulting file. This
 // links to all upstream files as well.
+// Returns the sanitizer whose runtime the output needs: that of this crate,
+// or otherwise that of any instrumented crate linked into it. The dependency
+// format checks have already ensured these all agree.
+/// Checks that the profiler runtime linked into instrumented code is part of
+/// the sysroot. It's only built when Rust is configured with the `profiler`
+/// option, so without this the linker would fail to find it.
+fn check_profiler_runtime(sess: &Session) {
+    let options = &sess.target.target.options;
+    let name = format!("{}profiler-rt{}", options.staticlib_prefix, options.staticlib_suffix);
+    let path = sess.target_filesearch(PathKind::Native).get_lib_path().join(&name);
+    if !path.is_file() {
+        sess.fatal(&format!("`-C profile-generate` requires the profiler runtime `{}`, \
+                             which was not built for this installation of Rust; build \
+                             Rust with `profiler = true` in config.toml (or with \
+                             `--enable-profiler`) to get it",
+                            path.display()));
+    }
+}
+
+fn sanitizer_runtime(sess: &Session, crate_type: config::CrateType) -> Option<Sanitizer> {
+    if let Some(sanitizer) = sess.opts.debugging_opts.sanitizer {
+        return Some(sanitizer)
+    }
+    let formats = sess.dependency_formats.borrow();
+    let data = match formats.get(&crate_type) {
+        Some(data) => data,
+        None => return None,
+    };
+    data.iter().enumerate().filter_map(|(i, linkage)| {
+        match *linkage {
+            Linkage::NotLinked => None,
+            _ => sess.cstore.sanitizer((i + 1) as ast::CrateNum),
+        }
+    }).next()
+}
+
 fn link_natively(sess: &Session,
                  crate_type: config::CrateType,
                  objects: &[PathBuf],
@@ -623,7 +691,11 @@ fn link_natively(sess: &Session,
     cmd.env("PATH", command_path(sess, extra));
 
     let root = sess.target_filesearch(PathKind::Native).get_lib_path();
+    let crt_static = sess.crt_static();
     cmd.args(&sess.target.target.options.pre_link_args);
+    if crt_static {
+        cmd.args(&sess.target.target.options.pre_link_args_crt);
+    }
 
     let pre_link_objects = if crate_type == config::CrateTypeExecutable {
         &sess.target.target.options.pre_link_objects_exe
@@ -633,6 +705,11 @@ fn link_natively(sess: &Session,
     for obj in pre_link_objects {
         cmd.arg(root.join(obj));
     }
+    if crate_type == config::CrateTypeExecutable && crt_static {
+        for obj in &sess.target.target.options.pre_link_objects_exe_crt {
+            cmd.arg(root.join(obj));
+        }
+    }
 
     {
         let mut linker = trans.linker_info.to_linker(&mut cmd, &sess);
@@ -641,11 +718,34 @@ fn link_natively(sess: &Session,
         if !sess.target.target.options.no_compiler_rt {
             linker.link_staticlib("compiler-rt");
         }
+        if sess.opts.cg.profile_generate.is_some() {
+            check_profiler_runtime(sess);
+            linker.link_staticlib("profiler-rt");
+            linker.pgo_gen();
+        }
+        if crate_type == config::CrateTypeExecutable {
+            if let Some(sanitizer) = sanitizer_runtime(sess, crate_type) {
+                let lib = match sanitizer {
+                    Sanitizer::Address => "rustc_asan",
+                    Sanitizer::Leak => "rustc_lsan",
+                    Sanitizer::Memory => "rustc_msan",
+                    Sanitizer::Thread => "rustc_tsan",
+                };
+                // The runtimes intercept libc functions, so all of their
+                // objects must be linked in whether referenced or not.
+                linker.link_whole_staticlib(lib, &archive_search_paths(sess));
+            }
+        }
     }
     cmd.args(&sess.target.target.options.late_link_args);
     for obj in &sess.target.target.options.post_link_objects {
         cmd.arg(root.join(obj));
     }
+    if crt_static {
+        for obj in &sess.target.target.options.post_link_objects_crt {
+            cmd.arg(root.join(obj));
+        }
+    }
     cmd.args(&sess.target.target.options.post_link_args);
 
     if sess.opts.debugging_opts.print_link_args {
@@ -699,12 +799,36 @@ fn link_natively(sess: &Session,
     }
 
 
-    // On OSX, debuggers need this utility to get run to do some munging of
-    // the symbols
-    if sess.target.target.options.is_like_osx && sess.opts.debuginfo != NoDebugInfo {
-        match Command::new("dsymutil").arg(out_filename).output() {
-            Ok(..) => {}
-            Err(e) => sess.fatal(&format!("failed to run dsymutil: {}", e)),
+    // On OSX the debuginfo is only referred to by the output, so unless it
+    // is to stay unpacked in the objects dsymutil gathers it into a .dSYM
+    // before the objects are removed. Packed split DWARF is gathered from
+    // the .dwo files into a .dwp by dwp.
+    if sess.opts.debuginfo != NoDebugInfo {
+        if sess.target.target.options.is_like_osx {
+            if sess.split_debuginfo() != SplitDebuginfo::Unpacked {
+                match Command::new("dsymutil").arg(out_filename).output() {
+                    Ok(..) => {}
+                    Err(e) => sess.fatal(&format!("failed to run dsymutil: {}", e)),
+                }
+            }
+        } else if sess.split_dwarf() && sess.split_debuginfo() == SplitDebuginfo::Packed {
+            let mut dwp = out_filename.as_os_str().to_owned();
+            dwp.push(".dwp");
+            let mut cmd = Command::new("dwp");
+            cmd.arg("-e").arg(out_filename).arg("-o").arg(&dwp);
+            match cmd.output() {
+                Ok(prog) => {
+                    if !prog.status.success() {
+                        sess.struct_err(&format!("packing debuginfo with `dwp` failed: {}",
+                                                 prog.status))
+                            .note(&format!("{:?}", &cmd))
+                            .note(&String::from_utf8_lossy(&prog.stderr))
+                            .emit();
+                        sess.abort_if_errors();
+                    }
+                }
+                Err(e) => sess.fatal(&format!("failed to run dwp: {}", e)),
+            }
         }
     }
 }
@@ -752,8 +876,10 @@ fn link_args(cmd: &mut Linker,
 
     let used_link_args = sess.cstore.used_link_args();
 
+    // A statically linked C runtime can't be combined with `-pie`.
     if crate_type == config::CrateTypeExecutable &&
-       t.options.position_independent_executables {
+       t.options.position_independent_executables &&
+       !sess.crt_static() {
         let empty_vec = Vec::new();
         let empty_str = String::new();
         let args = sess.opts.cg.link_args.as_ref().unwrap_or(&empty_vec);
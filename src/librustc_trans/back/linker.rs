@@ -8,7 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::ffi::OsString;
+use std::ffi::{OsStr, OsString};
 use std::fs::{self, File};
 use std::io::{self, BufWriter};
 use std::io::prelude::*;
@@ -20,6 +20,7 @@ use monomorphize::Instance;
 
 use back::archive;
 use middle::dependency_format::Linkage;
+use rustc_back::LinkerFlavor;
 use session::Session;
 use session::config::CrateType;
 use session::config;
@@ -44,18 +45,30 @@ impl<'a, 'tcx> LinkerInfo {
     pub fn to_linker(&'a self,
                      cmd: &'a mut Command,
                      sess: &'a Session) -> Box<Linker+'a> {
-        if sess.target.target.options.is_like_msvc {
-            Box::new(MsvcLinker {
-                cmd: cmd,
-                sess: sess,
-                info: self
-            }) as Box<Linker>
-        } else {
-            Box::new(GnuLinker {
-                cmd: cmd,
-                sess: sess,
-                info: self
-            }) as Box<Linker>
+        match sess.target.target.options.linker_flavor {
+            LinkerFlavor::Msvc => {
+                Box::new(MsvcLinker {
+                    cmd: cmd,
+                    sess: sess,
+                    info: self
+                }) as Box<Linker>
+            }
+            LinkerFlavor::Wasm => {
+                Box::new(WasmLd {
+                    cmd: cmd,
+                    sess: sess,
+                    info: self
+                }) as Box<Linker>
+            }
+            flavor @ LinkerFlavor::Gcc |
+            flavor @ LinkerFlavor::Ld => {
+                Box::new(GnuLinker {
+                    cmd: cmd,
+                    sess: sess,
+                    info: self,
+                    is_ld: flavor == LinkerFlavor::Ld,
+                }) as Box<Linker>
+            }
         }
     }
 }
@@ -65,8 +78,9 @@ impl<'a, 'tcx> LinkerInfo {
 ///
 /// This trait is the total list of requirements needed by `back::link` and
 /// represents the meaning of each option being passed down. This trait is then
-/// used to dispatch on whether a GNU-like linker (generally `ld.exe`) or an
-/// MSVC linker (e.g. `link.exe`) is being used.
+/// used to dispatch on the target's `linker_flavor`: a GNU-like linker, either
+/// driven through `cc` or invoked directly as `ld`, an MSVC linker (e.g.
+/// `link.exe`) or LLD's wasm flavor.
 pub trait Linker {
     fn link_dylib(&mut self, lib: &str);
     fn link_rust_dylib(&mut self, lib: &str, path: &Path);
@@ -91,18 +105,34 @@ pub trait Linker {
     fn whole_archives(&mut self);
     fn no_whole_archives(&mut self);
     fn export_symbols(&mut self, tmpdir: &Path, crate_type: CrateType);
+    fn pgo_gen(&mut self);
 }
 
 pub struct GnuLinker<'a> {
     cmd: &'a mut Command,
     sess: &'a Session,
-    info: &'a LinkerInfo
+    info: &'a LinkerInfo,
+    // Whether the linker is invoked directly rather than through `cc`.
+    is_ld: bool,
 }
 
 impl<'a> GnuLinker<'a> {
     fn takes_hints(&self) -> bool {
         !self.sess.target.target.options.is_like_osx
     }
+
+    /// Passes `arg` to the linker itself, which when going through `cc`
+    /// means wrapping it in `-Wl,`.
+    fn linker_arg<S: AsRef<OsStr>>(&mut self, arg: S) -> &mut Self {
+        if self.is_ld {
+            self.cmd.arg(arg);
+        } else {
+            let mut s = OsString::from("-Wl,");
+            s.push(arg);
+            self.cmd.arg(s);
+        }
+        self
+    }
 }
 
 impl<'a> Linker for GnuLinker<'a> {
@@ -127,26 +157,24 @@ impl<'a> Linker for GnuLinker<'a> {
     fn link_whole_staticlib(&mut self, lib: &str, search_path: &[PathBuf]) {
         let target = &self.sess.target.target;
         if !target.options.is_like_osx {
-            self.cmd.arg("-Wl,--whole-archive")
-                    .arg("-l").arg(lib)
-                    .arg("-Wl,--no-whole-archive");
+            self.linker_arg("--whole-archive");
+            self.cmd.arg("-l").arg(lib);
+            self.linker_arg("--no-whole-archive");
         } else {
             // -force_load is the OSX equivalent of --whole-archive, but it
             // involves passing the full path to the library to link.
-            let mut v = OsString::from("-Wl,-force_load,");
-            v.push(&archive::find_library(lib, search_path, &self.sess));
-            self.cmd.arg(&v);
+            let lib = archive::find_library(lib, search_path, &self.sess);
+            self.linker_arg("-force_load").linker_arg(&lib);
         }
     }
 
     fn link_whole_rlib(&mut self, lib: &Path) {
         if self.sess.target.target.options.is_like_osx {
-            let mut v = OsString::from("-Wl,-force_load,");
-            v.push(lib);
-            self.cmd.arg(&v);
+            self.linker_arg("-force_load").linker_arg(lib);
         } else {
-            self.cmd.arg("-Wl,--whole-archive").arg(lib)
-                    .arg("-Wl,--no-whole-archive");
+            self.linker_arg("--whole-archive");
+            self.cmd.arg(lib);
+            self.linker_arg("--no-whole-archive");
         }
     }
 
@@ -166,10 +194,9 @@ impl<'a> Linker for GnuLinker<'a> {
         // for partial linking when using multiple codegen units (-r).  So we
         // insert it here.
         if self.sess.target.target.options.is_like_osx {
-            self.cmd.arg("-Wl,-dead_strip");
+            self.linker_arg("-dead_strip");
         } else if self.sess.target.target.options.is_like_solaris {
-            self.cmd.arg("-Wl,-z");
-            self.cmd.arg("-Wl,ignore");
+            self.linker_arg("-z").linker_arg("ignore");
 
         // If we're building a dylib, we don't use --gc-sections because LLVM
         // has already done the best it can do, and we also don't want to
@@ -177,7 +204,7 @@ impl<'a> Linker for GnuLinker<'a> {
         // --gc-sections drops the size of hello world from 1.8MB to 597K, a 67%
         // reduction.
         } else if !keep_metadata {
-            self.cmd.arg("-Wl,--gc-sections");
+            self.linker_arg("--gc-sections");
         }
     }
 
@@ -188,7 +215,7 @@ impl<'a> Linker for GnuLinker<'a> {
         // need a numeric argument, but other linkers do.
         if self.sess.opts.optimize == config::OptLevel::Default ||
            self.sess.opts.optimize == config::OptLevel::Aggressive {
-            self.cmd.arg("-Wl,-O1");
+            self.linker_arg("-O1");
         }
     }
 
@@ -197,18 +224,24 @@ impl<'a> Linker for GnuLinker<'a> {
     }
 
     fn no_default_libraries(&mut self) {
-        self.cmd.arg("-nodefaultlibs");
+        // `ld` never links any default libraries in the first place.
+        if !self.is_ld {
+            self.cmd.arg("-nodefaultlibs");
+        }
     }
 
     fn build_dylib(&mut self, out_filename: &Path) {
         // On mac we need to tell the linker to let this library be rpathed
         if self.sess.target.target.options.is_like_osx {
-            self.cmd.args(&["-dynamiclib", "-Wl,-dylib"]);
+            if !self.is_ld {
+                self.cmd.arg("-dynamiclib");
+            }
+            self.linker_arg("-dylib");
 
             if self.sess.opts.cg.rpath {
-                let mut v = OsString::from("-Wl,-install_name,@rpath/");
+                let mut v = OsString::from("@rpath/");
                 v.push(out_filename.file_name().unwrap());
-                self.cmd.arg(&v);
+                self.linker_arg("-install_name").linker_arg(&v);
             }
         } else {
             self.cmd.arg("-shared");
@@ -217,22 +250,22 @@ impl<'a> Linker for GnuLinker<'a> {
 
     fn whole_archives(&mut self) {
         if !self.takes_hints() { return }
-        self.cmd.arg("-Wl,--whole-archive");
+        self.linker_arg("--whole-archive");
     }
 
     fn no_whole_archives(&mut self) {
         if !self.takes_hints() { return }
-        self.cmd.arg("-Wl,--no-whole-archive");
+        self.linker_arg("--no-whole-archive");
     }
 
     fn hint_static(&mut self) {
         if !self.takes_hints() { return }
-        self.cmd.arg("-Wl,-Bstatic");
+        self.linker_arg("-Bstatic");
     }
 
     fn hint_dynamic(&mut self) {
         if !self.takes_hints() { return }
-        self.cmd.arg("-Wl,-Bdynamic");
+        self.linker_arg("-Bdynamic");
     }
 
     fn export_symbols(&mut self, tmpdir: &Path, crate_type: CrateType) {
@@ -263,14 +296,21 @@ impl<'a> Linker for GnuLinker<'a> {
         if let Err(e) = res {
             self.sess.fatal(&format!("failed to write lib.def file: {}", e));
         }
-        let mut arg = OsString::new();
         if self.sess.target.target.options.is_like_osx {
-            arg.push("-Wl,-exported_symbols_list,");
+            self.linker_arg("-exported_symbols_list").linker_arg(&path);
         } else {
-            arg.push("-Wl,--retain-symbols-file=");
+            let mut arg = OsString::from("--retain-symbols-file=");
+            arg.push(&path);
+            self.linker_arg(arg);
         }
-        arg.push(&path);
-        self.cmd.arg(arg);
+    }
+
+    fn pgo_gen(&mut self) {
+        // Nothing in the instrumented code refers to the part of the profiler
+        // runtime which writes out the profile at exit, so make the linker
+        // pull it in. On OSX the instrumentation takes care of this itself.
+        if self.sess.target.target.options.is_like_osx { return }
+        self.linker_arg("-u").linker_arg("__llvm_profile_runtime");
     }
 }
 
@@ -297,6 +337,9 @@ impl<'a> Linker for MsvcLinker<'a> {
     }
 
     fn link_dylib(&mut self, lib: &str) {
+        // `msvcrt` is the import library of the dynamic C runtime; a static
+        // one is linked through `libcmt` instead.
+        let lib = if lib == "msvcrt" && self.sess.crt_static() { "libcmt" } else { lib };
         self.cmd.arg(&format!("{}.lib", lib));
     }
 
@@ -424,6 +467,109 @@ impl<'a> Linker for MsvcLinker<'a> {
         arg.push(path);
         self.cmd.arg(&arg);
     }
+
+    fn pgo_gen(&mut self) {
+        // The profiler runtime isn't built for MSVC targets.
+    }
+}
+
+/// LLD invoked directly with `-flavor wasm`, rather than through a C
+/// compiler, so arguments aren't prefixed with `-Wl,` here.
+pub struct WasmLd<'a> {
+    cmd: &'a mut Command,
+    sess: &'a Session,
+    info: &'a LinkerInfo
+}
+
+impl<'a> Linker for WasmLd<'a> {
+    fn link_dylib(&mut self, lib: &str) { self.cmd.arg("-l").arg(lib); }
+    fn link_staticlib(&mut self, lib: &str) { self.cmd.arg("-l").arg(lib); }
+    fn link_rlib(&mut self, lib: &Path) { self.cmd.arg(lib); }
+    fn include_path(&mut self, path: &Path) { self.cmd.arg("-L").arg(path); }
+    fn output_filename(&mut self, path: &Path) { self.cmd.arg("-o").arg(path); }
+    fn add_object(&mut self, path: &Path) { self.cmd.arg(path); }
+    fn args(&mut self, args: &[String]) { self.cmd.args(args); }
+
+    fn link_rust_dylib(&mut self, lib: &str, _path: &Path) {
+        // Rust dylibs are rejected for this target before we get here.
+        bug!("can't link to the Rust dylib `{}` in a wasm module", lib)
+    }
+
+    fn link_framework(&mut self, _framework: &str) {
+        bug!("frameworks are not supported on wasm")
+    }
+
+    fn framework_path(&mut self, _path: &Path) {
+        bug!("frameworks are not supported on wasm")
+    }
+
+    fn link_whole_staticlib(&mut self, lib: &str, _search_path: &[PathBuf]) {
+        self.cmd.arg("--whole-archive")
+                .arg("-l").arg(lib)
+                .arg("--no-whole-archive");
+    }
+
+    fn link_whole_rlib(&mut self, lib: &Path) {
+        self.cmd.arg("--whole-archive")
+                .arg(lib)
+                .arg("--no-whole-archive");
+    }
+
+    fn gc_sections(&mut self, _keep_metadata: bool) {
+        // Metadata never ends up in a wasm module, dylibs aren't supported.
+        self.cmd.arg("--gc-sections");
+    }
+
+    fn position_independent_executable(&mut self) {
+        // WebAssembly has no notion of position independent code.
+    }
+
+    fn optimize(&mut self) {
+        // LLVM has already optimized the modules, LLD only links them.
+    }
+
+    fn debuginfo(&mut self) {
+        if self.sess.opts.debuginfo == config::NoDebugInfo {
+            self.cmd.arg("--strip-debug");
+        }
+    }
+
+    fn no_default_libraries(&mut self) {
+        // LLD doesn't link any libraries by default.
+    }
+
+    fn build_dylib(&mut self, _out_filename: &Path) {
+        // A `cdylib` is a module without an entry point, only exports. This
+        // overrides the `--entry=main` in the target's pre-link arguments.
+        self.cmd.arg("--no-entry");
+    }
+
+    fn hint_static(&mut self) {}
+    fn hint_dynamic(&mut self) {}
+
+    fn whole_archives(&mut self) {
+        self.cmd.arg("--whole-archive");
+    }
+
+    fn no_whole_archives(&mut self) {
+        self.cmd.arg("--no-whole-archive");
+    }
+
+    fn export_symbols(&mut self, _tmpdir: &Path, crate_type: CrateType) {
+        // Everything which isn't exported explicitly stays internal to the
+        // module, so the cdylib's public symbols are listed here.
+        if crate_type != CrateType::CrateTypeCdylib {
+            return
+        }
+        for sym in &self.info.cdylib_exports {
+            self.cmd.arg(&format!("--export={}", sym));
+        }
+    }
+
+    fn pgo_gen(&mut self) {
+        // There's no profiler runtime for wasm, and no file system to write
+        // a profile to.
+    }
 }
 
 fn exported_symbols(scx: &SharedCrateContext,
@@ -11,111 +11,47 @@
 use super::link;
 use super::write;
 use rustc::session::{self, config};
+use rustc::session::config::{OutputFilenames, OutputType};
 use llvm;
 use llvm::archive_ro::ArchiveRO;
 use llvm::{ModuleRef, TargetMachineRef, True, False};
 use rustc::util::common::time;
 use rustc::util::common::path2cstr;
 use back::write::{ModuleConfig, with_llvm_pmb};
+use CrateTranslation;
 
 use libc;
 use flate;
 
 use std::ffi::CString;
-use std::path::Path;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::slice;
 
 pub fn run(sess: &session::Session, llmod: ModuleRef,
            tm: TargetMachineRef, reachable: &[String],
            config: &ModuleConfig,
            temp_no_opt_bc_filename: &Path) {
-    if sess.opts.cg.prefer_dynamic {
-        sess.struct_err("cannot prefer dynamic linking when performing LTO")
-            .note("only 'staticlib', 'bin', and 'cdylib' outputs are \
-                   supported with LTO")
-            .emit();
-        sess.abort_if_errors();
-    }
-
-    // Make sure we actually can run LTO
-    for crate_type in sess.crate_types.borrow().iter() {
-        match *crate_type {
-            config::CrateTypeExecutable |
-            config::CrateTypeCdylib |
-            config::CrateTypeStaticlib => {}
-            _ => {
-                sess.fatal("lto can only be run for executables and \
-                            static library outputs");
-            }
-        }
-    }
+    check_crate_types(sess);
 
     // For each of our upstream dependencies, find the corresponding rlib and
     // load the bitcode from the archive. Then merge it into the current LLVM
     // module that we've got.
-    link::each_linked_rlib(sess, &mut |cnum, path| {
-        // `#![no_builtins]` crates don't participate in LTO.
-        if sess.cstore.is_no_builtins(cnum) {
-            return;
-        }
+    each_upstream_bytecode(sess, &mut |name, _, bc_encoded| {
+        let bc_decoded = decode_bytecode(sess, name, bc_encoded);
 
-        let archive = ArchiveRO::open(&path).expect("wanted an rlib");
-        let bytecodes = archive.iter().filter_map(|child| {
-            child.ok().and_then(|c| c.name().map(|name| (name, c)))
-        }).filter(|&(name, _)| name.ends_with("bytecode.deflate"));
-        for (name, data) in bytecodes {
-            let bc_encoded = data.data();
-
-            let bc_decoded = if is_versioned_bytecode_format(bc_encoded) {
-                time(sess.time_passes(), &format!("decode {}", name), || {
-                    // Read the version
-                    let version = extract_bytecode_format_version(bc_encoded);
-
-                    if version == 1 {
-                        // The only version existing so far
-                        let data_size = extract_compressed_bytecode_size_v1(bc_encoded);
-                        let compressed_data = &bc_encoded[
-                            link::RLIB_BYTECODE_OBJECT_V1_DATA_OFFSET..
-                            (link::RLIB_BYTECODE_OBJECT_V1_DATA_OFFSET + data_size as usize)];
-
-                        match flate::inflate_bytes(compressed_data) {
-                            Ok(inflated) => inflated,
-                            Err(_) => {
-                                sess.fatal(&format!("failed to decompress bc of `{}`",
-                                                   name))
-                            }
-                        }
-                    } else {
-                        sess.fatal(&format!("Unsupported bytecode format version {}",
-                                           version))
-                    }
-                })
-            } else {
-                time(sess.time_passes(), &format!("decode {}", name), || {
-                    // the object must be in the old, pre-versioning format, so
-                    // simply inflate everything and let LLVM decide if it can
-                    // make sense of it
-                    match flate::inflate_bytes(bc_encoded) {
-                        Ok(bc) => bc,
-                        Err(_) => {
-                            sess.fatal(&format!("failed to decompress bc of `{}`",
-                                               name))
-                        }
-                    }
-                })
-            };
-
-            let ptr = bc_decoded.as_ptr();
-            debug!("linking {}", name);
-            time(sess.time_passes(), &format!("ll link {}", name), || unsafe {
-                if !llvm::LLVMRustLinkInExternalBitcode(llmod,
-                                                        ptr as *const libc::c_char,
-                                                        bc_decoded.len() as libc::size_t) {
-                    write::llvm_err(sess.diagnostic(),
-                                    format!("failed to load bc of `{}`",
-                                            &name[..]));
-                }
-            });
-        }
+        let ptr = bc_decoded.as_ptr();
+        debug!("linking {}", name);
+        time(sess.time_passes(), &format!("ll link {}", name), || unsafe {
+            if !llvm::LLVMRustLinkInExternalBitcode(llmod,
+                                                    ptr as *const libc::c_char,
+                                                    bc_decoded.len() as libc::size_t) {
+                write::llvm_err(sess.diagnostic(),
+                                format!("failed to load bc of `{}`",
+                                        &name[..]));
+            }
+        });
     });
 
     // Internalize everything but the reachable symbols of the current module
@@ -174,6 +110,220 @@ pub fn run(sess: &session::Session, llmod: ModuleRef,
     debug!("lto done");
 }
 
+/// Runs the thin link over the codegen units of the local crate, which have
+/// been optimized and serialized along with their summaries by
+/// `write::optimize_and_codegen`, and for `-C lto=thin` over the bytecode of
+/// all upstream crates as well. LLVM then imports functions across module
+/// boundaries and generates an object file for each module.
+pub fn run_thin(sess: &session::Session,
+                trans: &CrateTranslation,
+                tm: TargetMachineRef,
+                output_names: &OutputFilenames) {
+    if sess.lto() {
+        check_crate_types(sess);
+    }
+
+    // Each module is the name LLVM knows it by, its bitcode, and the location
+    // its object file is written to.
+    let mut modules = Vec::new();
+    for mtrans in trans.modules.iter() {
+        let input = output_names.temp_path_ext("thin-lto.bc", Some(&mtrans.name));
+        let mut data = Vec::new();
+        if let Err(e) = File::open(&input).and_then(|mut f| f.read_to_end(&mut data)) {
+            sess.fatal(&format!("failed to read ThinLTO input {}: {}",
+                                input.display(), e));
+        }
+        if !sess.opts.cg.save_temps {
+            link::remove(sess, &input);
+        }
+        let object = output_names.temp_path(OutputType::Object, Some(&mtrans.name));
+        modules.push((mtrans.name.clone(), data, object));
+    }
+
+    if sess.lto() {
+        each_upstream_bytecode(sess, &mut |name, rlib, bc_encoded| {
+            let bc_decoded = decode_bytecode(sess, name, bc_encoded);
+            let data = time(sess.time_passes(), &format!("summarize {}", name), || {
+                summarize_bytecode(sess, name, &bc_decoded)
+            });
+            let object = upstream_object_path(output_names, rlib, name);
+            modules.push((name.to_string(), data, object));
+        });
+    }
+
+    unsafe {
+        let cg = llvm::LLVMRustThinLTOCodeGeneratorCreate(tm);
+        for &(ref name, ref data, _) in modules.iter() {
+            let name = CString::new(&name[..]).unwrap();
+            llvm::LLVMRustThinLTOAddModule(cg,
+                                           name.as_ptr(),
+                                           data.as_ptr() as *const libc::c_char,
+                                           data.len() as libc::size_t);
+        }
+        for symbol in trans.reachable.iter() {
+            let symbol = CString::new(&symbol[..]).unwrap();
+            llvm::LLVMRustThinLTOPreserveSymbol(cg, symbol.as_ptr());
+        }
+
+        time(sess.time_passes(), "ThinLTO passes", || llvm::LLVMRustThinLTORun(cg));
+
+        for (i, &(ref name, _, ref object)) in modules.iter().enumerate() {
+            let mut len = 0;
+            let ptr = llvm::LLVMRustThinLTOGetObject(cg, i as libc::size_t, &mut len);
+            if ptr.is_null() {
+                sess.fatal(&format!("ThinLTO didn't produce an object file for `{}`",
+                                    name));
+            }
+            let obj = slice::from_raw_parts(ptr as *const u8, len as usize);
+            if let Err(e) = File::create(object).and_then(|mut f| f.write_all(obj)) {
+                sess.fatal(&format!("failed to write {}: {}", object.display(), e));
+            }
+        }
+        llvm::LLVMRustThinLTOCodeGeneratorFree(cg);
+    }
+    debug!("thin lto done");
+}
+
+/// Returns the object files which `-C lto=thin` generates for the upstream
+/// crates. These take the place of the object files in the rlibs when
+/// linking.
+pub fn thin_lto_upstream_objects(sess: &session::Session,
+                                 output_names: &OutputFilenames)
+                                 -> Vec<PathBuf> {
+    let mut objects = Vec::new();
+    each_upstream_bytecode(sess, &mut |name, rlib, _| {
+        objects.push(upstream_object_path(output_names, rlib, name));
+    });
+    objects
+}
+
+fn upstream_object_path(output_names: &OutputFilenames,
+                        rlib: &Path,
+                        name: &str) -> PathBuf {
+    // Objects in different rlibs may share a name, so the rlib's (hashed)
+    // file name is part of the path as well.
+    let rlib = rlib.file_stem().unwrap().to_str().unwrap();
+    let name = &name[..name.len() - ".bytecode.deflate".len()];
+    output_names.temp_path(OutputType::Object,
+                           Some(&format!("thin-lto.{}.{}", rlib, name)))
+}
+
+/// Serializes the upstream bitcode `bc` along with the summary which ThinLTO
+/// needs to decide what to import from it.
+fn summarize_bytecode(sess: &session::Session, name: &str, bc: &[u8]) -> Vec<u8> {
+    unsafe {
+        let llcx = llvm::LLVMContextCreate();
+        let identifier = CString::new(name).unwrap();
+        let llmod = llvm::LLVMRustParseBitcodeForThinLTO(llcx,
+                                                         bc.as_ptr() as *const libc::c_char,
+                                                         bc.len() as libc::size_t,
+                                                         identifier.as_ptr());
+        if llmod.is_null() {
+            write::llvm_err(sess.diagnostic(),
+                            format!("failed to parse bc of `{}`", name));
+        }
+        if sess.no_landing_pads() {
+            llvm::LLVMRustMarkAllFunctionsNounwind(llmod);
+        }
+
+        let buffer = llvm::LLVMRustThinLTOBufferCreate(llmod);
+        let data = slice::from_raw_parts(llvm::LLVMRustThinLTOBufferPtr(buffer) as *const u8,
+                                         llvm::LLVMRustThinLTOBufferLen(buffer) as usize)
+                       .to_vec();
+        llvm::LLVMRustThinLTOBufferFree(buffer);
+        llvm::LLVMDisposeModule(llmod);
+        llvm::LLVMContextDispose(llcx);
+        data
+    }
+}
+
+/// Makes sure that the upstream crates can be optimized together with this
+/// one, which requires all of them to be linked statically into the output.
+fn check_crate_types(sess: &session::Session) {
+    if sess.opts.cg.prefer_dynamic {
+        sess.struct_err("cannot prefer dynamic linking when performing LTO")
+            .note("only 'staticlib', 'bin', and 'cdylib' outputs are \
+                   supported with LTO")
+            .emit();
+        sess.abort_if_errors();
+    }
+
+    // Make sure we actually can run LTO
+    for crate_type in sess.crate_types.borrow().iter() {
+        match *crate_type {
+            config::CrateTypeExecutable |
+            config::CrateTypeCdylib |
+            config::CrateTypeStaticlib => {}
+            _ => {
+                sess.fatal("lto can only be run for executables and \
+                            static library outputs");
+            }
+        }
+    }
+}
+
+/// Calls `f` with the name, rlib and (still compressed) contents of every
+/// bytecode object in the rlibs of the upstream crates that participate in
+/// LTO.
+fn each_upstream_bytecode(sess: &session::Session,
+                          f: &mut FnMut(&str, &Path, &[u8])) {
+    link::each_linked_rlib(sess, &mut |cnum, path| {
+        // `#![no_builtins]` crates don't participate in LTO.
+        if sess.cstore.is_no_builtins(cnum) {
+            return;
+        }
+
+        let archive = ArchiveRO::open(&path).expect("wanted an rlib");
+        let bytecodes = archive.iter().filter_map(|child| {
+            child.ok().and_then(|c| c.name().map(|name| (name, c)))
+        }).filter(|&(name, _)| name.ends_with("bytecode.deflate"));
+        for (name, data) in bytecodes {
+            f(name, path, data.data());
+        }
+    });
+}
+
+fn decode_bytecode(sess: &session::Session, name: &str, bc_encoded: &[u8]) -> flate::Bytes {
+    if is_versioned_bytecode_format(bc_encoded) {
+        time(sess.time_passes(), &format!("decode {}", name), || {
+            // Read the version
+            let version = extract_bytecode_format_version(bc_encoded);
+
+            if version == 1 {
+                // The only version existing so far
+                let data_size = extract_compressed_bytecode_size_v1(bc_encoded);
+                let compressed_data = &bc_encoded[
+                    link::RLIB_BYTECODE_OBJECT_V1_DATA_OFFSET..
+                    (link::RLIB_BYTECODE_OBJECT_V1_DATA_OFFSET + data_size as usize)];
+
+                match flate::inflate_bytes(compressed_data) {
+                    Ok(inflated) => inflated,
+                    Err(_) => {
+                        sess.fatal(&format!("failed to decompress bc of `{}`",
+                                           name))
+                    }
+                }
+            } else {
+                sess.fatal(&format!("Unsupported bytecode format version {}",
+                                   version))
+            }
+        })
+    } else {
+        time(sess.time_passes(), &format!("decode {}", name), || {
+            // the object must be in the old, pre-versioning format, so
+            // simply inflate everything and let LLVM decide if it can
+            // make sense of it
+            match flate::inflate_bytes(bc_encoded) {
+                Ok(bc) => bc,
+                Err(_) => {
+                    sess.fatal(&format!("failed to decompress bc of `{}`",
+                                       name))
+                }
+            }
+        })
+    }
+}
+
 fn is_versioned_bytecode_format(bc: &[u8]) -> bool {
     let magic_id_byte_count = link::RLIB_BYTECODE_OBJECT_MAGIC.len();
     return bc.len() > magic_id_byte_count &&
@@ -13,7 +13,7 @@ use back::link::{get_linker, remove};
 use rustc_incremental::save_trans_partition;
 use session::config::{OutputFilenames, OutputTypes, Passes, SomePasses, AllPasses};
 use session::Session;
-use session::config::{self, OutputType};
+use session::config::{self, OutputType, Lto, Sanitizer, SplitDebuginfo};
 use llvm;
 use llvm::{ModuleRef, TargetMachineRef, PassManagerRef, DiagnosticInfoRef, ContextRef};
 use llvm::SMDiagnosticRef;
@@ -27,8 +27,12 @@ use syntax_pos::MultiSpan;
 use context::{is_pie_binary, get_reloc_model};
 
 use std::ffi::{CStr, CString};
-use std::fs;
+use std::fs::{self, File};
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::ptr;
+use std::slice;
 use std::str;
 use std::sync::{Arc, Mutex};
 use std::sync::mpsc::channel;
@@ -146,8 +150,15 @@ impl Emitter for SharedEmitter {
 // Note that without those flags various linking errors might
 // arise as some of intrinsics are converted into function calls
 // and nobody provides implementations those functions
+//
+// `crt-static` isn't an LLVM feature, it's handled by the linker
+// invocation instead (see `Session::crt_static`), so it is not passed on.
 fn target_feature(sess: &Session) -> String {
-    format!("{},{}", sess.target.target.options.features, sess.opts.cg.target_feature)
+    let rustc_features = sess.opts.cg.target_feature.split(',').filter(|f| {
+        let f = f.trim();
+        f != "+crt-static" && f != "-crt-static"
+    }).collect::<Vec<_>>();
+    format!("{},{}", sess.target.target.options.features, rustc_features.join(","))
 }
 
 fn get_llvm_opt_level(optimize: config::OptLevel) -> llvm::CodeGenOptLevel {
@@ -258,10 +269,20 @@ pub struct ModuleConfig {
     vectorize_slp: bool,
     merge_functions: bool,
     inline_threshold: Option<usize>,
+    // The file instrumented code writes its profile to, and the profile to
+    // optimize with, for profile-guided optimization.
+    pgo_gen: Option<PathBuf>,
+    pgo_use: Option<PathBuf>,
     // Instead of creating an object file by doing LLVM codegen, just
     // make the object file bitcode. Provides easy compatibility with
     // emscripten's ecc compiler, when used as the linker.
     obj_is_bitcode: bool,
+    // Instead of creating an object file right away, serialize the optimized
+    // module along with its summary, and leave codegen to the ThinLTO link
+    // in `run_passes`.
+    thin_lto: bool,
+    // Move the DWARF of the object file out into a `.dwo` file.
+    split_dwarf: bool,
 }
 
 unsafe impl Send for ModuleConfig { }
@@ -281,6 +302,8 @@ impl ModuleConfig {
             emit_asm: false,
             emit_obj: false,
             obj_is_bitcode: false,
+            thin_lto: false,
+            split_dwarf: false,
 
             no_verify: false,
             no_prepopulate_passes: false,
@@ -289,7 +312,9 @@ impl ModuleConfig {
             vectorize_loop: false,
             vectorize_slp: false,
             merge_functions: false,
-            inline_threshold: None
+            inline_threshold: None,
+            pgo_gen: None,
+            pgo_use: None,
         }
     }
 
@@ -509,7 +534,7 @@ unsafe fn optimize_and_codegen(cgcx: &CodegenContext,
         llvm::LLVMDisposePassManager(mpm);
 
         match cgcx.lto_ctxt {
-            Some((sess, reachable)) if sess.lto() =>  {
+            Some((sess, reachable)) if sess.opts.cg.lto == Lto::Fat =>  {
                 time(sess.time_passes(), "all lto passes", || {
                     let temp_no_opt_bc_filename =
                         output_names.temp_path_ext("no-opt.lto.bc", module_name);
@@ -530,6 +555,18 @@ unsafe fn optimize_and_codegen(cgcx: &CodegenContext,
         }
     }
 
+    if config.thin_lto {
+        let out = output_names.temp_path_ext("thin-lto.bc", module_name);
+        let buffer = llvm::LLVMRustThinLTOBufferCreate(llmod);
+        let data = slice::from_raw_parts(llvm::LLVMRustThinLTOBufferPtr(buffer) as *const u8,
+                                         llvm::LLVMRustThinLTOBufferLen(buffer) as usize);
+        if let Err(e) = File::create(&out).and_then(|mut f| f.write_all(data)) {
+            cgcx.handler.err(&format!("failed to write ThinLTO input {}: {}",
+                                      out.display(), e));
+        }
+        llvm::LLVMRustThinLTOBufferFree(buffer);
+    }
+
     // A codegen-specific pass manager is used to generate object
     // files for an LLVM module.
     //
@@ -556,7 +593,7 @@ unsafe fn optimize_and_codegen(cgcx: &CodegenContext,
     // machine code, instead copy the .o file from the .bc
     let write_bc = config.emit_bc || config.obj_is_bitcode;
     let rm_bc = !config.emit_bc && config.obj_is_bitcode;
-    let write_obj = config.emit_obj && !config.obj_is_bitcode;
+    let write_obj = config.emit_obj && !config.obj_is_bitcode && !config.thin_lto;
     let copy_bc_to_obj = config.emit_obj && config.obj_is_bitcode;
 
     let bc_out = output_names.temp_path(OutputType::Bitcode, module_name);
@@ -602,6 +639,10 @@ unsafe fn optimize_and_codegen(cgcx: &CodegenContext,
                 write_output_file(cgcx.handler, tm, cpm, llmod, &obj_out,
                                   llvm::FileType::ObjectFile);
             });
+            if config.split_dwarf {
+                let dwo_out = output_names.temp_path_ext("dwo", module_name);
+                split_dwarf(cgcx.handler, &obj_out, &dwo_out);
+            }
         }
     });
 
@@ -647,7 +688,7 @@ pub fn run_passes(sess: &Session,
     // case, but it would be confusing to have the validity of
     // `-Z lto -C codegen-units=2` depend on details of the crate being
     // compiled, so we complain regardless.
-    if sess.lto() && sess.opts.cg.codegen_units > 1 {
+    if sess.opts.cg.lto == Lto::Fat && sess.opts.cg.codegen_units > 1 {
         // This case is impossible to handle because LTO expects to be able
         // to combine the entire crate and all its dependencies into a
         // single compilation unit, but each codegen unit is in a separate
@@ -655,6 +696,43 @@ pub fn run_passes(sess: &Session,
         sess.fatal("can't perform LTO when using multiple codegen units");
     }
 
+    if sess.thin_lto() {
+        if !unsafe { llvm::LLVMRustThinLTOAvailable() } {
+            sess.fatal("ThinLTO is not available with this version of LLVM");
+        }
+        if sess.target.target.options.obj_is_bitcode {
+            sess.fatal("ThinLTO is not supported on targets whose object files \
+                        are bitcode");
+        }
+        if sess.opts.incremental.is_some() {
+            // The objects depend on what was imported from the other modules,
+            // so they can't be reused when only some of the modules changed.
+            sess.fatal("can't perform ThinLTO with incremental compilation");
+        }
+    }
+
+    if sess.target.target.options.is_like_msvc &&
+       sess.split_debuginfo() != SplitDebuginfo::Packed {
+        sess.fatal(&format!("`-C split-debuginfo={}` is not supported for MSVC \
+                             targets, which always put debuginfo into a .pdb",
+                            sess.split_debuginfo().desc()));
+    }
+    if !sess.target.target.options.is_like_osx && !sess.target.target.options.is_like_msvc &&
+       !sess.target_is_elf() && sess.split_debuginfo() != SplitDebuginfo::Off {
+        sess.fatal(&format!("`-C split-debuginfo={}` is not supported for this target, \
+                             which can only link debuginfo into the output",
+                            sess.split_debuginfo().desc()));
+    }
+
+    if let Some(sanitizer) = sess.opts.debugging_opts.sanitizer {
+        // The sanitizer runtimes are only built for this target.
+        if sess.opts.target_triple != "x86_64-unknown-linux-gnu" {
+            sess.fatal(&format!("the `{}` sanitizer is not supported for the \
+                                 `{}` target",
+                                sanitizer.desc(), sess.opts.target_triple));
+        }
+    }
+
     // Sanity check
     assert!(trans.modules.len() == sess.opts.cg.codegen_units ||
             sess.opts.debugging_opts.incremental.is_some());
@@ -669,6 +747,28 @@ pub fn run_passes(sess: &Session,
     modules_config.opt_level = Some(get_llvm_opt_level(sess.opts.optimize));
     modules_config.opt_size = Some(get_llvm_opt_size(sess.opts.optimize));
 
+    // `%m` keeps the profiles of different binaries using the same directory
+    // apart, while still letting runs of the same binary be merged.
+    modules_config.pgo_gen = sess.opts.cg.profile_generate.as_ref().map(|dir| {
+        Path::new(dir).join("default_%m.profraw")
+    });
+    modules_config.pgo_use = sess.opts.cg.profile_use.as_ref().map(PathBuf::from);
+    modules_config.split_dwarf = sess.split_dwarf();
+
+    match sess.opts.debugging_opts.sanitizer {
+        Some(Sanitizer::Address) => {
+            modules_config.passes.push("asan".to_owned());
+            modules_config.passes.push("asan-module".to_owned());
+        }
+        Some(Sanitizer::Memory) => {
+            modules_config.passes.push("msan".to_owned())
+        }
+        Some(Sanitizer::Thread) => {
+            modules_config.passes.push("tsan".to_owned())
+        }
+        Some(Sanitizer::Leak) | None => {}
+    }
+
     // Save all versions of the bytecode if we're saving our temporaries.
     if sess.opts.cg.save_temps {
         modules_config.emit_no_opt_bc = true;
@@ -713,6 +813,8 @@ pub fn run_passes(sess: &Session,
 
     modules_config.set_flags(sess, trans);
     metadata_config.set_flags(sess, trans);
+    // Without object files to produce there's nothing to link.
+    modules_config.thin_lto = sess.thin_lto() && modules_config.emit_obj;
 
 
     // Populate a buffer with a list of codegen threads.  Items are processed in
@@ -751,6 +853,12 @@ pub fn run_passes(sess: &Session,
         run_work_multithreaded(sess, work_items, num_workers);
     }
 
+    if modules_config.thin_lto {
+        time(sess.time_passes(), "ThinLTO", || {
+            lto::run_thin(sess, trans, tm, crate_output);
+        });
+    }
+
     // If in incr. comp. mode, preserve the `.o` files for potential re-use
     for mtrans in trans.modules.iter() {
         let mut files = vec![];
@@ -1069,6 +1177,37 @@ fn run_work_multithreaded(sess: &Session,
     }
 }
 
+// LLVM emits the split-off DWARF as `.dwo` sections of the object file
+// itself, so move them out into the file the skeleton compile unit names.
+fn split_dwarf(handler: &Handler, obj: &Path, dwo: &Path) {
+    let steps = [("--extract-dwo", Some(dwo)), ("--strip-dwo", None)];
+    for &(flag, out) in steps.iter() {
+        let mut cmd = Command::new("objcopy");
+        cmd.arg(flag).arg(obj);
+        if let Some(out) = out {
+            cmd.arg(out);
+        }
+        debug!("{:?}", cmd);
+
+        match cmd.output() {
+            Ok(prog) => {
+                if !prog.status.success() {
+                    handler.struct_err(&format!("splitting debuginfo with `objcopy` \
+                                                 failed: {}", prog.status))
+                        .note(&format!("{:?}", &cmd))
+                        .note(&String::from_utf8_lossy(&prog.stderr))
+                        .emit();
+                    return
+                }
+            }
+            Err(e) => {
+                handler.err(&format!("could not exec `objcopy`: {}", e));
+                return
+            }
+        }
+    }
+}
+
 pub fn run_assembler(sess: &Session, outputs: &OutputFilenames) {
     let (pname, mut cmd, _) = get_linker(sess);
 
@@ -1108,11 +1247,17 @@ pub unsafe fn with_llvm_pmb(llmod: ModuleRef,
     let opt_level = config.opt_level.unwrap_or(llvm::CodeGenOptLevel::None);
     let opt_size = config.opt_size.unwrap_or(llvm::CodeGenOptSizeNone);
     let inline_threshold = config.inline_threshold;
+    let pgo_gen = config.pgo_gen.as_ref().map(|p| path2cstr(p));
+    let pgo_use = config.pgo_use.as_ref().map(|p| path2cstr(p));
 
     llvm::LLVMRustConfigurePassManagerBuilder(builder, opt_level,
                                               config.merge_functions,
                                               config.vectorize_slp,
-                                              config.vectorize_loop);
+                                              config.vectorize_loop,
+                                              pgo_gen.as_ref().map_or(ptr::null(),
+                                                                      |s| s.as_ptr()),
+                                              pgo_use.as_ref().map_or(ptr::null(),
+                                                                      |s| s.as_ptr()));
     llvm::LLVMPassManagerBuilderSetSizeLevel(builder, opt_size as u32);
 
     if opt_size != llvm::CodeGenOptSizeNone {
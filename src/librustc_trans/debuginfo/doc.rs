@@ -32,7 +32,7 @@
 //! The public API of the module is a set of functions that will insert the
 //! correct metadata into the LLVM IR when called with the right parameters.
 //! The module is thus driven from an outside client with functions like
-//! `debuginfo::create_local_var_metadata(bcx: block, local: &ast::local)`.
+//! `debuginfo::declare_local(bcx, variable_name, variable_type, ...)`.
 //!
 //! Internally the module will try to reuse already created metadata by
 //! utilizing a cache. The way to get a shared metadata node when needed is
@@ -140,7 +140,7 @@
 //! must be linked to the source location of the variable being declared. For
 //! function parameters these `llvm.dbg.declare` instructions typically occur
 //! in the middle of the prologue, however, they are ignored by LLVM's prologue
-//! detection. The `create_argument_metadata()` and related functions take care
+//! detection. The `declare_local()` function takes care
 //! of linking the `llvm.dbg.declare` instructions to the correct source
 //! locations even while source location emission is still disabled, so there
 //! is no need to do anything special with source location handling here.
@@ -22,6 +22,7 @@
 use llvm::{self, ValueRef};
 use llvm::AttributePlace::Function;
 use rustc::ty;
+use rustc::session::config::Sanitizer;
 use abi::{Abi, FnType};
 use attributes;
 use context::CrateContext;
@@ -69,6 +70,22 @@ fn declare_raw_fn(ccx: &CrateContext, name: &str, callconv: llvm::CallConv, ty:
         llvm::Attribute::NoRedZone.apply_llfn(Function, llfn);
     }
 
+    attributes::set_probestack(ccx, llfn);
+
+    // The leak sanitizer only needs its runtime, not any instrumentation.
+    match ccx.tcx().sess.opts.debugging_opts.sanitizer {
+        Some(Sanitizer::Address) => {
+            llvm::Attribute::SanitizeAddress.apply_llfn(Function, llfn);
+        },
+        Some(Sanitizer::Memory) => {
+            llvm::Attribute::SanitizeMemory.apply_llfn(Function, llfn);
+        },
+        Some(Sanitizer::Thread) => {
+            llvm::Attribute::SanitizeThread.apply_llfn(Function, llfn);
+        },
+        Some(Sanitizer::Leak) | None => {},
+    }
+
     match ccx.tcx().sess.opts.cg.opt_level.as_ref().map(String::as_ref) {
         Some("s") => {
             llvm::Attribute::OptimizeForSize.apply_llfn(Function, llfn);
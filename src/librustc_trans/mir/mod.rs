@@ -145,7 +145,7 @@ impl<'tcx> LocalRef<'tcx> {
 ///////////////////////////////////////////////////////////////////////////
 
 pub fn trans_mir<'blk, 'tcx: 'blk>(fcx: &'blk FunctionContext<'blk, 'tcx>) {
-    let bcx = fcx.init(false, None).build();
+    let bcx = fcx.init(false).build();
     let mir = bcx.mir();
 
     // Analyze the temps to determine which must be lvalues
@@ -276,6 +276,15 @@ fn arg_local_refs<'bcx, 'tcx>(bcx: &BlockAndBuilder<'bcx, 'tcx>,
                               -> Vec<LocalRef<'tcx>> {
     let fcx = bcx.fcx();
     let tcx = bcx.tcx();
+
+    if fcx.naked {
+        // The body of a naked function can't refer to its arguments, it
+        // finds them wherever the calling convention put them.
+        return mir.arg_decls.iter().map(|arg_decl| {
+            LocalRef::new_operand(bcx.ccx(), bcx.monomorphize(&arg_decl.ty))
+        }).collect();
+    }
+
     let mut idx = 0;
     let mut llarg_idx = fcx.fn_ty.ret.is_indirect() as usize;
 
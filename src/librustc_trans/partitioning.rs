@@ -115,6 +115,19 @@
 //! Note though that as a side-effect of creating a codegen units per
 //! source-level module, functions from the same module will be available for
 //! inlining, even when they are not marked #[inline].
+//!
+//! A Fixed Number of Codegen Units
+//! -------------------------------
+//! Outside of incremental compilation, `-C codegen-units` asks for a fixed
+//! number of codegen units. These are still built from source-level modules,
+//! which are then merged until the requested count is reached: the smallest
+//! unit is always merged into its closest relative in the module tree, on
+//! the assumption that neighbouring modules call each other more often than
+//! unrelated ones do. All ties are broken by size and name, and a merged unit
+//! keeps the name of the module it was merged into, so the same source
+//! produces the same partitioning no matter in which order the translation
+//! items were collected, and adding or removing a module only renames the
+//! units it ends up in.
 
 use collector::InliningMap;
 use llvm;
@@ -384,22 +397,40 @@ fn merge_codegen_units<'tcx>(initial_partitioning: &mut PreInliningPartitioning<
     assert!(target_cgu_count >= 1);
     let codegen_units = &mut initial_partitioning.codegen_units;
 
-    // Merge the two smallest codegen units until the target size is reached.
-    // Note that "size" is estimated here rather inaccurately as the number of
-    // translation items in a given unit. This could be improved on.
+    // Merge the smallest codegen unit into its closest relative in the module
+    // tree until the target size is reached. Note that "size" is estimated
+    // here rather inaccurately as the number of translation items in a given
+    // unit. This could be improved on.
     while codegen_units.len() > target_cgu_count {
-        // Sort small cgus to the back
-        codegen_units.sort_by_key(|cgu| -(cgu.items.len() as i64));
+        // Sort small cgus to the back. Of equally small cgus, the one whose
+        // name sorts last goes first, so that submodules are merged before
+        // their parents.
+        codegen_units.sort_by(|cgu1, cgu2| {
+            (cgu2.items.len(), &cgu1.name[..]).cmp(&(cgu1.items.len(), &cgu2.name[..]))
+        });
         let smallest = codegen_units.pop().unwrap();
-        let second_smallest = codegen_units.last_mut().unwrap();
 
-        for (k, v) in smallest.items.into_iter() {
-            second_smallest.items.insert(k, v);
+        // Pick the closest relative, and of equally close ones the smallest,
+        // and of equally small ones the one whose name sorts first.
+        let mut closest = 0;
+        let mut closest_depth = common_module_depth(&codegen_units[0].name, &smallest.name);
+        for index in 1..codegen_units.len() {
+            let cgu = &codegen_units[index];
+            let depth = common_module_depth(&cgu.name, &smallest.name);
+            let best = &codegen_units[closest];
+            if (closest_depth, cgu.items.len(), &cgu.name[..]) <
+               (depth, best.items.len(), &best.name[..]) {
+                closest = index;
+                closest_depth = depth;
+            }
         }
-    }
 
-    for (index, cgu) in codegen_units.iter_mut().enumerate() {
-        cgu.name = numbered_codegen_unit_name(crate_name, index);
+        // The merged cgu keeps the name of the one merged into, so that it
+        // is still named after one of its modules.
+        let closest = &mut codegen_units[closest];
+        for (k, v) in smallest.items.into_iter() {
+            closest.items.insert(k, v);
+        }
     }
 
     // If the initial partitioning contained less than target_cgu_count to begin
@@ -412,6 +443,15 @@ fn merge_codegen_units<'tcx>(initial_partitioning: &mut PreInliningPartitioning<
     }
 }
 
+/// Returns the number of leading module path components that the names of two
+/// per-module codegen units (see `compute_codegen_unit_name`) have in common.
+fn common_module_depth(name1: &str, name2: &str) -> usize {
+    name1.split('-')
+         .zip(name2.split('-'))
+         .take_while(|&(part1, part2)| part1 == part2)
+         .count()
+}
+
 fn place_inlined_translation_items<'tcx>(initial_partitioning: PreInliningPartitioning<'tcx>,
                                          inlining_map: &InliningMap<'tcx>)
                                          -> PostInliningPartitioning<'tcx> {
@@ -48,7 +48,7 @@
 //! case but `&a` in the second.  Basically, defaults that appear inside
 //! an rptr (`&r.T`) use the region `r` that appears in the rptr.
 
-use rustc_const_eval::eval_length;
+use rustc_const_eval::eval_length_with_substs;
 use hir::{self, SelfKind};
 use hir::def::{Def, PathResolution};
 use hir::def_id::DefId;
@@ -73,7 +73,7 @@ use syntax::{abi, ast};
 use syntax::feature_gate::{GateIssue, emit_feature_err};
 use syntax::parse::token::{self, keywords};
 use syntax_pos::{Span, Pos};
-use errors::DiagnosticBuilder;
+use errors::{Applicability, DiagnosticBuilder};
 
 pub trait AstConv<'gcx, 'tcx> {
     fn tcx<'a>(&'a self) -> TyCtxt<'a, 'gcx, 'tcx>;
@@ -1004,20 +1004,26 @@ impl<'o, 'gcx: 'tcx, 'tcx> AstConv<'gcx, 'tcx>+'o {
                 match (&ty.node, full_span) {
                     (&hir::TyRptr(None, ref mut_ty), Some(full_span)) => {
                         let mutbl_str = if mut_ty.mutbl == hir::MutMutable { "mut " } else { "" };
-                        err.span_suggestion(full_span, "try adding parentheses (per RFC 438):",
-                                            format!("&{}({} +{})",
-                                                    mutbl_str,
-                                                    pprust::ty_to_string(&mut_ty.ty),
-                                                    pprust::bounds_to_string(bounds)));
+                        err.span_suggestion_with_applicability(
+                            full_span,
+                            "try adding parentheses (per RFC 438):",
+                            format!("&{}({} +{})",
+                                    mutbl_str,
+                                    pprust::ty_to_string(&mut_ty.ty),
+                                    pprust::bounds_to_string(bounds)),
+                            Applicability::MachineApplicable);
                     }
                     (&hir::TyRptr(Some(ref lt), ref mut_ty), Some(full_span)) => {
                         let mutbl_str = if mut_ty.mutbl == hir::MutMutable { "mut " } else { "" };
-                        err.span_suggestion(full_span, "try adding parentheses (per RFC 438):",
-                                            format!("&{} {}({} +{})",
-                                                    pprust::lifetime_to_string(lt),
-                                                    mutbl_str,
-                                                    pprust::ty_to_string(&mut_ty.ty),
-                                                    pprust::bounds_to_string(bounds)));
+                        err.span_suggestion_with_applicability(
+                            full_span,
+                            "try adding parentheses (per RFC 438):",
+                            format!("&{} {}({} +{})",
+                                    pprust::lifetime_to_string(lt),
+                                    mutbl_str,
+                                    pprust::ty_to_string(&mut_ty.ty),
+                                    pprust::bounds_to_string(bounds)),
+                            Applicability::MachineApplicable);
                     }
 
                     _ => {
@@ -1608,6 +1614,43 @@ impl<'o, 'gcx: 'tcx, 'tcx> AstConv<'gcx, 'tcx>+'o {
         (ty, def)
     }
 
+    /// If `expr` is a qualified path to a trait-associated constant, like
+    /// `<Foo as Trait>::N`, returns the substitutions of the trait reference,
+    /// so that const-eval can select the impl providing the constant. Returns
+    /// `None` if these still refer to type parameters, in which case the
+    /// constant can only be resolved after monomorphization.
+    fn assoc_const_substs(&self, rscope: &RegionScope, expr: &hir::Expr)
+                          -> Option<&'gcx Substs<'gcx>> {
+        let tcx = self.tcx();
+        let (qself, path) = match expr.node {
+            hir::ExprPath(Some(ref qself), ref path) => (qself, path),
+            _ => return None
+        };
+        let resolution = tcx.def_map.borrow().get(&expr.id).cloned();
+        let trait_def_id = match resolution {
+            Some(PathResolution { base_def: Def::AssociatedConst(def_id), depth: 0 }) => {
+                match tcx.trait_of_item(def_id) {
+                    Some(trait_def_id) => trait_def_id,
+                    None => return None
+                }
+            }
+            _ => return None
+        };
+
+        let self_ty = self.ast_ty_to_ty(rscope, &qself.ty);
+        let trait_ref = self.ast_path_to_mono_trait_ref(rscope,
+                                                        path.span,
+                                                        PathParamMode::Explicit,
+                                                        trait_def_id,
+                                                        self_ty,
+                                                        &path.segments[qself.position - 1]);
+        if trait_ref.references_error() || trait_ref.has_param_types() ||
+           trait_ref.has_self_ty() || trait_ref.needs_infer() {
+            return None;
+        }
+        tcx.lift_to_global(&tcx.erase_regions(&trait_ref.substs))
+    }
+
     /// Parses the programmer's textual representation of a type into our
     /// internal notion of a type.
     pub fn ast_ty_to_ty(&self, rscope: &RegionScope, ast_ty: &hir::Ty) -> Ty<'tcx> {
@@ -1759,7 +1802,9 @@ impl<'o, 'gcx: 'tcx, 'tcx> AstConv<'gcx, 'tcx>+'o {
                 ty
             }
             hir::TyFixedLengthVec(ref ty, ref e) => {
-                if let Ok(length) = eval_length(tcx.global_tcx(), &e, "array length") {
+                let substs = self.assoc_const_substs(rscope, &e);
+                if let Ok(length) = eval_length_with_substs(tcx.global_tcx(), &e, substs,
+                                                            "array length") {
                     tcx.mk_array(self.ast_ty_to_ty(rscope, &ty), length)
                 } else {
                     self.tcx().types.err
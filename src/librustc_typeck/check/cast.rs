@@ -42,6 +42,7 @@ use super::FnCtxt;
 
 use lint;
 use hir::def_id::DefId;
+use errors::Applicability;
 use rustc::hir;
 use rustc::traits;
 use rustc::ty::{self, Ty, TypeFoldable};
@@ -223,9 +224,11 @@ impl<'a, 'gcx, 'tcx> CastCheck<'tcx> {
                 if self.cast_ty.is_trait() {
                     match fcx.tcx.sess.codemap().span_to_snippet(self.cast_span) {
                         Ok(s) => {
-                            err.span_suggestion(self.cast_span,
-                                                "try casting to a reference instead:",
-                                                format!("&{}{}", mtstr, s));
+                            err.span_suggestion_with_applicability(
+                                self.cast_span,
+                                "try casting to a reference instead:",
+                                format!("&{}{}", mtstr, s),
+                                Applicability::MaybeIncorrect);
                         },
                         Err(_) =>
                             span_help!(err, self.cast_span,
@@ -240,9 +243,11 @@ impl<'a, 'gcx, 'tcx> CastCheck<'tcx> {
             ty::TyBox(..) => {
                 match fcx.tcx.sess.codemap().span_to_snippet(self.cast_span) {
                     Ok(s) => {
-                        err.span_suggestion(self.cast_span,
-                                            "try casting to a `Box` instead:",
-                                            format!("Box<{}>", s));
+                        err.span_suggestion_with_applicability(
+                            self.cast_span,
+                            "try casting to a `Box` instead:",
+                            format!("Box<{}>", s),
+                            Applicability::MaybeIncorrect);
                     },
                     Err(_) =>
                         span_help!(err, self.cast_span, "did you mean `Box<{}>`?", tstr),
@@ -310,7 +310,12 @@ impl<'a, 'gcx, 'tcx> ConfirmContext<'a, 'gcx, 'tcx> {
         let method = pick.item.as_opt_method().unwrap();
         let num_method_types = method.generics.types.len();
 
-        if num_supplied_types > 0 && num_supplied_types != num_method_types {
+        if num_supplied_types > 0 && method.generics.types.iter().any(|d| d.synthetic) {
+            span_err!(self.tcx.sess, self.span, E0569,
+                "cannot provide explicit type parameters when `impl Trait` \
+                 is used in argument position");
+            supplied_method_types = vec![];
+        } else if num_supplied_types > 0 && num_supplied_types != num_method_types {
             if num_method_types == 0 {
                 span_err!(self.tcx.sess, self.span, E0035,
                     "does not take type parameters");
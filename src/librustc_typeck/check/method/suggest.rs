@@ -25,6 +25,7 @@ use rustc::traits::{Obligation, SelectionContext};
 use util::nodemap::{FnvHashSet};
 
 use syntax::ast;
+use syntax::util::lev_distance::find_best_match_for_name;
 use errors::DiagnosticBuilder;
 use syntax_pos::Span;
 
@@ -212,7 +213,8 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
                     }
                 }
 
-                if !static_sources.is_empty() {
+                let has_static_sources = !static_sources.is_empty();
+                if has_static_sources {
                     err.note(
                         "found the following associated functions; to be used as \
                          methods, functions must have a `self` parameter");
@@ -234,6 +236,11 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
                                  bound_list));
                 }
 
+                if mode == Mode::MethodCall && !has_static_sources &&
+                   unsatisfied_predicates.is_empty() {
+                    self.suggest_method_names(&mut err, span, rcvr_ty, item_name);
+                }
+
                 self.suggest_traits_to_import(&mut err, span, rcvr_ty, item_name,
                                               rcvr_expr, out_of_scope_traits);
                 err.emit();
@@ -269,6 +276,43 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
         }
     }
 
+    // displays a hint about the closest match among the inherent methods
+    // of the receiver type
+    fn suggest_method_names(&self,
+                            err: &mut DiagnosticBuilder,
+                            span: Span,
+                            rcvr_ty: Ty<'tcx>,
+                            item_name: ast::Name) {
+        let mut names = vec![];
+        for (ty, _) in self.autoderef(span, rcvr_ty) {
+            let def_id = match ty.sty {
+                ty::TyStruct(def, _) | ty::TyEnum(def, _) => def.did,
+                _ => continue
+            };
+            self.tcx.populate_inherent_implementations_for_type_if_necessary(def_id);
+            if let Some(impl_infos) = self.tcx.inherent_impls.borrow().get(&def_id) {
+                for &impl_def_id in impl_infos.iter() {
+                    let impl_items = self.tcx.impl_items.borrow();
+                    for item in &impl_items[&impl_def_id] {
+                        if let ty::MethodTraitItem(ref method) =
+                                self.tcx.impl_or_trait_item(item.def_id()) {
+                            if method.explicit_self != ty::ExplicitSelfCategory::Static &&
+                               (method.def_id.is_local() ||
+                                method.vis == ty::Visibility::Public) {
+                                names.push(method.name);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let name = item_name.as_str();
+        if let Some(name) = find_best_match_for_name(names.iter(), &name, None) {
+            err.help(&format!("did you mean `{}`?", name));
+        }
+    }
+
     fn suggest_traits_to_import(&self,
                                 err: &mut DiagnosticBuilder,
                                 span: Span,
@@ -120,13 +120,13 @@ use syntax::parse::token::{self, InternedString, keywords};
 use syntax::ptr::P;
 use syntax::util::lev_distance::find_best_match_for_name;
 use syntax_pos::{self, Span};
-use errors::DiagnosticBuilder;
+use errors::{Applicability, DiagnosticBuilder};
 
 use rustc::hir::intravisit::{self, Visitor};
 use rustc::hir::{self, PatKind};
 use rustc::hir::print as pprust;
 use rustc_back::slice;
-use rustc_const_eval::eval_length;
+use rustc_const_eval::eval_length_with_substs;
 
 mod assoc;
 mod autoderef;
@@ -3637,7 +3637,19 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
           }
           hir::ExprRepeat(ref element, ref count_expr) => {
             self.check_expr_has_type(&count_expr, tcx.types.usize);
-            let count = eval_length(self.tcx.global_tcx(), &count_expr, "repeat count")
+            // The count may be an associated constant, whose impl can only be
+            // selected with the substitutions we just inferred for it.
+            let substs = self.tables.borrow().item_substs.get(&count_expr.id).map(|s| s.substs);
+            let substs = substs.and_then(|substs| {
+                let substs = self.resolve_type_vars_if_possible(&substs);
+                if substs.has_param_types() || substs.has_self_ty() || substs.needs_infer() {
+                    None
+                } else {
+                    tcx.lift_to_global(&tcx.erase_regions(&substs))
+                }
+            });
+            let count = eval_length_with_substs(self.tcx.global_tcx(), &count_expr, substs,
+                                                "repeat count")
                   .unwrap_or(0);
 
             let uty = match expected {
@@ -3755,11 +3767,12 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
                                             ast::LitIntType::Unsuffixed) = lit.node {
                                       let snip = tcx.sess.codemap().span_to_snippet(base.span);
                                       if let Ok(snip) = snip {
-                                          err.span_suggestion(expr.span,
-                                                              "to access tuple elements, \
-                                                               use tuple indexing syntax \
-                                                               as shown",
-                                                              format!("{}.{}", snip, i));
+                                          err.span_suggestion_with_applicability(
+                                              expr.span,
+                                              "to access tuple elements, use tuple \
+                                               indexing syntax as shown",
+                                              format!("{}.{}", snip, i),
+                                              Applicability::MachineApplicable);
                                           needs_note = false;
                                       }
                                   }
@@ -4386,6 +4399,24 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
                       count(lifetimes.len()));
         }
 
+        // Parameters introduced by `impl Trait` in argument position have
+        // no name to line explicit type parameters up with, so none may be
+        // given at all.
+        let has_synthetic = segment.map_or(false, |(_, generics)| {
+            generics.types.iter().any(|d| d.synthetic)
+        });
+        if has_synthetic && !types.is_empty() {
+            struct_span_err!(self.tcx.sess, types[0].span, E0569,
+                             "cannot provide explicit type parameters when `impl Trait` \
+                              is used in argument position")
+                .span_label(types[0].span, &format!("explicit type parameter"))
+                .emit();
+
+            // Infer all of them instead.
+            *segment = None;
+            return;
+        }
+
         // Check provided type parameters.
         let type_defs = segment.map_or(&[][..], |(_, generics)| {
             if generics.parent.is_none() {
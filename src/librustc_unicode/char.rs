@@ -41,6 +41,8 @@ pub use core::char::{EncodeUtf16, EncodeUtf8, EscapeDebug, EscapeDefault, Escape
 // unstable reexports
 #[unstable(feature = "decode_utf8", issue = "33906")]
 pub use core::char::{DecodeUtf8, decode_utf8};
+#[unstable(feature = "try_from", issue = "33417")]
+pub use core::char::CharTryFromError;
 #[unstable(feature = "unicode", issue = "27783")]
 pub use tables::UNICODE_VERSION;
 
@@ -37,6 +37,7 @@
 #![feature(decode_utf8)]
 #![feature(lang_items)]
 #![feature(staged_api)]
+#![feature(try_from)]
 #![feature(unicode)]
 
 mod tables;
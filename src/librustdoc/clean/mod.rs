@@ -954,9 +954,11 @@ pub struct Generics {
 
 impl Clean<Generics> for hir::Generics {
     fn clean(&self, cx: &DocContext) -> Generics {
+        // Parameters introduced by `impl Trait` only show up in arguments.
+        let type_params = self.ty_params.iter().filter(|tp| !tp.synthetic).collect::<Vec<_>>();
         Generics {
             lifetimes: self.lifetimes.clean(cx),
-            type_params: self.ty_params.clean(cx),
+            type_params: type_params.into_iter().map(|tp| tp.clean(cx)).collect(),
             where_predicates: self.where_clause.predicates.clean(cx)
         }
     }
@@ -976,10 +978,16 @@ impl<'a, 'tcx> Clean<Generics> for (&'a ty::Generics<'tcx>,
             if tp.name == keywords::SelfType.name() {
                 assert_eq!(tp.index, 0);
                 None
+            } else if tp.synthetic {
+                // Shows up as `impl Trait` in the arguments instead.
+                None
             } else {
                 Some(tp.clean(cx))
             }
         }).collect::<Vec<_>>();
+        let synthetic_typarams = gens.types.iter().filter(|tp| tp.synthetic).map(|tp| {
+            tp.name.to_string()
+        }).collect::<HashSet<_>>();
         let stripped_lifetimes = gens.regions.iter().map(|rp| {
             let mut srp = rp.clone();
             srp.bounds = Vec::new();
@@ -998,6 +1006,8 @@ impl<'a, 'tcx> Clean<Generics> for (&'a ty::Generics<'tcx>,
         let mut sized_params = HashSet::new();
         where_predicates.retain(|pred| {
             match *pred {
+                WP::BoundPredicate { ty: Generic(ref g), .. }
+                        if synthetic_typarams.contains(g) => false,
                 WP::BoundPredicate { ty: Generic(ref g), ref bounds } => {
                     if bounds.iter().any(|b| b.is_sized_bound(cx)) {
                         sized_params.insert(g.clone());
@@ -26,7 +26,7 @@ fn main() {
     let target = env::var("TARGET").unwrap();
     let host = env::var("HOST").unwrap();
     if cfg!(feature = "backtrace") && !target.contains("apple") && !target.contains("msvc") &&
-        !target.contains("emscripten") {
+        !target.contains("emscripten") && !target.contains("wasm32") {
         build_libbacktrace(&host, &target);
     }
 
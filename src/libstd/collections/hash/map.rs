@@ -45,7 +45,7 @@ const INITIAL_CAPACITY: usize = 1 << INITIAL_LOG2_CAP; // 2^5
 struct DefaultResizePolicy;
 
 impl DefaultResizePolicy {
-    fn new() -> DefaultResizePolicy {
+    const fn new() -> DefaultResizePolicy {
         DefaultResizePolicy
     }
 
@@ -417,17 +417,36 @@ fn pop_internal<K, V>(starting_bucket: FullBucketMut<K, V>) -> (K, V) {
     (retkey, retval)
 }
 
+/// Finds a bucket which is either empty or holds an element at its ideal
+/// position. No probe sequence extends past such a bucket, so backward shift
+/// deletion never moves elements across it.
+fn head_index<K, V>(table: &RawTable<K, V>) -> usize {
+    let mut bucket = Bucket::first(table);
+    loop {
+        bucket = match bucket.peek() {
+            Full(full) => {
+                if full.displacement() == 0 {
+                    return full.index();
+                }
+                full.next()
+            }
+            Empty(empty) => return empty.into_bucket().index(),
+        };
+    }
+}
+
 /// Perform robin hood bucket stealing at the given `bucket`. You must
 /// also pass the position of that bucket's initial bucket so we don't have
 /// to recalculate it.
 ///
 /// `hash`, `k`, and `v` are the elements to "robin hood" into the hashtable.
+/// Returns the bucket the new element ended up in.
 fn robin_hood<'a, K: 'a, V: 'a>(bucket: FullBucketMut<'a, K, V>,
                         mut ib: usize,
                         mut hash: SafeHash,
                         mut key: K,
                         mut val: V)
-                        -> &'a mut V {
+                        -> FullBucketMut<'a, K, V> {
     let starting_index = bucket.index();
     let size = bucket.table().size();
     // Save the *starting point*.
@@ -451,14 +470,13 @@ fn robin_hood<'a, K: 'a, V: 'a>(bucket: FullBucketMut<'a, K, V>,
                 Empty(bucket) => {
                     // Found a hole!
                     let bucket = bucket.put(hash, key, val);
-                    // Now that it's stolen, just read the value's pointer
-                    // right out of the table! Go back to the *starting point*.
+                    // Now that it's stolen, go back to the *starting point*.
                     //
                     // This use of `into_table` is misleading. It turns the
                     // bucket, which is a FullBucket on top of a
                     // FullBucketMut, into just one FullBucketMut. The "table"
                     // refers to the inner FullBucketMut in this context.
-                    return bucket.into_table().into_mut_refs().1;
+                    return bucket.into_table();
                 },
                 Full(bucket) => bucket
             };
@@ -560,7 +578,9 @@ impl<K, V, S> HashMap<K, V, S>
     /// Creates an empty hashmap which will use the given hash builder to hash
     /// keys.
     ///
-    /// The created map has the default initial capacity.
+    /// The created map has no capacity and will not allocate until it is
+    /// first inserted into. This is a `const fn`, so a map with a hasher
+    /// that can be built in a constant expression can be placed in a static.
     ///
     /// Warning: `hash_builder` is normally randomly generated, and
     /// is designed to allow HashMaps to be resistant to attacks that
@@ -579,11 +599,11 @@ impl<K, V, S> HashMap<K, V, S>
     /// ```
     #[inline]
     #[stable(feature = "hashmap_build_hasher", since = "1.7.0")]
-    pub fn with_hasher(hash_builder: S) -> HashMap<K, V, S> {
+    pub const fn with_hasher(hash_builder: S) -> HashMap<K, V, S> {
         HashMap {
             hash_builder: hash_builder,
             resize_policy: DefaultResizePolicy::new(),
-            table: RawTable::new(0),
+            table: RawTable::new_empty(),
         }
     }
 
@@ -1166,6 +1186,132 @@ impl<K, V, S> HashMap<K, V, S>
 
         self.search_mut(k).into_occupied_bucket().map(|bucket| pop_internal(bucket).1)
     }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all pairs `(k, v)` such that `f(&k, &mut v)`
+    /// returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(retain_hash_collection)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map: HashMap<isize, isize> = (0..8).map(|x|(x, x*10)).collect();
+    /// map.retain(|&k, _| k % 2 == 0);
+    /// assert_eq!(map.len(), 4);
+    /// ```
+    #[unstable(feature = "retain_hash_collection", issue = "0")]
+    pub fn retain<F>(&mut self, mut f: F)
+        where F: FnMut(&K, &mut V) -> bool
+    {
+        if self.table.size() == 0 {
+            return;
+        }
+
+        // Visit the buckets backwards, ending at the head bucket. Removing
+        // an element shifts the following elements of its probe sequence
+        // back by one, but those have already been visited.
+        let cap = self.table.capacity();
+        let mut idx = head_index(&self.table);
+        for _ in 0..cap {
+            idx = idx.wrapping_sub(1) & (cap - 1);
+            if let Full(mut full) = Bucket::at_index(&mut self.table, idx).peek() {
+                let keep = {
+                    let (k, v) = full.read_mut();
+                    f(k, v)
+                };
+                if !keep {
+                    pop_internal(full);
+                }
+            }
+        }
+    }
+
+    /// Creates a raw immutable entry builder for the HashMap.
+    ///
+    /// Raw entries provide the lowest level of control for searching and
+    /// manipulating a map. They must be manually initialized with a hash and
+    /// then manually searched.
+    ///
+    /// This is useful for
+    /// * Hash memoization
+    /// * Using a search key that doesn't work with the Borrow trait
+    /// * Using custom comparison logic without newtype wrappers
+    ///
+    /// Unless you are in such a situation, higher-level and more foolproof APIs
+    /// like `get` should be preferred.
+    ///
+    /// Immutable raw entries have very limited use; you might instead want
+    /// `raw_entry_mut`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_raw_entry)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map = HashMap::new();
+    /// map.insert("poneyland", 1);
+    ///
+    /// assert_eq!(map.raw_entry().from_key("poneyland"), Some((&"poneyland", &1)));
+    /// ```
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn raw_entry(&self) -> RawEntryBuilder<K, V, S> {
+        RawEntryBuilder { map: self }
+    }
+
+    /// Creates a raw entry builder for the HashMap.
+    ///
+    /// Raw entries provide the lowest level of control for searching and
+    /// manipulating a map. They must be manually initialized with a hash and
+    /// then manually searched. After this, insertions into a vacant entry
+    /// still require an owned key to be provided.
+    ///
+    /// Raw entries are useful for such exotic situations as:
+    ///
+    /// * Hash memoization
+    /// * Deferring the creation of an owned key until it is known to be required
+    /// * Using a search key that doesn't work with the Borrow trait
+    /// * Using custom comparison logic without newtype wrappers
+    ///
+    /// Because raw entries provide much more low-level control, it's much easier
+    /// to put the HashMap into an inconsistent state which, while memory-safe,
+    /// will cause the map to produce seemingly random results. Higher-level and
+    /// more foolproof APIs like `entry` should be preferred when possible.
+    ///
+    /// In particular, the hash used to initialized the raw entry must still be
+    /// consistent with the hash of the key that is ultimately stored in the entry.
+    /// This is because implementations of HashMap may need to recompute hashes
+    /// when resizing, at which point only the keys are available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_raw_entry)]
+    /// use std::collections::HashMap;
+    /// use std::collections::hash_map::RawEntryMut;
+    ///
+    /// let mut map: HashMap<String, u32> = HashMap::new();
+    ///
+    /// // Only allocate an owned key when it is actually inserted.
+    /// for word in "a b a c a".split(' ') {
+    ///     match map.raw_entry_mut().from_key(word) {
+    ///         RawEntryMut::Occupied(mut o) => *o.get_mut() += 1,
+    ///         RawEntryMut::Vacant(v) => { v.insert(word.to_string(), 1); }
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(map["a"], 3);
+    /// assert_eq!(map["c"], 1);
+    /// ```
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn raw_entry_mut(&mut self) -> RawEntryBuilderMut<K, V, S> {
+        // Gotta resize now.
+        self.reserve(1);
+        RawEntryBuilderMut { map: self }
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -1417,6 +1563,402 @@ enum VacantEntryState<K, V, M> {
     NoElem(EmptyBucket<K, V, M>),
 }
 
+/// A builder for computing where in a HashMap a key-value pair would be stored.
+///
+/// See the [`HashMap::raw_entry_mut`] docs for usage examples.
+///
+/// [`HashMap::raw_entry_mut`]: struct.HashMap.html#method.raw_entry_mut
+#[unstable(feature = "hash_raw_entry", issue = "0")]
+pub struct RawEntryBuilderMut<'a, K: 'a, V: 'a, S: 'a> {
+    map: &'a mut HashMap<K, V, S>,
+}
+
+/// A view into a single entry in a map, which may either be vacant or occupied.
+///
+/// This is a lower-level version of [`Entry`].
+///
+/// This `enum` is constructed from the [`from_hash`] method on [`RawEntryBuilderMut`].
+///
+/// [`Entry`]: enum.Entry.html
+/// [`from_hash`]: struct.RawEntryBuilderMut.html#method.from_hash
+/// [`RawEntryBuilderMut`]: struct.RawEntryBuilderMut.html
+#[unstable(feature = "hash_raw_entry", issue = "0")]
+pub enum RawEntryMut<'a, K: 'a, V: 'a, S: 'a> {
+    /// An occupied entry.
+    Occupied(RawOccupiedEntryMut<'a, K, V>),
+    /// A vacant entry.
+    Vacant(RawVacantEntryMut<'a, K, V, S>),
+}
+
+/// A view into an occupied entry in a `HashMap`.
+/// It is part of the [`RawEntryMut`] enum.
+///
+/// [`RawEntryMut`]: enum.RawEntryMut.html
+#[unstable(feature = "hash_raw_entry", issue = "0")]
+pub struct RawOccupiedEntryMut<'a, K: 'a, V: 'a> {
+    elem: FullBucket<K, V, &'a mut RawTable<K, V>>,
+}
+
+/// A view into a vacant entry in a `HashMap`.
+/// It is part of the [`RawEntryMut`] enum.
+///
+/// [`RawEntryMut`]: enum.RawEntryMut.html
+#[unstable(feature = "hash_raw_entry", issue = "0")]
+pub struct RawVacantEntryMut<'a, K: 'a, V: 'a, S: 'a> {
+    elem: VacantEntryState<K, V, &'a mut RawTable<K, V>>,
+    hash_builder: &'a S,
+}
+
+/// A builder for computing where in a HashMap a key-value pair would be stored.
+///
+/// See the [`HashMap::raw_entry`] docs for usage examples.
+///
+/// [`HashMap::raw_entry`]: struct.HashMap.html#method.raw_entry
+#[unstable(feature = "hash_raw_entry", issue = "0")]
+pub struct RawEntryBuilder<'a, K: 'a, V: 'a, S: 'a> {
+    map: &'a HashMap<K, V, S>,
+}
+
+impl<'a, K, V, S> RawEntryBuilderMut<'a, K, V, S>
+    where S: BuildHasher
+{
+    /// Create a `RawEntryMut` from the given key.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn from_key<Q: ?Sized>(self, k: &Q) -> RawEntryMut<'a, K, V, S>
+        where K: Borrow<Q>, Q: Hash + Eq
+    {
+        let hash = table::make_hash(&self.map.hash_builder, k);
+        self.from_key_hashed_nocheck(hash.inspect(), k)
+    }
+
+    /// Create a `RawEntryMut` from the given key and its hash.
+    ///
+    /// The hash is not checked against the key; passing a hash that was not
+    /// computed from `k` with the map's hasher is a logic error.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn from_key_hashed_nocheck<Q: ?Sized>(self, hash: u64, k: &Q) -> RawEntryMut<'a, K, V, S>
+        where K: Borrow<Q>, Q: Eq
+    {
+        self.from_hash(hash, |q| q.borrow().eq(k))
+    }
+
+    /// Create a `RawEntryMut` from the given hash, using `is_match` to
+    /// compare the keys stored under that hash.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn from_hash<F>(self, hash: u64, is_match: F) -> RawEntryMut<'a, K, V, S>
+        where F: FnMut(&K) -> bool
+    {
+        let hash_builder = &self.map.hash_builder;
+        match search_hashed(&mut self.map.table, SafeHash::new(hash), is_match) {
+            InternalEntry::Occupied { elem } => {
+                RawEntryMut::Occupied(RawOccupiedEntryMut { elem: elem })
+            }
+            InternalEntry::Vacant { elem, .. } => {
+                RawEntryMut::Vacant(RawVacantEntryMut {
+                    elem: elem,
+                    hash_builder: hash_builder,
+                })
+            }
+            InternalEntry::TableIsEmpty => {
+                unreachable!("raw_entry_mut reserves space before searching")
+            }
+        }
+    }
+}
+
+impl<'a, K, V, S> RawEntryBuilder<'a, K, V, S>
+    where S: BuildHasher
+{
+    /// Access an entry by key.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn from_key<Q: ?Sized>(self, k: &Q) -> Option<(&'a K, &'a V)>
+        where K: Borrow<Q>, Q: Hash + Eq
+    {
+        let hash = table::make_hash(&self.map.hash_builder, k);
+        self.from_key_hashed_nocheck(hash.inspect(), k)
+    }
+
+    /// Access an entry by a key and its hash.
+    ///
+    /// The hash is not checked against the key; passing a hash that was not
+    /// computed from `k` with the map's hasher is a logic error.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn from_key_hashed_nocheck<Q: ?Sized>(self, hash: u64, k: &Q) -> Option<(&'a K, &'a V)>
+        where K: Borrow<Q>, Q: Eq
+    {
+        self.from_hash(hash, |q| q.borrow().eq(k))
+    }
+
+    /// Access an entry by hash, using `is_match` to compare the keys stored
+    /// under that hash.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn from_hash<F>(self, hash: u64, is_match: F) -> Option<(&'a K, &'a V)>
+        where F: FnMut(&K) -> bool
+    {
+        search_hashed(&self.map.table, SafeHash::new(hash), is_match)
+            .into_occupied_bucket()
+            .map(|bucket| bucket.into_refs())
+    }
+}
+
+impl<'a, K, V, S> RawEntryMut<'a, K, V, S> {
+    /// Ensures a value is in the entry by inserting the default if empty, and returns
+    /// mutable references to the key and value in the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_raw_entry)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map: HashMap<&str, u32> = HashMap::new();
+    ///
+    /// map.raw_entry_mut().from_key("poneyland").or_insert("poneyland", 3);
+    /// assert_eq!(map["poneyland"], 3);
+    ///
+    /// *map.raw_entry_mut().from_key("poneyland").or_insert("poneyland", 10).1 *= 2;
+    /// assert_eq!(map["poneyland"], 6);
+    /// ```
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn or_insert(self, default_key: K, default_val: V) -> (&'a mut K, &'a mut V)
+        where K: Hash, S: BuildHasher
+    {
+        match self {
+            RawEntryMut::Occupied(entry) => entry.into_key_value(),
+            RawEntryMut::Vacant(entry) => entry.insert(default_key, default_val),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of the default function if empty,
+    /// and returns mutable references to the key and value in the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_raw_entry)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map: HashMap<&str, String> = HashMap::new();
+    ///
+    /// map.raw_entry_mut().from_key("poneyland").or_insert_with(|| {
+    ///     ("poneyland", "hoho".to_string())
+    /// });
+    ///
+    /// assert_eq!(map["poneyland"], "hoho".to_string());
+    /// ```
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn or_insert_with<F>(self, default: F) -> (&'a mut K, &'a mut V)
+        where F: FnOnce() -> (K, V), K: Hash, S: BuildHasher
+    {
+        match self {
+            RawEntryMut::Occupied(entry) => entry.into_key_value(),
+            RawEntryMut::Vacant(entry) => {
+                let (k, v) = default();
+                entry.insert(k, v)
+            }
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any
+    /// potential inserts into the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(hash_raw_entry)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map: HashMap<&str, u32> = HashMap::new();
+    ///
+    /// map.raw_entry_mut()
+    ///    .from_key("poneyland")
+    ///    .and_modify(|_k, v| { *v += 1 })
+    ///    .or_insert("poneyland", 42);
+    /// assert_eq!(map["poneyland"], 42);
+    ///
+    /// map.raw_entry_mut()
+    ///    .from_key("poneyland")
+    ///    .and_modify(|_k, v| { *v += 1 })
+    ///    .or_insert("poneyland", 0);
+    /// assert_eq!(map["poneyland"], 43);
+    /// ```
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn and_modify<F>(self, f: F) -> Self
+        where F: FnOnce(&mut K, &mut V)
+    {
+        match self {
+            RawEntryMut::Occupied(mut entry) => {
+                {
+                    let (k, v) = entry.get_key_value_mut();
+                    f(k, v);
+                }
+                RawEntryMut::Occupied(entry)
+            }
+            RawEntryMut::Vacant(entry) => RawEntryMut::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, K, V> RawOccupiedEntryMut<'a, K, V> {
+    /// Gets a reference to the key in the entry.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn key(&self) -> &K {
+        self.elem.read().0
+    }
+
+    /// Gets a mutable reference to the key in the entry.
+    ///
+    /// Changing the key in a way that alters its hash or equality is a logic
+    /// error.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn key_mut(&mut self) -> &mut K {
+        self.elem.read_mut().0
+    }
+
+    /// Converts the entry into a mutable reference to the key in the entry
+    /// with a lifetime bound to the map itself.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn into_key(self) -> &'a mut K {
+        self.elem.into_mut_refs().0
+    }
+
+    /// Gets a reference to the value in the entry.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn get(&self) -> &V {
+        self.elem.read().1
+    }
+
+    /// Gets a mutable reference to the value in the entry.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn get_mut(&mut self) -> &mut V {
+        self.elem.read_mut().1
+    }
+
+    /// Converts the entry into a mutable reference to the value in the entry
+    /// with a lifetime bound to the map itself.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn into_mut(self) -> &'a mut V {
+        self.elem.into_mut_refs().1
+    }
+
+    /// Gets references to the key and value in the entry.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn get_key_value(&self) -> (&K, &V) {
+        self.elem.read()
+    }
+
+    /// Gets mutable references to the key and value in the entry.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn get_key_value_mut(&mut self) -> (&mut K, &mut V) {
+        self.elem.read_mut()
+    }
+
+    /// Converts the entry into mutable references to the key and value in
+    /// the entry with a lifetime bound to the map itself.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn into_key_value(self) -> (&'a mut K, &'a mut V) {
+        self.elem.into_mut_refs()
+    }
+
+    /// Sets the value of the entry, and returns the entry's old value.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    /// Sets the key of the entry, and returns the entry's old key.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn insert_key(&mut self, key: K) -> K {
+        mem::replace(self.key_mut(), key)
+    }
+
+    /// Takes the value out of the entry, and returns it.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn remove(self) -> V {
+        pop_internal(self.elem).1
+    }
+
+    /// Takes the key and value out of the entry, and returns them.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn remove_entry(self) -> (K, V) {
+        pop_internal(self.elem)
+    }
+}
+
+impl<'a, K, V, S> RawVacantEntryMut<'a, K, V, S> {
+    /// Sets the value of the entry with the VacantEntry's key,
+    /// and returns mutable references to the key and value.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn insert(self, key: K, value: V) -> (&'a mut K, &'a mut V)
+        where K: Hash, S: BuildHasher
+    {
+        let hash = table::make_hash(self.hash_builder, &key);
+        self.insert_hashed_nocheck(hash.inspect(), key, value)
+    }
+
+    /// Sets the value of the entry with the VacantEntry's key,
+    /// and returns mutable references to the key and value.
+    ///
+    /// The hash must be the one the entry was looked up with, and must match
+    /// the hash of `key`; otherwise the map will misbehave.
+    #[unstable(feature = "hash_raw_entry", issue = "0")]
+    pub fn insert_hashed_nocheck(self, hash: u64, key: K, value: V) -> (&'a mut K, &'a mut V) {
+        let hash = SafeHash::new(hash);
+        match self.elem {
+            NeqElem(bucket, ib) => robin_hood(bucket, ib, hash, key, value).into_mut_refs(),
+            NoElem(bucket) => bucket.put(hash, key, value).into_mut_refs(),
+        }
+    }
+}
+
+#[unstable(feature = "hash_raw_entry", issue = "0")]
+impl<'a, K, V, S> Debug for RawEntryBuilderMut<'a, K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RawEntryBuilderMut")
+         .finish()
+    }
+}
+
+#[unstable(feature = "hash_raw_entry", issue = "0")]
+impl<'a, K: Debug, V: Debug, S> Debug for RawEntryMut<'a, K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RawEntryMut::Vacant(ref v) => f.debug_tuple("RawEntry")
+                                           .field(v)
+                                           .finish(),
+            RawEntryMut::Occupied(ref o) => f.debug_tuple("RawEntry")
+                                             .field(o)
+                                             .finish(),
+        }
+    }
+}
+
+#[unstable(feature = "hash_raw_entry", issue = "0")]
+impl<'a, K: Debug, V: Debug> Debug for RawOccupiedEntryMut<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RawOccupiedEntryMut")
+         .field("key", self.key())
+         .field("value", self.get())
+         .finish()
+    }
+}
+
+#[unstable(feature = "hash_raw_entry", issue = "0")]
+impl<'a, K, V, S> Debug for RawVacantEntryMut<'a, K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RawVacantEntryMut")
+         .finish()
+    }
+}
+
+#[unstable(feature = "hash_raw_entry", issue = "0")]
+impl<'a, K, V, S> Debug for RawEntryBuilder<'a, K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RawEntryBuilder")
+         .finish()
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a, K, V, S> IntoIterator for &'a HashMap<K, V, S>
     where K: Eq + Hash, S: BuildHasher
@@ -1604,6 +2146,65 @@ impl<'a, K, V> Entry<'a, K, V> {
         }
     }
 
+    /// Ensures a value is in the entry by inserting, if empty, the result of
+    /// the default function, which is passed a reference to the key. Returns
+    /// a mutable reference to the value in the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(or_insert_with_key)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map: HashMap<&str, usize> = HashMap::new();
+    ///
+    /// map.entry("poneyland").or_insert_with_key(|key| key.chars().count());
+    ///
+    /// assert_eq!(map["poneyland"], 9);
+    /// ```
+    #[unstable(feature = "or_insert_with_key", issue = "0")]
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Occupied(entry) => entry.into_mut(),
+            Vacant(entry) => {
+                let value = default(entry.key());
+                entry.insert(value)
+            }
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any
+    /// potential inserts into the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(entry_and_modify)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map: HashMap<&str, u32> = HashMap::new();
+    ///
+    /// map.entry("poneyland")
+    ///    .and_modify(|e| { *e += 1 })
+    ///    .or_insert(42);
+    /// assert_eq!(map["poneyland"], 42);
+    ///
+    /// map.entry("poneyland")
+    ///    .and_modify(|e| { *e += 1 })
+    ///    .or_insert(42);
+    /// assert_eq!(map["poneyland"], 43);
+    /// ```
+    #[unstable(feature = "entry_and_modify", issue = "0")]
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        match self {
+            Occupied(mut entry) => {
+                f(entry.get_mut());
+                Occupied(entry)
+            }
+            Vacant(entry) => Vacant(entry),
+        }
+    }
+
     /// Returns a reference to this entry's key.
     ///
     /// # Examples
@@ -1844,7 +2445,7 @@ impl<'a, K: 'a, V: 'a> VacantEntry<'a, K, V> {
     pub fn insert(self, value: V) -> &'a mut V {
         match self.elem {
             NeqElem(bucket, ib) => {
-                robin_hood(bucket, ib, self.hash, self.key, value)
+                robin_hood(bucket, ib, self.hash, self.key, value).into_mut_refs().1
             }
             NoElem(bucket) => {
                 bucket.put(self.hash, self.key, value).into_mut_refs().1
@@ -1965,11 +2566,43 @@ impl BuildHasher for RandomState {
 /// The default `Hasher` used by `RandomState`.
 ///
 /// The internal algorithm is not specified, and so it and its hashes should
-/// not be relied upon over releases.
-#[unstable(feature = "hashmap_default_hasher", issue = "0")]
+/// not be relied upon over releases. It is currently SipHash-1-3.
+#[derive(Clone, Debug)]
+#[stable(feature = "hashmap_default_hasher", since = "1.13.0")]
 pub struct DefaultHasher(SipHasher13);
 
-#[unstable(feature = "hashmap_default_hasher", issue = "0")]
+impl DefaultHasher {
+    /// Creates a new `DefaultHasher`.
+    ///
+    /// This hasher is not guaranteed to be the same as all other
+    /// `DefaultHasher` instances, but is the same as all other `DefaultHasher`
+    /// instances created through `new` or `default`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// let mut hasher = DefaultHasher::new();
+    /// 7920.hash(&mut hasher);
+    /// println!("Hash is {:x}!", hasher.finish());
+    /// ```
+    #[stable(feature = "hashmap_default_hasher", since = "1.13.0")]
+    pub fn new() -> DefaultHasher {
+        DefaultHasher(SipHasher13::new_with_keys(0, 0))
+    }
+}
+
+#[stable(feature = "hashmap_default_hasher", since = "1.13.0")]
+impl Default for DefaultHasher {
+    /// Creates a new `DefaultHasher` using `DefaultHasher::new`.
+    fn default() -> DefaultHasher {
+        DefaultHasher::new()
+    }
+}
+
+#[stable(feature = "hashmap_default_hasher", since = "1.13.0")]
 impl Hasher for DefaultHasher {
     #[inline]
     fn write(&mut self, msg: &[u8]) {
@@ -2043,7 +2676,7 @@ fn assert_covariance() {
 mod test_map {
     use prelude::v1::*;
 
-    use super::HashMap;
+    use super::{HashMap, RandomState};
     use super::Entry::{Occupied, Vacant};
     use cell::RefCell;
     use rand::{thread_rng, Rng};
@@ -2058,6 +2691,33 @@ mod test_map {
         assert!(!m.contains_key(&0));
     }
 
+    #[test]
+    fn test_default_hasher() {
+        use hash::{Hash, Hasher};
+        use super::DefaultHasher;
+
+        fn hash<T: Hash>(t: &T, mut hasher: DefaultHasher) -> u64 {
+            t.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        assert_eq!(hash(&"foo", DefaultHasher::new()), hash(&"foo", DefaultHasher::default()));
+        assert_eq!(hash(&1, DefaultHasher::new()), hash(&1, DefaultHasher::new().clone()));
+        assert!(hash(&1, DefaultHasher::new()) != hash(&2, DefaultHasher::new()));
+    }
+
+    #[test]
+    fn test_with_hasher_does_not_allocate() {
+        let mut m: HashMap<isize, isize> = HashMap::with_hasher(RandomState::new());
+        assert_eq!(m.capacity(), 0);
+        assert_eq!(m.get(&1), None);
+        assert_eq!(m.remove(&1), None);
+
+        assert!(m.insert(1, 1).is_none());
+        assert!(m.capacity() > 0);
+        assert_eq!(m.get(&1), Some(&1));
+    }
+
     #[test]
     fn test_insert() {
         let mut m = HashMap::new();
@@ -2839,6 +3499,134 @@ mod test_map {
         assert_eq!(a[key], value);
     }
 
+    #[test]
+    fn test_entry_and_modify() {
+        let mut map: HashMap<&str, u32> = HashMap::new();
+
+        map.entry("a").and_modify(|v| *v += 1).or_insert(1);
+        assert_eq!(map["a"], 1);
+        map.entry("a").and_modify(|v| *v += 1).or_insert(1);
+        assert_eq!(map["a"], 2);
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn test_entry_or_insert_with_key() {
+        let mut map: HashMap<String, usize> = HashMap::new();
+
+        assert_eq!(*map.entry("abc".to_string()).or_insert_with_key(|k| k.len()), 3);
+        // The default function is not called for an occupied entry.
+        assert_eq!(*map.entry("abc".to_string()).or_insert_with_key(|_| panic!()), 3);
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn test_raw_entry() {
+        use super::RawEntryMut::{Occupied, Vacant};
+
+        let xs = [(1i32, 10i32), (2, 20), (3, 30), (4, 40), (5, 50), (6, 60)];
+
+        let mut map: HashMap<_, _> = xs.iter().cloned().collect();
+
+        let compute_hash = |map: &HashMap<i32, i32>, k: i32| -> u64 {
+            use hash::{BuildHasher, Hash, Hasher};
+
+            let mut hasher = map.hasher().build_hasher();
+            k.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        // Existing key (insert)
+        match map.raw_entry_mut().from_key(&1) {
+            Vacant(_) => unreachable!(),
+            Occupied(mut view) => {
+                assert_eq!(view.get(), &10);
+                assert_eq!(view.insert(100), 10);
+            }
+        }
+        let hash1 = compute_hash(&map, 1);
+        assert_eq!(map.raw_entry().from_key(&1).unwrap(), (&1, &100));
+        assert_eq!(map.raw_entry().from_hash(hash1, |k| *k == 1).unwrap(), (&1, &100));
+        assert_eq!(map.raw_entry().from_key_hashed_nocheck(hash1, &1).unwrap(), (&1, &100));
+        assert_eq!(map.len(), 6);
+
+        // Existing key (update)
+        match map.raw_entry_mut().from_key(&2) {
+            Vacant(_) => unreachable!(),
+            Occupied(mut view) => {
+                let v = view.get_mut();
+                let new_v = (*v) * 10;
+                *v = new_v;
+            }
+        }
+        assert_eq!(map.raw_entry().from_key(&2).unwrap(), (&2, &200));
+        assert_eq!(map.len(), 6);
+
+        // Existing key (take)
+        let hash3 = compute_hash(&map, 3);
+        match map.raw_entry_mut().from_key_hashed_nocheck(hash3, &3) {
+            Vacant(_) => unreachable!(),
+            Occupied(view) => {
+                assert_eq!(view.remove_entry(), (3, 30));
+            }
+        }
+        assert_eq!(map.raw_entry().from_key(&3), None);
+        assert_eq!(map.len(), 5);
+
+        // Nonexistent key (insert)
+        let hash10 = compute_hash(&map, 10);
+        match map.raw_entry_mut().from_hash(hash10, |k| *k == 10) {
+            Occupied(_) => unreachable!(),
+            Vacant(view) => {
+                assert_eq!(view.insert(10, 1000), (&mut 10, &mut 1000));
+            }
+        }
+        assert_eq!(map.raw_entry().from_key(&10).unwrap(), (&10, &1000));
+        assert_eq!(map.len(), 6);
+
+        // Ensure all lookup methods produce equivalent results.
+        for k in 0..12 {
+            let hash = compute_hash(&map, k);
+            let v = map.get(&k).cloned();
+            let kv = v.as_ref().map(|v| (&k, v));
+
+            assert_eq!(map.raw_entry().from_key(&k), kv);
+            assert_eq!(map.raw_entry().from_hash(hash, |q| *q == k), kv);
+            assert_eq!(map.raw_entry().from_key_hashed_nocheck(hash, &k), kv);
+
+            match map.raw_entry_mut().from_key(&k) {
+                Occupied(o) => assert_eq!(Some(o.get_key_value()), kv),
+                Vacant(_) => assert_eq!(v, None),
+            }
+        }
+    }
+
+    #[test]
+    fn test_raw_entry_empty() {
+        let mut map: HashMap<i32, i32> = HashMap::new();
+        assert_eq!(map.raw_entry().from_key(&1), None);
+        map.raw_entry_mut().from_key(&1).or_insert(1, 2);
+        assert_eq!(map[&1], 2);
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut map: HashMap<isize, isize> = (0..100).map(|x|(x, x*10)).collect();
+
+        map.retain(|&k, _| k % 2 == 0);
+        assert_eq!(map.len(), 50);
+        assert_eq!(map[&2], 20);
+        assert_eq!(map[&4], 40);
+        assert_eq!(map[&6], 60);
+
+        map.retain(|_, v| { *v += 1; true });
+        assert_eq!(map.len(), 50);
+        assert_eq!(map[&2], 21);
+
+        map.retain(|_, _| false);
+        assert!(map.is_empty());
+    }
+
     #[test]
     fn test_vacant_entry_key() {
         let mut a = HashMap::new();
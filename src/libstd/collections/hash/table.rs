@@ -59,7 +59,6 @@ const EMPTY_BUCKET: u64 = 0;
 /// around just the "table" part of the hashtable. It enforces some
 /// invariants at the type level and employs some performance trickery,
 /// but in general is just a tricked out `Vec<Option<u64, K, V>>`.
-#[unsafe_no_drop_flag]
 pub struct RawTable<K, V> {
     capacity: usize,
     size: usize,
@@ -141,6 +140,17 @@ pub struct SafeHash {
 }
 
 impl SafeHash {
+    /// Make a hash safe by setting its most significant bit, so that it is
+    /// never zero.
+    #[inline(always)]
+    pub fn new(hash: u64) -> Self {
+        // We need to avoid 0 in order to prevent collisions with
+        // EMPTY_HASH. We can maintain our precious uniform distribution
+        // of initial indexes by unconditionally setting the MSB,
+        // effectively reducing 64-bits hashes to 63 bits.
+        SafeHash { hash: 0x8000_0000_0000_0000 | hash }
+    }
+
     /// Peek at the hash value, which is guaranteed to be non-zero.
     #[inline(always)]
     pub fn inspect(&self) -> u64 {
@@ -149,19 +159,15 @@ impl SafeHash {
 }
 
 /// We need to remove hashes of 0. That's reserved for empty buckets.
-/// This function wraps up `hash_keyed` to be the only way outside this
-/// module to generate a SafeHash.
+/// This function hashes `t` with `hash_state` and wraps the result up as a
+/// SafeHash.
 pub fn make_hash<T: ?Sized, S>(hash_state: &S, t: &T) -> SafeHash
     where T: Hash,
           S: BuildHasher
 {
     let mut state = hash_state.build_hasher();
     t.hash(&mut state);
-    // We need to avoid 0 in order to prevent collisions with
-    // EMPTY_HASH. We can maintain our precious uniform distribution
-    // of initial indexes by unconditionally setting the MSB,
-    // effectively reducing 64-bits hashes to 63 bits.
-    SafeHash { hash: 0x8000_0000_0000_0000 | state.finish() }
+    SafeHash::new(state.finish())
 }
 
 // `replace` casts a `*u64` to a `*SafeHash`. Since we statically
@@ -607,16 +613,22 @@ fn test_offset_calculation() {
 }
 
 impl<K, V> RawTable<K, V> {
+    /// Creates a table without any buckets. This does not allocate, so it
+    /// can be used to construct a map in a constant expression.
+    pub const fn new_empty() -> RawTable<K, V> {
+        RawTable {
+            size: 0,
+            capacity: 0,
+            hashes: unsafe { Unique::new(EMPTY as *mut u64) },
+            marker: marker::PhantomData,
+        }
+    }
+
     /// Does not initialize the buckets. The caller should ensure they,
     /// at the very least, set every hash to EMPTY_BUCKET.
     unsafe fn new_uninitialized(capacity: usize) -> RawTable<K, V> {
         if capacity == 0 {
-            return RawTable {
-                size: 0,
-                capacity: 0,
-                hashes: Unique::new(EMPTY as *mut u64),
-                marker: marker::PhantomData,
-            };
+            return RawTable::new_empty();
         }
 
         // No need for `checked_mul` before a more restrictive check performed
@@ -1042,7 +1054,7 @@ impl<K: Clone, V: Clone> Clone for RawTable<K, V> {
 impl<K, V> Drop for RawTable<K, V> {
     #[unsafe_destructor_blind_to_params]
     fn drop(&mut self) {
-        if self.capacity == 0 || self.capacity == mem::POST_DROP_USIZE {
+        if self.capacity == 0 {
             return;
         }
 
@@ -0,0 +1,143 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Runtime detection of x86 CPU features, backing `is_x86_feature_detected!`.
+//!
+//! The features are queried with `cpuid` the first time any of them is
+//! asked for, and cached in a single atomic word after that. Racing
+//! initializations are harmless as they all compute the same value.
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use sync::atomic::{AtomicUsize, Ordering};
+
+/// The features known to `is_x86_feature_detected!`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Feature {
+    Sse,
+    Sse2,
+    Sse3,
+    Ssse3,
+    Sse41,
+    Sse42,
+    Popcnt,
+    Avx,
+    Avx2,
+    Fma,
+    Bmi1,
+    Bmi2,
+    Lzcnt,
+}
+
+// Set once the cache has been filled in, so an empty feature set can be told
+// apart from a cache that wasn't initialized yet.
+const INITIALIZED: usize = 1 << 31;
+
+static CACHE: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns whether the CPU we're running on supports `feature`.
+pub fn check_for(feature: Feature) -> bool {
+    let mut cache = CACHE.load(Ordering::Relaxed);
+    if cache & INITIALIZED == 0 {
+        cache = detect_features() | INITIALIZED;
+        CACHE.store(cache, Ordering::Relaxed);
+    }
+    cache & (1 << feature as usize) != 0
+}
+
+fn bit(word: u32, n: u32) -> bool {
+    word & (1 << n) != 0
+}
+
+fn detect_features() -> usize {
+    let mut value = 0;
+    {
+        let mut enable = |feature: Feature, supported: bool| {
+            if supported {
+                value |= 1 << feature as usize;
+            }
+        };
+
+        if !has_cpuid() {
+            return 0
+        }
+
+        // See the Intel 64 and IA-32 Architectures Software Developer's Manual,
+        // volume 2A, for the meaning of the leaves and bits used here.
+        let max_leaf = unsafe { __cpuid(0).eax };
+        if max_leaf < 1 {
+            return 0
+        }
+        let CpuidResult { ecx, edx, .. } = unsafe { __cpuid(1) };
+        let ebx7 = if max_leaf >= 7 {
+            unsafe { __cpuid_count(7, 0).ebx }
+        } else {
+            0
+        };
+        let max_ext_leaf = unsafe { __cpuid(0x8000_0000).eax };
+        let ecx_ext = if max_ext_leaf >= 0x8000_0001 {
+            unsafe { __cpuid(0x8000_0001).ecx }
+        } else {
+            0
+        };
+
+        enable(Feature::Sse, bit(edx, 25));
+        enable(Feature::Sse2, bit(edx, 26));
+        enable(Feature::Sse3, bit(ecx, 0));
+        enable(Feature::Ssse3, bit(ecx, 9));
+        enable(Feature::Sse41, bit(ecx, 19));
+        enable(Feature::Sse42, bit(ecx, 20));
+        enable(Feature::Popcnt, bit(ecx, 23));
+        enable(Feature::Bmi1, bit(ebx7, 3));
+        enable(Feature::Bmi2, bit(ebx7, 8));
+        enable(Feature::Lzcnt, bit(ecx_ext, 5));
+
+        // The AVX registers are only usable if the OS saves them on context
+        // switches, which it advertises through OSXSAVE and XCR0.
+        let os_avx = bit(ecx, 27) && unsafe {
+            _xgetbv(_XCR_XFEATURE_ENABLED_MASK) & 0b110 == 0b110
+        };
+        if os_avx {
+            enable(Feature::Avx, bit(ecx, 28));
+            enable(Feature::Avx2, bit(ebx7, 5));
+            enable(Feature::Fma, bit(ecx, 12));
+        }
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_for, Feature};
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn baseline() {
+        assert!(check_for(Feature::Sse));
+        assert!(check_for(Feature::Sse2));
+    }
+
+    #[test]
+    fn avx2_implies_avx() {
+        if check_for(Feature::Avx2) {
+            assert!(check_for(Feature::Avx));
+        }
+    }
+
+    #[test]
+    fn macro_matches_check_for() {
+        assert_eq!(is_x86_feature_detected!("sse2"), check_for(Feature::Sse2));
+        assert_eq!(is_x86_feature_detected!("avx2"),
+                   cfg!(target_feature = "avx2") || check_for(Feature::Avx2));
+    }
+}
@@ -17,7 +17,6 @@ use error::Error;
 use fmt::{self, Write};
 use io;
 use iter::Iterator;
-use libc;
 use mem;
 use memchr;
 use ops;
@@ -27,6 +26,7 @@ use result::Result::{self, Ok, Err};
 use slice;
 use str::{self, Utf8Error};
 use string::String;
+use sys;
 use vec::Vec;
 
 /// A type representing an owned C-compatible string
@@ -224,7 +224,7 @@ impl CString {
     /// of the string will be recalculated from the pointer.
     #[stable(feature = "cstr_memory", since = "1.4.0")]
     pub unsafe fn from_raw(ptr: *mut c_char) -> CString {
-        let len = libc::strlen(ptr) + 1; // Including the NUL byte
+        let len = sys::strlen(ptr) + 1; // Including the NUL byte
         let slice = slice::from_raw_parts(ptr, len as usize);
         CString { inner: mem::transmute(slice) }
     }
@@ -467,7 +467,7 @@ impl CStr {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub unsafe fn from_ptr<'a>(ptr: *const c_char) -> &'a CStr {
-        let len = libc::strlen(ptr);
+        let len = sys::strlen(ptr);
         mem::transmute(slice::from_raw_parts(ptr, len as usize + 1))
     }
 
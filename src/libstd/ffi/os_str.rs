@@ -61,7 +61,7 @@ impl OsString {
         Some(OsString::from_vec(vec))
     }
 
-    #[cfg(windows)]
+    #[cfg(not(unix))]
     fn _from_bytes(vec: Vec<u8>) -> Option<OsString> {
         String::from_utf8(vec).ok().map(OsString::from)
     }
@@ -19,8 +19,9 @@
 
 use fmt;
 use ffi::OsString;
-use io::{self, SeekFrom, Seek, Read, Write};
+use io::{self, SeekFrom, Seek, Read, Write, IoSlice, IoSliceMut};
 use path::{Path, PathBuf};
+use string::String;
 use sys::fs as fs_imp;
 use sys_common::{AsInnerMut, FromInner, AsInner, IntoInner};
 use vec::Vec;
@@ -96,6 +97,30 @@ pub struct ReadDir(fs_imp::ReadDir);
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct DirEntry(fs_imp::DirEntry);
 
+/// A recursive iterator over the contents of a directory tree.
+///
+/// This struct is created by the [`walk_dir`] function. It walks the tree
+/// depth-first, yielding each directory before its contents. The directory
+/// the walk starts from is not itself yielded.
+///
+/// Errors are yielded in place of the entries they affect and iteration
+/// carries on afterwards, so a single unreadable directory doesn't end the
+/// whole walk. This includes the error for a symbolic link loop when links
+/// are being followed.
+///
+/// [`walk_dir`]: fn.walk_dir.html
+#[unstable(feature = "fs_walk_dir", issue = "0")]
+pub struct WalkDir {
+    root: Option<PathBuf>,
+    stack: Vec<ReadDir>,
+    // Canonical paths of the directories in `stack`, only kept when
+    // following symbolic links to detect loops.
+    ancestors: Vec<PathBuf>,
+    pending: Option<io::Error>,
+    max_depth: Option<usize>,
+    follow_links: bool,
+}
+
 /// Options and flags which can be used to configure how a file is opened.
 ///
 /// This builder exposes the ability to configure how a [`File`] is opened and
@@ -173,6 +198,133 @@ pub struct DirBuilder {
     recursive: bool,
 }
 
+/// How large a buffer to pre-allocate before reading the entire file.
+///
+/// One byte more than the file's length is reserved so that the final read,
+/// which returns zero to signal the end of the file, does not force the
+/// buffer to grow.
+fn initial_buffer_size(file: &File) -> usize {
+    // Don't worry about `usize` overflow because reading will fail regardless
+    // in that case.
+    file.metadata().map(|m| m.len() as usize + 1).unwrap_or(0)
+}
+
+/// Read the entire contents of a file into a bytes vector.
+///
+/// This is a convenience function for using [`File::open`] and [`read_to_end`]
+/// with fewer imports and without an intermediate variable. It pre-allocates a
+/// buffer based on the file size when available, so it is generally faster
+/// than reading into a vector created with `Vec::new()`.
+///
+/// [`File::open`]: struct.File.html#method.open
+/// [`read_to_end`]: ../io/trait.Read.html#method.read_to_end
+///
+/// # Errors
+///
+/// This function will return an error if `path` does not already exist.
+/// Other errors may also be returned according to [`OpenOptions::open`].
+///
+/// [`OpenOptions::open`]: struct.OpenOptions.html#method.open
+///
+/// It will also return an error if reading fails with an error of a kind
+/// other than [`ErrorKind::Interrupted`].
+///
+/// [`ErrorKind::Interrupted`]: ../io/enum.ErrorKind.html#variant.Interrupted
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_read_write)]
+///
+/// use std::fs;
+///
+/// # fn foo() -> std::io::Result<()> {
+/// let bytes = try!(fs::read("image.png"));
+/// println!("read {} bytes", bytes.len());
+/// # Ok(())
+/// # }
+/// ```
+#[unstable(feature = "fs_read_write", issue = "0")]
+pub fn read<P: AsRef<Path>>(path: P) -> io::Result<Vec<u8>> {
+    let mut file = try!(File::open(path));
+    let mut bytes = Vec::with_capacity(initial_buffer_size(&file));
+    try!(file.read_to_end(&mut bytes));
+    Ok(bytes)
+}
+
+/// Read the entire contents of a file into a string.
+///
+/// This is a convenience function for using [`File::open`] and [`read_to_string`]
+/// with fewer imports and without an intermediate variable. It pre-allocates a
+/// buffer based on the file size when available, so it is generally faster
+/// than reading into a string created with `String::new()`.
+///
+/// [`File::open`]: struct.File.html#method.open
+/// [`read_to_string`]: ../io/trait.Read.html#method.read_to_string
+///
+/// # Errors
+///
+/// This function will return an error if `path` does not already exist.
+/// Other errors may also be returned according to [`OpenOptions::open`].
+///
+/// [`OpenOptions::open`]: struct.OpenOptions.html#method.open
+///
+/// It will also return an error if reading fails with an error of a kind
+/// other than [`ErrorKind::Interrupted`], or if the contents of the file are
+/// not valid UTF-8.
+///
+/// [`ErrorKind::Interrupted`]: ../io/enum.ErrorKind.html#variant.Interrupted
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_read_write)]
+///
+/// use std::fs;
+/// use std::net::SocketAddr;
+///
+/// # fn foo() -> Result<(), Box<std::error::Error + 'static>> {
+/// let foo: SocketAddr = try!(try!(fs::read_to_string("address.txt")).parse());
+/// # Ok(())
+/// # }
+/// ```
+#[unstable(feature = "fs_read_write", issue = "0")]
+pub fn read_to_string<P: AsRef<Path>>(path: P) -> io::Result<String> {
+    let mut file = try!(File::open(path));
+    let mut string = String::with_capacity(initial_buffer_size(&file));
+    try!(file.read_to_string(&mut string));
+    Ok(string)
+}
+
+/// Write a slice as the entire contents of a file.
+///
+/// This function will create a file if it does not exist,
+/// and will entirely replace its contents if it does.
+///
+/// This is a convenience function for using [`File::create`] and [`write_all`]
+/// with fewer imports.
+///
+/// [`File::create`]: struct.File.html#method.create
+/// [`write_all`]: ../io/trait.Write.html#method.write_all
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_read_write)]
+///
+/// use std::fs;
+///
+/// # fn foo() -> std::io::Result<()> {
+/// try!(fs::write("foo.txt", b"Lorem ipsum"));
+/// try!(fs::write("bar.txt", "dolor sit"));
+/// # Ok(())
+/// # }
+/// ```
+#[unstable(feature = "fs_read_write", issue = "0")]
+pub fn write<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) -> io::Result<()> {
+    try!(File::create(path)).write_all(contents.as_ref())
+}
+
 impl File {
     /// Attempts to open a file in read-only mode.
     ///
@@ -348,6 +500,115 @@ impl File {
             inner: self.inner.duplicate()?
         })
     }
+
+    /// Acquires a shared (read) lock on the whole file, blocking until it
+    /// can be acquired.
+    ///
+    /// Any number of handles may hold a shared lock at the same time, but
+    /// not while another handle holds an exclusive lock. If this handle
+    /// already holds a lock, it is converted to a shared one (on Windows a
+    /// second lock is taken instead, so each must be unlocked).
+    ///
+    /// The lock is advisory: it only affects other callers of the locking
+    /// functions, not plain reads and writes. It is tied to this handle (and
+    /// its clones), and is released by `unlock` or when the last handle to
+    /// the file is closed.
+    ///
+    /// This uses `flock` on Unix and `LockFileEx` on Windows.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    ///
+    /// use std::fs::File;
+    ///
+    /// # fn foo() -> std::io::Result<()> {
+    /// let f = try!(File::open("foo.txt"));
+    /// try!(f.lock_shared());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "0")]
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.inner.lock_shared()
+    }
+
+    /// Acquires an exclusive (write) lock on the whole file, blocking until
+    /// it can be acquired.
+    ///
+    /// No other handle may hold any lock on the file at the same time. See
+    /// [`lock_shared`] for the general semantics of file locks.
+    ///
+    /// [`lock_shared`]: #method.lock_shared
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    ///
+    /// use std::fs::File;
+    ///
+    /// # fn foo() -> std::io::Result<()> {
+    /// let f = try!(File::create("foo.txt"));
+    /// try!(f.lock_exclusive());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "0")]
+    pub fn lock_exclusive(&self) -> io::Result<()> {
+        self.inner.lock_exclusive()
+    }
+
+    /// Attempts to acquire a shared lock on the whole file without blocking.
+    ///
+    /// Returns `Ok(false)` if the lock is currently held exclusively through
+    /// another handle. See [`lock_shared`] for the general semantics of file
+    /// locks.
+    ///
+    /// [`lock_shared`]: #method.lock_shared
+    #[unstable(feature = "file_lock", issue = "0")]
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.inner.try_lock_shared()
+    }
+
+    /// Attempts to acquire an exclusive lock on the whole file without
+    /// blocking.
+    ///
+    /// Returns `Ok(false)` if any lock on the file is currently held through
+    /// another handle. See [`lock_shared`] for the general semantics of file
+    /// locks.
+    ///
+    /// [`lock_shared`]: #method.lock_shared
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(file_lock)]
+    ///
+    /// use std::fs::File;
+    ///
+    /// # fn foo() -> std::io::Result<()> {
+    /// let f = try!(File::create("cache.lock"));
+    /// if !try!(f.try_lock_exclusive()) {
+    ///     println!("the cache is in use by another process");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[unstable(feature = "file_lock", issue = "0")]
+    pub fn try_lock_exclusive(&self) -> io::Result<bool> {
+        self.inner.try_lock_exclusive()
+    }
+
+    /// Releases a lock held on this file.
+    ///
+    /// On Windows this returns an error if the file isn't locked, while on
+    /// Unix it succeeds either way.
+    #[unstable(feature = "file_lock", issue = "0")]
+    pub fn unlock(&self) -> io::Result<()> {
+        self.inner.unlock()
+    }
 }
 
 impl AsInner<fs_imp::File> for File {
@@ -376,6 +637,9 @@ impl Read for File {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         self.inner.read(buf)
     }
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        self.inner.read_vectored(bufs)
+    }
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
         self.inner.read_to_end(buf)
     }
@@ -385,6 +649,9 @@ impl Write for File {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         self.inner.write(buf)
     }
+    fn write_vectored(&mut self, bufs: &[IoSlice]) -> io::Result<usize> {
+        self.inner.write_vectored(bufs)
+    }
     fn flush(&mut self) -> io::Result<()> { self.inner.flush() }
 }
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -398,6 +665,9 @@ impl<'a> Read for &'a File {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         self.inner.read(buf)
     }
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        self.inner.read_vectored(bufs)
+    }
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
         self.inner.read_to_end(buf)
     }
@@ -407,6 +677,9 @@ impl<'a> Write for &'a File {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         self.inner.write(buf)
     }
+    fn write_vectored(&mut self, bufs: &[IoSlice]) -> io::Result<usize> {
+        self.inner.write_vectored(bufs)
+    }
     fn flush(&mut self) -> io::Result<()> { self.inner.flush() }
 }
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -932,6 +1205,120 @@ impl Iterator for ReadDir {
     }
 }
 
+impl WalkDir {
+    /// Limits how far below the starting directory the walk descends.
+    ///
+    /// The contents of the starting directory are at depth 1, so
+    /// `max_depth(1)` yields the same entries as `read_dir`. By default there
+    /// is no limit.
+    #[unstable(feature = "fs_walk_dir", issue = "0")]
+    pub fn max_depth(mut self, depth: usize) -> WalkDir {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Sets whether to descend into directories that are reached through a
+    /// symbolic link.
+    ///
+    /// This is off by default, in which case symbolic links are yielded but
+    /// never traversed. When it is on, a link which leads back to one of the
+    /// directories currently being walked yields an error instead of being
+    /// traversed again.
+    #[unstable(feature = "fs_walk_dir", issue = "0")]
+    pub fn follow_links(mut self, follow: bool) -> WalkDir {
+        self.follow_links = follow;
+        self
+    }
+
+    fn push_dir(&mut self, path: &Path) -> io::Result<()> {
+        if self.follow_links {
+            let canonical = canonicalize(path)?;
+            if self.ancestors.contains(&canonical) {
+                return Err(io::Error::new(io::ErrorKind::Other,
+                                          "symbolic link loop detected"));
+            }
+            self.ancestors.push(canonical);
+        }
+        match read_dir(path) {
+            Ok(dir) => {
+                self.stack.push(dir);
+                Ok(())
+            }
+            Err(e) => {
+                if self.follow_links {
+                    self.ancestors.pop();
+                }
+                Err(e)
+            }
+        }
+    }
+
+    fn should_descend(&self, entry: &DirEntry) -> io::Result<bool> {
+        if let Some(max_depth) = self.max_depth {
+            if self.stack.len() >= max_depth {
+                return Ok(false)
+            }
+        }
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            Ok(true)
+        } else if file_type.is_symlink() && self.follow_links {
+            // A dangling link is yielded like any other, not an error.
+            Ok(metadata(entry.path()).map(|m| m.is_dir()).unwrap_or(false))
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+#[unstable(feature = "fs_walk_dir", issue = "0")]
+impl Iterator for WalkDir {
+    type Item = io::Result<DirEntry>;
+
+    fn next(&mut self) -> Option<io::Result<DirEntry>> {
+        if let Some(root) = self.root.take() {
+            if self.max_depth == Some(0) {
+                return None
+            }
+            if let Err(e) = self.push_dir(&root) {
+                return Some(Err(e))
+            }
+        }
+        if let Some(e) = self.pending.take() {
+            return Some(Err(e))
+        }
+        loop {
+            let next = match self.stack.last_mut() {
+                Some(dir) => dir.next(),
+                None => return None,
+            };
+            match next {
+                Some(Ok(entry)) => {
+                    // Any problem entering the directory is reported right
+                    // after the entry itself.
+                    match self.should_descend(&entry) {
+                        Ok(true) => {
+                            if let Err(e) = self.push_dir(&entry.path()) {
+                                self.pending = Some(e);
+                            }
+                        }
+                        Ok(false) => {}
+                        Err(e) => self.pending = Some(e),
+                    }
+                    return Some(Ok(entry))
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None => {
+                    self.stack.pop();
+                    if self.follow_links {
+                        self.ancestors.pop();
+                    }
+                }
+            }
+        }
+    }
+}
+
 impl DirEntry {
     /// Returns the full path to the file that this entry represents.
     ///
@@ -1558,6 +1945,47 @@ pub fn read_dir<P: AsRef<Path>>(path: P) -> io::Result<ReadDir> {
     fs_imp::readdir(path.as_ref()).map(ReadDir)
 }
 
+/// Returns an iterator which recursively walks the directory tree below
+/// `path`.
+///
+/// Entries are yielded depth-first, each directory coming right before its
+/// own contents, and the order of entries within a directory is the same as
+/// for [`read_dir`]. The returned [`WalkDir`] can be configured with a
+/// maximum depth and whether to follow symbolic links before iterating.
+///
+/// An error opening `path` itself is the first item yielded; errors further
+/// down the tree are yielded as they are found and don't stop the walk.
+///
+/// [`read_dir`]: fn.read_dir.html
+/// [`WalkDir`]: struct.WalkDir.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fs_walk_dir)]
+///
+/// use std::fs;
+///
+/// // print the path of every file up to two levels below `.`
+/// for entry in fs::walk_dir(".").max_depth(2) {
+///     match entry {
+///         Ok(entry) => println!("{}", entry.path().display()),
+///         Err(e) => println!("error: {}", e),
+///     }
+/// }
+/// ```
+#[unstable(feature = "fs_walk_dir", issue = "0")]
+pub fn walk_dir<P: AsRef<Path>>(path: P) -> WalkDir {
+    WalkDir {
+        root: Some(path.as_ref().to_path_buf()),
+        stack: Vec::new(),
+        ancestors: Vec::new(),
+        pending: None,
+        max_depth: None,
+        follow_links: false,
+    }
+}
+
 /// Changes the permissions found on a file or a directory.
 ///
 /// # Platform-specific behavior
@@ -1681,8 +2109,8 @@ mod tests {
     use io::prelude::*;
 
     use fs::{self, File, OpenOptions};
-    use io::{ErrorKind, SeekFrom};
-    use path::Path;
+    use io::{self, ErrorKind, IoSlice, IoSliceMut, SeekFrom};
+    use path::{Path, PathBuf};
     use rand::{StdRng, Rng};
     use str;
     use sys_common::io::test::{TempDir, tmpdir};
@@ -1734,6 +2162,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn read_write_whole_file() {
+        let tmpdir = tmpdir();
+        let path = tmpdir.join("read_write_whole_file.txt");
+
+        check!(fs::write(&path, "hello, world"));
+        assert_eq!(check!(fs::read(&path)), b"hello, world");
+        assert_eq!(check!(fs::read_to_string(&path)), "hello, world");
+
+        // Writing again replaces the previous contents.
+        check!(fs::write(&path, &[0xffu8, 0xfe]));
+        assert_eq!(check!(fs::read(&path)), &[0xff, 0xfe]);
+        error!(fs::read_to_string(&path), "stream did not contain valid UTF-8");
+
+        let missing = tmpdir.join("missing.txt");
+        assert!(fs::read(&missing).is_err());
+        assert!(fs::read_to_string(&missing).is_err());
+    }
+
+    #[test]
+    fn file_test_io_vectored() {
+        let tmpdir = tmpdir();
+        let path = tmpdir.join("file_test_io_vectored.txt");
+        let message = b"hello, world";
+        {
+            let mut f = check!(File::create(&path));
+            let bufs = [IoSlice::new(&message[..7]), IoSlice::new(&message[7..])];
+            // Short writes are allowed, so finish off whatever is left.
+            let n = check!(f.write_vectored(&bufs));
+            check!(f.write_all(&message[n..]));
+        }
+        let mut f = check!(File::open(&path));
+        let mut a = [0; 7];
+        let mut b = [0; 5];
+        let n = {
+            let mut bufs = [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+            check!(f.read_vectored(&mut bufs))
+        };
+        assert!(n > 0 && n <= message.len());
+        let mut read = a.to_vec();
+        read.extend_from_slice(&b);
+        assert_eq!(&read[..n], &message[..n]);
+    }
+
     #[test]
     fn file_test_io_smoke_test() {
         let message = "it's alright. have a good time";
@@ -1986,6 +2458,69 @@ mod tests {
         check!(fs::remove_dir(dir));
     }
 
+    #[test]
+    fn walk_dir() {
+        let tmpdir = tmpdir();
+        let root = tmpdir.join("walk_dir");
+        check!(fs::create_dir_all(root.join("a/b")));
+        check!(fs::create_dir(root.join("c")));
+        check!(File::create(root.join("a/1.txt")));
+        check!(File::create(root.join("a/b/2.txt")));
+        check!(File::create(root.join("3.txt")));
+
+        let walk = |w: fs::WalkDir| {
+            let mut paths = w.map(|e| {
+                check!(e).path().strip_prefix(&root).unwrap().to_path_buf()
+            }).collect::<Vec<_>>();
+            paths.sort();
+            paths
+        };
+        let paths = |names: &[&str]| {
+            names.iter().map(|n| Path::new(n).components().collect::<PathBuf>())
+                 .collect::<Vec<_>>()
+        };
+
+        assert_eq!(walk(fs::walk_dir(&root)),
+                   paths(&["3.txt", "a", "a/1.txt", "a/b", "a/b/2.txt", "c"]));
+        assert_eq!(walk(fs::walk_dir(&root).max_depth(2)),
+                   paths(&["3.txt", "a", "a/1.txt", "a/b", "c"]));
+        assert_eq!(walk(fs::walk_dir(&root).max_depth(1)), paths(&["3.txt", "a", "c"]));
+        assert_eq!(walk(fs::walk_dir(&root).max_depth(0)), paths(&[]));
+
+        // Directories come before their contents.
+        let order = fs::walk_dir(&root).map(|e| check!(e).path()).collect::<Vec<_>>();
+        let pos = |p: &str| order.iter().position(|e| *e == root.join(p)).unwrap();
+        assert!(pos("a") < pos("a/b"));
+        assert!(pos("a/b") < pos("a/b/2.txt"));
+
+        let mut missing = fs::walk_dir(tmpdir.join("missing"));
+        assert!(missing.next().unwrap().is_err());
+        assert!(missing.next().is_none());
+    }
+
+    #[test]
+    fn walk_dir_symlinks() {
+        let tmpdir = tmpdir();
+        if !got_symlink_permission(&tmpdir) { return };
+
+        let root = tmpdir.join("walk_dir_symlinks");
+        check!(fs::create_dir_all(root.join("a")));
+        check!(File::create(root.join("a/1.txt")));
+        check!(symlink_dir(root.join("a"), root.join("link")));
+        // a link back up the tree, which must not be walked forever
+        check!(symlink_dir(&root, root.join("a/up")));
+
+        let entries = fs::walk_dir(&root).collect::<Vec<_>>();
+        assert!(entries.iter().all(|e| e.is_ok()));
+        assert_eq!(entries.len(), 4);
+
+        let (entries, errors): (Vec<_>, Vec<_>) =
+            fs::walk_dir(&root).follow_links(true).partition(|e| e.is_ok());
+        let entries = entries.into_iter().map(|e| e.unwrap().path()).collect::<Vec<_>>();
+        assert!(entries.contains(&root.join("link/1.txt")));
+        assert!(!errors.is_empty());
+    }
+
     #[test]
     fn file_create_new_already_exists_error() {
         let tmpdir = tmpdir();
@@ -2158,6 +2693,88 @@ mod tests {
                    check!(out.metadata()).permissions());
     }
 
+    #[test]
+    #[cfg_attr(target_os = "solaris", ignore)]
+    fn file_lock() {
+        let tmpdir = tmpdir();
+        let path = tmpdir.join("file_lock");
+        let f1 = check!(File::create(&path));
+        let f2 = check!(File::open(&path));
+
+        check!(f1.lock_exclusive());
+        assert!(!check!(f2.try_lock_shared()));
+        assert!(!check!(f2.try_lock_exclusive()));
+        check!(f1.unlock());
+
+        check!(f1.lock_shared());
+        assert!(check!(f2.try_lock_shared()));
+        check!(f2.unlock());
+        assert!(!check!(f2.try_lock_exclusive()));
+        check!(f1.unlock());
+
+        assert!(check!(f2.try_lock_exclusive()));
+        check!(f2.unlock());
+
+        // Closing the handle releases its lock.
+        check!(f1.lock_exclusive());
+        drop(f1);
+        assert!(check!(f2.try_lock_exclusive()));
+        check!(f2.unlock());
+    }
+
+    #[test]
+    fn io_copy_between_files() {
+        let tmpdir = tmpdir();
+        let input = tmpdir.join("in.txt");
+        let out = tmpdir.join("out.txt");
+
+        // Larger than any of the copy buffers, so the copy takes several
+        // rounds whichever way it ends up being done.
+        let data: Vec<u8> = (0..300 * 1024).map(|i| i as u8).collect();
+        check!(fs::write(&input, &data));
+
+        // Both the source and destination offsets are honored.
+        let mut reader = check!(File::open(&input));
+        check!(reader.seek(SeekFrom::Start(10)));
+        let mut writer = check!(File::create(&out));
+        check!(writer.write_all(b"xy"));
+        assert_eq!(check!(io::copy(&mut reader, &mut writer)), data.len() as u64 - 10);
+        drop(writer);
+
+        let copied = check!(fs::read(&out));
+        assert_eq!(&copied[..2], b"xy");
+        assert!(copied[2..] == data[10..]);
+    }
+
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn io_copy_from_procfs() {
+        // Files in /proc report a size of zero, which the kernel copies take
+        // for the end of the file.
+        let tmpdir = tmpdir();
+        let out = tmpdir.join("status.txt");
+        let mut reader = check!(File::open("/proc/self/status"));
+        let mut writer = check!(File::create(&out));
+        let copied = check!(io::copy(&mut reader, &mut writer));
+        assert!(copied > 0);
+        assert_eq!(check!(out.metadata()).len(), copied);
+    }
+
+    #[test]
+    fn io_copy_to_append_file() {
+        let tmpdir = tmpdir();
+        let input = tmpdir.join("in.txt");
+        let out = tmpdir.join("out.txt");
+        check!(fs::write(&input, b"world"));
+        check!(fs::write(&out, b"hello "));
+
+        let mut reader = check!(File::open(&input));
+        let mut writer = check!(OpenOptions::new().append(true).open(&out));
+        assert_eq!(check!(io::copy(&mut reader, &mut writer)), 5);
+        drop(writer);
+        assert_eq!(check!(fs::read(&out)), b"hello world");
+    }
+
     #[test]
     fn copy_file_dst_dir() {
         let tmpdir = tmpdir();
@@ -2211,6 +2828,22 @@ mod tests {
         check!(fs::set_permissions(&out, attr.permissions()));
     }
 
+    #[cfg(windows)]
+    #[test]
+    fn open_options_share_mode() {
+        use os::windows::fs::OpenOptionsExt;
+
+        let tmpdir = tmpdir();
+        let path = tmpdir.join("share_mode.txt");
+        check!(File::create(&path));
+
+        // Deny all sharing while the first handle is open.
+        let f = check!(OpenOptions::new().read(true).share_mode(0).open(&path));
+        assert!(OpenOptions::new().read(true).open(&path).is_err());
+        drop(f);
+        check!(OpenOptions::new().read(true).open(&path));
+    }
+
     #[cfg(windows)]
     #[test]
     fn copy_file_preserves_streams() {
@@ -162,6 +162,46 @@ impl<R: Read> BufReader<R> {
     pub fn into_inner(self) -> R { self.inner }
 }
 
+impl<R: Seek> BufReader<R> {
+    /// Seeks relative to the current position.
+    ///
+    /// If the new position lies within the buffer, the buffer will not be
+    /// flushed, allowing for more efficient seeks. Otherwise this behaves
+    /// like `seek(SeekFrom::Current(offset))` and discards the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bufreader_seek_relative)]
+    ///
+    /// use std::io::prelude::*;
+    /// use std::io::{BufReader, Cursor};
+    ///
+    /// let mut reader = BufReader::new(Cursor::new(&b"hello world"[..]));
+    /// assert_eq!(reader.fill_buf().unwrap(), b"hello world");
+    ///
+    /// // Skip over "hello " without touching the underlying reader.
+    /// reader.seek_relative(6).unwrap();
+    /// assert_eq!(reader.fill_buf().unwrap(), b"world");
+    /// ```
+    #[unstable(feature = "bufreader_seek_relative", issue = "0")]
+    pub fn seek_relative(&mut self, offset: i64) -> io::Result<()> {
+        let pos = self.pos as u64;
+        let new_pos = if offset < 0 {
+            pos.checked_sub(offset.wrapping_neg() as u64)
+        } else {
+            pos.checked_add(offset as u64)
+        };
+        match new_pos {
+            Some(new_pos) if new_pos <= self.cap as u64 => {
+                self.pos = new_pos as usize;
+                Ok(())
+            }
+            _ => self.seek(SeekFrom::Current(offset)).map(|_| ()),
+        }
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<R: Read> Read for BufReader<R> {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
@@ -862,6 +902,53 @@ mod tests {
         assert_eq!(reader.seek(SeekFrom::Current(-2)).ok(), Some(3));
     }
 
+    #[test]
+    fn test_buffered_reader_seek_relative() {
+        let inner: &[u8] = &[5, 6, 7, 0, 1, 2, 3, 4];
+        let mut reader = BufReader::with_capacity(2, io::Cursor::new(inner));
+
+        assert!(reader.seek_relative(3).is_ok());
+        assert_eq!(reader.fill_buf().ok(), Some(&[0, 1][..]));
+        assert!(reader.seek_relative(0).is_ok());
+        assert_eq!(reader.fill_buf().ok(), Some(&[0, 1][..]));
+        assert!(reader.seek_relative(1).is_ok());
+        assert_eq!(reader.fill_buf().ok(), Some(&[1][..]));
+        assert!(reader.seek_relative(-1).is_ok());
+        assert_eq!(reader.fill_buf().ok(), Some(&[0, 1][..]));
+        assert!(reader.seek_relative(2).is_ok());
+        assert_eq!(reader.fill_buf().ok(), Some(&[2, 3][..]));
+        assert!(reader.seek_relative(-4).is_ok());
+        assert_eq!(reader.fill_buf().ok(), Some(&[6, 7][..]));
+        assert!(reader.seek_relative(-3).is_err());
+    }
+
+    #[test]
+    fn test_buffered_reader_seek_relative_keeps_buffer() {
+        // A reader which refuses to seek, so that any seek which can be
+        // satisfied from the buffer must not reach it.
+        struct NoSeek<'a>(&'a [u8]);
+        impl<'a> Read for NoSeek<'a> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.0.read(buf)
+            }
+        }
+        impl<'a> Seek for NoSeek<'a> {
+            fn seek(&mut self, _: SeekFrom) -> io::Result<u64> {
+                Err(io::Error::new(io::ErrorKind::Other, "seek called"))
+            }
+        }
+
+        let mut reader = BufReader::with_capacity(4, NoSeek(&[0, 1, 2, 3, 4, 5]));
+        assert_eq!(reader.fill_buf().ok(), Some(&[0, 1, 2, 3][..]));
+        assert!(reader.seek_relative(3).is_ok());
+        assert_eq!(reader.fill_buf().ok(), Some(&[3][..]));
+        assert!(reader.seek_relative(-2).is_ok());
+        assert_eq!(reader.fill_buf().ok(), Some(&[1, 2, 3][..]));
+        assert!(reader.seek_relative(1).is_ok());
+        assert!(reader.seek_relative(2).is_ok());
+        assert!(reader.seek_relative(1).is_err());
+    }
+
     #[test]
     fn test_buffered_reader_seek_underflow() {
         // gimmick reader that yields its position modulo 256 for each byte
@@ -255,12 +255,13 @@ use error as std_error;
 use fmt;
 use iter::{Iterator};
 use marker::Sized;
-use ops::{Drop, FnOnce};
+use ops::{Deref, DerefMut, Drop, FnOnce};
 use option::Option::{self, Some, None};
 use result::Result::{Ok, Err};
 use result;
 use string::String;
 use str;
+use sys;
 use vec::Vec;
 use memchr;
 
@@ -477,6 +478,42 @@ pub trait Read {
     #[stable(feature = "rust1", since = "1.0.0")]
     fn read(&mut self, buf: &mut [u8]) -> Result<usize>;
 
+    /// Like `read`, except that it reads into a slice of buffers.
+    ///
+    /// Data is copied to fill each buffer in order, with the final buffer
+    /// written to possibly being only partially filled. This method must behave
+    /// as a single call to `read` with the buffers concatenated would.
+    ///
+    /// The default implementation calls `read` with either the first nonempty
+    /// buffer provided, or an empty one if none exists. Files and sockets
+    /// override it to read with a single `readv` (or `WSARecv`) system call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iovec)]
+    ///
+    /// use std::io::{self, IoSliceMut, Read};
+    ///
+    /// # fn foo() -> io::Result<()> {
+    /// let mut data: &[u8] = b"headerbody";
+    /// let mut header = [0; 6];
+    /// let mut body = [0; 4];
+    ///
+    /// let n = try!(data.read_vectored(&mut [IoSliceMut::new(&mut header),
+    ///                                       IoSliceMut::new(&mut body)]));
+    /// assert_eq!(n, 10);
+    /// assert_eq!(&header, b"header");
+    /// assert_eq!(&body, b"body");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[unstable(feature = "iovec", issue = "0")]
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> Result<usize> {
+        let buf = bufs.iter_mut().find(|b| !b.is_empty()).map_or(&mut [][..], |b| &mut **b);
+        self.read(buf)
+    }
+
     /// Read all bytes until EOF in this source, placing them into `buf`.
     ///
     /// All bytes read from this source will be appended to the specified buffer
@@ -885,6 +922,38 @@ pub trait Write {
     #[stable(feature = "rust1", since = "1.0.0")]
     fn write(&mut self, buf: &[u8]) -> Result<usize>;
 
+    /// Like `write`, except that it writes from a slice of buffers.
+    ///
+    /// Data is copied from each buffer in order, with the final buffer
+    /// read from possibly being only partially consumed. This method must
+    /// behave as a call to `write` with the buffers concatenated would.
+    ///
+    /// The default implementation calls `write` with either the first nonempty
+    /// buffer provided, or an empty one if none exists. Files and sockets
+    /// override it to write with a single `writev` (or `WSASend`) system call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(iovec)]
+    ///
+    /// use std::io::{self, IoSlice, Write};
+    ///
+    /// # fn foo() -> io::Result<()> {
+    /// let mut buffer = Vec::new();
+    /// let n = try!(buffer.write_vectored(&[IoSlice::new(b"header"),
+    ///                                      IoSlice::new(b"body")]));
+    /// assert_eq!(n, 10);
+    /// assert_eq!(buffer, b"headerbody");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[unstable(feature = "iovec", issue = "0")]
+    fn write_vectored(&mut self, bufs: &[IoSlice]) -> Result<usize> {
+        let buf = bufs.iter().find(|b| !b.is_empty()).map_or(&[][..], |b| &**b);
+        self.write(buf)
+    }
+
     /// Flush this output stream, ensuring that all intermediately buffered
     /// contents reach their destination.
     ///
@@ -1047,6 +1116,104 @@ pub trait Write {
     fn by_ref(&mut self) -> &mut Self where Self: Sized { self }
 }
 
+/// A buffer type used with `Read::read_vectored`.
+///
+/// It is semantically a wrapper around an `&mut [u8]`, but is guaranteed to be
+/// ABI compatible with the `iovec` type on Unix platforms and `WSABUF` on
+/// Windows, so a slice of them can be handed to the operating system as is.
+#[unstable(feature = "iovec", issue = "0")]
+#[repr(C)]
+pub struct IoSliceMut<'a>(sys::io::IoSliceMut<'a>);
+
+#[unstable(feature = "iovec", issue = "0")]
+unsafe impl<'a> Send for IoSliceMut<'a> {}
+
+#[unstable(feature = "iovec", issue = "0")]
+unsafe impl<'a> Sync for IoSliceMut<'a> {}
+
+#[unstable(feature = "iovec", issue = "0")]
+impl<'a> fmt::Debug for IoSliceMut<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.0.as_slice(), fmt)
+    }
+}
+
+impl<'a> IoSliceMut<'a> {
+    /// Creates a new `IoSliceMut` wrapping a byte slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics on Windows if the slice is larger than 4GB.
+    #[unstable(feature = "iovec", issue = "0")]
+    #[inline]
+    pub fn new(buf: &'a mut [u8]) -> IoSliceMut<'a> {
+        IoSliceMut(sys::io::IoSliceMut::new(buf))
+    }
+}
+
+#[unstable(feature = "iovec", issue = "0")]
+impl<'a> Deref for IoSliceMut<'a> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+#[unstable(feature = "iovec", issue = "0")]
+impl<'a> DerefMut for IoSliceMut<'a> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.0.as_mut_slice()
+    }
+}
+
+/// A buffer type used with `Write::write_vectored`.
+///
+/// It is semantically a wrapper around an `&[u8]`, but is guaranteed to be
+/// ABI compatible with the `iovec` type on Unix platforms and `WSABUF` on
+/// Windows, so a slice of them can be handed to the operating system as is.
+#[unstable(feature = "iovec", issue = "0")]
+#[repr(C)]
+pub struct IoSlice<'a>(sys::io::IoSlice<'a>);
+
+#[unstable(feature = "iovec", issue = "0")]
+unsafe impl<'a> Send for IoSlice<'a> {}
+
+#[unstable(feature = "iovec", issue = "0")]
+unsafe impl<'a> Sync for IoSlice<'a> {}
+
+#[unstable(feature = "iovec", issue = "0")]
+impl<'a> fmt::Debug for IoSlice<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.0.as_slice(), fmt)
+    }
+}
+
+impl<'a> IoSlice<'a> {
+    /// Creates a new `IoSlice` wrapping a byte slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics on Windows if the slice is larger than 4GB.
+    #[unstable(feature = "iovec", issue = "0")]
+    #[inline]
+    pub fn new(buf: &'a [u8]) -> IoSlice<'a> {
+        IoSlice(sys::io::IoSlice::new(buf))
+    }
+}
+
+#[unstable(feature = "iovec", issue = "0")]
+impl<'a> Deref for IoSlice<'a> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
 /// The `Seek` trait provides a cursor which can be moved within a stream of
 /// bytes.
 ///
@@ -1737,10 +1904,50 @@ mod tests {
     use prelude::v1::*;
     use io::prelude::*;
     use io;
-    use super::Cursor;
+    use super::{Cursor, IoSlice, IoSliceMut};
     use test;
     use super::repeat;
 
+    #[test]
+    fn read_vectored() {
+        // `Cursor` uses the default implementation, which only fills the
+        // first non-empty buffer.
+        let mut c = Cursor::new(&b"1234"[..]);
+        let mut a = [0; 2];
+        let mut b = [0; 2];
+        {
+            let mut bufs = [IoSliceMut::new(&mut []),
+                            IoSliceMut::new(&mut a),
+                            IoSliceMut::new(&mut b)];
+            assert_eq!(c.read_vectored(&mut bufs).unwrap(), 2);
+        }
+        assert_eq!(a, [b'1', b'2']);
+        assert_eq!(b, [0, 0]);
+
+        let mut r = &b"12345"[..];
+        let mut a = [0; 2];
+        let mut b = [0; 4];
+        {
+            let mut bufs = [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+            assert_eq!(r.read_vectored(&mut bufs).unwrap(), 5);
+        }
+        assert_eq!(a, [b'1', b'2']);
+        assert_eq!(b, [b'3', b'4', b'5', 0]);
+        assert_eq!(r, &[][..]);
+    }
+
+    #[test]
+    fn write_vectored() {
+        let mut w = Vec::new();
+        let bufs = [IoSlice::new(b"12"), IoSlice::new(&[]), IoSlice::new(b"345")];
+        assert_eq!(w.write_vectored(&bufs).unwrap(), 5);
+        assert_eq!(w, b"12345");
+
+        let mut c = Cursor::new(Vec::new());
+        assert_eq!(c.write_vectored(&bufs).unwrap(), 2);
+        assert_eq!(c.into_inner(), b"12");
+    }
+
     #[test]
     fn read_until() {
         let mut buf = Cursor::new(&b"12"[..]);
@@ -10,7 +10,13 @@
 
 #![allow(missing_copy_implementations)]
 
+use fs::File;
 use io::{self, Read, Write, ErrorKind, BufRead};
+use net::TcpStream;
+use process::{ChildStderr, ChildStdin, ChildStdout};
+use sys::kernel_copy::{self, CopyResult, Endpoint};
+use sys_common::AsInner;
+use vec::Vec;
 
 /// Copies the entire contents of a reader into a writer.
 ///
@@ -46,10 +52,16 @@ use io::{self, Read, Write, ErrorKind, BufRead};
 pub fn copy<R: ?Sized, W: ?Sized>(reader: &mut R, writer: &mut W) -> io::Result<u64>
     where R: Read, W: Write
 {
-    let mut buf = [0; super::DEFAULT_BUF_SIZE];
+    reader.spec_copy(writer)
+}
+
+fn copy_with_buf<R: ?Sized, W: ?Sized>(reader: &mut R, writer: &mut W, buf: &mut [u8])
+                                       -> io::Result<u64>
+    where R: Read, W: Write
+{
     let mut written = 0;
     loop {
-        let len = match reader.read(&mut buf) {
+        let len = match reader.read(buf) {
             Ok(0) => return Ok(written),
             Ok(len) => len,
             Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
@@ -60,6 +72,62 @@ pub fn copy<R: ?Sized, W: ?Sized>(reader: &mut R, writer: &mut W) -> io::Result<
     }
 }
 
+// Used when the endpoints are files, sockets or pipes, but the kernel can't
+// do the copy for us. These are usually read in large chunks, so a bigger
+// buffer than the default pays off; it lives on the heap to keep `copy`'s
+// stack frame small.
+const KERNEL_BUF_SIZE: usize = 128 * 1024;
+
+/// Readers and writers backed by a descriptor the kernel can copy from or to.
+trait CopyEndpoint {
+    fn endpoint(&self) -> Endpoint;
+}
+
+impl CopyEndpoint for File {
+    fn endpoint(&self) -> Endpoint { Endpoint::File(self.as_inner()) }
+}
+
+impl CopyEndpoint for TcpStream {
+    fn endpoint(&self) -> Endpoint { Endpoint::Socket(self.as_inner().socket()) }
+}
+
+impl CopyEndpoint for ChildStdin {
+    fn endpoint(&self) -> Endpoint { Endpoint::Pipe(self.as_inner()) }
+}
+
+impl CopyEndpoint for ChildStdout {
+    fn endpoint(&self) -> Endpoint { Endpoint::Pipe(self.as_inner()) }
+}
+
+impl CopyEndpoint for ChildStderr {
+    fn endpoint(&self) -> Endpoint { Endpoint::Pipe(self.as_inner()) }
+}
+
+/// Specializes `copy` for readers and writers between which the platform
+/// can move data without going through a userspace buffer.
+trait SpecCopy<W: ?Sized> {
+    fn spec_copy(&mut self, writer: &mut W) -> io::Result<u64>;
+}
+
+impl<R: Read + ?Sized, W: Write + ?Sized> SpecCopy<W> for R {
+    default fn spec_copy(&mut self, writer: &mut W) -> io::Result<u64> {
+        let mut buf = [0; super::DEFAULT_BUF_SIZE];
+        copy_with_buf(self, writer, &mut buf)
+    }
+}
+
+impl<R: Read + CopyEndpoint, W: Write + CopyEndpoint> SpecCopy<W> for R {
+    fn spec_copy(&mut self, writer: &mut W) -> io::Result<u64> {
+        match kernel_copy::copy(self.endpoint(), writer.endpoint()) {
+            CopyResult::Ended(ret) => ret,
+            CopyResult::Fallback(written) => {
+                let mut buf = vec![0; KERNEL_BUF_SIZE];
+                copy_with_buf(self, writer, &mut buf).map(|n| n + written)
+            }
+        }
+    }
+}
+
 /// A reader which is always at EOF.
 ///
 /// This struct is generally created by calling [`empty()`][empty]. Please see
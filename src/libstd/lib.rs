@@ -222,6 +222,7 @@
 #![feature(cfg_target_vendor)]
 #![feature(char_escape_debug)]
 #![feature(char_internals)]
+#![cfg_attr(test, feature(clamp))]
 #![feature(collections)]
 #![feature(collections_bound)]
 #![feature(const_fn)]
@@ -236,6 +237,7 @@
 #![feature(heap_api)]
 #![feature(inclusive_range)]
 #![feature(int_error_internals)]
+#![feature(integer_atomics)]
 #![feature(into_cow)]
 #![feature(lang_items)]
 #![feature(libc)]
@@ -262,6 +264,7 @@
 #![feature(slice_bytes)]
 #![feature(slice_concat_ext)]
 #![feature(slice_patterns)]
+#![feature(specialization)]
 #![feature(staged_api)]
 #![feature(stmt_expr_attributes)]
 #![feature(str_char)]
@@ -269,12 +272,12 @@
 #![feature(str_utf16)]
 #![feature(test, rustc_private)]
 #![feature(thread_local)]
+#![cfg_attr(test, feature(total_cmp))]
 #![feature(try_borrow)]
 #![feature(try_from)]
 #![feature(unboxed_closures)]
 #![feature(unicode)]
 #![feature(unique)]
-#![feature(unsafe_no_drop_flag, filling_drop)]
 #![feature(unwind_attributes)]
 #![feature(vec_push_all)]
 #![feature(zero_one)]
@@ -284,6 +287,9 @@
 // might be unavailable or disabled
 #![cfg_attr(stage0, feature(alloc_system))]
 
+// `core::arch` is unknown to stage0
+#![cfg_attr(not(stage0), feature(stdsimd))]
+
 // Don't link to std. We are std.
 #![no_std]
 
@@ -322,6 +328,9 @@ extern crate alloc_system;
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::any;
+#[cfg(not(stage0))]
+#[unstable(feature = "stdsimd", issue = "0")]
+pub use core::arch;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::cell;
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -334,6 +343,8 @@ pub use core::convert;
 pub use core::default;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::hash;
+#[unstable(feature = "bench_black_box", issue = "0")]
+pub use core::hint;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::intrinsics;
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -350,6 +361,8 @@ pub use core::ptr;
 pub use core::raw;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::result;
+#[unstable(feature = "portable_simd", issue = "0")]
+pub use core::simd;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::option;
 
@@ -451,11 +464,20 @@ mod memchr;
 #[path = "sys/unix/mod.rs"] mod sys;
 #[cfg(windows)]
 #[path = "sys/windows/mod.rs"] mod sys;
+#[cfg(target_arch = "wasm32")]
+#[path = "sys/wasm/mod.rs"] mod sys;
 
 pub mod rt;
 mod panicking;
 mod rand;
 
+// Support for `is_x86_feature_detected!`, which has to be able to reach it
+// from other crates.
+#[cfg(all(not(stage0), any(target_arch = "x86", target_arch = "x86_64")))]
+#[doc(hidden)]
+#[unstable(feature = "stdsimd", issue = "0")]
+pub mod detect;
+
 // Some external utilities of the standard library rely on randomness (aka
 // rustc_back::TempDir and tests) and need a way to get at the OS rng we've got
 // here. This module is not at all intended for stabilization as-is, however,
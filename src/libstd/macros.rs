@@ -128,6 +128,10 @@ macro_rules! println {
 /// receivers. It places no restrictions on the types of receivers given to
 /// this macro, this can be viewed as a heterogeneous select.
 ///
+/// The `std::sync::mpsc::ReceiverSet` type is intended to replace this macro.
+/// It supports the same heterogeneous selection without `unsafe` code, as
+/// well as timeouts and sets of receivers built at runtime.
+///
 /// # Examples
 ///
 /// ```
@@ -175,6 +179,84 @@ macro_rules! select {
     })
 }
 
+/// Tests at runtime whether the CPU supports an x86 feature.
+///
+/// This allows code compiled for a baseline target to use a faster
+/// implementation of a function when the CPU it runs on supports it. The
+/// specialized function is compiled with `#[target_feature(enable = "...")]`,
+/// and it is only safe to call once this macro has returned `true` for each
+/// of its features.
+///
+/// The feature names are the ones accepted by `#[target_feature]`: `"sse"`,
+/// `"sse2"`, `"sse3"`, `"ssse3"`, `"sse4.1"`, `"sse4.2"`, `"popcnt"`,
+/// `"avx"`, `"avx2"`, `"fma"`, `"bmi1"`, `"bmi2"` and `"lzcnt"`. Features
+/// enabled at compile time with `-C target-feature` are always reported as
+/// present; the others are looked up with `cpuid` once, and cached.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(stdsimd, target_feature)]
+///
+/// # #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+/// fn sum(xs: &[u32]) -> u32 {
+///     if is_x86_feature_detected!("avx2") {
+///         // Safe because the CPU was just checked for AVX2 support.
+///         unsafe { sum_avx2(xs) }
+///     } else {
+///         sum_fallback(xs)
+///     }
+/// }
+///
+/// // LLVM can vectorize the loop with AVX2 instructions in this copy.
+/// # #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+/// #[target_feature(enable = "avx2")]
+/// unsafe fn sum_avx2(xs: &[u32]) -> u32 {
+///     sum_fallback(xs)
+/// }
+///
+/// fn sum_fallback(xs: &[u32]) -> u32 {
+///     xs.iter().fold(0, |a, &b| a.wrapping_add(b))
+/// }
+///
+/// # #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+/// # fn main() {
+/// assert_eq!(sum(&[1, 2, 3, 4]), 10);
+/// # }
+/// # #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+/// # fn main() {}
+/// ```
+#[cfg(all(not(stage0), any(target_arch = "x86", target_arch = "x86_64")))]
+#[macro_export]
+#[unstable(feature = "stdsimd", issue = "0")]
+#[allow_internal_unstable]
+macro_rules! is_x86_feature_detected {
+    ("sse") => (__is_x86_feature_detected!("sse", Sse));
+    ("sse2") => (__is_x86_feature_detected!("sse2", Sse2));
+    ("sse3") => (__is_x86_feature_detected!("sse3", Sse3));
+    ("ssse3") => (__is_x86_feature_detected!("ssse3", Ssse3));
+    ("sse4.1") => (__is_x86_feature_detected!("sse4.1", Sse41));
+    ("sse4.2") => (__is_x86_feature_detected!("sse4.2", Sse42));
+    ("popcnt") => (__is_x86_feature_detected!("popcnt", Popcnt));
+    ("avx") => (__is_x86_feature_detected!("avx", Avx));
+    ("avx2") => (__is_x86_feature_detected!("avx2", Avx2));
+    ("fma") => (__is_x86_feature_detected!("fma", Fma));
+    ("bmi1") => (__is_x86_feature_detected!("bmi1", Bmi1));
+    ("bmi2") => (__is_x86_feature_detected!("bmi2", Bmi2));
+    ("lzcnt") => (__is_x86_feature_detected!("lzcnt", Lzcnt));
+}
+
+#[cfg(all(not(stage0), any(target_arch = "x86", target_arch = "x86_64")))]
+#[doc(hidden)]
+#[macro_export]
+#[allow_internal_unstable]
+macro_rules! __is_x86_feature_detected {
+    ($name:tt, $feature:ident) => (
+        cfg!(target_feature = $name) ||
+            $crate::detect::check_for($crate::detect::Feature::$feature)
+    )
+}
+
 #[cfg(test)]
 macro_rules! assert_approx_eq {
     ($a:expr, $b:expr) => ({
@@ -459,3 +541,9 @@ pub mod builtin {
     #[macro_export]
     macro_rules! include { ($file:expr) => ({ /* compiler built-in */ }) }
 }
+
+// The bootstrap compiler still calls `llvm_asm!` by its old name.
+#[cfg(stage0)]
+macro_rules! llvm_asm {
+    ($($t:tt)*) => (asm!($($t)*))
+}
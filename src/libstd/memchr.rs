@@ -34,7 +34,7 @@
 /// ```
 pub fn memchr(needle: u8, haystack: &[u8]) -> Option<usize> {
     // libc memchr
-    #[cfg(not(target_os = "windows"))]
+    #[cfg(not(any(target_os = "windows", target_arch = "wasm32")))]
     fn memchr_specific(needle: u8, haystack: &[u8]) -> Option<usize> {
         use libc;
 
@@ -51,8 +51,9 @@ pub fn memchr(needle: u8, haystack: &[u8]) -> Option<usize> {
         }
     }
 
-    // use fallback on windows, since it's faster
-    #[cfg(target_os = "windows")]
+    // use fallback on windows, since it's faster, and on wasm, which has no
+    // libc at all
+    #[cfg(any(target_os = "windows", target_arch = "wasm32"))]
     fn memchr_specific(needle: u8, haystack: &[u8]) -> Option<usize> {
         fallback::memchr(needle, haystack)
     }
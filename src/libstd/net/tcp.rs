@@ -12,7 +12,7 @@ use prelude::v1::*;
 use io::prelude::*;
 
 use fmt;
-use io;
+use io::{self, IoSlice, IoSliceMut};
 use net::{ToSocketAddrs, SocketAddr, Shutdown};
 use sys_common::net as net_imp;
 use sys_common::{AsInner, FromInner, IntoInner};
@@ -98,6 +98,33 @@ impl TcpStream {
         super::each_addr(addr, net_imp::TcpStream::connect).map(TcpStream)
     }
 
+    /// Opens a TCP connection to a remote host with a timeout.
+    ///
+    /// Unlike `connect`, `connect_timeout` takes a single `SocketAddr` since
+    /// the timeout must be applied to an individual address rather than to
+    /// the whole set of addresses a hostname may resolve to.
+    ///
+    /// If the connection has not been established within `timeout`, an error
+    /// of kind `TimedOut` is returned. It is an error to pass a zero
+    /// `Duration` to this function.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(tcpstream_connect_timeout)]
+    ///
+    /// use std::net::{SocketAddr, TcpStream};
+    /// use std::time::Duration;
+    ///
+    /// let addr: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+    /// let stream = TcpStream::connect_timeout(&addr, Duration::from_secs(1))
+    ///                        .expect("couldn't connect to the server");
+    /// ```
+    #[unstable(feature = "tcpstream_connect_timeout", issue = "0")]
+    pub fn connect_timeout(addr: &SocketAddr, timeout: Duration) -> io::Result<TcpStream> {
+        net_imp::TcpStream::connect_timeout(addr, timeout).map(TcpStream)
+    }
+
     /// Returns the socket address of the remote peer of this TCP connection.
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn peer_addr(&self) -> io::Result<SocketAddr> {
@@ -110,6 +137,29 @@ impl TcpStream {
         self.0.socket_addr()
     }
 
+    /// Receives data on the socket from the remote address to which it is
+    /// connected, without removing that data from the queue. On success,
+    /// returns the number of bytes peeked.
+    ///
+    /// Successive calls return the same data. This is accomplished by passing
+    /// `MSG_PEEK` as a flag to the underlying `recv` system call.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(peek)]
+    /// use std::net::TcpStream;
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:8000")
+    ///                        .expect("couldn't bind to address");
+    /// let mut buf = [0; 10];
+    /// let len = stream.peek(&mut buf).expect("peek failed");
+    /// ```
+    #[unstable(feature = "peek", issue = "0")]
+    pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.peek(buf)
+    }
+
     /// Shuts down the read, write, or both halves of this connection.
     ///
     /// This function will cause all pending and future I/O on the specified
@@ -251,6 +301,9 @@ impl TcpStream {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Read for TcpStream {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> { self.0.read(buf) }
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
         self.0.read_to_end(buf)
     }
@@ -258,11 +311,17 @@ impl Read for TcpStream {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Write for TcpStream {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> { self.0.write(buf) }
+    fn write_vectored(&mut self, bufs: &[IoSlice]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
     fn flush(&mut self) -> io::Result<()> { Ok(()) }
 }
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a> Read for &'a TcpStream {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> { self.0.read(buf) }
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
         self.0.read_to_end(buf)
     }
@@ -270,6 +329,9 @@ impl<'a> Read for &'a TcpStream {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a> Write for &'a TcpStream {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> { self.0.write(buf) }
+    fn write_vectored(&mut self, bufs: &[IoSlice]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
     fn flush(&mut self) -> io::Result<()> { Ok(()) }
 }
 
@@ -440,7 +502,7 @@ impl fmt::Debug for TcpListener {
 mod tests {
     use prelude::v1::*;
 
-    use io::ErrorKind;
+    use io::{ErrorKind, IoSlice};
     use io::prelude::*;
     use net::*;
     use net::test::{next_test_ip4, next_test_ip6};
@@ -484,6 +546,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn connect_timeout_unroutable() {
+        // this IP is unroutable, so connections should always time out,
+        // provided the network is reachable to begin with.
+        let addr = "10.255.255.1:80".parse().unwrap();
+        let e = TcpStream::connect_timeout(&addr, Duration::from_millis(250)).unwrap_err();
+        assert!(e.kind() == ErrorKind::TimedOut ||
+                e.kind() == ErrorKind::Other,
+                "bad error: {} {:?}", e, e.kind());
+    }
+
+    #[test]
+    fn connect_timeout_refused() {
+        let addr = next_test_ip4();
+        // nothing is listening on this port
+        let e = TcpStream::connect_timeout(&addr, Duration::from_secs(5)).unwrap_err();
+        assert!(e.kind() == ErrorKind::ConnectionRefused ||
+                e.kind() == ErrorKind::Other,
+                "bad error: {} {:?}", e, e.kind());
+    }
+
+    #[test]
+    fn connect_timeout_valid() {
+        let listener = t!(TcpListener::bind("127.0.0.1:0"));
+        let addr = t!(listener.local_addr());
+        t!(TcpStream::connect_timeout(&addr, Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn connect_timeout_zero() {
+        let listener = t!(TcpListener::bind("127.0.0.1:0"));
+        let addr = t!(listener.local_addr());
+        let e = TcpStream::connect_timeout(&addr, Duration::new(0, 0)).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidInput);
+    }
+
     #[test]
     fn listen_localhost() {
         let socket_addr = next_test_ip4();
@@ -522,6 +620,29 @@ mod tests {
         })
     }
 
+    #[test]
+    fn read_write_vectored() {
+        each_ip(&mut |addr| {
+            let acceptor = t!(TcpListener::bind(&addr));
+
+            let _t = thread::spawn(move|| {
+                let mut stream = t!(TcpStream::connect(&addr));
+                let bufs = [IoSlice::new(&[1, 2]), IoSlice::new(&[3])];
+                let mut written = t!(stream.write_vectored(&bufs));
+                if written < 3 {
+                    t!(stream.write_all(&[1, 2, 3][written..]));
+                    written = 3;
+                }
+                assert_eq!(written, 3);
+            });
+
+            let mut stream = t!(acceptor.accept()).0;
+            let mut buf = Vec::new();
+            t!(stream.read_to_end(&mut buf));
+            assert_eq!(buf, [1, 2, 3]);
+        })
+    }
+
     #[test]
     fn smoke_test() {
         each_ip(&mut |addr| {
@@ -1127,6 +1248,39 @@ mod tests {
         assert_eq!(ttl, t!(stream.ttl()));
     }
 
+    #[test]
+    fn peek() {
+        each_ip(&mut |addr| {
+            let (txdone, rxdone) = channel();
+
+            let srv = t!(TcpListener::bind(&addr));
+            let _t = thread::spawn(move|| {
+                let mut cl = t!(srv.accept()).0;
+                cl.write(&[1,3,3,7]).unwrap();
+                t!(rxdone.recv());
+            });
+
+            let mut c = t!(TcpStream::connect(&addr));
+            let mut b = [0; 10];
+            for _ in 1..3 {
+                let len = c.peek(&mut b).unwrap();
+                assert_eq!(len, 4);
+                assert_eq!(&b[..4], &[1, 3, 3, 7]);
+            }
+            let len = c.read(&mut b).unwrap();
+            assert_eq!(len, 4);
+            assert_eq!(&b[..4], &[1, 3, 3, 7]);
+
+            t!(c.set_nonblocking(true));
+            match c.peek(&mut b) {
+                Ok(_) => panic!("expected error"),
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock => {}
+                Err(e) => panic!("unexpected error {}", e),
+            }
+            t!(txdone.send(()));
+        })
+    }
+
     #[test]
     fn set_nonblocking() {
         let addr = next_test_ip4();
@@ -62,6 +62,29 @@ impl UdpSocket {
         self.0.recv_from(buf)
     }
 
+    /// Receives data from the socket without removing it from the queue. On
+    /// success, returns the number of bytes read and the address from whence
+    /// the data came.
+    ///
+    /// Successive calls return the same data. This is accomplished by passing
+    /// `MSG_PEEK` as a flag to the underlying `recvfrom` system call.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(peek)]
+    /// use std::net::UdpSocket;
+    ///
+    /// let socket = UdpSocket::bind("127.0.0.1:34254").expect("couldn't bind to address");
+    /// let mut buf = [0; 10];
+    /// let (number_of_bytes, src_addr) = socket.peek_from(&mut buf)
+    ///                                         .expect("didn't receive data");
+    /// ```
+    #[unstable(feature = "peek", issue = "0")]
+    pub fn peek_from(&self, buf: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+        self.0.peek_from(buf)
+    }
+
     /// Sends data on the socket to the given address. On success, returns the
     /// number of bytes written.
     ///
@@ -619,6 +642,66 @@ mod tests {
         assert_eq!(ttl, t!(stream.ttl()));
     }
 
+    #[test]
+    fn broadcast() {
+        let addr = next_test_ip4();
+
+        let socket = t!(UdpSocket::bind(&addr));
+
+        t!(socket.set_broadcast(true));
+        assert_eq!(true, t!(socket.broadcast()));
+        t!(socket.set_broadcast(false));
+        assert_eq!(false, t!(socket.broadcast()));
+    }
+
+    #[test]
+    fn multicast_v4_options() {
+        let addr = next_test_ip4();
+
+        let socket = t!(UdpSocket::bind(&addr));
+
+        t!(socket.set_multicast_loop_v4(false));
+        assert_eq!(false, t!(socket.multicast_loop_v4()));
+        t!(socket.set_multicast_loop_v4(true));
+        assert_eq!(true, t!(socket.multicast_loop_v4()));
+
+        t!(socket.set_multicast_ttl_v4(42));
+        assert_eq!(42, t!(socket.multicast_ttl_v4()));
+    }
+
+    #[test]
+    fn multicast_v6_options() {
+        let addr = next_test_ip6();
+
+        let socket = t!(UdpSocket::bind(&addr));
+
+        t!(socket.set_multicast_loop_v6(false));
+        assert_eq!(false, t!(socket.multicast_loop_v6()));
+        t!(socket.set_multicast_loop_v6(true));
+        assert_eq!(true, t!(socket.multicast_loop_v6()));
+    }
+
+    #[test]
+    fn peek_from() {
+        each_ip(&mut |server_ip, client_ip| {
+            let server = t!(UdpSocket::bind(&server_ip));
+            let client = t!(UdpSocket::bind(&client_ip));
+            t!(client.send_to(&[1, 3, 3, 7], &server_ip));
+
+            let mut buf = [0; 10];
+            for _ in 1..3 {
+                let (len, src) = t!(server.peek_from(&mut buf));
+                assert_eq!(len, 4);
+                assert_eq!(&buf[..4], &[1, 3, 3, 7]);
+                assert_eq!(src, client_ip);
+            }
+            let (len, src) = t!(server.recv_from(&mut buf));
+            assert_eq!(len, 4);
+            assert_eq!(&buf[..4], &[1, 3, 3, 7]);
+            assert_eq!(src, client_ip);
+        })
+    }
+
     #[test]
     fn set_nonblocking() {
         let addr = next_test_ip4();
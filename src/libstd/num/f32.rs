@@ -15,12 +15,16 @@
 #![stable(feature = "rust1", since = "1.0.0")]
 #![allow(missing_docs)]
 
+#[cfg(not(test))]
+use cmp::Ordering;
 #[cfg(not(test))]
 use core::num;
 #[cfg(not(test))]
 use intrinsics;
 #[cfg(not(test))]
-use libc::c_int;
+use os::raw::c_int;
+#[cfg(not(test))]
+use mem;
 #[cfg(not(test))]
 use num::FpCategory;
 
@@ -38,7 +42,7 @@ pub use core::f32::consts;
 
 #[allow(dead_code)]
 mod cmath {
-    use libc::{c_float, c_int};
+    use os::raw::{c_float, c_int};
 
     extern {
         pub fn cbrtf(n: c_float) -> c_float;
@@ -82,7 +86,7 @@ mod cmath {
     pub use self::shims::*;
     #[cfg(target_env = "msvc")]
     mod shims {
-        use libc::{c_float, c_int};
+        use os::raw::{c_float, c_int};
 
         #[inline]
         pub unsafe fn acosf(n: c_float) -> c_float {
@@ -825,6 +829,97 @@ impl f32 {
         unsafe { cmath::fminf(self, other) }
     }
 
+    /// Restricts a value to a certain interval unless it is NaN.
+    ///
+    /// Returns `max` if `self` is greater than `max`, and `min` if `self` is
+    /// less than `min`. Otherwise this returns `self`.
+    ///
+    /// Note that this function returns NaN if the initial value was NaN as
+    /// well, unlike `max` and `min` which would silently skip it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, `min` is NaN, or `max` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(clamp)]
+    /// use std::f32::NAN;
+    ///
+    /// assert!((-3.0f32).clamp(-2.0, 1.0) == -2.0);
+    /// assert!((0.0f32).clamp(-2.0, 1.0) == 0.0);
+    /// assert!((2.0f32).clamp(-2.0, 1.0) == 1.0);
+    /// assert!((NAN).clamp(-2.0, 1.0).is_nan());
+    /// ```
+    #[unstable(feature = "clamp", issue = "0")]
+    #[inline]
+    pub fn clamp(self, min: f32, max: f32) -> f32 {
+        assert!(min <= max, "min > max, or either was NaN");
+        let mut x = self;
+        if x < min {
+            x = min;
+        }
+        if x > max {
+            x = max;
+        }
+        x
+    }
+
+    /// Returns an ordering between `self` and `other`, which is the
+    /// `totalOrder` predicate of the IEEE 754 (2008 revision) standard.
+    ///
+    /// Unlike the `PartialOrd` implementation, this is a total order. The
+    /// values are ordered as:
+    ///
+    /// - negative quiet NaN
+    /// - negative signaling NaN
+    /// - negative infinity
+    /// - negative numbers
+    /// - negative subnormal numbers
+    /// - negative zero
+    /// - positive zero
+    /// - positive subnormal numbers
+    /// - positive numbers
+    /// - positive infinity
+    /// - positive signaling NaN
+    /// - positive quiet NaN
+    ///
+    /// Note that this doesn't always agree with the `PartialOrd` and
+    /// `PartialEq` implementations. In particular, they regard negative and
+    /// positive zero as equal, while `total_cmp` doesn't.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(total_cmp)]
+    /// use std::f32;
+    ///
+    /// let mut numbers = [2.5, -1.0, f32::INFINITY, 0.0, -0.0, f32::NEG_INFINITY];
+    /// numbers.sort_by(|a, b| a.total_cmp(b));
+    ///
+    /// assert_eq!(numbers[0], f32::NEG_INFINITY);
+    /// assert_eq!(numbers[1], -1.0);
+    /// assert!(numbers[2].is_sign_negative() && numbers[2] == 0.0);
+    /// assert!(numbers[3].is_sign_positive() && numbers[3] == 0.0);
+    /// assert_eq!(numbers[4], 2.5);
+    /// assert_eq!(numbers[5], f32::INFINITY);
+    /// ```
+    #[unstable(feature = "total_cmp", issue = "0")]
+    #[inline]
+    pub fn total_cmp(&self, other: &f32) -> Ordering {
+        let mut left = unsafe { mem::transmute::<f32, i32>(*self) };
+        let mut right = unsafe { mem::transmute::<f32, i32>(*other) };
+
+        // For negative numbers every bit except the sign is flipped, which
+        // makes the bit patterns of all values compare like two's
+        // complement integers in the order listed above.
+        left ^= (((left >> 31) as u32) >> 1) as i32;
+        right ^= (((right >> 31) as u32) >> 1) as i32;
+
+        left.cmp(&right)
+    }
+
     /// The positive difference of two numbers.
     ///
     /// * If `self <= other`: `0:0`
@@ -1250,6 +1345,54 @@ mod tests {
         assert_eq!(2.0f32.max(NAN), 2.0);
     }
 
+    #[test]
+    fn test_clamp() {
+        assert_eq!(1.0f32.clamp(-2.0, 3.0), 1.0);
+        assert_eq!((-5.0f32).clamp(-2.0, 3.0), -2.0);
+        assert_eq!(5.0f32.clamp(-2.0, 3.0), 3.0);
+        assert_eq!(INFINITY.clamp(-2.0, 3.0), 3.0);
+        assert_eq!(1.0f32.clamp(1.0, 1.0), 1.0);
+        assert!(NAN.clamp(-2.0, 3.0).is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_min_greater_than_max() {
+        1.0f32.clamp(3.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_min_is_nan() {
+        1.0f32.clamp(NAN, 1.0);
+    }
+
+    #[test]
+    fn test_total_cmp() {
+        use cmp::Ordering::*;
+        use mem;
+
+        let q_nan: f32 = unsafe { mem::transmute(0x7fc0_0000u32) };
+        let s_nan: f32 = unsafe { mem::transmute(0x7fa0_0000u32) };
+        // Negating a NaN isn't guaranteed to flip its sign bit, so spell out
+        // the negative ones too.
+        let neg_q_nan: f32 = unsafe { mem::transmute(0xffc0_0000u32) };
+        let neg_s_nan: f32 = unsafe { mem::transmute(0xffa0_0000u32) };
+        let min_sub: f32 = unsafe { mem::transmute(1u32) };
+
+        let ordered = [neg_q_nan, neg_s_nan, NEG_INFINITY, MIN, -1.0, -min_sub, -0.0,
+                       0.0, min_sub, 1.0, MAX, INFINITY, s_nan, q_nan];
+        for (i, a) in ordered.iter().enumerate() {
+            for (j, b) in ordered.iter().enumerate() {
+                assert_eq!(a.total_cmp(b), i.cmp(&j), "{:?} vs {:?}", a, b);
+            }
+        }
+
+        assert_eq!(1.0f32.total_cmp(&1.0), Equal);
+        assert_eq!((-0.0f32).total_cmp(&0.0), Less);
+        assert_eq!(NAN.total_cmp(&NAN), Equal);
+    }
+
     #[test]
     fn test_nan() {
         let nan: f32 = f32::NAN;
@@ -23,6 +23,10 @@ pub use core::num::{Zero, One};
 pub use core::num::{FpCategory, ParseIntError, ParseFloatError, TryFromIntError};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::num::Wrapping;
+#[unstable(feature = "nonzero_int", issue = "0")]
+pub use core::num::{NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroUsize};
+#[unstable(feature = "nonzero_int", issue = "0")]
+pub use core::num::{NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroIsize};
 
 #[cfg(test)] use cmp::PartialEq;
 #[cfg(test)] use fmt;
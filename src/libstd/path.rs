@@ -111,6 +111,7 @@ use iter;
 use mem;
 use ops::{self, Deref};
 use string::String;
+use sys;
 use vec::Vec;
 
 use ffi::{OsStr, OsString};
@@ -134,7 +135,7 @@ use self::platform::{is_sep_byte, is_verbatim_sep, MAIN_SEP_STR, parse_prefix};
 // The following modules give the most basic tools for parsing paths on various
 // platforms. The bulk of the code is devoted to parsing prefixes on Windows.
 
-#[cfg(unix)]
+#[cfg(any(unix, target_arch = "wasm32"))]
 mod platform {
     use super::Prefix;
     use ffi::OsStr;
@@ -448,6 +449,23 @@ fn split_file_at_dot(file: &OsStr) -> (Option<&OsStr>, Option<&OsStr>) {
     }
 }
 
+// splits a file name at its first dot, ignoring a leading dot
+fn split_file_at_first_dot(file: &OsStr) -> (&OsStr, Option<&OsStr>) {
+    let slice = os_str_as_u8_slice(file);
+    if slice == b".." {
+        return (file, None);
+    }
+
+    // Same reasoning as in `split_file_at_dot` for the unsafety.
+    let i = match slice.iter().skip(1).position(|b| *b == b'.') {
+        Some(i) => i + 1,
+        None => return (file, None),
+    };
+    unsafe {
+        (u8_slice_as_os_str(&slice[..i]), Some(u8_slice_as_os_str(&slice[i + 1..])))
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // The core iterators
 ////////////////////////////////////////////////////////////////////////////////
@@ -1653,6 +1671,33 @@ impl Path {
         self.file_name().map(split_file_at_dot).and_then(|(before, after)| before.or(after))
     }
 
+    /// Extracts the prefix of `self.file_name()`.
+    ///
+    /// The prefix is:
+    ///
+    /// * None, if there is no file name;
+    /// * The entire file name if there is no embedded `.`;
+    /// * The entire file name if the file name begins with `.` and has no other `.`s within;
+    /// * Otherwise, the portion of the file name before the first non-beginning `.`
+    ///
+    /// This differs from `file_stem`, which splits at the *last* `.`, so that
+    /// `"foo.tar.gz"` has the stem `"foo.tar"` but the prefix `"foo"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(path_file_prefix)]
+    ///
+    /// use std::path::Path;
+    ///
+    /// assert_eq!("foo", Path::new("foo.rs").file_prefix().unwrap());
+    /// assert_eq!("foo", Path::new("foo.tar.gz").file_prefix().unwrap());
+    /// ```
+    #[unstable(feature = "path_file_prefix", issue = "0")]
+    pub fn file_prefix(&self) -> Option<&OsStr> {
+        self.file_name().map(split_file_at_first_dot).map(|(before, _)| before)
+    }
+
     /// Extracts the extension of `self.file_name()`, if possible.
     ///
     /// The extension is:
@@ -2143,6 +2188,49 @@ impl Error for StripPrefixError {
     fn description(&self) -> &str { "prefix not found" }
 }
 
+/// Makes a path absolute without accessing the filesystem.
+///
+/// A relative path is resolved against the current working directory. On
+/// Unix, `.` components are then dropped and each `..` removes the component
+/// before it, purely lexically. On Windows this is done by
+/// `GetFullPathNameW`, which follows the same rules and also handles
+/// drive-relative paths such as `C:foo`.
+///
+/// Unlike `fs::canonicalize`, the path doesn't need to exist and symbolic
+/// links are not resolved. This also means that if `a/b` is a symbolic link,
+/// `a/b/..` is turned into `a`, which may not be the same file.
+///
+/// # Errors
+///
+/// This function returns an error if `path` is empty or if the current
+/// working directory can't be determined.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(path_absolute)]
+///
+/// use std::path::{self, Path};
+///
+/// # fn foo() -> std::io::Result<()> {
+/// let path = try!(path::absolute("foo/./bar/../baz"));
+/// assert!(path.is_absolute());
+/// # if cfg!(unix) {
+/// assert_eq!(path::absolute("/foo/./bar/../baz").unwrap(), Path::new("/foo/baz"));
+/// # }
+/// # Ok(())
+/// # }
+/// ```
+#[unstable(feature = "path_absolute", issue = "0")]
+pub fn absolute<P: AsRef<Path>>(path: P) -> io::Result<PathBuf> {
+    let path = path.as_ref();
+    if path.as_os_str().is_empty() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                  "cannot make an empty path absolute"));
+    }
+    sys::fs::absolute(path)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3116,6 +3204,53 @@ mod tests {
            );
     }
 
+    #[test]
+    pub fn test_file_prefix() {
+        macro_rules! tfp(
+            ($path:expr, $expected:expr) => ( {
+                let actual = Path::new($path).file_prefix().map(|p| p.to_str().unwrap());
+                let expected: Option<&str> = $expected;
+                assert!(actual == expected, "file_prefix of {:?}: Expected {:?}, found {:?}",
+                        $path, expected, actual);
+            });
+        );
+
+        tfp!("foo", Some("foo"));
+        tfp!("foo.", Some("foo"));
+        tfp!(".foo", Some(".foo"));
+        tfp!(".foo.rs", Some(".foo"));
+        tfp!("foo.rs", Some("foo"));
+        tfp!("foo.tar.gz", Some("foo"));
+        tfp!("dir/foo.tar.gz", Some("foo"));
+        tfp!("foo..rs", Some("foo"));
+        tfp!(".", None);
+        tfp!("..", None);
+        tfp!("", None);
+    }
+
+    #[test]
+    pub fn test_absolute() {
+        use env;
+        use super::absolute;
+
+        let cwd = env::current_dir().unwrap();
+        assert_eq!(absolute("foo").unwrap(), cwd.join("foo"));
+        assert_eq!(absolute("./foo/../bar/.").unwrap(), cwd.join("bar"));
+        assert!(absolute("").is_err());
+
+        if cfg!(unix) {
+            assert_eq!(absolute("/").unwrap(), Path::new("/"));
+            assert_eq!(absolute("/..").unwrap(), Path::new("/"));
+            assert_eq!(absolute("/foo//bar/./baz/..").unwrap(), Path::new("/foo/bar"));
+            assert_eq!(absolute("/foo/bar/../../..").unwrap(), Path::new("/"));
+        } else {
+            assert_eq!(absolute(r"C:\foo\.\bar\..\baz").unwrap(), Path::new(r"C:\foo\baz"));
+            assert_eq!(absolute(r"C:\..").unwrap(), Path::new(r"C:\"));
+            assert_eq!(absolute(r"\\server\share\a\..\b").unwrap(),
+                       Path::new(r"\\server\share\b"));
+        }
+    }
+
     #[test]
     pub fn test_push() {
         macro_rules! tp(
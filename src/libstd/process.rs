@@ -23,6 +23,7 @@ use str;
 use sys::pipe::{read2, AnonPipe};
 use sys::process as imp;
 use sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
+use time::Duration;
 
 /// Representation of a running or exited child process.
 ///
@@ -320,6 +321,41 @@ impl Command {
         self
     }
 
+    /// Adds or updates multiple environment variable mappings.
+    ///
+    /// This is equivalent to calling `env` once for each key/value pair
+    /// yielded by `vars`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// #![feature(command_envs)]
+    ///
+    /// use std::process::Command;
+    /// use std::env;
+    ///
+    /// let filtered_env = env::vars().filter(|&(ref k, _)| {
+    ///     k == "TERM" || k == "TZ" || k == "LANG" || k == "PATH"
+    /// });
+    ///
+    /// Command::new("printenv")
+    ///         .env_clear()
+    ///         .envs(filtered_env)
+    ///         .spawn()
+    ///         .expect("printenv failed to start");
+    /// ```
+    #[unstable(feature = "command_envs", issue = "0")]
+    pub fn envs<I, K, V>(&mut self, vars: I) -> &mut Command
+        where I: IntoIterator<Item=(K, V)>, K: AsRef<OsStr>, V: AsRef<OsStr>
+    {
+        for (ref key, ref val) in vars {
+            self.inner.env(key.as_ref(), val.as_ref());
+        }
+        self
+    }
+
     /// Removes an environment variable mapping.
     ///
     /// # Examples
@@ -729,6 +765,70 @@ impl Child {
         self.handle.wait().map(ExitStatus)
     }
 
+    /// Attempts to collect the exit status of the child if it has already
+    /// exited.
+    ///
+    /// This function will not block the calling thread. If the child has
+    /// exited then `Ok(Some(status))` is returned, and on Unix the process is
+    /// reaped; once the child has exited this function keeps returning the
+    /// same status. If the child is still running then `Ok(None)` is returned.
+    ///
+    /// Note that unlike `wait`, this function will not close the stdin handle
+    /// of the child.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// #![feature(process_try_wait)]
+    ///
+    /// use std::process::Command;
+    ///
+    /// let mut child = Command::new("ls").spawn().unwrap();
+    ///
+    /// match child.try_wait() {
+    ///     Ok(Some(status)) => println!("exited with: {}", status),
+    ///     Ok(None) => println!("child is still running"),
+    ///     Err(e) => println!("error attempting to wait: {}", e),
+    /// }
+    /// ```
+    #[unstable(feature = "process_try_wait", issue = "0")]
+    pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
+        Ok(self.handle.try_wait()?.map(ExitStatus))
+    }
+
+    /// Waits at most `dur` for the child to exit.
+    ///
+    /// Returns `Ok(Some(status))` if the child exited within the timeout and
+    /// `Ok(None)` if it is still running once the timeout has elapsed. As
+    /// with `try_wait`, the stdin handle of the child is left open.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```no_run
+    /// #![feature(process_try_wait)]
+    ///
+    /// use std::process::Command;
+    /// use std::time::Duration;
+    ///
+    /// let mut child = Command::new("sleep").arg("10").spawn().unwrap();
+    ///
+    /// match child.wait_timeout(Duration::from_secs(1)).unwrap() {
+    ///     Some(status) => println!("exited with: {}", status),
+    ///     None => {
+    ///         child.kill().unwrap();
+    ///         child.wait().unwrap();
+    ///     }
+    /// }
+    /// ```
+    #[unstable(feature = "process_try_wait", issue = "0")]
+    pub fn wait_timeout(&mut self, dur: Duration) -> io::Result<Option<ExitStatus>> {
+        Ok(self.handle.wait_timeout(dur)?.map(ExitStatus))
+    }
+
     /// Simultaneously waits for the child to exit and collect all remaining
     /// output on the stdout/stderr handles, returning an `Output`
     /// instance.
@@ -813,7 +913,7 @@ mod tests {
     use prelude::v1::*;
     use io::prelude::*;
 
-    use io::ErrorKind;
+    use io::{self, ErrorKind};
     use str;
     use super::{Command, Output, Stdio};
 
@@ -910,6 +1010,27 @@ mod tests {
         assert_eq!(out, "foobar\n");
     }
 
+    #[test]
+    #[cfg_attr(any(windows, target_os = "android"), ignore)]
+    fn io_copy_between_pipes() {
+        let mut source = Command::new("echo").arg("foobar")
+                                 .stdout(Stdio::piped())
+                                 .spawn().unwrap();
+        let mut sink = Command::new("cat")
+                               .stdin(Stdio::piped())
+                               .stdout(Stdio::piped())
+                               .spawn().unwrap();
+        let copied = io::copy(source.stdout.as_mut().unwrap(),
+                              sink.stdin.as_mut().unwrap()).unwrap();
+        assert_eq!(copied, 7);
+        drop(sink.stdin.take());
+        let mut out = String::new();
+        sink.stdout.as_mut().unwrap().read_to_string(&mut out).unwrap();
+        assert!(source.wait().unwrap().success());
+        assert!(sink.wait().unwrap().success());
+        assert_eq!(out, "foobar\n");
+    }
+
 
     #[test]
     #[cfg_attr(target_os = "android", ignore)]
@@ -938,6 +1059,25 @@ mod tests {
         assert!(Command::new("/bin/ls").uid(0).gid(0).spawn().is_err());
     }
 
+    #[test]
+    #[cfg_attr(any(windows, target_os = "android"), ignore)]
+    fn test_try_wait() {
+        use time::Duration;
+
+        let mut p = Command::new("/bin/sh")
+                            .arg("-c").arg("read a")
+                            .stdin(Stdio::piped())
+                            .spawn().unwrap();
+        assert!(p.try_wait().unwrap().is_none());
+        assert!(p.wait_timeout(Duration::from_millis(50)).unwrap().is_none());
+
+        drop(p.stdin.take());
+        let status = p.wait_timeout(Duration::from_secs(60)).unwrap().unwrap();
+        assert!(status.success());
+        assert_eq!(p.try_wait().unwrap(), Some(status));
+        assert_eq!(p.wait().unwrap(), status);
+    }
+
     #[test]
     #[cfg_attr(target_os = "android", ignore)]
     fn test_process_status() {
@@ -1079,6 +1219,18 @@ mod tests {
                 "didn't find RUN_TEST_NEW_ENV inside of:\n\n{}", output);
     }
 
+    #[test]
+    fn test_add_multiple_to_env() {
+        let vars = vec![("RUN_TEST_NEW_ENV1", "123"), ("RUN_TEST_NEW_ENV2", "456")];
+        let result = env_cmd().envs(vars).output().unwrap();
+        let output = String::from_utf8_lossy(&result.stdout).to_string();
+
+        assert!(output.contains("RUN_TEST_NEW_ENV1=123"),
+                "didn't find RUN_TEST_NEW_ENV1 inside of:\n\n{}", output);
+        assert!(output.contains("RUN_TEST_NEW_ENV2=456"),
+                "didn't find RUN_TEST_NEW_ENV2 inside of:\n\n{}", output);
+    }
+
     // Regression tests for #30858.
     #[test]
     fn test_interior_nul_in_progname_is_error() {
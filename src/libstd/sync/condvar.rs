@@ -15,7 +15,7 @@ use sync::{mutex, MutexGuard, PoisonError};
 use sys_common::condvar as sys;
 use sys_common::mutex as sys_mutex;
 use sys_common::poison::{self, LockResult};
-use time::Duration;
+use time::{Duration, Instant};
 
 /// A type indicating whether a timed wait on a condition variable returned
 /// due to a time out or not.
@@ -130,6 +130,107 @@ impl Condvar {
         }
     }
 
+    /// Blocks the current thread until `condition` returns `true` for the data
+    /// protected by the mutex.
+    ///
+    /// The condition is checked before waiting for the first time, and then
+    /// again every time this thread is woken up, so spurious wakeups are
+    /// handled here rather than by the caller. The returned guard is locked
+    /// and the condition held when it was last checked.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the mutex being waited on is
+    /// poisoned when this thread re-acquires the lock. For more information,
+    /// see information about poisoning on the Mutex type.
+    ///
+    /// # Panics
+    ///
+    /// This function will `panic!()` if it is used with more than one mutex
+    /// over time, like `wait`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(wait_until)]
+    ///
+    /// use std::sync::{Arc, Mutex, Condvar};
+    /// use std::thread;
+    ///
+    /// let pair = Arc::new((Mutex::new(false), Condvar::new()));
+    /// let pair2 = pair.clone();
+    ///
+    /// thread::spawn(move|| {
+    ///     let &(ref lock, ref cvar) = &*pair2;
+    ///     *lock.lock().unwrap() = true;
+    ///     cvar.notify_one();
+    /// });
+    ///
+    /// // wait for the thread to start up
+    /// let &(ref lock, ref cvar) = &*pair;
+    /// let started = cvar.wait_until(lock.lock().unwrap(), |started| *started).unwrap();
+    /// assert!(*started);
+    /// ```
+    #[unstable(feature = "wait_until", issue = "0")]
+    pub fn wait_until<'a, T, F>(&self, mut guard: MutexGuard<'a, T>,
+                                mut condition: F)
+                                -> LockResult<MutexGuard<'a, T>>
+                                where F: FnMut(&mut T) -> bool {
+        while !condition(&mut *guard) {
+            guard = self.wait(guard)?;
+        }
+        Ok(guard)
+    }
+
+    /// Blocks the current thread until `condition` returns `true` for the data
+    /// protected by the mutex, or until `dur` has elapsed.
+    ///
+    /// This behaves like `wait_until`, with the total time spent waiting
+    /// bounded by `dur` no matter how many times the thread is woken up in
+    /// between. The same caveats about precise timing as for `wait_timeout`
+    /// apply.
+    ///
+    /// The returned `WaitTimeoutResult` indicates whether the timeout elapsed
+    /// before the condition was met. The condition is checked once more when
+    /// the lock is re-acquired after a timeout, so a timed out result means
+    /// it was still `false` at that point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(wait_until)]
+    ///
+    /// use std::sync::{Arc, Mutex, Condvar};
+    /// use std::time::Duration;
+    ///
+    /// let pair = Arc::new((Mutex::new(false), Condvar::new()));
+    ///
+    /// // nobody sets the flag, so this times out
+    /// let &(ref lock, ref cvar) = &*pair;
+    /// let (ready, result) = cvar.wait_timeout_until(lock.lock().unwrap(),
+    ///                                               Duration::from_millis(10),
+    ///                                               |ready| *ready).unwrap();
+    /// assert!(result.timed_out());
+    /// assert!(!*ready);
+    /// ```
+    #[unstable(feature = "wait_until", issue = "0")]
+    pub fn wait_timeout_until<'a, T, F>(&self, mut guard: MutexGuard<'a, T>,
+                                        dur: Duration, mut condition: F)
+                                        -> LockResult<(MutexGuard<'a, T>, WaitTimeoutResult)>
+                                        where F: FnMut(&mut T) -> bool {
+        let start = Instant::now();
+        loop {
+            if condition(&mut *guard) {
+                return Ok((guard, WaitTimeoutResult(false)));
+            }
+            let elapsed = start.elapsed();
+            if elapsed >= dur {
+                return Ok((guard, WaitTimeoutResult(true)));
+            }
+            guard = self.wait_timeout(guard, dur - elapsed)?.0;
+        }
+    }
+
     /// Waits on this condition variable for a notification, timing out after a
     /// specified duration.
     ///
@@ -250,7 +351,7 @@ mod tests {
     use sync::mpsc::channel;
     use sync::{Condvar, Mutex, Arc};
     use thread;
-    use time::Duration;
+    use time::{Duration, Instant};
     use u32;
 
     #[test]
@@ -332,6 +433,50 @@ mod tests {
         drop(g);
     }
 
+    #[test]
+    fn wait_until() {
+        let pair = Arc::new((Mutex::new(0), Condvar::new()));
+        let pair2 = pair.clone();
+
+        let _t = thread::spawn(move|| {
+            let &(ref lock, ref cvar) = &*pair2;
+            // Notify once for every increment, so the waiter sees values
+            // which don't satisfy the condition yet.
+            for _ in 0..5 {
+                *lock.lock().unwrap() += 1;
+                cvar.notify_one();
+            }
+        });
+
+        let &(ref lock, ref cvar) = &*pair;
+        let guard = cvar.wait_until(lock.lock().unwrap(), |n| *n == 5).unwrap();
+        assert_eq!(*guard, 5);
+    }
+
+    #[test]
+    fn wait_timeout_until() {
+        let pair = Arc::new((Mutex::new(false), Condvar::new()));
+        let pair2 = pair.clone();
+        let &(ref lock, ref cvar) = &*pair;
+
+        let start = Instant::now();
+        let dur = Duration::from_millis(10);
+        let (guard, res) = cvar.wait_timeout_until(lock.lock().unwrap(), dur,
+                                                   |ready| *ready).unwrap();
+        assert!(res.timed_out());
+        assert!(Instant::now() >= start + dur);
+
+        let _t = thread::spawn(move|| {
+            let &(ref lock, ref cvar) = &*pair2;
+            *lock.lock().unwrap() = true;
+            cvar.notify_one();
+        });
+        let (guard, res) = cvar.wait_timeout_until(guard, Duration::from_millis(u32::MAX as u64),
+                                                   |ready| *ready).unwrap();
+        assert!(!res.timed_out());
+        assert!(*guard);
+    }
+
     #[test]
     #[should_panic]
     fn two_mutexes() {
@@ -30,15 +30,22 @@ pub use self::condvar::{Condvar, WaitTimeoutResult};
 pub use self::mutex::{Mutex, MutexGuard};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::once::{Once, OnceState, ONCE_INIT};
+#[unstable(feature = "once_cell", issue = "0")]
+pub use self::once_cell::{OnceCell, Lazy};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use sys_common::poison::{PoisonError, TryLockError, TryLockResult, LockResult};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::rwlock::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+#[unstable(feature = "rwlock_upgradable_read", issue = "0")]
+pub use self::rwlock::RwLockUpgradableReadGuard;
 
 pub mod mpsc;
+#[unstable(feature = "nonpoison_mutex", issue = "0")]
+pub mod nonpoison;
 
 mod barrier;
 mod condvar;
 mod mutex;
 mod once;
+mod once_cell;
 mod rwlock;
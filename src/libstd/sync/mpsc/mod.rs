@@ -275,6 +275,8 @@ use time::{Duration, Instant};
 
 #[unstable(feature = "mpsc_select", issue = "27800")]
 pub use self::select::{Select, Handle};
+#[unstable(feature = "mpsc_receiver_set", issue = "0")]
+pub use self::select::ReceiverSet;
 use self::select::StartResult;
 use self::select::StartResult::*;
 use self::blocking::SignalToken;
@@ -936,6 +938,38 @@ impl<T> Receiver<T> {
         }
     }
 
+    /// Attempts to wait for a value on this receiver, returning an error if the
+    /// corresponding channel has hung up, or if `deadline` is reached.
+    ///
+    /// This behaves like `recv_timeout`, but takes an absolute point in time
+    /// rather than a duration. This makes it easy to share a single deadline
+    /// across several calls, for example when waiting for a batch of messages.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(deadline_api)]
+    ///
+    /// use std::sync::mpsc::{self, RecvTimeoutError};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let (send, recv) = mpsc::channel::<()>();
+    ///
+    /// let deadline = Instant::now() + Duration::from_millis(100);
+    /// assert_eq!(Err(RecvTimeoutError::Timeout), recv.recv_deadline(deadline));
+    /// ```
+    #[unstable(feature = "deadline_api", issue = "0")]
+    pub fn recv_deadline(&self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+        match self.try_recv() {
+            Ok(result)
+                => Ok(result),
+            Err(TryRecvError::Disconnected)
+                => Err(RecvTimeoutError::Disconnected),
+            Err(TryRecvError::Empty)
+                => self.recv_max_until(deadline)
+        }
+    }
+
     fn recv_max_until(&self, deadline: Instant) -> Result<T, RecvTimeoutError> {
         use self::RecvTimeoutError::*;
 
@@ -1268,6 +1302,38 @@ impl error::Error for TryRecvError {
     }
 }
 
+#[unstable(feature = "mpsc_recv_timeout", issue = "34029")]
+impl fmt::Display for RecvTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RecvTimeoutError::Timeout => {
+                "timed out waiting on channel".fmt(f)
+            }
+            RecvTimeoutError::Disconnected => {
+                "channel is empty and sending half is closed".fmt(f)
+            }
+        }
+    }
+}
+
+#[unstable(feature = "mpsc_recv_timeout", issue = "34029")]
+impl error::Error for RecvTimeoutError {
+    fn description(&self) -> &str {
+        match *self {
+            RecvTimeoutError::Timeout => {
+                "timed out waiting on channel"
+            }
+            RecvTimeoutError::Disconnected => {
+                "channel is empty and sending half is closed"
+            }
+        }
+    }
+
+    fn cause(&self) -> Option<&error::Error> {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use prelude::v1::*;
@@ -1714,6 +1780,33 @@ mod tests {
         assert_eq!(recv_count, stress);
     }
 
+    #[test]
+    fn recv_timeout_disconnected() {
+        let (tx, rx) = channel::<()>();
+        let _t = thread::spawn(move|| {
+            thread::sleep(Duration::from_millis(10));
+            drop(tx);
+        });
+        assert_eq!(rx.recv_timeout(Duration::from_secs(10)),
+                   Err(RecvTimeoutError::Disconnected));
+        assert_eq!(RecvTimeoutError::Timeout.to_string(), "timed out waiting on channel");
+    }
+
+    #[test]
+    fn recv_deadline() {
+        let (tx, rx) = channel();
+        let deadline = Instant::now() + Duration::from_millis(10);
+        tx.send(1).unwrap();
+        assert_eq!(rx.recv_deadline(deadline), Ok(1));
+        assert_eq!(rx.recv_deadline(deadline), Err(RecvTimeoutError::Timeout));
+        assert!(Instant::now() >= deadline);
+        // A deadline in the past still returns queued data.
+        tx.send(2).unwrap();
+        assert_eq!(rx.recv_deadline(deadline), Ok(2));
+        drop(tx);
+        assert_eq!(rx.recv_deadline(deadline), Err(RecvTimeoutError::Disconnected));
+    }
+
     #[test]
     fn recv_timeout_upgrade() {
         let (tx, rx) = channel::<()>();
@@ -19,7 +19,12 @@
 //! The set can be waited on multiple times to prevent re-adding each receiver
 //! to the set.
 //!
-//! Usage of this module is currently encouraged to go through the use of the
+//! The `ReceiverSet` structure is the intended replacement for `Select` and
+//! the `select!` macro. It only borrows the receivers it waits on, so it needs
+//! no `unsafe` code, and reports which receiver is ready by the index it was
+//! added at.
+//!
+//! Usage of `Select` is currently encouraged to go through the use of the
 //! `select!` macro. This macro allows naturally binding of variables to the
 //! received values of receivers in a much more natural syntax then usage of the
 //! `Select` structure directly.
@@ -64,12 +69,16 @@ use core::cell::{Cell, UnsafeCell};
 use core::marker;
 use core::ptr;
 use core::usize;
+use time::{Duration, Instant};
 
 use sync::mpsc::{Receiver, RecvError};
 use sync::mpsc::blocking::{self, SignalToken};
 
 /// The "receiver set" of the select interface. This structure is used to manage
 /// a set of receivers which are being selected over.
+///
+/// Managing the handles of a `Select` requires `unsafe` code. Prefer
+/// `ReceiverSet`, which provides the same functionality safely.
 pub struct Select {
     inner: UnsafeCell<SelectInner>,
     next_id: Cell<usize>,
@@ -165,97 +174,47 @@ impl Select {
     /// event could either be that data is available or the corresponding
     /// channel has been closed.
     pub fn wait(&self) -> usize {
-        self.wait2(true)
+        self.wait2(true, None).unwrap()
     }
 
-    /// Helper method for skipping the preflight checks during testing
-    fn wait2(&self, do_preflight_checks: bool) -> usize {
-        // Note that this is currently an inefficient implementation. We in
-        // theory have knowledge about all receivers in the set ahead of time,
-        // so this method shouldn't really have to iterate over all of them yet
-        // again. The idea with this "receiver set" interface is to get the
-        // interface right this time around, and later this implementation can
-        // be optimized.
-        //
-        // This implementation can be summarized by:
-        //
-        //      fn select(receivers) {
-        //          if any receiver ready { return ready index }
-        //          deschedule {
-        //              block on all receivers
-        //          }
-        //          unblock on all receivers
-        //          return ready index
-        //      }
-        //
-        // Most notably, the iterations over all of the receivers shouldn't be
-        // necessary.
-        unsafe {
-            // Stage 1: preflight checks. Look for any packets ready to receive
-            if do_preflight_checks {
-                for handle in self.iter() {
-                    if (*handle).packet.can_recv() {
-                        return (*handle).id();
-                    }
-                }
-            }
-
-            // Stage 2: begin the blocking process
-            //
-            // Create a number of signal tokens, and install each one
-            // sequentially until one fails. If one fails, then abort the
-            // selection on the already-installed tokens.
-            let (wait_token, signal_token) = blocking::tokens();
-            for (i, handle) in self.iter().enumerate() {
-                match (*handle).packet.start_selection(signal_token.clone()) {
-                    StartResult::Installed => {}
-                    StartResult::Abort => {
-                        // Go back and abort the already-begun selections
-                        for handle in self.iter().take(i) {
-                            (*handle).packet.abort_selection();
-                        }
-                        return (*handle).id;
-                    }
-                }
-            }
-
-            // Stage 3: no messages available, actually block
-            wait_token.wait();
+    /// Waits for an event on this receiver set for at most `timeout`.
+    ///
+    /// This behaves like `wait`, except that `None` is returned if no receiver
+    /// in the set became ready before the timeout elapsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(mpsc_select)]
+    ///
+    /// use std::sync::mpsc::{channel, Select};
+    /// use std::time::Duration;
+    ///
+    /// let (_tx, rx) = channel::<i32>();
+    /// let select = Select::new();
+    /// let mut handle = select.handle(&rx);
+    /// unsafe { handle.add(); }
+    ///
+    /// assert_eq!(select.wait_timeout(Duration::from_millis(10)), None);
+    /// ```
+    pub fn wait_timeout(&self, timeout: Duration) -> Option<usize> {
+        self.wait_deadline(Instant::now() + timeout)
+    }
 
-            // Stage 4: there *must* be message available; find it.
-            //
-            // Abort the selection process on each receiver. If the abort
-            // process returns `true`, then that means that the receiver is
-            // ready to receive some data. Note that this also means that the
-            // receiver may have yet to have fully read the `to_wake` field and
-            // woken us up (although the wakeup is guaranteed to fail).
-            //
-            // This situation happens in the window of where a sender invokes
-            // increment(), sees -1, and then decides to wake up the thread. After
-            // all this is done, the sending thread will set `selecting` to
-            // `false`. Until this is done, we cannot return. If we were to
-            // return, then a sender could wake up a receiver which has gone
-            // back to sleep after this call to `select`.
-            //
-            // Note that it is a "fairly small window" in which an increment()
-            // views that it should wake a thread up until the `selecting` bit
-            // is set to false. For now, the implementation currently just spins
-            // in a yield loop. This is very distasteful, but this
-            // implementation is already nowhere near what it should ideally be.
-            // A rewrite should focus on avoiding a yield loop, and for now this
-            // implementation is tying us over to a more efficient "don't
-            // iterate over everything every time" implementation.
-            let mut ready_id = usize::MAX;
-            for handle in self.iter() {
-                if (*handle).packet.abort_selection() {
-                    ready_id = (*handle).id;
-                }
-            }
+    /// Waits for an event on this receiver set until `deadline` is reached.
+    ///
+    /// This behaves like `wait`, except that `None` is returned if no receiver
+    /// in the set became ready before the deadline.
+    pub fn wait_deadline(&self, deadline: Instant) -> Option<usize> {
+        self.wait2(true, Some(deadline))
+    }
 
-            // We must have found a ready receiver
-            assert!(ready_id != usize::MAX);
-            return ready_id;
-        }
+    /// Helper method for skipping the preflight checks during testing
+    fn wait2(&self, do_preflight_checks: bool, deadline: Option<Instant>) -> Option<usize> {
+        let packets = || self.iter().map(|handle| unsafe { (*handle).packet });
+        wait_packets(packets, do_preflight_checks, deadline).map(|i| {
+            unsafe { (*self.iter().nth(i).unwrap()).id }
+        })
     }
 
     fn iter(&self) -> Packets { Packets { cur: unsafe { &*self.inner.get() }.head } }
@@ -323,6 +282,236 @@ impl<'rx, T: Send> Handle<'rx, T> {
     }
 }
 
+/// A set of receivers which can be waited on together.
+///
+/// Unlike `Select`, a `ReceiverSet` only borrows the receivers, so it needs
+/// no `unsafe` code to set up and can be built with an arbitrary number of
+/// receivers at runtime. Waiting on the set returns the index of a receiver
+/// which is ready, meaning that a message is available on it or that its
+/// channel has hung up. Receiving from that receiver then won't block.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(mpsc_receiver_set)]
+///
+/// use std::sync::mpsc::{channel, ReceiverSet};
+/// use std::thread;
+///
+/// let (tx1, rx1) = channel();
+/// let (tx2, rx2) = channel::<i32>();
+///
+/// thread::spawn(move || tx1.send("done").unwrap());
+///
+/// let mut set = ReceiverSet::new();
+/// let first = set.add(&rx1);
+/// let second = set.add(&rx2);
+///
+/// match set.wait() {
+///     i if i == first => assert_eq!(rx1.recv(), Ok("done")),
+///     i if i == second => panic!("nothing was sent on the second channel"),
+///     _ => unreachable!(),
+/// }
+/// # drop(tx2);
+/// ```
+#[unstable(feature = "mpsc_receiver_set", issue = "0")]
+pub struct ReceiverSet<'a> {
+    receivers: Vec<&'a (Packet + 'a)>,
+}
+
+impl<'a> ReceiverSet<'a> {
+    /// Creates a new, empty set of receivers.
+    #[unstable(feature = "mpsc_receiver_set", issue = "0")]
+    pub fn new() -> ReceiverSet<'a> {
+        ReceiverSet { receivers: Vec::new() }
+    }
+
+    /// Adds `rx` to the set, returning the index which identifies it in the
+    /// results of `wait` and its siblings.
+    ///
+    /// Indices are assigned in the order in which receivers are added,
+    /// starting at zero. Adding a receiver which is already in the set
+    /// leaves the set unchanged and returns its existing index, as a
+    /// receiver can only be waited on once.
+    #[unstable(feature = "mpsc_receiver_set", issue = "0")]
+    pub fn add<T>(&mut self, rx: &'a Receiver<T>) -> usize {
+        let addr = rx as *const Receiver<T> as *const u8;
+        let existing = self.receivers.iter().position(|&r| {
+            r as *const (Packet + 'a) as *const u8 == addr
+        });
+        match existing {
+            Some(index) => index,
+            None => {
+                self.receivers.push(rx);
+                self.receivers.len() - 1
+            }
+        }
+    }
+
+    /// Returns the number of receivers in the set.
+    #[unstable(feature = "mpsc_receiver_set", issue = "0")]
+    pub fn len(&self) -> usize {
+        self.receivers.len()
+    }
+
+    /// Blocks until one of the receivers in the set is ready, and returns its
+    /// index.
+    ///
+    /// If several receivers are ready, any of them may be returned.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the set is empty, as it would block forever.
+    #[unstable(feature = "mpsc_receiver_set", issue = "0")]
+    pub fn wait(&self) -> usize {
+        assert!(!self.receivers.is_empty(), "waiting on an empty ReceiverSet");
+        self.wait_until(None).unwrap()
+    }
+
+    /// Blocks until one of the receivers in the set is ready, returning its
+    /// index, or `None` if `timeout` elapses first.
+    ///
+    /// A sender racing with the timeout may still cause its receiver to be
+    /// reported as ready.
+    #[unstable(feature = "mpsc_receiver_set", issue = "0")]
+    pub fn wait_timeout(&self, timeout: Duration) -> Option<usize> {
+        self.wait_deadline(Instant::now() + timeout)
+    }
+
+    /// Blocks until one of the receivers in the set is ready, returning its
+    /// index, or `None` if `deadline` is reached first.
+    ///
+    /// A sender racing with the deadline may still cause its receiver to be
+    /// reported as ready.
+    #[unstable(feature = "mpsc_receiver_set", issue = "0")]
+    pub fn wait_deadline(&self, deadline: Instant) -> Option<usize> {
+        self.wait_until(Some(deadline))
+    }
+
+    fn wait_until(&self, deadline: Option<Instant>) -> Option<usize> {
+        wait_packets(|| self.receivers.iter().cloned(), true, deadline)
+    }
+}
+
+#[unstable(feature = "mpsc_receiver_set", issue = "0")]
+impl<'a> fmt::Debug for ReceiverSet<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ReceiverSet {{ .. }}")
+    }
+}
+
+/// Blocks until one of the receivers yielded by `packets` is ready, returning
+/// its position, or `None` if `deadline` passed first. This is the machinery
+/// shared by `Select` and `ReceiverSet`.
+///
+/// `packets` is called each time the set of receivers has to be walked, and
+/// must yield the same receivers in the same order every time.
+fn wait_packets<'a, F, I>(packets: F,
+                          do_preflight_checks: bool,
+                          deadline: Option<Instant>)
+                          -> Option<usize>
+    where F: Fn() -> I, I: Iterator<Item = &'a (Packet + 'a)>
+{
+    // Note that this is currently an inefficient implementation. We in
+    // theory have knowledge about all receivers in the set ahead of time,
+    // so this method shouldn't really have to iterate over all of them yet
+    // again. The idea with this "receiver set" interface is to get the
+    // interface right this time around, and later this implementation can
+    // be optimized.
+    //
+    // This implementation can be summarized by:
+    //
+    //      fn select(receivers) {
+    //          if any receiver ready { return ready index }
+    //          deschedule {
+    //              block on all receivers
+    //          }
+    //          unblock on all receivers
+    //          return ready index
+    //      }
+    //
+    // Most notably, the iterations over all of the receivers shouldn't be
+    // necessary.
+
+    // Stage 1: preflight checks. Look for any packets ready to receive
+    if do_preflight_checks {
+        for (i, packet) in packets().enumerate() {
+            if packet.can_recv() {
+                return Some(i);
+            }
+        }
+    }
+
+    // Stage 2: begin the blocking process
+    //
+    // Create a number of signal tokens, and install each one
+    // sequentially until one fails. If one fails, then abort the
+    // selection on the already-installed tokens.
+    let (wait_token, signal_token) = blocking::tokens();
+    for (i, packet) in packets().enumerate() {
+        match packet.start_selection(signal_token.clone()) {
+            StartResult::Installed => {}
+            StartResult::Abort => {
+                // Go back and abort the already-begun selections
+                for packet in packets().take(i) {
+                    packet.abort_selection();
+                }
+                return Some(i);
+            }
+        }
+    }
+
+    // Stage 3: no messages available, actually block
+    let woken = match deadline {
+        Some(deadline) => wait_token.wait_max_until(deadline),
+        None => {
+            wait_token.wait();
+            true
+        }
+    };
+
+    // Stage 4: unless we timed out there *must* be message available;
+    // find it.
+    //
+    // Abort the selection process on each receiver. If the abort
+    // process returns `true`, then that means that the receiver is
+    // ready to receive some data. Note that this also means that the
+    // receiver may have yet to have fully read the `to_wake` field and
+    // woken us up (although the wakeup is guaranteed to fail).
+    //
+    // This situation happens in the window of where a sender invokes
+    // increment(), sees -1, and then decides to wake up the thread. After
+    // all this is done, the sending thread will set `selecting` to
+    // `false`. Until this is done, we cannot return. If we were to
+    // return, then a sender could wake up a receiver which has gone
+    // back to sleep after this call to `select`.
+    //
+    // Note that it is a "fairly small window" in which an increment()
+    // views that it should wake a thread up until the `selecting` bit
+    // is set to false. For now, the implementation currently just spins
+    // in a yield loop. This is very distasteful, but this
+    // implementation is already nowhere near what it should ideally be.
+    // A rewrite should focus on avoiding a yield loop, and for now this
+    // implementation is tying us over to a more efficient "don't
+    // iterate over everything every time" implementation.
+    let mut ready = usize::MAX;
+    for (i, packet) in packets().enumerate() {
+        if packet.abort_selection() {
+            ready = i;
+        }
+    }
+
+    // A sender may have raced with the timeout, in which case its
+    // receiver is still reported as ready. Otherwise we must have
+    // found a ready receiver.
+    if ready == usize::MAX {
+        assert!(!woken);
+        None
+    } else {
+        Some(ready)
+    }
+}
+
 impl Drop for Select {
     fn drop(&mut self) {
         unsafe {
@@ -373,6 +562,7 @@ mod tests {
 
     use thread;
     use sync::mpsc::*;
+    use time::{Duration, Instant};
 
     // Don't use the libstd version so we can pull in the right Select structure
     // (std::comm points at the wrong one)
@@ -623,7 +813,7 @@ mod tests {
         let s = Select::new();
         let mut h = s.handle(&rx);
         unsafe { h.add(); }
-        assert_eq!(s.wait2(false), h.id);
+        assert_eq!(s.wait2(false, None), Some(h.id));
     }
 
     #[test]
@@ -634,7 +824,7 @@ mod tests {
         let s = Select::new();
         let mut h = s.handle(&rx);
         unsafe { h.add(); }
-        assert_eq!(s.wait2(false), h.id);
+        assert_eq!(s.wait2(false, None), Some(h.id));
     }
 
     #[test]
@@ -645,7 +835,7 @@ mod tests {
         let s = Select::new();
         let mut h = s.handle(&rx);
         unsafe { h.add(); }
-        assert_eq!(s.wait2(false), h.id);
+        assert_eq!(s.wait2(false, None), Some(h.id));
     }
 
     #[test]
@@ -655,7 +845,7 @@ mod tests {
         let s = Select::new();
         let mut h = s.handle(&rx);
         unsafe { h.add(); }
-        assert_eq!(s.wait2(false), h.id);
+        assert_eq!(s.wait2(false, None), Some(h.id));
     }
 
     #[test]
@@ -667,7 +857,7 @@ mod tests {
         let s = Select::new();
         let mut h = s.handle(&rx);
         unsafe { h.add(); }
-        assert_eq!(s.wait2(false), h.id);
+        assert_eq!(s.wait2(false, None), Some(h.id));
     }
 
     #[test]
@@ -680,7 +870,7 @@ mod tests {
         let s = Select::new();
         let mut h = s.handle(&rx);
         unsafe { h.add(); }
-        assert_eq!(s.wait2(false), h.id);
+        assert_eq!(s.wait2(false, None), Some(h.id));
     }
 
     #[test]
@@ -779,6 +969,113 @@ mod tests {
         }
     }
 
+    #[test]
+    fn wait_timeout() {
+        let (tx1, rx1) = channel::<i32>();
+        let (_tx2, rx2) = channel::<i32>();
+        let sel = Select::new();
+        let mut h1 = sel.handle(&rx1);
+        let mut h2 = sel.handle(&rx2);
+        unsafe {
+            h1.add();
+            h2.add();
+        }
+
+        let start = Instant::now();
+        let timeout = Duration::from_millis(10);
+        assert_eq!(sel.wait_timeout(timeout), None);
+        assert!(Instant::now() >= start + timeout);
+
+        // A waiter which timed out can select again.
+        let t = thread::spawn(move|| {
+            thread::sleep(Duration::from_millis(10));
+            tx1.send(1).unwrap();
+        });
+        assert_eq!(sel.wait_timeout(Duration::from_secs(10)), Some(h1.id()));
+        assert_eq!(h1.recv(), Ok(1));
+        t.join().unwrap();
+        assert_eq!(sel.wait_deadline(Instant::now()), Some(h1.id()));
+        assert!(h1.recv().is_err());
+    }
+
+    #[test]
+    fn receiver_set_smoke() {
+        let (tx1, rx1) = channel::<i32>();
+        let (tx2, rx2) = channel::<&'static str>();
+        let mut set = ReceiverSet::new();
+        assert_eq!(set.add(&rx1), 0);
+        assert_eq!(set.add(&rx2), 1);
+        assert_eq!(set.len(), 2);
+
+        tx2.send("a").unwrap();
+        assert_eq!(set.wait(), 1);
+        assert_eq!(rx2.recv(), Ok("a"));
+
+        tx1.send(1).unwrap();
+        assert_eq!(set.wait(), 0);
+        assert_eq!(rx1.recv(), Ok(1));
+
+        drop(tx1);
+        assert_eq!(set.wait(), 0);
+        assert!(rx1.recv().is_err());
+    }
+
+    #[test]
+    fn receiver_set_blocks() {
+        let (tx1, rx1) = channel::<i32>();
+        let (tx2, rx2) = sync_channel::<i32>(0);
+        let t = thread::spawn(move|| {
+            thread::sleep(Duration::from_millis(10));
+            tx2.send(2).unwrap();
+            drop(tx1);
+        });
+
+        let mut set = ReceiverSet::new();
+        set.add(&rx1);
+        set.add(&rx2);
+        assert_eq!(set.wait(), 1);
+        assert_eq!(rx2.recv(), Ok(2));
+        t.join().unwrap();
+    }
+
+    #[test]
+    fn receiver_set_timeout() {
+        let (_tx1, rx1) = channel::<i32>();
+        let (tx2, rx2) = channel::<i32>();
+        let mut set = ReceiverSet::new();
+        set.add(&rx1);
+        set.add(&rx2);
+
+        let start = Instant::now();
+        let timeout = Duration::from_millis(10);
+        assert_eq!(set.wait_timeout(timeout), None);
+        assert!(Instant::now() >= start + timeout);
+
+        tx2.send(1).unwrap();
+        assert_eq!(set.wait_deadline(Instant::now()), Some(1));
+    }
+
+    #[test]
+    fn receiver_set_duplicates() {
+        let (tx1, rx1) = channel::<i32>();
+        let (_tx2, rx2) = channel::<i32>();
+        let mut set = ReceiverSet::new();
+        assert_eq!(set.add(&rx1), 0);
+        assert_eq!(set.add(&rx2), 1);
+        assert_eq!(set.add(&rx1), 0);
+        assert_eq!(set.len(), 2);
+
+        tx1.send(1).unwrap();
+        assert_eq!(set.wait(), 0);
+        assert_eq!(rx1.recv(), Ok(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn receiver_set_empty() {
+        ReceiverSet::new().wait();
+    }
+
     #[test]
     fn fmt_debug_select() {
         let sel = Select::new();
@@ -0,0 +1,293 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Locks which don't implement poisoning.
+//!
+//! The primitives in this module behave like their counterparts in
+//! `std::sync`, except that a panic while holding the lock is not recorded.
+//! Locking therefore can't fail and returns the guard directly.
+//!
+//! They are also lighter: the lock is stored inline instead of in a separate
+//! allocation, locking it when it's free takes a single atomic operation, and
+//! a lock can be created in a `const` or `static` initializer.
+
+use prelude::v1::*;
+
+use cell::UnsafeCell;
+use fmt;
+use marker;
+use mem;
+use ops::{Deref, DerefMut};
+use ptr;
+use sys::lock::Lock;
+
+/// A mutual exclusion primitive useful for protecting shared data, without
+/// poisoning.
+///
+/// This is like `std::sync::Mutex`, except that if a thread panics while
+/// holding the lock the mutex is simply unlocked, and other threads can keep
+/// using it. Any invariants the panicking thread was in the middle of
+/// upholding may be broken, so this type is best suited for data which is
+/// valid at all times, such as counters or caches.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(nonpoison_mutex)]
+///
+/// use std::sync::nonpoison::Mutex;
+/// use std::thread;
+///
+/// static COUNT: Mutex<usize> = Mutex::new(0);
+///
+/// let threads = (0..10).map(|_| {
+///     thread::spawn(|| *COUNT.lock() += 1)
+/// }).collect::<Vec<_>>();
+///
+/// for t in threads {
+///     t.join().unwrap();
+/// }
+/// assert_eq!(*COUNT.lock(), 10);
+/// ```
+#[unstable(feature = "nonpoison_mutex", issue = "0")]
+pub struct Mutex<T: ?Sized> {
+    inner: Lock,
+    data: UnsafeCell<T>,
+}
+
+#[unstable(feature = "nonpoison_mutex", issue = "0")]
+unsafe impl<T: ?Sized + Send> Send for Mutex<T> { }
+#[unstable(feature = "nonpoison_mutex", issue = "0")]
+unsafe impl<T: ?Sized + Send> Sync for Mutex<T> { }
+
+/// An RAII implementation of a "scoped lock" of a `nonpoison::Mutex`. When
+/// this structure is dropped (falls out of scope), the lock will be unlocked.
+///
+/// The data protected by the mutex can be accessed through this guard via its
+/// `Deref` and `DerefMut` implementations.
+#[must_use]
+#[unstable(feature = "nonpoison_mutex", issue = "0")]
+pub struct MutexGuard<'a, T: ?Sized + 'a> {
+    // funny underscores due to how Deref/DerefMut currently work (they
+    // disregard field privacy).
+    __lock: &'a Mutex<T>,
+}
+
+#[unstable(feature = "nonpoison_mutex", issue = "0")]
+impl<'a, T: ?Sized> !marker::Send for MutexGuard<'a, T> {}
+
+impl<T> Mutex<T> {
+    /// Creates a new mutex in an unlocked state ready for use.
+    #[unstable(feature = "nonpoison_mutex", issue = "0")]
+    pub const fn new(t: T) -> Mutex<T> {
+        Mutex {
+            inner: Lock::new(),
+            data: UnsafeCell::new(t),
+        }
+    }
+
+    /// Consumes this mutex, returning the underlying data.
+    #[unstable(feature = "nonpoison_mutex", issue = "0")]
+    pub fn into_inner(self) -> T {
+        // We know statically that there are no outstanding references to
+        // `self` so there's no need to lock the inner lock.
+        unsafe {
+            let (inner, data) = {
+                let Mutex { ref inner, ref data } = self;
+                (ptr::read(inner), ptr::read(data))
+            };
+            mem::forget(self);
+            inner.destroy();  // Keep in sync with the `Drop` impl.
+            data.into_inner()
+        }
+    }
+}
+
+impl<T: ?Sized> Mutex<T> {
+    /// Acquires the mutex, blocking the current thread until it is able to
+    /// do so.
+    ///
+    /// The exact behavior on locking a mutex in the thread which already
+    /// holds the lock is left unspecified. However, this function will not
+    /// return on the second call (it might panic or deadlock, for example).
+    #[unstable(feature = "nonpoison_mutex", issue = "0")]
+    pub fn lock(&self) -> MutexGuard<T> {
+        unsafe {
+            self.inner.lock();
+            MutexGuard { __lock: self }
+        }
+    }
+
+    /// Attempts to acquire this lock.
+    ///
+    /// If the lock could not be acquired at this time, then `None` is
+    /// returned. Otherwise, an RAII guard is returned. The lock will be
+    /// unlocked when the guard is dropped.
+    ///
+    /// This function does not block.
+    #[unstable(feature = "nonpoison_mutex", issue = "0")]
+    pub fn try_lock(&self) -> Option<MutexGuard<T>> {
+        unsafe {
+            if self.inner.try_lock() {
+                Some(MutexGuard { __lock: self })
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns a mutable reference to the underlying data.
+    ///
+    /// Since this call borrows the `Mutex` mutably, no actual locking needs to
+    /// take place---the mutable borrow statically guarantees no locks exist.
+    #[unstable(feature = "nonpoison_mutex", issue = "0")]
+    pub fn get_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.data.get() }
+    }
+}
+
+#[unstable(feature = "nonpoison_mutex", issue = "0")]
+impl<T: ?Sized> Drop for Mutex<T> {
+    #[unsafe_destructor_blind_to_params]
+    fn drop(&mut self) {
+        // IMPORTANT: This code must be kept in sync with `Mutex::into_inner`.
+        unsafe { self.inner.destroy() }
+    }
+}
+
+#[unstable(feature = "nonpoison_mutex", issue = "0")]
+impl<T: ?Sized + Default> Default for Mutex<T> {
+    fn default() -> Mutex<T> {
+        Mutex::new(Default::default())
+    }
+}
+
+#[unstable(feature = "nonpoison_mutex", issue = "0")]
+impl<T: ?Sized + fmt::Debug> fmt::Debug for Mutex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.try_lock() {
+            Some(guard) => write!(f, "Mutex {{ data: {:?} }}", &*guard),
+            None => write!(f, "Mutex {{ <locked> }}")
+        }
+    }
+}
+
+#[unstable(feature = "nonpoison_mutex", issue = "0")]
+impl<'mutex, T: ?Sized> Deref for MutexGuard<'mutex, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.__lock.data.get() }
+    }
+}
+
+#[unstable(feature = "nonpoison_mutex", issue = "0")]
+impl<'mutex, T: ?Sized> DerefMut for MutexGuard<'mutex, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.__lock.data.get() }
+    }
+}
+
+#[unstable(feature = "nonpoison_mutex", issue = "0")]
+impl<'a, T: ?Sized> Drop for MutexGuard<'a, T> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { self.__lock.inner.unlock(); }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::v1::*;
+
+    use panic;
+    use sync::Arc;
+    use sync::mpsc::channel;
+    use super::Mutex;
+    use thread;
+
+    #[test]
+    fn smoke() {
+        let m = Mutex::new(());
+        drop(m.lock());
+        drop(m.lock());
+    }
+
+    #[test]
+    fn lots_and_lots() {
+        const J: u32 = 1000;
+        const K: u32 = 3;
+
+        static LOCK: Mutex<u32> = Mutex::new(0);
+
+        fn inc() {
+            for _ in 0..J {
+                *LOCK.lock() += 1;
+            }
+        }
+
+        let (tx, rx) = channel();
+        for _ in 0..K {
+            let tx2 = tx.clone();
+            thread::spawn(move|| { inc(); tx2.send(()).unwrap(); });
+            let tx2 = tx.clone();
+            thread::spawn(move|| { inc(); tx2.send(()).unwrap(); });
+        }
+
+        drop(tx);
+        for _ in 0..2 * K {
+            rx.recv().unwrap();
+        }
+        assert_eq!(*LOCK.lock(), J * K * 2);
+    }
+
+    #[test]
+    fn try_lock() {
+        let m = Mutex::new(1);
+        {
+            let _g = m.lock();
+            assert!(m.try_lock().is_none());
+        }
+        assert_eq!(*m.try_lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn into_inner_and_get_mut() {
+        let mut m = Mutex::new(vec![1, 2]);
+        m.get_mut().push(3);
+        assert_eq!(m.into_inner(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn panic_doesnt_poison() {
+        let arc = Arc::new(Mutex::new(1));
+        let arc2 = arc.clone();
+        let _ = thread::spawn(move|| {
+            let mut lock = arc2.lock();
+            *lock = 2;
+            panic!();
+        }).join();
+        assert_eq!(*arc.lock(), 2);
+
+        let res = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            let _lock = arc.lock();
+            panic!();
+        }));
+        assert!(res.is_err());
+        assert!(arc.try_lock().is_some());
+    }
+
+    #[test]
+    fn unsized_data() {
+        let mutex: &Mutex<[i32]> = &Mutex::new([1, 2, 3]);
+        mutex.lock()[1] = 4;
+        assert_eq!(&*mutex.lock(), &[1, 4, 3][..]);
+    }
+}
@@ -131,9 +131,9 @@ struct Waiter {
 
 // Helper struct used to clean up after a closure call with a `Drop`
 // implementation to also run on panic.
-struct Finish {
+struct Finish<'a> {
     panicked: bool,
-    me: &'static Once,
+    me: &'a Once,
 }
 
 impl Once {
@@ -200,7 +200,7 @@ impl Once {
     ///
     /// [poison]: struct.Mutex.html#poisoning
     #[stable(feature = "rust1", since = "1.0.0")]
-    pub fn call_once<F>(&'static self, f: F) where F: FnOnce() {
+    pub fn call_once<F>(&self, f: F) where F: FnOnce() {
         // Fast path, just see if we've completed initialization.
         if self.state.load(Ordering::SeqCst) == COMPLETE {
             return
@@ -220,7 +220,7 @@ impl Once {
     /// state of this `Once` (whether initialization has previously panicked or
     /// not).
     #[unstable(feature = "once_poison", issue = "33577")]
-    pub fn call_once_force<F>(&'static self, f: F) where F: FnOnce(&OnceState) {
+    pub fn call_once_force<F>(&self, f: F) where F: FnOnce(&OnceState) {
         // same as above, just with a different parameter to `call_inner`.
         if self.state.load(Ordering::SeqCst) == COMPLETE {
             return
@@ -232,6 +232,32 @@ impl Once {
         });
     }
 
+    /// Returns true if some initialization routine has completed successfully.
+    ///
+    /// This returns `false` while an initialization routine is still running
+    /// and after one has panicked, poisoning this `Once`. A `true` result
+    /// guarantees that the memory writes of the finished routine are visible,
+    /// just as returning from `call_once` does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_is_completed)]
+    /// use std::sync::{Once, ONCE_INIT};
+    ///
+    /// static INIT: Once = ONCE_INIT;
+    ///
+    /// assert_eq!(INIT.is_completed(), false);
+    /// INIT.call_once(|| {
+    ///     assert_eq!(INIT.is_completed(), false);
+    /// });
+    /// assert_eq!(INIT.is_completed(), true);
+    /// ```
+    #[unstable(feature = "once_is_completed", issue = "0")]
+    pub fn is_completed(&self) -> bool {
+        self.state.load(Ordering::SeqCst) == COMPLETE
+    }
+
     // This is a non-generic function to reduce the monomorphization cost of
     // using `call_once` (this isn't exactly a trivial or small implementation).
     //
@@ -244,7 +270,7 @@ impl Once {
     // currently no way to take an `FnOnce` and call it via virtual dispatch
     // without some allocation overhead.
     #[cold]
-    fn call_inner(&'static self,
+    fn call_inner(&self,
                   ignore_poisoning: bool,
                   mut init: &mut FnMut(bool)) {
         let mut state = self.state.load(Ordering::SeqCst);
@@ -328,7 +354,7 @@ impl Once {
     }
 }
 
-impl Drop for Finish {
+impl<'a> Drop for Finish<'a> {
     fn drop(&mut self) {
         // Swap out our state with however we finished. We should only ever see
         // an old state which was RUNNING.
@@ -486,4 +512,20 @@ mod tests {
         assert!(t2.join().is_ok());
 
     }
+
+    #[test]
+    fn is_completed() {
+        // a `Once` doesn't have to be a static
+        let o = Once::new();
+        assert!(!o.is_completed());
+
+        let t = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            o.call_once(|| panic!());
+        }));
+        assert!(t.is_err());
+        assert!(!o.is_completed());
+
+        o.call_once_force(|_| assert!(!o.is_completed()));
+        assert!(o.is_completed());
+    }
 }
@@ -0,0 +1,352 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Thread-safe cells which are initialized once.
+//!
+//! These are the synchronized counterparts of `cell::OnceCell` and
+//! `cell::Lazy`, built on top of `Once`. Unlike a plain `Once`, a panicking
+//! initializer doesn't poison the `OnceCell`: the next caller simply gets to
+//! try again.
+
+use cell::UnsafeCell;
+use fmt;
+use ops::Deref;
+use panic::{RefUnwindSafe, UnwindSafe};
+use sync::Once;
+
+/// A synchronization primitive which can be written to only once.
+///
+/// This is a thread-safe `cell::OnceCell`, and can be used in statics.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(once_cell)]
+///
+/// use std::sync::OnceCell;
+/// use std::thread;
+///
+/// static CELL: OnceCell<usize> = OnceCell::new();
+/// assert!(CELL.get().is_none());
+///
+/// thread::spawn(|| {
+///     let value: &usize = CELL.get_or_init(|| 12345);
+///     assert_eq!(value, &12345);
+/// }).join().unwrap();
+///
+/// assert_eq!(CELL.get(), Some(&12345));
+/// ```
+#[unstable(feature = "once_cell", issue = "0")]
+pub struct OnceCell<T> {
+    once: Once,
+    // Only written to inside `once`, and only read after `once` completed.
+    value: UnsafeCell<Option<T>>,
+}
+
+#[unstable(feature = "once_cell", issue = "0")]
+unsafe impl<T: Sync + Send> Sync for OnceCell<T> {}
+#[unstable(feature = "once_cell", issue = "0")]
+unsafe impl<T: Send> Send for OnceCell<T> {}
+
+#[unstable(feature = "once_cell", issue = "0")]
+impl<T: RefUnwindSafe + UnwindSafe> RefUnwindSafe for OnceCell<T> {}
+#[unstable(feature = "once_cell", issue = "0")]
+impl<T: UnwindSafe> UnwindSafe for OnceCell<T> {}
+
+impl<T> OnceCell<T> {
+    /// Creates a new empty cell.
+    #[unstable(feature = "once_cell", issue = "0")]
+    pub const fn new() -> OnceCell<T> {
+        OnceCell {
+            once: Once::new(),
+            value: UnsafeCell::new(None),
+        }
+    }
+
+    /// Gets a reference to the value, or `None` if the cell is empty or
+    /// being initialized.
+    ///
+    /// This method never blocks.
+    #[unstable(feature = "once_cell", issue = "0")]
+    pub fn get(&self) -> Option<&T> {
+        if self.once.is_completed() {
+            unsafe { (*self.value.get()).as_ref() }
+        } else {
+            None
+        }
+    }
+
+    /// Gets a mutable reference to the value, or `None` if the cell is
+    /// empty.
+    ///
+    /// This call borrows the cell mutably, so no synchronization is needed.
+    #[unstable(feature = "once_cell", issue = "0")]
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        unsafe { (*self.value.get()).as_mut() }
+    }
+
+    /// Sets the contents of the cell to `value`.
+    ///
+    /// This may block if another thread is currently initializing the cell.
+    /// Returns `Err(value)` if the cell was already full when this call
+    /// finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(once_cell)]
+    ///
+    /// use std::sync::OnceCell;
+    ///
+    /// static CELL: OnceCell<i32> = OnceCell::new();
+    ///
+    /// assert_eq!(CELL.set(92), Ok(()));
+    /// assert_eq!(CELL.set(62), Err(62));
+    /// assert_eq!(CELL.get(), Some(&92));
+    /// ```
+    #[unstable(feature = "once_cell", issue = "0")]
+    pub fn set(&self, value: T) -> Result<(), T> {
+        let mut value = Some(value);
+        self.get_or_init(|| value.take().unwrap());
+        match value {
+            None => Ok(()),
+            Some(value) => Err(value),
+        }
+    }
+
+    /// Gets the contents of the cell, initializing it with `f` if the cell
+    /// was empty.
+    ///
+    /// Many threads may call `get_or_init` concurrently with different
+    /// initializing functions, but it is guaranteed that only one function
+    /// will be executed. The other callers block until it has finished.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the panic is propagated to the caller and the cell
+    /// remains uninitialized, so a later call runs its own function.
+    ///
+    /// It is an error to reentrantly initialize the cell from `f`. The exact
+    /// outcome is unspecified; currently the calling thread deadlocks.
+    #[unstable(feature = "once_cell", issue = "0")]
+    pub fn get_or_init<F>(&self, f: F) -> &T where F: FnOnce() -> T {
+        if let Some(value) = self.get() {
+            return value
+        }
+        let mut f = Some(f);
+        let slot = &self.value;
+        self.once.call_once_force(|_| {
+            let value = f.take().unwrap()();
+            unsafe { *slot.get() = Some(value); }
+        });
+        self.get().unwrap()
+    }
+
+    /// Consumes the cell, returning the wrapped value, or `None` if the cell
+    /// was empty.
+    #[unstable(feature = "once_cell", issue = "0")]
+    pub fn into_inner(self) -> Option<T> {
+        unsafe { self.value.into_inner() }
+    }
+}
+
+#[unstable(feature = "once_cell", issue = "0")]
+impl<T> Default for OnceCell<T> {
+    fn default() -> OnceCell<T> {
+        OnceCell::new()
+    }
+}
+
+#[unstable(feature = "once_cell", issue = "0")]
+impl<T: fmt::Debug> fmt::Debug for OnceCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.get() {
+            Some(value) => f.debug_tuple("OnceCell").field(value).finish(),
+            None => f.write_str("OnceCell(<uninit>)"),
+        }
+    }
+}
+
+/// A value which is initialized on the first access.
+///
+/// This is a thread-safe `cell::Lazy`. If several threads access it at the
+/// same time, one of them runs the initializing function while the others
+/// wait for it to finish.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(once_cell, drop_types_in_const)]
+///
+/// use std::collections::HashMap;
+/// use std::sync::Lazy;
+///
+/// fn make_table() -> HashMap<u32, &'static str> {
+///     let mut m = HashMap::new();
+///     m.insert(13, "Spica");
+///     m.insert(74, "Hoyten");
+///     m
+/// }
+///
+/// static TABLE: Lazy<HashMap<u32, &'static str>> = Lazy::new(make_table);
+///
+/// assert_eq!(TABLE.get(&13), Some(&"Spica"));
+/// ```
+#[unstable(feature = "once_cell", issue = "0")]
+pub struct Lazy<T, F = fn() -> T> {
+    cell: OnceCell<T>,
+    init: UnsafeCell<Option<F>>,
+}
+
+// `init` is only accessed by the single thread which runs the initialization
+// inside of `cell`, so sharing a `Lazy` only shares `T`.
+#[unstable(feature = "once_cell", issue = "0")]
+unsafe impl<T, F: Send> Sync for Lazy<T, F> where OnceCell<T>: Sync {}
+
+#[unstable(feature = "once_cell", issue = "0")]
+impl<T, F: UnwindSafe> RefUnwindSafe for Lazy<T, F> where OnceCell<T>: RefUnwindSafe {}
+
+impl<T, F> Lazy<T, F> {
+    /// Creates a new lazy value with the given initializing function.
+    #[unstable(feature = "once_cell", issue = "0")]
+    pub const fn new(init: F) -> Lazy<T, F> {
+        Lazy { cell: OnceCell::new(), init: UnsafeCell::new(Some(init)) }
+    }
+}
+
+impl<T, F: FnOnce() -> T> Lazy<T, F> {
+    /// Forces the evaluation of this lazy value and returns a reference to
+    /// the result.
+    ///
+    /// This is equivalent to the `Deref` impl, but is explicit.
+    ///
+    /// # Panics
+    ///
+    /// If the initializing function panicked on an earlier access, this and
+    /// every later access panics as well.
+    #[unstable(feature = "once_cell", issue = "0")]
+    pub fn force(this: &Lazy<T, F>) -> &T {
+        this.cell.get_or_init(|| {
+            match unsafe { (*this.init.get()).take() } {
+                Some(f) => f(),
+                None => panic!("Lazy instance has previously been poisoned"),
+            }
+        })
+    }
+}
+
+#[unstable(feature = "once_cell", issue = "0")]
+impl<T, F: FnOnce() -> T> Deref for Lazy<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        Lazy::force(self)
+    }
+}
+
+#[unstable(feature = "once_cell", issue = "0")]
+impl<T: Default> Default for Lazy<T> {
+    fn default() -> Lazy<T> {
+        Lazy::new(T::default)
+    }
+}
+
+#[unstable(feature = "once_cell", issue = "0")]
+impl<T: fmt::Debug, F> fmt::Debug for Lazy<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Lazy").field("cell", &self.cell).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::v1::*;
+
+    use panic;
+    use sync::atomic::{AtomicUsize, Ordering};
+    use sync::mpsc::channel;
+    use sync::{Arc, Barrier};
+    use thread;
+    use super::{Lazy, OnceCell};
+
+    #[test]
+    fn smoke() {
+        let cell = OnceCell::new();
+        assert_eq!(cell.get(), None);
+        assert_eq!(*cell.get_or_init(|| 92), 92);
+        assert_eq!(*cell.get_or_init(|| panic!()), 92);
+        assert_eq!(cell.set(62), Err(62));
+
+        let mut cell = cell;
+        *cell.get_mut().unwrap() += 1;
+        assert_eq!(cell.into_inner(), Some(93));
+    }
+
+    #[test]
+    fn stampede() {
+        static CELL: OnceCell<usize> = OnceCell::new();
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let barrier = Arc::new(Barrier::new(10));
+        let (tx, rx) = channel();
+        for i in 0..10 {
+            let (barrier, tx) = (barrier.clone(), tx.clone());
+            thread::spawn(move|| {
+                barrier.wait();
+                let value = *CELL.get_or_init(|| {
+                    CALLS.fetch_add(1, Ordering::SeqCst);
+                    i
+                });
+                tx.send(value).unwrap();
+            });
+        }
+        drop(tx);
+
+        let values = rx.iter().collect::<Vec<_>>();
+        assert_eq!(values.len(), 10);
+        assert!(values.iter().all(|&v| v == values[0]));
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn init_panic_doesnt_poison() {
+        let cell = OnceCell::new();
+        let res = panic::catch_unwind(|| {
+            cell.get_or_init(|| panic!());
+        });
+        assert!(res.is_err());
+        assert_eq!(cell.get(), None);
+        assert_eq!(*cell.get_or_init(|| 1), 1);
+    }
+
+    #[test]
+    fn lazy_static() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        fn init() -> usize {
+            CALLS.fetch_add(1, Ordering::SeqCst) + 92
+        }
+        static LAZY: Lazy<usize> = Lazy::new(init);
+
+        let t = thread::spawn(|| *LAZY);
+        assert_eq!(*LAZY, 92);
+        assert_eq!(t.join().unwrap(), 92);
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+
+        let lazy = Lazy::new(|| vec![1, 2, 3]);
+        assert_eq!(lazy[1], 2);
+    }
+
+    #[test]
+    fn lazy_poisoned() {
+        let lazy: Lazy<i32, _> = Lazy::new(|| panic!());
+        assert!(panic::catch_unwind(|| *lazy).is_err());
+        assert!(panic::catch_unwind(|| *lazy).is_err());
+    }
+}
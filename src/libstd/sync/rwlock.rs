@@ -16,6 +16,7 @@ use marker;
 use mem;
 use ops::{Deref, DerefMut};
 use ptr;
+use sys_common::mutex as sys_mutex;
 use sys_common::poison::{self, LockResult, TryLockError, TryLockResult};
 use sys_common::rwlock as sys;
 
@@ -68,6 +69,10 @@ use sys_common::rwlock as sys;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub struct RwLock<T: ?Sized> {
     inner: Box<sys::RWLock>,
+    // Held by the upgradable reader, if any, and by every writer, so that no
+    // writer can get in while an upgradable reader waits for its upgrade.
+    // Plain readers never touch it.
+    upgrade: Box<sys_mutex::Mutex>,
     poison: poison::Flag,
     data: UnsafeCell<T>,
 }
@@ -100,6 +105,23 @@ pub struct RwLockWriteGuard<'a, T: ?Sized + 'a> {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a, T: ?Sized> !marker::Send for RwLockWriteGuard<'a, T> {}
 
+/// RAII structure used to release the shared read access of a lock when
+/// dropped, which can be upgraded to exclusive write access.
+///
+/// This structure is created by the [`upgradable_read`] method on
+/// [`RwLock`].
+///
+/// [`upgradable_read`]: struct.RwLock.html#method.upgradable_read
+/// [`RwLock`]: struct.RwLock.html
+#[must_use]
+#[unstable(feature = "rwlock_upgradable_read", issue = "0")]
+pub struct RwLockUpgradableReadGuard<'a, T: ?Sized + 'a> {
+    __lock: &'a RwLock<T>,
+}
+
+#[unstable(feature = "rwlock_upgradable_read", issue = "0")]
+impl<'a, T: ?Sized> !marker::Send for RwLockUpgradableReadGuard<'a, T> {}
+
 impl<T> RwLock<T> {
     /// Creates a new instance of an `RwLock<T>` which is unlocked.
     ///
@@ -112,11 +134,16 @@ impl<T> RwLock<T> {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn new(t: T) -> RwLock<T> {
-        RwLock {
+        let mut lock = RwLock {
             inner: box sys::RWLock::new(),
+            upgrade: box sys_mutex::Mutex::new(),
             poison: poison::Flag::new(),
             data: UnsafeCell::new(t),
+        };
+        unsafe {
+            lock.upgrade.init();
         }
+        lock
     }
 }
 
@@ -194,6 +221,7 @@ impl<T: ?Sized> RwLock<T> {
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn write(&self) -> LockResult<RwLockWriteGuard<T>> {
         unsafe {
+            self.upgrade.lock();
             self.inner.write();
             RwLockWriteGuard::new(self)
         }
@@ -220,14 +248,72 @@ impl<T: ?Sized> RwLock<T> {
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn try_write(&self) -> TryLockResult<RwLockWriteGuard<T>> {
         unsafe {
+            if !self.upgrade.try_lock() {
+                return Err(TryLockError::WouldBlock);
+            }
             if self.inner.try_write() {
                 Ok(RwLockWriteGuard::new(self)?)
             } else {
+                self.upgrade.unlock();
                 Err(TryLockError::WouldBlock)
             }
         }
     }
 
+    /// Locks this rwlock with shared read access which can later be upgraded
+    /// to exclusive write access, blocking the current thread until it can be
+    /// acquired.
+    ///
+    /// Any number of plain readers may hold the lock at the same time as an
+    /// upgradable reader, but at most one upgradable reader and no writers:
+    /// [`write`] blocks until the upgradable reader is gone. Upgrading the
+    /// returned guard with [`upgrade`] waits for the plain readers to leave
+    /// and then grants write access, so the data can't have been modified
+    /// between the creation of the guard and the upgrade.
+    ///
+    /// Returns an RAII guard which will release the shared access when
+    /// dropped.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the RwLock is poisoned. An RwLock
+    /// is poisoned whenever a writer panics while holding an exclusive lock.
+    /// The failure will occur immediately after the lock has been acquired.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic or deadlock when called if the lock is
+    /// already held by the current thread, other than by a plain reader.
+    ///
+    /// [`upgrade`]: struct.RwLockUpgradableReadGuard.html#method.upgrade
+    /// [`write`]: #method.write
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rwlock_upgradable_read)]
+    ///
+    /// use std::sync::{RwLock, RwLockUpgradableReadGuard};
+    ///
+    /// let cache = RwLock::new(Vec::new());
+    ///
+    /// let entries = cache.upgradable_read().unwrap();
+    /// if entries.is_empty() {
+    ///     // nobody else can fill the cache between the check and the write
+    ///     let mut entries = RwLockUpgradableReadGuard::upgrade(entries).unwrap();
+    ///     entries.push("filled");
+    /// }
+    /// assert_eq!(*cache.read().unwrap(), ["filled"]);
+    /// ```
+    #[unstable(feature = "rwlock_upgradable_read", issue = "0")]
+    pub fn upgradable_read(&self) -> LockResult<RwLockUpgradableReadGuard<T>> {
+        unsafe {
+            self.upgrade.lock();
+            self.inner.read();
+            RwLockUpgradableReadGuard::new(self)
+        }
+    }
+
     /// Determines whether the lock is poisoned.
     ///
     /// If another thread is active, the lock can still become poisoned at any
@@ -256,14 +342,16 @@ impl<T: ?Sized> RwLock<T> {
         // but because `RwLock` impl-s `Drop`, we can't move out of it, so
         // we'll have to destructure it manually instead.
         unsafe {
-            // Like `let RwLock { inner, poison, data } = self`.
-            let (inner, poison, data) = {
-                let RwLock { ref inner, ref poison, ref data } = self;
-                (ptr::read(inner), ptr::read(poison), ptr::read(data))
+            // Like `let RwLock { inner, upgrade, poison, data } = self`.
+            let (inner, upgrade, poison, data) = {
+                let RwLock { ref inner, ref upgrade, ref poison, ref data } = self;
+                (ptr::read(inner), ptr::read(upgrade), ptr::read(poison), ptr::read(data))
             };
             mem::forget(self);
             inner.destroy();  // Keep in sync with the `Drop` impl.
             drop(inner);
+            upgrade.destroy();
+            drop(upgrade);
 
             poison::map_result(poison.borrow(), |_| data.into_inner())
         }
@@ -294,7 +382,10 @@ impl<T: ?Sized> Drop for RwLock<T> {
     #[unsafe_destructor_blind_to_params]
     fn drop(&mut self) {
         // IMPORTANT: This code needs to be kept in sync with `RwLock::into_inner`.
-        unsafe { self.inner.destroy() }
+        unsafe {
+            self.inner.destroy();
+            self.upgrade.destroy();
+        }
     }
 }
 
@@ -329,6 +420,47 @@ impl<'rwlock, T: ?Sized> RwLockReadGuard<'rwlock, T> {
     }
 }
 
+impl<'rwlock, T: ?Sized> RwLockUpgradableReadGuard<'rwlock, T> {
+    unsafe fn new(lock: &'rwlock RwLock<T>)
+                  -> LockResult<RwLockUpgradableReadGuard<'rwlock, T>> {
+        poison::map_result(lock.poison.borrow(), |_| {
+            RwLockUpgradableReadGuard {
+                __lock: lock,
+            }
+        })
+    }
+
+    /// Upgrades the shared read access of `guard` to exclusive write access,
+    /// blocking the current thread until the other readers have released the
+    /// lock.
+    ///
+    /// No writer can acquire the lock between the creation of `guard` and the
+    /// returned write guard, so the data is still the one seen through
+    /// `guard`.
+    ///
+    /// This is an associated function rather than a method, so it doesn't
+    /// shadow a method on the data of the same name.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the RwLock is poisoned. An RwLock
+    /// is poisoned whenever a writer panics while holding an exclusive lock.
+    #[unstable(feature = "rwlock_upgradable_read", issue = "0")]
+    pub fn upgrade(guard: RwLockUpgradableReadGuard<'rwlock, T>)
+                   -> LockResult<RwLockWriteGuard<'rwlock, T>> {
+        let lock = guard.__lock;
+        // The `upgrade` mutex stays locked, and is released by the write
+        // guard instead. Plain readers may get in after the read lock is
+        // released, but writers can't as they need the mutex first.
+        mem::forget(guard);
+        unsafe {
+            lock.inner.read_unlock();
+            lock.inner.write();
+            RwLockWriteGuard::new(lock)
+        }
+    }
+}
+
 impl<'rwlock, T: ?Sized> RwLockWriteGuard<'rwlock, T> {
     unsafe fn new(lock: &'rwlock RwLock<T>)
                   -> LockResult<RwLockWriteGuard<'rwlock, T>> {
@@ -350,6 +482,15 @@ impl<'rwlock, T: ?Sized> Deref for RwLockReadGuard<'rwlock, T> {
     }
 }
 
+#[unstable(feature = "rwlock_upgradable_read", issue = "0")]
+impl<'rwlock, T: ?Sized> Deref for RwLockUpgradableReadGuard<'rwlock, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.__lock.data.get() }
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'rwlock, T: ?Sized> Deref for RwLockWriteGuard<'rwlock, T> {
     type Target = T;
@@ -373,11 +514,24 @@ impl<'a, T: ?Sized> Drop for RwLockReadGuard<'a, T> {
     }
 }
 
+#[unstable(feature = "rwlock_upgradable_read", issue = "0")]
+impl<'a, T: ?Sized> Drop for RwLockUpgradableReadGuard<'a, T> {
+    fn drop(&mut self) {
+        unsafe {
+            self.__lock.inner.read_unlock();
+            self.__lock.upgrade.unlock();
+        }
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a, T: ?Sized> Drop for RwLockWriteGuard<'a, T> {
     fn drop(&mut self) {
         self.__lock.poison.done(&self.__poison);
-        unsafe { self.__lock.inner.write_unlock(); }
+        unsafe {
+            self.__lock.inner.write_unlock();
+            self.__lock.upgrade.unlock();
+        }
     }
 }
 
@@ -390,7 +544,7 @@ mod tests {
     use rand::{self, Rng};
     use sync::mpsc::channel;
     use thread;
-    use sync::{Arc, RwLock, TryLockError};
+    use sync::{Arc, RwLock, RwLockUpgradableReadGuard, TryLockError};
     use sync::atomic::{AtomicUsize, Ordering};
 
     #[derive(Eq, PartialEq, Debug)]
@@ -629,4 +783,80 @@ mod tests {
             Ok(x) => panic!("get_mut of poisoned RwLock is Ok: {:?}", x),
         }
     }
+
+    #[test]
+    fn upgradable_read() {
+        let lock = RwLock::new(1);
+        {
+            let upgradable = lock.upgradable_read().unwrap();
+            // plain readers can still get in, writers can't
+            assert_eq!(*lock.read().unwrap(), 1);
+            assert!(lock.try_write().is_err());
+            assert_eq!(*upgradable, 1);
+
+            let mut write = RwLockUpgradableReadGuard::upgrade(upgradable).unwrap();
+            assert!(lock.try_read().is_err());
+            *write += 1;
+        }
+        assert_eq!(*lock.write().unwrap(), 2);
+        drop(lock.upgradable_read().unwrap());
+        drop(lock.try_write().unwrap());
+    }
+
+    #[test]
+    fn upgrade_excludes_upgradable_readers() {
+        const N: usize = 10;
+
+        let lock = Arc::new(RwLock::new(0));
+        let (tx, rx) = channel();
+        for _ in 0..N {
+            let lock = lock.clone();
+            let tx = tx.clone();
+            thread::spawn(move|| {
+                for _ in 0..100 {
+                    let guard = lock.upgradable_read().unwrap();
+                    let seen = *guard;
+                    thread::yield_now();
+                    let mut guard = RwLockUpgradableReadGuard::upgrade(guard).unwrap();
+                    // all writes go through upgrades, so nobody else wrote
+                    // in between
+                    assert_eq!(*guard, seen);
+                    *guard += 1;
+                    drop(guard);
+                    assert!(*lock.read().unwrap() > seen);
+                }
+                tx.send(()).unwrap();
+            });
+        }
+        drop(tx);
+        for _ in 0..N {
+            rx.recv().unwrap();
+        }
+        assert_eq!(*lock.read().unwrap(), N * 100);
+    }
+
+    #[test]
+    fn upgrade_excludes_writers() {
+        const N: usize = 100;
+
+        let lock = Arc::new(RwLock::new(0));
+        let lock2 = lock.clone();
+        let writer = thread::spawn(move|| {
+            for _ in 0..N {
+                *lock2.write().unwrap() += 1;
+                thread::yield_now();
+            }
+        });
+        for _ in 0..N {
+            let guard = lock.upgradable_read().unwrap();
+            let seen = *guard;
+            thread::yield_now();
+            let mut guard = RwLockUpgradableReadGuard::upgrade(guard).unwrap();
+            // the plain writer can't get in between the read and the upgrade
+            assert_eq!(*guard, seen);
+            *guard += 1;
+        }
+        writer.join().unwrap();
+        assert_eq!(*lock.read().unwrap(), 2 * N);
+    }
 }
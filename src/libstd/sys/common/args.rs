@@ -89,7 +89,8 @@ mod imp {
 
 #[cfg(any(target_os = "macos",
           target_os = "ios",
-          target_os = "windows"))]
+          target_os = "windows",
+          target_arch = "wasm32"))]
 mod imp {
     use vec::Vec;
 
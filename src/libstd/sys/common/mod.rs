@@ -33,16 +33,25 @@ pub mod backtrace;
 pub mod condvar;
 pub mod io;
 pub mod mutex;
-pub mod net;
 pub mod poison;
 pub mod remutex;
 pub mod rwlock;
-pub mod thread;
 pub mod thread_info;
 pub mod thread_local;
 pub mod util;
 pub mod wtf8;
 
+// There are no sockets on wasm, so instead of being built on top of
+// `sys::net` this whole module is stubbed out there.
+#[cfg(target_arch = "wasm32")]
+pub use sys::net;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod net;
+
+// Threads can't be spawned on wasm.
+#[cfg(not(target_arch = "wasm32"))]
+pub mod thread;
+
 #[cfg(any(not(cargobuild), feature = "backtrace"))]
 #[cfg(any(all(unix, not(any(target_os = "macos", target_os = "ios", target_os = "emscripten"))),
           all(windows, target_env = "gnu")))]
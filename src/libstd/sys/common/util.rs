@@ -45,6 +45,12 @@ unsafe fn abort_internal() -> ! {
     ::libc::abort()
 }
 
+// There's nothing to hand the abort to in a wasm module, so just trap.
+#[cfg(target_arch = "wasm32")]
+unsafe fn abort_internal() -> ! {
+    ::intrinsics::abort()
+}
+
 // On Windows, use the processor-specific __fastfail mechanism.  In Windows 8
 // and later, this will terminate the process immediately without running any
 // in-process exception handlers.  In earlier versions of Windows, this
@@ -55,7 +61,7 @@ unsafe fn abort_internal() -> ! {
 // https://msdn.microsoft.com/en-us/library/dn774154.aspx
 #[cfg(all(windows, any(target_arch = "x86", target_arch = "x86_64")))]
 unsafe fn abort_internal() -> ! {
-    asm!("int $$0x29" :: "{ecx}"(7) ::: volatile); // 7 is FAST_FAIL_FATAL_APP_EXIT
+    llvm_asm!("int $$0x29" :: "{ecx}"(7) ::: volatile); // 7 is FAST_FAIL_FATAL_APP_EXIT
     ::intrinsics::unreachable();
 }
 
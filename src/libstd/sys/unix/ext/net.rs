@@ -28,7 +28,10 @@ use time::Duration;
 use sys::cvt;
 use sys::net::Socket;
 use sys_common::{AsInner, FromInner, IntoInner};
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use sys_common::net::getsockopt;
 
+#[allow(deprecated)]
 fn sun_path_offset() -> usize {
     unsafe {
         // Work with an actual instance of the type since using a null pointer is UB
@@ -200,6 +203,40 @@ impl fmt::Debug for UnixStream {
     }
 }
 
+/// Credentials of the process on the other end of a `UnixStream`.
+///
+/// This `struct` is created by the [`peer_cred`] method on [`UnixStream`].
+///
+/// [`peer_cred`]: struct.UnixStream.html#method.peer_cred
+/// [`UnixStream`]: struct.UnixStream.html
+#[unstable(feature = "peer_credentials_unix_socket", issue = "0")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UCred {
+    /// The effective user ID of the peer process.
+    pub uid: libc::uid_t,
+    /// The effective group ID of the peer process.
+    pub gid: libc::gid_t,
+    /// The process ID of the peer, on platforms which report it.
+    pub pid: Option<libc::pid_t>,
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn peer_cred(socket: &Socket) -> io::Result<UCred> {
+    let cred: libc::ucred = getsockopt(socket, libc::SOL_SOCKET, libc::SO_PEERCRED)?;
+    Ok(UCred { uid: cred.uid, gid: cred.gid, pid: Some(cred.pid) })
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd",
+          target_os = "dragonfly", target_os = "openbsd", target_os = "netbsd"))]
+fn peer_cred(socket: &Socket) -> io::Result<UCred> {
+    unsafe {
+        let mut uid = 0;
+        let mut gid = 0;
+        cvt(libc::getpeereid(*socket.as_inner(), &mut uid, &mut gid))?;
+        Ok(UCred { uid: uid, gid: gid, pid: None })
+    }
+}
+
 impl UnixStream {
     /// Connects to the socket named by `path`.
     #[stable(feature = "unix_socket", since = "1.10.0")]
@@ -248,6 +285,31 @@ impl UnixStream {
         SocketAddr::new(|addr, len| unsafe { libc::getpeername(*self.0.as_inner(), addr, len) })
     }
 
+    /// Returns the credentials of the process on the other end of this
+    /// connection.
+    ///
+    /// The credentials are those the peer had when the connection was
+    /// established. The process ID is only available on Linux and Android.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(peer_credentials_unix_socket)]
+    ///
+    /// use std::os::unix::net::UnixStream;
+    ///
+    /// let socket = UnixStream::connect("/tmp/sock").unwrap();
+    /// let cred = socket.peer_cred().expect("couldn't get peer credentials");
+    /// println!("connected to uid {}", cred.uid);
+    /// ```
+    #[unstable(feature = "peer_credentials_unix_socket", issue = "0")]
+    #[cfg(any(target_os = "linux", target_os = "android", target_os = "macos",
+              target_os = "ios", target_os = "freebsd", target_os = "dragonfly",
+              target_os = "openbsd", target_os = "netbsd"))]
+    pub fn peer_cred(&self) -> io::Result<UCred> {
+        peer_cred(&self.0)
+    }
+
     /// Sets the read timeout for the socket.
     ///
     /// If the provided value is `None`, then `read` calls will block
@@ -309,6 +371,10 @@ impl io::Read for UnixStream {
         io::Read::read(&mut &*self, buf)
     }
 
+    fn read_vectored(&mut self, bufs: &mut [io::IoSliceMut]) -> io::Result<usize> {
+        io::Read::read_vectored(&mut &*self, bufs)
+    }
+
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
         io::Read::read_to_end(&mut &*self, buf)
     }
@@ -320,6 +386,10 @@ impl<'a> io::Read for &'a UnixStream {
         self.0.read(buf)
     }
 
+    fn read_vectored(&mut self, bufs: &mut [io::IoSliceMut]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
         self.0.read_to_end(buf)
     }
@@ -331,6 +401,10 @@ impl io::Write for UnixStream {
         io::Write::write(&mut &*self, buf)
     }
 
+    fn write_vectored(&mut self, bufs: &[io::IoSlice]) -> io::Result<usize> {
+        io::Write::write_vectored(&mut &*self, bufs)
+    }
+
     fn flush(&mut self) -> io::Result<()> {
         io::Write::flush(&mut &*self)
     }
@@ -342,6 +416,10 @@ impl<'a> io::Write for &'a UnixStream {
         self.0.write(buf)
     }
 
+    fn write_vectored(&mut self, bufs: &[io::IoSlice]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
+
     fn flush(&mut self) -> io::Result<()> {
         Ok(())
     }
@@ -835,6 +913,25 @@ mod test {
         thread.join().unwrap();
     }
 
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "android", target_os = "macos",
+              target_os = "ios", target_os = "freebsd", target_os = "dragonfly",
+              target_os = "openbsd", target_os = "netbsd"))]
+    fn peer_cred() {
+        use libc;
+
+        let (s1, s2) = or_panic!(UnixStream::pair());
+        let cred = or_panic!(s1.peer_cred());
+        assert_eq!(cred, or_panic!(s2.peer_cred()));
+        assert_eq!(cred.uid, unsafe { libc::geteuid() });
+        assert_eq!(cred.gid, unsafe { libc::getegid() });
+        if cfg!(any(target_os = "linux", target_os = "android")) {
+            assert_eq!(cred.pid, Some(unsafe { libc::getpid() }));
+        } else {
+            assert_eq!(cred.pid, None);
+        }
+    }
+
     #[test]
     fn pair() {
         let msg1 = b"hello";
@@ -62,6 +62,26 @@ pub trait CommandExt {
     fn before_exec<F>(&mut self, f: F) -> &mut process::Command
         where F: FnMut() -> io::Result<()> + Send + Sync + 'static;
 
+    /// Schedules a closure to be run between the `fork` and `exec` calls
+    /// that spawn the child process.
+    ///
+    /// This behaves like `before_exec`: the closure may return an I/O error
+    /// which is reported back to the parent, and multiple closures are run in
+    /// the order they were registered. It is intended for setting up process
+    /// attributes which `Command` does not cover itself, such as calling
+    /// `setsid`, adjusting resource limits or rearranging file descriptors.
+    ///
+    /// # Safety
+    ///
+    /// The closure runs in the child after a `fork` of a possibly
+    /// multithreaded parent, so only async-signal-safe operations are sound.
+    /// In particular it must not allocate, take locks, or touch state that
+    /// another thread of the parent may have been holding at the time of the
+    /// `fork`. The caller is responsible for upholding this.
+    #[unstable(feature = "process_pre_exec", issue = "0")]
+    unsafe fn pre_exec<F>(&mut self, f: F) -> &mut process::Command
+        where F: FnMut() -> io::Result<()> + Send + Sync + 'static;
+
     /// Performs all the required setup by this `Command`, followed by calling
     /// the `execvp` syscall.
     ///
@@ -99,6 +119,12 @@ impl CommandExt for process::Command {
 
     fn before_exec<F>(&mut self, f: F) -> &mut process::Command
         where F: FnMut() -> io::Result<()> + Send + Sync + 'static
+    {
+        unsafe { self.pre_exec(f) }
+    }
+
+    unsafe fn pre_exec<F>(&mut self, f: F) -> &mut process::Command
+        where F: FnMut() -> io::Result<()> + Send + Sync + 'static
     {
         self.as_inner_mut().before_exec(Box::new(f));
         self
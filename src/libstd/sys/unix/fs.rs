@@ -11,12 +11,13 @@
 use prelude::v1::*;
 use os::unix::prelude::*;
 
+use env;
 use ffi::{CString, CStr, OsString, OsStr};
 use fmt;
-use io::{self, Error, ErrorKind, SeekFrom};
+use io::{self, Error, ErrorKind, IoSlice, IoSliceMut, SeekFrom};
 use libc::{self, c_int, mode_t};
 use mem;
-use path::{Path, PathBuf};
+use path::{Component, Path, PathBuf};
 use ptr;
 use sync::Arc;
 use sys::fd::FileDesc;
@@ -469,10 +470,56 @@ impl File {
         }).map(|_| ());
     }
 
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.flock(libc::LOCK_SH)
+    }
+
+    pub fn lock_exclusive(&self) -> io::Result<()> {
+        self.flock(libc::LOCK_EX)
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.try_flock(libc::LOCK_SH)
+    }
+
+    pub fn try_lock_exclusive(&self) -> io::Result<bool> {
+        self.try_flock(libc::LOCK_EX)
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        self.flock(libc::LOCK_UN)
+    }
+
+    fn try_flock(&self, operation: c_int) -> io::Result<bool> {
+        match self.flock(operation | libc::LOCK_NB) {
+            Ok(()) => Ok(true),
+            Err(ref e) if e.raw_os_error() == Some(libc::EWOULDBLOCK) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[cfg(not(target_os = "solaris"))]
+    fn flock(&self, operation: c_int) -> io::Result<()> {
+        cvt_r(|| unsafe { libc::flock(self.0.raw(), operation) }).map(|_| ())
+    }
+
+    // Solaris has no `flock`, and `fcntl` locks have different enough
+    // semantics (they're per-process and released on any close) that
+    // emulating `flock` with them would be misleading.
+    #[cfg(target_os = "solaris")]
+    fn flock(&self, _operation: c_int) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other,
+                           "file locking is not supported on this platform"))
+    }
+
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         self.0.read(buf)
     }
 
+    pub fn read_vectored(&self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
     pub fn read_to_end(&self, buf: &mut Vec<u8>) -> io::Result<usize> {
         self.0.read_to_end(buf)
     }
@@ -481,6 +528,10 @@ impl File {
         self.0.write(buf)
     }
 
+    pub fn write_vectored(&self, bufs: &[IoSlice]) -> io::Result<usize> {
+        self.0.write_vectored(bufs)
+    }
+
     pub fn flush(&self) -> io::Result<()> { Ok(()) }
 
     pub fn seek(&self, pos: SeekFrom) -> io::Result<u64> {
@@ -714,6 +765,25 @@ pub fn lstat(p: &Path) -> io::Result<FileAttr> {
     Ok(FileAttr { stat: stat })
 }
 
+pub fn absolute(p: &Path) -> io::Result<PathBuf> {
+    let mut normalized = if p.is_absolute() {
+        PathBuf::new()
+    } else {
+        env::current_dir()?
+    };
+    for component in p.components() {
+        match component {
+            Component::Prefix(..) => unreachable!(),
+            Component::RootDir => normalized.push(component.as_os_str()),
+            Component::CurDir => {}
+            // `pop` leaves the root alone, so `/..` stays `/`
+            Component::ParentDir => { normalized.pop(); }
+            Component::Normal(name) => normalized.push(name),
+        }
+    }
+    Ok(normalized)
+}
+
 pub fn canonicalize(p: &Path) -> io::Result<PathBuf> {
     let path = CString::new(p.as_os_str().as_bytes())?;
     let buf;
@@ -0,0 +1,176 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! In-kernel copies between file descriptors, used to speed up `io::copy`.
+//!
+//! On Linux data can be moved from a file to another file with
+//! `copy_file_range`, from a file to a socket with `sendfile`, and into or
+//! out of a pipe with `splice`, without ever being copied into userspace.
+//! None of these is guaranteed to work for a given pair of descriptors (old
+//! kernels, different filesystems, special files, ...), so `copy` reports
+//! whether the caller should carry on with an ordinary read/write loop.
+
+use io;
+use libc::c_int;
+use sys::fs::File;
+use sys::net::Socket;
+use sys::pipe::AnonPipe;
+use sys_common::AsInner;
+
+/// The outcome of an attempted in-kernel copy.
+pub enum CopyResult {
+    /// The copy ran to completion, successfully or not.
+    Ended(io::Result<u64>),
+    /// The kernel could not (or could no longer) copy between these
+    /// descriptors; the caller should finish the copy itself. The value is
+    /// the number of bytes that were already copied.
+    Fallback(u64),
+}
+
+/// One side of a copy.
+#[derive(Clone, Copy)]
+pub enum Endpoint<'a> {
+    File(&'a File),
+    Socket(&'a Socket),
+    Pipe(&'a AnonPipe),
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod imp {
+    use io;
+    use libc::{self, c_int, c_uint, loff_t, size_t, ssize_t};
+    use ptr;
+    use super::CopyResult;
+
+    // All of these calls report the number of bytes copied as an `ssize_t`,
+    // so ask for at most this much at a time.
+    const CHUNK: usize = 1 << 30;
+
+    // Not exposed by every libc we bind to.
+    const SPLICE_F_MOVE: c_uint = 1;
+
+    pub fn file_to_file(reader: c_int, writer: c_int) -> CopyResult {
+        weak! {
+            fn copy_file_range(c_int, *mut loff_t, c_int, *mut loff_t, size_t, c_uint) -> ssize_t
+        }
+
+        // The glibc wrapper only appeared in 2.27, so look it up at runtime.
+        if let Some(copy) = copy_file_range.get() {
+            let ret = copy_loop(|| unsafe {
+                copy(reader, ptr::null_mut(), writer, ptr::null_mut(), CHUNK, 0)
+            });
+            match ret {
+                CopyResult::Fallback(0) => {}
+                ret => return ret,
+            }
+        }
+
+        // Since Linux 2.6.33 `sendfile` also accepts a regular file as its
+        // destination, which covers kernels without `copy_file_range`.
+        send_file(reader, writer)
+    }
+
+    pub fn send_file(reader: c_int, writer: c_int) -> CopyResult {
+        copy_loop(|| unsafe {
+            libc::sendfile(writer, reader, ptr::null_mut(), CHUNK)
+        })
+    }
+
+    /// At least one of `reader` and `writer` has to be a pipe.
+    pub fn splice(reader: c_int, writer: c_int) -> CopyResult {
+        weak! {
+            fn splice(c_int, *mut loff_t, c_int, *mut loff_t, size_t, c_uint) -> ssize_t
+        }
+
+        match splice.get() {
+            Some(splice) => copy_loop(|| unsafe {
+                splice(reader, ptr::null_mut(), writer, ptr::null_mut(), CHUNK, SPLICE_F_MOVE)
+            }),
+            None => CopyResult::Fallback(0),
+        }
+    }
+
+    fn copy_loop<F>(mut f: F) -> CopyResult
+        where F: FnMut() -> ssize_t
+    {
+        let mut written = 0u64;
+        loop {
+            let ret = f();
+            if ret == -1 {
+                let err = io::Error::last_os_error();
+                match err.raw_os_error() {
+                    Some(libc::EINTR) => continue,
+                    // The kernel doesn't support this call, or not for this
+                    // combination of descriptors. Nothing has been consumed
+                    // by the failed call, so the caller can pick up from here.
+                    Some(libc::ENOSYS) | Some(libc::EXDEV) | Some(libc::EINVAL) |
+                    Some(libc::EOPNOTSUPP) => return CopyResult::Fallback(written),
+                    // Writers opened with `O_APPEND` are refused with `EBADF`,
+                    // and seccomp filters often answer with `EPERM`. Past the
+                    // first call these are real errors.
+                    Some(libc::EBADF) | Some(libc::EPERM) if written == 0 => {
+                        return CopyResult::Fallback(0)
+                    }
+                    _ => return CopyResult::Ended(Err(err)),
+                }
+            } else if ret == 0 {
+                // Some special files (in procfs or sysfs, for example) claim
+                // to be empty to the kernel but still produce data when read,
+                // so let an ordinary read find out what the end really is.
+                if written == 0 {
+                    return CopyResult::Fallback(0)
+                }
+                return CopyResult::Ended(Ok(written))
+            } else {
+                written += ret as u64;
+            }
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+mod imp {
+    use libc::c_int;
+    use super::CopyResult;
+
+    pub fn file_to_file(_reader: c_int, _writer: c_int) -> CopyResult {
+        CopyResult::Fallback(0)
+    }
+
+    pub fn send_file(_reader: c_int, _writer: c_int) -> CopyResult {
+        CopyResult::Fallback(0)
+    }
+
+    pub fn splice(_reader: c_int, _writer: c_int) -> CopyResult {
+        CopyResult::Fallback(0)
+    }
+}
+
+fn raw_fd(endpoint: Endpoint) -> c_int {
+    match endpoint {
+        Endpoint::File(file) => file.fd().raw(),
+        Endpoint::Socket(socket) => *socket.as_inner(),
+        Endpoint::Pipe(pipe) => pipe.fd().raw(),
+    }
+}
+
+/// Copies the rest of `reader` to `writer`, starting at their current
+/// offsets.
+pub fn copy(reader: Endpoint, writer: Endpoint) -> CopyResult {
+    let (r, w) = (raw_fd(reader), raw_fd(writer));
+    match (reader, writer) {
+        (Endpoint::File(_), Endpoint::File(_)) => imp::file_to_file(r, w),
+        (Endpoint::File(_), Endpoint::Socket(_)) => imp::send_file(r, w),
+        (Endpoint::Pipe(_), _) | (_, Endpoint::Pipe(_)) => imp::splice(r, w),
+        // `sendfile` needs a reader it can map, so reading from a socket
+        // only works through a pipe.
+        (Endpoint::Socket(_), _) => CopyResult::Fallback(0),
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A lock which is a single atomic word.
+//!
+//! Unlike a pthread mutex this needs neither initialization nor a stable
+//! address while it is unlocked, so it can be created in a constant and moved
+//! around freely. Uncontended locking and unlocking is one atomic operation.
+//!
+//! The word is 0 while unlocked, 1 while locked and 2 while locked with
+//! (possibly) other threads waiting for it. On Linux the waiting threads
+//! sleep on the word itself with futex(2). Other platforms have no such
+//! primitive, so there the waiters sleep on one of a fixed set of condition
+//! variables, picked by the address of the word.
+
+use sync::atomic::{AtomicU32, Ordering};
+
+pub struct Lock {
+    state: AtomicU32,
+}
+
+const UNLOCKED: u32 = 0;
+const LOCKED: u32 = 1;
+const CONTENDED: u32 = 2;
+
+impl Lock {
+    pub const fn new() -> Lock {
+        Lock { state: AtomicU32::new(UNLOCKED) }
+    }
+
+    #[inline]
+    pub unsafe fn lock(&self) {
+        if self.state.compare_and_swap(UNLOCKED, LOCKED, Ordering::Acquire) != UNLOCKED {
+            self.lock_contended();
+        }
+    }
+
+    #[cold]
+    unsafe fn lock_contended(&self) {
+        // Once we've had to wait we can't know whether anybody else is still
+        // waiting, so from here on the lock is always taken as contended. At
+        // worst this costs a spurious wakeup when it's unlocked.
+        while self.state.swap(CONTENDED, Ordering::Acquire) != UNLOCKED {
+            wait(&self.state, CONTENDED);
+        }
+    }
+
+    #[inline]
+    pub unsafe fn try_lock(&self) -> bool {
+        self.state.compare_and_swap(UNLOCKED, LOCKED, Ordering::Acquire) == UNLOCKED
+    }
+
+    #[inline]
+    pub unsafe fn unlock(&self) {
+        if self.state.swap(UNLOCKED, Ordering::Release) == CONTENDED {
+            wake_one(&self.state);
+        }
+    }
+
+    #[inline]
+    pub unsafe fn destroy(&self) {}
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+unsafe fn wait(futex: &AtomicU32, expected: u32) {
+    use libc;
+    use ptr;
+
+    const FUTEX_WAIT: libc::c_int = 0;
+    const FUTEX_PRIVATE_FLAG: libc::c_int = 128;
+
+    // Returns immediately if the word no longer holds `expected`, and may
+    // also return early on signals; the caller re-checks either way.
+    libc::syscall(libc::SYS_futex, futex as *const AtomicU32,
+                  FUTEX_WAIT | FUTEX_PRIVATE_FLAG, expected,
+                  ptr::null::<libc::timespec>());
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+unsafe fn wake_one(futex: &AtomicU32) {
+    use libc;
+
+    const FUTEX_WAKE: libc::c_int = 1;
+    const FUTEX_PRIVATE_FLAG: libc::c_int = 128;
+
+    libc::syscall(libc::SYS_futex, futex as *const AtomicU32,
+                  FUTEX_WAKE | FUTEX_PRIVATE_FLAG, 1);
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+mod parking {
+    use sync::atomic::AtomicU32;
+    use sys::condvar::Condvar;
+    use sys::mutex::Mutex;
+
+    pub struct Bucket {
+        pub lock: Mutex,
+        pub cvar: Condvar,
+    }
+
+    unsafe impl Sync for Bucket {}
+
+    // Both of these are used without `init`, which is fine as the mutex is
+    // never locked recursively and the condvar is never waited on with a
+    // timeout.
+    const BUCKET: Bucket = Bucket { lock: Mutex::new(), cvar: Condvar::new() };
+
+    static BUCKETS: [Bucket; 32] = [
+        BUCKET, BUCKET, BUCKET, BUCKET, BUCKET, BUCKET, BUCKET, BUCKET,
+        BUCKET, BUCKET, BUCKET, BUCKET, BUCKET, BUCKET, BUCKET, BUCKET,
+        BUCKET, BUCKET, BUCKET, BUCKET, BUCKET, BUCKET, BUCKET, BUCKET,
+        BUCKET, BUCKET, BUCKET, BUCKET, BUCKET, BUCKET, BUCKET, BUCKET,
+    ];
+
+    /// Returns the bucket the waiters for `futex` sleep in.
+    ///
+    /// Unrelated locks may share a bucket, so it has to be woken with
+    /// `notify_all`, but only threads which hash to the same bucket suffer
+    /// the spurious wakeups.
+    pub fn bucket(futex: &AtomicU32) -> &'static Bucket {
+        // The low bits are always zero, so Fibonacci hashing spreads the
+        // rest of the address over the buckets.
+        let addr = futex as *const AtomicU32 as usize;
+        let hash = ((addr >> 2) as u32).wrapping_mul(0x9e3779b9);
+        &BUCKETS[(hash >> 27) as usize]
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+unsafe fn wait(futex: &AtomicU32, expected: u32) {
+    // The word is checked under the bucket's lock, and `wake_one` only
+    // notifies while holding it after the word has changed, so no wakeup can
+    // be lost.
+    let bucket = parking::bucket(futex);
+    bucket.lock.lock();
+    if futex.load(Ordering::Relaxed) == expected {
+        bucket.cvar.wait(&bucket.lock);
+    }
+    bucket.lock.unlock();
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+unsafe fn wake_one(futex: &AtomicU32) {
+    // Other locks may hash to the same bucket, so waking just one thread
+    // could pick one that waits for a different lock.
+    let bucket = parking::bucket(futex);
+    bucket.lock.lock();
+    bucket.cvar.notify_all();
+    bucket.lock.unlock();
+}
@@ -36,6 +36,9 @@ pub mod condvar;
 pub mod ext;
 pub mod fd;
 pub mod fs;
+pub mod io;
+pub mod kernel_copy;
+pub mod lock;
 pub mod mutex;
 pub mod net;
 pub mod os;
@@ -96,6 +99,8 @@ pub use sys::android::signal;
 #[cfg(not(target_os = "android"))]
 pub use libc::signal;
 
+pub use libc::strlen;
+
 pub fn decode_error_kind(errno: i32) -> ErrorKind {
     match errno as libc::c_int {
         libc::ECONNREFUSED => ErrorKind::ConnectionRefused,
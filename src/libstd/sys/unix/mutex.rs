@@ -30,6 +30,7 @@ impl Mutex {
         Mutex { inner: UnsafeCell::new(libc::PTHREAD_MUTEX_INITIALIZER) }
     }
     #[inline]
+    #[allow(deprecated)]
     pub unsafe fn init(&mut self) {
         // Issue #33770
         //
@@ -101,10 +102,12 @@ unsafe impl Send for ReentrantMutex {}
 unsafe impl Sync for ReentrantMutex {}
 
 impl ReentrantMutex {
+    #[allow(deprecated)]
     pub unsafe fn uninitialized() -> ReentrantMutex {
         ReentrantMutex { inner: mem::uninitialized() }
     }
 
+    #[allow(deprecated)]
     pub unsafe fn init(&mut self) {
         let mut attr: libc::pthread_mutexattr_t = mem::uninitialized();
         let result = libc::pthread_mutexattr_init(&mut attr as *mut _);
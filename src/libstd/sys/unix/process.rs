@@ -11,6 +11,7 @@
 use prelude::v1::*;
 use os::unix::prelude::*;
 
+use cmp;
 use collections::hash_map::{HashMap, Entry};
 use env;
 use ffi::{OsString, OsStr, CString, CStr};
@@ -23,6 +24,8 @@ use sys::fd::FileDesc;
 use sys::fs::{File, OpenOptions};
 use sys::pipe::{self, AnonPipe};
 use sys::{self, cvt, cvt_r};
+use thread;
+use time::{Duration, Instant};
 
 ////////////////////////////////////////////////////////////////////////////////
 // Command
@@ -329,6 +332,7 @@ impl Command {
     // allocation). Instead we just close it manually. This will never
     // have the drop glue anyway because this code never returns (the
     // child will either exec() or invoke libc::exit)
+    #[allow(deprecated)]
     unsafe fn do_exec(&mut self, stdio: ChildPipes) -> io::Error {
         macro_rules! t {
             ($e:expr) => (match $e {
@@ -588,6 +592,40 @@ impl Process {
         self.status = Some(ExitStatus(status));
         Ok(ExitStatus(status))
     }
+
+    pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
+        if let Some(status) = self.status {
+            return Ok(Some(status))
+        }
+        let mut status = 0 as c_int;
+        let pid = cvt_r(|| unsafe {
+            libc::waitpid(self.pid, &mut status, libc::WNOHANG)
+        })?;
+        if pid == 0 {
+            Ok(None)
+        } else {
+            self.status = Some(ExitStatus(status));
+            Ok(Some(ExitStatus(status)))
+        }
+    }
+
+    pub fn wait_timeout(&mut self, dur: Duration) -> io::Result<Option<ExitStatus>> {
+        // There's no portable way to block on a child with a timeout, so poll
+        // with `WNOHANG`, backing off up to a bounded sleep between attempts.
+        let start = Instant::now();
+        let mut delay = Duration::from_millis(1);
+        loop {
+            if let Some(status) = self.try_wait()? {
+                return Ok(Some(status))
+            }
+            let elapsed = start.elapsed();
+            if elapsed >= dur {
+                return Ok(None)
+            }
+            thread::sleep(cmp::min(delay, dur - elapsed));
+            delay = cmp::min(delay * 2, Duration::from_millis(100));
+        }
+    }
 }
 
 #[cfg(test)]
@@ -632,6 +670,7 @@ mod tests {
     #[test]
     #[cfg_attr(target_os = "macos", ignore)]
     #[cfg_attr(target_os = "nacl", ignore)] // no signals on NaCl.
+    #[allow(deprecated)]
     fn test_process_mask() {
         unsafe {
             // Test to make sure that a signal mask does not get inherited.
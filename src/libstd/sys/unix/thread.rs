@@ -12,7 +12,7 @@ use prelude::v1::*;
 
 use alloc::boxed::FnBox;
 use cmp;
-use ffi::CStr;
+use ffi::{CStr, CString};
 use io;
 use libc;
 use mem;
@@ -122,6 +122,56 @@ impl Thread {
         // Newlib, Illumos and Emscripten have no way to set a thread name.
     }
 
+    #[cfg(any(target_os = "linux",
+              target_os = "android"))]
+    pub fn get_name() -> Option<CString> {
+        const PR_GET_NAME: libc::c_int = 16;
+        // The kernel limits names to 16 bytes, including the nul terminator.
+        let mut buf = [0 as libc::c_char; 16];
+        let ret = unsafe {
+            libc::prctl(PR_GET_NAME, buf.as_mut_ptr() as libc::c_ulong, 0, 0, 0)
+        };
+        if ret != 0 {
+            return None
+        }
+        Some(unsafe { CStr::from_ptr(buf.as_ptr()) }.to_owned())
+    }
+
+    #[cfg(any(target_os = "macos",
+              target_os = "ios",
+              target_os = "netbsd"))]
+    pub fn get_name() -> Option<CString> {
+        extern {
+            fn pthread_getname_np(thread: libc::pthread_t,
+                                  name: *mut libc::c_char,
+                                  len: libc::size_t) -> libc::c_int;
+        }
+        // Matches MAXTHREADNAMESIZE on OSX and PTHREAD_MAX_NAMELEN_NP on
+        // NetBSD.
+        let mut buf = [0 as libc::c_char; 64];
+        let ret = unsafe {
+            pthread_getname_np(libc::pthread_self(), buf.as_mut_ptr(),
+                               buf.len() as libc::size_t)
+        };
+        if ret != 0 {
+            return None
+        }
+        Some(unsafe { CStr::from_ptr(buf.as_ptr()) }.to_owned())
+    }
+
+    #[cfg(any(target_env = "newlib",
+              target_os = "solaris",
+              target_os = "emscripten",
+              target_os = "freebsd",
+              target_os = "dragonfly",
+              target_os = "bitrig",
+              target_os = "openbsd"))]
+    pub fn get_name() -> Option<CString> {
+        // Either there's no way to name a thread at all, or the name can be
+        // set but not read back.
+        None
+    }
+
     pub fn sleep(dur: Duration) {
         let mut secs = dur.as_secs();
         let mut nsecs = dur.subsec_nanos() as libc::c_long;
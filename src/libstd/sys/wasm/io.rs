@@ -0,0 +1,49 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Vectored I/O never reaches the "system" here, so the buffers are plain
+// slices rather than anything laid out for a syscall.
+
+pub struct IoSlice<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> IoSlice<'a> {
+    #[inline]
+    pub fn new(buf: &'a [u8]) -> IoSlice<'a> {
+        IoSlice { buf: buf }
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        self.buf
+    }
+}
+
+pub struct IoSliceMut<'a> {
+    buf: &'a mut [u8],
+}
+
+impl<'a> IoSliceMut<'a> {
+    #[inline]
+    pub fn new(buf: &'a mut [u8]) -> IoSliceMut<'a> {
+        IoSliceMut { buf: buf }
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        self.buf
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        self.buf
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! System bindings for `wasm32-unknown-unknown`.
+//!
+//! There is no operating system underneath this target: no files, no
+//! processes, no threads, no network and no clock. The modules here provide
+//! the same interface as the unix and windows ones so the rest of the
+//! standard library compiles, but nearly every operation simply returns an
+//! "unsupported" error. The few things which do work (memory allocation,
+//! the single-threaded synchronization primitives, thread locals) are
+//! implemented without any help from a libc.
+
+#![allow(dead_code, missing_docs, bad_style)]
+
+use os::raw::c_char;
+
+#[cfg(any(not(cargobuild), feature = "backtrace"))]
+pub mod backtrace;
+pub mod condvar;
+pub mod fs;
+pub mod io;
+pub mod kernel_copy;
+pub mod lock;
+pub mod mutex;
+pub mod net;
+pub mod os;
+#[path = "../unix/os_str.rs"]
+pub mod os_str;
+pub mod pipe;
+pub mod process;
+pub mod rand;
+pub mod rwlock;
+pub mod stack_overflow;
+pub mod thread;
+pub mod thread_local;
+pub mod time;
+pub mod stdio;
+
+#[cfg(not(test))]
+pub fn init() {}
+
+pub fn unsupported<T>() -> ::io::Result<T> {
+    Err(unsupported_err())
+}
+
+pub fn unsupported_err() -> ::io::Error {
+    ::io::Error::new(::io::ErrorKind::Other,
+                   "operation not supported on wasm yet")
+}
+
+pub fn decode_error_kind(_code: i32) -> ::io::ErrorKind {
+    ::io::ErrorKind::Other
+}
+
+pub unsafe fn strlen(mut s: *const c_char) -> usize {
+    let mut n = 0;
+    while *s != 0 {
+        n += 1;
+        s = s.offset(1);
+    }
+    n
+}
+
+/// An uninhabited type, used for the handles (files, processes, sockets,
+/// ...) which can never be created on this target.
+pub enum Void {}
@@ -0,0 +1,38 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use boxed::Box;
+use ptr;
+
+// Each key is a heap allocated slot holding the value. There is only one
+// thread, so the slot *is* the thread local value. Destructors are never
+// run, just like for the main thread on other platforms.
+
+pub type Key = usize;
+
+#[inline]
+pub unsafe fn create(_dtor: Option<unsafe extern fn(*mut u8)>) -> Key {
+    Box::into_raw(Box::new(ptr::null_mut::<u8>())) as Key
+}
+
+#[inline]
+pub unsafe fn set(key: Key, value: *mut u8) {
+    *(key as *mut *mut u8) = value;
+}
+
+#[inline]
+pub unsafe fn get(key: Key) -> *mut u8 {
+    *(key as *mut *mut u8)
+}
+
+#[inline]
+pub unsafe fn destroy(key: Key) {
+    Box::from_raw(key as *mut *mut u8);
+}
@@ -0,0 +1,93 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use time::Duration;
+
+// There is no clock to read, so time values can only be made from
+// `UNIX_EPOCH` and arithmetic on it. Both are kept as a number of
+// nanoseconds.
+
+const NSEC_PER_SEC: u64 = 1_000_000_000;
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Instant {
+    t: u64
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct SystemTime {
+    t: u64
+}
+
+pub const UNIX_EPOCH: SystemTime = SystemTime { t: 0 };
+
+fn dur2nanos(dur: &Duration) -> u64 {
+    dur.as_secs().checked_mul(NSEC_PER_SEC).and_then(|nanos| {
+        nanos.checked_add(dur.subsec_nanos() as u64)
+    }).expect("overflow converting duration to nanoseconds")
+}
+
+fn nanos2dur(nanos: u64) -> Duration {
+    Duration::new(nanos / NSEC_PER_SEC, (nanos % NSEC_PER_SEC) as u32)
+}
+
+impl Instant {
+    pub fn now() -> Instant {
+        panic!("not supported on web assembly");
+    }
+
+    pub fn sub_instant(&self, other: &Instant) -> Duration {
+        nanos2dur(self.t.checked_sub(other.t)
+                        .expect("second instant is later than self"))
+    }
+
+    pub fn add_duration(&self, other: &Duration) -> Instant {
+        Instant {
+            t: self.t.checked_add(dur2nanos(other))
+                   .expect("overflow when adding duration to instant"),
+        }
+    }
+
+    pub fn sub_duration(&self, other: &Duration) -> Instant {
+        Instant {
+            t: self.t.checked_sub(dur2nanos(other))
+                   .expect("overflow when adding duration to instant"),
+        }
+    }
+}
+
+impl SystemTime {
+    pub fn now() -> SystemTime {
+        panic!("not supported on web assembly");
+    }
+
+    pub fn sub_time(&self, other: &SystemTime)
+                    -> Result<Duration, Duration> {
+        if self.t >= other.t {
+            Ok(nanos2dur(self.t - other.t))
+        } else {
+            Err(nanos2dur(other.t - self.t))
+        }
+    }
+
+    pub fn add_duration(&self, other: &Duration) -> SystemTime {
+        SystemTime {
+            t: self.t.checked_add(dur2nanos(other))
+                   .expect("overflow when adding duration to time"),
+        }
+    }
+
+    pub fn sub_duration(&self, other: &Duration) -> SystemTime {
+        SystemTime {
+            t: self.t.checked_sub(dur2nanos(other))
+                   .expect("overflow when subtracting duration from time"),
+        }
+    }
+}
@@ -121,6 +121,21 @@ pub trait OpenOptionsExt {
     /// Sets the `dwSecurityQosFlags` argument to the call to `CreateFile2` to
     /// the specified value (or combines it with `custom_flags` and `attributes`
     /// to set the `dwFlagsAndAttributes` for `CreateFile`).
+    ///
+    /// These flags only take effect when opening the client side of a named
+    /// pipe, where they control the impersonation level granted to the server.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// extern crate winapi;
+    /// use std::fs::OpenOptions;
+    /// use std::os::windows::fs::OpenOptionsExt;
+    ///
+    /// let file = OpenOptions::new().write(true)
+    ///                              .security_qos_flags(winapi::SECURITY_IDENTIFICATION)
+    ///                              .open(r"\\.\pipe\MyPipe");
+    /// ```
     #[stable(feature = "open_options_ext", since = "1.10.0")]
     fn security_qos_flags(&mut self, flags: u32) -> &mut OpenOptions;
 }
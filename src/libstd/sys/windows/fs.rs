@@ -13,7 +13,7 @@ use os::windows::prelude::*;
 
 use ffi::OsString;
 use fmt;
-use io::{self, Error, SeekFrom};
+use io::{self, Error, IoSlice, IoSliceMut, SeekFrom};
 use mem;
 use path::{Path, PathBuf};
 use ptr;
@@ -285,6 +285,44 @@ impl File {
         Ok(())
     }
 
+    pub fn lock_shared(&self) -> io::Result<()> {
+        self.lock(0)
+    }
+
+    pub fn lock_exclusive(&self) -> io::Result<()> {
+        self.lock(c::LOCKFILE_EXCLUSIVE_LOCK)
+    }
+
+    pub fn try_lock_shared(&self) -> io::Result<bool> {
+        self.try_lock(0)
+    }
+
+    pub fn try_lock_exclusive(&self) -> io::Result<bool> {
+        self.try_lock(c::LOCKFILE_EXCLUSIVE_LOCK)
+    }
+
+    pub fn unlock(&self) -> io::Result<()> {
+        cvt(unsafe { c::UnlockFile(self.handle.raw(), 0, 0, !0, !0) })?;
+        Ok(())
+    }
+
+    fn try_lock(&self, flags: c::DWORD) -> io::Result<bool> {
+        match self.lock(flags | c::LOCKFILE_FAIL_IMMEDIATELY) {
+            Ok(()) => Ok(true),
+            Err(ref e) if e.raw_os_error() == Some(c::ERROR_LOCK_VIOLATION as i32) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    // Locks the whole file, including any part of it that doesn't exist yet.
+    fn lock(&self, flags: c::DWORD) -> io::Result<()> {
+        unsafe {
+            let mut overlapped: c::OVERLAPPED = mem::zeroed();
+            cvt(c::LockFileEx(self.handle.raw(), flags, 0, !0, !0, &mut overlapped))?;
+        }
+        Ok(())
+    }
+
     pub fn file_attr(&self) -> io::Result<FileAttr> {
         unsafe {
             let mut info: c::BY_HANDLE_FILE_INFORMATION = mem::zeroed();
@@ -312,6 +350,14 @@ impl File {
         self.handle.read(buf)
     }
 
+    pub fn read_vectored(&self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        // Scatter reads on Windows (`ReadFileScatter`) require unbuffered,
+        // overlapped handles with page-sized buffers, so just fill the first
+        // nonempty buffer like a plain `read`.
+        let buf = bufs.iter_mut().find(|b| !b.is_empty()).map_or(&mut [][..], |b| &mut **b);
+        self.read(buf)
+    }
+
     pub fn read_to_end(&self, buf: &mut Vec<u8>) -> io::Result<usize> {
         self.handle.read_to_end(buf)
     }
@@ -320,6 +366,12 @@ impl File {
         self.handle.write(buf)
     }
 
+    pub fn write_vectored(&self, bufs: &[IoSlice]) -> io::Result<usize> {
+        // See `read_vectored` for why `WriteFileGather` is not used.
+        let buf = bufs.iter().find(|b| !b.is_empty()).map_or(&[][..], |b| &**b);
+        self.write(buf)
+    }
+
     pub fn flush(&self) -> io::Result<()> { Ok(()) }
 
     pub fn seek(&self, pos: SeekFrom) -> io::Result<u64> {
@@ -663,6 +715,13 @@ fn get_path(f: &File) -> io::Result<PathBuf> {
     })
 }
 
+pub fn absolute(p: &Path) -> io::Result<PathBuf> {
+    let path = to_u16s(p)?;
+    super::fill_utf16_buf(|buf, sz| unsafe {
+        c::GetFullPathNameW(path.as_ptr(), sz, buf, ptr::null_mut())
+    }, super::os2path)
+}
+
 pub fn canonicalize(p: &Path) -> io::Result<PathBuf> {
     let mut opts = OpenOptions::new();
     // No read or write permissions are necessary
@@ -0,0 +1,39 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Windows has no way to copy between two arbitrary open handles inside the
+//! kernel (`CopyFileEx` works on paths, and `TransmitFile` only on
+//! overlapped sockets), so `io::copy` always falls back to a buffered loop.
+
+use io;
+use sys::fs::File;
+use sys::net::Socket;
+use sys::pipe::AnonPipe;
+
+/// The outcome of an attempted in-kernel copy.
+pub enum CopyResult {
+    /// The copy ran to completion, successfully or not.
+    Ended(io::Result<u64>),
+    /// The caller should finish the copy itself, having already copied this
+    /// many bytes.
+    Fallback(u64),
+}
+
+/// One side of a copy.
+#[derive(Clone, Copy)]
+pub enum Endpoint<'a> {
+    File(&'a File),
+    Socket(&'a Socket),
+    Pipe(&'a AnonPipe),
+}
+
+pub fn copy(_reader: Endpoint, _writer: Endpoint) -> CopyResult {
+    CopyResult::Fallback(0)
+}
@@ -0,0 +1,17 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A lock which needs neither initialization nor a stable address.
+//!
+//! The system mutex already fits the bill: an unlocked SRWLock is a single
+//! zeroed word which may be moved around freely, and the critical section
+//! used on Windows XP is allocated lazily and only referenced by pointer.
+
+pub use sys::mutex::Mutex as Lock;
@@ -27,6 +27,9 @@ pub mod dynamic_lib;
 pub mod ext;
 pub mod fs;
 pub mod handle;
+pub mod io;
+pub mod kernel_copy;
+pub mod lock;
 pub mod mutex;
 pub mod net;
 pub mod os;
@@ -63,6 +66,8 @@ pub fn init() {
     }
 }
 
+pub use libc::strlen;
+
 pub fn decode_error_kind(errno: i32) -> ErrorKind {
     match errno as c::DWORD {
         c::ERROR_ACCESS_DENIED => return ErrorKind::PermissionDenied,
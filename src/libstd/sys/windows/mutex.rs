@@ -163,6 +163,7 @@ unsafe impl Send for ReentrantMutex {}
 unsafe impl Sync for ReentrantMutex {}
 
 impl ReentrantMutex {
+    #[allow(deprecated)]
     pub unsafe fn uninitialized() -> ReentrantMutex {
         mem::uninitialized()
     }
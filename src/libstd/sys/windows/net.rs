@@ -13,7 +13,7 @@
 use prelude::v1::*;
 
 use cmp;
-use io::{self, Read};
+use io::{self, IoSlice, IoSliceMut, Read};
 use libc::{c_int, c_void, c_ulong};
 use mem;
 use net::{SocketAddr, Shutdown};
@@ -130,6 +130,63 @@ impl Socket {
         Ok(socket)
     }
 
+    pub fn connect_timeout(&self, addr: &SocketAddr, timeout: Duration) -> io::Result<()> {
+        if timeout.as_secs() == 0 && timeout.subsec_nanos() == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                      "cannot set a 0 duration timeout"));
+        }
+
+        let event = unsafe { c::WSACreateEvent() };
+        if event == c::WSA_INVALID_EVENT {
+            return Err(last_error());
+        }
+        let ret = self.connect_with_event(addr, timeout, event);
+
+        // The socket has to be dissociated from the event before it can be
+        // put back into blocking mode.
+        unsafe {
+            c::WSAEventSelect(self.0, c::WSA_INVALID_EVENT, 0);
+            c::WSACloseEvent(event);
+        }
+        self.set_nonblocking(false)?;
+        ret
+    }
+
+    fn connect_with_event(&self, addr: &SocketAddr, timeout: Duration,
+                          event: c::WSAEVENT) -> io::Result<()> {
+        // Selecting FD_CONNECT also switches the socket to non-blocking
+        // mode, so `connect` returns immediately and the event is signalled
+        // once the attempt has either succeeded or failed.
+        cvt(unsafe { c::WSAEventSelect(self.0, event, c::FD_CONNECT) })?;
+
+        let (addrp, len) = addr.into_inner();
+        match cvt(unsafe { c::connect(self.0, addrp, len) }) {
+            Ok(_) => return Ok(()),
+            Err(ref e) if e.raw_os_error() == Some(c::WSAEWOULDBLOCK) => {}
+            Err(e) => return Err(e),
+        }
+
+        let ret = unsafe {
+            c::WSAWaitForMultipleEvents(1, &event, c::FALSE,
+                                        sys::dur2timeout(timeout), c::FALSE)
+        };
+        match ret {
+            c::WSA_WAIT_FAILED => return Err(last_error()),
+            c::WSA_WAIT_TIMEOUT => {
+                return Err(io::Error::new(io::ErrorKind::TimedOut,
+                                          "connection timed out"))
+            }
+            _ => {}
+        }
+
+        let mut events: c::WSANETWORKEVENTS = unsafe { mem::zeroed() };
+        cvt(unsafe { c::WSAEnumNetworkEvents(self.0, event, &mut events) })?;
+        match events.iErrorCode[c::FD_CONNECT_BIT] {
+            0 => Ok(()),
+            err => Err(io::Error::from_raw_os_error(err)),
+        }
+    }
+
     pub fn duplicate(&self) -> io::Result<Socket> {
         let socket = unsafe {
             let mut info: c::WSAPROTOCOL_INFO = mem::zeroed();
@@ -150,11 +207,19 @@ impl Socket {
     }
 
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.recv_with_flags(buf, 0)
+    }
+
+    pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.recv_with_flags(buf, c::MSG_PEEK)
+    }
+
+    fn recv_with_flags(&self, buf: &mut [u8], flags: c_int) -> io::Result<usize> {
         // On unix when a socket is shut down all further reads return 0, so we
         // do the same on windows to map a shut down socket to returning EOF.
         let len = cmp::min(buf.len(), i32::max_value() as usize) as i32;
         unsafe {
-            match c::recv(self.0, buf.as_mut_ptr() as *mut c_void, len, 0) {
+            match c::recv(self.0, buf.as_mut_ptr() as *mut c_void, len, flags) {
                 -1 if c::WSAGetLastError() == c::WSAESHUTDOWN => Ok(0),
                 -1 => Err(last_error()),
                 n => Ok(n as usize)
@@ -162,11 +227,50 @@ impl Socket {
         }
     }
 
+    pub fn read_vectored(&self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        // `IoSliceMut` has the same layout as `WSABUF`.
+        let len = cmp::min(bufs.len(), c::DWORD::max_value() as usize) as c::DWORD;
+        let mut nread = 0;
+        let mut flags = 0;
+        unsafe {
+            let ret = c::WSARecv(self.0,
+                                 bufs.as_mut_ptr() as *mut c::WSABUF,
+                                 len,
+                                 &mut nread,
+                                 &mut flags,
+                                 ptr::null_mut(),
+                                 None);
+            match ret {
+                0 => Ok(nread as usize),
+                // As with `read`, map a shut down socket to EOF.
+                _ if c::WSAGetLastError() == c::WSAESHUTDOWN => Ok(0),
+                _ => Err(last_error()),
+            }
+        }
+    }
+
     pub fn read_to_end(&self, buf: &mut Vec<u8>) -> io::Result<usize> {
         let mut me = self;
         (&mut me).read_to_end(buf)
     }
 
+    pub fn write_vectored(&self, bufs: &[IoSlice]) -> io::Result<usize> {
+        // `IoSlice` has the same layout as `WSABUF`, and `WSASend` does not
+        // write through the buffer pointers despite its signature.
+        let len = cmp::min(bufs.len(), c::DWORD::max_value() as usize) as c::DWORD;
+        let mut nwritten = 0;
+        let ret = unsafe {
+            c::WSASend(self.0,
+                       bufs.as_ptr() as *const c::WSABUF as *mut c::WSABUF,
+                       len,
+                       &mut nwritten,
+                       0,
+                       ptr::null_mut(),
+                       None)
+        };
+        cvt(ret).map(|_| nwritten as usize)
+    }
+
     pub fn set_timeout(&self, dur: Option<Duration>,
                        kind: c_int) -> io::Result<()> {
         let timeout = match dur {
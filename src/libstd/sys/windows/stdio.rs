@@ -179,6 +179,10 @@ impl io::Write for Stderr {
     fn flush(&mut self) -> io::Result<()> { Ok(()) }
 }
 
+pub fn is_ebadf(err: &io::Error) -> bool {
+    err.raw_os_error() == Some(c::ERROR_INVALID_HANDLE as i32)
+}
+
 impl NoClose {
     fn new(handle: c::HANDLE) -> NoClose {
         NoClose(Some(Handle::new(handle)))
@@ -12,10 +12,12 @@ use prelude::v1::*;
 
 use alloc::boxed::FnBox;
 use io;
-use ffi::CStr;
+use ffi::{CStr, CString};
 use mem;
 use libc::c_void;
 use ptr;
+use slice;
+use str;
 use sys::c;
 use sys::handle::Handle;
 use sys_common::thread::*;
@@ -55,11 +57,40 @@ impl Thread {
         }
     }
 
-    pub fn set_name(_name: &CStr) {
-        // Windows threads are nameless
-        // The names in MSVC debugger are obtained using a "magic" exception,
-        // which requires a use of MS C++ extensions.
+    pub fn set_name(name: &CStr) {
+        // SetThreadDescription is only available starting with Windows 10
+        // 1607. On older systems threads stay nameless, as the names in the
+        // MSVC debugger are otherwise obtained using a "magic" exception which
+        // requires a use of MS C++ extensions.
         // See https://msdn.microsoft.com/en-us/library/xcb2z8hs.aspx
+        if let Ok(name) = str::from_utf8(name.to_bytes()) {
+            let mut name = name.encode_utf16().collect::<Vec<u16>>();
+            name.push(0);
+            unsafe {
+                c::SetThreadDescription(c::GetCurrentThread(), name.as_ptr());
+            }
+        }
+    }
+
+    pub fn get_name() -> Option<CString> {
+        unsafe {
+            let mut ptr = ptr::null_mut();
+            if c::GetThreadDescription(c::GetCurrentThread(), &mut ptr) < 0 {
+                return None
+            }
+            let mut len = 0;
+            while *ptr.offset(len) != 0 {
+                len += 1;
+            }
+            let name = slice::from_raw_parts(ptr, len as usize);
+            let name = String::from_utf16_lossy(name);
+            c::LocalFree(ptr as *mut c_void);
+            if name.is_empty() {
+                None
+            } else {
+                CString::new(name).ok()
+            }
+        }
     }
 
     pub fn join(self) {
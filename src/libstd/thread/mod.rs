@@ -187,6 +187,15 @@ use time::Duration;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::local::{LocalKey, LocalKeyState};
 
+////////////////////////////////////////////////////////////////////////////////
+// Scoped threads
+////////////////////////////////////////////////////////////////////////////////
+
+mod scoped;
+
+#[unstable(feature = "scoped_threads", issue = "0")]
+pub use self::scoped::{scope, Scope, ScopedJoinHandle};
+
 #[unstable(feature = "libstd_thread_internals", issue = "0")]
 #[cfg(target_thread_local)]
 #[doc(hidden)] pub use self::local::elf::Key as __ElfLocalKeyInner;
@@ -218,8 +227,12 @@ impl Builder {
         }
     }
 
-    /// Names the thread-to-be. Currently the name is used for identification
-    /// only in panic messages.
+    /// Names the thread-to-be. The name is used for identification in panic
+    /// messages, and is also passed on to the operating system where this is
+    /// supported, so that debuggers and profilers can display it.
+    ///
+    /// Platforms may truncate the name the OS sees; Linux, for example, keeps
+    /// only the first 15 bytes. `Thread::name` always returns the full name.
     ///
     /// # Examples
     ///
@@ -329,6 +342,34 @@ pub fn current() -> Thread {
                                           data has been destroyed")
 }
 
+/// Returns the name the operating system associates with the current thread.
+///
+/// This is the name installed by `Builder::name`, possibly truncated, unless
+/// it was changed by other code in the meantime. Threads which were not named
+/// may report a platform-specific default, such as the executable name on
+/// Linux.
+///
+/// Returns `None` if the platform has no notion of thread names or doesn't
+/// allow reading them back.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(thread_os_name)]
+///
+/// use std::thread;
+///
+/// thread::Builder::new().name("worker".into()).spawn(|| {
+///     if let Some(name) = thread::current_os_name() {
+///         assert_eq!(name, "worker");
+///     }
+/// }).unwrap().join().unwrap();
+/// ```
+#[unstable(feature = "thread_os_name", issue = "0")]
+pub fn current_os_name() -> Option<String> {
+    imp::Thread::get_name().and_then(|name| name.into_string().ok())
+}
+
 /// Cooperatively gives up a timeslice to the OS scheduler.
 #[stable(feature = "rust1", since = "1.0.0")]
 pub fn yield_now() {
@@ -759,6 +800,14 @@ mod tests {
         }).unwrap().join().unwrap();
     }
 
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "android", target_os = "macos"))]
+    fn test_os_thread_name() {
+        Builder::new().name("ada lovelace".to_string()).spawn(move|| {
+            assert_eq!(thread::current_os_name().unwrap(), "ada lovelace");
+        }).unwrap().join().unwrap();
+    }
+
     #[test]
     #[should_panic]
     fn test_invalid_named_thread() {
@@ -0,0 +1,415 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Scoped threads, which may borrow from the stack of the thread spawning
+//! them.
+//!
+//! The previous `thread::scoped` API relied on the destructor of its join
+//! guard to wait for the thread, which is unsound because destructors can be
+//! skipped with `mem::forget`. Here `scope` itself waits for every thread
+//! spawned in it before returning. Each thread reports to its scope when it
+//! finishes, independently of its join handle, so this holds even if a handle
+//! is forgotten; the thread's return value is leaked along with the handle.
+
+use prelude::v1::*;
+
+use cell::UnsafeCell;
+use fmt;
+use io;
+use marker::PhantomData;
+use panic;
+use sync::Arc;
+use sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use sys::thread as imp;
+use sys_common::thread_info;
+use sys_common::util;
+use super::{current, park, Builder, Result, Thread};
+
+/// A scope to spawn scoped threads in.
+///
+/// See [`scope`] for details.
+///
+/// [`scope`]: fn.scope.html
+#[unstable(feature = "scoped_threads", issue = "0")]
+pub struct Scope<'env> {
+    data: Arc<ScopeData>,
+    // Invariant over `'env`, so that the scope can't be used with a shorter
+    // lifetime than the one `scope` was called with.
+    _env: PhantomData<&'env mut &'env ()>,
+}
+
+// Shared between the scope and the packets of its threads. The threads may
+// still use this after `scope` has returned, so it lives in an `Arc`.
+struct ScopeData {
+    num_running_threads: AtomicUsize,
+    a_thread_panicked: AtomicBool,
+    main_thread: Thread,
+}
+
+impl ScopeData {
+    fn increment_num_running_threads(&self) {
+        self.num_running_threads.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn decrement_num_running_threads(&self) {
+        if self.num_running_threads.fetch_sub(1, Ordering::Release) == 1 {
+            self.main_thread.unpark();
+        }
+    }
+}
+
+/// An owned permission to join on a scoped thread (block on its termination).
+///
+/// This `struct` is created by the [`Scope::spawn`] method and the
+/// [`Builder::spawn_scoped`] method. Unlike a `JoinHandle`, dropping it
+/// doesn't detach the thread: the enclosing [`scope`] still waits for it.
+///
+/// [`Scope::spawn`]: struct.Scope.html#method.spawn
+/// [`Builder::spawn_scoped`]: struct.Builder.html#method.spawn_scoped
+/// [`scope`]: fn.scope.html
+#[unstable(feature = "scoped_threads", issue = "0")]
+pub struct ScopedJoinHandle<'scope, T> {
+    native: Option<imp::Thread>,
+    thread: Thread,
+    packet: Arc<Packet<T>>,
+    _scope: PhantomData<&'scope ()>,
+}
+
+// The return value of a scoped thread. The value may borrow from the scope's
+// environment, so the thread holds on to its reference until it has finished
+// running: if the handle is gone by then, the value is dropped before the
+// scope can return. A panic that nobody joined on is reported to the scope at
+// the same time.
+struct Packet<T> {
+    scope: Arc<ScopeData>,
+    result: UnsafeCell<Option<Result<T>>>,
+}
+
+// Like `thread::Packet`, synchronization happens through joining the thread,
+// or through dropping the last reference.
+unsafe impl<T: Send> Sync for Packet<T> {}
+
+impl<T> Drop for Packet<T> {
+    fn drop(&mut self) {
+        let unhandled_panic = match unsafe { (*self.result.get()).take() } {
+            Some(Err(_)) => true,
+            _ => false,
+        };
+        if unhandled_panic {
+            self.scope.a_thread_panicked.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Creates a scope for spawning scoped threads.
+///
+/// The function passed to `scope` is given a [`Scope`], which can be used to
+/// spawn threads. Unlike threads created by `thread::spawn`, these threads
+/// can borrow anything that outlives the call to `scope`, because all of
+/// them are automatically joined before `scope` returns, including ones
+/// whose [`ScopedJoinHandle`] was dropped or forgotten.
+///
+/// # Panics
+///
+/// If the function passed to `scope` panics, `scope` waits for the threads
+/// and then continues unwinding with that panic. Otherwise, if any thread
+/// panicked and its handle was dropped without joining it, `scope` panics
+/// once all threads finished.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(scoped_threads)]
+///
+/// use std::thread;
+///
+/// let mut a = vec![1, 2, 3];
+/// let mut x = 0;
+///
+/// thread::scope(|s| {
+///     s.spawn(|| {
+///         println!("hello from the first scoped thread");
+///         // We can borrow `a` here.
+///         println!("{:?}", a);
+///     });
+///     s.spawn(|| {
+///         println!("hello from the second scoped thread");
+///         // We can even mutably borrow `x` here,
+///         // because no other threads are using it.
+///         x += a[0] + a[2];
+///     });
+///     println!("hello from the main thread");
+/// });
+///
+/// // After the scope, we can modify and access our variables again:
+/// a.push(4);
+/// assert_eq!(x, a.len());
+/// ```
+///
+/// [`Scope`]: struct.Scope.html
+/// [`ScopedJoinHandle`]: struct.ScopedJoinHandle.html
+#[unstable(feature = "scoped_threads", issue = "0")]
+pub fn scope<'env, F, T>(f: F) -> T
+    where F: FnOnce(&Scope<'env>) -> T
+{
+    let scope = Scope {
+        data: Arc::new(ScopeData {
+            num_running_threads: AtomicUsize::new(0),
+            a_thread_panicked: AtomicBool::new(false),
+            main_thread: current(),
+        }),
+        _env: PhantomData,
+    };
+
+    // Run `f`, but catch panics so we can make sure to wait for all the
+    // threads to finish first.
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| f(&scope)));
+
+    while scope.data.num_running_threads.load(Ordering::Acquire) != 0 {
+        park();
+    }
+
+    match result {
+        Err(e) => panic::resume_unwind(e),
+        Ok(_) if scope.data.a_thread_panicked.load(Ordering::Relaxed) => {
+            panic!("a scoped thread panicked")
+        }
+        Ok(result) => result,
+    }
+}
+
+impl<'env> Scope<'env> {
+    /// Spawns a new thread within a scope, returning a [`ScopedJoinHandle`]
+    /// for it.
+    ///
+    /// Unlike non-scoped threads, the closure may borrow non-`'static` data
+    /// from outside the scope. The thread is joined at the end of the scope
+    /// at the latest, or earlier through its join handle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the OS fails to create a thread; use
+    /// [`Builder::spawn_scoped`] to recover from such errors.
+    ///
+    /// [`ScopedJoinHandle`]: struct.ScopedJoinHandle.html
+    /// [`Builder::spawn_scoped`]: struct.Builder.html#method.spawn_scoped
+    #[unstable(feature = "scoped_threads", issue = "0")]
+    pub fn spawn<'scope, F, T>(&'scope self, f: F) -> ScopedJoinHandle<'scope, T>
+        where F: FnOnce() -> T, F: Send + 'env, T: Send + 'env
+    {
+        Builder::new().spawn_scoped(self, f).expect("failed to spawn thread")
+    }
+}
+
+impl Builder {
+    /// Spawns a new scoped thread using the settings set through this
+    /// `Builder`.
+    ///
+    /// This is the scoped counterpart of `Builder::spawn`; see
+    /// [`Scope::spawn`] for how scoped threads differ.
+    ///
+    /// # Errors
+    ///
+    /// Unlike `Scope::spawn`, this method yields an `io::Result` to capture
+    /// any failure to create the thread at the OS level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(scoped_threads)]
+    ///
+    /// use std::thread;
+    ///
+    /// let mut a = vec![1, 2, 3];
+    ///
+    /// thread::scope(|s| {
+    ///     thread::Builder::new()
+    ///         .name("first".to_string())
+    ///         .spawn_scoped(s, || {
+    ///             println!("hello from the {:?} scoped thread", thread::current().name());
+    ///             a.push(4);
+    ///         })
+    ///         .unwrap();
+    /// });
+    /// assert_eq!(a.len(), 4);
+    /// ```
+    ///
+    /// [`Scope::spawn`]: struct.Scope.html#method.spawn
+    #[unstable(feature = "scoped_threads", issue = "0")]
+    pub fn spawn_scoped<'scope, 'env, F, T>(self, scope: &'scope Scope<'env>, f: F)
+                                            -> io::Result<ScopedJoinHandle<'scope, T>>
+        where F: FnOnce() -> T, F: Send + 'env, T: Send + 'env
+    {
+        let Builder { name, stack_size } = self;
+
+        let stack_size = stack_size.unwrap_or(util::min_stack());
+
+        let my_thread = Thread::new(name);
+        let their_thread = my_thread.clone();
+
+        let my_packet = Arc::new(Packet {
+            scope: scope.data.clone(),
+            result: UnsafeCell::new(None),
+        });
+        let their_packet = my_packet.clone();
+        let their_scope = scope.data.clone();
+
+        let main = move || {
+            if let Some(name) = their_thread.cname() {
+                imp::Thread::set_name(name);
+            }
+            unsafe {
+                thread_info::set(imp::guard::current(), their_thread);
+                let try_result = panic::catch_unwind(panic::AssertUnwindSafe(f));
+                *their_packet.result.get() = Some(try_result);
+            }
+            // If the handle was already dropped, this drops the result.
+            drop(their_packet);
+            // This may let `scope` return, so nothing borrowed from `'env`
+            // may be used past this point.
+            their_scope.decrement_num_running_threads();
+        };
+
+        // Counted before the thread starts, so that it can't finish first.
+        scope.data.increment_num_running_threads();
+
+        let native = match unsafe { imp::Thread::new(stack_size, Box::new(main)) } {
+            Ok(native) => native,
+            Err(e) => {
+                scope.data.decrement_num_running_threads();
+                return Err(e);
+            }
+        };
+
+        Ok(ScopedJoinHandle {
+            native: Some(native),
+            thread: my_thread,
+            packet: my_packet,
+            _scope: PhantomData,
+        })
+    }
+}
+
+impl<'scope, T> ScopedJoinHandle<'scope, T> {
+    /// Extracts a handle to the underlying thread.
+    #[unstable(feature = "scoped_threads", issue = "0")]
+    pub fn thread(&self) -> &Thread {
+        &self.thread
+    }
+
+    /// Waits for the associated thread to finish.
+    ///
+    /// If the child thread panics, `Err` is returned with the parameter given
+    /// to `panic`, and the enclosing `scope` won't panic because of it.
+    #[unstable(feature = "scoped_threads", issue = "0")]
+    pub fn join(mut self) -> Result<T> {
+        self.native.take().unwrap().join();
+        unsafe {
+            (*self.packet.result.get()).take().unwrap()
+        }
+    }
+}
+
+#[unstable(feature = "scoped_threads", issue = "0")]
+impl<'env> fmt::Debug for Scope<'env> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Scope")
+         .field("num_running_threads",
+                &self.data.num_running_threads.load(Ordering::Relaxed))
+         .field("main_thread", &self.data.main_thread)
+         .finish()
+    }
+}
+
+#[unstable(feature = "scoped_threads", issue = "0")]
+impl<'scope, T> fmt::Debug for ScopedJoinHandle<'scope, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ScopedJoinHandle")
+         .field("thread", &self.thread)
+         .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::v1::*;
+
+    use mem;
+    use panic;
+    use sync::atomic::{AtomicUsize, Ordering};
+    use thread;
+    use time::Duration;
+
+    #[test]
+    fn borrow_from_stack() {
+        let mut data = vec![1, 2, 3];
+        let counter = AtomicUsize::new(0);
+        thread::scope(|s| {
+            for &x in &data {
+                let counter = &counter;
+                s.spawn(move || {
+                    counter.fetch_add(x, Ordering::SeqCst);
+                });
+            }
+        });
+        assert_eq!(counter.load(Ordering::SeqCst), 6);
+
+        thread::scope(|s| {
+            s.spawn(|| data.push(4));
+        });
+        assert_eq!(data, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn waits_for_forgotten_threads() {
+        let done = AtomicUsize::new(0);
+        thread::scope(|s| {
+            let handle = s.spawn(|| {
+                thread::sleep(Duration::from_millis(10));
+                done.store(1, Ordering::SeqCst);
+            });
+            mem::forget(handle);
+        });
+        assert_eq!(done.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn join_returns_value() {
+        let x = 5;
+        let r = thread::scope(|s| {
+            let a = s.spawn(|| x * 2);
+            let b = s.spawn(|| -> i32 { panic!() });
+            assert!(b.join().is_err());
+            a.join().unwrap()
+        });
+        assert_eq!(r, 10);
+    }
+
+    #[test]
+    fn unjoined_panic_propagates() {
+        let result = panic::catch_unwind(|| {
+            thread::scope(|s| {
+                s.spawn(|| panic!());
+            });
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn named_scoped_thread() {
+        thread::scope(|s| {
+            let h = thread::Builder::new().name("scoped".to_string()).spawn_scoped(s, || {
+                assert_eq!(thread::current().name(), Some("scoped"));
+            }).unwrap();
+            assert_eq!(h.thread().name(), Some("scoped"));
+            h.join().unwrap();
+        });
+    }
+}
@@ -12,7 +12,9 @@ use ops::{Add, Sub, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign};
 
 const NANOS_PER_SEC: u32 = 1_000_000_000;
 const NANOS_PER_MILLI: u32 = 1_000_000;
+const NANOS_PER_MICRO: u32 = 1_000;
 const MILLIS_PER_SEC: u64 = 1_000;
+const MICROS_PER_SEC: u64 = 1_000_000;
 
 /// A duration type to represent a span of time, typically used for system
 /// timeouts.
@@ -81,6 +83,86 @@ impl Duration {
         Duration { secs: secs, nanos: nanos }
     }
 
+    /// Creates a new `Duration` from the specified number of microseconds.
+    #[unstable(feature = "duration_extras", issue = "0")]
+    #[inline]
+    pub fn from_micros(micros: u64) -> Duration {
+        let secs = micros / MICROS_PER_SEC;
+        let nanos = ((micros % MICROS_PER_SEC) as u32) * NANOS_PER_MICRO;
+        Duration { secs: secs, nanos: nanos }
+    }
+
+    /// Creates a new `Duration` from the specified number of nanoseconds.
+    #[unstable(feature = "duration_extras", issue = "0")]
+    #[inline]
+    pub fn from_nanos(nanos: u64) -> Duration {
+        let secs = nanos / (NANOS_PER_SEC as u64);
+        let nanos = (nanos % (NANOS_PER_SEC as u64)) as u32;
+        Duration { secs: secs, nanos: nanos }
+    }
+
+    /// Creates a new `Duration` from the specified number of seconds
+    /// represented as `f64`.
+    ///
+    /// The fractional part is truncated to whole nanoseconds.
+    ///
+    /// # Panics
+    ///
+    /// This constructor will panic if `secs` is negative, not finite, or
+    /// overflows `Duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_float)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::from_secs_f64(2.5);
+    /// assert_eq!(dur, Duration::new(2, 500_000_000));
+    /// ```
+    #[unstable(feature = "duration_float", issue = "0")]
+    #[inline]
+    pub fn from_secs_f64(secs: f64) -> Duration {
+        // 2^64, the first value which doesn't fit into the seconds counter.
+        const MAX_SECS: f64 = 18446744073709551616.0;
+        if !secs.is_finite() {
+            panic!("got non-finite value when converting float to duration");
+        }
+        if secs < 0.0 {
+            panic!("got negative value when converting float to duration");
+        }
+        if secs >= MAX_SECS {
+            panic!("overflow when converting float to duration");
+        }
+        let whole = secs.trunc();
+        let nanos = ((secs - whole) * (NANOS_PER_SEC as f64)) as u32;
+        // `nanos` may have rounded up to a full second, which `new` carries.
+        Duration::new(whole as u64, nanos)
+    }
+
+    /// Creates a new `Duration` from the specified number of seconds
+    /// represented as `f32`.
+    ///
+    /// # Panics
+    ///
+    /// This constructor will panic if `secs` is negative, not finite, or
+    /// overflows `Duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_float)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::from_secs_f32(0.25);
+    /// assert_eq!(dur, Duration::from_millis(250));
+    /// ```
+    #[unstable(feature = "duration_float", issue = "0")]
+    #[inline]
+    pub fn from_secs_f32(secs: f32) -> Duration {
+        Duration::from_secs_f64(secs as f64)
+    }
+
     /// Returns the number of whole seconds represented by this duration.
     ///
     /// The extra precision represented by this duration is ignored (e.g. extra
@@ -97,22 +179,232 @@ impl Duration {
     #[stable(feature = "duration", since = "1.3.0")]
     #[inline]
     pub fn subsec_nanos(&self) -> u32 { self.nanos }
-}
 
-#[stable(feature = "duration", since = "1.3.0")]
-impl Add for Duration {
-    type Output = Duration;
+    /// Returns the fractional part of this duration, in whole milliseconds.
+    ///
+    /// Like `subsec_nanos`, this is always less than one thousand and does
+    /// not include the whole seconds.
+    #[unstable(feature = "duration_extras", issue = "0")]
+    #[inline]
+    pub fn subsec_millis(&self) -> u32 { self.nanos / NANOS_PER_MILLI }
 
-    fn add(self, rhs: Duration) -> Duration {
-        let mut secs = self.secs.checked_add(rhs.secs)
-                           .expect("overflow when adding durations");
+    /// Returns the fractional part of this duration, in whole microseconds.
+    ///
+    /// Like `subsec_nanos`, this is always less than one million and does
+    /// not include the whole seconds.
+    #[unstable(feature = "duration_extras", issue = "0")]
+    #[inline]
+    pub fn subsec_micros(&self) -> u32 { self.nanos / NANOS_PER_MICRO }
+
+    /// Returns the total number of whole milliseconds in this duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result doesn't fit into a `u64`, which only happens for
+    /// durations of more than about 584 million years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_extras)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(5, 730023852);
+    /// assert_eq!(dur.as_millis(), 5730);
+    /// ```
+    #[unstable(feature = "duration_extras", issue = "0")]
+    #[inline]
+    pub fn as_millis(&self) -> u64 {
+        self.secs.checked_mul(MILLIS_PER_SEC)
+            .and_then(|ms| ms.checked_add(self.subsec_millis() as u64))
+            .expect("overflow when converting duration to milliseconds")
+    }
+
+    /// Returns the total number of whole microseconds in this duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result doesn't fit into a `u64`, which only happens for
+    /// durations of more than about 584 thousand years.
+    #[unstable(feature = "duration_extras", issue = "0")]
+    #[inline]
+    pub fn as_micros(&self) -> u64 {
+        self.secs.checked_mul(MICROS_PER_SEC)
+            .and_then(|us| us.checked_add(self.subsec_micros() as u64))
+            .expect("overflow when converting duration to microseconds")
+    }
+
+    /// Returns the total number of nanoseconds in this duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result doesn't fit into a `u64`, which happens for
+    /// durations of more than about 584 years.
+    #[unstable(feature = "duration_extras", issue = "0")]
+    #[inline]
+    pub fn as_nanos(&self) -> u64 {
+        self.secs.checked_mul(NANOS_PER_SEC as u64)
+            .and_then(|ns| ns.checked_add(self.nanos as u64))
+            .expect("overflow when converting duration to nanoseconds")
+    }
+
+    /// Returns the number of seconds in this duration as an `f64`, including
+    /// the fractional part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_float)]
+    /// use std::time::Duration;
+    ///
+    /// let dur = Duration::new(2, 500_000_000);
+    /// assert_eq!(dur.as_secs_f64(), 2.5);
+    /// ```
+    #[unstable(feature = "duration_float", issue = "0")]
+    #[inline]
+    pub fn as_secs_f64(&self) -> f64 {
+        (self.secs as f64) + (self.nanos as f64) / (NANOS_PER_SEC as f64)
+    }
+
+    /// Returns the number of seconds in this duration as an `f32`, including
+    /// the fractional part.
+    #[unstable(feature = "duration_float", issue = "0")]
+    #[inline]
+    pub fn as_secs_f32(&self) -> f32 {
+        (self.secs as f32) + (self.nanos as f32) / (NANOS_PER_SEC as f32)
+    }
+
+    /// Checked `Duration` addition. Computes `self + other`, returning `None`
+    /// if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_checked_ops)]
+    /// use std::time::Duration;
+    /// use std::u64;
+    ///
+    /// assert_eq!(Duration::new(0, 0).checked_add(Duration::new(0, 1)),
+    ///            Some(Duration::new(0, 1)));
+    /// assert_eq!(Duration::new(1, 0).checked_add(Duration::new(u64::MAX, 0)), None);
+    /// ```
+    #[unstable(feature = "duration_checked_ops", issue = "0")]
+    #[inline]
+    pub fn checked_add(self, rhs: Duration) -> Option<Duration> {
+        let mut secs = match self.secs.checked_add(rhs.secs) {
+            Some(secs) => secs,
+            None => return None,
+        };
         let mut nanos = self.nanos + rhs.nanos;
         if nanos >= NANOS_PER_SEC {
             nanos -= NANOS_PER_SEC;
-            secs = secs.checked_add(1).expect("overflow when adding durations");
+            secs = match secs.checked_add(1) {
+                Some(secs) => secs,
+                None => return None,
+            };
         }
         debug_assert!(nanos < NANOS_PER_SEC);
-        Duration { secs: secs, nanos: nanos }
+        Some(Duration { secs: secs, nanos: nanos })
+    }
+
+    /// Checked `Duration` subtraction. Computes `self - other`, returning
+    /// `None` if the result would be negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_checked_ops)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(0, 1).checked_sub(Duration::new(0, 0)),
+    ///            Some(Duration::new(0, 1)));
+    /// assert_eq!(Duration::new(0, 0).checked_sub(Duration::new(0, 1)), None);
+    /// ```
+    #[unstable(feature = "duration_checked_ops", issue = "0")]
+    #[inline]
+    pub fn checked_sub(self, rhs: Duration) -> Option<Duration> {
+        let mut secs = match self.secs.checked_sub(rhs.secs) {
+            Some(secs) => secs,
+            None => return None,
+        };
+        let nanos = if self.nanos >= rhs.nanos {
+            self.nanos - rhs.nanos
+        } else {
+            secs = match secs.checked_sub(1) {
+                Some(secs) => secs,
+                None => return None,
+            };
+            self.nanos + NANOS_PER_SEC - rhs.nanos
+        };
+        debug_assert!(nanos < NANOS_PER_SEC);
+        Some(Duration { secs: secs, nanos: nanos })
+    }
+
+    /// Checked `Duration` multiplication. Computes `self * other`, returning
+    /// `None` if overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_checked_ops)]
+    /// use std::time::Duration;
+    /// use std::u64;
+    ///
+    /// assert_eq!(Duration::new(0, 500_000_001).checked_mul(2),
+    ///            Some(Duration::new(1, 2)));
+    /// assert_eq!(Duration::new(u64::MAX - 1, 0).checked_mul(2), None);
+    /// ```
+    #[unstable(feature = "duration_checked_ops", issue = "0")]
+    #[inline]
+    pub fn checked_mul(self, rhs: u32) -> Option<Duration> {
+        // Multiply nanoseconds as u64, because it cannot overflow that way.
+        let total_nanos = self.nanos as u64 * rhs as u64;
+        let extra_secs = total_nanos / (NANOS_PER_SEC as u64);
+        let nanos = (total_nanos % (NANOS_PER_SEC as u64)) as u32;
+        match self.secs.checked_mul(rhs as u64).and_then(|s| s.checked_add(extra_secs)) {
+            Some(secs) => {
+                debug_assert!(nanos < NANOS_PER_SEC);
+                Some(Duration { secs: secs, nanos: nanos })
+            }
+            None => None,
+        }
+    }
+
+    /// Checked `Duration` division. Computes `self / other`, returning `None`
+    /// if `other == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_checked_ops)]
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(2, 0).checked_div(2), Some(Duration::new(1, 0)));
+    /// assert_eq!(Duration::new(1, 0).checked_div(2),
+    ///            Some(Duration::new(0, 500_000_000)));
+    /// assert_eq!(Duration::new(2, 0).checked_div(0), None);
+    /// ```
+    #[unstable(feature = "duration_checked_ops", issue = "0")]
+    #[inline]
+    pub fn checked_div(self, rhs: u32) -> Option<Duration> {
+        if rhs == 0 {
+            return None;
+        }
+        let secs = self.secs / (rhs as u64);
+        let carry = self.secs - secs * (rhs as u64);
+        let extra_nanos = carry * (NANOS_PER_SEC as u64) / (rhs as u64);
+        let nanos = self.nanos / rhs + (extra_nanos as u32);
+        debug_assert!(nanos < NANOS_PER_SEC);
+        Some(Duration { secs: secs, nanos: nanos })
+    }
+}
+
+#[stable(feature = "duration", since = "1.3.0")]
+impl Add for Duration {
+    type Output = Duration;
+
+    fn add(self, rhs: Duration) -> Duration {
+        self.checked_add(rhs).expect("overflow when adding durations")
     }
 }
 
@@ -128,17 +420,7 @@ impl Sub for Duration {
     type Output = Duration;
 
     fn sub(self, rhs: Duration) -> Duration {
-        let mut secs = self.secs.checked_sub(rhs.secs)
-                           .expect("overflow when subtracting durations");
-        let nanos = if self.nanos >= rhs.nanos {
-            self.nanos - rhs.nanos
-        } else {
-            secs = secs.checked_sub(1)
-                       .expect("overflow when subtracting durations");
-            self.nanos + NANOS_PER_SEC - rhs.nanos
-        };
-        debug_assert!(nanos < NANOS_PER_SEC);
-        Duration { secs: secs, nanos: nanos }
+        self.checked_sub(rhs).expect("overflow when subtracting durations")
     }
 }
 
@@ -154,15 +436,7 @@ impl Mul<u32> for Duration {
     type Output = Duration;
 
     fn mul(self, rhs: u32) -> Duration {
-        // Multiply nanoseconds as u64, because it cannot overflow that way.
-        let total_nanos = self.nanos as u64 * rhs as u64;
-        let extra_secs = total_nanos / (NANOS_PER_SEC as u64);
-        let nanos = (total_nanos % (NANOS_PER_SEC as u64)) as u32;
-        let secs = self.secs.checked_mul(rhs as u64)
-                       .and_then(|s| s.checked_add(extra_secs))
-                       .expect("overflow when multiplying duration");
-        debug_assert!(nanos < NANOS_PER_SEC);
-        Duration { secs: secs, nanos: nanos }
+        self.checked_mul(rhs).expect("overflow when multiplying duration by scalar")
     }
 }
 
@@ -178,12 +452,7 @@ impl Div<u32> for Duration {
     type Output = Duration;
 
     fn div(self, rhs: u32) -> Duration {
-        let secs = self.secs / (rhs as u64);
-        let carry = self.secs - secs * (rhs as u64);
-        let extra_nanos = carry * (NANOS_PER_SEC as u64) / (rhs as u64);
-        let nanos = self.nanos / rhs + (extra_nanos as u32);
-        debug_assert!(nanos < NANOS_PER_SEC);
-        Duration { secs: secs, nanos: nanos }
+        self.checked_div(rhs).expect("divide by zero error when dividing duration by scalar")
     }
 }
 
@@ -270,4 +539,67 @@ mod tests {
         assert_eq!(Duration::new(99, 999_999_000) / 100,
                    Duration::new(0, 999_999_990));
     }
+
+    #[test]
+    fn creation_from_small_units() {
+        assert_eq!(Duration::from_micros(1_000_001), Duration::new(1, 1_000));
+        assert_eq!(Duration::from_nanos(1_000_000_001), Duration::new(1, 1));
+        assert_eq!(Duration::from_nanos(999), Duration::new(0, 999));
+    }
+
+    #[test]
+    fn total_units() {
+        let dur = Duration::new(5, 730_023_852);
+        assert_eq!(dur.subsec_millis(), 730);
+        assert_eq!(dur.subsec_micros(), 730_023);
+        assert_eq!(dur.as_millis(), 5_730);
+        assert_eq!(dur.as_micros(), 5_730_023);
+        assert_eq!(dur.as_nanos(), 5_730_023_852);
+        assert_eq!(Duration::from_secs(::u64::MAX / 1000).as_millis(),
+                   ::u64::MAX / 1000 * 1000);
+    }
+
+    #[test] #[should_panic]
+    fn as_nanos_overflow() {
+        Duration::from_secs(::u64::MAX / 1_000_000_000 + 1).as_nanos();
+    }
+
+    #[test]
+    fn floats() {
+        assert_eq!(Duration::from_secs_f64(0.0), Duration::new(0, 0));
+        assert_eq!(Duration::from_secs_f64(1.5), Duration::new(1, 500_000_000));
+        assert_eq!(Duration::from_secs_f32(0.125), Duration::from_millis(125));
+        assert_eq!(Duration::new(3, 250_000_000).as_secs_f64(), 3.25);
+        assert_eq!(Duration::new(0, 500_000_000).as_secs_f32(), 0.5);
+    }
+
+    #[test] #[should_panic]
+    fn from_negative_float() {
+        Duration::from_secs_f64(-1.0);
+    }
+
+    #[test] #[should_panic]
+    fn from_nan() {
+        Duration::from_secs_f64(::f64::NAN);
+    }
+
+    #[test] #[should_panic]
+    fn from_huge_float() {
+        Duration::from_secs_f64(1e20);
+    }
+
+    #[test]
+    fn checked_ops() {
+        assert_eq!(Duration::new(0, 500_000_000).checked_add(Duration::new(0, 500_000_001)),
+                   Some(Duration::new(1, 1)));
+        assert_eq!(Duration::new(::u64::MAX, 999_999_999).checked_add(Duration::new(0, 1)),
+                   None);
+        assert_eq!(Duration::new(1, 0).checked_sub(Duration::new(0, 1)),
+                   Some(Duration::new(0, 999_999_999)));
+        assert_eq!(Duration::new(0, 0).checked_sub(Duration::new(0, 1)), None);
+        assert_eq!(Duration::new(1, 1).checked_mul(3), Some(Duration::new(3, 3)));
+        assert_eq!(Duration::new(::u64::MAX, 0).checked_mul(2), None);
+        assert_eq!(Duration::new(1, 1).checked_div(3), Some(Duration::new(0, 333_333_333)));
+        assert_eq!(Duration::new(1, 0).checked_div(0), None);
+    }
 }
@@ -988,7 +988,7 @@ pub enum ExprKind {
     /// A `return`, with an optional value to be returned
     Ret(Option<P<Expr>>),
 
-    /// Output of the `asm!()` macro
+    /// Output of the `asm!()` and `llvm_asm!()` macros
     InlineAsm(InlineAsm),
 
     /// A macro invocation; pre-expansion
@@ -1388,7 +1388,7 @@ pub enum TyKind {
 
 /// Inline assembly dialect.
 ///
-/// E.g. `"intel"` as in `asm!("mov eax, 2" : "={eax}"(result) : : : "intel")``
+/// E.g. `"intel"` as in `llvm_asm!("mov eax, 2" : "={eax}"(result) : : : "intel")``
 #[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug, Copy)]
 pub enum AsmDialect {
     Att,
@@ -1397,7 +1397,7 @@ pub enum AsmDialect {
 
 /// Inline assembly.
 ///
-/// E.g. `"={eax}"(result)` as in `asm!("mov eax, 2" : "={eax}"(result) : : : "intel")``
+/// E.g. `"={eax}"(result)` as in `llvm_asm!("mov eax, 2" : "={eax}"(result) : : : "intel")``
 #[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
 pub struct InlineAsmOutput {
     pub constraint: InternedString,
@@ -1406,9 +1406,9 @@ pub struct InlineAsmOutput {
     pub is_indirect: bool,
 }
 
-/// Inline assembly.
+/// Inline assembly, in the form LLVM takes it.
 ///
-/// E.g. `asm!("NOP");`
+/// E.g. `llvm_asm!("NOP");`
 #[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
 pub struct InlineAsm {
     pub asm: InternedString,
@@ -1420,6 +1420,9 @@ pub struct InlineAsm {
     pub alignstack: bool,
     pub dialect: AsmDialect,
     pub expn_id: ExpnId,
+    /// The register class of each operand of an `asm!`, outputs first, which
+    /// the operand types are checked against. Empty for `llvm_asm!`.
+    pub operand_classes: Vec<InternedString>,
 }
 
 /// An argument in a function header.
@@ -1871,6 +1874,12 @@ pub enum ItemKind {
     ///
     /// E.g. `trait Foo { .. }` or `trait Foo<T> { .. }`
     Trait(Unsafety, Generics, TyParamBounds, Vec<TraitItem>),
+    /// A trait alias (`trait` or `pub trait`).
+    ///
+    /// E.g. `trait Foo = Bar + Quux;`. These are desugared during expansion
+    /// into a trait with the aliased bounds as supertraits, plus a blanket
+    /// impl, so they never reach resolution.
+    TraitAlias(Generics, TyParamBounds),
     // Default trait implementation.
     ///
     /// E.g. `impl Trait for .. {}` or `impl<T> Trait<T> for .. {}`
@@ -1904,6 +1913,7 @@ impl ItemKind {
             ItemKind::Enum(..) => "enum",
             ItemKind::Struct(..) => "struct",
             ItemKind::Trait(..) => "trait",
+            ItemKind::TraitAlias(..) => "trait alias",
             ItemKind::Mac(..) |
             ItemKind::Impl(..) |
             ItemKind::DefaultImpl(..) => "item"
@@ -506,6 +506,8 @@ pub struct RustcDeprecation {
 pub struct Deprecation {
     pub since: Option<InternedString>,
     pub note: Option<InternedString>,
+    /// The name that uses of the deprecated item should be renamed to.
+    pub suggestion: Option<InternedString>,
 }
 
 impl StabilityLevel {
@@ -738,10 +740,12 @@ fn find_deprecation_generic<'a, I>(diagnostic: &Handler,
 
             let mut since = None;
             let mut note = None;
+            let mut suggestion = None;
             for meta in metas {
                 match &*meta.name() {
                     "since" => if !get(meta, &mut since) { continue 'outer },
                     "note" => if !get(meta, &mut note) { continue 'outer },
+                    "suggestion" => if !get(meta, &mut suggestion) { continue 'outer },
                     _ => {
                         handle_errors(diagnostic, meta.span,
                                       AttrError::UnknownMetaItem(meta.name()));
@@ -750,9 +754,9 @@ fn find_deprecation_generic<'a, I>(diagnostic: &Handler,
                 }
             }
 
-            Some(Deprecation {since: since, note: note})
+            Some(Deprecation {since: since, note: note, suggestion: suggestion})
         } else {
-            Some(Deprecation{since: None, note: None})
+            Some(Deprecation{since: None, note: None, suggestion: None})
         }
     }
 
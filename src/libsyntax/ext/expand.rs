@@ -18,10 +18,11 @@ use codemap::{dummy_spanned, ExpnInfo, NameAndSpan, MacroBang, MacroAttribute};
 use syntax_pos::{self, Span, ExpnId};
 use config::StripUnconfigured;
 use ext::base::*;
+use ext::build::AstBuilder;
 use feature_gate::{self, Features};
 use fold;
 use fold::*;
-use parse::token::{intern, keywords};
+use parse::token::{self, intern, keywords};
 use ptr::P;
 use tokenstream::TokenTree;
 use util::small_vector::SmallVector;
@@ -400,6 +401,10 @@ fn expand_multi_modified(a: Annotatable, fld: &mut MacroExpander) -> SmallVector
                 }
                 result
             },
+            ast::ItemKind::TraitAlias(..) => {
+                expand_trait_alias(it, fld).into_iter()
+                    .flat_map(|it| noop_fold_item(it, fld).into_iter()).collect()
+            }
             _ => noop_fold_item(it, fld),
         }.into_iter().map(|i| Annotatable::Item(i)).collect(),
 
@@ -415,6 +420,64 @@ fn expand_multi_modified(a: Annotatable, fld: &mut MacroExpander) -> SmallVector
     }
 }
 
+/// Desugars a trait alias `trait Alias<P..> = Bounds where W;` into
+///
+/// ```ignore
+/// trait Alias<P..>: Bounds where W {}
+/// impl<P.., T: ?Sized + Bounds> Alias<P..> for T where W {}
+/// ```
+///
+/// so that `T: Alias<P..>` holds exactly when `T: Bounds` does, and the
+/// former implies the latter wherever it is used as a bound.
+fn expand_trait_alias(it: P<ast::Item>, fld: &mut MacroExpander) -> SmallVector<P<ast::Item>> {
+    if !fld.cx.ecfg.features.unwrap().trait_alias {
+        feature_gate::emit_feature_err(
+            &fld.cx.parse_sess.span_diagnostic,
+            "trait_alias",
+            it.span,
+            feature_gate::GateIssue::Language,
+            "trait aliases are experimental");
+    }
+
+    let (generics, bounds) = match it.node {
+        ItemKind::TraitAlias(ref generics, ref bounds) => (generics.clone(), bounds.clone()),
+        _ => unreachable!(),
+    };
+    let span = it.span;
+    let cx = &fld.cx;
+
+    // The implementing type must not clash with the alias' own parameters.
+    let self_ident = token::gensym_ident("T");
+    let sized = cx.path_global(span, cx.std_path(&["marker", "Sized"]));
+    let mut self_bounds = bounds.clone().into_vec();
+    self_bounds.push(ast::TraitTyParamBound(cx.poly_trait_ref(span, sized),
+                                            ast::TraitBoundModifier::Maybe));
+    let mut impl_generics = generics.clone();
+    let mut ty_params: Vec<_> = impl_generics.ty_params.into_vec().into_iter().map(|tp| {
+        ast::TyParam { default: None, ..tp }
+    }).collect();
+    ty_params.push(cx.typaram(span, self_ident, P::from_vec(self_bounds), None));
+    impl_generics.ty_params = P::from_vec(ty_params);
+
+    let trait_path = cx.path_all(span, false, vec![it.ident],
+                                 generics.lifetimes.iter().map(|l| l.lifetime).collect(),
+                                 cx.ty_vars(&generics.ty_params),
+                                 vec![]);
+    let blanket_impl = cx.item(span, keywords::Invalid.ident(), Vec::new(),
+                               ItemKind::Impl(ast::Unsafety::Normal,
+                                              ast::ImplPolarity::Positive,
+                                              impl_generics,
+                                              Some(cx.trait_ref(trait_path)),
+                                              cx.ty_ident(span, self_ident),
+                                              Vec::new()));
+
+    let alias = it.map(|item| ast::Item {
+        node: ItemKind::Trait(ast::Unsafety::Normal, generics, bounds, Vec::new()),
+        ..item
+    });
+    SmallVector::many(vec![alias, blanket_impl])
+}
+
 fn expand_annotatable(mut item: Annotatable, fld: &mut MacroExpander) -> SmallVector<Annotatable> {
     let mut multi_modifier = None;
     item = item.map_attrs(|mut attrs| {
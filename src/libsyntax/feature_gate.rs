@@ -38,6 +38,7 @@ use parse::ParseSess;
 use parse::token::InternedString;
 
 use std::ascii::AsciiExt;
+use std::mem;
 
 macro_rules! setter {
     ($field: ident) => {{
@@ -164,10 +165,6 @@ declare_features! (
     // Allows using `box` in patterns; RFC 469
     (active, box_patterns, "1.0.0", Some(29641)),
 
-    // Allows using the unsafe_no_drop_flag attribute (unlikely to
-    // switch to Accepted; see RFC 320)
-    (active, unsafe_no_drop_flag, "1.0.0", None),
-
     // Allows using the unsafe_destructor_blind_to_params attribute;
     // RFC 1238
     (active, dropck_parametricity, "1.3.0", Some(28498)),
@@ -287,7 +284,25 @@ declare_features! (
     (active, relaxed_adts, "1.12.0", Some(35626)),
 
     // The `!` type
-    (active, never_type, "1.13.0", Some(35121))
+    (active, never_type, "1.13.0", Some(35121)),
+
+    // Allows the `suggestion` field of `#[deprecated]`
+    (active, deprecated_suggestion, "1.13.0", None),
+
+    // Allows scoped names such as `clippy::needless_return` in attributes
+    (active, tool_lints, "1.13.0", None),
+
+    // Allows `#[target_feature(enable = "...")]` on unsafe functions
+    (active, target_feature, "1.13.0", None),
+
+    // Allows `#[used]` to keep statics the linker would otherwise strip
+    (active, used, "1.13.0", None),
+
+    // Allows `impl Trait` in function arguments, as an anonymous type parameter
+    (active, universal_impl_trait, "1.13.0", None),
+
+    // Allows `trait Foo = Bar + Baz;` as an alias for a set of bounds
+    (active, trait_alias, "1.13.0", None)
 );
 
 declare_features! (
@@ -300,6 +315,7 @@ declare_features! (
     (removed, quad_precision_float, "1.0.0", None),
     (removed, struct_inherit, "1.0.0", None),
     (removed, test_removed_feature, "1.0.0", None),
+    (removed, unsafe_no_drop_flag, "1.0.0", None),
     (removed, visible_private_types, "1.0.0", None)
 );
 
@@ -517,11 +533,6 @@ pub const KNOWN_ATTRIBUTES: &'static [(&'static str, AttributeType, AttributeGat
                                       is just used for rustc unit tests \
                                       and will never be stable",
                                      cfg_fn!(rustc_attrs))),
-    ("rustc_no_mir", Whitelisted, Gated("rustc_attrs",
-                                        "the `#[rustc_no_mir]` attribute \
-                                         is just used to make tests pass \
-                                         and will never be stable",
-                                        cfg_fn!(rustc_attrs))),
     ("rustc_inherit_overflow_checks", Whitelisted, Gated("rustc_attrs",
                                                          "the `#[rustc_inherit_overflow_checks]` \
                                                           attribute is just used to control \
@@ -554,6 +565,13 @@ pub const KNOWN_ATTRIBUTES: &'static [(&'static str, AttributeType, AttributeGat
                                  "the `#[naked]` attribute \
                                   is an experimental feature",
                                  cfg_fn!(naked_functions))),
+    ("target_feature", Whitelisted, Gated("target_feature",
+                                          "the `#[target_feature]` attribute \
+                                           is an experimental feature",
+                                          cfg_fn!(target_feature))),
+    ("used", Whitelisted, Gated("used",
+                                "the `#[used]` attribute is an experimental feature",
+                                cfg_fn!(used))),
     ("export_name", Whitelisted, Ungated),
     ("inline", Whitelisted, Ungated),
     ("link", Whitelisted, Ungated),
@@ -570,10 +588,6 @@ pub const KNOWN_ATTRIBUTES: &'static [(&'static str, AttributeType, AttributeGat
                                                         attribute is just used for the Rust test \
                                                         suite",
                                                        cfg_fn!(omit_gdb_pretty_printer_section))),
-    ("unsafe_no_drop_flag", Whitelisted, Gated("unsafe_no_drop_flag",
-                                               "unsafe_no_drop_flag has unstable semantics \
-                                                and may be removed in the future",
-                                               cfg_fn!(unsafe_no_drop_flag))),
     ("unsafe_destructor_blind_to_params",
      Normal,
      Gated("dropck_parametricity",
@@ -809,6 +823,9 @@ pub const EXPLAIN_PLACEMENT_IN: &'static str =
 
 struct PostExpansionVisitor<'a> {
     context: &'a Context<'a>,
+    // Whether we're in the argument types of a function item or method,
+    // where `impl Trait` introduces a type parameter.
+    in_fn_args: bool,
 }
 
 macro_rules! gate_feature_post {
@@ -848,6 +865,23 @@ impl<'a> Visitor for PostExpansionVisitor<'a> {
         if !self.context.cm.span_allows_unstable(attr.span) {
             self.context.check_attribute(attr, false);
         }
+
+        for meta in attr.meta_item_list().unwrap_or(&[]) {
+            if meta.name().contains("::") {
+                gate_feature_post!(&self, tool_lints, meta.span,
+                                   "scoped names in attributes are experimental");
+            }
+        }
+
+        if attr.check_name("deprecated") {
+            for meta in attr.meta_item_list().unwrap_or(&[]) {
+                if meta.check_name("suggestion") {
+                    gate_feature_post!(&self, deprecated_suggestion, meta.span,
+                                       "the `suggestion` field of `#[deprecated]` \
+                                        is experimental");
+                }
+            }
+        }
     }
 
     fn visit_name(&mut self, sp: Span, name: ast::Name) {
@@ -961,6 +995,15 @@ impl<'a> Visitor for PostExpansionVisitor<'a> {
         match ty.node {
             ast::TyKind::BareFn(ref bare_fn_ty) => {
                 self.check_abi(bare_fn_ty.abi, ty.span);
+
+                let in_fn_args = mem::replace(&mut self.in_fn_args, false);
+                visit::walk_ty(self, ty);
+                self.in_fn_args = in_fn_args;
+                return;
+            }
+            ast::TyKind::ImplTrait(..) if self.in_fn_args => {
+                gate_feature_post!(&self, universal_impl_trait, ty.span,
+                                   "`impl Trait` in argument position is experimental");
             }
             ast::TyKind::ImplTrait(..) => {
                 gate_feature_post!(&self, conservative_impl_trait, ty.span,
@@ -976,6 +1019,7 @@ impl<'a> Visitor for PostExpansionVisitor<'a> {
     }
 
     fn visit_fn_ret_ty(&mut self, ret_ty: &ast::FunctionRetTy) {
+        self.in_fn_args = false;
         if let ast::FunctionRetTy::Ty(ref output_ty) = *ret_ty {
             match output_ty.node {
                 ast::TyKind::Never => return,
@@ -1074,12 +1118,29 @@ impl<'a> Visitor for PostExpansionVisitor<'a> {
             FnKind::ItemFn(_, _, _, _, abi, _) |
             FnKind::Method(_, &ast::MethodSig { abi, .. }, _) => {
                 self.check_abi(abi, span);
+                self.in_fn_args = true;
             }
             _ => {}
         }
         visit::walk_fn(self, fn_kind, fn_decl, block, span);
     }
 
+    fn visit_generics(&mut self, generics: &ast::Generics) {
+        let in_fn_args = mem::replace(&mut self.in_fn_args, false);
+        visit::walk_generics(self, generics);
+        self.in_fn_args = in_fn_args;
+    }
+
+    fn visit_path_parameters(&mut self, path_span: Span, parameters: &ast::PathParameters) {
+        // `Fn(impl Trait)` doesn't introduce a type parameter.
+        let in_fn_args = match *parameters {
+            ast::PathParameters::Parenthesized(_) => mem::replace(&mut self.in_fn_args, false),
+            ast::PathParameters::AngleBracketed(_) => self.in_fn_args,
+        };
+        visit::walk_path_parameters(self, path_span, parameters);
+        self.in_fn_args = in_fn_args;
+    }
+
     fn visit_trait_item(&mut self, ti: &ast::TraitItem) {
         match ti.node {
             ast::TraitItemKind::Const(..) => {
@@ -1090,6 +1151,8 @@ impl<'a> Visitor for PostExpansionVisitor<'a> {
             ast::TraitItemKind::Method(ref sig, ref block) => {
                 if block.is_none() {
                     self.check_abi(sig.abi, ti.span);
+                    // Methods with a body go through `visit_fn` instead.
+                    self.in_fn_args = true;
                 }
                 if sig.constness == ast::Constness::Const {
                     gate_feature_post!(&self, const_fn, ti.span, "const fn is unstable");
@@ -1208,7 +1271,7 @@ pub fn check_crate(krate: &ast::Crate,
         cm: sess.codemap(),
         plugin_attributes: plugin_attributes,
     };
-    visit::walk_crate(&mut PostExpansionVisitor { context: &ctx }, krate);
+    visit::walk_crate(&mut PostExpansionVisitor { context: &ctx, in_fn_args: false }, krate);
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
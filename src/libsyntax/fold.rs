@@ -905,6 +905,10 @@ pub fn noop_fold_item_kind<T: Folder>(i: ItemKind, folder: &mut T) -> ItemKind {
                       bounds,
                       items)
         }
+        ItemKind::TraitAlias(generics, bounds) => {
+            ItemKind::TraitAlias(folder.fold_generics(generics),
+                                 folder.fold_bounds(bounds))
+        }
         ItemKind::Mac(m) => ItemKind::Mac(folder.fold_mac(m)),
     }
 }
@@ -1254,6 +1258,7 @@ pub fn noop_fold_expr<T: Folder>(Expr {id, node, span, attrs}: Expr, folder: &mu
                 alignstack,
                 dialect,
                 expn_id,
+                operand_classes,
             }) => ExprKind::InlineAsm(InlineAsm {
                 inputs: inputs.move_map(|(c, input)| {
                     (c, folder.fold_expr(input))
@@ -1273,6 +1278,7 @@ pub fn noop_fold_expr<T: Folder>(Expr {id, node, span, attrs}: Expr, folder: &mu
                 alignstack: alignstack,
                 dialect: dialect,
                 expn_id: expn_id,
+                operand_classes: operand_classes,
             }),
             ExprKind::Mac(mac) => ExprKind::Mac(folder.fold_mac(mac)),
             ExprKind::Struct(path, fields, maybe_expr) => {
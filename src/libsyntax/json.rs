@@ -23,6 +23,7 @@ use codemap::CodeMap;
 use syntax_pos::{self, MacroBacktrace, Span, SpanLabel, MultiSpan};
 use errors::registry::Registry;
 use errors::{DiagnosticBuilder, SubDiagnostic, RenderSpan, CodeSuggestion, CodeMapper};
+use errors::Applicability;
 use errors::emitter::Emitter;
 
 use std::rc::Rc;
@@ -82,6 +83,7 @@ struct Diagnostic<'a> {
 #[derive(RustcEncodable)]
 struct DiagnosticSpan {
     file_name: String,
+    /// 0-based, relative to the start of the file.
     byte_start: u32,
     byte_end: u32,
     /// 1-based.
@@ -102,6 +104,9 @@ struct DiagnosticSpan {
     /// load the fully rendered version from the parent `Diagnostic`,
     /// however.
     suggested_replacement: Option<String>,
+    /// If a suggestion is present, whether it can be applied by tools
+    /// without asking the user.
+    suggestion_applicability: Option<Applicability>,
     /// Macro invocations that created the code at this span, if any.
     expansion: Option<Box<DiagnosticSpanMacroExpansion>>,
 }
@@ -171,7 +176,7 @@ impl<'a> Diagnostic<'a> {
 
 impl DiagnosticSpan {
     fn from_span_label(span: SpanLabel,
-                       suggestion: Option<&String>,
+                       suggestion: Option<(&String, Applicability)>,
                        je: &JsonEmitter)
                        -> DiagnosticSpan {
         Self::from_span_etc(span.span,
@@ -184,7 +189,7 @@ impl DiagnosticSpan {
     fn from_span_etc(span: Span,
                      is_primary: bool,
                      label: Option<String>,
-                     suggestion: Option<&String>,
+                     suggestion: Option<(&String, Applicability)>,
                      je: &JsonEmitter)
                      -> DiagnosticSpan {
         // obtain the full backtrace from the `macro_backtrace`
@@ -204,7 +209,7 @@ impl DiagnosticSpan {
     fn from_span_full(span: Span,
                       is_primary: bool,
                       label: Option<String>,
-                      suggestion: Option<&String>,
+                      suggestion: Option<(&String, Applicability)>,
                       mut backtrace: vec::IntoIter<MacroBacktrace>,
                       je: &JsonEmitter)
                       -> DiagnosticSpan {
@@ -234,15 +239,16 @@ impl DiagnosticSpan {
         });
         DiagnosticSpan {
             file_name: start.file.name.clone(),
-            byte_start: span.lo.0,
-            byte_end: span.hi.0,
+            byte_start: span.lo.0 - start.file.start_pos.0,
+            byte_end: span.hi.0 - start.file.start_pos.0,
             line_start: start.line,
             line_end: end.line,
             column_start: start.col.0 + 1,
             column_end: end.col.0 + 1,
             is_primary: is_primary,
             text: DiagnosticSpanLine::from_span(span, je),
-            suggested_replacement: suggestion.cloned(),
+            suggested_replacement: suggestion.map(|x| x.0.clone()),
+            suggestion_applicability: suggestion.map(|x| x.1),
             expansion: backtrace_step,
             label: label,
         }
@@ -261,9 +267,10 @@ impl DiagnosticSpan {
         suggestion.msp.span_labels()
                       .into_iter()
                       .zip(&suggestion.substitutes)
-                      .map(|(span_label, suggestion)| {
+                      .map(|(span_label, substitute)| {
                           DiagnosticSpan::from_span_label(span_label,
-                                                          Some(suggestion),
+                                                          Some((substitute,
+                                                                suggestion.applicability)),
                                                           je)
                       })
                       .collect()
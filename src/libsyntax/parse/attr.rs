@@ -209,7 +209,28 @@ impl<'a> Parser<'a> {
 
         let lo = self.span.lo;
         let ident = self.parse_ident()?;
-        let name = self.id_to_interned_str(ident);
+        let mut name = self.id_to_interned_str(ident);
+
+        // Scoped names such as `clippy::needless_return` are only valid as
+        // words, and are joined into a single name.
+        if self.token == token::ModSep {
+            let mut path = name.to_string();
+            while self.eat(&token::ModSep) {
+                let ident = self.parse_ident()?;
+                path.push_str("::");
+                path.push_str(&self.id_to_interned_str(ident));
+            }
+            name = token::intern_and_get_ident(&path);
+            match self.token {
+                token::Eq | token::OpenDelim(token::Paren) => {
+                    let span = self.span;
+                    return Err(self.span_fatal(span, "expected `,` or `)` after a scoped \
+                                                      name in a meta item"));
+                }
+                _ => {}
+            }
+        }
+
         match self.token {
             token::Eq => {
                 self.bump();
@@ -4242,6 +4242,23 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parses a `const NAME: Type` generic parameter and reports it as
+    /// unsupported. Only lifetimes and types can be generic parameters, but
+    /// the rest of the parameter list is still parsed as usual.
+    fn parse_const_param(&mut self) -> PResult<'a, ()> {
+        let lo = self.span.lo;
+        self.expect_keyword(keywords::Const)?;
+        self.parse_ident()?;
+        self.expect(&token::Colon)?;
+        self.parse_ty()?;
+        let span = mk_sp(lo, self.last_span.hi);
+        self.diagnostic()
+            .struct_span_err(span, "const generic parameters are not supported")
+            .note("only lifetimes and types can be used as generic parameters")
+            .emit();
+        Ok(())
+    }
+
     /// Parse a set of optional generic type parameter declarations. Where
     /// clauses are not parsed here, and must be added later via
     /// `parse_where_clause()`.
@@ -4257,6 +4274,10 @@ impl<'a> Parser<'a> {
             let mut seen_default = false;
             let ty_params = self.parse_seq_to_gt(Some(token::Comma), |p| {
                 p.forbid_lifetime()?;
+                if p.check_keyword(keywords::Const) {
+                    p.parse_const_param()?;
+                    return Ok(None);
+                }
                 let ty_param = p.parse_ty_param()?;
                 if ty_param.default.is_some() {
                     seen_default = true;
@@ -4265,11 +4286,11 @@ impl<'a> Parser<'a> {
                     p.span_err(last_span,
                                "type parameters with a default must be trailing");
                 }
-                Ok(ty_param)
+                Ok(Some(ty_param))
             })?;
             Ok(ast::Generics {
                 lifetimes: lifetime_defs,
-                ty_params: ty_params,
+                ty_params: ty_params.into_vec().into_iter().filter_map(|p| p).collect(),
                 where_clause: WhereClause {
                     id: ast::DUMMY_NODE_ID,
                     predicates: Vec::new(),
@@ -4885,6 +4906,18 @@ impl<'a> Parser<'a> {
         let ident = self.parse_ident()?;
         let mut tps = self.parse_generics()?;
 
+        if self.eat(&token::Eq) {
+            // It's a trait alias.
+            let bounds = self.parse_ty_param_bounds(BoundParsingMode::Bare)?;
+            tps.where_clause = self.parse_where_clause()?;
+            self.expect(&token::Semi)?;
+            if unsafety != Unsafety::Normal {
+                let last_span = self.last_span;
+                self.span_err(last_span, "trait aliases cannot be `unsafe`");
+            }
+            return Ok((ident, ItemKind::TraitAlias(tps, bounds), None));
+        }
+
         // Parse supertrait bounds.
         let bounds = self.parse_colon_then_ty_param_bounds(BoundParsingMode::Bare)?;
 
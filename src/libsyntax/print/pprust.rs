@@ -1315,6 +1315,18 @@ impl<'a> State<'a> {
                 }
                 try!(self.bclose(item.span));
             }
+            ast::ItemKind::TraitAlias(ref generics, ref bounds) => {
+                try!(self.head(""));
+                try!(self.print_visibility(&item.vis));
+                try!(self.word_nbsp("trait"));
+                try!(self.print_ident(item.ident));
+                try!(self.print_generics(generics));
+                try!(self.print_bounds(" =", &bounds[..]));
+                try!(self.print_where_clause(&generics.where_clause));
+                try!(word(&mut self.s, ";"));
+                try!(self.end()); // end inner head-block
+                try!(self.end()); // end outer head-block
+            }
             ast::ItemKind::Mac(codemap::Spanned { ref node, .. }) => {
                 try!(self.print_visibility(&item.vis));
                 try!(self.print_path(&node.path, false, 0));
@@ -2217,7 +2229,7 @@ impl<'a> State<'a> {
                 }
             }
             ast::ExprKind::InlineAsm(ref a) => {
-                try!(word(&mut self.s, "asm!"));
+                try!(word(&mut self.s, "llvm_asm!"));
                 try!(self.popen());
                 try!(self.print_string(&a.asm, a.asm_str_style));
                 try!(self.word_space(":"));
@@ -1,4 +1,4 @@
-// Copyright 2012-2013 The Rust Project Developers. See the COPYRIGHT
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
 // file at the top-level directory of this distribution and at
 // http://rust-lang.org/COPYRIGHT.
 //
@@ -8,46 +8,287 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-/*
- * Inline assembly support.
- */
-use self::State::*;
+//! Inline assembly support.
+//!
+//! `asm!` takes one or more template strings, followed by the operands and
+//! finally the options of the assembly block:
+//!
+//! ```ignore
+//! asm!("add {0}, {n}", inout(reg) x, n = in(reg) 5, options(nostack));
+//! ```
+//!
+//! Placeholders in the template work like those of `format!`, with an
+//! optional modifier selecting a sub-register, e.g. `{0:e}`. All of this is
+//! checked here, and then lowered to the operand constraints of
+//! `llvm_asm!`, which is all the rest of the compiler knows about.
+
+use std::collections::HashMap;
+
+use fmt_macros as parse;
 
 use syntax::ast;
+use syntax::ast::AsmDialect;
+use syntax::attr::AttrMetaMethods;
 use syntax::codemap;
 use syntax::ext::base;
 use syntax::ext::base::*;
 use syntax::feature_gate;
-use syntax::parse::token::intern;
-use syntax::parse::{self, token};
+use syntax::parse::parser::Parser;
+use syntax::parse::token::{self, intern, keywords, InternedString};
 use syntax::ptr::P;
-use syntax::ast::AsmDialect;
-use syntax_pos::Span;
+use syntax_pos::{mk_sp, Span};
 use syntax::tokenstream;
 
-enum State {
-    Asm,
-    Outputs,
-    Inputs,
-    Clobbers,
-    Options,
-    StateNone
+#[derive(Copy, Clone, PartialEq)]
+enum Direction {
+    In,
+    /// `out` and `lateout`. A late output may share its register with an
+    /// input, as it is only written after all inputs have been read.
+    Out { late: bool },
+    /// `inout` and `inlateout`.
+    InOut { late: bool },
 }
 
-impl State {
-    fn next(&self) -> State {
+enum Register {
+    /// Any register of the named class, with its LLVM constraint code.
+    Class(InternedString, &'static str),
+    /// A specific register, e.g. `"eax"`, with the class it belongs to.
+    Explicit(InternedString, &'static str),
+}
+
+impl Register {
+    fn class(&self) -> InternedString {
         match *self {
-            Asm       => Outputs,
-            Outputs   => Inputs,
-            Inputs    => Clobbers,
-            Clobbers  => Options,
-            Options   => StateNone,
-            StateNone => StateNone
+            Register::Class(ref class, _) => class.clone(),
+            Register::Explicit(_, class) => InternedString::new(class),
         }
     }
 }
 
-const OPTIONS: &'static [&'static str] = &["volatile", "alignstack", "intel"];
+struct Operand {
+    direction: Direction,
+    register: Register,
+    /// The input value, or the output place of `out` and `lateout`. `None`
+    /// for an output which is discarded with `_`.
+    expr: Option<P<ast::Expr>>,
+    /// The output place of an `inout` written as `inout(reg) in => out`.
+    out_expr: Option<P<ast::Expr>>,
+    span: Span,
+}
+
+#[derive(Default)]
+struct Options {
+    pure: bool,
+    nomem: bool,
+    readonly: bool,
+    nostack: bool,
+    att_syntax: bool,
+}
+
+/// Returns the LLVM constraint code for the register class `class` on the
+/// architecture `arch`, if there is such a class.
+fn register_class(arch: &str, class: &str) -> Option<&'static str> {
+    let code = match arch {
+        "x86" | "x86_64" => match class {
+            "reg" => "r",
+            "reg_abcd" => "Q",
+            "reg_byte" => "q",
+            "xmm_reg" | "ymm_reg" => "x",
+            "zmm_reg" => "v",
+            "kreg" => "Yk",
+            _ => return None,
+        },
+        "arm" => match class {
+            "reg" => "r",
+            "sreg" => "t",
+            "dreg" | "qreg" => "w",
+            _ => return None,
+        },
+        "aarch64" => match class {
+            "reg" => "r",
+            "vreg" => "w",
+            "vreg_low16" => "x",
+            _ => return None,
+        },
+        "mips" | "mips64" => match class {
+            "reg" => "r",
+            "freg" => "f",
+            _ => return None,
+        },
+        "powerpc" | "powerpc64" => match class {
+            "reg" => "r",
+            "reg_nonzero" => "b",
+            "freg" => "f",
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(code)
+}
+
+/// Translates the template modifier `modifier` of an operand in the register
+/// class `class` to the LLVM operand modifier.
+fn template_modifier(arch: &str, class: &str, modifier: &str) -> Option<&'static str> {
+    let llvm_modifier = match (arch, class, modifier) {
+        ("x86", "reg", "l") | ("x86_64", "reg", "l") |
+        ("x86", "reg_abcd", "l") | ("x86_64", "reg_abcd", "l") => "b",
+        ("x86", "reg_abcd", "h") | ("x86_64", "reg_abcd", "h") => "h",
+        ("x86", "reg", "x") | ("x86_64", "reg", "x") |
+        ("x86", "reg_abcd", "x") | ("x86_64", "reg_abcd", "x") => "w",
+        ("x86", "reg", "e") | ("x86_64", "reg", "e") |
+        ("x86", "reg_abcd", "e") | ("x86_64", "reg_abcd", "e") => "k",
+        ("x86_64", "reg", "r") | ("x86_64", "reg_abcd", "r") => "q",
+        ("x86", _, "x") | ("x86_64", _, "x") if class.ends_with("mm_reg") => "x",
+        ("x86", _, "y") | ("x86_64", _, "y") if class.ends_with("mm_reg") => "t",
+        ("x86", _, "z") | ("x86_64", _, "z") if class.ends_with("mm_reg") => "g",
+        ("aarch64", "reg", "w") => "w",
+        ("aarch64", "reg", "x") => "x",
+        ("aarch64", "vreg", m) | ("aarch64", "vreg_low16", m) => match m {
+            "b" => "b",
+            "h" => "h",
+            "s" => "s",
+            "d" => "d",
+            "q" => "q",
+            "v" => "v",
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(llvm_modifier)
+}
+
+/// Whether `reg` is `prefix` followed by a number in `range`.
+fn is_numbered(reg: &str, prefix: &str, range: ::std::ops::Range<u32>) -> bool {
+    if !reg.starts_with(prefix) {
+        return false;
+    }
+    let num = &reg[prefix.len()..];
+    match num.parse::<u32>() {
+        Ok(n) => n >= range.start && n < range.end && num == n.to_string(),
+        Err(_) => false,
+    }
+}
+
+/// Returns the register class of the explicit register `reg` on the
+/// architecture `arch`, or the error to report if it can't be used.
+fn explicit_register(arch: &str, reg: &str) -> Result<&'static str, String> {
+    let reserved = |what: &str| {
+        Err(format!("{} cannot be used as an operand for inline asm", what))
+    };
+    match arch {
+        "x86" | "x86_64" => {
+            let x86_64 = arch == "x86_64";
+            let (vregs, zregs) = if x86_64 { (16, 32) } else { (8, 8) };
+            match reg {
+                "sp" | "esp" | "rsp" | "spl" => return reserved("the stack pointer"),
+                "bp" | "ebp" | "rbp" | "bpl" => return reserved("the frame pointer"),
+                "ip" | "eip" | "rip" => return reserved("the instruction pointer"),
+                "bl" | "bh" | "bx" | "ebx" | "rbx" if x86_64 => {
+                    return reserved("rbx is used internally by LLVM and");
+                }
+                "si" | "esi" if !x86_64 => {
+                    return reserved("esi is used internally by LLVM and");
+                }
+                "k0" => return reserved("the k0 AVX mask register"),
+                "ax" | "eax" | "bx" | "ebx" | "cx" | "ecx" |
+                "dx" | "edx" | "si" | "esi" | "di" | "edi" => return Ok("reg"),
+                "rax" | "rcx" | "rdx" | "rsi" | "rdi" if x86_64 => return Ok("reg"),
+                "al" | "ah" | "bl" | "bh" | "cl" | "ch" | "dl" | "dh" => return Ok("reg_byte"),
+                "sil" | "dil" if x86_64 => return Ok("reg_byte"),
+                _ => {}
+            }
+            if x86_64 {
+                for &(suffix, class) in &[("", "reg"), ("d", "reg"), ("w", "reg"),
+                                          ("b", "reg_byte")] {
+                    if reg.ends_with(suffix) &&
+                       is_numbered(&reg[..reg.len() - suffix.len()], "r", 8..16) {
+                        return Ok(class);
+                    }
+                }
+            }
+            if is_numbered(reg, "xmm", 0..vregs) {
+                Ok("xmm_reg")
+            } else if is_numbered(reg, "ymm", 0..vregs) {
+                Ok("ymm_reg")
+            } else if is_numbered(reg, "zmm", 0..zregs) {
+                Ok("zmm_reg")
+            } else if is_numbered(reg, "k", 1..8) {
+                Ok("kreg")
+            } else {
+                Err(format!("invalid register `{}`", reg))
+            }
+        }
+        "arm" => match reg {
+            "sp" | "r13" => reserved("the stack pointer"),
+            "pc" | "r15" => reserved("the program counter"),
+            "fp" | "r11" | "r7" => reserved("the frame pointer"),
+            "r6" => reserved("r6 is used internally by LLVM and"),
+            "lr" | "r14" => Ok("reg"),
+            _ if is_numbered(reg, "r", 0..13) => Ok("reg"),
+            _ if is_numbered(reg, "s", 0..32) => Ok("sreg"),
+            _ if is_numbered(reg, "d", 0..32) => Ok("dreg"),
+            _ if is_numbered(reg, "q", 0..16) => Ok("qreg"),
+            _ => Err(format!("invalid register `{}`", reg)),
+        },
+        "aarch64" => match reg {
+            "sp" => reserved("the stack pointer"),
+            "xzr" | "wzr" => reserved("the zero register"),
+            "fp" | "x29" | "w29" => reserved("the frame pointer"),
+            "x18" | "w18" => reserved("x18 is a reserved platform register and"),
+            "x19" | "w19" => reserved("x19 is used internally by LLVM and"),
+            "lr" => Ok("reg"),
+            _ if is_numbered(reg, "x", 0..31) || is_numbered(reg, "w", 0..31) => Ok("reg"),
+            _ if is_numbered(reg, "v", 0..32) => Ok("vreg"),
+            _ => Err(format!("invalid register `{}`", reg)),
+        },
+        "mips" | "mips64" => match reg {
+            "$0" => reserved("the zero register"),
+            "$1" => reserved("the assembler temporary"),
+            "$26" | "$27" => reserved("the kernel registers"),
+            "$28" => reserved("the global pointer"),
+            "$29" => reserved("the stack pointer"),
+            "$30" => reserved("the frame pointer"),
+            "$31" => reserved("the return address register"),
+            _ if is_numbered(reg, "$", 2..26) => Ok("reg"),
+            _ if is_numbered(reg, "$f", 0..32) => Ok("freg"),
+            _ => Err(format!("invalid register `{}`", reg)),
+        },
+        "powerpc" | "powerpc64" => match reg {
+            "r1" => reserved("the stack pointer"),
+            "r2" => reserved("the TOC pointer"),
+            "r13" => reserved("the thread pointer"),
+            "r29" | "r30" => reserved("r29 and r30 are used internally by LLVM and"),
+            "r31" => reserved("the frame pointer"),
+            _ if is_numbered(reg, "r", 0..32) => Ok("reg"),
+            _ if is_numbered(reg, "f", 0..32) => Ok("freg"),
+            _ => Err(format!("invalid register `{}`", reg)),
+        },
+        _ => Err(format!("explicit registers are not supported on target architecture `{}`",
+                         arch)),
+    }
+}
+
+fn target_arch(cx: &ExtCtxt) -> InternedString {
+    cx.cfg.iter()
+          .find(|mi| mi.check_name("target_arch"))
+          .and_then(|mi| mi.value_str())
+          .unwrap_or_else(|| InternedString::new(""))
+}
+
+fn is_options(token: &token::Token) -> bool {
+    match *token {
+        token::Ident(ident) => ident.name.as_str() == "options",
+        _ => false,
+    }
+}
+
+fn is_str_lit(token: &token::Token) -> bool {
+    match *token {
+        token::Literal(token::Str_(_), _) |
+        token::Literal(token::StrRaw(..), _) => true,
+        _ => false,
+    }
+}
 
 pub fn expand_asm<'cx>(cx: &'cx mut ExtCtxt, sp: Span, tts: &[tokenstream::TokenTree])
                        -> Box<base::MacResult+'cx> {
@@ -59,188 +300,186 @@ pub fn expand_asm<'cx>(cx: &'cx mut ExtCtxt, sp: Span, tts: &[tokenstream::Token
         return DummyResult::expr(sp);
     }
 
-    // Split the tts before the first colon, to avoid `asm!("x": y)`  being
-    // parsed as `asm!(z)` with `z = "x": y` which is type ascription.
-    let first_colon = tts.iter().position(|tt| {
-        match *tt {
-            tokenstream::TokenTree::Token(_, token::Colon) |
-            tokenstream::TokenTree::Token(_, token::ModSep) => true,
-            _ => false
-        }
-    }).unwrap_or(tts.len());
-    let mut p = cx.new_parser_from_tts(&tts[first_colon..]);
-    let mut asm = token::InternedString::new("");
-    let mut asm_str_style = None;
-    let mut outputs = Vec::new();
-    let mut inputs = Vec::new();
-    let mut clobs = Vec::new();
-    let mut volatile = false;
-    let mut alignstack = false;
-    let mut dialect = AsmDialect::Att;
-
-    let mut state = Asm;
-
-    'statement: loop {
-        match state {
-            Asm => {
-                if asm_str_style.is_some() {
-                    // If we already have a string with instructions,
-                    // ending up in Asm state again is an error.
-                    cx.span_err(sp, "malformed inline assembly");
-                    return DummyResult::expr(sp);
-                }
-                // Nested parser, stop before the first colon (see above).
-                let mut p2 = cx.new_parser_from_tts(&tts[..first_colon]);
-                let (s, style) = match expr_to_string(cx, panictry!(p2.parse_expr()),
-                                                   "inline assembly must be a string literal") {
-                    Some((s, st)) => (s, st),
-                    // let compilation continue
-                    None => return DummyResult::expr(sp),
-                };
-
-                // This is most likely malformed.
-                if p2.token != token::Eof {
-                    let mut extra_tts = panictry!(p2.parse_all_token_trees());
-                    extra_tts.extend(tts[first_colon..].iter().cloned());
-                    p = parse::tts_to_parser(cx.parse_sess, extra_tts, cx.cfg());
-                }
+    let arch = target_arch(cx);
+    let mut p = cx.new_parser_from_tts(tts);
 
-                asm = s;
-                asm_str_style = Some(style);
-            }
-            Outputs => {
-                while p.token != token::Eof &&
-                      p.token != token::Colon &&
-                      p.token != token::ModSep {
-
-                    if !outputs.is_empty() {
-                        p.eat(&token::Comma);
-                    }
-
-                    let (constraint, _str_style) = panictry!(p.parse_str());
-
-                    let span = p.last_span;
-
-                    panictry!(p.expect(&token::OpenDelim(token::Paren)));
-                    let out = panictry!(p.parse_expr());
-                    panictry!(p.expect(&token::CloseDelim(token::Paren)));
-
-                    // Expands a read+write operand into two operands.
-                    //
-                    // Use '+' modifier when you want the same expression
-                    // to be both an input and an output at the same time.
-                    // It's the opposite of '=&' which means that the memory
-                    // cannot be shared with any other operand (usually when
-                    // a register is clobbered early.)
-                    let mut ch = constraint.chars();
-                    let output = match ch.next() {
-                        Some('=') => None,
-                        Some('+') => {
-                            Some(token::intern_and_get_ident(&format!(
-                                        "={}", ch.as_str())))
-                        }
-                        _ => {
-                            cx.span_err(span, "output operand constraint lacks '=' or '+'");
-                            None
-                        }
-                    };
-
-                    let is_rw = output.is_some();
-                    let is_indirect = constraint.contains("*");
-                    outputs.push(ast::InlineAsmOutput {
-                        constraint: output.unwrap_or(constraint.clone()),
-                        expr: out,
-                        is_rw: is_rw,
-                        is_indirect: is_indirect,
-                    });
-                }
-            }
-            Inputs => {
-                while p.token != token::Eof &&
-                      p.token != token::Colon &&
-                      p.token != token::ModSep {
-
-                    if !inputs.is_empty() {
-                        p.eat(&token::Comma);
-                    }
+    if p.token == token::Eof {
+        cx.span_err(sp, "requires at least a template string argument");
+        return DummyResult::expr(sp);
+    }
 
-                    let (constraint, _str_style) = panictry!(p.parse_str());
+    let mut templates = vec![];
+    let first = panictry!(p.parse_expr());
+    let first_span = first.span;
+    match expr_to_string(cx, first, "asm template must be a string literal") {
+        Some((s, _)) => templates.push((s, first_span)),
+        None => return DummyResult::expr(sp),
+    }
 
-                    if constraint.starts_with("=") {
-                        cx.span_err(p.last_span, "input operand constraint contains '='");
-                    } else if constraint.starts_with("+") {
-                        cx.span_err(p.last_span, "input operand constraint contains '+'");
-                    }
+    let mut operands = Vec::<Operand>::new();
+    let mut names = HashMap::<String, usize>::new();
+    let mut options = None;
+    while p.token != token::Eof {
+        if !p.eat(&token::Comma) {
+            cx.span_err(p.span, "expected token: `,`");
+            return DummyResult::expr(sp);
+        }
+        if p.token == token::Eof { break } // accept trailing commas
 
-                    panictry!(p.expect(&token::OpenDelim(token::Paren)));
-                    let input = panictry!(p.parse_expr());
-                    panictry!(p.expect(&token::CloseDelim(token::Paren)));
+        if options.is_some() {
+            cx.span_err(p.span, "the options must come last");
+            return DummyResult::expr(sp);
+        }
 
-                    inputs.push((constraint, input));
-                }
+        // Further template strings, which are joined by newlines.
+        if is_str_lit(&p.token) {
+            if !operands.is_empty() {
+                cx.span_err(p.span, "template strings must come before the operands");
+                return DummyResult::expr(sp);
             }
-            Clobbers => {
-                while p.token != token::Eof &&
-                      p.token != token::Colon &&
-                      p.token != token::ModSep {
+            let (s, _) = panictry!(p.parse_str());
+            templates.push((s, p.last_span));
+            continue;
+        }
 
-                    if !clobs.is_empty() {
-                        p.eat(&token::Comma);
-                    }
+        if is_options(&p.token) && p.look_ahead(1, |t| *t == token::OpenDelim(token::Paren)) {
+            p.bump();
+            options = Some(match parse_options(cx, &mut p, &arch) {
+                Some(options) => options,
+                None => return DummyResult::expr(sp),
+            });
+            continue;
+        }
 
-                    let (s, _str_style) = panictry!(p.parse_str());
+        let name = if p.token.is_ident() && p.look_ahead(1, |t| *t == token::Eq) {
+            let ident = panictry!(p.parse_ident());
+            p.bump();
+            Some(ident.name.as_str())
+        } else {
+            None
+        };
 
-                    if OPTIONS.iter().any(|&opt| s == opt) {
-                        cx.span_warn(p.last_span, "expected a clobber, found an option");
-                    } else if s.starts_with("{") || s.ends_with("}") {
-                        cx.span_err(p.last_span, "clobber should not be surrounded by braces");
-                    }
+        let operand = match parse_operand(cx, &mut p, &arch) {
+            Some(operand) => operand,
+            None => return DummyResult::expr(sp),
+        };
 
-                    clobs.push(s);
-                }
+        if name.is_none() && !names.is_empty() {
+            if let Register::Class(..) = operand.register {
+                cx.span_err(operand.span,
+                            "positional arguments cannot follow named arguments");
             }
-            Options => {
-                let (option, _str_style) = panictry!(p.parse_str());
-
-                if option == "volatile" {
-                    // Indicates that the inline assembly has side effects
-                    // and must not be optimized out along with its outputs.
-                    volatile = true;
-                } else if option == "alignstack" {
-                    alignstack = true;
-                } else if option == "intel" {
-                    dialect = AsmDialect::Intel;
-                } else {
-                    cx.span_warn(p.last_span, "unrecognized option");
-                }
-
-                if p.token == token::Comma {
-                    p.eat(&token::Comma);
-                }
+        }
+        if let Some(name) = name {
+            if let Register::Explicit(..) = operand.register {
+                cx.span_err(operand.span, "explicit register arguments cannot have names");
+            } else if let Some(&prev) = names.get(&*name) {
+                cx.struct_span_err(operand.span,
+                                   &format!("duplicate argument named `{}`", name))
+                    .span_note(operands[prev].span, "previously here")
+                    .emit();
             }
-            StateNone => ()
+            names.insert(name.to_string(), operands.len());
         }
+        operands.push(operand);
+    }
+    let options = options.unwrap_or(Options::default());
 
-        loop {
-            // MOD_SEP is a double colon '::' without space in between.
-            // When encountered, the state must be advanced twice.
-            match (&p.token, state.next(), state.next().next()) {
-                (&token::Colon, StateNone, _)   |
-                (&token::ModSep, _, StateNone) => {
-                    p.bump();
-                    break 'statement;
+    // LLVM numbers the outputs before the inputs, and only ties an input to
+    // an output by the output's number, so build both lists first and then
+    // refer to the operands in the template by their `slots`.
+    let mut outputs = vec![];
+    let mut inputs = vec![];
+    let mut output_classes = vec![];
+    let mut input_classes = vec![];
+    let mut clobbers = vec![];
+    let mut slots = vec![];
+    for operand in &operands {
+        let constraint = match operand.register {
+            Register::Class(_, code) => code.to_string(),
+            Register::Explicit(ref reg, _) => format!("{{{}}}", reg),
+        };
+        let class = operand.register.class();
+        let out_constraint = |late| {
+            token::intern_and_get_ident(&format!("={}{}", if late { "" } else { "&" },
+                                                 constraint))
+        };
+        match operand.direction {
+            Direction::In => {
+                slots.push(Slot::Input(inputs.len()));
+                inputs.push((token::intern_and_get_ident(&constraint),
+                             operand.expr.clone().unwrap()));
+                input_classes.push(class);
+            }
+            Direction::Out { late } => match operand.expr {
+                Some(ref expr) => {
+                    slots.push(Slot::Output(outputs.len()));
+                    outputs.push(ast::InlineAsmOutput {
+                        constraint: out_constraint(late),
+                        expr: expr.clone(),
+                        is_rw: false,
+                        is_indirect: false,
+                    });
+                    output_classes.push(class);
                 }
-                (&token::Colon, st, _)   |
-                (&token::ModSep, _, st) => {
-                    p.bump();
-                    state = st;
+                None => {
+                    // Only allowed for explicit registers, which can't be
+                    // named in the template anyway.
+                    slots.push(Slot::Discarded);
+                    if let Register::Explicit(ref reg, _) = operand.register {
+                        clobbers.push(reg.clone());
+                    }
+                }
+            },
+            Direction::InOut { late } => {
+                let output = outputs.len();
+                slots.push(Slot::Output(output));
+                output_classes.push(class.clone());
+                match operand.out_expr {
+                    None => {
+                        outputs.push(ast::InlineAsmOutput {
+                            constraint: out_constraint(late),
+                            expr: operand.expr.clone().unwrap(),
+                            is_rw: true,
+                            is_indirect: false,
+                        });
+                    }
+                    Some(ref out_expr) => {
+                        outputs.push(ast::InlineAsmOutput {
+                            constraint: out_constraint(late),
+                            expr: out_expr.clone(),
+                            is_rw: false,
+                            is_indirect: false,
+                        });
+                        inputs.push((token::intern_and_get_ident(&output.to_string()),
+                                     operand.expr.clone().unwrap()));
+                        input_classes.push(class);
+                    }
                 }
-                (&token::Eof, _, _) => break 'statement,
-                _ => break
             }
         }
     }
 
+    let asm = match expand_templates(cx, &templates, &operands, &names, &slots,
+                                     outputs.len(), &arch) {
+        Some(asm) => asm,
+        None => return DummyResult::expr(sp),
+    };
+
+    if options.pure && outputs.is_empty() {
+        cx.span_err(sp, "asm with the `pure` option must have at least one output");
+    }
+    if options.pure && !options.nomem && !options.readonly {
+        cx.span_err(sp, "the `pure` option must be combined with either `nomem` or `readonly`");
+    }
+    if !options.nomem && !options.readonly {
+        clobbers.push(InternedString::new("memory"));
+    }
+    let dialect = match &*arch {
+        "x86" | "x86_64" if !options.att_syntax => AsmDialect::Intel,
+        _ => AsmDialect::Att,
+    };
+
     let expn_id = cx.codemap().record_expansion(codemap::ExpnInfo {
         call_site: sp,
         callee: codemap::NameAndSpan {
@@ -254,16 +493,267 @@ pub fn expand_asm<'cx>(cx: &'cx mut ExtCtxt, sp: Span, tts: &[tokenstream::Token
         id: ast::DUMMY_NODE_ID,
         node: ast::ExprKind::InlineAsm(ast::InlineAsm {
             asm: token::intern_and_get_ident(&asm),
-            asm_str_style: asm_str_style.unwrap(),
+            asm_str_style: ast::StrStyle::Cooked,
             outputs: outputs,
             inputs: inputs,
-            clobbers: clobs,
-            volatile: volatile,
-            alignstack: alignstack,
+            clobbers: clobbers,
+            // Without `pure`, the asm is assumed to have side effects and
+            // must not be removed even if its outputs are unused.
+            volatile: !options.pure,
+            alignstack: !options.nostack,
             dialect: dialect,
             expn_id: expn_id,
+            operand_classes: output_classes.into_iter().chain(input_classes).collect(),
         }),
         span: sp,
         attrs: ast::ThinVec::new(),
     }))
 }
+
+/// Where LLVM finds an operand.
+#[derive(Copy, Clone)]
+enum Slot {
+    Output(usize),
+    Input(usize),
+    Discarded,
+}
+
+/// Parses `dir(reg) expr`, after the optional `name =` of the operand.
+fn parse_operand(cx: &mut ExtCtxt, p: &mut Parser, arch: &str) -> Option<Operand> {
+    let start = p.span;
+    let direction = if p.eat_keyword(keywords::In) {
+        Direction::In
+    } else {
+        let dir = if p.token.is_ident() {
+            match &*panictry!(p.parse_ident()).name.as_str() {
+                "out" => Some(Direction::Out { late: false }),
+                "lateout" => Some(Direction::Out { late: true }),
+                "inout" => Some(Direction::InOut { late: false }),
+                "inlateout" => Some(Direction::InOut { late: true }),
+                _ => None,
+            }
+        } else {
+            None
+        };
+        match dir {
+            Some(dir) => dir,
+            None => {
+                cx.span_err(start,
+                            "expected one of `in`, `out`, `lateout`, `inout`, \
+                             `inlateout` or `options`");
+                return None;
+            }
+        }
+    };
+
+    panictry!(p.expect(&token::OpenDelim(token::Paren)));
+    let register = if is_str_lit(&p.token) {
+        let reg = panictry!(p.parse_str()).0;
+        match explicit_register(arch, &reg) {
+            Ok(class) => Register::Explicit(reg, class),
+            Err(msg) => {
+                cx.span_err(p.last_span, &msg);
+                return None;
+            }
+        }
+    } else {
+        let class = panictry!(p.parse_ident()).name.as_str();
+        match register_class(arch, &class) {
+            Some(code) => Register::Class(class, code),
+            None => {
+                cx.span_err(p.last_span,
+                            &format!("invalid register class `{}` for target architecture \
+                                      `{}`", class, arch));
+                return None;
+            }
+        }
+    };
+    panictry!(p.expect(&token::CloseDelim(token::Paren)));
+
+    let (expr, out_expr) = match direction {
+        Direction::In => (Some(panictry!(p.parse_expr())), None),
+        Direction::Out { .. } => {
+            if p.eat(&token::Underscore) {
+                if let Register::Class(..) = register {
+                    cx.span_err(p.last_span,
+                                "outputs can only be discarded with `_` when using an \
+                                 explicit register");
+                    return None;
+                }
+                (None, None)
+            } else {
+                (Some(panictry!(p.parse_expr())), None)
+            }
+        }
+        Direction::InOut { .. } => {
+            let expr = panictry!(p.parse_expr());
+            let out_expr = if p.eat(&token::FatArrow) {
+                if p.eat(&token::Underscore) {
+                    cx.span_err(p.last_span,
+                                "the output of an `inout` operand cannot be discarded, use \
+                                 `in` and an explicit `out(\"reg\") _` instead");
+                    return None;
+                }
+                Some(panictry!(p.parse_expr()))
+            } else {
+                None
+            };
+            (Some(expr), out_expr)
+        }
+    };
+
+    Some(Operand {
+        direction: direction,
+        register: register,
+        expr: expr,
+        out_expr: out_expr,
+        span: mk_sp(start.lo, p.last_span.hi),
+    })
+}
+
+/// Parses `(opt, ...)` after `options`.
+fn parse_options(cx: &mut ExtCtxt, p: &mut Parser, arch: &str) -> Option<Options> {
+    let mut options = Options::default();
+    panictry!(p.expect(&token::OpenDelim(token::Paren)));
+    while !p.eat(&token::CloseDelim(token::Paren)) {
+        let option = panictry!(p.parse_ident()).name.as_str();
+        match &*option {
+            "pure" => options.pure = true,
+            "nomem" => options.nomem = true,
+            "readonly" => options.readonly = true,
+            "nostack" => options.nostack = true,
+            "att_syntax" if arch == "x86" || arch == "x86_64" => options.att_syntax = true,
+            "att_syntax" => {
+                cx.span_err(p.last_span, "the `att_syntax` option is only supported on x86");
+            }
+            _ => {
+                cx.span_err(p.last_span,
+                            &format!("unknown option `{}`, expected one of `pure`, `nomem`, \
+                                      `readonly`, `nostack` or `att_syntax`", option));
+                return None;
+            }
+        }
+        if !p.eat(&token::Comma) {
+            panictry!(p.expect(&token::CloseDelim(token::Paren)));
+            break;
+        }
+    }
+    if options.nomem && options.readonly {
+        cx.span_err(p.last_span, "the `nomem` and `readonly` options are mutually exclusive");
+    }
+    Some(options)
+}
+
+/// Joins the template strings and replaces their placeholders with the LLVM
+/// operands they refer to.
+fn expand_templates(cx: &mut ExtCtxt,
+                    templates: &[(InternedString, Span)],
+                    operands: &[Operand],
+                    names: &HashMap<String, usize>,
+                    slots: &[Slot],
+                    num_outputs: usize,
+                    arch: &str)
+                    -> Option<String> {
+    let mut asm = String::new();
+    let mut used = vec![false; operands.len()];
+    let mut next_positional = 0;
+    let mut ok = true;
+
+    for (i, &(ref template, span)) in templates.iter().enumerate() {
+        if i > 0 {
+            asm.push('\n');
+        }
+        let mut parser = parse::Parser::new(template);
+        loop {
+            let arg = match parser.next() {
+                Some(parse::String(s)) => {
+                    // `$` introduces an operand in LLVM's syntax.
+                    asm.push_str(&s.replace("$", "$$"));
+                    continue;
+                }
+                Some(parse::NextArgument(arg)) => arg,
+                None => break,
+            };
+            if !parser.errors.is_empty() { break }
+
+            let index = match arg.position {
+                parse::ArgumentNext => {
+                    next_positional += 1;
+                    next_positional - 1
+                }
+                parse::ArgumentIs(index) => index,
+                parse::ArgumentNamed(name) => match names.get(name) {
+                    Some(&index) => index,
+                    None => {
+                        cx.span_err(span, &format!("there is no argument named `{}`", name));
+                        ok = false;
+                        continue;
+                    }
+                },
+            };
+            let operand = match operands.get(index) {
+                Some(operand) => operand,
+                None => {
+                    cx.span_err(span, &format!("invalid reference to argument at index {}",
+                                               index));
+                    ok = false;
+                    continue;
+                }
+            };
+            used[index] = true;
+
+            let class = match operand.register {
+                Register::Class(ref class, _) => class,
+                Register::Explicit(..) => {
+                    cx.span_err(span, "explicit register arguments cannot be used in the \
+                                       asm template");
+                    ok = false;
+                    continue;
+                }
+            };
+
+            let spec = arg.format;
+            if spec.fill.is_some() || spec.align != parse::AlignUnknown || spec.flags != 0 ||
+               spec.precision != parse::CountImplied || spec.width != parse::CountImplied {
+                cx.span_err(span, "asm template placeholders only accept a modifier, \
+                                   e.g. `{0:e}`");
+                ok = false;
+                continue;
+            }
+
+            let llvm_index = match slots[index] {
+                Slot::Output(i) => i,
+                Slot::Input(i) => num_outputs + i,
+                Slot::Discarded => unreachable!(),
+            };
+            if spec.ty.is_empty() {
+                asm.push_str(&format!("${{{}}}", llvm_index));
+            } else {
+                match template_modifier(arch, class, spec.ty) {
+                    Some(modifier) => asm.push_str(&format!("${{{}:{}}}", llvm_index, modifier)),
+                    None => {
+                        cx.span_err(span, &format!("invalid modifier `{}` for register class \
+                                                    `{}`", spec.ty, class));
+                        ok = false;
+                    }
+                }
+            }
+        }
+        if !parser.errors.is_empty() {
+            cx.span_err(span, &format!("invalid asm template string: {}",
+                                       parser.errors.remove(0)));
+            return None;
+        }
+    }
+
+    for (operand, &used) in operands.iter().zip(&used) {
+        if let Register::Class(..) = operand.register {
+            if !used {
+                cx.span_err(operand.span, "argument never used");
+                ok = false;
+            }
+        }
+    }
+
+    if ok { Some(asm) } else { None }
+}
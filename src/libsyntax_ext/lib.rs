@@ -40,6 +40,7 @@ mod concat;
 mod concat_idents;
 mod env;
 mod format;
+mod llvm_asm;
 mod log_syntax;
 mod trace_macros;
 
@@ -67,6 +68,8 @@ pub fn register_builtins(env: &mut SyntaxEnv) {
     env.insert(intern("format_args"),
                // format_args uses `unstable` things internally.
                NormalTT(Box::new(format::expand_format_args), None, true));
+    env.insert(intern("llvm_asm"),
+               builtin_normal_expander(llvm_asm::expand_llvm_asm));
     env.insert(intern("log_syntax"),
                builtin_normal_expander(log_syntax::expand_syntax_ext));
     env.insert(intern("trace_macros"),
@@ -115,6 +115,7 @@ impl<T: Write + Send + 'static> WinConsole<T> {
 
     /// Returns `None` whenever the terminal cannot be created for some
     /// reason.
+    #[allow(deprecated)]
     pub fn new(out: T) -> io::Result<WinConsole<T>> {
         let fg;
         let bg;
@@ -14,9 +14,16 @@ fn main() {
     println!("cargo:rustc-cfg=cargobuild");
 
     let target = env::var("TARGET").unwrap();
+    // Build scripts don't see `cfg(target_feature = "crt-static")`, so
+    // rustbuild tells us whether the C runtime is linked statically. musl
+    // targets do so by default.
+    let crt_static = match env::var("RUSTC_CRT_STATIC") {
+        Ok(s) => s == "true",
+        Err(..) => target.contains("musl"),
+    };
 
     if target.contains("linux") {
-        if target.contains("musl") && !target.contains("mips") {
+        if target.contains("musl") && !target.contains("mips") && crt_static {
             println!("cargo:rustc-link-lib=static=unwind");
         } else if !target.contains("android") {
             println!("cargo:rustc-link-lib=gcc_s");
@@ -14,16 +14,18 @@
 #![unstable(feature = "panic_unwind", issue = "32837")]
 #![cfg_attr(not(stage0), deny(warnings))]
 
+#![feature(cfg_target_feature)]
 #![feature(cfg_target_vendor)]
 #![feature(staged_api)]
 #![feature(unwind_attributes)]
 
-#![cfg_attr(not(target_env = "msvc"), feature(libc))]
+#![cfg_attr(not(any(target_env = "msvc", target_arch = "wasm32")), feature(libc))]
 
-#[cfg(not(target_env = "msvc"))]
+#[cfg(not(any(target_env = "msvc", target_arch = "wasm32")))]
 extern crate libc;
 
-#[cfg(not(target_env = "msvc"))]
+// There's no unwinder to bind to in a wasm module.
+#[cfg(not(any(target_env = "msvc", target_arch = "wasm32")))]
 mod libunwind;
-#[cfg(not(target_env = "msvc"))]
+#[cfg(not(any(target_env = "msvc", target_arch = "wasm32")))]
 pub use libunwind::*;
@@ -235,6 +235,9 @@ if #[cfg(not(all(target_os = "ios", target_arch = "arm")))] {
 #[cfg_attr(any(all(target_os = "linux", not(target_env = "musl")),
                target_os = "freebsd",
                target_os = "solaris",
+               all(target_os = "linux",
+                   target_env = "musl",
+                   not(target_feature = "crt-static")),
                all(target_os = "linux",
                    target_env = "musl",
                    not(target_arch = "x86"),
@@ -242,6 +245,7 @@ if #[cfg(not(all(target_os = "ios", target_arch = "arm")))] {
            link(name = "gcc_s"))]
 #[cfg_attr(all(target_os = "linux",
                target_env = "musl",
+               target_feature = "crt-static",
                any(target_arch = "x86", target_arch = "x86_64"),
                not(test)),
            link(name = "unwind", kind = "static"))]
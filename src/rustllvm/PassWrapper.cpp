@@ -22,6 +22,12 @@
 #include "llvm/Target/TargetSubtargetInfo.h"
 #include "llvm/Transforms/IPO/PassManagerBuilder.h"
 
+#if LLVM_VERSION_MINOR >= 9
+#include "llvm/Bitcode/BitcodeWriterPass.h"
+#include "llvm/Bitcode/ReaderWriter.h"
+#include "llvm/LTO/ThinLTOCodeGenerator.h"
+#endif
+
 
 #include "llvm-c/Transforms/PassManagerBuilder.h"
 
@@ -373,12 +379,29 @@ LLVMRustConfigurePassManagerBuilder(LLVMPassManagerBuilderRef PMB,
 				    LLVMRustCodeGenOptLevel OptLevel,
                                     bool MergeFunctions,
                                     bool SLPVectorize,
-                                    bool LoopVectorize) {
+                                    bool LoopVectorize,
+                                    const char* PGOGenPath,
+                                    const char* PGOUsePath) {
     // Ignore mergefunc for now as enabling it causes crashes.
     //unwrap(PMB)->MergeFunctions = MergeFunctions;
     unwrap(PMB)->SLPVectorize = SLPVectorize;
     unwrap(PMB)->OptLevel = from_rust(OptLevel);
     unwrap(PMB)->LoopVectorize = LoopVectorize;
+
+#if LLVM_VERSION_MINOR >= 9
+    if (PGOGenPath) {
+        assert(!PGOUsePath);
+        unwrap(PMB)->EnablePGOInstrGen = true;
+        unwrap(PMB)->PGOInstrGen = PGOGenPath;
+    }
+    if (PGOUsePath) {
+        assert(!PGOGenPath);
+        unwrap(PMB)->PGOInstrUse = PGOUsePath;
+    }
+#else
+    if (PGOGenPath || PGOUsePath)
+        report_fatal_error("profile-guided optimization requires LLVM 3.9");
+#endif
 }
 
 // Unfortunately, the LLVM C API doesn't provide a way to set the `LibraryInfo`
@@ -590,3 +613,158 @@ LLVMRustSetModulePIELevel(LLVMModuleRef M) {
     unwrap(M)->setPIELevel(PIELevel::Level::Large);
 #endif
 }
+
+// ThinLTO support
+//
+// The summaries are emitted wherever a module is serialized for ThinLTO, and
+// the thin link itself (building the combined index, deciding what gets
+// imported where, internalizing, and finally optimizing and generating code
+// for each module) is all handled by LLVM's `ThinLTOCodeGenerator`. These
+// bindings only shuttle buffers back and forth, and all of them are stubbed
+// out on LLVM versions which don't ship a usable ThinLTO implementation.
+
+struct LLVMRustThinLTOBuffer {
+    std::string data;
+};
+
+extern "C" bool
+LLVMRustThinLTOAvailable() {
+#if LLVM_VERSION_MINOR >= 9
+    return true;
+#else
+    return false;
+#endif
+}
+
+extern "C" LLVMRustThinLTOBuffer*
+LLVMRustThinLTOBufferCreate(LLVMModuleRef M) {
+#if LLVM_VERSION_MINOR >= 9
+    auto Ret = llvm::make_unique<LLVMRustThinLTOBuffer>();
+    {
+        raw_string_ostream OS(Ret->data);
+        legacy::PassManager PM;
+        PM.add(createBitcodeWriterPass(OS,
+                                       /* ShouldPreserveUseListOrder = */ false,
+                                       /* EmitSummaryIndex = */ true));
+        PM.run(*unwrap(M));
+    }
+    return Ret.release();
+#else
+    report_fatal_error("ThinLTO not available");
+#endif
+}
+
+extern "C" void
+LLVMRustThinLTOBufferFree(LLVMRustThinLTOBuffer *Buffer) {
+    delete Buffer;
+}
+
+extern "C" const char*
+LLVMRustThinLTOBufferPtr(const LLVMRustThinLTOBuffer *Buffer) {
+    return Buffer->data.data();
+}
+
+extern "C" size_t
+LLVMRustThinLTOBufferLen(const LLVMRustThinLTOBuffer *Buffer) {
+    return Buffer->data.length();
+}
+
+// Parses the bitcode of an upstream crate into a fresh module so that it can
+// be re-serialized along with a summary.
+extern "C" LLVMModuleRef
+LLVMRustParseBitcodeForThinLTO(LLVMContextRef Context,
+                               const char *data,
+                               size_t len,
+                               const char *identifier) {
+#if LLVM_VERSION_MINOR >= 9
+    MemoryBufferRef Buffer(StringRef(data, len), identifier);
+    ErrorOr<std::unique_ptr<Module>> SrcOrError =
+        parseBitcodeFile(Buffer, *unwrap(Context));
+    if (!SrcOrError) {
+        LLVMRustSetLastError(SrcOrError.getError().message().c_str());
+        return nullptr;
+    }
+    return wrap(std::move(*SrcOrError).release());
+#else
+    report_fatal_error("ThinLTO not available");
+#endif
+}
+
+#if LLVM_VERSION_MINOR >= 9
+typedef ThinLTOCodeGenerator LLVMRustThinLTOCodeGenerator;
+#else
+struct LLVMRustThinLTOCodeGenerator;
+#endif
+
+extern "C" LLVMRustThinLTOCodeGenerator*
+LLVMRustThinLTOCodeGeneratorCreate(LLVMTargetMachineRef TMR) {
+#if LLVM_VERSION_MINOR >= 9
+    TargetMachine *Target = unwrap(TMR);
+    auto CG = llvm::make_unique<ThinLTOCodeGenerator>();
+    CG->setTargetOptions(Target->Options);
+    CG->setCpu(Target->getTargetCPU());
+    CG->setAttr(Target->getTargetFeatureString());
+    CG->setCodePICModel(Target->getRelocationModel());
+    CG->setCodeGenOptLevel(Target->getOptLevel());
+    return CG.release();
+#else
+    report_fatal_error("ThinLTO not available");
+#endif
+}
+
+extern "C" void
+LLVMRustThinLTOCodeGeneratorFree(LLVMRustThinLTOCodeGenerator *CG) {
+#if LLVM_VERSION_MINOR >= 9
+    delete CG;
+#endif
+}
+
+// The module data must outlive the code generator, it's not copied.
+extern "C" void
+LLVMRustThinLTOAddModule(LLVMRustThinLTOCodeGenerator *CG,
+                         const char *identifier,
+                         const char *data,
+                         size_t len) {
+#if LLVM_VERSION_MINOR >= 9
+    CG->addModule(identifier, StringRef(data, len));
+#else
+    report_fatal_error("ThinLTO not available");
+#endif
+}
+
+extern "C" void
+LLVMRustThinLTOPreserveSymbol(LLVMRustThinLTOCodeGenerator *CG,
+                              const char *name) {
+#if LLVM_VERSION_MINOR >= 9
+    CG->preserveSymbol(name);
+#else
+    report_fatal_error("ThinLTO not available");
+#endif
+}
+
+extern "C" void
+LLVMRustThinLTORun(LLVMRustThinLTOCodeGenerator *CG) {
+#if LLVM_VERSION_MINOR >= 9
+    CG->run();
+#else
+    report_fatal_error("ThinLTO not available");
+#endif
+}
+
+// Returns the object file generated for the `idx`th module added to `CG`.
+extern "C" const char*
+LLVMRustThinLTOGetObject(LLVMRustThinLTOCodeGenerator *CG,
+                         size_t idx,
+                         size_t *len) {
+#if LLVM_VERSION_MINOR >= 9
+    auto &Binaries = CG->getProducedBinaries();
+    if (idx >= Binaries.size() || !Binaries[idx]) {
+        *len = 0;
+        return nullptr;
+    }
+    *len = Binaries[idx]->getBufferSize();
+    return Binaries[idx]->getBufferStart();
+#else
+    report_fatal_error("ThinLTO not available");
+#endif
+}
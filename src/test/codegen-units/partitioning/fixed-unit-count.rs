@@ -0,0 +1,55 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// ignore-tidy-linelength
+// compile-flags:-Zprint-trans-items=eager -Ccodegen-units=2
+
+#![allow(dead_code)]
+#![crate_type="lib"]
+
+// The smallest codegen units are merged into their closest relatives in the
+// module tree: `a::b` goes into `a`. The crate root is then equally close to
+// `a` and `c`, which are also of the same size, so it goes into `a` as that
+// name sorts first. The merged units keep the names of the modules they were
+// merged into.
+
+//~ TRANS_ITEM fn fixed_unit_count::foo[0] @@ fixed_unit_count-a[External]
+fn foo() {}
+
+mod a {
+    //~ TRANS_ITEM fn fixed_unit_count::a[0]::foo[0] @@ fixed_unit_count-a[External]
+    fn foo() {}
+    //~ TRANS_ITEM fn fixed_unit_count::a[0]::bar[0] @@ fixed_unit_count-a[External]
+    fn bar() {}
+
+    mod b {
+        //~ TRANS_ITEM fn fixed_unit_count::a[0]::b[0]::foo[0] @@ fixed_unit_count-a[External]
+        fn foo() {}
+    }
+}
+
+mod c {
+    // Generic code is instantiated in the unit of the module that uses it.
+    //~ TRANS_ITEM fn fixed_unit_count::c[0]::foo[0] @@ fixed_unit_count-c[External]
+    fn foo() -> i32 {
+        generic(0i32)
+    }
+    //~ TRANS_ITEM fn fixed_unit_count::c[0]::bar[0] @@ fixed_unit_count-c[External]
+    fn bar() {}
+    //~ TRANS_ITEM fn fixed_unit_count::c[0]::baz[0] @@ fixed_unit_count-c[External]
+    fn baz() {}
+
+    //~ TRANS_ITEM fn fixed_unit_count::c[0]::generic[0]<i32> @@ fixed_unit_count-c[Internal]
+    fn generic<T>(x: T) -> T {
+        x
+    }
+}
+
+//~ TRANS_ITEM drop-glue i8
@@ -0,0 +1,78 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// ignore-tidy-linelength
+// ignore-arm
+// ignore-aarch64
+// ignore-mips
+// ignore-powerpc
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+#![feature(asm)]
+
+// CHECK-LABEL: @default_options
+#[no_mangle]
+pub unsafe fn default_options() {
+    // CHECK: call void asm sideeffect alignstack inteldialect "nop", "~{memory},~{dirflag},~{fpsr},~{flags}"()
+    asm!("nop");
+}
+
+// CHECK-LABEL: @nomem_nostack
+#[no_mangle]
+pub unsafe fn nomem_nostack() {
+    // CHECK: call void asm sideeffect inteldialect "nop", "~{dirflag},~{fpsr},~{flags}"()
+    asm!("nop", options(nomem, nostack));
+}
+
+// CHECK-LABEL: @pure_att
+#[no_mangle]
+pub fn pure_att(x: u32) -> u32 {
+    let y: u32;
+    // CHECK: call i32 asm "mov ${1}, ${0}\0Aadd $$1, ${0}", "=&r,r,~{dirflag},~{fpsr},~{flags}"(i32 %{{.*}})
+    unsafe {
+        asm!("mov {1}, {0}",
+             "add $1, {0}",
+             out(reg) y,
+             in(reg) x,
+             options(pure, nomem, nostack, att_syntax));
+    }
+    y
+}
+
+// CHECK-LABEL: @operands
+#[no_mangle]
+pub fn operands(a: u32, b: u32) -> u32 {
+    let mut x = a;
+    let y: u32;
+    // CHECK: call { i32, i32 } asm inteldialect "add ${0}, ${2}\0Alea ${1:k}, [${0} + 1]", "=&r,=r,r,0,~{dirflag},~{fpsr},~{flags}"(i32 %{{.*}}, i32 %{{.*}})
+    unsafe {
+        asm!("add {x}, {b}",
+             "lea {y:e}, [{x} + 1]",
+             x = inout(reg) x,
+             y = lateout(reg) y,
+             b = in(reg) b,
+             options(pure, readonly, nostack));
+    }
+    x + y
+}
+
+// CHECK-LABEL: @explicit_registers
+#[no_mangle]
+pub unsafe fn explicit_registers(a: u32) -> u32 {
+    let b: u32;
+    // CHECK: call i32 asm sideeffect inteldialect "xchg eax, ecx", "=&{ecx},{eax},~{edx},~{dirflag},~{fpsr},~{flags}"(i32 %{{.*}})
+    asm!("xchg eax, ecx",
+         out("ecx") b,
+         in("eax") a,
+         out("edx") _,
+         options(nomem, nostack));
+    b
+}
@@ -0,0 +1,35 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -O
+
+#![crate_type = "lib"]
+#![feature(bench_black_box)]
+
+use std::hint::black_box;
+
+// Check that the constant isn't folded through `black_box`.
+// CHECK-LABEL: @opaque_add
+#[no_mangle]
+pub fn opaque_add() -> u32 {
+// CHECK: store volatile i32 2
+// CHECK: load volatile i32
+// CHECK: add
+    black_box(2u32) + 2
+}
+
+// CHECK-LABEL: @opaque_slice
+#[no_mangle]
+pub fn opaque_slice(x: &[u8]) -> usize {
+// CHECK: store volatile
+// CHECK: store volatile
+// CHECK: load volatile
+    black_box(x).len()
+}
@@ -13,59 +13,47 @@
 // compile-flags: -C no-prepopulate-passes
 
 #![crate_type = "lib"]
-#![feature(naked_functions, rustc_attrs)]
+#![feature(asm, naked_functions)]
 
 // CHECK: Function Attrs: naked uwtable
 // CHECK-NEXT: define internal void @naked_empty()
 #[no_mangle]
 #[naked]
 fn naked_empty() {
-    // CHECK: ret void
+    // CHECK: call void asm sideeffect {{.*}}"ret"
+    // CHECK-NEXT: unreachable
+    unsafe { asm!("ret", options(nostack)); }
 }
 
 // CHECK: Function Attrs: naked uwtable
-#[no_mangle]
-#[naked]
-#[rustc_no_mir] // FIXME #27840 MIR has different codegen.
 // CHECK-NEXT: define internal void @naked_with_args(i{{[0-9]+}})
-fn naked_with_args(a: isize) {
-    // CHECK: %a = alloca i{{[0-9]+}}
-    // CHECK: ret void
-}
-
-// CHECK: Function Attrs: naked uwtable
-// CHECK-NEXT: define internal i{{[0-9]+}} @naked_with_return()
 #[no_mangle]
 #[naked]
-fn naked_with_return() -> isize {
-    // CHECK: ret i{{[0-9]+}} 0
-    0
+fn naked_with_args(_a: isize) {
+    // CHECK-NOT: alloca
+    // CHECK: call void asm sideeffect {{.*}}"ret"
+    // CHECK-NEXT: unreachable
+    unsafe { asm!("ret", options(nostack)); }
 }
 
 // CHECK: Function Attrs: naked uwtable
-// CHECK-NEXT: define internal i{{[0-9]+}} @naked_with_args_and_return(i{{[0-9]+}})
+// CHECK-NEXT: define internal void @naked_unsafe(i{{[0-9]+}}, i{{[0-9]+}})
 #[no_mangle]
 #[naked]
-#[rustc_no_mir] // FIXME #27840 MIR has different codegen.
-fn naked_with_args_and_return(a: isize) -> isize {
-    // CHECK: %a = alloca i{{[0-9]+}}
-    // CHECK: ret i{{[0-9]+}} %{{[0-9]+}}
-    a
+unsafe fn naked_unsafe(_a: isize, _: isize) {
+    // CHECK-NOT: alloca
+    // CHECK: call void asm sideeffect {{.*}}"ret"
+    // CHECK-NEXT: unreachable
+    asm!("ret", options(nostack));
 }
 
-// CHECK: Function Attrs: naked uwtable
-// CHECK-NEXT: define internal void @naked_recursive()
+// CHECK-LABEL: define void @call_naked()
 #[no_mangle]
-#[naked]
-fn naked_recursive() {
+pub fn call_naked() {
     // CHECK: call void @naked_empty()
     naked_empty();
-    // CHECK: %{{[0-9]+}} = call i{{[0-9]+}} @naked_with_return()
-    naked_with_args(
-        // CHECK: %{{[0-9]+}} = call i{{[0-9]+}} @naked_with_args_and_return(i{{[0-9]+}} %{{[0-9]+}})
-        naked_with_args_and_return(
-            // CHECK: call void @naked_with_args(i{{[0-9]+}} %{{[0-9]+}})
-            naked_with_return()
-        )
-    );
+    // CHECK: call void @naked_with_args(i{{[0-9]+}} 1)
+    naked_with_args(1);
+    // CHECK: call void @naked_unsafe(i{{[0-9]+}} 2, i{{[0-9]+}} 3)
+    unsafe { naked_unsafe(2, 3) }
 }
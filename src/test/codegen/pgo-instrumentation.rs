@@ -0,0 +1,23 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Test that `-C profile-generate` instruments the generated code.
+
+// compile-flags: -O -C profile-generate=/tmp/rustc-pgo
+
+#![crate_type="lib"]
+
+// CHECK: @__profc_{{.*}}count_calls
+// CHECK-LABEL: @count_calls
+// CHECK: load {{.*}} @__profc_{{.*}}count_calls
+#[no_mangle]
+pub fn count_calls(x: u32) -> u32 {
+    if x > 10 { x * 2 } else { x + 1 }
+}
@@ -0,0 +1,37 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -C opt-level=3
+
+#![crate_type = "lib"]
+#![feature(portable_simd)]
+
+use std::simd::{f32x4, m32x4, u8x16};
+
+// CHECK-LABEL: @add
+#[no_mangle]
+pub fn add(a: f32x4, b: f32x4) -> f32x4 {
+    // CHECK: fadd <4 x float>
+    a + b
+}
+
+// CHECK-LABEL: @lt
+#[no_mangle]
+pub fn lt(a: f32x4, b: f32x4) -> m32x4 {
+    // CHECK: fcmp olt <4 x float>
+    a.lt(b)
+}
+
+// CHECK-LABEL: @max
+#[no_mangle]
+pub fn max(a: u8x16, b: u8x16) -> u8x16 {
+    // CHECK: icmp ugt <16 x i8>
+    a.max(b)
+}
@@ -0,0 +1,35 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -O
+
+#![crate_type = "lib"]
+#![feature(reverse_bits)]
+
+// CHECK-LABEL: @reverse_u8
+#[no_mangle]
+pub fn reverse_u8(x: u8) -> u8 {
+// CHECK: call i8 @llvm.bitreverse.i8
+    x.reverse_bits()
+}
+
+// CHECK-LABEL: @reverse_i32
+#[no_mangle]
+pub fn reverse_i32(x: i32) -> i32 {
+// CHECK: call i32 @llvm.bitreverse.i32
+    x.reverse_bits()
+}
+
+// CHECK-LABEL: @reverse_u64
+#[no_mangle]
+pub fn reverse_u64(x: u64) -> u64 {
+// CHECK: call i64 @llvm.bitreverse.i64
+    x.reverse_bits()
+}
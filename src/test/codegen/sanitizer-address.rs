@@ -0,0 +1,33 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Test that `-Z sanitizer=address` marks functions for instrumentation.
+
+// The sanitizers are only supported on x86_64-unknown-linux-gnu.
+// ignore-windows
+// ignore-macos
+// ignore-ios
+// ignore-android
+// ignore-freebsd
+// ignore-musl
+// ignore-x86
+// ignore-arm
+// ignore-aarch64
+
+// compile-flags: -C no-prepopulate-passes -Z sanitizer=address
+
+#![crate_type="lib"]
+
+// CHECK: Function Attrs: {{.*}}sanitize_address
+// CHECK-NEXT: define i32 @load
+#[no_mangle]
+pub fn load(x: &i32) -> i32 {
+    *x
+}
@@ -0,0 +1,44 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Test that functions whose frame is larger than a page probe every page of
+// it before using it, and that other functions don't.
+
+// ignore-windows
+// ignore-android
+// ignore-ios
+// ignore-asmjs
+// ignore-arm
+// ignore-aarch64
+// ignore-mips
+// ignore-powerpc
+
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type="lib"]
+
+// CHECK-LABEL: @huge_frame
+#[no_mangle]
+pub fn huge_frame() {
+    // CHECK: alloca [1048576 x i8]
+    // CHECK: call void asm sideeffect "lea {{[0-9]+}}(%{{[er]}}sp)
+    // CHECK: store
+    let buf = [0u8; 1 << 20];
+    drop(buf);
+}
+
+// CHECK-LABEL: @small_frame
+#[no_mangle]
+pub fn small_frame() {
+    // CHECK-NOT: asm sideeffect
+    // CHECK: ret void
+    let buf = [0u8; 16];
+    drop(buf);
+}
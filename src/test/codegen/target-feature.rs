@@ -0,0 +1,28 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+#![feature(target_feature)]
+
+// CHECK: define void @avx2() unnamed_addr #[[ATTRS:[0-9]+]]
+#[no_mangle]
+#[target_feature(enable = "avx2")]
+pub unsafe fn avx2() {}
+
+// CHECK: define void @several() unnamed_addr #[[ATTRS2:[0-9]+]]
+#[no_mangle]
+#[target_feature(enable = "sse4.2,popcnt")]
+#[target_feature(enable = "avx")]
+pub unsafe fn several() {}
+
+// CHECK: attributes #[[ATTRS]] = {{.*}}"target-features"="+avx2"
+// CHECK: attributes #[[ATTRS2]] = {{.*}}"target-features"="+sse4.2,+popcnt,+avx"
@@ -0,0 +1,21 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -O
+
+#![crate_type = "lib"]
+#![feature(used)]
+
+// CHECK: @llvm.used = appending global [1 x i8*] [{{.*}}@{{.*}}FOO{{.*}}], section "llvm.metadata"
+
+// Nothing refers to `FOO` and it isn't exported, so without `#[used]` the
+// optimizer would remove it.
+#[used]
+static FOO: [u8; 4] = *b"used";
@@ -0,0 +1,64 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// ignore-arm
+// ignore-aarch64
+// ignore-mips
+// ignore-powerpc
+
+#![feature(asm)]
+
+fn main() {
+    let mut x: u32 = 0;
+    unsafe {
+        asm!("{}", in(foo) x);
+        //~^ ERROR invalid register class `foo` for target architecture
+        asm!("{}", in(reg) x, options(pure, nomem));
+        //~^ ERROR asm with the `pure` option must have at least one output
+        asm!("{}", out(reg) x, options(pure));
+        //~^ ERROR the `pure` option must be combined with either `nomem` or `readonly`
+        asm!("{}", out(reg) x, options(nomem, readonly));
+        //~^ ERROR the `nomem` and `readonly` options are mutually exclusive
+        asm!("", options(volatile));
+        //~^ ERROR unknown option `volatile`
+        asm!("{}", in(reg) x, options(nostack), in(reg) x);
+        //~^ ERROR the options must come last
+        asm!("{}", in(reg) x, "nop");
+        //~^ ERROR template strings must come before the operands
+        asm!("{a} {1}", a = in(reg) x, in(reg) x);
+        //~^ ERROR positional arguments cannot follow named arguments
+        asm!("{0} {a}", a = in(reg) x, a = in(reg) x);
+        //~^ ERROR duplicate argument named `a`
+        asm!("", reg = in("eax") x);
+        //~^ ERROR explicit register arguments cannot have names
+        asm!("{}", in("eax") x);
+        //~^ ERROR explicit register arguments cannot be used in the asm template
+        asm!("{}", out(reg) _);
+        //~^ ERROR outputs can only be discarded with `_` when using an explicit register
+        asm!("{}", inout(reg) x => _);
+        //~^ ERROR the output of an `inout` operand cannot be discarded
+        asm!("{1}", in(reg) x);
+        //~^ ERROR invalid reference to argument at index 1
+        //~| ERROR argument never used
+        asm!("{b}", in(reg) x);
+        //~^ ERROR there is no argument named `b`
+        //~| ERROR argument never used
+        asm!("{0:q}", in(reg) x);
+        //~^ ERROR invalid modifier `q` for register class `reg`
+        asm!("{0:>8}", in(reg) x);
+        //~^ ERROR asm template placeholders only accept a modifier
+        asm!("{", in(reg) x);
+        //~^ ERROR invalid asm template string
+        asm!("", in(reg) x);
+        //~^ ERROR argument never used
+        asm!("{}", foo(reg) x);
+        //~^ ERROR expected one of `in`, `out`, `lateout`, `inout`, `inlateout` or `options`
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// ignore-x86
+// ignore-arm
+// ignore-aarch64
+// ignore-mips
+// ignore-powerpc
+// ignore-powerpc64
+
+#![feature(asm)]
+
+fn main() {
+    let x: u64 = 0;
+    unsafe {
+        asm!("", in("foo") x);
+        //~^ ERROR invalid register `foo`
+        asm!("", in("xmm16") x);
+        //~^ ERROR invalid register `xmm16`
+        asm!("", in("r16") x);
+        //~^ ERROR invalid register `r16`
+        asm!("", in("rsp") x);
+        //~^ ERROR the stack pointer cannot be used as an operand for inline asm
+        asm!("", in("rbp") x);
+        //~^ ERROR the frame pointer cannot be used as an operand for inline asm
+        asm!("", in("rbx") x);
+        //~^ ERROR rbx is used internally by LLVM and cannot be used as an operand
+        asm!("", out("bl") _);
+        //~^ ERROR rbx is used internally by LLVM and cannot be used as an operand
+        asm!("", in("rip") x);
+        //~^ ERROR the instruction pointer cannot be used as an operand for inline asm
+        asm!("", in("k0") x);
+        //~^ ERROR the k0 AVX mask register cannot be used as an operand for inline asm
+    }
+}
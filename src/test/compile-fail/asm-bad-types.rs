@@ -0,0 +1,53 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// ignore-x86
+// ignore-arm
+// ignore-aarch64
+// ignore-mips
+// ignore-powerpc
+// ignore-powerpc64
+
+#![feature(asm)]
+
+struct Foo(u64);
+
+fn main() {
+    let a: u8 = 0;
+    let b: u64 = 0;
+    let mut c: u16 = 0;
+    let s: &[u8] = &[];
+    unsafe {
+        // These are fine.
+        asm!("", in("al") a);
+        asm!("{}", in(reg_byte) a);
+        asm!("{}", in(reg) b);
+        asm!("{}", in(xmm_reg) b);
+        asm!("{}", in(reg) &b);
+        asm!("", inout("cx") c);
+
+        asm!("{}", in(reg) a);
+        //~^ ERROR type `u8` cannot be used with register class `reg`
+        asm!("{}", in(reg_byte) b);
+        //~^ ERROR type `u64` cannot be used with register class `reg_byte`
+        asm!("", in("al") b);
+        //~^ ERROR type `u64` cannot be used with register class `reg_byte`
+        asm!("{}", inout(xmm_reg) c);
+        //~^ ERROR type `u16` cannot be used with register class `xmm_reg`
+        asm!("{}", in(reg) Foo(b));
+        //~^ ERROR type `Foo` cannot be used with register class `reg`
+        asm!("{}", in(reg) s);
+        //~^ ERROR type `&[u8]` cannot be used with register class `reg`
+        asm!("{}", in(reg) true);
+        //~^ ERROR type `bool` cannot be used with register class `reg`
+        asm!("{}", inout(reg) b => a);
+        //~^ ERROR type `u8` cannot be used with register class `reg`
+    }
+}
@@ -21,10 +21,10 @@ pub fn main() {
     x = 1; //~ NOTE first assignment
     foo(x);
     unsafe {
-        asm!("mov $1, $0" : "=r"(x) : "r"(5));
+        llvm_asm!("mov $1, $0" : "=r"(x) : "r"(5));
         //~^ ERROR re-assignment of immutable variable `x`
         //~| NOTE re-assignment of immutable
-        //~| NOTE in this expansion of asm!
+        //~| NOTE in this expansion of llvm_asm!
     }
     foo(x);
 }
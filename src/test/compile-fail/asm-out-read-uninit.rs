@@ -19,7 +19,8 @@ fn foo(x: isize) { println!("{}", x); }
 pub fn main() {
     let x: isize;
     unsafe {
-        asm!("mov $1, $0" : "=r"(x) : "r"(x)); //~ ERROR use of possibly uninitialized variable: `x`
+        llvm_asm!("mov $1, $0" : "=r"(x) : "r"(x));
+        //~^ ERROR use of possibly uninitialized variable: `x`
     }
     foo(x);
 }
@@ -17,6 +17,6 @@
 
 fn main() {
     unsafe {
-        asm!("nowayisthisavalidinstruction");
+        llvm_asm!("nowayisthisavalidinstruction");
     }
 }
@@ -0,0 +1,46 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(deprecated_suggestion)]
+#![deny(deprecated)]
+#![allow(dead_code)]
+
+mod foo {
+    #[deprecated(since = "1.0.0", note = "renamed", suggestion = "new_function")]
+    pub fn old_function() {}
+
+    pub fn new_function() {}
+}
+
+struct Foo;
+
+impl Foo {
+    #[deprecated(since = "1.0.0", suggestion = "new_method")]
+    fn old_method(&self) {}
+
+    fn new_method(&self) {}
+}
+
+mod bogus {
+    #[deprecated(suggestion = "a", suggestion = "b")] //~ ERROR multiple 'suggestion' items
+    fn f() {}
+
+    #[deprecated(suggestion)] //~ ERROR incorrect meta item
+    fn g() {}
+}
+
+fn main() {
+    foo::old_function();
+    //~^ ERROR use of deprecated item: renamed
+    //~| HELP replace the use of the deprecated item
+    Foo.old_method();
+    //~^ ERROR use of deprecated item
+    //~| HELP replace the use of the deprecated item
+}
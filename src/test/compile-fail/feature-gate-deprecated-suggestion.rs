@@ -8,16 +8,10 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-pub struct T;
+#[deprecated(since = "1.0.0", suggestion = "bar")]
+//~^ ERROR the `suggestion` field of `#[deprecated]` is experimental
+fn foo() {}
 
-#[unsafe_no_drop_flag]
-//~^ ERROR unsafe_no_drop_flag has unstable semantics and may be removed
-pub struct S {
-    pub x: T,
-}
+fn bar() {}
 
-impl Drop for S {
-    fn drop(&mut self) {}
-}
-
-pub fn main() {}
+fn main() {}
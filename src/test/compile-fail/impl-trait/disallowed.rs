@@ -10,9 +10,11 @@
 
 #![feature(conservative_impl_trait)]
 
-fn arguments(_: impl Fn(),
+// `impl Trait` in arguments is tested in `universal-*.rs`; the arguments of
+// function pointers and `Fn` sugar aren't arguments of the function though.
+fn arguments(_: fn(impl Fn()),
 //~^ ERROR `impl Trait` not allowed outside of function and inherent method return types
-             _: Vec<impl Clone>) {}
+             _: Box<Fn(impl Clone)>) {}
 //~^ ERROR `impl Trait` not allowed outside of function and inherent method return types
 
 type Factory<R> = impl Fn() -> R;
@@ -0,0 +1,22 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(universal_impl_trait)]
+
+// The bounds of `impl Trait` in argument position are those of a type
+// parameter, so they can't elide lifetimes.
+fn first(_: impl Iterator<Item = &u8>) {}
+//~^ ERROR missing lifetime specifier
+
+// Nor does the `impl Trait` itself provide a lifetime for the return type.
+fn name(x: impl AsRef<str>) -> &str { "" }
+//~^ ERROR missing lifetime specifier
+
+fn main() {}
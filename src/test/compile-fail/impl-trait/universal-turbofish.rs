@@ -0,0 +1,30 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(universal_impl_trait)]
+
+use std::fmt::Debug;
+
+fn foo<T: Debug>(x: T, y: impl Debug) {}
+
+struct S;
+
+impl S {
+    fn bar(&self, x: impl Debug) {}
+}
+
+fn main() {
+    foo::<u8>(1, 2);
+    //~^ ERROR cannot provide explicit type parameters when `impl Trait` is used in argument position
+    foo(1, 2); // OK
+    S.bar::<u8>(1);
+    //~^ ERROR cannot provide explicit type parameters when `impl Trait` is used in argument position
+    S.bar(1); // OK
+}
@@ -11,6 +11,6 @@
 
 fn main() {
     let a;
-    asm!("nop" "nop"); //~ ERROR malformed inline assembly
-    asm!("nop" "nop" : "=r"(a)); //~ ERROR malformed inline assembly
+    llvm_asm!("nop" "nop"); //~ ERROR malformed inline assembly
+    llvm_asm!("nop" "nop" : "=r"(a)); //~ ERROR malformed inline assembly
 }
@@ -0,0 +1,21 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Test that lint groups can be named on the command line, with dashes.
+
+// compile-flags: -D bad-style -A unused
+
+use std::option;
+
+fn CamelCase() {} //~ ERROR function `CamelCase` should have a snake case name
+
+static bad: isize = 1; //~ ERROR static constant `bad` should have an upper case name
+
+fn main() {}
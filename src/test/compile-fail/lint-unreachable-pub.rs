@@ -0,0 +1,42 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![crate_type = "lib"]
+#![feature(pub_restricted)]
+#![deny(unreachable_pub)]
+#![allow(dead_code)]
+
+pub mod exported {
+    pub struct Reachable {
+        pub field: u8,
+    }
+
+    impl Reachable {
+        pub fn method(&self) {}
+    }
+}
+
+mod private {
+    pub struct Unreachable { //~ ERROR unreachable `pub` item
+        pub field: u8, //~ ERROR unreachable `pub` field
+    }
+
+    impl Unreachable {
+        pub fn method(&self) {} //~ ERROR unreachable `pub` item
+    }
+
+    pub fn unreachable() {} //~ ERROR unreachable `pub` item
+
+    pub struct Reexported;
+
+    pub(crate) struct Restricted;
+}
+
+pub use private::Reexported;
@@ -0,0 +1,50 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// General test of maybe_live state computed by MIR dataflow, which
+// propagates backwards through the control flow.
+
+#![feature(rustc_attrs)]
+#![feature(stmt_expr_attributes)]
+
+use std::intrinsics::rustc_peek;
+
+#[rustc_mir_borrowck]
+#[rustc_mir(rustc_peek_maybe_live,stop_after_dataflow)]
+fn foo(test: bool, x: u32, y: u32) -> u32 {
+    let a = 1;
+    let b;
+
+    // `b` is assigned before it is ever read, so it is not live here.
+    unsafe { rustc_peek(&b); } //~ ERROR rustc_peek: bit not set
+
+    // `a` is read at the very end.
+    unsafe { rustc_peek(&a); }
+
+    // `y` is only read on one of the paths below, which is enough.
+    unsafe { rustc_peek(&y); }
+    unsafe { rustc_peek(&test); }
+
+    b = if test { x } else { y };
+
+    // Nothing reads the arguments any more.
+    unsafe { rustc_peek(&test); } //~ ERROR rustc_peek: bit not set
+    unsafe { rustc_peek(&x); } //~ ERROR rustc_peek: bit not set
+
+    // `b` is read by the return value.
+    unsafe { rustc_peek(&b); }
+
+    a + b
+}
+
+fn main() {
+    foo(true, 1, 2);
+    foo(false, 1, 2);
+}
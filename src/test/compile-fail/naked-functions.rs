@@ -0,0 +1,57 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(asm, naked_functions)]
+
+struct Pair(u32, u32);
+
+#[naked]
+unsafe fn patterns(Pair(a, b): Pair, (c, d): (u32, u32), ref e: u32) {
+    //~^ ERROR patterns aren't allowed in naked function parameters
+    //~| ERROR patterns aren't allowed in naked function parameters
+    //~| ERROR patterns aren't allowed in naked function parameters
+    asm!("ret", options(nostack));
+}
+
+#[naked]
+unsafe fn input(a: u32) {
+    asm!("mov eax, {}", in(reg) a, options(nostack));
+    //~^ ERROR referencing function parameters is not allowed in naked functions
+}
+
+#[naked]
+unsafe fn output(b: &mut u32) {
+    asm!("mov {}, eax", out(reg) *b, options(nostack));
+    //~^ ERROR referencing function parameters is not allowed in naked functions
+}
+
+#[naked]
+fn empty() {}
+//~^ ERROR naked functions must contain a single asm block
+
+#[naked]
+unsafe fn two_asm_blocks() {
+//~^ ERROR naked functions must contain a single asm block
+    asm!("nop", options(nostack));
+    asm!("ret", options(nostack));
+}
+
+#[naked]
+fn not_asm() -> u32 {
+//~^ ERROR naked functions must contain a single asm block
+    0
+}
+
+#[naked]
+unsafe fn ok(_a: u32, mut _b: u32, _: u32) {
+    asm!("ret", options(nostack));
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+struct Foo;
+
+impl Foo {
+    fn bar(self) {}
+    fn baz(&self, x: u8) -> bool { x > 0 }
+    fn new() -> Foo { Foo }
+}
+
+fn main() {
+    let f = Foo;
+    f.bazz(1u8);
+    //~^ ERROR no method named `bazz` found for type `Foo` in the current scope
+    //~| HELP did you mean `baz`?
+
+    let f = &Foo;
+    f.barr();
+    //~^ ERROR no method named `barr` found for type `&Foo` in the current scope
+    //~| HELP did you mean `bar`?
+
+    // Associated functions without `self` are not suggested as methods.
+    Foo.neww();
+    //~^ ERROR no method named `neww` found for type `Foo` in the current scope
+}
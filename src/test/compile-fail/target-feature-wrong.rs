@@ -0,0 +1,56 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(target_feature)]
+
+#[target_feature(enable = "sse2")]
+//~^ ERROR `#[target_feature]` can only be applied to `unsafe` functions
+fn safe() {}
+
+#[target_feature(enable = "sse2")]
+//~^ ERROR `#[target_feature]` can only be applied to `unsafe` functions
+struct Foo;
+
+#[target_feature = "sse2"]
+//~^ ERROR malformed `target_feature` attribute
+unsafe fn bad_syntax() {}
+
+#[target_feature(sse2)]
+//~^ ERROR malformed `target_feature` attribute
+unsafe fn bad_item() {}
+
+struct Bar;
+
+impl Bar {
+    #[target_feature(enable = "sse2")]
+    //~^ ERROR `#[target_feature]` can only be applied to `unsafe` functions
+    fn method(&self) {}
+
+    #[target_feature(enable = "sse2")]
+    unsafe fn unsafe_method(&self) {}
+}
+
+trait Baz {
+    #[target_feature(enable = "sse2")]
+    //~^ ERROR `#[target_feature]` can only be applied to `unsafe` functions
+    fn default_method(&self) {}
+
+    #[target_feature(enable = "sse2")]
+    //~^ ERROR `#[target_feature]` can only be applied to `unsafe` functions
+    fn required_method(&self);
+
+    #[target_feature(enable = "sse2")]
+    unsafe fn unsafe_default_method(&self) {}
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn ok() {}
+
+fn main() {}
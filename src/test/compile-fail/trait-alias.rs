@@ -0,0 +1,26 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(trait_alias)]
+
+use std::rc::Rc;
+
+trait Async = Send + Sync + 'static;
+
+fn needs_async<T: Async>(_: T) {}
+
+// An alias is implemented for every type satisfying its bounds, and for no
+// others.
+impl Async for u8 {} //~ ERROR E0119
+
+fn main() {
+    needs_async(1u8);
+    needs_async(Rc::new(1u8)); //~ ERROR E0277
+}
@@ -0,0 +1,35 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Test that unresolved values are matched against items of the same
+// name elsewhere, and that the candidates are suggested as imports.
+
+mod foo {
+    pub fn frobnicate() {}
+    pub const LIMIT: usize = 10;
+}
+
+mod bar {
+    pub mod baz {
+        pub struct Unit;
+    }
+}
+
+fn main() {
+    frobnicate();
+    //~^ ERROR unresolved name `frobnicate`
+    //~| HELP you can import it into scope: `use foo::frobnicate;`.
+    let _ = LIMIT;
+    //~^ ERROR unresolved name `LIMIT`
+    //~| HELP you can import it into scope: `use foo::LIMIT;`.
+    let _ = Unit;
+    //~^ ERROR unresolved name `Unit`
+    //~| HELP you can import it into scope: `use bar::baz::Unit;`.
+}
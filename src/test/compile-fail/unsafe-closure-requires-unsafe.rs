@@ -0,0 +1,35 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unsafe operations inside a closure are allowed if the closure itself is
+// created in an unsafe context.
+
+#![deny(unused_unsafe)]
+
+static mut COUNT: u32 = 0;
+
+unsafe fn f() {}
+
+fn safe() {
+    let _ = || f(); //~ ERROR call to unsafe function requires unsafe function or block
+    let _ = || || COUNT += 1; //~ ERROR use of mutable static requires unsafe function or block
+}
+
+unsafe fn unsafe_fn() {
+    let _ = || f();
+    let _ = || || COUNT += 1;
+}
+
+fn main() {
+    unsafe {
+        let _ = || f();
+        let _ = || unsafe { COUNT += 1 }; //~ ERROR unnecessary `unsafe` block
+    }
+}
@@ -0,0 +1,27 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z variant-size-ratio=2
+
+#![deny(variant_size_differences)]
+
+enum _Small {
+    V0(u8),
+    V1([u8; 2]),
+}
+
+enum _Large {
+    V0(u8),
+    V1([u8; 3]),
+    //~^ ERROR variant is more than 2 times larger (3 bytes) than the next largest (1 byte)
+    //~| HELP consider boxing the large fields of `V1` to reduce the total size of `_Large`
+}
+
+fn main() {}
@@ -12,7 +12,7 @@
 
 enum _En {
     V0(u8),
-    VBig([u8; 1024]),   //~ ERROR variant is more than three times larger
+    VBig([u8; 1024]),   //~ ERROR variant is more than 3 times larger (1024 bytes) than the next largest (1 byte)
 }
 
 fn main() {}
@@ -0,0 +1,33 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+fn main() {
+    let x = 2 + 2;
+    let _y = x * 3;
+}
+
+// END RUST SOURCE
+// START rustc.node4.ConstPropagation.before.mir
+// bb0: {
+//     tmp0 = CheckedAdd(const 2i32, const 2i32);
+//     assert(!(tmp0.1: bool), "attempt to add with overflow") -> bb1;
+// }
+// bb1: {
+//     var0 = (tmp0.0: i32);
+// END rustc.node4.ConstPropagation.before.mir
+// START rustc.node4.ConstPropagation.after.mir
+// bb0: {
+//     tmp0 = CheckedAdd(const 2i32, const 2i32);
+//     assert(!const false, "attempt to add with overflow") -> bb1;
+// }
+// bb1: {
+//     var0 = const 4i32;
+//     var1 = const 12i32;
+// END rustc.node4.ConstPropagation.after.mir
@@ -0,0 +1,36 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+fn test(x: u32) -> u32 {
+    let y = x;
+    y
+}
+
+fn main() { }
+
+// END RUST SOURCE
+// START rustc.node4.CopyPropagation.before.mir
+// bb0: {
+//     var0 = arg0;
+//     tmp0 = var0;
+//     var1 = tmp0;
+//     tmp1 = var1;
+//     return = tmp1;
+//     goto -> bb1;
+// }
+// END rustc.node4.CopyPropagation.before.mir
+// START rustc.node4.CopyPropagation.after.mir
+// bb0: {
+//     var0 = arg0;
+//     var1 = var0;
+//     return = var1;
+//     goto -> bb1;
+// }
+// END rustc.node4.CopyPropagation.after.mir
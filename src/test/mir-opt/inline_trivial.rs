@@ -0,0 +1,31 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+fn main() {
+    let _x = seven();
+}
+
+fn seven() -> u32 {
+    7
+}
+
+// END RUST SOURCE
+// START rustc.node4.Inline.before.mir
+// bb0: {
+//     var0 = seven() -> bb1;
+// }
+// END rustc.node4.Inline.before.mir
+// START rustc.node4.Inline.after.mir
+// bb0: {
+//     goto -> bb2;
+// }
+// bb2: {
+//     var0 = const 7u32;
+// END rustc.node4.Inline.after.mir
@@ -16,7 +16,7 @@ use std::intrinsics;
 pub fn exit(n: usize) -> ! {
     unsafe {
         // Pretend this asm is an exit() syscall.
-        asm!("" :: "r"(n) :: "volatile");
+        llvm_asm!("" :: "r"(n) :: "volatile");
         intrinsics::unreachable()
     }
 }
@@ -0,0 +1,103 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(asm)]
+
+#[cfg(target_arch = "x86_64")]
+fn add(a: u64, b: u64) -> u64 {
+    let mut c = a;
+    unsafe {
+        asm!("add {0}, {1}", inout(reg) c, in(reg) b, options(pure, nomem, nostack));
+    }
+    c
+}
+
+#[cfg(target_arch = "x86_64")]
+fn shift_left(x: u64, n: u8) -> u64 {
+    let y: u64;
+    unsafe {
+        asm!("mov {y}, {x}",
+             "shl {y}, cl",
+             x = in(reg) x,
+             y = out(reg) y,
+             in("cl") n,
+             options(pure, nomem, nostack));
+    }
+    y
+}
+
+#[cfg(target_arch = "x86_64")]
+fn low_half(x: u64) -> u32 {
+    let y: u32;
+    unsafe {
+        asm!("mov {0:e}, {1:e}", lateout(reg) y, in(reg) x, options(pure, nomem, nostack));
+    }
+    y
+}
+
+#[cfg(target_arch = "x86_64")]
+fn increment_att(x: u64) -> u64 {
+    let y: u64;
+    unsafe {
+        asm!("lea 1({1}), {0}", out(reg) y, in(reg) x,
+             options(pure, nomem, nostack, att_syntax));
+    }
+    y
+}
+
+#[cfg(target_arch = "x86_64")]
+fn swap(a: u64, b: u64) -> (u64, u64) {
+    let (x, y);
+    unsafe {
+        asm!("xchg {0}, {1}", inout(reg) a => x, inout(reg) b => y, options(nomem, nostack));
+    }
+    (x, y)
+}
+
+#[cfg(target_arch = "x86_64")]
+fn read(p: &u64) -> u64 {
+    let x: u64;
+    unsafe {
+        asm!("mov {}, [{}]", lateout(reg) x, in(reg) p, options(pure, readonly, nostack));
+    }
+    x
+}
+
+#[cfg(target_arch = "x86_64")]
+fn write(p: &mut u64, x: u64) {
+    unsafe {
+        asm!("mov [{}], {}", in(reg) p, in(reg) x, options(nostack));
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn main() {
+    assert_eq!(add(3, 14159), 14162);
+    assert_eq!(shift_left(3, 4), 48);
+    assert_eq!(low_half(0x1234_5678_9abc_def0), 0x9abc_def0);
+    assert_eq!(increment_att(41), 42);
+    assert_eq!(swap(1, 2), (2, 1));
+
+    let mut x = 5;
+    assert_eq!(read(&x), 5);
+    write(&mut x, 7);
+    assert_eq!(x, 7);
+
+    // Without `nomem` or `readonly`, the asm may write any memory, so `y`
+    // has to be read again afterwards.
+    let mut y: u64 = 1;
+    unsafe {
+        asm!("mov qword ptr [{}], 2", in(reg) &mut y);
+    }
+    assert_eq!(y, 2);
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn main() {}
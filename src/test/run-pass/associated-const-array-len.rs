@@ -0,0 +1,45 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(associated_consts)]
+
+trait Foo {
+    const N: usize;
+    const M: usize = 2;
+}
+
+struct A;
+impl Foo for A {
+    const N: usize = 3;
+}
+
+struct B;
+impl Foo for B {
+    const N: usize = 4;
+    const M: usize = 5;
+}
+
+impl Foo for u8 {
+    const N: usize = 7;
+}
+
+fn main() {
+    let a: [u8; <A as Foo>::N] = [0; <A as Foo>::N];
+    assert_eq!(a.len(), 3);
+
+    let b: [u8; <B as Foo>::N] = [1; 4];
+    assert_eq!(b, [1, 1, 1, 1]);
+
+    // Defaults from the trait are used if the impl doesn't override them.
+    let m: [u8; <A as Foo>::M] = [0; <A as Foo>::M];
+    assert_eq!(m.len(), 2);
+
+    assert_eq!([0u16; <u8 as Foo>::N].len(), 7);
+}
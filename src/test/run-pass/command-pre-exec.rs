@@ -0,0 +1,70 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// ignore-windows - this is a unix-specific test
+// ignore-emscripten
+
+#![feature(process_pre_exec, libc)]
+
+extern crate libc;
+
+use std::env;
+use std::io::Error;
+use std::os::unix::process::CommandExt;
+use std::process::Command;
+
+fn main() {
+    if let Some(arg) = env::args().skip(1).next() {
+        match &arg[..] {
+            "test1" => println!("hello2"),
+            "setsid" => {
+                // The child was made the leader of a new session.
+                let pid = unsafe { libc::getpid() };
+                assert_eq!(unsafe { libc::getsid(0) }, pid);
+            }
+            _ => panic!("unknown argument: {}", arg),
+        }
+        return
+    }
+
+    let me = env::current_exe().unwrap();
+
+    let output = unsafe {
+        Command::new(&me).arg("test1").pre_exec(|| {
+            // Only async-signal-safe calls are allowed between fork and exec.
+            let msg = b"hello\n";
+            libc::write(1, msg.as_ptr() as *const libc::c_void, msg.len());
+            Ok(())
+        }).output().unwrap()
+    };
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty());
+    assert_eq!(output.stdout, b"hello\nhello2\n");
+
+    let output = unsafe {
+        Command::new(&me).arg("setsid").pre_exec(|| {
+            if libc::setsid() == -1 {
+                Err(Error::last_os_error())
+            } else {
+                Ok(())
+            }
+        }).output().unwrap()
+    };
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty());
+    assert!(output.stdout.is_empty());
+
+    let output = unsafe {
+        Command::new(&me).arg("bad").pre_exec(|| {
+            Err(Error::from_raw_os_error(102))
+        }).output().unwrap_err()
+    };
+    assert_eq!(output.raw_os_error(), Some(102));
+}
@@ -0,0 +1,62 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Checks that maps and sets with a constant hasher can be placed in statics.
+
+#![feature(const_fn, drop_types_in_const)]
+
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hasher};
+
+struct FnvHasher(u64);
+
+impl Hasher for FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ byte as u64).wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+struct BuildFnvHasher;
+
+impl BuildHasher for BuildFnvHasher {
+    type Hasher = FnvHasher;
+
+    fn build_hasher(&self) -> FnvHasher {
+        FnvHasher(0xcbf29ce484222325)
+    }
+}
+
+static EMPTY_MAP: HashMap<u32, u32, BuildFnvHasher> = HashMap::with_hasher(BuildFnvHasher);
+static mut MAP: HashMap<u32, u32, BuildFnvHasher> = HashMap::with_hasher(BuildFnvHasher);
+static mut SET: HashSet<u32, BuildFnvHasher> = HashSet::with_hasher(BuildFnvHasher);
+
+pub fn main() {
+    assert!(EMPTY_MAP.is_empty());
+    assert_eq!(EMPTY_MAP.capacity(), 0);
+    assert_eq!(EMPTY_MAP.get(&1), None);
+
+    unsafe {
+        assert_eq!(MAP.capacity(), 0);
+        MAP.insert(1, 10);
+        MAP.insert(2, 20);
+        assert_eq!(MAP.get(&1), Some(&10));
+        assert_eq!(MAP.len(), 2);
+
+        assert_eq!(SET.capacity(), 0);
+        assert!(SET.insert(3));
+        assert!(SET.contains(&3));
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(conservative_impl_trait)]
+
+use std::fmt::Debug;
+use std::thread;
+
+fn adder(n: u32) -> impl Fn(u32) -> u32 {
+    move |x| x + n
+}
+
+fn counter() -> impl FnMut() -> u32 {
+    let mut count = 0;
+    move || { count += 1; count }
+}
+
+fn call_once() -> impl FnOnce() -> String {
+    let s = String::from("once");
+    move || s
+}
+
+fn squares<'a>(v: &'a [u32]) -> impl Iterator<Item = u32> + 'a {
+    v.iter().map(|&x| x * x)
+}
+
+fn odd_squares(n: u32) -> impl Iterator<Item = u32> {
+    (0..n).filter(|x| x % 2 == 1).map(|x| x * x)
+}
+
+fn chained(n: u32) -> impl Iterator<Item = u32> {
+    odd_squares(n).chain(Some(1000)).skip(1)
+}
+
+fn debuggable() -> impl Debug {
+    (1, "two", [3])
+}
+
+// The concrete type is `Send`, and that leaks through the `impl Trait`.
+fn spawn_me() -> impl Iterator<Item = u32> {
+    vec![1, 2, 3].into_iter()
+}
+
+fn main() {
+    assert_eq!(adder(2)(40), 42);
+
+    let mut c = counter();
+    c();
+    assert_eq!(c(), 2);
+
+    assert_eq!(call_once()(), "once");
+
+    assert_eq!(squares(&[1, 2, 3]).collect::<Vec<_>>(), [1, 4, 9]);
+    assert_eq!(odd_squares(6).collect::<Vec<_>>(), [1, 9, 25]);
+    assert_eq!(chained(6).collect::<Vec<_>>(), [9, 25, 1000]);
+
+    assert_eq!(format!("{:?}", debuggable()), "(1, \"two\", [3])");
+
+    let it = spawn_me();
+    let sum = thread::spawn(move || it.sum::<u32>()).join().unwrap();
+    assert_eq!(sum, 6);
+}
@@ -0,0 +1,20 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:universal_xcrate.rs
+
+extern crate universal_xcrate;
+
+use std::path::PathBuf;
+
+fn main() {
+    assert_eq!(universal_xcrate::to_path_buf("a"), PathBuf::from("a"));
+    assert_eq!(universal_xcrate::count(vec![[0, 1], [2, 3]].into_iter()), 2);
+}
@@ -0,0 +1,95 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(conservative_impl_trait, universal_impl_trait)]
+
+use std::fmt::Debug;
+use std::path::{Path, PathBuf};
+
+fn join(base: impl AsRef<Path>, rest: impl AsRef<Path>) -> PathBuf {
+    base.as_ref().join(rest)
+}
+
+fn sum(xs: impl IntoIterator<Item = u32>) -> u32 {
+    xs.into_iter().fold(0, |a, b| a + b)
+}
+
+// Parameters may be nested in other types and mixed with explicit ones.
+fn show_all<T: Debug>(prefix: T, xs: Vec<impl Debug>) -> String {
+    format!("{:?}: {:?}", prefix, xs)
+}
+
+// Lifetimes are elided as if there were a named type parameter.
+fn first<'a>(xs: &'a [impl AsRef<str>]) -> &'a str {
+    xs[0].as_ref()
+}
+
+fn name(x: &impl AsRef<str>) -> &str {
+    x.as_ref()
+}
+
+fn call_twice(mut f: impl FnMut() -> u32) -> u32 {
+    f() + f()
+}
+
+// ... and can be combined with `impl Trait` in return position.
+fn doubled(xs: impl Iterator<Item = u32>) -> impl Iterator<Item = u32> {
+    xs.map(|x| x * 2)
+}
+
+struct Counter(u32);
+
+impl Counter {
+    fn add_all(&mut self, xs: impl IntoIterator<Item = u32>) {
+        for x in xs {
+            self.0 += x;
+        }
+    }
+}
+
+trait Sink {
+    fn put(&mut self, x: impl Debug) -> String;
+}
+
+impl Sink for Vec<String> {
+    fn put(&mut self, x: impl Debug) -> String {
+        let s = format!("{:?}", x);
+        self.push(s.clone());
+        s
+    }
+}
+
+fn main() {
+    assert_eq!(join("a", "b"), Path::new("a").join("b"));
+    assert_eq!(join(PathBuf::from("a"), String::from("b")), Path::new("a").join("b"));
+
+    assert_eq!(sum(vec![1, 2, 3]), 6);
+    assert_eq!(sum(1..4), 6);
+
+    assert_eq!(show_all("xs", vec![Some(1)]), "\"xs\": [Some(1)]");
+
+    assert_eq!(first(&["a", "b"]), "a");
+    assert_eq!(name(&String::from("n")), "n");
+
+    let mut n = 0;
+    assert_eq!(call_twice(|| { n += 1; n }), 3);
+
+    assert_eq!(doubled(1..4).collect::<Vec<_>>(), [2, 4, 6]);
+
+    let mut c = Counter(0);
+    c.add_all(vec![1, 2]);
+    c.add_all(Some(3));
+    assert_eq!(c.0, 6);
+
+    let mut sink = vec![];
+    assert_eq!(sink.put(1), "1");
+    assert_eq!(sink.put("x"), "\"x\"");
+    assert_eq!(sink.len(), 2);
+}
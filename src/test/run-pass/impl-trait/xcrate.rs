@@ -8,13 +8,15 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-// ignore-pretty
-// compile-flags:-Zincremental=tmp/cfail-tests/enable-orbit-for-incr-comp -Zorbit=off
-// error-pattern:Automatically enabling `-Z orbit` because `-Z incremental` was specified
+// aux-build:xcrate.rs
 
-#![deny(warnings)]
+extern crate xcrate;
 
 fn main() {
-    FAIL! // We just need some compilation error. What we really care about is
-          // that the error pattern above is checked.
+    assert_eq!(xcrate::fourway_add(1)(2)(3)(4), 10);
+    assert_eq!(xcrate::return_closure_accessing_internal_fn()(), 2);
+
+    let evens = xcrate::evens(7);
+    assert_eq!(evens.clone().collect::<Vec<_>>(), [0, 2, 4, 6]);
+    assert_eq!(evens.sum::<u32>(), 12);
 }
@@ -0,0 +1,43 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(stdsimd, target_feature)]
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "sse4.2")]
+unsafe fn crc(x: u32) -> u32 {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::_mm_crc32_u32;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::_mm_crc32_u32;
+
+    _mm_crc32_u32(0, x)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn main() {
+    // Repeated queries are answered from the cache and have to agree.
+    let avx2 = is_x86_feature_detected!("avx2");
+    assert_eq!(avx2, is_x86_feature_detected!("avx2"));
+    if avx2 {
+        assert!(is_x86_feature_detected!("avx"));
+    }
+
+    if cfg!(target_arch = "x86_64") {
+        assert!(is_x86_feature_detected!("sse2"));
+    }
+
+    if is_x86_feature_detected!("sse4.2") {
+        assert_eq!(unsafe { crc(0) }, 0);
+    }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+fn main() {}
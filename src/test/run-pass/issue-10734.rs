@@ -9,11 +9,8 @@
 // except according to those terms.
 
 
-#![feature(unsafe_no_drop_flag)]
-
 static mut drop_count: usize = 0;
 
-#[unsafe_no_drop_flag]
 struct Foo {
     dropped: bool
 }
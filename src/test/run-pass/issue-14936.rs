@@ -26,7 +26,7 @@ macro_rules! demo {
 
             let mut history: History = vec!();
             unsafe {
-                asm!("mov ($1), $0"
+                llvm_asm!("mov ($1), $0"
                      : $output_constraint (*wrap(&mut x, "out", &mut history))
                      : "r"(&wrap(y, "in", &mut history))
                      :: "volatile");
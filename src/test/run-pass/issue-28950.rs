@@ -9,7 +9,7 @@
 // except according to those terms.
 
 // ignore-emscripten
-// compile-flags: -Z orbit=off
+// compile-flags: -O
 // (blows the stack with MIR trans and no optimizations)
 
 // Tests that the `vec!` macro does not overflow the stack when it is
@@ -13,7 +13,6 @@
 #[rustc_mir]
 fn const_mir() -> f32 { 9007199791611905.0 }
 
-#[rustc_no_mir]
 fn const_old() -> f32 { 9007199791611905.0 }
 
 fn main() {
@@ -0,0 +1,23 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Test that `--cap-lints warn` demotes lint errors requested both on the
+// command line and in attributes.
+
+// compile-flags: --cap-lints warn -D warnings -D future-incompatible
+
+#![deny(unused)]
+#![forbid(bad_style)]
+
+use std::option;
+
+fn CamelCase() {}
+
+fn main() {}
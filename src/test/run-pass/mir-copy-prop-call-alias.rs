@@ -0,0 +1,37 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z mir-opt-level=2
+
+// Copy propagation must not make `a` the destination of the call: the
+// callee would be writing its return value into the memory it's reading.
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Big {
+    a: u64,
+    b: u64,
+    c: u64,
+    d: u64,
+}
+
+#[inline(never)]
+fn shift(x: &Big) -> Big {
+    Big { a: x.d, b: x.a, c: x.b, d: x.c }
+}
+
+fn main() {
+    let mut a = Big { a: 1, b: 2, c: 3, d: 4 };
+    a = shift(&a);
+    assert_eq!(a, Big { a: 4, b: 1, c: 2, d: 3 });
+
+    let mut tuple = (Big { a: 1, b: 2, c: 3, d: 4 }, 0u8);
+    tuple.0 = shift(&tuple.0);
+    assert_eq!(tuple.0, Big { a: 4, b: 1, c: 2, d: 3 });
+}
@@ -0,0 +1,45 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Tests constants that are also evaluated from their MIR.
+
+#![feature(const_fn)]
+
+const fn double(x: u32) -> u32 {
+    x * 2
+}
+
+const A: u32 = 3 + 4;
+const B: u32 = double(A);
+const C: i8 = 200u8 as i8;
+const D: bool = B > 10 && !(A == 0);
+const E: usize = (5, 6).0 + 3;
+const F: char = 97u8 as char;
+
+#[repr(u8)]
+enum Discr {
+    X = 1 << 2,
+    Y = 0xf0 >> 4,
+}
+
+fn main() {
+    let array = [0u8; A as usize + 1];
+    let lengths: [u8; E] = [0; 2 * 4];
+    assert_eq!(A, 7);
+    assert_eq!(B, 14);
+    assert_eq!(C, -56);
+    assert!(D);
+    assert_eq!(E, 8);
+    assert_eq!(F, 'a');
+    assert_eq!(Discr::X as u8, 4);
+    assert_eq!(Discr::Y as u8, 15);
+    assert_eq!(array.len(), 8);
+    assert_eq!(lengths.len(), 8);
+}
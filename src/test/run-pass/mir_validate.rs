@@ -0,0 +1,36 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z validate-mir -Z mir-opt-level=3
+// Tests that the MIR passes leave the MIR consistent.
+
+fn add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+fn pick(x: Option<u32>) -> u32 {
+    match x {
+        Some(v) if v > 2 => add(v, 1),
+        Some(v) => v,
+        None => add(2, 2),
+    }
+}
+
+fn main() {
+    let s = String::from("hello");
+    let n = pick(Some(s.len() as u32));
+    let mut total = 0;
+    for i in 0..n {
+        total = add(total, i);
+    }
+    assert_eq!(n, 6);
+    assert_eq!(total, 15);
+    assert_eq!(pick(None), 4);
+}
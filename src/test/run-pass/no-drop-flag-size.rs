@@ -8,11 +8,10 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-#![feature(unsafe_no_drop_flag)]
+// Types with destructors don't carry a hidden drop flag.
 
 use std::mem::size_of;
 
-#[unsafe_no_drop_flag]
 struct Test<T> {
     a: T
 }
@@ -0,0 +1,60 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(specialization)]
+
+// Tests specializing a blanket impl over a bound on `T` with an impl for a
+// reference type, without going through a newtype.
+
+use std::fmt::Display;
+
+trait Describe {
+    fn describe(&self) -> String;
+
+    fn tag(&self) -> &'static str {
+        "default"
+    }
+}
+
+impl<T: Display> Describe for T {
+    default fn describe(&self) -> String {
+        format!("<{}>", self)
+    }
+
+    default fn tag(&self) -> &'static str {
+        "display"
+    }
+}
+
+impl<'a> Describe for &'a str {
+    fn describe(&self) -> String {
+        let mut s = String::with_capacity(self.len() + 2);
+        s.push('<');
+        s.push_str(self);
+        s.push('>');
+        s
+    }
+}
+
+fn describe_generic<T: Describe>(t: T) -> (String, &'static str) {
+    (t.describe(), t.tag())
+}
+
+fn main() {
+    assert_eq!(1.describe(), "<1>");
+    assert_eq!(1.tag(), "display");
+    assert_eq!("a".describe(), "<a>");
+    // `tag` is inherited from the blanket impl.
+    assert_eq!("a".tag(), "display");
+
+    let s = String::from("b");
+    assert_eq!(describe_generic(&s[..]), (String::from("<b>"), "display"));
+    assert_eq!(describe_generic(s), (String::from("<b>"), "display"));
+}
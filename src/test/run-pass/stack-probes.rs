@@ -0,0 +1,53 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Test that a function whose frame is much larger than the guard page still
+// reports a stack overflow, rather than jumping over the guard page.
+
+// ignore-windows
+// ignore-android
+// ignore-ios
+// ignore-musl
+// ignore-asmjs
+// ignore-arm
+// ignore-aarch64
+// ignore-mips
+// ignore-powerpc
+
+#![feature(asm)]
+
+use std::env;
+use std::process::Command;
+use std::thread;
+
+#[inline(always)]
+pub fn black_box<T>(dummy: T) { unsafe { llvm_asm!("" : : "r"(&dummy)) } }
+
+// Allocates a 1MB frame in one go, well past the end of a 64KB stack.
+#[inline(never)]
+fn huge_frame() {
+    let buf = [0u8; 1 << 20];
+    black_box(&buf);
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() > 1 && args[1] == "child" {
+        thread::Builder::new().stack_size(64 * 1024).spawn(huge_frame)
+            .unwrap().join().unwrap();
+        return
+    }
+
+    let child = Command::new(&args[0]).arg("child").output().unwrap();
+    assert!(!child.status.success());
+    let error = String::from_utf8_lossy(&child.stderr);
+    assert!(error.contains("has overflowed its stack"),
+            "missing overflow message: {}", error);
+}
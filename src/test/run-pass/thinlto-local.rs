@@ -0,0 +1,61 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Check that ThinLTO over the codegen units of a single crate keeps the
+// cross-unit references intact.
+
+// compile-flags: -C lto=thin-local -C codegen-units=3 -O
+
+mod a {
+    pub fn one() -> usize {
+        1
+    }
+
+    pub struct Counter(pub usize);
+
+    impl Drop for Counter {
+        fn drop(&mut self) {
+            ::b::bump();
+        }
+    }
+}
+
+mod b {
+    use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+
+    static DROPS: AtomicUsize = ATOMIC_USIZE_INIT;
+
+    pub fn bump() {
+        DROPS.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub fn drops() -> usize {
+        DROPS.load(Ordering::SeqCst)
+    }
+
+    pub fn two() -> usize {
+        ::a::one() + 1
+    }
+}
+
+mod c {
+    pub fn three<T: Fn() -> usize>(f: T) -> usize {
+        f() + ::b::two()
+    }
+}
+
+fn main() {
+    assert_eq!(a::one(), 1);
+    assert_eq!(b::two(), 2);
+    assert_eq!(c::three(a::one), 3);
+
+    drop(a::Counter(0));
+    assert_eq!(b::drops(), 1);
+}
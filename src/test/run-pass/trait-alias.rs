@@ -0,0 +1,56 @@
+// Copyright 2016 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(trait_alias)]
+
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::thread;
+
+trait Async = Send + Sync + 'static;
+
+trait DebugClone = Debug + Clone;
+
+trait IntoAndClone<T> = Into<T> + Clone;
+
+trait U32Iterator = Iterator<Item = u32>;
+
+fn spawn_with<T: Async + Debug>(t: Arc<T>) -> String {
+    // `T: Async` implies all of the aliased bounds.
+    thread::spawn(move || format!("{:?}", t)).join().unwrap()
+}
+
+fn dup<T>(t: T) -> String where T: DebugClone {
+    format!("{:?}{:?}", t.clone(), t)
+}
+
+fn convert<T: From<u8>, U: IntoAndClone<T>>(u: U) -> (T, T) {
+    (u.clone().into(), u.into())
+}
+
+fn sum<I: U32Iterator>(it: I) -> u32 {
+    it.fold(0, |a, b| a + b)
+}
+
+mod m {
+    pub trait Shared = ::std::fmt::Display + Sync;
+}
+
+fn show<T: m::Shared + ?Sized>(t: &T) -> String {
+    t.to_string()
+}
+
+fn main() {
+    assert_eq!(spawn_with(Arc::new(vec![1])), "[1]");
+    assert_eq!(dup("a"), "\"a\"\"a\"");
+    assert_eq!(convert::<u32, u8>(3), (3, 3));
+    assert_eq!(sum(vec![1, 2, 3].into_iter()), 6);
+    assert_eq!(show("str"), "str");
+}